@@ -1,28 +1,26 @@
-use nutype::nutype;
-
-/// Represents a tempo measured in beats per minute.
-#[nutype(
-    new_unchecked,
-    validate(finite, greater_or_equal = f64::EPSILON),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 1.0
-)]
-pub struct Bpm(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite, greater_or_equal = f64::EPSILON),
+        default = 1.0
+    )]
+    /// Represents a tempo measured in beats per minute.
+    pub struct Bpm(f64);
+}
 
 impl Bpm {
     /// The "soft minimum" tempo.
@@ -44,11 +42,14 @@ mod tests {
         assert!(Bpm::new(-1.0).is_err());
         assert!(Bpm::new(60.0).unwrap() < Bpm::new(120.0).unwrap());
         assert_eq!(Bpm::default(), Bpm::new(1.0).unwrap());
-        assert_eq!(
-            serde_json::from_str::<Bpm>("5").unwrap(),
-            Bpm::new(5.0).unwrap()
-        );
-        assert!(serde_json::from_str::<Bpm>("-0.5").is_err());
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                serde_json::from_str::<Bpm>("5").unwrap(),
+                Bpm::new(5.0).unwrap()
+            );
+            assert!(serde_json::from_str::<Bpm>("-0.5").is_err());
+        }
         assert_eq!(Bpm::new(756.5).unwrap().to_string(), "756.5");
         assert_eq!(format!("{:?}", Bpm::new(756.5).unwrap()), "Bpm(756.5)");
         assert_eq!(Bpm::ONE_BPM.into_inner(), 1.0);
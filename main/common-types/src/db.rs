@@ -1,28 +1,27 @@
 use crate::constants::LN10_OVER_TWENTY;
 use crate::LinearVolumeValue;
-use nutype::nutype;
 
-/// Represents a volume measured in decibel.
-#[nutype(
-    new_unchecked,
-    validate(greater_or_equal = -1000.0),
-    derive(
-        Copy,
-        Clone,
-        PartialEq,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct Db(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            PartialEq,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(greater_or_equal = -1000.0),
+        default = 0.0
+    )]
+    /// Represents a volume measured in decibel.
+    pub struct Db(f64);
+}
 
 impl Db {
     /// The minimum possible value (= [`MINUS_INF`]).
@@ -74,11 +73,14 @@ mod tests {
     fn basics() {
         assert!(Db::new(60.0).unwrap() < Db::new(120.0).unwrap());
         assert_eq!(Db::default(), Db::new(0.0).unwrap());
-        assert_eq!(
-            serde_json::from_str::<Db>("5").unwrap(),
-            Db::new(5.0).unwrap()
-        );
-        assert!(serde_json::from_str::<Db>("-2000").is_err());
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                serde_json::from_str::<Db>("5").unwrap(),
+                Db::new(5.0).unwrap()
+            );
+            assert!(serde_json::from_str::<Db>("-2000").is_err());
+        }
         assert_eq!(Db::new(756.5).unwrap().to_string(), "756.5");
         assert_eq!(format!("{:?}", Db::new(756.5).unwrap()), "Db(756.5)");
         unsafe {
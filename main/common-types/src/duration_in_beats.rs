@@ -1,28 +1,26 @@
-use nutype::nutype;
-
-/// This represents a duration expressed as positive amount of beats.
-#[nutype(
-    new_unchecked,
-    validate(finite, greater_or_equal = 0.0),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct DurationInBeats(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite, greater_or_equal = 0.0),
+        default = 0.0
+    )]
+    /// This represents a duration expressed as positive amount of beats.
+    pub struct DurationInBeats(f64);
+}
 
 impl DurationInBeats {
     /// The minimum duration (zero, empty).
@@ -1,28 +1,26 @@
-use nutype::nutype;
-
-/// This represents a duration expressed as positive amount of quarter notes.
-#[nutype(
-    new_unchecked,
-    validate(finite, greater_or_equal = 0.0),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct DurationInQuarterNotes(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite, greater_or_equal = 0.0),
+        default = 0.0
+    )]
+    /// This represents a duration expressed as positive amount of quarter notes.
+    pub struct DurationInQuarterNotes(f64);
+}
 
 impl DurationInQuarterNotes {
     /// The minimum duration (zero, empty).
@@ -0,0 +1,88 @@
+use crate::{samples_from_seconds, DurationInSeconds, Hz};
+
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Hash,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        // Every raw value is valid but a validation is required for the fallible constructor
+        validate(predicate = |_| true),
+        default = 0
+    )]
+    /// This represents a duration expressed as positive amount of samples.
+    pub struct DurationInSamples(u64);
+}
+
+impl DurationInSamples {
+    /// The minimum duration (zero, empty).
+    pub const ZERO: DurationInSamples = unsafe { DurationInSamples::new_unchecked(0) };
+
+    nutype_additions!(u64);
+
+    /// The maximum possible duration.
+    pub const MAX: DurationInSamples = unsafe { DurationInSamples::new_unchecked(u64::MAX) };
+
+    /// Converts the given duration in seconds to the nearest amount of samples.
+    ///
+    /// Returns `None` if the result doesn't fit into a signed 64-bit integer (so it can always be
+    /// added to a [`PositionInSamples`](crate::PositionInSamples) without precision loss).
+    pub fn from_seconds(duration: DurationInSeconds, sample_rate: Hz) -> Option<Self> {
+        let samples = samples_from_seconds(duration.get(), sample_rate)?;
+        Some(Self::new_panic(samples as u64))
+    }
+
+    /// Converts this duration to seconds.
+    pub fn to_seconds(&self, sample_rate: Hz) -> DurationInSeconds {
+        DurationInSeconds::new_panic(self.get() as f64 / sample_rate.get())
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(&self, rhs: DurationInSamples) -> Option<Self> {
+        Some(Self::new_panic(self.get().checked_add(rhs.get())?))
+    }
+
+    /// Saturating duration subtraction.
+    ///
+    /// Computes `self - rhs`, saturating at zero.
+    pub fn saturating_sub(&self, rhs: DurationInSamples) -> Self {
+        Self::new_panic(self.get().saturating_sub(rhs.get()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        let sample_rate = Hz::new_panic(44100.0);
+        let duration =
+            DurationInSamples::from_seconds(DurationInSeconds::new_panic(0.5), sample_rate)
+                .unwrap();
+        assert_eq!(duration, DurationInSamples::new_panic(22050));
+        assert_eq!(duration.to_seconds(sample_rate).get(), 0.5);
+        assert_eq!(
+            DurationInSamples::from_seconds(DurationInSeconds::MAX, sample_rate),
+            None
+        );
+        assert_eq!(DurationInSamples::MAX.checked_add(duration), None);
+        assert_eq!(
+            DurationInSamples::ZERO.saturating_sub(duration),
+            DurationInSamples::ZERO
+        );
+        assert_eq!(duration.to_string(), "22050");
+    }
+}
@@ -1,30 +1,29 @@
 use crate::PositionInSeconds;
-use nutype::nutype;
 use std::ops::{Add, Mul};
 
-/// This represents a duration expressed as positive amount of seconds.
-#[nutype(
-    new_unchecked,
-    validate(finite, greater_or_equal = 0.0),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct DurationInSeconds(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite, greater_or_equal = 0.0),
+        default = 0.0
+    )]
+    /// This represents a duration expressed as positive amount of seconds.
+    pub struct DurationInSeconds(f64);
+}
 
 impl DurationInSeconds {
     /// The minimum duration (zero, empty).
@@ -1,29 +1,28 @@
-use nutype::nutype;
 use std::ops::Div;
 
-/// Represents a frequency measured in hertz (how often something happens per second).
-#[nutype(
-    new_unchecked,
-    validate(finite, greater_or_equal = f64::EPSILON),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = f64::EPSILON
-)]
-pub struct Hz(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite, greater_or_equal = f64::EPSILON),
+        default = f64::EPSILON
+    )]
+    /// Represents a frequency measured in hertz (how often something happens per second).
+    pub struct Hz(f64);
+}
 
 impl Hz {
     /// The minimum frequency.
@@ -48,11 +47,14 @@ mod tests {
     fn basics() {
         assert!(Hz::new(60.0).unwrap() < Hz::new(120.0).unwrap());
         assert_eq!(Hz::default(), Hz::new(f64::EPSILON).unwrap());
-        assert_eq!(
-            serde_json::from_str::<Hz>("5").unwrap(),
-            Hz::new(5.0).unwrap()
-        );
-        assert!(serde_json::from_str::<Hz>("-0.5").is_err());
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                serde_json::from_str::<Hz>("5").unwrap(),
+                Hz::new(5.0).unwrap()
+            );
+            assert!(serde_json::from_str::<Hz>("-0.5").is_err());
+        }
         assert_eq!(Hz::new(756.5).unwrap().to_string(), "756.5");
         assert_eq!(format!("{:?}", Hz::new(756.5).unwrap()), "Hz(756.5)");
         assert_eq!(Hz::MIN.into_inner(), f64::EPSILON);
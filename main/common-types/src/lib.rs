@@ -9,28 +9,44 @@ mod bpm;
 mod db;
 mod duration_in_beats;
 mod duration_in_quarter_notes;
+mod duration_in_samples;
 mod duration_in_seconds;
 mod hz;
 mod linear_volume_value;
+mod midi_note_number;
+mod octave;
 mod pan_value;
+mod pitch_class;
 mod position_in_beats;
 mod position_in_pulses_per_quarter_note;
 mod position_in_quarter_notes;
+mod position_in_samples;
 mod position_in_seconds;
 mod rgb_color;
 mod semitones;
+mod time_range;
+mod time_signature;
+mod timecode;
 
 pub use bpm::*;
 pub use db::*;
 pub use duration_in_beats::*;
 pub use duration_in_quarter_notes::*;
+pub use duration_in_samples::*;
 pub use duration_in_seconds::*;
 pub use hz::*;
 pub use linear_volume_value::*;
+pub use midi_note_number::*;
+pub use octave::*;
 pub use pan_value::*;
+pub use pitch_class::*;
 pub use position_in_beats::*;
 pub use position_in_pulses_per_quarter_note::*;
 pub use position_in_quarter_notes::*;
+pub use position_in_samples::*;
 pub use position_in_seconds::*;
 pub use rgb_color::*;
 pub use semitones::*;
+pub use time_range::*;
+pub use time_signature::*;
+pub use timecode::*;
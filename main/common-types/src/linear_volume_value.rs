@@ -1,68 +1,67 @@
 use crate::constants::TWENTY_OVER_LN10;
 use crate::Db;
-use nutype::nutype;
 
-/// This represents a volume measured in REAPER's native volume unit.
-///
-/// What I call "REAPER's native volume unit" is in REAPER/WDL code often called `val` or `gain`. It's essentially
-/// a dB value represented as linear factor, and thus it's suitable in scenarios such as altering the amplitude of
-/// a sample, simply by multiplying with this value.
-///
-/// # Formulas
-///
-/// Some formulas for conversion from val to dB and vice versa. Using the constants
-/// is maybe slightly more efficient.
-///
-/// ```ignore
-/// TWENTY_OVER_LN10 = 20 / log(10)
-/// LN10_OVER_TWENTY = log(10) / 20
-///
-/// db = log10(val) * 20
-///    = log(val) / LN10_OVER_TWENTY
-///    = log(val) * TWENTY_OVER_LN10
-///
-/// val = pow(10, db / 20.0)
-///     = exp(db * LN10_OVER_TWENTY)
-/// ```
-///
-/// # Examples
-///
-/// - A value of 0.0 or very close corresponds to -inf dB
-/// - A value of 0.000000063095734448019 corresponds to -144 dB (the first dB value not showed as -inf anymore
-///   in the REAPER GUI)
-/// - A value of 0.5 corresponds to -6.02 dB (roughly halved volume)
-/// - A value of 1.0 corresponds to 0.0 dB (unaltered volume)
-/// - A value of 2.0 corresponds to 6.02 dB (roughly doubled volume)
-/// - A value of 3.981071705535 corresponds to 12 dB (REAPER's "soft maximum" volume)
-/// - Higher values are possible but harder to enter via GUI
-///
-/// # Usages
-///
-/// - Track volume
-/// - Send volume
-/// - Item volume
-/// - Take volume
-/// - Track peaks
-#[nutype(
-    new_unchecked,
-    validate(greater_or_equal = 0.0),
-    derive(
-        Copy,
-        Clone,
-        PartialEq,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 1.0
-)]
-pub struct LinearVolumeValue(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            PartialEq,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(greater_or_equal = 0.0),
+        default = 1.0
+    )]
+    /// This represents a volume measured in REAPER's native volume unit.
+    ///
+    /// What I call "REAPER's native volume unit" is in REAPER/WDL code often called `val` or `gain`. It's essentially
+    /// a dB value represented as linear factor, and thus it's suitable in scenarios such as altering the amplitude of
+    /// a sample, simply by multiplying with this value.
+    ///
+    /// # Formulas
+    ///
+    /// Some formulas for conversion from val to dB and vice versa. Using the constants
+    /// is maybe slightly more efficient.
+    ///
+    /// ```ignore
+    /// TWENTY_OVER_LN10 = 20 / log(10)
+    /// LN10_OVER_TWENTY = log(10) / 20
+    ///
+    /// db = log10(val) * 20
+    ///    = log(val) / LN10_OVER_TWENTY
+    ///    = log(val) * TWENTY_OVER_LN10
+    ///
+    /// val = pow(10, db / 20.0)
+    ///     = exp(db * LN10_OVER_TWENTY)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// - A value of 0.0 or very close corresponds to -inf dB
+    /// - A value of 0.000000063095734448019 corresponds to -144 dB (the first dB value not showed as -inf anymore
+    ///   in the REAPER GUI)
+    /// - A value of 0.5 corresponds to -6.02 dB (roughly halved volume)
+    /// - A value of 1.0 corresponds to 0.0 dB (unaltered volume)
+    /// - A value of 2.0 corresponds to 6.02 dB (roughly doubled volume)
+    /// - A value of 3.981071705535 corresponds to 12 dB (REAPER's "soft maximum" volume)
+    /// - Higher values are possible but harder to enter via GUI
+    ///
+    /// # Usages
+    ///
+    /// - Track volume
+    /// - Send volume
+    /// - Item volume
+    /// - Take volume
+    /// - Track peaks
+    pub struct LinearVolumeValue(f64);
+}
 
 impl LinearVolumeValue {
     /// The minimum possible value (0.0).
@@ -138,11 +137,14 @@ mod tests {
             LinearVolumeValue::default(),
             LinearVolumeValue::new(1.0).unwrap()
         );
-        assert_eq!(
-            serde_json::from_str::<LinearVolumeValue>("5").unwrap(),
-            LinearVolumeValue::new(5.0).unwrap()
-        );
-        assert!(serde_json::from_str::<LinearVolumeValue>("-0.5").is_err());
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                serde_json::from_str::<LinearVolumeValue>("5").unwrap(),
+                LinearVolumeValue::new(5.0).unwrap()
+            );
+            assert!(serde_json::from_str::<LinearVolumeValue>("-0.5").is_err());
+        }
         assert_eq!(LinearVolumeValue::new(756.5).unwrap().to_string(), "756.5");
         assert_eq!(
             format!("{:?}", LinearVolumeValue::new(756.5).unwrap()),
@@ -43,3 +43,23 @@ macro_rules! nutype_additions {
         }
     };
 }
+
+/// Declares a newtype via nutype, additionally deriving `Serialize` and `Deserialize` if the
+/// `serde` feature is enabled.
+///
+/// The `derive` argument must come first and the doc comments after the `nutype` attribute.
+macro_rules! nutype_with_optional_serde {
+    (
+        #[nutype(derive($($derive:ident),* $(,)?), $($arg:tt)*)]
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($inner:ty);
+    ) => {
+        #[cfg_attr(
+            feature = "serde",
+            ::nutype::nutype(derive($($derive,)* Serialize, Deserialize), $($arg)*)
+        )]
+        #[cfg_attr(not(feature = "serde"), ::nutype::nutype(derive($($derive),*), $($arg)*))]
+        $(#[$attr])*
+        $vis struct $name($inner);
+    };
+}
@@ -0,0 +1,180 @@
+use crate::pitch_class::parse_pitch_name;
+use crate::{Octave, PitchClass, Semitones};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Hash,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(less_or_equal = 127),
+        default = 0
+    )]
+    /// Represents a MIDI note number (0 to 127).
+    ///
+    /// Note 60 is middle C, which is called `C4` in scientific pitch notation.
+    pub struct MidiNoteNumber(u8);
+}
+
+impl MidiNoteNumber {
+    /// The lowest note (`C-1`).
+    pub const MIN: Self = unsafe { Self::new_unchecked(0) };
+
+    /// Middle C (`C4`).
+    pub const MIDDLE_C: Self = unsafe { Self::new_unchecked(60) };
+
+    /// The highest note (`G9`).
+    pub const MAX: Self = unsafe { Self::new_unchecked(127) };
+
+    nutype_additions!(u8);
+
+    /// Creates the note with the given pitch class in the given octave.
+    ///
+    /// Returns `None` if the note is above `G9`.
+    pub fn from_parts(pitch_class: PitchClass, octave: Octave) -> Option<Self> {
+        let number = (octave.get() as i16 + 1) * 12 + pitch_class.index() as i16;
+        Self::new(u8::try_from(number).ok()?).ok()
+    }
+
+    pub fn pitch_class(&self) -> PitchClass {
+        PitchClass::from_index(self.get())
+    }
+
+    pub fn octave(&self) -> Octave {
+        Octave::new_panic((self.get() / 12) as i8 - 1)
+    }
+
+    /// Returns the note transposed by the given amount of semitones.
+    ///
+    /// Returns `None` if the amount is not a whole number or if the result is out of range.
+    pub fn transpose(&self, amount: Semitones) -> Option<Self> {
+        let target = Semitones::new(Semitones::from(*self).get() + amount.get()).ok()?;
+        target.try_into().ok()
+    }
+
+    /// Returns the name of this note in scientific pitch notation using sharps, e.g. `C#4`.
+    pub fn note_name(&self) -> String {
+        format!("{}{}", self.pitch_class(), self.octave())
+    }
+
+    /// Parses a note name in scientific pitch notation such as `C4`, `f#-1` or `Bb3`.
+    pub fn from_note_name(name: &str) -> Result<Self, NoteNameError> {
+        let name = name.trim();
+        let octave_start = name
+            .find(|c: char| c == '-' || c.is_ascii_digit())
+            .ok_or(NoteNameError::Malformed)?;
+        let (pitch_class, octave) = name.split_at(octave_start);
+        let semitones = parse_pitch_name(pitch_class).ok_or(NoteNameError::Malformed)?;
+        let octave: i8 = octave.parse().map_err(|_| NoteNameError::Malformed)?;
+        // Not using Octave because Cb0 belongs to octave -1 and B#9 to octave 10
+        let number = (octave as i16 + 1) * 12 + semitones as i16;
+        u8::try_from(number)
+            .ok()
+            .and_then(|n| Self::new(n).ok())
+            .ok_or(NoteNameError::OutOfRange)
+    }
+}
+
+/// Returns the distance to note 0 (`C-1`).
+impl From<MidiNoteNumber> for Semitones {
+    fn from(value: MidiNoteNumber) -> Self {
+        Semitones::new_panic(value.get() as f64)
+    }
+}
+
+/// Interprets the semitones as distance to note 0 (`C-1`).
+impl TryFrom<Semitones> for MidiNoteNumber {
+    type Error = NoteNameError;
+
+    fn try_from(value: Semitones) -> Result<Self, Self::Error> {
+        let raw = value.get();
+        if raw.fract() != 0.0 || !(0.0..=127.0).contains(&raw) {
+            return Err(NoteNameError::OutOfRange);
+        }
+        Ok(Self::new_panic(raw as u8))
+    }
+}
+
+/// Error which can occur when converting something into a [`MidiNoteNumber`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NoteNameError {
+    Malformed,
+    OutOfRange,
+}
+
+impl Display for NoteNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            NoteNameError::Malformed => "note name must have the form C#4",
+            NoteNameError::OutOfRange => "not a valid MIDI note",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for NoteNameError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        assert!(MidiNoteNumber::new(128).is_err());
+        let note = MidiNoteNumber::MIDDLE_C;
+        assert_eq!(note.pitch_class(), PitchClass::C);
+        assert_eq!(note.octave().get(), 4);
+        assert_eq!(note.note_name(), "C4");
+        assert_eq!(MidiNoteNumber::MIN.note_name(), "C-1");
+        assert_eq!(MidiNoteNumber::MAX.note_name(), "G9");
+        assert_eq!(
+            MidiNoteNumber::from_parts(PitchClass::A, Octave::new_panic(4)),
+            Some(MidiNoteNumber::new_panic(69))
+        );
+        assert_eq!(
+            MidiNoteNumber::from_parts(PitchClass::GSharp, Octave::MAX),
+            None
+        );
+        assert_eq!(
+            MidiNoteNumber::from_note_name("f#-1"),
+            Ok(MidiNoteNumber::new_panic(6))
+        );
+        assert_eq!(
+            MidiNoteNumber::from_note_name("Cb4"),
+            Ok(MidiNoteNumber::new_panic(59))
+        );
+        assert_eq!(
+            MidiNoteNumber::from_note_name("B#3"),
+            Ok(MidiNoteNumber::MIDDLE_C)
+        );
+        assert_eq!(
+            MidiNoteNumber::from_note_name("G#9"),
+            Err(NoteNameError::OutOfRange)
+        );
+        assert_eq!(
+            MidiNoteNumber::from_note_name("X4"),
+            Err(NoteNameError::Malformed)
+        );
+        assert_eq!(Semitones::from(note).get(), 60.0);
+        assert_eq!(
+            note.transpose(Semitones::new_panic(-12.0)),
+            Some(MidiNoteNumber::new_panic(48))
+        );
+        assert_eq!(note.transpose(Semitones::new_panic(0.5)), None);
+        assert_eq!(note.transpose(Semitones::new_panic(68.0)), None);
+    }
+}
@@ -0,0 +1,48 @@
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Hash,
+            Debug,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(greater_or_equal = -1, less_or_equal = 9)
+    )]
+    /// Represents an octave in scientific pitch notation, in which middle C (MIDI note 60) is in
+    /// octave 4.
+    ///
+    /// The MIDI note range spans octaves -1 to 9.
+    pub struct Octave(i8);
+}
+
+impl Octave {
+    /// The lowest octave which contains MIDI notes.
+    pub const MIN: Self = unsafe { Self::new_unchecked(-1) };
+
+    /// The highest octave which contains MIDI notes.
+    pub const MAX: Self = unsafe { Self::new_unchecked(9) };
+
+    nutype_additions!(i8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        assert!(Octave::new(-2).is_err());
+        assert!(Octave::new(10).is_err());
+        assert!(Octave::MIN < Octave::new_panic(4));
+        assert_eq!(Octave::MAX.get(), 9);
+    }
+}
@@ -1,28 +1,26 @@
-use nutype::nutype;
-
-/// This represents a pan measured in REAPER's native pan unit.
-#[nutype(
-    new_unchecked,
-    validate(finite, greater_or_equal = -1.0, less_or_equal = 1.0),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct PanValue(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite, greater_or_equal = -1.0, less_or_equal = 1.0),
+        default = 0.0
+    )]
+    /// This represents a pan measured in REAPER's native pan unit.
+    pub struct PanValue(f64);
+}
 
 impl PanValue {
     /// The minimum possible value (= [`LEFT`]).
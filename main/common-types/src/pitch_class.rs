@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter};
+
+/// One of the 12 pitch classes of the chromatic scale.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PitchClass {
+    #[default]
+    C,
+    CSharp,
+    D,
+    DSharp,
+    E,
+    F,
+    FSharp,
+    G,
+    GSharp,
+    A,
+    ASharp,
+    B,
+}
+
+impl PitchClass {
+    /// All pitch classes in ascending order, starting with C.
+    pub const ALL: [PitchClass; 12] = {
+        use PitchClass::*;
+        [C, CSharp, D, DSharp, E, F, FSharp, G, GSharp, A, ASharp, B]
+    };
+
+    /// Returns the pitch class at the given index (wrapping around, so 12 is C again).
+    pub fn from_index(index: u8) -> Self {
+        Self::ALL[(index % 12) as usize]
+    }
+
+    /// Returns the number of semitones above C (0 to 11).
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the name of this pitch class using sharps, e.g. `C#`.
+    pub fn name(self) -> &'static str {
+        use PitchClass::*;
+        match self {
+            C => "C",
+            CSharp => "C#",
+            D => "D",
+            DSharp => "D#",
+            E => "E",
+            F => "F",
+            FSharp => "F#",
+            G => "G",
+            GSharp => "G#",
+            A => "A",
+            ASharp => "A#",
+            B => "B",
+        }
+    }
+
+    /// Parses a pitch class name such as `C`, `c#` or `Eb`.
+    ///
+    /// Returns `None` if the name is not valid.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let semitones = parse_pitch_name(name)?;
+        Some(Self::from_index(semitones.rem_euclid(12) as u8))
+    }
+}
+
+/// Parses a pitch name such as `Eb` and returns the number of semitones above C.
+///
+/// Doesn't wrap around, so `Cb` results in -1 and `B#` in 12.
+pub(crate) fn parse_pitch_name(name: &str) -> Option<i8> {
+    let mut chars = name.chars();
+    let natural = match chars.next()?.to_ascii_uppercase() {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+    let accidental = match chars.as_str() {
+        "" => 0,
+        "#" => 1,
+        "b" => -1,
+        _ => return None,
+    };
+    Some(natural + accidental)
+}
+
+impl Display for PitchClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        assert_eq!(PitchClass::from_index(13), PitchClass::CSharp);
+        assert_eq!(PitchClass::B.index(), 11);
+        assert_eq!(PitchClass::from_name("Eb"), Some(PitchClass::DSharp));
+        assert_eq!(PitchClass::from_name("cb"), Some(PitchClass::B));
+        assert_eq!(PitchClass::from_name("H"), None);
+        assert_eq!(PitchClass::FSharp.to_string(), "F#");
+    }
+}
@@ -1,34 +1,32 @@
 use std::ops::{Add, Neg};
 
-use nutype::nutype;
-
 use crate::DurationInBeats;
 
-/// This represents a position expressed as an amount of beats.
-///
-/// Can be negative.
-#[nutype(
-    new_unchecked,
-    validate(finite),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct PositionInBeats(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite),
+        default = 0.0
+    )]
+    /// This represents a position expressed as an amount of beats.
+    ///
+    /// Can be negative.
+    pub struct PositionInBeats(f64);
+}
 
 impl PositionInBeats {
     /// Position at 0.0 seconds. E.g. start of project, measure, etc. depending on the context.
@@ -1,32 +1,31 @@
 use crate::DurationInQuarterNotes;
-use nutype::nutype;
 use std::ops::{Add, Neg};
 
-/// This represents a position expressed as an amount of quarter notes.
-///
-/// Can be negative.
-#[nutype(
-    new_unchecked,
-    validate(finite),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct PositionInQuarterNotes(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite),
+        default = 0.0
+    )]
+    /// This represents a position expressed as an amount of quarter notes.
+    ///
+    /// Can be negative.
+    pub struct PositionInQuarterNotes(f64);
+}
 
 impl PositionInQuarterNotes {
     /// Position at 0.0 seconds. E.g. start of project, measure, etc. depending on the context.
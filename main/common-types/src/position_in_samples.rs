@@ -0,0 +1,117 @@
+use crate::{DurationInSamples, Hz, PositionInSeconds};
+
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Hash,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        // Every raw value is valid but a validation is required for the fallible constructor
+        validate(predicate = |_| true),
+        default = 0
+    )]
+    /// This represents a position expressed as an amount of samples.
+    ///
+    /// Can be negative. Convert from and to seconds only at the boundaries, e.g. in audio-hook code,
+    /// in order to avoid rounding errors accumulating.
+    pub struct PositionInSamples(i64);
+}
+
+impl PositionInSamples {
+    /// Position at sample 0. E.g. start of project, measure, etc. depending on the context.
+    pub const ZERO: PositionInSamples = unsafe { PositionInSamples::new_unchecked(0) };
+
+    nutype_additions!(i64);
+
+    /// Converts the given position in seconds to the nearest sample position.
+    ///
+    /// Returns `None` if the result doesn't fit into this type.
+    pub fn from_seconds(position: PositionInSeconds, sample_rate: Hz) -> Option<Self> {
+        let samples = samples_from_seconds(position.get(), sample_rate)?;
+        Some(Self::new_panic(samples as i64))
+    }
+
+    /// Converts this position to seconds.
+    pub fn to_seconds(&self, sample_rate: Hz) -> PositionInSeconds {
+        PositionInSeconds::new_panic(self.get() as f64 / sample_rate.get())
+    }
+
+    /// Checked addition of a duration. Returns `None` on overflow.
+    pub fn checked_add(&self, rhs: DurationInSamples) -> Option<Self> {
+        let rhs = i64::try_from(rhs.get()).ok()?;
+        Some(Self::new_panic(self.get().checked_add(rhs)?))
+    }
+
+    /// Checked subtraction of a duration. Returns `None` on overflow.
+    pub fn checked_sub(&self, rhs: DurationInSamples) -> Option<Self> {
+        let rhs = i64::try_from(rhs.get()).ok()?;
+        Some(Self::new_panic(self.get().checked_sub(rhs)?))
+    }
+
+    /// Returns the distance from `earlier` to this position.
+    ///
+    /// Returns `None` if `earlier` is after this position.
+    pub fn checked_duration_since(&self, earlier: PositionInSamples) -> Option<DurationInSamples> {
+        let diff = self.get().checked_sub(earlier.get())?;
+        Some(DurationInSamples::new_panic(u64::try_from(diff).ok()?))
+    }
+}
+
+/// Converts seconds to a (rounded) amount of samples, making sure the result is representable as
+/// `i64`.
+pub(crate) fn samples_from_seconds(seconds: f64, sample_rate: Hz) -> Option<f64> {
+    let samples = (seconds * sample_rate.get()).round();
+    if samples.is_finite() && samples >= i64::MIN as f64 && samples < i64::MAX as f64 {
+        Some(samples)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        let sample_rate = Hz::new_panic(48000.0);
+        let pos = PositionInSamples::from_seconds(PositionInSeconds::new_panic(-1.5), sample_rate);
+        assert_eq!(pos, Some(PositionInSamples::new_panic(-72000)));
+        assert_eq!(pos.unwrap().to_seconds(sample_rate).get(), -1.5);
+        assert_eq!(
+            PositionInSamples::from_seconds(PositionInSeconds::new_panic(f64::MAX), sample_rate),
+            None
+        );
+        let pos = PositionInSamples::new_panic(100);
+        let duration = DurationInSamples::new_panic(150);
+        assert_eq!(
+            pos.checked_sub(duration),
+            Some(PositionInSamples::new_panic(-50))
+        );
+        assert_eq!(
+            pos.checked_add(duration)
+                .unwrap()
+                .checked_duration_since(pos),
+            Some(duration)
+        );
+        assert_eq!(PositionInSamples::ZERO.checked_duration_since(pos), None);
+        assert_eq!(
+            PositionInSamples::new_panic(i64::MAX).checked_add(DurationInSamples::new_panic(1)),
+            None
+        );
+        #[cfg(feature = "serde")]
+        assert_eq!(serde_json::to_string(&pos).unwrap(), "100");
+    }
+}
@@ -1,35 +1,34 @@
 use crate::duration_in_seconds::DurationInSeconds;
-use nutype::nutype;
 use std::cmp::Ordering;
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-/// Represents a position expressed as amount of seconds.
-///
-/// Sometimes this is a negative number, e.g. when it's a position on the timeline and a metronome
-/// count-in is used or at the very beginning of the project (maybe because of rounding). Negative
-/// project start values don't seem to cause negative position values though.
-#[nutype(
-    new_unchecked,
-    validate(finite),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct PositionInSeconds(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite),
+        default = 0.0
+    )]
+    /// Represents a position expressed as amount of seconds.
+    ///
+    /// Sometimes this is a negative number, e.g. when it's a position on the timeline and a metronome
+    /// count-in is used or at the very beginning of the project (maybe because of rounding). Negative
+    /// project start values don't seem to cause negative position values though.
+    pub struct PositionInSeconds(f64);
+}
 
 impl PositionInSeconds {
     /// Position at 0.0 seconds. E.g. start of project, measure, etc. depending on the context.
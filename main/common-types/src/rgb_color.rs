@@ -1,5 +1,6 @@
 /// 24-bit non-linear sRGB color.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RgbColor {
     pub r: u8,
     pub g: u8,
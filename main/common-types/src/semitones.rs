@@ -1,28 +1,26 @@
-use nutype::nutype;
-
-/// Represents a pitch delta measured in semitones.
-#[nutype(
-    new_unchecked,
-    validate(finite),
-    derive(
-        Copy,
-        Clone,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        Debug,
-        Default,
-        Display,
-        FromStr,
-        Into,
-        TryFrom,
-        Serialize,
-        Deserialize
-    ),
-    default = 0.0
-)]
-pub struct Semitones(f64);
+nutype_with_optional_serde! {
+    #[nutype(
+        derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Debug,
+            Default,
+            Display,
+            FromStr,
+            Into,
+            TryFrom
+        ),
+        new_unchecked,
+        validate(finite),
+        default = 0.0
+    )]
+    /// Represents a pitch delta measured in semitones.
+    pub struct Semitones(f64);
+}
 
 impl Semitones {
     /// No difference.
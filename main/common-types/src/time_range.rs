@@ -0,0 +1,178 @@
+use crate::{DurationInSeconds, PositionInSeconds};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A range on the timeline, e.g. the time selection, the loop range or the extent of an item.
+///
+/// The start is inclusive, the end exclusive.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawTimeRange")
+)]
+pub struct TimeRange {
+    start: PositionInSeconds,
+    end: PositionInSeconds,
+}
+
+impl TimeRange {
+    /// Creates a time range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `end` is before `start`.
+    pub fn new(start: PositionInSeconds, end: PositionInSeconds) -> Result<Self, TimeRangeError> {
+        if end < start {
+            return Err(TimeRangeError);
+        }
+        Ok(TimeRange { start, end })
+    }
+
+    /// Creates a time range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` is before `start`.
+    pub fn new_panic(start: PositionInSeconds, end: PositionInSeconds) -> Self {
+        Self::new(start, end).expect("invalid time range")
+    }
+
+    /// Creates a time range that starts at the given position and has the given length.
+    pub fn from_start_and_length(start: PositionInSeconds, length: DurationInSeconds) -> TimeRange {
+        TimeRange {
+            start,
+            end: start + length,
+        }
+    }
+
+    pub fn start(&self) -> PositionInSeconds {
+        self.start
+    }
+
+    pub fn end(&self) -> PositionInSeconds {
+        self.end
+    }
+
+    pub fn length(&self) -> DurationInSeconds {
+        DurationInSeconds::new_panic(self.end.get() - self.start.get())
+    }
+
+    /// Returns whether this range has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns whether the given position is within this range (start inclusive, end exclusive).
+    pub fn contains(&self, position: PositionInSeconds) -> bool {
+        position >= self.start && position < self.end
+    }
+
+    /// Returns whether the given range lies completely within this range.
+    pub fn contains_range(&self, other: &TimeRange) -> bool {
+        other.start >= self.start && other.end <= self.end
+    }
+
+    /// Returns whether this range and the given one have a non-empty intersection.
+    pub fn overlaps(&self, other: &TimeRange) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the range covered by both this range and the given one.
+    ///
+    /// Returns `None` if the ranges don't overlap (ranges which just touch each other don't).
+    pub fn intersection(&self, other: &TimeRange) -> Option<TimeRange> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        if start < end {
+            Some(TimeRange { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns this range moved by the given offset (which can be negative).
+    pub fn shift(&self, offset: PositionInSeconds) -> TimeRange {
+        TimeRange {
+            start: self.start + offset,
+            end: self.end + offset,
+        }
+    }
+}
+
+/// Unvalidated form of [`TimeRange`] for deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawTimeRange {
+    start: PositionInSeconds,
+    end: PositionInSeconds,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawTimeRange> for TimeRange {
+    type Error = TimeRangeError;
+
+    fn try_from(value: RawTimeRange) -> Result<Self, Self::Error> {
+        TimeRange::new(value.start, value.end)
+    }
+}
+
+/// Error which can occur when creating a [`TimeRange`] whose end is before its start.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TimeRangeError;
+
+impl Display for TimeRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("end of time range must not be before start")
+    }
+}
+
+impl Error for TimeRangeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: f64, end: f64) -> TimeRange {
+        TimeRange::new_panic(
+            PositionInSeconds::new_panic(start),
+            PositionInSeconds::new_panic(end),
+        )
+    }
+
+    #[test]
+    fn basics() {
+        let a = range(1.0, 5.0);
+        assert_eq!(a.length().get(), 4.0);
+        assert!(a.contains(PositionInSeconds::new_panic(1.0)));
+        assert!(!a.contains(PositionInSeconds::new_panic(5.0)));
+        assert_eq!(TimeRange::from_start_and_length(a.start(), a.length()), a);
+        assert_eq!(a.intersection(&range(3.0, 8.0)), Some(range(3.0, 5.0)));
+        assert_eq!(a.intersection(&range(5.0, 8.0)), None);
+        assert!(!a.overlaps(&range(-2.0, 1.0)));
+        assert!(a.contains_range(&range(2.0, 5.0)));
+        assert!(!a.contains_range(&range(2.0, 6.0)));
+        assert_eq!(
+            a.shift(PositionInSeconds::new_panic(-2.0)),
+            range(-1.0, 3.0)
+        );
+        assert!(range(2.0, 2.0).is_empty());
+        let zero = PositionInSeconds::new_panic(0.0);
+        assert_eq!(
+            TimeRange::new(PositionInSeconds::new_panic(1.0), zero),
+            Err(TimeRangeError)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        let a: TimeRange = serde_json::from_str(r#"{"start":1.0,"end":5.0}"#).unwrap();
+        assert_eq!(a, range(1.0, 5.0));
+        assert_eq!(
+            serde_json::to_string(&a).unwrap(),
+            r#"{"start":1.0,"end":5.0}"#
+        );
+        assert!(serde_json::from_str::<TimeRange>(r#"{"start":5.0,"end":1.0}"#).is_err());
+    }
+}
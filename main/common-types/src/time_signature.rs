@@ -0,0 +1,158 @@
+use crate::{Bpm, DurationInQuarterNotes, DurationInSeconds};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+/// Time signature.
+///
+/// Tempo values ([`Bpm`]) are interpreted the way REAPER does it, as quarter notes per minute,
+/// independent of the time signature's denominator.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeSignature {
+    /// Measure length in beats.
+    pub numerator: NonZeroU32,
+    /// What musical unit one beat stands for.
+    pub denominator: NonZeroU32,
+}
+
+impl TimeSignature {
+    /// Creates a time signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the numerator or denominator is zero.
+    pub fn new(numerator: u32, denominator: u32) -> Result<Self, TimeSignatureError> {
+        let numerator = NonZeroU32::new(numerator).ok_or(TimeSignatureError::ZeroNumerator)?;
+        let denominator =
+            NonZeroU32::new(denominator).ok_or(TimeSignatureError::ZeroDenominator)?;
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Creates a time signature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numerator or denominator is zero.
+    pub fn new_panic(numerator: u32, denominator: u32) -> Self {
+        Self::new(numerator, denominator).expect("invalid time signature")
+    }
+
+    /// Returns the number of beats per measure.
+    pub fn beats_per_measure(&self) -> u32 {
+        self.numerator.get()
+    }
+
+    /// Returns the length of one beat in quarter notes (e.g. 0.5 in 6/8).
+    pub fn beat_length(&self) -> DurationInQuarterNotes {
+        DurationInQuarterNotes::new_panic(4.0 / self.denominator.get() as f64)
+    }
+
+    /// Returns the length of one measure in quarter notes (e.g. 3.0 in 6/8).
+    pub fn measure_length(&self) -> DurationInQuarterNotes {
+        DurationInQuarterNotes::new_panic(self.numerator.get() as f64 * self.beat_length().get())
+    }
+
+    /// Returns the duration of one beat at the given tempo.
+    pub fn beat_duration(&self, tempo: Bpm) -> DurationInSeconds {
+        quarter_notes_to_seconds(self.beat_length(), tempo)
+    }
+
+    /// Returns the duration of one measure at the given tempo.
+    pub fn measure_duration(&self, tempo: Bpm) -> DurationInSeconds {
+        quarter_notes_to_seconds(self.measure_length(), tempo)
+    }
+
+    /// Returns the number of beats (in the unit of this time signature) per minute at the given
+    /// tempo (e.g. 240 at 120 BPM in 6/8).
+    pub fn beats_per_minute(&self, tempo: Bpm) -> f64 {
+        tempo.get() / self.beat_length().get()
+    }
+
+    /// Returns the tempo at which there are the given number of beats (in the unit of this time
+    /// signature) per minute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting tempo is not valid (e.g. not positive).
+    pub fn tempo_from_beats_per_minute(&self, beats_per_minute: f64) -> Bpm {
+        Bpm::new_panic(beats_per_minute * self.beat_length().get())
+    }
+}
+
+fn quarter_notes_to_seconds(length: DurationInQuarterNotes, tempo: Bpm) -> DurationInSeconds {
+    DurationInSeconds::new_panic(length.get() * 60.0 / tempo.get())
+}
+
+impl Display for TimeSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl FromStr for TimeSignature {
+    type Err = TimeSignatureError;
+
+    /// Parses a time signature such as `6/8`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numerator, denominator) = s.split_once('/').ok_or(TimeSignatureError::Malformed)?;
+        let numerator = numerator
+            .trim()
+            .parse()
+            .map_err(|_| TimeSignatureError::Malformed)?;
+        let denominator = denominator
+            .trim()
+            .parse()
+            .map_err(|_| TimeSignatureError::Malformed)?;
+        Self::new(numerator, denominator)
+    }
+}
+
+/// Error which can occur when creating a [`TimeSignature`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TimeSignatureError {
+    ZeroNumerator,
+    ZeroDenominator,
+    Malformed,
+}
+
+impl Display for TimeSignatureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            TimeSignatureError::ZeroNumerator => "time signature numerator must not be zero",
+            TimeSignatureError::ZeroDenominator => "time signature denominator must not be zero",
+            TimeSignatureError::Malformed => {
+                "time signature must have the form numerator/denominator"
+            }
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for TimeSignatureError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        assert!(TimeSignature::new(0, 4).is_err());
+        assert!(TimeSignature::new(4, 0).is_err());
+        let six_eight: TimeSignature = "6/8".parse().unwrap();
+        assert_eq!(six_eight, TimeSignature::new_panic(6, 8));
+        assert_eq!(six_eight.to_string(), "6/8");
+        assert_eq!(six_eight.beats_per_measure(), 6);
+        assert_eq!(six_eight.measure_length().get(), 3.0);
+        let tempo = Bpm::new_panic(120.0);
+        assert_eq!(six_eight.beat_duration(tempo).get(), 0.25);
+        assert_eq!(six_eight.measure_duration(tempo).get(), 1.5);
+        assert_eq!(six_eight.beats_per_minute(tempo), 240.0);
+        assert_eq!(six_eight.tempo_from_beats_per_minute(240.0), tempo);
+        assert!("6-8".parse::<TimeSignature>().is_err());
+    }
+}
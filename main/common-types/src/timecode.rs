@@ -0,0 +1,304 @@
+use crate::PositionInSeconds;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Video/timecode frame rate.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameRate {
+    /// 24000/1001 fps (film pulled down to NTSC).
+    Fps23_976,
+    Fps24,
+    Fps25,
+    /// 30000/1001 fps with non-drop-frame timecode.
+    Fps29_97NonDrop,
+    /// 30000/1001 fps with drop-frame timecode.
+    Fps29_97Drop,
+    Fps30,
+}
+
+impl FrameRate {
+    /// Returns the actual number of frames per second.
+    pub fn fps(self) -> f64 {
+        use FrameRate::*;
+        match self {
+            Fps23_976 => 24000.0 / 1001.0,
+            Fps24 => 24.0,
+            Fps25 => 25.0,
+            Fps29_97NonDrop | Fps29_97Drop => 30000.0 / 1001.0,
+            Fps30 => 30.0,
+        }
+    }
+
+    /// Returns the number of frames per timecode second (the frame count at which the frames
+    /// field wraps).
+    pub fn nominal_fps(self) -> u32 {
+        use FrameRate::*;
+        match self {
+            Fps23_976 | Fps24 => 24,
+            Fps25 => 25,
+            Fps29_97NonDrop | Fps29_97Drop | Fps30 => 30,
+        }
+    }
+
+    /// Returns whether timecode labels are dropped in order to stay in sync with the clock.
+    pub fn is_drop_frame(self) -> bool {
+        self == FrameRate::Fps29_97Drop
+    }
+}
+
+impl Display for FrameRate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use FrameRate::*;
+        let label = match self {
+            Fps23_976 => "23.976",
+            Fps24 => "24",
+            Fps25 => "25",
+            Fps29_97NonDrop => "29.97 NDF",
+            Fps29_97Drop => "29.97 DF",
+            Fps30 => "30",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Number of frame labels dropped at the start of each minute (except every tenth minute) in
+/// 29.97 drop-frame timecode.
+const DROPPED_FRAMES_PER_MINUTE: u64 = 2;
+
+/// A SMPTE timecode (hours:minutes:seconds:frames) at a certain frame rate.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Timecode {
+    is_negative: bool,
+    hours: u32,
+    minutes: u32,
+    seconds: u32,
+    frames: u32,
+    frame_rate: FrameRate,
+}
+
+impl Timecode {
+    /// Creates a (non-negative) timecode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the fields is out of range or if the frame label doesn't exist
+    /// in drop-frame timecode.
+    pub fn new(
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+        frames: u32,
+        frame_rate: FrameRate,
+    ) -> Result<Self, TimecodeError> {
+        if minutes >= 60 || seconds >= 60 || frames >= frame_rate.nominal_fps() {
+            return Err(TimecodeError::OutOfRange);
+        }
+        if frame_rate.is_drop_frame()
+            && seconds == 0
+            && !minutes.is_multiple_of(10)
+            && (frames as u64) < DROPPED_FRAMES_PER_MINUTE
+        {
+            return Err(TimecodeError::DroppedFrame);
+        }
+        Ok(Self {
+            is_negative: false,
+            hours,
+            minutes,
+            seconds,
+            frames,
+            frame_rate,
+        })
+    }
+
+    /// Creates the timecode of the given frame number (counted from zero).
+    pub fn from_frame_count(frame_count: i64, frame_rate: FrameRate) -> Self {
+        let nominal_fps = frame_rate.nominal_fps() as u64;
+        let mut label = frame_count.unsigned_abs();
+        if frame_rate.is_drop_frame() {
+            // Convert the real frame count to the frame label count by adding the dropped labels
+            let frames_per_minute = nominal_fps * 60 - DROPPED_FRAMES_PER_MINUTE;
+            let frames_per_ten_minutes = nominal_fps * 600 - 9 * DROPPED_FRAMES_PER_MINUTE;
+            let ten_minute_blocks = label / frames_per_ten_minutes;
+            let remainder = label % frames_per_ten_minutes;
+            let minutes_with_drop = if remainder > DROPPED_FRAMES_PER_MINUTE {
+                (remainder - DROPPED_FRAMES_PER_MINUTE) / frames_per_minute
+            } else {
+                0
+            };
+            label += 9 * DROPPED_FRAMES_PER_MINUTE * ten_minute_blocks
+                + DROPPED_FRAMES_PER_MINUTE * minutes_with_drop;
+        }
+        let total_seconds = label / nominal_fps;
+        Self {
+            is_negative: frame_count < 0,
+            hours: (total_seconds / 3600) as u32,
+            minutes: (total_seconds / 60 % 60) as u32,
+            seconds: (total_seconds % 60) as u32,
+            frames: (label % nominal_fps) as u32,
+            frame_rate,
+        }
+    }
+
+    /// Creates the timecode of the frame which contains the given position.
+    pub fn from_position(position: PositionInSeconds, frame_rate: FrameRate) -> Self {
+        // The tolerance prevents positions exactly at a frame start from ending up in the
+        // previous frame due to floating point inaccuracy
+        let frame_count = (position.get() * frame_rate.fps() + 1e-6).floor() as i64;
+        Self::from_frame_count(frame_count, frame_rate)
+    }
+
+    /// Returns the frame number (counted from zero).
+    pub fn to_frame_count(&self) -> i64 {
+        let nominal_fps = self.frame_rate.nominal_fps() as u64;
+        let total_seconds =
+            self.hours as u64 * 3600 + self.minutes as u64 * 60 + self.seconds as u64;
+        let mut count = total_seconds * nominal_fps + self.frames as u64;
+        if self.frame_rate.is_drop_frame() {
+            let total_minutes = self.hours as u64 * 60 + self.minutes as u64;
+            count -= DROPPED_FRAMES_PER_MINUTE * (total_minutes - total_minutes / 10);
+        }
+        let count = count as i64;
+        if self.is_negative {
+            -count
+        } else {
+            count
+        }
+    }
+
+    /// Returns the position at which the frame of this timecode starts.
+    pub fn to_position(&self) -> PositionInSeconds {
+        PositionInSeconds::new_panic(self.to_frame_count() as f64 / self.frame_rate.fps())
+    }
+
+    /// Parses a timecode such as `01:02:03:04` (drop-frame timecode may use `;` as last separator)
+    /// optionally prefixed by `-`.
+    pub fn parse(text: &str, frame_rate: FrameRate) -> Result<Self, TimecodeError> {
+        let (is_negative, text) = match text.trim().strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.trim()),
+        };
+        let mut fields = text.split([':', ';', '.']).map(|f| f.parse::<u32>());
+        let mut next = || match fields.next() {
+            Some(Ok(v)) => Ok(v),
+            _ => Err(TimecodeError::Malformed),
+        };
+        let (hours, minutes, seconds, frames) = (next()?, next()?, next()?, next()?);
+        if fields.next().is_some() {
+            return Err(TimecodeError::Malformed);
+        }
+        let timecode = Self::new(hours, minutes, seconds, frames, frame_rate)?;
+        Ok(Self {
+            is_negative,
+            ..timecode
+        })
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    pub fn hours(&self) -> u32 {
+        self.hours
+    }
+
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    pub fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    pub fn frame_rate(&self) -> FrameRate {
+        self.frame_rate
+    }
+}
+
+/// Formats the timecode as `HH:MM:SS:FF` (`HH:MM:SS;FF` for drop-frame timecode).
+impl Display for Timecode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative { "-" } else { "" };
+        let frame_separator = if self.frame_rate.is_drop_frame() {
+            ';'
+        } else {
+            ':'
+        };
+        write!(
+            f,
+            "{sign}{:02}:{:02}:{:02}{frame_separator}{:02}",
+            self.hours, self.minutes, self.seconds, self.frames
+        )
+    }
+}
+
+/// Error which can occur when creating a [`Timecode`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TimecodeError {
+    OutOfRange,
+    DroppedFrame,
+    Malformed,
+}
+
+impl Display for TimecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            TimecodeError::OutOfRange => "timecode field out of range",
+            TimecodeError::DroppedFrame => "frame doesn't exist in drop-frame timecode",
+            TimecodeError::Malformed => "timecode must have the form HH:MM:SS:FF",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for TimecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_drop() {
+        let tc = Timecode::from_position(PositionInSeconds::new_panic(3661.5), FrameRate::Fps25);
+        assert_eq!(tc.to_string(), "01:01:01:12");
+        assert_eq!(tc.to_position().get(), 3661.48);
+        let tc = Timecode::from_position(PositionInSeconds::new_panic(-1.0), FrameRate::Fps24);
+        assert_eq!(tc.to_string(), "-00:00:01:00");
+        assert_eq!(Timecode::parse("-00:00:01:00", FrameRate::Fps24), Ok(tc));
+        assert!(Timecode::parse("00:00:01:24", FrameRate::Fps24).is_err());
+    }
+
+    #[test]
+    fn drop_frame() {
+        let rate = FrameRate::Fps29_97Drop;
+        assert_eq!(
+            Timecode::from_frame_count(1799, rate).to_string(),
+            "00:00:59;29"
+        );
+        assert_eq!(
+            Timecode::from_frame_count(1800, rate).to_string(),
+            "00:01:00;02"
+        );
+        assert_eq!(
+            Timecode::from_frame_count(17982, rate).to_string(),
+            "00:10:00;00"
+        );
+        for count in [0, 1799, 1800, 17981, 17982, 107892, 123456] {
+            assert_eq!(
+                Timecode::from_frame_count(count, rate).to_frame_count(),
+                count
+            );
+        }
+        assert_eq!(
+            Timecode::parse("00:01:00;00", rate),
+            Err(TimecodeError::DroppedFrame)
+        );
+        // One hour of drop-frame timecode is almost exactly one hour of real time
+        let tc = Timecode::parse("01:00:00;00", rate).unwrap();
+        assert!((tc.to_position().get() - 3600.0).abs() < 0.01);
+    }
+}
@@ -209,6 +209,26 @@ impl Action {
         Ok(name)
     }
 
+    /// Returns human-readable descriptions of all keyboard shortcuts assigned to this action.
+    pub fn shortcut_descriptions(&self) -> ReaperResult<Vec<ReaperString>> {
+        let rd = self.load_if_necessary_or_complain()?;
+        let descriptions = rd
+            .section
+            .with_raw(|s| unsafe {
+                let reaper = Reaper::get().medium_reaper();
+                let count = reaper.count_action_shortcuts(Sec(s), rd.command_id);
+                (0..count)
+                    .filter_map(|i| {
+                        reaper
+                            .get_action_shortcut_desc(Sec(s), rd.command_id, i, 256)
+                            .ok()
+                    })
+                    .collect()
+            })
+            .ok_or("section not available")?;
+        Ok(descriptions)
+    }
+
     pub fn invoke_as_trigger(
         &self,
         project: Option<Project>,
@@ -1,5 +1,10 @@
+use crate::error::ReaperResult;
 use crate::{Project, Reaper};
-use reaper_medium::{BookmarkId, EnumProjectMarkers3Result, NativeColor, PositionInSeconds};
+use enumflags2::BitFlags;
+use reaper_medium::{
+    BookmarkId, EnumProjectMarkers3Result, MarkerOrRegionPosition, NativeColor, PositionInSeconds,
+    ReaperStringArg, SetProjectMarkerFlag,
+};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum BookmarkType {
@@ -35,6 +40,108 @@ impl IndexBasedBookmark {
         self.with_full_info(|res| res.name.to_str().to_owned())
     }
 
+    pub fn set_name<'a>(&self, name: impl Into<ReaperStringArg<'a>>) -> ReaperResult<()> {
+        let name = name.into().into_inner();
+        // An empty name would otherwise leave the name unchanged.
+        let flags = if name.to_str().is_empty() {
+            SetProjectMarkerFlag::ClearName.into()
+        } else {
+            BitFlags::empty()
+        };
+        self.edit("Rename marker/region", name.as_ref(), None, flags, |pos| {
+            pos
+        })
+    }
+
+    pub fn set_color(&self, color: NativeColor) -> ReaperResult<()> {
+        self.edit(
+            "Change marker/region color",
+            "",
+            Some(color),
+            BitFlags::empty(),
+            |pos| pos,
+        )
+    }
+
+    /// Moves the marker or region start to the given position, keeping the region length.
+    pub fn set_position(&self, position: PositionInSeconds) -> ReaperResult<()> {
+        self.edit(
+            "Move marker/region",
+            "",
+            None,
+            BitFlags::empty(),
+            |pos| match pos {
+                MarkerOrRegionPosition::Marker(_) => MarkerOrRegionPosition::Marker(position),
+                MarkerOrRegionPosition::Region(start, end) => {
+                    let length = end.get() - start.get();
+                    MarkerOrRegionPosition::Region(
+                        position,
+                        PositionInSeconds::new_panic(position.get() + length),
+                    )
+                }
+            },
+        )
+    }
+
+    /// Sets the end of this region.
+    ///
+    /// Returns an error if this is a marker.
+    pub fn set_region_end_position(&self, end: PositionInSeconds) -> ReaperResult<()> {
+        if self.basic_info().bookmark_type() != BookmarkType::Region {
+            return Err("bookmark is not a region".into());
+        }
+        self.edit(
+            "Resize region",
+            "",
+            None,
+            BitFlags::empty(),
+            |pos| match pos {
+                MarkerOrRegionPosition::Region(start, _) => {
+                    MarkerOrRegionPosition::Region(start, end)
+                }
+                marker => marker,
+            },
+        )
+    }
+
+    pub fn delete(&self) -> ReaperResult<()> {
+        self.project.undoable("Delete marker/region", || {
+            Reaper::get()
+                .medium_reaper()
+                .delete_project_marker_by_index(self.project.context(), self.index)?;
+            Ok(())
+        })
+    }
+
+    fn edit<'a>(
+        &self,
+        undo_label: &'static str,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+        flags: BitFlags<SetProjectMarkerFlag>,
+        get_new_pos: impl FnOnce(MarkerOrRegionPosition) -> MarkerOrRegionPosition,
+    ) -> ReaperResult<()> {
+        let info = self.basic_info();
+        let pos = match info.region_end_position {
+            None => MarkerOrRegionPosition::Marker(info.position),
+            Some(end) => MarkerOrRegionPosition::Region(info.position, end),
+        };
+        self.project.undoable(undo_label, || {
+            Reaper::get()
+                .medium_reaper()
+                .set_project_marker_by_index_2(
+                    self.project.context(),
+                    self.index,
+                    get_new_pos(pos),
+                    info.id,
+                    name,
+                    color,
+                    flags,
+                )?;
+            Ok(())
+        })
+    }
+
     pub fn with_full_info<R>(&self, use_result: impl FnOnce(EnumProjectMarkers3Result) -> R) -> R {
         Reaper::get()
             .medium_reaper()
@@ -1,17 +1,24 @@
 use crate::{
-    get_media_track_guid, ControlSurfaceEvent, Fx, FxParameter, Guid, Project, Reaper, Track,
-    TrackRoute,
+    get_media_track_guid, ControlSurfaceEvent, Envelope, Fx, FxParameter, Guid, Item,
+    MetronomeState, MidiInputDevice, MidiOutputDevice, Project, Reaper, Take, Track, TrackRoute,
 };
 use reaper_medium::ProjectContext::{CurrentProject, Proj};
 use reaper_medium::{
-    reaper_str, AutomationMode, Bpm, ExtSetFxParamArgs, GlobalAutomationModeOverride,
-    InputMonitoringMode, MediaTrack, Pan, PanMode, PlayState, PlaybackSpeedFactor, ReaProject,
+    reaper_str, AutomationMode, BeginLoadProjectStateArgs, Bpm, DurationInSeconds, EnvelopePoint,
+    ExtSetFxParamArgs, GlobalAutomationModeOverride, InputMonitoringMode, MediaItem, MediaItemTake,
+    MediaTrack, MidiInputDeviceId, MidiOutputDeviceId, Pan, PanMode, PlayState,
+    PlaybackSpeedFactor, PositionInSeconds, ProjectConfigExtension, ReaProject,
     ReaperNormalizedFxParamValue, ReaperPanValue, ReaperStr, ReaperVersion, ReaperVolumeValue,
-    RecordingInput, TrackAttributeKey, TrackFxChainType, TrackLocation, TrackSendCategory,
-    TrackSendDirection, VersionDependentFxLocation, VersionDependentTrackFxLocation,
+    RecordingInput, TempoTimeSigMarker, TimeRange, TrackAttributeKey, TrackEnvelope,
+    TrackFxChainType, TrackLocation, TrackSendCategory, TrackSendDirection,
+    VersionDependentFxLocation, VersionDependentTrackFxLocation,
 };
 use std::cell::{Cell, RefCell, RefMut};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 #[derive(Debug)]
 pub struct ChangeDetectionMiddleware {
@@ -19,15 +26,132 @@ pub struct ChangeDetectionMiddleware {
     last_active_project: Cell<Project>,
     last_global_automation_mode_override: Cell<Option<GlobalAutomationModeOverride>>,
     project_datas: RefCell<ProjectDataMap>,
+    /// If set, FX parameter changes are additionally reported as settled values once they haven't
+    /// changed for this long.
+    fx_param_settle_interval: Cell<Option<Duration>>,
+    pending_fx_param_settles: RefCell<HashMap<(MediaTrack, TrackFxKey), PendingFxParamSettle>>,
+    /// Metronome state at the time of the last poll (REAPER doesn't notify us about changes).
+    last_metronome_state: Cell<Option<MetronomeState>>,
+    midi_device_data: RefCell<Option<MidiDeviceData>>,
+    /// Whether the projects which were open before change detection started have been captured.
+    open_projects_captured: Cell<bool>,
+    /// Projects reported by [`ProjectLoadListener`] which haven't been processed yet.
+    pending_project_loads: Rc<RefCell<Vec<Project>>>,
+    /// Tracks reported by control surface callbacks since the last envelope poll.
+    ///
+    /// Only envelopes on these tracks are hashed completely, the others are just checked for a
+    /// changed point count.
+    tracks_touched_since_envelope_poll: RefCell<HashSet<MediaTrack>>,
     // Capabilities depending on REAPER version
     supports_detection_of_input_fx: bool,
 }
 
 type ProjectDataMap = HashMap<ReaProject, ProjectData>;
 
+/// How often to check for connected or disconnected MIDI devices.
+///
+/// Checking all device slots is comparatively expensive, so we don't do it on each main loop cycle.
+const MIDI_DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Keeps the connection state of all MIDI devices (indexed by device ID) for detecting hot-plugs.
+#[derive(Debug)]
+struct MidiDeviceData {
+    last_poll: Instant,
+    connected_inputs: Vec<bool>,
+    connected_outputs: Vec<bool>,
+}
+
 #[derive(Debug, Default)]
 struct ProjectData {
     track_datas: TrackDataMap,
+    tempo_map_data: Option<TempoMapData>,
+    item_set_data: Option<ItemSetData>,
+    envelope_set_data: Option<EnvelopeSetData>,
+    /// Whether the project was dirty at the time of the last poll.
+    was_dirty: Option<bool>,
+    time_range_data: Option<TimeRangeData>,
+}
+
+/// Keeps the time selection and loop range for detecting changes, which REAPER doesn't notify us
+/// about.
+#[derive(Copy, Clone, Debug)]
+struct TimeRangeData {
+    time_selection: Option<TimeRange>,
+    loop_range: Option<TimeRange>,
+}
+
+/// Keeps the tempo map for detecting changes, which REAPER doesn't notify us about.
+#[derive(Debug)]
+struct TempoMapData {
+    project_state_change_count: u32,
+    markers: Vec<TempoTimeSigMarker>,
+}
+
+/// Keeps current item values for detecting changes, which REAPER doesn't notify us about.
+#[derive(Debug)]
+struct ItemSetData {
+    project_state_change_count: u32,
+    item_datas: HashMap<MediaItem, ItemData>,
+}
+
+/// Keeps a signature of all track envelopes for detecting edits, which REAPER doesn't notify us
+/// about.
+#[derive(Debug)]
+struct EnvelopeSetData {
+    project_state_change_count: u32,
+    signatures: HashMap<TrackEnvelope, EnvelopeSignature>,
+}
+
+/// Cheap fingerprint of the points of an envelope.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct EnvelopeSignature {
+    point_count: u32,
+    points_hash: u64,
+}
+
+impl EnvelopeSignature {
+    fn from_envelope(envelope: &Envelope, point_count: u32) -> EnvelopeSignature {
+        let mut hasher = DefaultHasher::new();
+        for point in envelope.points() {
+            hash_envelope_point(&point, &mut hasher);
+        }
+        EnvelopeSignature {
+            point_count,
+            points_hash: hasher.finish(),
+        }
+    }
+}
+
+fn hash_envelope_point(point: &EnvelopePoint, hasher: &mut impl Hasher) {
+    point.time.get().to_bits().hash(hasher);
+    point.value.to_bits().hash(hasher);
+    point.shape.hash(hasher);
+    point.tension.to_bits().hash(hasher);
+    point.is_selected.hash(hasher);
+}
+
+#[derive(Debug)]
+struct ItemData {
+    track: Option<MediaTrack>,
+    position: PositionInSeconds,
+    length: DurationInSeconds,
+    active_take: Option<MediaItemTake>,
+    active_take_name: Option<String>,
+    is_selected: bool,
+}
+
+impl ItemData {
+    fn from_item(item: Item) -> ItemData {
+        let active_take = item.active_take();
+        ItemData {
+            track: item.track().map(|t| t.raw_unchecked()),
+            position: item.position(),
+            length: item.length(),
+            active_take: active_take.map(|t| t.raw()),
+            active_take_name: active_take.map(|t| t.name()),
+            is_selected: item.is_selected(),
+        }
+    }
 }
 
 type TrackDataMap = HashMap<MediaTrack, TrackData>;
@@ -126,6 +250,31 @@ impl TrackData {
     }
 }
 
+/// Reports project loads to the [`ChangeDetectionMiddleware`] which created it.
+///
+/// See [`ChangeDetectionMiddleware::project_load_listener()`].
+#[derive(Debug)]
+pub struct ProjectLoadListener {
+    pending_project_loads: Rc<RefCell<Vec<Project>>>,
+}
+
+impl ProjectConfigExtension for ProjectLoadListener {
+    fn begin_load_project_state(&mut self, args: BeginLoadProjectStateArgs) {
+        if args.is_undo {
+            return;
+        }
+        let Some(project) = Reaper::get()
+            .medium_reaper()
+            .get_current_project_in_load_save()
+        else {
+            return;
+        };
+        self.pending_project_loads
+            .borrow_mut()
+            .push(Project::new(project));
+    }
+}
+
 /// For detection of added or removed FX.
 #[derive(Debug, Default)]
 struct FxChainPair {
@@ -133,6 +282,13 @@ struct FxChainPair {
     output_fx_guids: HashSet<Guid>,
 }
 
+#[derive(Debug)]
+struct PendingFxParamSettle {
+    parameter: FxParameter,
+    value: ReaperNormalizedFxParamValue,
+    last_change: Instant,
+}
+
 #[derive(Eq, PartialEq, Hash, Debug)]
 struct TrackFxKey {
     is_input_fx: bool,
@@ -158,6 +314,13 @@ impl Default for ChangeDetectionMiddleware {
                 Reaper::get().global_automation_override(),
             ),
             project_datas: Default::default(),
+            fx_param_settle_interval: Default::default(),
+            pending_fx_param_settles: Default::default(),
+            last_metronome_state: Default::default(),
+            midi_device_data: Default::default(),
+            open_projects_captured: Default::default(),
+            pending_project_loads: Default::default(),
+            tracks_touched_since_envelope_poll: Default::default(),
             // since pre1,
             supports_detection_of_input_fx: version >= reaper_version_5_95,
         }
@@ -169,10 +332,39 @@ impl ChangeDetectionMiddleware {
         Default::default()
     }
 
+    /// Returns a project config extension which lets this middleware detect project loads.
+    ///
+    /// Without it, opened projects are only detected when they show up for the first time, so
+    /// loading a project into an existing tab goes unnoticed. Register it via
+    /// [`Reaper::register_project_config_extension()`].
+    pub fn project_load_listener(&self) -> ProjectLoadListener {
+        ProjectLoadListener {
+            pending_project_loads: self.pending_project_loads.clone(),
+        }
+    }
+
+    /// Enables or disables reporting of settled FX parameter values.
+    ///
+    /// During automation playback, REAPER reports FX parameter changes at a very high rate. If an
+    /// interval is given, an additional [`ChangeEvent::FxParameterValueSettled`] is emitted for a
+    /// parameter as soon as its value hasn't changed for at least that long. Consumers which are
+    /// only interested in the final values can subscribe to that event instead of
+    /// [`ChangeEvent::FxParameterValueChanged`].
+    ///
+    /// Disabled by default.
+    pub fn set_fx_parameter_settle_interval(&self, interval: Option<Duration>) {
+        self.fx_param_settle_interval.set(interval);
+        if interval.is_none() {
+            self.pending_fx_param_settles.borrow_mut().clear();
+        }
+    }
+
     /// This should be called regularly. It takes care of detecting changes that can only be
     /// detected via polling. Some things only when project state count has changed, others every
     /// single time.
     pub fn run(&mut self, handle_change: &mut impl FnMut(ChangeEvent)) {
+        self.capture_open_projects();
+        self.process_pending_project_loads(handle_change);
         let reaper = Reaper::get();
         let project = reaper.current_project();
         if let Some(mut project_data) = self.find_project_data(project.raw()) {
@@ -182,6 +374,306 @@ impl ChangeDetectionMiddleware {
                 &mut project_data,
                 handle_change,
             );
+            self.poll_for_tempo_map_changes(project, &mut project_data, handle_change);
+            self.poll_for_item_changes(project, &mut project_data, handle_change);
+            self.poll_for_envelope_changes(project, &mut project_data, handle_change);
+            self.poll_for_project_save(project, &mut project_data, handle_change);
+            self.poll_for_time_range_changes(project, &mut project_data, handle_change);
+        }
+        self.poll_for_metronome_changes(handle_change);
+        self.poll_for_midi_device_changes(handle_change);
+        self.emit_settled_fx_param_values(handle_change);
+    }
+
+    fn poll_for_midi_device_changes(&self, handle_change: &mut impl FnMut(ChangeEvent)) {
+        let now = Instant::now();
+        let mut data = self.midi_device_data.borrow_mut();
+        if let Some(d) = data.as_ref() {
+            if now.duration_since(d.last_poll) < MIDI_DEVICE_POLL_INTERVAL {
+                return;
+            }
+        }
+        let reaper = Reaper::get();
+        let new_data = MidiDeviceData {
+            last_poll: now,
+            connected_inputs: reaper
+                .midi_input_devices()
+                .map(|d| d.is_connected())
+                .collect(),
+            connected_outputs: reaper
+                .midi_output_devices()
+                .map(|d| d.is_connected())
+                .collect(),
+        };
+        let Some(old_data) = data.replace(new_data) else {
+            // First time, nothing to compare with
+            return;
+        };
+        let new_data = data.as_ref().unwrap();
+        let (connected_inputs, disconnected_inputs) =
+            diff_connection_states(&old_data.connected_inputs, &new_data.connected_inputs);
+        let (connected_outputs, disconnected_outputs) =
+            diff_connection_states(&old_data.connected_outputs, &new_data.connected_outputs);
+        if connected_inputs.is_empty()
+            && disconnected_inputs.is_empty()
+            && connected_outputs.is_empty()
+            && disconnected_outputs.is_empty()
+        {
+            return;
+        }
+        let to_inputs = |ids: Vec<u8>| {
+            ids.into_iter()
+                .map(|id| MidiInputDevice::new(MidiInputDeviceId::new(id)))
+                .collect()
+        };
+        let to_outputs = |ids: Vec<u8>| {
+            ids.into_iter()
+                .map(|id| MidiOutputDevice::new(MidiOutputDeviceId::new(id)))
+                .collect()
+        };
+        handle_change(ChangeEvent::MidiDevicesChanged(MidiDevicesChangedEvent {
+            connected_inputs: to_inputs(connected_inputs),
+            disconnected_inputs: to_inputs(disconnected_inputs),
+            connected_outputs: to_outputs(connected_outputs),
+            disconnected_outputs: to_outputs(disconnected_outputs),
+        }));
+    }
+
+    fn poll_for_metronome_changes(&self, handle_change: &mut impl FnMut(ChangeEvent)) {
+        let new_state = Reaper::get().metronome().state();
+        let old_state = self.last_metronome_state.replace(Some(new_state));
+        if matches!(old_state, Some(old_state) if old_state != new_state) {
+            handle_change(ChangeEvent::MetronomeChanged(MetronomeChangedEvent {
+                new_value: new_state,
+            }));
+        }
+    }
+
+    fn emit_settled_fx_param_values(&self, handle_change: &mut impl FnMut(ChangeEvent)) {
+        let Some(interval) = self.fx_param_settle_interval.get() else {
+            return;
+        };
+        let now = Instant::now();
+        let mut settled = vec![];
+        self.pending_fx_param_settles
+            .borrow_mut()
+            .retain(|_, pending| {
+                if now.duration_since(pending.last_change) < interval {
+                    return true;
+                }
+                settled.push(FxParameterValueSettledEvent {
+                    parameter: pending.parameter.clone(),
+                    value: pending.value,
+                });
+                false
+            });
+        // Emit only after the borrow has ended because handlers might change FX parameters.
+        for event in settled {
+            handle_change(ChangeEvent::FxParameterValueSettled(event));
+        }
+    }
+
+    fn poll_for_project_save(
+        &self,
+        project: Project,
+        project_data: &mut ProjectData,
+        handle_change: &mut impl FnMut(ChangeEvent),
+    ) {
+        let is_dirty = project.is_dirty();
+        let was_dirty = project_data.was_dirty.replace(is_dirty);
+        if was_dirty == Some(true) && !is_dirty {
+            handle_change(ChangeEvent::ProjectSaved(ProjectSavedEvent { project }));
+        }
+    }
+
+    fn poll_for_time_range_changes(
+        &self,
+        project: Project,
+        project_data: &mut ProjectData,
+        handle_change: &mut impl FnMut(ChangeEvent),
+    ) {
+        // Changing the time selection doesn't necessarily change the project state, so we need to
+        // check every time.
+        let new_data = TimeRangeData {
+            time_selection: project.time_selection(),
+            loop_range: project.loop_range(),
+        };
+        if let Some(old_data) = project_data.time_range_data.replace(new_data) {
+            if old_data.time_selection != new_data.time_selection {
+                handle_change(ChangeEvent::TimeSelectionChanged(
+                    TimeSelectionChangedEvent {
+                        project,
+                        new_value: new_data.time_selection,
+                    },
+                ));
+            }
+            if old_data.loop_range != new_data.loop_range {
+                handle_change(ChangeEvent::LoopRangeChanged(LoopRangeChangedEvent {
+                    project,
+                    new_value: new_data.loop_range,
+                }));
+            }
+        }
+    }
+
+    fn poll_for_item_changes(
+        &self,
+        project: Project,
+        project_data: &mut ProjectData,
+        handle_change: &mut impl FnMut(ChangeEvent),
+    ) {
+        // Reading all items is only necessary if something in the project has changed at all.
+        let project_state_change_count = project.state_change_count();
+        if let Some(data) = &project_data.item_set_data {
+            if data.project_state_change_count == project_state_change_count {
+                return;
+            }
+        }
+        let new_item_datas: HashMap<_, _> = project
+            .items()
+            .map(|item| (item.raw(), ItemData::from_item(item)))
+            .collect();
+        let old_data = project_data.item_set_data.take();
+        // First time, nothing to compare with
+        if let Some(old_data) = old_data {
+            for (raw_item, old) in &old_data.item_datas {
+                if !new_item_datas.contains_key(raw_item) {
+                    handle_change(ChangeEvent::ItemRemoved(ItemRemovedEvent {
+                        project,
+                        item: Item::new(*raw_item),
+                    }));
+                }
+                let Some(new) = new_item_datas.get(raw_item) else {
+                    continue;
+                };
+                let item = Item::new(*raw_item);
+                if new.position != old.position || new.track != old.track {
+                    handle_change(ChangeEvent::ItemMoved(ItemMovedEvent {
+                        item,
+                        old_position: old.position,
+                        new_position: new.position,
+                    }));
+                }
+                if new.length != old.length {
+                    handle_change(ChangeEvent::ItemResized(ItemResizedEvent {
+                        item,
+                        old_length: old.length,
+                        new_length: new.length,
+                    }));
+                }
+                if new.is_selected != old.is_selected {
+                    handle_change(ChangeEvent::ItemSelectedChanged(ItemSelectedChangedEvent {
+                        item,
+                        new_value: new.is_selected,
+                    }));
+                }
+                if new.active_take != old.active_take {
+                    handle_change(ChangeEvent::TakeSwitched(TakeSwitchedEvent {
+                        item,
+                        new_take: new.active_take.map(Take::new),
+                    }));
+                } else if new.active_take_name != old.active_take_name {
+                    if let Some(raw_take) = new.active_take {
+                        handle_change(ChangeEvent::TakeRenamed(TakeRenamedEvent {
+                            take: Take::new(raw_take),
+                        }));
+                    }
+                }
+            }
+            for raw_item in new_item_datas.keys() {
+                if !old_data.item_datas.contains_key(raw_item) {
+                    handle_change(ChangeEvent::ItemAdded(ItemAddedEvent {
+                        item: Item::new(*raw_item),
+                    }));
+                }
+            }
+        }
+        project_data.item_set_data = Some(ItemSetData {
+            project_state_change_count,
+            item_datas: new_item_datas,
+        });
+    }
+
+    fn poll_for_envelope_changes(
+        &self,
+        project: Project,
+        project_data: &mut ProjectData,
+        handle_change: &mut impl FnMut(ChangeEvent),
+    ) {
+        // Reading points is only necessary if something in the project has changed at all.
+        let project_state_change_count = project.state_change_count();
+        if let Some(data) = &project_data.envelope_set_data {
+            if data.project_state_change_count == project_state_change_count {
+                return;
+            }
+        }
+        let touched_tracks = self.tracks_touched_since_envelope_poll.take();
+        let envelopes: Vec<_> = project
+            .master_track()
+            .into_iter()
+            .chain(project.tracks())
+            .flat_map(|t| t.envelopes().collect::<Vec<_>>())
+            .collect();
+        let mut new_signatures = HashMap::with_capacity(envelopes.len());
+        for envelope in envelopes {
+            let point_count = envelope.point_count();
+            let old_signature = project_data
+                .envelope_set_data
+                .as_ref()
+                .and_then(|data| data.signatures.get(&envelope.raw()).copied());
+            // Edits which don't change the number of points are only detected on tracks reported
+            // by control surface callbacks, hashing all points of all envelopes would be too
+            // expensive. If such an edit is missed, it's detected as soon as the track is touched.
+            let signature = match old_signature {
+                Some(old)
+                    if old.point_count == point_count
+                        && !touched_tracks.contains(&envelope.track().raw_unchecked()) =>
+                {
+                    old
+                }
+                _ => EnvelopeSignature::from_envelope(&envelope, point_count),
+            };
+            new_signatures.insert(envelope.raw(), signature);
+            let changed = matches!(old_signature, Some(old) if old != signature);
+            if changed {
+                handle_change(ChangeEvent::EnvelopeChanged(EnvelopeChangedEvent {
+                    envelope,
+                }));
+            }
+        }
+        project_data.envelope_set_data = Some(EnvelopeSetData {
+            project_state_change_count,
+            signatures: new_signatures,
+        });
+    }
+
+    fn poll_for_tempo_map_changes(
+        &self,
+        project: Project,
+        project_data: &mut ProjectData,
+        handle_change: &mut impl FnMut(ChangeEvent),
+    ) {
+        // Reading all markers is only necessary if something in the project has changed at all.
+        let project_state_change_count = project.state_change_count();
+        if let Some(data) = &project_data.tempo_map_data {
+            if data.project_state_change_count == project_state_change_count {
+                return;
+            }
+        }
+        let markers: Vec<_> = project.tempo_map().markers().collect();
+        let changed = match &project_data.tempo_map_data {
+            // First time, nothing to compare with
+            None => false,
+            Some(data) => data.markers != markers,
+        };
+        project_data.tempo_map_data = Some(TempoMapData {
+            project_state_change_count,
+            markers,
+        });
+        if changed {
+            handle_change(ChangeEvent::TempoMapChanged(TempoMapChangedEvent {
+                project,
+            }));
         }
     }
 
@@ -221,6 +713,7 @@ impl ChangeDetectionMiddleware {
         // TODO-low This executes a bunch of REAPER functions right on start. Maybe do more lazily
         // on activate?  But before activate we can do almost nothing because
         // execute_on_main_thread doesn't work.
+        self.capture_open_projects();
         self.react_to_track_list_change(Reaper::get().current_project(), handle_change);
     }
 
@@ -229,6 +722,12 @@ impl ChangeDetectionMiddleware {
         event: &ControlSurfaceEvent,
         mut handle_change: impl FnMut(ChangeEvent),
     ) -> bool {
+        self.capture_open_projects();
+        if let Some(track) = touched_track(event) {
+            self.tracks_touched_since_envelope_poll
+                .borrow_mut()
+                .insert(track);
+        }
         use ControlSurfaceEvent::*;
         match event {
             SetTrackListChange => self.set_track_list_change(handle_change),
@@ -663,7 +1162,13 @@ impl ChangeDetectionMiddleware {
             // TODO-low What's the difference to SetSurfaceSelected?
             OnTrackSelection(_) |
             ExtReset(_) |
-            ExtSetLastTouchedFx(_) => return false
+            ExtSetLastTouchedFx(_) |
+            ExtSetMetronome(_) |
+            ExtSetAutoRecArm(_) |
+            ExtSetRecMode(_) |
+            ExtSetLastTouchedTrack(_) |
+            ExtSetMixerScroll(_) |
+            ExtMidiDeviceRemap(_) => return false
         };
         true
     }
@@ -846,6 +1351,21 @@ impl ChangeDetectionMiddleware {
         };
         if let Some(fx) = fx_chain.fx_by_index(args.fx_index) {
             let parameter = fx.parameter_by_index(args.param_index);
+            if self.fx_param_settle_interval.get().is_some() {
+                let key = TrackFxKey {
+                    is_input_fx,
+                    fx_index: args.fx_index,
+                    param_index: args.param_index,
+                };
+                self.pending_fx_param_settles.borrow_mut().insert(
+                    (args.track, key),
+                    PendingFxParamSettle {
+                        parameter: parameter.clone(),
+                        value: args.param_value,
+                        last_change: Instant::now(),
+                    },
+                );
+            }
             handle_change(ChangeEvent::FxParameterValueChanged(
                 FxParameterValueChangedEvent {
                     touched: {
@@ -863,7 +1383,9 @@ impl ChangeDetectionMiddleware {
         }
     }
 
-    fn set_track_list_change(&self, handle_change: impl FnMut(ChangeEvent)) {
+    fn set_track_list_change(&self, mut handle_change: impl FnMut(ChangeEvent)) {
+        // REAPER notifies about a changed track list after loading a project
+        self.process_pending_project_loads(&mut handle_change);
         // TODO-low Not multi-project compatible!
         let new_active_project = Reaper::get().current_project();
         self.num_track_set_changes_left_to_be_propagated
@@ -875,6 +1397,16 @@ impl ChangeDetectionMiddleware {
         &self,
         new_active_project: Project,
         mut handle_change: impl FnMut(ChangeEvent),
+    ) {
+        self.detect_project_switch(new_active_project, &mut handle_change);
+        self.remove_invalid_rea_projects(&mut handle_change);
+        self.detect_track_set_changes(handle_change);
+    }
+
+    fn detect_project_switch(
+        &self,
+        new_active_project: Project,
+        handle_change: &mut impl FnMut(ChangeEvent),
     ) {
         if new_active_project != self.last_active_project.get() {
             let old = self.last_active_project.replace(new_active_project);
@@ -883,8 +1415,36 @@ impl ChangeDetectionMiddleware {
                 new_project: new_active_project,
             }));
         }
-        self.remove_invalid_rea_projects(&mut handle_change);
-        self.detect_track_set_changes(handle_change);
+    }
+
+    /// Makes sure that projects which were already open when change detection started are not
+    /// reported as opened later on.
+    fn capture_open_projects(&self) {
+        if self.open_projects_captured.replace(true) {
+            return;
+        }
+        let mut project_datas = self.project_datas.borrow_mut();
+        for project in Reaper::get().projects() {
+            project_datas.entry(project.raw()).or_default();
+        }
+    }
+
+    fn process_pending_project_loads(&self, handle_change: &mut impl FnMut(ChangeEvent)) {
+        let loaded_projects = std::mem::take(&mut *self.pending_project_loads.borrow_mut());
+        for project in loaded_projects {
+            if !project.is_available() {
+                continue;
+            }
+            // The project might have been loaded into an existing tab, so it can be known already
+            self.project_datas
+                .borrow_mut()
+                .entry(project.raw())
+                .or_default();
+            handle_change(ChangeEvent::ProjectOpened(ProjectOpenedEvent { project }));
+            if project == Reaper::get().current_project() {
+                self.detect_project_switch(project, handle_change);
+            }
+        }
     }
 
     fn remove_invalid_rea_projects(&self, handle_change: &mut impl FnMut(ChangeEvent)) {
@@ -903,9 +1463,12 @@ impl ChangeDetectionMiddleware {
         });
     }
 
-    fn detect_track_set_changes(&self, handle_change: impl FnMut(ChangeEvent)) {
+    fn detect_track_set_changes(&self, mut handle_change: impl FnMut(ChangeEvent)) {
         let project = Reaper::get().current_project();
         let mut project_datas = self.project_datas.borrow_mut();
+        if !project_datas.contains_key(&project.raw()) {
+            handle_change(ChangeEvent::ProjectOpened(ProjectOpenedEvent { project }));
+        }
         let project_data = project_datas.entry(project.raw()).or_default();
         let track_datas = &mut project_data.track_datas;
         let old_track_count = track_datas.len() as u32;
@@ -1247,6 +1810,7 @@ impl ChangeDetectionMiddleware {
 #[derive(Clone, Debug)]
 pub enum ChangeEvent {
     ProjectSwitched(ProjectSwitchedEvent),
+    ProjectOpened(ProjectOpenedEvent),
     TrackVolumeChanged(TrackVolumeChangedEvent),
     TrackPanChanged(TrackPanChangedEvent),
     TrackRouteVolumeChanged(TrackRouteVolumeChangedEvent),
@@ -1274,6 +1838,7 @@ pub enum ChangeEvent {
     FxFocused(FxFocusedEvent),
     FxReordered(FxReorderedEvent),
     FxParameterValueChanged(FxParameterValueChangedEvent),
+    FxParameterValueSettled(FxParameterValueSettledEvent),
     FxPresetChanged(FxPresetChangedEvent),
     MasterTempoChanged(MasterTempoChangedEvent),
     MasterPlayRateChanged(MasterPlayRateChangedEvent),
@@ -1282,6 +1847,20 @@ pub enum ChangeEvent {
     RepeatStateChanged(RepeatStateChangedEvent),
     ProjectClosed(ProjectClosedEvent),
     BookmarksChanged(BookmarksChangedEvent),
+    TempoMapChanged(TempoMapChangedEvent),
+    ProjectSaved(ProjectSavedEvent),
+    TimeSelectionChanged(TimeSelectionChangedEvent),
+    LoopRangeChanged(LoopRangeChangedEvent),
+    MetronomeChanged(MetronomeChangedEvent),
+    MidiDevicesChanged(MidiDevicesChangedEvent),
+    ItemAdded(ItemAddedEvent),
+    ItemRemoved(ItemRemovedEvent),
+    ItemMoved(ItemMovedEvent),
+    ItemResized(ItemResizedEvent),
+    ItemSelectedChanged(ItemSelectedChangedEvent),
+    EnvelopeChanged(EnvelopeChangedEvent),
+    TakeSwitched(TakeSwitchedEvent),
+    TakeRenamed(TakeRenamedEvent),
 }
 
 impl ChangeEvent {
@@ -1299,6 +1878,7 @@ impl ChangeEvent {
     pub fn is_still_valid(&self) -> bool {
         match self {
             ChangeEvent::ProjectSwitched(evt) => evt.new_project.is_available(),
+            ChangeEvent::ProjectOpened(evt) => evt.project.is_available(),
             ChangeEvent::TrackVolumeChanged(evt) => evt.track.is_available(),
             ChangeEvent::TrackPanChanged(evt) => evt.track.is_available(),
             ChangeEvent::TrackRouteVolumeChanged(evt) => evt.route.is_available(),
@@ -1328,6 +1908,7 @@ impl ChangeEvent {
             }
             ChangeEvent::FxReordered(evt) => evt.track.is_available(),
             ChangeEvent::FxParameterValueChanged(evt) => evt.parameter.is_available(),
+            ChangeEvent::FxParameterValueSettled(evt) => evt.parameter.is_available(),
             ChangeEvent::FxPresetChanged(evt) => evt.fx.is_available(),
             ChangeEvent::MasterTempoChanged(evt) => evt.project.is_available(),
             ChangeEvent::MasterPlayRateChanged(evt) => evt.project.is_available(),
@@ -1336,6 +1917,20 @@ impl ChangeEvent {
             ChangeEvent::RepeatStateChanged(evt) => evt.project.is_available(),
             ChangeEvent::ProjectClosed(_) => true,
             ChangeEvent::BookmarksChanged(evt) => evt.project.is_available(),
+            ChangeEvent::TempoMapChanged(evt) => evt.project.is_available(),
+            ChangeEvent::ProjectSaved(evt) => evt.project.is_available(),
+            ChangeEvent::TimeSelectionChanged(evt) => evt.project.is_available(),
+            ChangeEvent::LoopRangeChanged(evt) => evt.project.is_available(),
+            ChangeEvent::MetronomeChanged(_) => true,
+            ChangeEvent::MidiDevicesChanged(_) => true,
+            ChangeEvent::ItemAdded(evt) => evt.item.is_available(),
+            ChangeEvent::ItemRemoved(_) => true,
+            ChangeEvent::ItemMoved(evt) => evt.item.is_available(),
+            ChangeEvent::ItemSelectedChanged(evt) => evt.item.is_available(),
+            ChangeEvent::EnvelopeChanged(evt) => evt.envelope.track().is_available(),
+            ChangeEvent::ItemResized(evt) => evt.item.is_available(),
+            ChangeEvent::TakeSwitched(evt) => evt.item.is_available(),
+            ChangeEvent::TakeRenamed(evt) => evt.take.is_available(),
         }
     }
 }
@@ -1346,6 +1941,15 @@ pub struct ProjectSwitchedEvent {
     pub new_project: Project,
 }
 
+/// A project has been opened or created in a new project tab.
+///
+/// Opening a project in an existing tab is not reported because REAPER reuses the project in this
+/// case and doesn't notify us about the loading.
+#[derive(Clone, Debug)]
+pub struct ProjectOpenedEvent {
+    pub project: Project,
+}
+
 #[derive(Clone, Debug)]
 pub struct TrackVolumeChangedEvent {
     pub touched: bool,
@@ -1531,6 +2135,16 @@ pub struct FxParameterValueChangedEvent {
     pub new_value: ReaperNormalizedFxParamValue,
 }
 
+/// An FX parameter value hasn't changed anymore for the configured settle interval.
+///
+/// Only emitted if enabled via
+/// [`ChangeDetectionMiddleware::set_fx_parameter_settle_interval()`].
+#[derive(Clone, Debug)]
+pub struct FxParameterValueSettledEvent {
+    pub parameter: FxParameter,
+    pub value: ReaperNormalizedFxParamValue,
+}
+
 #[derive(Clone, Debug)]
 pub struct FxPresetChangedEvent {
     pub fx: Fx,
@@ -1581,6 +2195,121 @@ pub struct BookmarksChangedEvent {
     pub project: Project,
 }
 
+/// Tempo/time signature markers have been added, removed or changed.
+///
+/// This is detected by polling, so it's reported with a slight delay.
+#[derive(Clone, Debug)]
+pub struct TempoMapChangedEvent {
+    pub project: Project,
+}
+
+/// The current project has been saved.
+///
+/// This is detected by polling the dirty state. Saving a project which is not dirty is not
+/// detected. If "Maximum undo memory" is 0 in REAPER's preferences, projects never become dirty,
+/// so this is never emitted.
+#[derive(Clone, Debug)]
+pub struct ProjectSavedEvent {
+    pub project: Project,
+}
+
+/// The time selection of the current project has been changed.
+///
+/// This is detected by polling, so it's reported with a slight delay. `None` means there's no time
+/// selection anymore.
+#[derive(Clone, Debug)]
+pub struct TimeSelectionChangedEvent {
+    pub project: Project,
+    pub new_value: Option<TimeRange>,
+}
+
+/// The loop range of the current project has been changed.
+///
+/// This is detected by polling, so it's reported with a slight delay.
+#[derive(Clone, Debug)]
+pub struct LoopRangeChangedEvent {
+    pub project: Project,
+    pub new_value: Option<TimeRange>,
+}
+
+/// The metronome or pre-roll settings have been changed.
+///
+/// This is detected by polling, so it's reported with a slight delay.
+#[derive(Clone, Debug)]
+pub struct MetronomeChangedEvent {
+    pub new_value: MetronomeState,
+}
+
+/// MIDI devices have been connected or disconnected.
+///
+/// This is detected by polling about once per second.
+#[derive(Clone, Debug)]
+pub struct MidiDevicesChangedEvent {
+    pub connected_inputs: Vec<MidiInputDevice>,
+    pub disconnected_inputs: Vec<MidiInputDevice>,
+    pub connected_outputs: Vec<MidiOutputDevice>,
+    pub disconnected_outputs: Vec<MidiOutputDevice>,
+}
+
+/// An item has been added to the current project.
+///
+/// Item and take changes are detected by polling whenever the project state changes, so they are
+/// reported with a slight delay.
+#[derive(Clone, Debug)]
+pub struct ItemAddedEvent {
+    pub item: Item,
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemRemovedEvent {
+    pub project: Project,
+    /// Not valid anymore, just for identification.
+    pub item: Item,
+}
+
+/// An item has been moved in time or to another track.
+#[derive(Clone, Debug)]
+pub struct ItemMovedEvent {
+    pub item: Item,
+    pub old_position: PositionInSeconds,
+    pub new_position: PositionInSeconds,
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemResizedEvent {
+    pub item: Item,
+    pub old_length: DurationInSeconds,
+    pub new_length: DurationInSeconds,
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemSelectedChangedEvent {
+    pub item: Item,
+    pub new_value: bool,
+}
+
+/// Points of a track envelope have been added, removed or edited.
+///
+/// This is detected by polling whenever the project state changes, so it's reported with a slight
+/// delay.
+#[derive(Clone, Debug)]
+pub struct EnvelopeChangedEvent {
+    pub envelope: Envelope,
+}
+
+/// Another take of an item has been activated.
+#[derive(Clone, Debug)]
+pub struct TakeSwitchedEvent {
+    pub item: Item,
+    pub new_take: Option<Take>,
+}
+
+/// The active take of an item has been renamed (which is what REAPER displays as item name).
+#[derive(Clone, Debug)]
+pub struct TakeRenamedEvent {
+    pub take: Take,
+}
+
 unsafe fn get_track_visibility(
     reaper: &reaper_medium::Reaper,
     track: MediaTrack,
@@ -1598,3 +2327,65 @@ unsafe fn get_boolean_track_prop(
 ) -> bool {
     reaper.get_media_track_info_value(track, key) != 0.0
 }
+
+/// Returns the track which the given control surface event is about, if any.
+fn touched_track(event: &ControlSurfaceEvent) -> Option<MediaTrack> {
+    use ControlSurfaceEvent::*;
+    let track = match event {
+        SetSurfaceVolume(args) => args.track,
+        SetSurfacePan(args) => args.track,
+        SetSurfaceMute(args) => args.track,
+        SetSurfaceSelected(args) => args.track,
+        SetSurfaceSolo(args) => args.track,
+        SetSurfaceRecArm(args) => args.track,
+        OnTrackSelection(args) => args.track,
+        ExtSetInputMonitor(args) => args.track,
+        ExtSetFxParam(args) | ExtSetFxParamRecFx(args) => args.track,
+        ExtSetSendVolume(args) => args.track,
+        ExtSetSendPan(args) => args.track,
+        ExtSetRecvVolume(args) => args.track,
+        ExtSetRecvPan(args) => args.track,
+        ExtSetPanExt(args) => args.track,
+        ExtSetLastTouchedTrack(args) => args.track?,
+        _ => return None,
+    };
+    Some(track)
+}
+
+/// Returns the IDs of newly connected and newly disconnected devices.
+fn diff_connection_states(old: &[bool], new: &[bool]) -> (Vec<u8>, Vec<u8>) {
+    let mut connected = Vec::new();
+    let mut disconnected = Vec::new();
+    // Devices beyond the end of a list count as disconnected
+    for i in 0..old.len().max(new.len()) {
+        let was_connected = old.get(i).copied().unwrap_or(false);
+        let is_connected = new.get(i).copied().unwrap_or(false);
+        if is_connected && !was_connected {
+            connected.push(i as u8);
+        } else if !is_connected && was_connected {
+            disconnected.push(i as u8);
+        }
+    }
+    (connected, disconnected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connection_state_diff() {
+        assert_eq!(
+            diff_connection_states(&[true, false], &[false, true, true]),
+            (vec![1, 2], vec![0])
+        );
+    }
+
+    #[test]
+    fn connection_state_diff_with_shrinking_device_list() {
+        assert_eq!(
+            diff_connection_states(&[true, false, true, true], &[true]),
+            (vec![], vec![2, 3])
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{Reaper, ReaperResult};
+
+/// A persistent key-value store for extension settings.
+///
+/// Values are stored as persistent extended state in `reaper-extstate.ini`, using the namespace as
+/// section. They survive REAPER restarts and are shared with all other extensions and scripts
+/// which use the same namespace.
+///
+/// With feature `serde` enabled, arbitrary serializable values can be stored as well. They are
+/// encoded as JSON.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ConfigStore {
+    namespace: String,
+}
+
+impl ConfigStore {
+    pub(crate) fn new(namespace: String) -> ConfigStore {
+        ConfigStore { namespace }
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        Reaper::get()
+            .medium_reaper()
+            .has_ext_state(self.namespace.as_str(), key)
+    }
+
+    /// Returns the raw string value stored under the given key.
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        Reaper::get()
+            .medium_reaper()
+            .get_ext_state(self.namespace.as_str(), key, |value| {
+                value.map(|v| v.to_str().to_owned())
+            })
+    }
+
+    /// Stores the given raw string value under the given key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is empty or the value contains line breaks (which can't be
+    /// persisted).
+    pub fn set_string(&self, key: &str, value: &str) -> ReaperResult<()> {
+        if key.is_empty() {
+            return Err("config key must not be empty".into());
+        }
+        if value.contains(['\r', '\n']) {
+            return Err("config value must not contain line breaks".into());
+        }
+        Reaper::get()
+            .medium_reaper()
+            .set_ext_state(self.namespace.as_str(), key, value, true);
+        Ok(())
+    }
+
+    pub fn remove(&self, key: &str) {
+        Reaper::get()
+            .medium_reaper()
+            .delete_ext_state(self.namespace.as_str(), key, true);
+    }
+
+    /// Returns the value stored under the given key, deserialized from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored value can't be deserialized into the requested type.
+    #[cfg(feature = "serde")]
+    pub fn get<T: serde::de::DeserializeOwned>(&self, key: &str) -> ReaperResult<Option<T>> {
+        let Some(json) = self.get_string(key) else {
+            return Ok(None);
+        };
+        let value = serde_json::from_str(&json).map_err(|_| "couldn't deserialize config value")?;
+        Ok(Some(value))
+    }
+
+    /// Stores the given value under the given key, serialized as JSON.
+    #[cfg(feature = "serde")]
+    pub fn set<T: serde::Serialize>(&self, key: &str, value: &T) -> ReaperResult<()> {
+        let json = serde_json::to_string(value).map_err(|_| "couldn't serialize config value")?;
+        self.set_string(key, &json)
+    }
+}
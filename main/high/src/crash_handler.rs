@@ -1,6 +1,6 @@
 use crate::Reaper;
 use backtrace::Backtrace;
-use reaper_low::Swell;
+use reaper_low::{PanicReport, Swell};
 use std::ffi::CString;
 use std::fmt::{Display, Formatter};
 use std::os::raw::c_char;
@@ -44,6 +44,9 @@ pub struct PluginInfo {
 pub struct CrashInfo<'a> {
     pub plugin_info: &'a PluginInfo,
     pub panic_info: &'a PanicInfo<'a>,
+    /// Additional information if the crash has been reported via
+    /// [`reaper_low::set_panic_handler()`].
+    pub panic_report: Option<&'a PanicReport<'a>>,
     pub backtrace: Option<&'a Backtrace>,
     pub console_enabled: bool,
     pub sentry_enabled: bool,
@@ -60,6 +63,17 @@ impl CrashHandler {
     ///
     /// This must be called from the panic hook.
     pub fn handle_crash(&self, panic_info: &PanicInfo) {
+        self.handle_crash_internal(panic_info, None);
+    }
+
+    /// Like [`handle_crash()`](Self::handle_crash) but takes a panic report.
+    ///
+    /// This must be called from the panic handler (see [`reaper_low::set_panic_handler()`]).
+    pub fn handle_panic_report(&self, panic_report: &PanicReport) {
+        self.handle_crash_internal(panic_report.panic_info, Some(panic_report));
+    }
+
+    fn handle_crash_internal(&self, panic_info: &PanicInfo, panic_report: Option<&PanicReport>) {
         let console_enabled = self.config.console_logging_enabled.load(Ordering::Relaxed);
         let sentry_enabled = self.config.sentry_enabled.load(Ordering::Relaxed);
         if !console_enabled && !sentry_enabled {
@@ -70,6 +84,7 @@ impl CrashHandler {
             let crash_info = CrashInfo {
                 plugin_info: &self.config.plugin_info,
                 panic_info,
+                panic_report,
                 backtrace: None,
                 console_enabled: false,
                 sentry_enabled: false,
@@ -102,6 +117,7 @@ impl CrashHandler {
         let crash_info = CrashInfo {
             plugin_info: &self.config.plugin_info,
             panic_info,
+            panic_report,
             backtrace: Some(&backtrace),
             console_enabled,
             sentry_enabled,
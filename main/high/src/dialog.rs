@@ -0,0 +1,94 @@
+use crate::{next_main_loop_cycle, Reaper, ReaperResult};
+use camino::{Utf8Path, Utf8PathBuf};
+use reaper_medium::{MessageBoxResult, MessageBoxType};
+
+/// Separator for the values of `GetUserInputs`, chosen because it can't be typed into the fields.
+const USER_INPUTS_SEPARATOR: char = '\n';
+
+/// Async variants of REAPER's modal dialogs.
+///
+/// The returned futures must be polled in the main thread, typically by spawning them via
+/// [`FutureSupport`](crate::FutureSupport). They open the dialog in the next main loop cycle
+/// instead of immediately, so it's safe to use them from within REAPER callbacks (e.g. action
+/// invocations or control surface events). The dialogs themselves are still modal.
+impl Reaper {
+    /// Shows a message box and resolves with the button pressed by the user.
+    pub async fn prompt_message_box(
+        &self,
+        title: &str,
+        message: &str,
+        r#type: MessageBoxType,
+    ) -> MessageBoxResult {
+        next_main_loop_cycle().await;
+        self.medium_reaper()
+            .show_message_box(message, title, r#type)
+    }
+
+    /// Prompts the user for one value per caption and resolves with the entered values.
+    ///
+    /// Resolves with `None` if the user canceled the dialog.
+    ///
+    /// Values are exchanged with REAPER using a newline as separator, so they may contain commas.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no captions or more than 16, if the number of initial values
+    /// doesn't match the number of captions, if a caption contains a comma or if a caption or
+    /// value contains a newline.
+    pub async fn prompt_user_inputs(
+        &self,
+        title: &str,
+        captions: &[&str],
+        initial_values: &[&str],
+    ) -> ReaperResult<Option<Vec<String>>> {
+        if captions.is_empty() || captions.len() > 16 {
+            return Err("number of captions must be between 1 and 16".into());
+        }
+        if initial_values.len() != captions.len() {
+            return Err("number of initial values must match number of captions".into());
+        }
+        if captions.iter().any(|s| s.contains(',')) {
+            return Err("captions must not contain commas".into());
+        }
+        if captions
+            .iter()
+            .chain(initial_values)
+            .any(|s| s.contains(USER_INPUTS_SEPARATOR))
+        {
+            return Err("captions and values must not contain newlines".into());
+        }
+        // The captions themselves are always separated by commas, the separator only applies to
+        // the values
+        let captions_csv = format!("{},separator={USER_INPUTS_SEPARATOR}", captions.join(","));
+        let initial_csv = initial_values.join(&USER_INPUTS_SEPARATOR.to_string());
+        next_main_loop_cycle().await;
+        let csv = self.medium_reaper().get_user_inputs(
+            title,
+            captions.len() as u32,
+            captions_csv.as_str(),
+            initial_csv.as_str(),
+            4096,
+        );
+        let values = csv.map(|csv| {
+            csv.to_str()
+                .split(USER_INPUTS_SEPARATOR)
+                .map(String::from)
+                .collect()
+        });
+        Ok(values)
+    }
+
+    /// Opens a file picker for choosing an existing file.
+    ///
+    /// Resolves with `None` if the user canceled the dialog.
+    pub async fn prompt_file_for_read(
+        &self,
+        title: &str,
+        initial_path: &Utf8Path,
+        default_extension: &str,
+    ) -> Option<Utf8PathBuf> {
+        next_main_loop_cycle().await;
+        self.medium_reaper()
+            .get_user_file_name_for_read(initial_path, title, default_extension)
+    }
+}
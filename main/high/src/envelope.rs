@@ -0,0 +1,124 @@
+use crate::error::ReaperResult;
+use crate::{Project, Reaper, Track};
+use reaper_medium::{
+    EnvelopePoint, Hz, PositionInSeconds, ProjectInfoAttributeKey, ReaperString, TrackEnvelope,
+};
+
+/// An envelope of a track or an FX parameter.
+///
+/// Point values are in the envelope's native scaling (e.g. fader scaling for volume envelopes).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Envelope {
+    raw: TrackEnvelope,
+    track: Track,
+}
+
+impl Envelope {
+    pub fn new(raw: TrackEnvelope, track: Track) -> Envelope {
+        Envelope { raw, track }
+    }
+
+    pub fn raw(&self) -> TrackEnvelope {
+        self.raw
+    }
+
+    pub fn track(&self) -> &Track {
+        &self.track
+    }
+
+    pub fn project(&self) -> Project {
+        self.track.project()
+    }
+
+    pub fn name(&self) -> ReaperResult<ReaperString> {
+        let name = unsafe { Reaper::get().medium_reaper().get_envelope_name(self.raw)? };
+        Ok(name)
+    }
+
+    pub fn point_count(&self) -> u32 {
+        unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .count_envelope_points(self.raw)
+        }
+    }
+
+    pub fn points(&self) -> impl ExactSizeIterator<Item = EnvelopePoint> + '_ {
+        (0..self.point_count()).map(move |i| {
+            self.point_by_index(i)
+                .expect("envelope point index out of range")
+        })
+    }
+
+    pub fn point_by_index(&self, index: u32) -> Option<EnvelopePoint> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_envelope_point(self.raw, index)
+                .ok()
+        }
+    }
+
+    /// Inserts the given point (creating an undo point).
+    pub fn insert_point(&self, point: EnvelopePoint) -> ReaperResult<()> {
+        self.project().undoable("Insert envelope point", || {
+            unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .insert_envelope_point(self.raw, point)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Deletes all points with `start <= time < end` (creating an undo point).
+    pub fn delete_points_in_range(
+        &self,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    ) -> ReaperResult<()> {
+        self.project().undoable("Delete envelope points", || {
+            unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .delete_envelope_point_range(self.raw, start, end)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the value of the envelope at the given position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is a take envelope (whose positions are relative to the take) or
+    /// if the sample rate can't be determined.
+    pub fn value_at(&self, position: PositionInSeconds) -> ReaperResult<f64> {
+        let reaper = Reaper::get().medium_reaper();
+        if unsafe { reaper.get_envelope_info_get_take(self.raw) }.is_some() {
+            return Err("take envelopes are not supported".into());
+        }
+        let result =
+            unsafe { reaper.envelope_evaluate(self.raw, position, self.sample_rate()?, 1) };
+        Ok(result.value)
+    }
+
+    /// Returns the sample rate of the project if it overrides the one of the audio device,
+    /// otherwise the one of the audio device.
+    fn sample_rate(&self) -> ReaperResult<Hz> {
+        let reaper = Reaper::get();
+        let project = self.project().context();
+        let medium_reaper = reaper.medium_reaper();
+        if medium_reaper
+            .get_set_project_info_get(project, ProjectInfoAttributeKey::ProjectSampleRateUse)
+            != 0.0
+        {
+            let project_sample_rate = medium_reaper
+                .get_set_project_info_get(project, ProjectInfoAttributeKey::ProjectSampleRate);
+            if let Ok(sample_rate) = Hz::try_from(project_sample_rate) {
+                return Ok(sample_rate);
+            }
+        }
+        Ok(reaper.audio_device_sample_rate()?)
+    }
+}
@@ -9,6 +9,7 @@ use crate::fx_chain::FxChain;
 use crate::fx_parameter::FxParameter;
 use crate::guid::Guid;
 use crate::{ChunkRegion, FxChainContext, Project, Reaper, Track};
+use camino::Utf8Path;
 use either::Either;
 use reaper_medium::{
     FxPresetRef, FxShowInstruction, Hwnd, ParamId, ReaperFunctionError, ReaperString,
@@ -90,7 +91,6 @@ impl Fx {
         if self.load_if_necessary_or_err().is_err() {
             return Default::default();
         }
-        let buffer_size = 256;
         match self.chain.context() {
             FxChainContext::Take(_) => todo!(),
             _ => {
@@ -98,7 +98,7 @@ impl Fx {
                 unsafe {
                     Reaper::get()
                         .medium_reaper()
-                        .track_fx_get_fx_name(track.raw_unchecked(), location, buffer_size)
+                        .track_fx_get_fx_name_auto(track.raw_unchecked(), location)
                         .expect("Couldn't get track FX name")
                 }
             }
@@ -736,6 +736,41 @@ impl Fx {
         }
     }
 
+    /// Returns the index of the currently selected preset.
+    ///
+    /// Returns `None` if no preset or a factory preset is selected.
+    pub fn preset_index(&self) -> Option<u32> {
+        self.preset_index_and_count().index
+    }
+
+    /// Returns the names of the user presets which REAPER stored for this FX, in the order in
+    /// which they appear in REAPER's preset file.
+    ///
+    /// Factory presets are not included because REAPER doesn't expose their names without
+    /// activating them. Take FX are not supported yet and result in an error.
+    pub fn presets(&self) -> ReaperResult<Vec<String>> {
+        self.load_if_necessary_or_err()?;
+        let file_name = match self.chain.context() {
+            FxChainContext::Take(_) => {
+                return Err("listing presets of take FX not supported".into())
+            }
+            _ => {
+                let (track, location) = self.track_and_location();
+                unsafe {
+                    Reaper::get()
+                        .medium_reaper()
+                        .track_fx_get_user_preset_filename(track.raw_unchecked(), location)?
+                }
+            }
+        };
+        let content = match std::fs::read(&file_name) {
+            Ok(c) => c,
+            // No preset file means no user presets
+            Err(_) => return Ok(vec![]),
+        };
+        Ok(parse_user_preset_names(&String::from_utf8_lossy(&content)))
+    }
+
     pub fn activate_preset(&self, preset: FxPresetRef) -> ReaperResult<()> {
         self.load_if_necessary_or_err()?;
         match self.chain.context() {
@@ -775,6 +810,11 @@ impl Fx {
         }
     }
 
+    /// Loads the given preset file (e.g. a `.vstpreset` file for VST3 plug-ins).
+    pub fn load_preset_file(&self, path: &Utf8Path) -> ReaperResult<()> {
+        self.activate_preset_by_name(path.as_str())
+    }
+
     pub fn preset_is_dirty(&self) -> bool {
         if self.load_if_necessary_or_err().is_err() {
             return false;
@@ -804,7 +844,7 @@ impl Fx {
                 unsafe {
                     Reaper::get()
                         .medium_reaper()
-                        .track_fx_get_preset(track.raw_unchecked(), location, 2000)
+                        .track_fx_get_preset_auto(track.raw_unchecked(), location)
                         .name
                 }
             }
@@ -812,6 +852,22 @@ impl Fx {
     }
 }
 
+/// Extracts the preset names from the content of a REAPER user preset file (INI format).
+fn parse_user_preset_names(content: &str) -> Vec<String> {
+    let mut names = vec![];
+    let mut in_preset_section = false;
+    for line in content.lines().map(str::trim) {
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            in_preset_section = section.starts_with("Preset");
+        } else if in_preset_section {
+            if let Some(name) = line.strip_prefix("Name=") {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
 fn get_track_and_location(chain: &FxChain, index: u32) -> Option<(Track, TrackFxLocation)> {
     match chain.context() {
         FxChainContext::Monitoring => {
@@ -1082,4 +1138,14 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn user_preset_names() {
+        // Given
+        let content = "[General]\nNbPresets=2\n\n[Preset0]\nData=00\nName=Soft\nLen=1\n\n[Preset1]\nName=Hard Pad\n";
+        // When
+        let result = parse_user_preset_names(content);
+        // Then
+        assert_eq!(result, vec!["Soft".to_string(), "Hard Pad".to_string()]);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::fx::Fx;
 
 use crate::error::ReaperResult;
-use crate::{FxChain, FxChainContext, Reaper};
+use crate::{Envelope, FxChain, FxChainContext, Reaper};
 use reaper_medium::{
     GetParamExResult, GetParameterStepSizesResult, ReaperFunctionError,
     ReaperNormalizedFxParamValue, ReaperString,
@@ -163,6 +163,27 @@ impl FxParameter {
         }
     }
 
+    /// Returns the envelope of this parameter if it exists.
+    ///
+    /// Returns `None` for take FX.
+    pub fn envelope(&self) -> Option<Envelope> {
+        match self.chain().context() {
+            FxChainContext::Take(_) => None,
+            _ => {
+                let (track, location) = self.fx().track_and_location();
+                let raw = unsafe {
+                    Reaper::get().medium_reaper().get_fx_envelope(
+                        track.raw_unchecked(),
+                        location,
+                        self.index,
+                        false,
+                    )?
+                };
+                Some(Envelope::new(raw, track))
+            }
+        }
+    }
+
     pub fn fx(&self) -> &Fx {
         &self.fx
     }
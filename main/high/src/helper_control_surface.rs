@@ -31,4 +31,8 @@ impl ControlSurface for HelperControlSurface {
             }
         }
     }
+
+    fn set_track_list_change(&self) {
+        Reaper::get().track_cache().invalidate();
+    }
 }
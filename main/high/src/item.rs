@@ -1,8 +1,9 @@
+use crate::error::ReaperResult;
 use crate::{Project, Reaper, Take, Track};
 use reaper_medium::{
     BeatAttachMode, DurationInSeconds, FadeCurvature, FadeShape, ItemAttributeKey, ItemGroupId,
     MediaItem, NativeColorValue, PositionInSeconds, ProjectContext, ReaperFunctionError,
-    ReaperVolumeValue, RgbColor, UiRefreshBehavior,
+    ReaperVolumeValue, RgbColor, TimeRange, UiRefreshBehavior,
 };
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -95,6 +96,15 @@ impl Item {
         }
     }
 
+    pub fn is_selected(&self) -> bool {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_media_item_info_value(self.raw, ItemAttributeKey::Selected)
+                != 0.0
+        }
+    }
+
     pub fn set_selected(&self, selected: bool) {
         unsafe {
             Reaper::get()
@@ -420,4 +430,76 @@ impl Item {
             )
         }
     }
+
+    /// Returns the range on the timeline covered by this item.
+    pub fn bounds(&self) -> TimeRange {
+        TimeRange::from_start_and_length(self.position(), self.length())
+    }
+
+    /// Moves and resizes this item so that it covers the given range (creating an undo point).
+    pub fn edit_bounds(&self, bounds: TimeRange) -> ReaperResult<()> {
+        self.undoable("Move/resize item", || {
+            self.set_position(bounds.start(), UiRefreshBehavior::NoRefresh)?;
+            self.set_length(bounds.length(), UiRefreshBehavior::NoRefresh)?;
+            self.update_in_project();
+            Ok(())
+        })
+    }
+
+    /// Sets the fade lengths of this item (creating an undo point).
+    pub fn edit_fades(
+        &self,
+        fade_in_length: DurationInSeconds,
+        fade_out_length: DurationInSeconds,
+    ) {
+        self.undoable("Set item fades", || {
+            self.set_fade_in_length(fade_in_length);
+            self.set_fade_out_length(fade_out_length);
+            self.update_in_project();
+        })
+    }
+
+    /// Splits this item at the given position (creating an undo point).
+    ///
+    /// This item becomes the left part. Returns the right part.
+    pub fn split_at(&self, position: PositionInSeconds) -> ReaperResult<Item> {
+        self.undoable("Split item", || {
+            let right = unsafe {
+                Reaper::get()
+                    .medium_reaper
+                    .split_media_item(self.raw, position)?
+            };
+            Reaper::get().medium_reaper.update_arrange();
+            Ok(Item::new(right))
+        })
+    }
+
+    /// Moves this item to the given track (creating an undo point).
+    pub fn move_to_track(&self, track: &Track) -> ReaperResult<()> {
+        let raw_track = track.raw()?;
+        self.undoable("Move item to track", || {
+            unsafe {
+                Reaper::get()
+                    .medium_reaper
+                    .move_media_item_to_track(self.raw, raw_track)?;
+            }
+            Reaper::get().medium_reaper.update_arrange();
+            Ok(())
+        })
+    }
+
+    /// Redraws this item in the arrange view.
+    pub fn update_in_project(&self) {
+        unsafe {
+            Reaper::get().medium_reaper.update_item_in_project(self.raw);
+        }
+    }
+
+    /// Executes the given operation within an undo block of this item's project.
+    pub(crate) fn undoable<R>(&self, label: &'static str, operation: impl FnOnce() -> R) -> R {
+        match self.project() {
+            None => operation(),
+            Some(project) => project.undoable(label, operation),
+        }
+    }
 }
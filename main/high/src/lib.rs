@@ -40,6 +40,9 @@ pub use main_task_middleware::*;
 mod main_future_middleware;
 pub use main_future_middleware::*;
 
+mod real_time_channel;
+pub use real_time_channel::*;
+
 mod reaper_simple;
 pub use reaper_simple::*;
 
@@ -49,6 +52,8 @@ pub use project::*;
 mod track;
 pub use track::*;
 
+mod track_cache;
+
 mod take;
 pub use take::*;
 
@@ -94,12 +99,39 @@ pub use width::*;
 mod tempo;
 pub use tempo::*;
 
+mod tempo_map;
+pub use tempo_map::*;
+
+mod transport;
+pub use transport::*;
+
+mod metronome;
+pub use metronome::*;
+
+mod routing_graph;
+pub use routing_graph::*;
+
+mod reascript;
+pub use reascript::*;
+
 mod chunk;
 pub use chunk::*;
 
 mod item;
 pub use item::*;
 
+mod envelope;
+pub use envelope::*;
+
+mod midi_sequence;
+pub use midi_sequence::*;
+
+mod render;
+pub use render::*;
+
+mod preview;
+pub use preview::*;
+
 mod source;
 pub use source::*;
 
@@ -116,12 +148,22 @@ mod normalized_value;
 mod middleware_control_surface;
 pub use middleware_control_surface::*;
 
+mod middleware_chain;
+pub use middleware_chain::*;
+
+mod registration;
+
 mod change_detection_middleware;
 pub use change_detection_middleware::*;
 
 mod bookmark;
 pub use bookmark::*;
 
+mod config_store;
+pub use config_store::*;
+
+mod dialog;
+
 mod accelerator;
 pub use accelerator::*;
 
@@ -4,9 +4,10 @@ use std::error::Error;
 //  with run_loop_executor and try to implement this stuff without Arc and Mutex (the waker stuff
 //  gets hairy though)!
 use crate::mutex_util::lock_ignoring_poisoning;
-use crate::run_loop_executor::FutureResult;
+use crate::run_loop_executor::{run_prioritized, FutureResult, TaskPriority};
 use crossbeam_channel::{Receiver, Sender};
 use futures::future::LocalBoxFuture;
+use std::time::Duration;
 use {
     futures::{
         future::FutureExt,
@@ -20,16 +21,20 @@ use {
 };
 
 /// Task executor that receives tasks off of a channel and runs them.
+///
+/// Schedules tasks in the same way as [`crate::run_loop_executor::RunLoopExecutor`].
 #[derive(Clone, Debug)]
 pub struct RunLoopExecutor {
-    ready_queue: Receiver<Arc<Task>>,
+    /// One queue per priority, highest priority first.
+    ready_queues: [Receiver<Arc<Task>>; TaskPriority::COUNT],
     bulk_size: usize,
+    time_budget: Option<Duration>,
 }
 
 /// `Spawner` spawns new futures onto the task channel.
 #[derive(Clone, Debug)]
 pub struct Spawner {
-    task_sender: Sender<Arc<Task>>,
+    task_senders: [Sender<Arc<Task>>; TaskPriority::COUNT],
 }
 
 /// A future that can reschedule itself to be polled by an `Executor`.
@@ -47,25 +52,43 @@ struct Task {
     task_sender: Sender<Arc<Task>>,
 }
 
+/// Creates a spawner and an executor which runs at most `bulk_size` tasks per cycle.
 pub fn new_spawner_and_executor(bulk_size: usize) -> (Spawner, RunLoopExecutor) {
-    let (task_sender, ready_queue) = crossbeam_channel::unbounded();
+    let channels: [_; TaskPriority::COUNT] =
+        std::array::from_fn(|_| crossbeam_channel::unbounded());
     (
-        Spawner { task_sender },
+        Spawner {
+            task_senders: channels.clone().map(|(sender, _)| sender),
+        },
         RunLoopExecutor {
-            ready_queue,
+            ready_queues: channels.map(|(_, receiver)| receiver),
             bulk_size,
+            time_budget: None,
         },
     )
 }
 
 impl Spawner {
+    /// Spawns a future with [`TaskPriority::Normal`].
     pub fn spawn(&self, future: impl Future<Output = Result<(), Box<dyn Error>>> + 'static) {
+        self.spawn_with_priority(TaskPriority::Normal, future);
+    }
+
+    /// Spawns a future with the given priority.
+    ///
+    /// The priority sticks with the task, so it also applies when the future is woken up again.
+    pub fn spawn_with_priority(
+        &self,
+        priority: TaskPriority,
+        future: impl Future<Output = Result<(), Box<dyn Error>>> + 'static,
+    ) {
         let future = future.boxed_local();
+        let task_sender = self.task_senders[priority.index()].clone();
         let task = Arc::new(Task {
             future: Mutex::new(Some(future)),
-            task_sender: self.task_sender.clone(),
+            task_sender: task_sender.clone(),
         });
-        self.task_sender.send(task).expect("too many tasks queued");
+        task_sender.send(task).expect("too many tasks queued");
     }
 }
 
@@ -85,30 +108,47 @@ unsafe impl Send for Task {}
 unsafe impl Sync for Task {}
 
 impl RunLoopExecutor {
+    /// Sets the maximum time which should be spent running tasks in one cycle.
+    ///
+    /// See [`crate::run_loop_executor::RunLoopExecutor::set_time_budget()`].
+    pub fn set_time_budget(&mut self, time_budget: Option<Duration>) {
+        self.time_budget = time_budget;
+    }
+
     /// Returns number of discarded tasks.
     pub fn discard_tasks(&self) -> usize {
-        self.ready_queue.try_iter().count()
+        self.ready_queues
+            .iter()
+            .map(|queue| queue.try_iter().count())
+            .sum()
     }
 
     pub fn run(&self) {
-        for task in self.ready_queue.try_iter().take(self.bulk_size) {
-            // Take the future, and if it has not yet completed (is still Some),
-            // poll it in an attempt to complete it.
-            let mut future_slot = lock_ignoring_poisoning(&task.future);
-            if let Some(mut future) = future_slot.take() {
-                // Create a `LocalWaker` from the task itself
-                let waker = waker_ref(&task);
-                let context = &mut Context::from_waker(&waker);
-                // `BoxFuture<T>` is a type alias for
-                // `Pin<Box<dyn Future<Output = T> + Send + 'static>>`.
-                // We can get a `Pin<&mut dyn Future + Send + 'static>`
-                // from it by calling the `Pin::as_mut` method.
-                if future.as_mut().poll(context).is_pending() {
-                    // We're not done processing the future, so put it
-                    // back in its task to be run again in the future.
-                    *future_slot = Some(future);
-                }
-            }
+        run_prioritized(
+            &self.ready_queues,
+            self.bulk_size,
+            self.time_budget,
+            poll_task,
+        );
+    }
+}
+
+fn poll_task(task: &Arc<Task>) {
+    // Take the future, and if it has not yet completed (is still Some),
+    // poll it in an attempt to complete it.
+    let mut future_slot = lock_ignoring_poisoning(&task.future);
+    if let Some(mut future) = future_slot.take() {
+        // Create a `LocalWaker` from the task itself
+        let waker = waker_ref(task);
+        let context = &mut Context::from_waker(&waker);
+        // `BoxFuture<T>` is a type alias for
+        // `Pin<Box<dyn Future<Output = T> + Send + 'static>>`.
+        // We can get a `Pin<&mut dyn Future + Send + 'static>`
+        // from it by calling the `Pin::as_mut` method.
+        if future.as_mut().poll(context).is_pending() {
+            // We're not done processing the future, so put it
+            // back in its task to be run again in the future.
+            *future_slot = Some(future);
         }
     }
 }
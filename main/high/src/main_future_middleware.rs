@@ -1,7 +1,14 @@
+use crate::mutex_util::lock_ignoring_poisoning;
+use crate::run_loop_executor::TaskPriority;
 use crate::{local_run_loop_executor, run_loop_executor, Reaper};
 use std::error::Error;
+use std::sync::Mutex;
+use std::task::{Poll, Waker};
 use tracing::warn;
 
+/// Wakers of futures which wait for the next main loop cycle (see [`next_main_loop_cycle()`]).
+static NEXT_MAIN_LOOP_CYCLE_WAKERS: Mutex<Vec<Waker>> = Mutex::new(Vec::new());
+
 pub struct FutureSupport {
     main_thread_future_spawner: run_loop_executor::Spawner,
     local_main_thread_future_spawner: local_run_loop_executor::Spawner,
@@ -27,6 +34,19 @@ impl FutureSupport {
         spawner.spawn(future);
     }
 
+    /// Spawns a future for execution in main thread with the given priority.
+    ///
+    /// Low-priority futures are deferred to later main loop cycles if the executor's time budget
+    /// is exhausted.
+    pub fn spawn_in_main_thread_with_priority(
+        &self,
+        priority: TaskPriority,
+        future: impl std::future::Future<Output = Result<(), Box<dyn Error>>> + 'static + Send,
+    ) {
+        let spawner = &self.main_thread_future_spawner;
+        spawner.spawn_with_priority(priority, future);
+    }
+
     /// Spawns a future for execution in main thread.
     ///
     /// Panics if not in main thread. The difference to `spawn_in_main_thread()` is that `Send` is
@@ -39,6 +59,17 @@ impl FutureSupport {
         let spawner = &self.local_main_thread_future_spawner;
         spawner.spawn(future);
     }
+
+    /// Like `spawn_in_main_thread_from_main_thread()` but with the given priority.
+    pub fn spawn_in_main_thread_from_main_thread_with_priority(
+        &self,
+        priority: TaskPriority,
+        future: impl std::future::Future<Output = Result<(), Box<dyn Error>>> + 'static,
+    ) {
+        Reaper::get().require_main_thread();
+        let spawner = &self.local_main_thread_future_spawner;
+        spawner.spawn_with_priority(priority, future);
+    }
 }
 
 #[derive(Debug)]
@@ -63,6 +94,7 @@ impl FutureMiddleware {
     }
 
     fn discard_tasks(&self) {
+        lock_ignoring_poisoning(&NEXT_MAIN_LOOP_CYCLE_WAKERS).clear();
         let shared_task_count = self.main_thread_executor.discard_tasks();
         let local_task_count = self.local_main_thread_executor.discard_tasks();
         let total_task_count = shared_task_count + local_task_count;
@@ -75,7 +107,58 @@ impl FutureMiddleware {
     }
 
     pub fn run(&mut self) {
+        // Wake up futures which have been waiting since the previous cycle. Futures which start
+        // waiting during this cycle will be woken up in the next one.
+        let wakers = std::mem::take(&mut *lock_ignoring_poisoning(&NEXT_MAIN_LOOP_CYCLE_WAKERS));
+        for waker in wakers {
+            waker.wake();
+        }
         self.main_thread_executor.run();
         self.local_main_thread_executor.run();
     }
 }
+
+/// Resolves in the next main loop cycle, giving the current one the chance to finish.
+pub(crate) async fn next_main_loop_cycle() {
+    let mut yielded = false;
+    futures::future::poll_fn(|cx| {
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        lock_ignoring_poisoning(&NEXT_MAIN_LOOP_CYCLE_WAKERS).push(cx.waker().clone());
+        Poll::Pending
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn next_main_loop_cycle_resolves_in_next_cycle() {
+        let (_, executor) = run_loop_executor::new_spawner_and_executor(10);
+        let (local_spawner, local_executor) = local_run_loop_executor::new_spawner_and_executor(10);
+        let mut middleware = FutureMiddleware::new(executor, local_executor);
+        let cycles_passed = Arc::new(AtomicU32::new(0));
+        local_spawner.spawn({
+            let cycles_passed = cycles_passed.clone();
+            async move {
+                next_main_loop_cycle().await;
+                cycles_passed.fetch_add(1, Ordering::SeqCst);
+                next_main_loop_cycle().await;
+                cycles_passed.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        });
+        middleware.run();
+        assert_eq!(cycles_passed.load(Ordering::SeqCst), 0);
+        middleware.run();
+        assert_eq!(cycles_passed.load(Ordering::SeqCst), 1);
+        middleware.run();
+        assert_eq!(cycles_passed.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{Reaper, ReaperResult};
+use reaper_medium::{CommandId, ProjectContext, SectionId};
+
+/// "Options: Toggle metronome"
+const TOGGLE_METRONOME: u32 = 40364;
+/// "Pre-roll: Toggle pre-roll on play"
+const TOGGLE_PRE_ROLL_ON_PLAY: u32 = 41818;
+/// "Pre-roll: Toggle pre-roll on record"
+const TOGGLE_PRE_ROLL_ON_RECORD: u32 = 41819;
+/// Config variable containing the pre-roll/count-in length in measures.
+const PRE_ROLL_MEASURES_VAR: &str = "prerollmeas";
+
+/// Gives access to REAPER's metronome and pre-roll settings.
+///
+/// These are global settings, so they affect all projects.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Metronome(());
+
+/// Snapshot of the metronome settings.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MetronomeState {
+    pub is_enabled: bool,
+    pub pre_roll_on_play: bool,
+    pub pre_roll_on_record: bool,
+    pub pre_roll_measure_count: f64,
+}
+
+impl Metronome {
+    pub(crate) fn new() -> Metronome {
+        Metronome(())
+    }
+
+    pub fn state(&self) -> MetronomeState {
+        MetronomeState {
+            is_enabled: self.is_enabled(),
+            pre_roll_on_play: self.pre_roll_on_play(),
+            pre_roll_on_record: self.pre_roll_on_record(),
+            pre_roll_measure_count: self.pre_roll_measure_count().unwrap_or_default(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        toggle_state(TOGGLE_METRONOME)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        set_toggle_state(TOGGLE_METRONOME, enabled);
+    }
+
+    pub fn toggle(&self) {
+        invoke(TOGGLE_METRONOME);
+    }
+
+    /// Returns whether playback starts with a pre-roll.
+    pub fn pre_roll_on_play(&self) -> bool {
+        toggle_state(TOGGLE_PRE_ROLL_ON_PLAY)
+    }
+
+    pub fn set_pre_roll_on_play(&self, enabled: bool) {
+        set_toggle_state(TOGGLE_PRE_ROLL_ON_PLAY, enabled);
+    }
+
+    /// Returns whether recording starts with a pre-roll.
+    pub fn pre_roll_on_record(&self) -> bool {
+        toggle_state(TOGGLE_PRE_ROLL_ON_RECORD)
+    }
+
+    pub fn set_pre_roll_on_record(&self, enabled: bool) {
+        set_toggle_state(TOGGLE_PRE_ROLL_ON_RECORD, enabled);
+    }
+
+    /// Returns the length of pre-roll and count-in in measures.
+    ///
+    /// REAPER uses the same length for both.
+    pub fn pre_roll_measure_count(&self) -> ReaperResult<f64> {
+        let value: &mut f64 = Reaper::get().get_preference_ref(PRE_ROLL_MEASURES_VAR)?;
+        Ok(*value)
+    }
+
+    /// Sets the length of pre-roll and count-in in measures.
+    pub fn set_pre_roll_measure_count(&self, count: f64) -> ReaperResult<()> {
+        if !count.is_finite() || count < 0.0 {
+            return Err("measure count must be a non-negative number".into());
+        }
+        let value: &mut f64 = Reaper::get().get_preference_ref(PRE_ROLL_MEASURES_VAR)?;
+        *value = count;
+        Ok(())
+    }
+}
+
+fn toggle_state(command_id: u32) -> bool {
+    Reaper::get()
+        .medium_reaper()
+        .get_toggle_command_state_ex(SectionId::new(0), CommandId::new(command_id))
+        .unwrap_or(false)
+}
+
+fn set_toggle_state(command_id: u32, on: bool) {
+    if toggle_state(command_id) != on {
+        invoke(command_id);
+    }
+}
+
+fn invoke(command_id: u32) {
+    Reaper::get().medium_reaper().main_on_command_ex(
+        CommandId::new(command_id),
+        0,
+        ProjectContext::CurrentProject,
+    );
+}
@@ -0,0 +1,304 @@
+use crate::{ControlSurfaceEvent, ControlSurfaceMiddleware, Reaper};
+use derivative::Derivative;
+use reaper_medium::{ExtSupportsExtendedTouchArgs, GetTouchStateArgs, IsKeyDownArgs, ReaperStr};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// A middleware which combines multiple middlewares and interleaves their work.
+///
+/// - Events are passed to all middlewares in the order in which they were added.
+/// - [`run()`](ControlSurfaceMiddleware::run) is sliced: If a time budget is set and exhausted,
+///   the remaining middlewares are skipped in this main loop cycle. The next cycle continues with
+///   the first skipped middleware, so no middleware can be starved by the others.
+/// - Middlewares can opt out of being called while REAPER is loading or saving a project, which
+///   is useful for expensive change detection (see [`ChainedMiddlewareOptions`]).
+///
+/// The time spent in each middleware is recorded and can be queried via [`stats()`](Self::stats).
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct MiddlewareChain {
+    entries: Vec<ChainEntry>,
+    run_budget: Option<Duration>,
+    /// Index of the middleware which should run first in the next cycle.
+    next_run_index: usize,
+    /// Whether a project is being loaded or saved, queried at most once per batch of events.
+    ///
+    /// A batch ends with the next main loop cycle or with a track list change (which REAPER
+    /// reports after loading a project).
+    in_load_or_save: Cell<Option<bool>>,
+    #[derivative(Debug = "ignore")]
+    clock: Box<dyn Fn() -> Instant>,
+}
+
+impl Default for MiddlewareChain {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            run_budget: None,
+            next_run_index: 0,
+            in_load_or_save: Default::default(),
+            clock: Box::new(Instant::now),
+        }
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+struct ChainEntry {
+    name: &'static str,
+    #[derivative(Debug = "ignore")]
+    middleware: Box<dyn ControlSurfaceMiddleware>,
+    options: ChainedMiddlewareOptions,
+    stats: Cell<MiddlewareStats>,
+}
+
+/// Options for a middleware added to a [`MiddlewareChain`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ChainedMiddlewareOptions {
+    /// If `true`, neither `run()` nor `handle_event()` is called while REAPER is loading or saving
+    /// a project.
+    ///
+    /// REAPER fires lots of control surface callbacks while loading a project, and polling
+    /// project state in this phase is wasted work. Middlewares which do change detection usually
+    /// get in sync again anyway because REAPER notifies about a track list change afterwards.
+    pub skip_during_project_load_or_save: bool,
+}
+
+/// Time accounting for one middleware in a [`MiddlewareChain`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MiddlewareStats {
+    /// How often `run()` has been called.
+    pub run_count: u64,
+    /// How often `run()` has not been called because the time budget was exhausted or a project
+    /// was being loaded or saved.
+    pub skipped_run_count: u64,
+    /// Total time spent in `run()`.
+    pub run_time: Duration,
+    /// How many events have been passed to `handle_event()`.
+    pub event_count: u64,
+    /// Total time spent in `handle_event()`.
+    pub event_time: Duration,
+}
+
+impl MiddlewareChain {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a middleware to the end of the chain.
+    ///
+    /// The name is just used for identifying the middleware in [`stats()`](Self::stats).
+    pub fn add(
+        &mut self,
+        name: &'static str,
+        middleware: impl ControlSurfaceMiddleware + 'static,
+        options: ChainedMiddlewareOptions,
+    ) {
+        self.entries.push(ChainEntry {
+            name,
+            middleware: Box::new(middleware),
+            options,
+            stats: Default::default(),
+        });
+    }
+
+    /// Sets the maximum time which should be spent in the `run()` methods of all middlewares per
+    /// main loop cycle.
+    ///
+    /// At least one middleware runs per cycle and a running middleware is not interrupted, so the
+    /// budget can be exceeded by the duration of one `run()` call. `None` (the default) means no
+    /// limit.
+    pub fn set_run_budget(&mut self, budget: Option<Duration>) {
+        self.run_budget = budget;
+    }
+
+    /// Returns the name and time accounting of each middleware.
+    pub fn stats(&self) -> impl Iterator<Item = (&'static str, MiddlewareStats)> + '_ {
+        self.entries.iter().map(|e| (e.name, e.stats.get()))
+    }
+
+    /// Resets the time accounting of all middlewares.
+    pub fn reset_stats(&self) {
+        for e in &self.entries {
+            e.stats.take();
+        }
+    }
+
+    fn project_is_being_loaded_or_saved(&self) -> bool {
+        if let Some(in_load_or_save) = self.in_load_or_save.get() {
+            return in_load_or_save;
+        }
+        let any_entry_cares = self
+            .entries
+            .iter()
+            .any(|e| e.options.skip_during_project_load_or_save);
+        let in_load_or_save = any_entry_cares
+            && Reaper::get()
+                .currently_loading_or_saving_project()
+                .is_some();
+        self.in_load_or_save.set(Some(in_load_or_save));
+        in_load_or_save
+    }
+
+    fn run_budget_exhausted(&self, start: Instant) -> bool {
+        match self.run_budget {
+            None => false,
+            Some(budget) => self.now() - start >= budget,
+        }
+    }
+
+    fn now(&self) -> Instant {
+        (self.clock)()
+    }
+}
+
+impl ControlSurfaceMiddleware for MiddlewareChain {
+    fn run(&mut self) {
+        // New cycle, new batch
+        self.in_load_or_save.set(None);
+        let count = self.entries.len();
+        if count == 0 {
+            return;
+        }
+        let in_load_or_save = self.project_is_being_loaded_or_saved();
+        let start = self.now();
+        for i in 0..count {
+            let index = (self.next_run_index + i) % count;
+            if i > 0 && self.run_budget_exhausted(start) {
+                for j in i..count {
+                    let entry = &self.entries[(self.next_run_index + j) % count];
+                    update_stats(entry, |s| s.skipped_run_count += 1);
+                }
+                self.next_run_index = index;
+                return;
+            }
+            let entry = &mut self.entries[index];
+            if in_load_or_save && entry.options.skip_during_project_load_or_save {
+                update_stats(entry, |s| s.skipped_run_count += 1);
+                continue;
+            }
+            let run_start = (self.clock)();
+            entry.middleware.run();
+            let elapsed = (self.clock)() - run_start;
+            update_stats(entry, |s| {
+                s.run_count += 1;
+                s.run_time += elapsed;
+            });
+        }
+        // Rotate, so that the same middleware doesn't always get the fresh budget
+        self.next_run_index = (self.next_run_index + 1) % count;
+    }
+
+    fn handle_event(&self, event: ControlSurfaceEvent) -> bool {
+        if matches!(event, ControlSurfaceEvent::SetTrackListChange) {
+            // Project loading might have finished
+            self.in_load_or_save.set(None);
+        }
+        let in_load_or_save = self.project_is_being_loaded_or_saved();
+        let mut handled = false;
+        for entry in &self.entries {
+            if in_load_or_save && entry.options.skip_during_project_load_or_save {
+                continue;
+            }
+            let start = self.now();
+            handled |= entry.middleware.handle_event(event.clone());
+            let elapsed = self.now() - start;
+            update_stats(entry, |s| {
+                s.event_count += 1;
+                s.event_time += elapsed;
+            });
+        }
+        handled
+    }
+
+    fn get_type_string(&self) -> Option<&ReaperStr> {
+        self.entries
+            .iter()
+            .find_map(|e| e.middleware.get_type_string())
+    }
+
+    fn get_desc_string(&self) -> Option<&ReaperStr> {
+        self.entries
+            .iter()
+            .find_map(|e| e.middleware.get_desc_string())
+    }
+
+    fn get_config_string(&self) -> Option<&ReaperStr> {
+        self.entries
+            .iter()
+            .find_map(|e| e.middleware.get_config_string())
+    }
+
+    fn get_touch_state(&self, args: GetTouchStateArgs) -> bool {
+        self.entries
+            .iter()
+            .any(|e| e.middleware.get_touch_state(args))
+    }
+
+    fn is_key_down(&self, args: IsKeyDownArgs) -> bool {
+        self.entries.iter().any(|e| e.middleware.is_key_down(args))
+    }
+
+    fn ext_supports_extended_touch(&self, args: ExtSupportsExtendedTouchArgs) -> i32 {
+        self.entries
+            .iter()
+            .map(|e| e.middleware.ext_supports_extended_touch(args))
+            .find(|result| *result != 0)
+            .unwrap_or(0)
+    }
+}
+
+fn update_stats(entry: &ChainEntry, f: impl FnOnce(&mut MiddlewareStats)) {
+    let mut stats = entry.stats.get();
+    f(&mut stats);
+    entry.stats.set(stats);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    /// Advances the fake clock by the given duration when run.
+    struct Worker {
+        clock: Rc<Cell<Instant>>,
+        duration: Duration,
+    }
+
+    impl ControlSurfaceMiddleware for Worker {
+        fn run(&mut self) {
+            self.clock.set(self.clock.get() + self.duration);
+        }
+    }
+
+    #[test]
+    fn run_budget_prevents_starvation() {
+        let clock = Rc::new(Cell::new(Instant::now()));
+        let worker = |millis| Worker {
+            clock: clock.clone(),
+            duration: Duration::from_millis(millis),
+        };
+        let mut chain = MiddlewareChain::new();
+        chain.clock = Box::new({
+            let clock = clock.clone();
+            move || clock.get()
+        });
+        chain.add("a", worker(2), Default::default());
+        chain.add("b", worker(0), Default::default());
+        chain.add("c", worker(0), Default::default());
+        chain.set_run_budget(Some(Duration::from_millis(1)));
+        // "a" exhausts the budget, so "b" and "c" have to wait
+        chain.run();
+        // Continues with "b" and "c", which leave enough budget for "a"
+        chain.run();
+        let run_counts: Vec<_> = chain.stats().map(|(_, s)| s.run_count).collect();
+        assert_eq!(run_counts, vec![2, 1, 1]);
+        let skipped_counts: Vec<_> = chain.stats().map(|(_, s)| s.skipped_run_count).collect();
+        assert_eq!(skipped_counts, vec![0, 1, 1]);
+        let run_times: Vec<_> = chain.stats().map(|(_, s)| s.run_time).collect();
+        assert_eq!(
+            run_times,
+            vec![Duration::from_millis(4), Duration::ZERO, Duration::ZERO]
+        );
+    }
+}
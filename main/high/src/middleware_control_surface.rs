@@ -1,11 +1,12 @@
 use reaper_medium::{
-    ControlSurface, ExtResetArgs, ExtSetBpmAndPlayRateArgs, ExtSetFocusedFxArgs,
-    ExtSetFxChangeArgs, ExtSetFxEnabledArgs, ExtSetFxOpenArgs, ExtSetFxParamArgs,
-    ExtSetInputMonitorArgs, ExtSetLastTouchedFxArgs, ExtSetPanExArgs,
-    ExtSetProjectMarkerChangeArgs, ExtSetRecvPanArgs, ExtSetRecvVolumeArgs, ExtSetSendPanArgs,
-    ExtSetSendVolumeArgs, ExtSupportsExtendedTouchArgs, ExtTrackFxPresetChangedArgs,
-    GetTouchStateArgs, IsKeyDownArgs, OnTrackSelectionArgs, ReaperStr, SetAutoModeArgs,
-    SetPlayStateArgs, SetRepeatStateArgs, SetSurfaceMuteArgs, SetSurfacePanArgs,
+    ControlSurface, ExtMidiDeviceRemapArgs, ExtResetArgs, ExtSetAutoRecArmArgs,
+    ExtSetBpmAndPlayRateArgs, ExtSetFocusedFxArgs, ExtSetFxChangeArgs, ExtSetFxEnabledArgs,
+    ExtSetFxOpenArgs, ExtSetFxParamArgs, ExtSetInputMonitorArgs, ExtSetLastTouchedFxArgs,
+    ExtSetLastTouchedTrackArgs, ExtSetMetronomeArgs, ExtSetMixerScrollArgs, ExtSetPanExArgs,
+    ExtSetProjectMarkerChangeArgs, ExtSetRecModeArgs, ExtSetRecvPanArgs, ExtSetRecvVolumeArgs,
+    ExtSetSendPanArgs, ExtSetSendVolumeArgs, ExtSupportsExtendedTouchArgs,
+    ExtTrackFxPresetChangedArgs, GetTouchStateArgs, IsKeyDownArgs, OnTrackSelectionArgs, ReaperStr,
+    SetAutoModeArgs, SetPlayStateArgs, SetRepeatStateArgs, SetSurfaceMuteArgs, SetSurfacePanArgs,
     SetSurfaceRecArmArgs, SetSurfaceSelectedArgs, SetSurfaceSoloArgs, SetSurfaceVolumeArgs,
     SetTrackTitleArgs,
 };
@@ -263,6 +264,48 @@ impl<H: ControlSurfaceMiddleware + Debug> ControlSurface for MiddlewareControlSu
         )
     }
 
+    fn ext_set_metronome(&self, args: ExtSetMetronomeArgs) -> i32 {
+        to_int(
+            self.middleware
+                .handle_event(ControlSurfaceEvent::ExtSetMetronome(args)),
+        )
+    }
+
+    fn ext_set_auto_rec_arm(&self, args: ExtSetAutoRecArmArgs) -> i32 {
+        to_int(
+            self.middleware
+                .handle_event(ControlSurfaceEvent::ExtSetAutoRecArm(args)),
+        )
+    }
+
+    fn ext_set_rec_mode(&self, args: ExtSetRecModeArgs) -> i32 {
+        to_int(
+            self.middleware
+                .handle_event(ControlSurfaceEvent::ExtSetRecMode(args)),
+        )
+    }
+
+    fn ext_set_last_touched_track(&self, args: ExtSetLastTouchedTrackArgs) -> i32 {
+        to_int(
+            self.middleware
+                .handle_event(ControlSurfaceEvent::ExtSetLastTouchedTrack(args)),
+        )
+    }
+
+    fn ext_set_mixer_scroll(&self, args: ExtSetMixerScrollArgs) -> i32 {
+        to_int(
+            self.middleware
+                .handle_event(ControlSurfaceEvent::ExtSetMixerScroll(args)),
+        )
+    }
+
+    fn ext_midi_device_remap(&self, args: ExtMidiDeviceRemapArgs) -> i32 {
+        to_int(
+            self.middleware
+                .handle_event(ControlSurfaceEvent::ExtMidiDeviceRemap(args)),
+        )
+    }
+
     fn get_type_string(&self) -> Option<&ReaperStr> {
         self.middleware.get_type_string()
     }
@@ -321,6 +364,12 @@ pub enum ControlSurfaceEvent<'a> {
     ExtTrackFxPresetChanged(ExtTrackFxPresetChangedArgs),
     ExtReset(ExtResetArgs),
     ExtSetProjectMarkerChange(ExtSetProjectMarkerChangeArgs),
+    ExtSetMetronome(ExtSetMetronomeArgs),
+    ExtSetAutoRecArm(ExtSetAutoRecArmArgs),
+    ExtSetRecMode(ExtSetRecModeArgs),
+    ExtSetLastTouchedTrack(ExtSetLastTouchedTrackArgs),
+    ExtSetMixerScroll(ExtSetMixerScrollArgs),
+    ExtMidiDeviceRemap(ExtMidiDeviceRemapArgs),
 }
 
 impl<'a> ControlSurfaceEvent<'a> {
@@ -358,6 +407,12 @@ impl<'a> ControlSurfaceEvent<'a> {
             ExtTrackFxPresetChanged(e) => ExtTrackFxPresetChanged(e),
             ExtReset(e) => ExtReset(e),
             ExtSetProjectMarkerChange(e) => ExtSetProjectMarkerChange(e),
+            ExtSetMetronome(e) => ExtSetMetronome(e),
+            ExtSetAutoRecArm(e) => ExtSetAutoRecArm(e),
+            ExtSetRecMode(e) => ExtSetRecMode(e),
+            ExtSetLastTouchedTrack(e) => ExtSetLastTouchedTrack(e),
+            ExtSetMixerScroll(e) => ExtSetMixerScroll(e),
+            ExtMidiDeviceRemap(e) => ExtMidiDeviceRemap(e),
         }
     }
 }
@@ -22,7 +22,7 @@ impl MidiInputDevice {
     pub fn name(self) -> Option<CString> {
         Reaper::get()
             .medium_reaper()
-            .get_midi_input_name(self.id, 64)
+            .get_midi_input_name_auto(self.id)
             .name
     }
 
@@ -22,7 +22,7 @@ impl MidiOutputDevice {
     pub fn name(self) -> Option<CString> {
         Reaper::get()
             .medium_reaper()
-            .get_midi_output_name(self.id, 64)
+            .get_midi_output_name_auto(self.id)
             .name
     }
 
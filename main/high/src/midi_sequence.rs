@@ -0,0 +1,381 @@
+use crate::error::ReaperResult;
+use helgoboss_midi::{Channel, ControllerNumber, KeyNumber, U7};
+use std::collections::{HashMap, VecDeque};
+
+/// An editable in-memory representation of all MIDI events of a MIDI take.
+///
+/// All positions are absolute MIDI tick (PPQ) positions within the take's source. Use
+/// [`Take::midi_sequence()`](crate::Take::midi_sequence) to load a sequence and
+/// [`Take::set_midi_sequence()`](crate::Take::set_midi_sequence) to write it back.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MidiSequence {
+    pub notes: Vec<MidiNote>,
+    pub ccs: Vec<MidiCc>,
+    pub sysex: Vec<MidiSysEx>,
+    /// All other events (e.g. pitch bend, program change, text events).
+    pub other_events: Vec<MidiRawEvent>,
+}
+
+/// A note consisting of a note-on and a note-off event.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MidiNote {
+    pub position: i64,
+    pub end_position: i64,
+    pub channel: Channel,
+    pub key: KeyNumber,
+    pub velocity: U7,
+    pub off_velocity: U7,
+    pub is_selected: bool,
+    pub is_muted: bool,
+}
+
+/// A control change event.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MidiCc {
+    pub position: i64,
+    pub channel: Channel,
+    pub controller: ControllerNumber,
+    pub value: U7,
+    /// Shape of the curve towards the next CC event of the same lane.
+    pub shape: MidiCcShape,
+    pub is_selected: bool,
+    pub is_muted: bool,
+}
+
+/// Shape of the curve between a CC event and the next one.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum MidiCcShape {
+    #[default]
+    Square,
+    Linear,
+    SlowStartEnd,
+    FastStart,
+    FastEnd,
+    /// Bezier curve with a tension between -1.0 and 1.0.
+    Bezier(f32),
+}
+
+/// A system exclusive message.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MidiSysEx {
+    pub position: i64,
+    /// The complete message including the leading `0xF0` and trailing `0xF7` bytes.
+    pub data: Vec<u8>,
+    pub is_selected: bool,
+    pub is_muted: bool,
+}
+
+/// A MIDI event which is not modeled explicitly.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MidiRawEvent {
+    pub position: i64,
+    /// Flags as used in REAPER's packed event list (`&1` → selected, `&2` → muted).
+    pub flags: u8,
+    pub message: Vec<u8>,
+}
+
+const FLAG_SELECTED: u8 = 1;
+const FLAG_MUTED: u8 = 2;
+const CC_SHAPE_MASK: u8 = 0xf0;
+const CC_BEZIER_PREFIX: &[u8] = b"\xff\x0fCCBZ ";
+
+impl MidiSequence {
+    /// Parses REAPER's packed event list as returned by `MIDI_GetAllEvts`.
+    ///
+    /// Note-on events without matching note-off event (and vice versa) end up in
+    /// [`other_events`](#structfield.other_events).
+    pub fn from_packed_events(packed: &[u8]) -> ReaperResult<MidiSequence> {
+        let mut sequence = MidiSequence::default();
+        // Note-ons waiting for their note-off, keyed by channel and key number
+        let mut pending_notes: HashMap<(u8, u8), VecDeque<MidiNote>> = HashMap::new();
+        // Whether the last parsed event was a CC, so that subsequent bezier data can be applied
+        let mut last_event_was_cc = false;
+        let mut position: i64 = 0;
+        let mut rest = packed;
+        while !rest.is_empty() {
+            if rest.len() < 9 {
+                return Err("truncated MIDI event".into());
+            }
+            let offset = i32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]);
+            let flags = rest[4];
+            let len = u32::from_le_bytes([rest[5], rest[6], rest[7], rest[8]]) as usize;
+            let msg = rest
+                .get(9..9 + len)
+                .ok_or("MIDI event message exceeds buffer")?;
+            rest = &rest[9 + len..];
+            position += offset as i64;
+            let is_selected = flags & FLAG_SELECTED != 0;
+            let is_muted = flags & FLAG_MUTED != 0;
+            if let Some(bezier_data) = msg.strip_prefix(CC_BEZIER_PREFIX) {
+                if let (true, [_, t0, t1, t2, t3]) = (last_event_was_cc, bezier_data) {
+                    let tension = f32::from_le_bytes([*t0, *t1, *t2, *t3]);
+                    if let Some(cc) = sequence.ccs.last_mut() {
+                        cc.shape = MidiCcShape::Bezier(tension);
+                    }
+                    continue;
+                }
+            }
+            last_event_was_cc = false;
+            let status = msg.first().copied().unwrap_or(0);
+            match (status & 0xf0, msg) {
+                (0x90, [_, key, velocity]) if *velocity > 0 => {
+                    let note = MidiNote {
+                        position,
+                        end_position: position,
+                        channel: Channel::new(status & 0x0f),
+                        key: KeyNumber::new(key & 0x7f),
+                        velocity: U7::new(*velocity & 0x7f),
+                        off_velocity: U7::new(0),
+                        is_selected,
+                        is_muted,
+                    };
+                    pending_notes
+                        .entry((status & 0x0f, *key))
+                        .or_default()
+                        .push_back(note);
+                }
+                (0x80 | 0x90, [_, key, velocity]) => {
+                    let pending_note = pending_notes
+                        .get_mut(&(status & 0x0f, *key))
+                        .and_then(|notes| notes.pop_front());
+                    match pending_note {
+                        Some(mut note) => {
+                            note.end_position = position;
+                            if status & 0xf0 == 0x80 {
+                                note.off_velocity = U7::new(*velocity & 0x7f);
+                            }
+                            sequence.notes.push(note);
+                        }
+                        None => sequence.other_events.push(MidiRawEvent {
+                            position,
+                            flags,
+                            message: msg.to_vec(),
+                        }),
+                    }
+                }
+                (0xb0, [_, controller, value]) => {
+                    let shape = match (flags & CC_SHAPE_MASK) >> 4 {
+                        1 => MidiCcShape::Linear,
+                        2 => MidiCcShape::SlowStartEnd,
+                        3 => MidiCcShape::FastStart,
+                        4 => MidiCcShape::FastEnd,
+                        5 => MidiCcShape::Bezier(0.0),
+                        _ => MidiCcShape::Square,
+                    };
+                    sequence.ccs.push(MidiCc {
+                        position,
+                        channel: Channel::new(status & 0x0f),
+                        controller: ControllerNumber::new(controller & 0x7f),
+                        value: U7::new(value & 0x7f),
+                        shape,
+                        is_selected,
+                        is_muted,
+                    });
+                    last_event_was_cc = true;
+                }
+                _ if status == 0xf0 => sequence.sysex.push(MidiSysEx {
+                    position,
+                    data: msg.to_vec(),
+                    is_selected,
+                    is_muted,
+                }),
+                _ => sequence.other_events.push(MidiRawEvent {
+                    position,
+                    flags,
+                    message: msg.to_vec(),
+                }),
+            }
+        }
+        // Keep dangling note-ons as they are
+        let mut dangling_notes: Vec<_> = pending_notes.into_values().flatten().collect();
+        dangling_notes.sort_by_key(|n| n.position);
+        for note in dangling_notes {
+            sequence.other_events.push(MidiRawEvent {
+                position: note.position,
+                flags: note_flags(&note),
+                message: vec![
+                    0x90 | note.channel.get(),
+                    note.key.get(),
+                    note.velocity.get(),
+                ],
+            });
+        }
+        sequence.notes.sort_by_key(|n| n.position);
+        Ok(sequence)
+    }
+
+    /// Serializes this sequence into REAPER's packed event list as expected by
+    /// `MIDI_SetAllEvts`.
+    ///
+    /// Events are ordered by position. At the same position, note-offs come before all other
+    /// events, except for note-offs of zero-length notes, which come after all other events (so
+    /// after their own note-on as well).
+    pub fn to_packed_events(&self) -> Vec<u8> {
+        // (position, rank within position, flags, message)
+        let mut events: Vec<(i64, EventRank, u8, Vec<u8>)> = vec![];
+        for note in &self.notes {
+            let flags = note_flags(note);
+            let channel = note.channel.get();
+            let end_position = note.end_position.max(note.position);
+            let off_rank = if end_position == note.position {
+                EventRank::ZeroLengthNoteOff
+            } else {
+                EventRank::NoteOff
+            };
+            events.push((
+                note.position,
+                EventRank::Other,
+                flags,
+                vec![0x90 | channel, note.key.get(), note.velocity.get()],
+            ));
+            events.push((
+                end_position,
+                off_rank,
+                flags,
+                vec![0x80 | channel, note.key.get(), note.off_velocity.get()],
+            ));
+        }
+        for cc in &self.ccs {
+            let shape_bits = match cc.shape {
+                MidiCcShape::Square => 0,
+                MidiCcShape::Linear => 1,
+                MidiCcShape::SlowStartEnd => 2,
+                MidiCcShape::FastStart => 3,
+                MidiCcShape::FastEnd => 4,
+                MidiCcShape::Bezier(_) => 5,
+            };
+            let flags = (shape_bits << 4) | selected_muted_flags(cc.is_selected, cc.is_muted);
+            let mut message = vec![0xb0 | cc.channel.get(), cc.controller.get(), cc.value.get()];
+            if let MidiCcShape::Bezier(tension) = cc.shape {
+                // The bezier data follows the CC as separate event. We carry it within the same
+                // entry and split it up when serializing.
+                message.push(0);
+                message.extend_from_slice(&tension.to_le_bytes());
+            }
+            events.push((cc.position, EventRank::Other, flags, message));
+        }
+        for sysex in &self.sysex {
+            let flags = selected_muted_flags(sysex.is_selected, sysex.is_muted);
+            events.push((sysex.position, EventRank::Other, flags, sysex.data.clone()));
+        }
+        for event in &self.other_events {
+            events.push((
+                event.position,
+                EventRank::Other,
+                event.flags,
+                event.message.clone(),
+            ));
+        }
+        // Stable sort keeps the original order of events with the same position and rank, so
+        // zero-length notes which share position and key are still paired up in order
+        events.sort_by_key(|(position, rank, _, _)| (*position, *rank));
+        let mut packed = vec![];
+        let mut last_position = 0;
+        for (position, _, flags, message) in events {
+            let is_cc = message.first().map(|s| s & 0xf0) == Some(0xb0);
+            let (message, bezier_data) = if is_cc && message.len() > 3 {
+                (&message[..3], Some(&message[3..]))
+            } else {
+                (&message[..], None)
+            };
+            write_packed_event(
+                &mut packed,
+                (position - last_position) as i32,
+                flags,
+                message,
+            );
+            if let Some(data) = bezier_data {
+                let bezier_message = [CC_BEZIER_PREFIX, data].concat();
+                write_packed_event(&mut packed, 0, 0, &bezier_message);
+            }
+            last_position = position;
+        }
+        packed
+    }
+}
+
+/// Order of events at the same position when serializing.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+enum EventRank {
+    NoteOff,
+    Other,
+    ZeroLengthNoteOff,
+}
+
+fn note_flags(note: &MidiNote) -> u8 {
+    selected_muted_flags(note.is_selected, note.is_muted)
+}
+
+fn selected_muted_flags(is_selected: bool, is_muted: bool) -> u8 {
+    let mut flags = 0;
+    if is_selected {
+        flags |= FLAG_SELECTED;
+    }
+    if is_muted {
+        flags |= FLAG_MUTED;
+    }
+    flags
+}
+
+fn write_packed_event(packed: &mut Vec<u8>, offset: i32, flags: u8, message: &[u8]) {
+    packed.extend_from_slice(&offset.to_le_bytes());
+    packed.push(flags);
+    packed.extend_from_slice(&(message.len() as u32).to_le_bytes());
+    packed.extend_from_slice(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        // Given
+        let mut packed = vec![];
+        write_packed_event(&mut packed, 0, 1, &[0x90, 60, 100]);
+        write_packed_event(&mut packed, 0, 0x50, &[0xb1, 7, 64]);
+        write_packed_event(
+            &mut packed,
+            0,
+            0,
+            &[CC_BEZIER_PREFIX, &[0, 0, 0, 0, 0x3f]].concat(),
+        );
+        write_packed_event(&mut packed, 480, 1, &[0x80, 60, 0]);
+        write_packed_event(&mut packed, 0, 0, &[0xf0, 0x7e, 0xf7]);
+        write_packed_event(&mut packed, 960, 0, &[0xb0, 123, 0]);
+        // When
+        let sequence = MidiSequence::from_packed_events(&packed).unwrap();
+        // Then
+        assert_eq!(sequence.notes.len(), 1);
+        let note = sequence.notes[0];
+        assert_eq!((note.position, note.end_position), (0, 480));
+        assert!(note.is_selected);
+        assert_eq!(sequence.ccs.len(), 2);
+        assert_eq!(sequence.ccs[0].shape, MidiCcShape::Bezier(0.5));
+        assert_eq!(sequence.ccs[1].position, 1440);
+        assert_eq!(sequence.sysex.len(), 1);
+        assert!(sequence.other_events.is_empty());
+        assert_eq!(sequence.to_packed_events(), packed);
+    }
+
+    #[test]
+    fn zero_length_note_roundtrip() {
+        // Given
+        let mut packed = vec![];
+        write_packed_event(&mut packed, 0, 0, &[0x90, 60, 100]);
+        write_packed_event(&mut packed, 480, 0, &[0x80, 60, 0]);
+        write_packed_event(&mut packed, 0, 0, &[0x90, 60, 90]);
+        write_packed_event(&mut packed, 0, 0, &[0x80, 60, 0]);
+        // When
+        let sequence = MidiSequence::from_packed_events(&packed).unwrap();
+        // Then
+        let bounds: Vec<_> = sequence
+            .notes
+            .iter()
+            .map(|n| (n.position, n.end_position, n.velocity.get()))
+            .collect();
+        assert_eq!(bounds, [(0, 480, 100), (480, 480, 90)]);
+        assert!(sequence.other_events.is_empty());
+        assert_eq!(sequence.to_packed_events(), packed);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{next_main_loop_cycle, OwnedSource, Project, Reaper, ReaperResult, Track};
+use camino::Utf8Path;
+use reaper_low::raw;
+use reaper_medium::{
+    DurationInSeconds, FlexibleOwnedPcmSource, Handle, MeasureAlignment, MidiImportBehavior,
+    OwnedPreviewRegister, PositionInSeconds, PreviewOutput, ReaperMutex, ReaperMutexGuard,
+    ReaperVolumeValue,
+};
+use std::cell::Cell;
+use std::sync::Arc;
+
+/// Options for playing a media file via [`Reaper::play_media_file()`].
+#[derive(Clone, Debug)]
+pub struct PreviewOptions {
+    pub volume: ReaperVolumeValue,
+    pub looped: bool,
+    pub start_position: PositionInSeconds,
+    /// If set, the file is played through this track (including its FX) instead of directly
+    /// through the hardware outputs.
+    pub track: Option<Track>,
+}
+
+impl Default for PreviewOptions {
+    fn default() -> Self {
+        Self {
+            volume: ReaperVolumeValue::ZERO_DB,
+            looped: false,
+            start_position: PositionInSeconds::ZERO,
+            track: None,
+        }
+    }
+}
+
+impl Reaper {
+    /// Starts playing the given media file, e.g. for auditioning it.
+    ///
+    /// Playback stops when the returned handle is dropped.
+    pub fn play_media_file(
+        &self,
+        path: &Utf8Path,
+        options: PreviewOptions,
+    ) -> ReaperResult<PreviewHandle> {
+        let source = OwnedSource::from_file(path, MidiImportBehavior::UsePreference)?;
+        let length = source.length().ok();
+        let mut register = OwnedPreviewRegister::new();
+        register.set_src(Some(FlexibleOwnedPcmSource::Reaper(source.into_raw())));
+        register.set_volume(options.volume);
+        register.set_looped(options.looped);
+        register.set_cur_pos(options.start_position);
+        let project = match &options.track {
+            None => {
+                register.set_output(PreviewOutput::HardwareOutput {
+                    first_channel_index: 0,
+                    mono: false,
+                });
+                None
+            }
+            Some(track) => {
+                register.set_preview_track(Some(track.raw()?));
+                register.set_output(PreviewOutput::PreviewTrack);
+                Some(track.project())
+            }
+        };
+        let register = Arc::new(ReaperMutex::new(register));
+        let mut session = self.medium_session();
+        let handle = match project {
+            None => session.play_preview_ex(
+                register.clone(),
+                Default::default(),
+                MeasureAlignment::PlayImmediately,
+            )?,
+            Some(p) => session.play_track_preview_2_ex(
+                p.context(),
+                register.clone(),
+                Default::default(),
+                MeasureAlignment::PlayImmediately,
+            )?,
+        };
+        let preview_handle = PreviewHandle {
+            register,
+            handle,
+            project,
+            length,
+            stopped: Cell::new(false),
+        };
+        Ok(preview_handle)
+    }
+}
+
+/// Controls a media file which is being played via [`Reaper::play_media_file()`].
+///
+/// Must be used in the main thread. Dropping it stops playback.
+#[derive(Debug)]
+pub struct PreviewHandle {
+    register: Arc<ReaperMutex<OwnedPreviewRegister>>,
+    handle: Handle<raw::preview_register_t>,
+    /// Set if playing through a track.
+    project: Option<Project>,
+    length: Option<DurationInSeconds>,
+    stopped: Cell<bool>,
+}
+
+impl PreviewHandle {
+    /// Stops playback. Does nothing if already stopped.
+    pub fn stop(&self) -> ReaperResult<()> {
+        if self.stopped.replace(true) {
+            return Ok(());
+        }
+        let mut session = Reaper::get().medium_session();
+        match self.project {
+            None => session.stop_preview(self.handle)?,
+            Some(p) => session.stop_track_preview_2(p.context(), self.handle)?,
+        }
+        Ok(())
+    }
+
+    /// Returns whether the file is still playing.
+    ///
+    /// Returns `false` if playback has been stopped or has reached the end of a non-looped file.
+    pub fn is_playing(&self) -> bool {
+        if self.stopped.get() {
+            return false;
+        }
+        let Ok(register) = self.lock() else {
+            return false;
+        };
+        if register.is_looped() {
+            return true;
+        }
+        match self.length {
+            None => true,
+            Some(length) => register.cur_pos().get() < length.get(),
+        }
+    }
+
+    /// Resolves as soon as playback has stopped or reached the end of a non-looped file.
+    ///
+    /// Must be polled in the main thread. The playback state is checked once per main loop cycle,
+    /// so the future doesn't keep the main thread busy while waiting.
+    pub async fn finished(&self) {
+        while self.is_playing() {
+            next_main_loop_cycle().await;
+        }
+    }
+
+    /// Returns the length of the file if known.
+    pub fn length(&self) -> Option<DurationInSeconds> {
+        self.length
+    }
+
+    pub fn position(&self) -> ReaperResult<PositionInSeconds> {
+        Ok(self.lock()?.cur_pos())
+    }
+
+    pub fn seek(&self, position: PositionInSeconds) -> ReaperResult<()> {
+        self.lock()?.set_cur_pos(position);
+        Ok(())
+    }
+
+    pub fn volume(&self) -> ReaperResult<ReaperVolumeValue> {
+        Ok(self.lock()?.volume())
+    }
+
+    pub fn set_volume(&self, volume: ReaperVolumeValue) -> ReaperResult<()> {
+        self.lock()?.set_volume(volume);
+        Ok(())
+    }
+
+    pub fn is_looped(&self) -> ReaperResult<bool> {
+        Ok(self.lock()?.is_looped())
+    }
+
+    pub fn set_looped(&self, looped: bool) -> ReaperResult<()> {
+        self.lock()?.set_looped(looped);
+        Ok(())
+    }
+
+    fn lock(&self) -> ReaperResult<ReaperMutexGuard<'_, OwnedPreviewRegister>> {
+        self.register
+            .lock()
+            .map_err(|_| "couldn't lock preview register".into())
+    }
+}
+
+impl Drop for PreviewHandle {
+    fn drop(&mut self) {
+        // If stopping fails, the preview has already stopped by itself (e.g. because the track has
+        // been removed). The register is released in any case.
+        let _ = self.stop();
+    }
+}
@@ -1,23 +1,34 @@
 use crate::guid::Guid;
 use crate::{
-    BasicBookmarkInfo, BookmarkType, IndexBasedBookmark, Item, PlayRate, Reaper, ReaperResult,
-    Tempo, Track,
+    next_main_loop_cycle, BasicBookmarkInfo, BookmarkType, IndexBasedBookmark, Item, PlayRate,
+    Reaper, ReaperResult, Tempo, TempoMap, Track, Transport,
 };
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 use std::{iter, mem};
 
 use camino::{Utf8Path, Utf8PathBuf};
 use either::Either;
+use enumflags2::BitFlags;
 use reaper_medium::ProjectContext::{CurrentProject, Proj};
 use reaper_medium::{
-    AutoSeekBehavior, BookmarkId, BookmarkRef, CountProjectMarkersResult, DurationInSeconds,
-    GetLastMarkerAndCurRegionResult, GetLoopTimeRange2Result, MasterTrackBehavior, PanMode,
-    PlayState, PositionInSeconds, ProjectContext, ProjectRef, ReaProject, ReaperString,
-    ReaperStringArg, SetEditCurPosOptions, TimeMap2TimeToBeatsResult, TimeMode, TimeModeOverride,
-    TimeRangeType, TimeSignature, TrackDefaultsBehavior, TrackLocation, UndoBehavior,
+    AutoSeekBehavior, BookmarkId, BookmarkRef, CommandId, CountProjectMarkersResult,
+    DurationInSeconds, GetLastMarkerAndCurRegionResult, MarkerOrRegionPosition,
+    MasterTrackBehavior, NativeColor, PanMode, PlayState, PositionInSeconds, ProjectContext,
+    ProjectRef, ReaProject, ReaperString, ReaperStringArg, SaveProjectFlag, SectionId,
+    SetEditCurPosOptions, TimeMap2TimeToBeatsResult, TimeMode, TimeModeOverride, TimeRange,
+    TimeRangeType, TimeSignature, TrackDefaultsBehavior, TrackLocation, UndoBehavior, UndoScope,
 };
 use std::path::PathBuf;
 
+/// "Transport: Stop (save all recorded media)"
+const STOP_SAVING_ALL_RECORDED_MEDIA: u32 = 40667;
+/// "Options: Record mode: normal"
+const RECORD_MODE_NORMAL: u32 = 40252;
+/// "Options: Record mode: time selection auto-punch"
+const RECORD_MODE_AUTO_PUNCH_TIME_SELECTION: u32 = 40076;
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Project {
     rea_project: ReaProject,
@@ -45,6 +56,14 @@ impl Project {
             .get_project_state_change_count(self.context())
     }
 
+    pub fn tempo_map(self) -> TempoMap {
+        TempoMap::new(self)
+    }
+
+    pub fn transport(self) -> Transport {
+        Transport::new(self)
+    }
+
     pub fn count_tempo_time_sig_markers(&self) -> u32 {
         Reaper::get()
             .medium_reaper()
@@ -127,6 +146,37 @@ impl Project {
         Either::Right(iter)
     }
 
+    /// Returns the top-level tracks of this project, each one with its (nested) children.
+    pub fn track_tree(self) -> Vec<TrackTreeNode> {
+        // Stack of open folders. The bottom entry collects the top-level tracks.
+        let mut stack: Vec<TrackTreeNode> = vec![];
+        let mut roots = vec![];
+        for track in self.tracks() {
+            let depth_change = track.folder_depth_change();
+            let node = TrackTreeNode {
+                track,
+                children: vec![],
+            };
+            if depth_change > 0 {
+                stack.push(node);
+                continue;
+            }
+            push_track_tree_node(&mut stack, &mut roots, node);
+            // Close as many folders as requested
+            for _ in 0..depth_change.unsigned_abs() {
+                match stack.pop() {
+                    None => break,
+                    Some(folder) => push_track_tree_node(&mut stack, &mut roots, folder),
+                }
+            }
+        }
+        // Close folders which haven't been closed properly
+        while let Some(folder) = stack.pop() {
+            push_track_tree_node(&mut stack, &mut roots, folder);
+        }
+        roots
+    }
+
     pub fn items(self) -> impl ExactSizeIterator<Item = Item> + DoubleEndedIterator + 'static {
         if self.complain_if_not_available().is_err() {
             return Either::Left(iter::empty());
@@ -253,6 +303,7 @@ impl Project {
                 .medium_reaper()
                 .delete_track(track.raw_unchecked());
         }
+        Reaper::get().track_cache().invalidate();
     }
 
     // TODO-low Introduce variant that doesn't notify ControlSurface
@@ -290,13 +341,58 @@ impl Project {
             operation()
         } else {
             let label = label.into().into_inner();
-            let undo_block = Reaper::get().enter_undo_block_internal(self, label.as_ref());
+            let undo_block =
+                Reaper::get().enter_undo_block_internal(self, label.as_ref(), UndoScope::All);
             let result = operation();
             std::mem::drop(undo_block);
             result
         }
     }
 
+    /// Like [`undoable()`] but treats the operation as a transaction.
+    ///
+    /// If the operation returns an error, the changes made so far are rolled back by performing
+    /// an immediate undo and the error is returned. Nothing is undone if the operation didn't
+    /// change the project. Nested calls don't open a new undo block and leave the rollback to the
+    /// outermost call, so the whole transaction is reverted as one.
+    ///
+    /// Nothing is rolled back in the following cases because no undo block is created:
+    ///
+    /// - While a project is being loaded or saved.
+    /// - When called within a non-transactional [`undoable()`] call. Undoing would revert the
+    ///   changes of the enclosing operation as well.
+    ///
+    /// [`undoable()`]: #method.undoable
+    pub fn undoable_transaction<'a, F, R, E>(
+        self,
+        label: impl Into<ReaperStringArg<'a>>,
+        scope: UndoScope,
+        operation: F,
+    ) -> Result<R, E>
+    where
+        F: FnOnce() -> Result<R, E>,
+    {
+        if Reaper::get()
+            .currently_loading_or_saving_project()
+            .is_some()
+        {
+            return operation();
+        }
+        let label = label.into().into_inner();
+        let undo_block = Reaper::get().enter_undo_block_internal(self, label.as_ref(), scope);
+        let is_outermost = undo_block.is_some();
+        let state_change_count_before = self.state_change_count();
+        let result = operation();
+        std::mem::drop(undo_block);
+        let project_changed = self.state_change_count() != state_change_count_before;
+        if is_outermost && result.is_err() && project_changed {
+            Reaper::get()
+                .medium_reaper()
+                .undo_do_undo_2(Proj(self.rea_project));
+        }
+        result
+    }
+
     pub fn undo(self) -> bool {
         if self.complain_if_not_available().is_err() {
             return false;
@@ -327,6 +423,28 @@ impl Project {
             .is_project_dirty(Proj(self.rea_project))
     }
 
+    /// Saves this project to its file.
+    ///
+    /// Shows the "Save as" dialog if the project hasn't been saved before.
+    pub fn save(self) -> ReaperResult<()> {
+        self.complain_if_not_available()?;
+        Reaper::get()
+            .medium_reaper()
+            .main_save_project(self.context(), false);
+        Ok(())
+    }
+
+    /// Saves this project to the given file without showing a dialog.
+    ///
+    /// The project file path changes accordingly (unless saving a track template).
+    pub fn save_as(self, file: &Utf8Path, flags: BitFlags<SaveProjectFlag>) -> ReaperResult<()> {
+        self.complain_if_not_available()?;
+        Reaper::get()
+            .medium_reaper()
+            .main_save_project_ex(self.context(), file, flags);
+        Ok(())
+    }
+
     pub fn label_of_last_undoable_action(self) -> Option<ReaperString> {
         self.complain_if_not_available().ok()?;
         Reaper::get()
@@ -415,6 +533,99 @@ impl Project {
         self.play_state().is_recording
     }
 
+    /// Starts recording (if not already recording).
+    ///
+    /// The returned future resolves as soon as REAPER is recording. It must be polled in the main
+    /// thread and checks the recording state once per main loop cycle. Only the current project
+    /// can be recorded.
+    ///
+    /// Resolves with an error if REAPER is not recording within the given timeout, e.g. because
+    /// the user cancelled a prompt.
+    pub async fn start_recording(self, timeout: Duration) -> ReaperResult<()> {
+        self.complain_if_not_current()?;
+        if !self.is_recording() {
+            Reaper::get().medium_reaper().csurf_on_record();
+        }
+        let start = Instant::now();
+        while !self.is_recording() {
+            if start.elapsed() >= timeout {
+                return Err("couldn't start recording".into());
+            }
+            next_main_loop_cycle().await;
+        }
+        Ok(())
+    }
+
+    /// Stops recording, saving all recorded media.
+    ///
+    /// The returned future resolves with the items which have been created by the recording. It
+    /// must be polled in the main thread and checks for new items once per main loop cycle, so it
+    /// also catches items which REAPER creates later on, e.g. after asking the user whether to
+    /// keep the recorded files.
+    ///
+    /// Resolves with an empty list if no items have been created within the given timeout, e.g.
+    /// because the user chose to delete the recorded files. Dropping the future stops waiting.
+    pub async fn stop_recording(self, timeout: Duration) -> ReaperResult<Vec<Item>> {
+        self.complain_if_not_current()?;
+        if !self.is_recording() {
+            return Err("not recording".into());
+        }
+        let items_before: HashSet<Item> = self.items().collect();
+        Reaper::get().medium_reaper().main_on_command_ex(
+            CommandId::new(STOP_SAVING_ALL_RECORDED_MEDIA),
+            0,
+            self.context(),
+        );
+        let start = Instant::now();
+        loop {
+            if !self.is_available() {
+                return Err("project has been closed while waiting for recorded items".into());
+            }
+            let new_items: Vec<Item> = self
+                .items()
+                .filter(|item| !items_before.contains(item))
+                .collect();
+            if !new_items.is_empty() || start.elapsed() >= timeout {
+                return Ok(new_items);
+            }
+            next_main_loop_cycle().await;
+        }
+    }
+
+    /// Returns the auto-punch range if the record mode is "auto-punch time selection".
+    pub fn punch_range(self) -> Option<TimeRange> {
+        let is_auto_punch = Reaper::get()
+            .medium_reaper()
+            .get_toggle_command_state_ex(
+                SectionId::new(0),
+                CommandId::new(RECORD_MODE_AUTO_PUNCH_TIME_SELECTION),
+            )
+            .unwrap_or(false);
+        if !is_auto_punch {
+            return None;
+        }
+        self.time_selection()
+    }
+
+    /// Sets the record mode to "auto-punch time selection" with the given range or, if `None`,
+    /// back to normal.
+    ///
+    /// The record mode is a global setting, the range is set as time selection of this project.
+    pub fn set_punch_range(self, range: Option<TimeRange>) {
+        let command_id = match range {
+            None => RECORD_MODE_NORMAL,
+            Some(range) => {
+                self.set_time_selection(range);
+                RECORD_MODE_AUTO_PUNCH_TIME_SELECTION
+            }
+        };
+        Reaper::get().medium_reaper().main_on_command_ex(
+            CommandId::new(command_id),
+            0,
+            self.context(),
+        );
+    }
+
     pub fn repeat_is_enabled(self) -> bool {
         Reaper::get()
             .medium_reaper()
@@ -453,6 +664,63 @@ impl Project {
             .find(|res| res.basic_info.id == id)
     }
 
+    /// Adds a marker or region (creating an undo point) and returns it.
+    pub fn add_bookmark<'a>(
+        self,
+        position: MarkerOrRegionPosition,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperResult<IndexBasedBookmark> {
+        let bookmark_type = match position {
+            MarkerOrRegionPosition::Marker(_) => BookmarkType::Marker,
+            MarkerOrRegionPosition::Region(_, _) => BookmarkType::Region,
+        };
+        let id = self.undoable("Add marker/region", || {
+            Reaper::get().medium_reaper().add_project_marker_2(
+                self.context(),
+                position,
+                name,
+                None,
+                color,
+            )
+        })?;
+        let res = self
+            .find_bookmark_by_type_and_id(bookmark_type, BookmarkId::new(id))
+            .ok_or("added bookmark not found")?;
+        Ok(res.bookmark)
+    }
+
+    /// Returns the last marker at or before the given position or the region which contains the
+    /// given position, depending on the given type.
+    pub fn find_bookmark_at(
+        self,
+        bookmark_type: BookmarkType,
+        position: PositionInSeconds,
+    ) -> Option<IndexBasedBookmark> {
+        let res = Reaper::get()
+            .medium_reaper()
+            .get_last_marker_and_cur_region(self.context(), position);
+        let index = match bookmark_type {
+            BookmarkType::Marker => res.marker_index?,
+            BookmarkType::Region => res.region_index?,
+        };
+        self.find_bookmark_by_index(index)
+    }
+
+    /// Returns the marker or region (depending on the given type) whose start is nearest to the
+    /// given position.
+    pub fn find_nearest_bookmark(
+        self,
+        bookmark_type: BookmarkType,
+        position: PositionInSeconds,
+    ) -> Option<FindBookmarkResult> {
+        self.bookmarks_of_type(bookmark_type).min_by(|a, b| {
+            let distance_a = (a.basic_info.position.get() - position.get()).abs();
+            let distance_b = (b.basic_info.position.get() - position.get()).abs();
+            distance_a.total_cmp(&distance_b)
+        })
+    }
+
     pub fn directory(self) -> Option<Utf8PathBuf> {
         let file = self.file()?;
         let dir = file.parent()?;
@@ -602,39 +870,70 @@ impl Project {
             .get_cursor_position_ex(self.context())
     }
 
-    pub fn time_selection(self) -> Option<GetLoopTimeRange2Result> {
-        Reaper::get()
-            .medium_reaper
-            .get_set_loop_time_range_2_get(self.context(), TimeRangeType::TimeSelection)
+    pub fn time_selection(self) -> Option<TimeRange> {
+        self.time_range(TimeRangeType::TimeSelection)
     }
 
-    pub fn loop_points(self) -> Option<GetLoopTimeRange2Result> {
-        Reaper::get()
-            .medium_reaper
-            .get_set_loop_time_range_2_get(self.context(), TimeRangeType::LoopPoints)
+    pub fn set_time_selection(self, range: TimeRange) {
+        self.set_time_range(
+            TimeRangeType::TimeSelection,
+            range,
+            AutoSeekBehavior::DenyAutoSeek,
+        );
     }
 
-    pub fn set_time_selection(self, start: PositionInSeconds, end: PositionInSeconds) {
-        Reaper::get().medium_reaper.get_set_loop_time_range_2_set(
-            self.context(),
-            TimeRangeType::TimeSelection,
-            start,
-            end,
+    pub fn clear_time_selection(self) {
+        let zero = PositionInSeconds::new_panic(0.0);
+        self.set_time_selection(TimeRange::new_panic(zero, zero));
+    }
+
+    pub fn loop_range(self) -> Option<TimeRange> {
+        self.time_range(TimeRangeType::LoopPoints)
+    }
+
+    pub fn set_loop_range(self, range: TimeRange) {
+        self.set_time_range(
+            TimeRangeType::LoopPoints,
+            range,
             AutoSeekBehavior::DenyAutoSeek,
         );
     }
 
+    pub fn loop_points(self) -> Option<TimeRange> {
+        self.loop_range()
+    }
+
     pub fn set_loop_points(
         self,
         start: PositionInSeconds,
         end: PositionInSeconds,
         auto_seek_behavior: AutoSeekBehavior,
+    ) {
+        self.set_time_range(
+            TimeRangeType::LoopPoints,
+            TimeRange::new_panic(start, end),
+            auto_seek_behavior,
+        );
+    }
+
+    fn time_range(self, time_range_type: TimeRangeType) -> Option<TimeRange> {
+        Reaper::get()
+            .medium_reaper
+            .get_set_loop_time_range_2_get(self.context(), time_range_type)
+            .map(TimeRange::from)
+    }
+
+    fn set_time_range(
+        self,
+        time_range_type: TimeRangeType,
+        range: TimeRange,
+        auto_seek_behavior: AutoSeekBehavior,
     ) {
         Reaper::get().medium_reaper.get_set_loop_time_range_2_set(
             self.context(),
-            TimeRangeType::LoopPoints,
-            start,
-            end,
+            time_range_type,
+            range.start(),
+            range.end(),
             auto_seek_behavior,
         );
     }
@@ -761,6 +1060,13 @@ impl Project {
             .get_set_repeat_ex_set(self.context(), repeat);
     }
 
+    fn complain_if_not_current(self) -> ReaperResult<()> {
+        if self != Reaper::get().current_project() {
+            return Err("Project is not the current project".into());
+        }
+        Ok(())
+    }
+
     fn complain_if_not_available(self) -> ReaperResult<()> {
         if !self.is_available() {
             return Err("Project not available".into());
@@ -769,6 +1075,25 @@ impl Project {
     }
 }
 
+/// A track within the folder hierarchy of a project.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TrackTreeNode {
+    pub track: Track,
+    /// Direct children, only non-empty if the track is a folder.
+    pub children: Vec<TrackTreeNode>,
+}
+
+fn push_track_tree_node(
+    stack: &mut [TrackTreeNode],
+    roots: &mut Vec<TrackTreeNode>,
+    node: TrackTreeNode,
+) {
+    match stack.last_mut() {
+        None => roots.push(node),
+        Some(parent) => parent.children.push(node),
+    }
+}
+
 pub struct FindBookmarkResult {
     pub index: u32,
     pub index_within_type: u32,
@@ -0,0 +1,86 @@
+use crossbeam_channel::{Receiver, Sender, TrySendError};
+
+/// Creates a channel for sending values from a real-time thread (e.g. within
+/// [`OnAudioBuffer`](reaper_medium::OnAudioBuffer)) to the main thread.
+///
+/// The channel is bounded and all memory is allocated upfront. Sending is lock-free and never
+/// allocates, blocks or makes syscalls, so it's safe to use in the audio thread. If the channel is
+/// full, values are handed back to the sender instead of being queued.
+///
+/// There's exactly one sender and one receiver. The receiver is supposed to be drained in the main
+/// thread, typically in [`ControlSurface::run()`](reaper_medium::ControlSurface::run) next to the
+/// other middlewares.
+///
+/// # Panics
+///
+/// Panics if the capacity is 0.
+pub fn real_time_channel<T: Send>(capacity: usize) -> (RealTimeSender<T>, RealTimeReceiver<T>) {
+    assert!(capacity > 0, "capacity must be greater than 0");
+    let (sender, receiver) = crossbeam_channel::bounded(capacity);
+    (RealTimeSender { sender }, RealTimeReceiver { receiver })
+}
+
+/// Sending side of a [`real_time_channel()`], to be used in the real-time thread.
+#[derive(Debug)]
+pub struct RealTimeSender<T> {
+    sender: Sender<T>,
+}
+
+impl<T> RealTimeSender<T> {
+    /// Sends the given value without blocking.
+    ///
+    /// Returns the value as error if the channel is full or the receiver has been dropped.
+    pub fn try_send(&self, value: T) -> Result<(), T> {
+        self.sender.try_send(value).map_err(|e| match e {
+            TrySendError::Full(v) | TrySendError::Disconnected(v) => v,
+        })
+    }
+
+    /// Returns whether the channel is currently full.
+    pub fn is_full(&self) -> bool {
+        self.sender.is_full()
+    }
+}
+
+/// Receiving side of a [`real_time_channel()`], to be drained in the main thread.
+#[derive(Debug)]
+pub struct RealTimeReceiver<T> {
+    receiver: Receiver<T>,
+}
+
+impl<T> RealTimeReceiver<T> {
+    /// Processes at most `bulk_size` of the values currently in the channel.
+    ///
+    /// Limiting the bulk size prevents a chatty real-time thread from stalling the main thread.
+    pub fn drain(&self, bulk_size: usize, mut process: impl FnMut(T)) {
+        for value in self.receiver.try_iter().take(bulk_size) {
+            process(value);
+        }
+    }
+
+    /// Discards all values currently in the channel and returns how many they were.
+    pub fn discard(&self) -> usize {
+        self.receiver.try_iter().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn send_until_full_and_drain() {
+        // Given
+        let (sender, receiver) = real_time_channel(2);
+        // When
+        sender.try_send(1).unwrap();
+        sender.try_send(2).unwrap();
+        let rejected = sender.try_send(3);
+        let mut received = vec![];
+        receiver.drain(10, |v| received.push(v));
+        // Then
+        assert_eq!(rejected, Err(3));
+        assert_eq!(received, vec![1, 2]);
+        assert!(sender.try_send(4).is_ok());
+    }
+}
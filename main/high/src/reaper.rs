@@ -3,6 +3,7 @@ use std::cell::{Cell, RefCell, RefMut};
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
+use std::ffi::{c_char, c_void};
 use std::rc::Rc;
 use std::sync::{Arc, Weak};
 
@@ -10,24 +11,25 @@ use crate::undo_block::UndoBlock;
 use crate::ActionKind::Toggleable;
 use crate::{DefaultConsoleMessageFormatter, Project};
 use once_cell::sync::Lazy;
-use reaper_low::{raw, register_plugin_destroy_hook};
+use reaper_low::{firewall, raw, register_plugin_destroy_hook};
 
 use reaper_low::PluginContext;
 
 use crate::helper_control_surface::{HelperControlSurface, HelperTask};
 use crate::mutex_util::lock_ignoring_poisoning;
+use crate::track_cache::TrackCache;
 use derivative::Derivative;
 use reaper_medium::ProjectContext::Proj;
-use reaper_medium::UndoScope::All;
 use reaper_medium::{
     ActionValueChange, CommandId, Handle, HookCommand, HookPostCommand2, OwnedGaccelRegister,
     ReaProject, RealTimeAudioThreadScope, ReaperSession, ReaperStr, ReaperString, ReaperStringArg,
-    SectionContext, ToggleAction, ToggleActionResult, WindowContext,
+    SectionContext, SectionId, ToggleAction, ToggleActionResult, UndoScope, WindowContext,
 };
 use std::fmt::{Debug, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tracing::debug;
 
 /// How many tasks to process at a maximum in one main loop iteration.
@@ -83,13 +85,23 @@ impl ReaperBuilder {
                     undo_block_is_active: Cell::new(false),
                     session_status: RefCell::new(SessionStatus::Sleeping),
                     helper_task_sender,
+                    deferred_tasks: Default::default(),
+                    defer_timer_is_registered: Cell::new(false),
                     log_crashes_to_console: Default::default(),
                     report_crashes_to_sentry: Default::default(),
+                    track_cache: Default::default(),
                     #[cfg(feature = "sentry")]
                     sentry_guard: Default::default(),
                 };
                 INSTANCE = Some(reaper);
-                register_plugin_destroy_hook(|| INSTANCE = None);
+                register_plugin_destroy_hook(|| {
+                    // The timer is not registered via the session, so it's not unregistered
+                    // automatically.
+                    if let Some(reaper) = Reaper::get_if_available() {
+                        reaper.set_defer_timer_registered(false);
+                    }
+                    INSTANCE = None;
+                });
                 // We register a tiny control surface permanently just for the most essential stuff.
                 // It will be unregistered automatically using reaper-medium's Drop implementation.
                 let helper_control_surface = HelperControlSurface::new(helper_task_receiver);
@@ -109,6 +121,31 @@ impl ReaperBuilder {
 
 pub struct RealTimeReaper {}
 
+/// Point in time at which a closure scheduled via [`Reaper::defer`] should run.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DeferDeadline(Instant);
+
+impl From<Instant> for DeferDeadline {
+    fn from(instant: Instant) -> Self {
+        Self(instant)
+    }
+}
+
+impl From<Duration> for DeferDeadline {
+    fn from(duration: Duration) -> Self {
+        Self(Instant::now() + duration)
+    }
+}
+
+struct DeferredTask {
+    deadline: Instant,
+    op: Box<dyn FnOnce()>,
+}
+
+extern "C" fn process_deferred_tasks() {
+    firewall(|| Reaper::get().run_due_deferred_tasks());
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct Reaper {
@@ -130,10 +167,15 @@ pub struct Reaper {
     undo_block_is_active: Cell<bool>,
     session_status: RefCell<SessionStatus>,
     helper_task_sender: crossbeam_channel::Sender<HelperTask>,
+    /// Closures scheduled via [`Reaper::defer`], processed by a REAPER timer.
+    #[derivative(Debug = "ignore")]
+    deferred_tasks: RefCell<Vec<DeferredTask>>,
+    defer_timer_is_registered: Cell<bool>,
     /// Whether to log to the REAPER console (user can toggle this at runtime).
     log_crashes_to_console: Arc<AtomicBool>,
     /// Whether to report to Sentry (user can toggle this at runtime).
     report_crashes_to_sentry: Arc<AtomicBool>,
+    pub(crate) track_cache: TrackCache,
     #[cfg(feature = "sentry")]
     #[derivative(Debug = "ignore")]
     sentry_guard: RefCell<Option<sentry::ClientInitGuard>>,
@@ -167,6 +209,11 @@ impl ActionReg {
 
 pub enum ActionKind {
     NotToggleable,
+    /// An action with an on/off state, provided by the given closure.
+    ///
+    /// The state is reported to REAPER via the toggle action hook. Toolbar buttons are refreshed
+    /// automatically after the action has been executed. If the state changes for other reasons,
+    /// call [`RegisteredAction::refresh_toggle_state()`].
     Toggleable(Box<dyn Fn() -> bool>),
 }
 
@@ -239,7 +286,7 @@ impl Reaper {
         require_main_thread(&plugin_context);
         Reaper::load(plugin_context).setup();
         let reaper = Reaper::get();
-        // Add custom panic hook
+        // Add custom panic handler
         let crash_handler_config = CrashHandlerConfig {
             plugin_info,
             crash_formatter: Box::new(DefaultConsoleMessageFormatter),
@@ -247,9 +294,9 @@ impl Reaper {
             sentry_enabled: reaper.report_crashes_to_sentry.clone(),
         };
         let crash_handler = CrashHandler::new(crash_handler_config);
-        std::panic::set_hook(Box::new(move |panic_info| {
-            crash_handler.handle_crash(panic_info);
-        }));
+        reaper_low::set_panic_handler(move |panic_report| {
+            crash_handler.handle_panic_report(panic_report);
+        });
     }
 
     pub fn log_crashes_to_console(&self) -> bool {
@@ -281,11 +328,14 @@ impl Reaper {
     // singleton and allowing all REAPER functions to be called from an immutable context ...
     // although they can and often will lead to mutations within REAPER!
     pub fn get() -> &'static Reaper {
-        unsafe {
-            INSTANCE
-                .as_ref()
-                .expect("Reaper::load().setup() must be called before Reaper::get()")
-        }
+        Reaper::get_if_available()
+            .expect("Reaper::load().setup() must be called before Reaper::get()")
+    }
+
+    /// Like [`get()`](Self::get) but returns `None` if reaper-rs hasn't been set up or has been
+    /// shut down already.
+    pub(crate) fn get_if_available() -> Option<&'static Reaper> {
+        unsafe { INSTANCE.as_ref() }
     }
 
     /// This wakes reaper-rs up.
@@ -387,6 +437,66 @@ impl Reaper {
         }
     }
 
+    /// Schedules the given closure for execution in the main thread at the given point in time.
+    ///
+    /// Accepts either an [`Instant`] or a [`Duration`] (relative to now). Execution is driven by a
+    /// REAPER timer, so the timing accuracy is limited to REAPER's timer interval (roughly 30
+    /// milliseconds). Unlike the task support of the middleware, this doesn't require a control
+    /// surface.
+    ///
+    /// Must be called from the main thread.
+    pub fn defer(&self, when: impl Into<DeferDeadline>, op: impl FnOnce() + 'static) {
+        self.require_main_thread();
+        let task = DeferredTask {
+            deadline: when.into().0,
+            op: Box::new(op),
+        };
+        self.deferred_tasks.borrow_mut().push(task);
+        self.set_defer_timer_registered(true);
+    }
+
+    /// Registers or unregisters the timer which processes deferred tasks.
+    ///
+    /// This goes directly to the low-level API instead of the medium-level session because
+    /// `defer()` might be called while the session is borrowed (e.g. from a control surface
+    /// callback).
+    fn set_defer_timer_registered(&self, registered: bool) {
+        if self.defer_timer_is_registered.get() == registered {
+            return;
+        }
+        let name: &[u8] = if registered { b"timer\0" } else { b"-timer\0" };
+        unsafe {
+            self.medium_reaper.low().plugin_register(
+                name.as_ptr() as *const c_char,
+                process_deferred_tasks as *mut c_void,
+            );
+        }
+        self.defer_timer_is_registered.set(registered);
+    }
+
+    fn run_due_deferred_tasks(&self) {
+        let now = Instant::now();
+        // Take due tasks out first so that the closures themselves can call `defer()`.
+        let due_tasks: Vec<_> = {
+            let mut tasks = self.deferred_tasks.borrow_mut();
+            if tasks.is_empty() {
+                return;
+            }
+            let (due, pending) = std::mem::take(&mut *tasks)
+                .into_iter()
+                .partition(|t: &DeferredTask| t.deadline <= now);
+            *tasks = pending;
+            due
+        };
+        for task in due_tasks {
+            (task.op)();
+        }
+        // No need to keep the timer running if nothing is scheduled anymore
+        if self.deferred_tasks.borrow().is_empty() {
+            self.set_defer_timer_registered(false);
+        }
+    }
+
     /// This looks for a command that has been registered via [`Self::register_action`].
     ///
     /// This is a pure reaper-rs feature, it doesn't communicate with REAPER.
@@ -492,6 +602,7 @@ impl Reaper {
         &self,
         project: Project,
         label: &'a ReaperStr,
+        scope: UndoScope,
     ) -> Option<UndoBlock<'a>> {
         self.require_main_thread();
         if self.undo_block_is_active.get() {
@@ -499,17 +610,22 @@ impl Reaper {
         }
         self.undo_block_is_active.replace(true);
         self.medium_reaper().undo_begin_block_2(Proj(project.raw()));
-        Some(UndoBlock::new(project, label))
+        Some(UndoBlock::new(project, label, scope))
     }
 
     // Doesn't attempt to end a block if we are not in an undo block.
-    pub(super) fn leave_undo_block_internal(&self, project: Project, label: &ReaperStr) {
+    pub(super) fn leave_undo_block_internal(
+        &self,
+        project: Project,
+        label: &ReaperStr,
+        scope: UndoScope,
+    ) {
         self.require_main_thread();
         if !self.undo_block_is_active.get() {
             return;
         }
         self.medium_reaper()
-            .undo_end_block_2(Proj(project.raw()), label, All);
+            .undo_end_block_2(Proj(project.raw()), label, scope);
         self.undo_block_is_active.replace(false);
     }
 
@@ -597,10 +713,21 @@ impl RegisteredAction {
         RegisteredAction { command_id }
     }
 
+    pub fn command_id(&self) -> CommandId {
+        self.command_id
+    }
+
     pub fn unregister(&self) {
         require_main_thread(Reaper::get().medium_reaper().low().plugin_context());
         Reaper::get().unregister_action(self.command_id);
     }
+
+    /// Makes REAPER query the on/off state of this action again and refresh its toolbar buttons.
+    pub fn refresh_toggle_state(&self) {
+        Reaper::get()
+            .medium_reaper()
+            .refresh_toolbar_2(SectionId::new(0), self.command_id);
+    }
 }
 
 // Called by REAPER (using a delegate function)!
@@ -610,12 +737,21 @@ struct HighLevelHookCommand {}
 impl HookCommand for HighLevelHookCommand {
     fn call(command_id: CommandId, _flag: i32) -> bool {
         // TODO-low Pass on flag
-        let operation = match Reaper::get().command_by_id.borrow().get(&command_id) {
-            Some(command) => command.operation.clone(),
+        let (operation, is_toggleable) = match Reaper::get().command_by_id.borrow().get(&command_id)
+        {
+            Some(command) => (
+                command.operation.clone(),
+                matches!(command.kind, ActionKind::Toggleable(_)),
+            ),
             None => return false,
         };
         let mut operation = operation.borrow_mut();
         operation();
+        if is_toggleable {
+            Reaper::get()
+                .medium_reaper()
+                .refresh_toolbar_2(SectionId::new(0), command_id);
+        }
         true
     }
 }
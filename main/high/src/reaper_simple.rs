@@ -2,8 +2,8 @@
 //! just access to `reaper_medium::Reaper` - without all the advanced stuff like subjects,
 //! channels etc. Although they end up in the same struct, this gives a little bit of structure.
 use crate::{
-    Action, Fx, FxChain, FxParameter, Guid, MidiInputDevice, MidiOutputDevice, Project, Reaper,
-    Section,
+    Action, ConfigStore, Fx, FxChain, FxParameter, Guid, Metronome, MidiInputDevice,
+    MidiOutputDevice, Project, Reaper, Section,
 };
 use camino::Utf8PathBuf;
 use helgoboss_midi::ShortMessage;
@@ -113,6 +113,42 @@ impl Reaper {
         self.medium_reaper.get_resource_path(|p| p.to_owned())
     }
 
+    /// Returns a persistent key-value store for the given namespace.
+    ///
+    /// Use a namespace which is unique to your extension, e.g. its name.
+    pub fn config_store(&self, namespace: impl Into<String>) -> ConfigStore {
+        ConfigStore::new(namespace.into())
+    }
+
+    /// Executes the given operation on the current project with UI refreshes suspended.
+    ///
+    /// One single undo point is created and the UI as well as all control surfaces are refreshed
+    /// once at the end. This makes mass operations such as renaming hundreds of tracks a lot
+    /// faster.
+    ///
+    /// This only batches the UI refresh. REAPER still invokes the control surface callbacks for
+    /// each single change during the operation, so change events (e.g. from
+    /// [`ChangeDetectionMiddleware`](crate::ChangeDetectionMiddleware)) are neither suppressed
+    /// nor coalesced.
+    pub fn batch_ui_refresh<'a, R>(
+        &self,
+        label: impl Into<ReaperStringArg<'a>>,
+        operation: impl FnOnce() -> R,
+    ) -> R {
+        let result = {
+            let _guard = self.medium_reaper.prevent_ui_refresh();
+            self.current_project().undoable(label, operation)
+        };
+        self.medium_reaper.track_list_update_all_external_surfaces();
+        self.medium_reaper.update_arrange();
+        result
+    }
+
+    /// Gives access to the metronome and pre-roll settings.
+    pub fn metronome(&self) -> Metronome {
+        Metronome::new()
+    }
+
     // Attention: Returns normal fx only, not input fx!
     // This is not reliable! After REAPER start no focused Fx can be found!
     #[allow(deprecated)]
@@ -0,0 +1,120 @@
+use crate::{Action, Project, Reaper, ReaperResult};
+use camino::{Utf8Path, Utf8PathBuf};
+use reaper_medium::{CommandId, SectionId};
+
+/// A ReaScript (Lua, EEL or Python) which has been registered as action so it can be run from
+/// Rust.
+///
+/// Values can be passed to and from the script via non-persistent extended state. The script can
+/// access it using the section returned by [`exchange_section()`], e.g. in Lua:
+///
+/// ```lua
+/// local input = reaper.GetExtState("my_script", "input")
+/// reaper.SetExtState("my_script", "output", input:upper(), false)
+/// ```
+///
+/// [`exchange_section()`]: #method.exchange_section
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReaScript {
+    path: Utf8PathBuf,
+    command_id: CommandId,
+    exchange_section: String,
+}
+
+impl Reaper {
+    /// Registers the given ReaScript file in the main section so it can be run.
+    ///
+    /// The file stem (e.g. `my_script` for `my_script.lua`) is used as extended state section for
+    /// exchanging values. The action is not saved to `reaper-kb.ini`, so it doesn't survive a
+    /// REAPER restart unless REAPER saves the action list for another reason.
+    pub fn load_reascript(&self, path: impl Into<Utf8PathBuf>) -> ReaperResult<ReaScript> {
+        let path = path.into();
+        let exchange_section = path
+            .file_stem()
+            .ok_or("ReaScript path doesn't have a file name")?
+            .to_owned();
+        let command_id = self
+            .medium_reaper()
+            .add_reascript(SectionId::new(0), &path, false)?;
+        let script = ReaScript {
+            path,
+            command_id,
+            exchange_section,
+        };
+        Ok(script)
+    }
+}
+
+impl ReaScript {
+    pub fn path(&self) -> &Utf8Path {
+        &self.path
+    }
+
+    pub fn command_id(&self) -> CommandId {
+        self.command_id
+    }
+
+    /// Returns the action which runs this script.
+    pub fn action(&self) -> Action {
+        Reaper::get()
+            .main_section()
+            .action_by_command_id(self.command_id)
+    }
+
+    /// Returns the extended state section used for exchanging values with the script.
+    pub fn exchange_section(&self) -> &str {
+        &self.exchange_section
+    }
+
+    /// Uses the given extended state section for exchanging values instead of the file stem.
+    pub fn with_exchange_section(mut self, section: impl Into<String>) -> Self {
+        self.exchange_section = section.into();
+        self
+    }
+
+    /// Runs the script in the context of the given project.
+    ///
+    /// Returns when the script has finished, unless the script uses `defer`, in which case it
+    /// continues running in the background.
+    pub fn run(&self, project: Project) {
+        Reaper::get()
+            .medium_reaper()
+            .main_on_command_ex(self.command_id, 0, project.context());
+    }
+
+    /// Makes the given value available to the script.
+    pub fn set_value(&self, key: &str, value: &str) {
+        Reaper::get().medium_reaper().set_ext_state(
+            self.exchange_section.as_str(),
+            key,
+            value,
+            false,
+        );
+    }
+
+    /// Returns the value which has been set by the script (or via [`set_value()`]).
+    ///
+    /// [`set_value()`]: #method.set_value
+    pub fn value(&self, key: &str) -> Option<String> {
+        Reaper::get()
+            .medium_reaper()
+            .get_ext_state(self.exchange_section.as_str(), key, |value| {
+                value.map(|v| v.to_str().to_owned())
+            })
+    }
+
+    /// Removes the given value.
+    pub fn remove_value(&self, key: &str) {
+        Reaper::get()
+            .medium_reaper()
+            .delete_ext_state(self.exchange_section.as_str(), key, false);
+    }
+
+    /// Unregisters the script.
+    pub fn unload(self) -> ReaperResult<()> {
+        Reaper::get()
+            .medium_reaper()
+            .remove_reascript(SectionId::new(0), &self.path, true)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{Reaper, ReaperResult};
+use reaper_medium::{
+    ControlSurface, HookCommand, OnAudioBuffer, OwnedGaccelRegister, ProjectConfigExtension,
+    Registration,
+};
+
+impl Reaper {
+    /// Registers the given control surface and returns a registration which unregisters it on
+    /// drop.
+    ///
+    /// Don't drop the registration from within one of the control surface's own callbacks.
+    pub fn register_control_surface<T: ControlSurface + 'static>(
+        &self,
+        control_surface: T,
+    ) -> ReaperResult<Registration> {
+        let registration = self
+            .medium_session()
+            .register_control_surface(Box::new(control_surface))?;
+        Ok(registration)
+    }
+
+    /// Registers the given audio hook and returns a registration which unregisters it on drop.
+    pub fn register_audio_hook<T: OnAudioBuffer + 'static>(
+        &self,
+        callback: T,
+    ) -> ReaperResult<Registration> {
+        let registration = self
+            .medium_session()
+            .register_audio_hook(Box::new(callback))?;
+        Ok(registration)
+    }
+
+    /// Registers the given hook command and returns a registration which unregisters it on drop.
+    pub fn register_hook_command<T: HookCommand>(&self) -> ReaperResult<Registration> {
+        let registration = self.medium_session().register_hook_command::<T>()?;
+        Ok(registration)
+    }
+
+    /// Registers the given key binding and returns a registration which unregisters it on drop.
+    pub fn register_gaccel(&self, register: OwnedGaccelRegister) -> ReaperResult<Registration> {
+        let registration = self.medium_session().register_gaccel(register)?;
+        Ok(registration)
+    }
+
+    /// Registers the given project config extension and returns a registration which unregisters
+    /// it on drop.
+    pub fn register_project_config_extension<T: ProjectConfigExtension + 'static>(
+        &self,
+        extension: T,
+    ) -> ReaperResult<Registration> {
+        let registration = self
+            .medium_session()
+            .register_project_config_extension(Box::new(extension))?;
+        Ok(registration)
+    }
+}
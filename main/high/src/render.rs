@@ -0,0 +1,325 @@
+use crate::{next_main_loop_cycle, Project, Reaper, ReaperResult};
+use camino::{Utf8Path, Utf8PathBuf};
+use reaper_medium::{CommandId, Hz, PositionInSeconds, ProjectInfoAttributeKey, ReaperString};
+use std::time::{Duration, Instant, SystemTime};
+
+/// "File: Render project, using the most recent render settings, auto-close render dialog"
+const RENDER_WITH_MOST_RECENT_SETTINGS_AUTO_CLOSE: u32 = 42230;
+
+/// How long the target files must stay unchanged until a render with missing files is considered
+/// finished.
+const RENDER_SETTLE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Settings to be applied before rendering a project.
+///
+/// Each setting which is `None` keeps the value currently stored in the project.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct RenderSettings {
+    /// Output directory.
+    pub directory: Option<Utf8PathBuf>,
+    /// File name pattern, may contain wildcards such as `$project` or `$region`.
+    pub file_pattern: Option<String>,
+    pub bounds: Option<RenderBounds>,
+    pub sample_rate: Option<Hz>,
+    pub channel_count: Option<u32>,
+    /// Base64-encoded sink configuration as found in the `RENDER_FORMAT` project info.
+    pub format: Option<String>,
+    pub add_to_project: Option<bool>,
+}
+
+/// Defines which part of the project gets rendered.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RenderBounds {
+    CustomTimeRange {
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    },
+    EntireProject,
+    TimeSelection,
+    AllRegions,
+    SelectedItems,
+    SelectedRegions,
+}
+
+impl RenderBounds {
+    fn to_raw(self) -> i32 {
+        use RenderBounds::*;
+        match self {
+            CustomTimeRange { .. } => 0,
+            EntireProject => 1,
+            TimeSelection => 2,
+            AllRegions => 3,
+            SelectedItems => 4,
+            SelectedRegions => 5,
+        }
+    }
+}
+
+/// Outcome of a render operation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RenderResult {
+    /// Files which have been written.
+    pub files: Vec<Utf8PathBuf>,
+    /// Files which should have been written according to the render settings but haven't been
+    /// (e.g. because the render has been canceled).
+    pub missing_files: Vec<Utf8PathBuf>,
+}
+
+/// Progress of a render operation, reported whenever the number of written files changes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RenderProgress {
+    pub written_file_count: usize,
+    pub total_file_count: usize,
+}
+
+impl Project {
+    /// Applies the given settings, renders this project and restores the previous render settings.
+    ///
+    /// The returned future must be run by the main-loop executor. It starts rendering in the next
+    /// main loop cycle. REAPER shows its own progress window while rendering. Afterwards, the
+    /// future checks the target files once per main loop cycle until all of them have been
+    /// written or nothing has changed for a while (e.g. because the render has been canceled).
+    /// `on_progress` is called whenever the number of written files changes.
+    ///
+    /// Only the current project can be rendered.
+    pub async fn render(
+        self,
+        settings: RenderSettings,
+        mut on_progress: impl FnMut(RenderProgress),
+    ) -> ReaperResult<RenderResult> {
+        next_main_loop_cycle().await;
+        if self != Reaper::get().current_project() {
+            return Err("only the current project can be rendered".into());
+        }
+        let saved_settings = self.save_render_settings()?;
+        let result = self.render_internal(&settings, &mut on_progress).await;
+        if self.is_available() {
+            saved_settings.restore(self);
+        }
+        result
+    }
+
+    async fn render_internal(
+        self,
+        settings: &RenderSettings,
+        on_progress: &mut impl FnMut(RenderProgress),
+    ) -> ReaperResult<RenderResult> {
+        self.apply_render_settings(settings)?;
+        let targets = self.render_targets()?;
+        // Existing files might be left over from a previous render, so we compare modification
+        // times and sizes in order to find out which files have actually been written.
+        let previous_states: Vec<_> = targets.iter().map(|f| file_state(f)).collect();
+        Reaper::get().medium_reaper().main_on_command_ex(
+            CommandId::new(RENDER_WITH_MOST_RECENT_SETTINGS_AUTO_CLOSE),
+            0,
+            self.context(),
+        );
+        let is_written = |current: &Option<FileState>, previous: &Option<FileState>| {
+            current.is_some() && current != previous
+        };
+        let mut last_states: Option<Vec<Option<FileState>>> = None;
+        let mut last_change = Instant::now();
+        let mut last_written_file_count = None;
+        loop {
+            let states: Vec<_> = targets.iter().map(|f| file_state(f)).collect();
+            let written_file_count = states
+                .iter()
+                .zip(&previous_states)
+                .filter(|(current, previous)| is_written(current, previous))
+                .count();
+            if last_written_file_count != Some(written_file_count) {
+                on_progress(RenderProgress {
+                    written_file_count,
+                    total_file_count: targets.len(),
+                });
+                last_written_file_count = Some(written_file_count);
+            }
+            if last_states.as_ref() != Some(&states) {
+                last_change = Instant::now();
+                last_states = Some(states);
+            } else if written_file_count == targets.len()
+                || last_change.elapsed() >= RENDER_SETTLE_TIMEOUT
+            {
+                break;
+            }
+            next_main_loop_cycle().await;
+        }
+        let last_states = last_states.unwrap_or_default();
+        let (files, missing_files) = targets
+            .into_iter()
+            .zip(last_states.iter().zip(&previous_states))
+            .partition::<Vec<_>, _>(|(_, (current, previous))| is_written(current, previous));
+        Ok(RenderResult {
+            files: files.into_iter().map(|(f, _)| f).collect(),
+            missing_files: missing_files.into_iter().map(|(f, _)| f).collect(),
+        })
+    }
+
+    /// Returns the files which would be written when rendering this project with the most recent
+    /// render settings.
+    pub fn render_targets(self) -> ReaperResult<Vec<Utf8PathBuf>> {
+        let targets = Reaper::get()
+            .medium_reaper()
+            .get_set_project_info_string_get(
+                self.context(),
+                ProjectInfoAttributeKey::RenderTargets,
+            )?;
+        let targets = targets
+            .to_str()
+            .split(';')
+            .filter(|t| !t.is_empty())
+            .map(Utf8PathBuf::from)
+            .collect();
+        Ok(targets)
+    }
+
+    fn save_render_settings(self) -> ReaperResult<SavedRenderSettings> {
+        let reaper = Reaper::get().medium_reaper();
+        let ctx = self.context();
+        let strings = SAVED_STRING_KEYS
+            .iter()
+            .map(|key| {
+                let value = reaper.get_set_project_info_string_get(ctx, key.clone())?;
+                Ok((key.clone(), value))
+            })
+            .collect::<ReaperResult<_>>()?;
+        let numbers = SAVED_NUMBER_KEYS
+            .iter()
+            .map(|key| {
+                (
+                    key.clone(),
+                    reaper.get_set_project_info_get(ctx, key.clone()),
+                )
+            })
+            .collect();
+        Ok(SavedRenderSettings { strings, numbers })
+    }
+
+    fn apply_render_settings(self, settings: &RenderSettings) -> ReaperResult<()> {
+        let reaper = Reaper::get().medium_reaper();
+        let ctx = self.context();
+        if let Some(dir) = &settings.directory {
+            reaper.get_set_project_info_string_set(
+                ctx,
+                ProjectInfoAttributeKey::RenderFile,
+                dir.as_str(),
+            )?;
+        }
+        if let Some(pattern) = &settings.file_pattern {
+            reaper.get_set_project_info_string_set(
+                ctx,
+                ProjectInfoAttributeKey::RenderPattern,
+                pattern.as_str(),
+            )?;
+        }
+        if let Some(format) = &settings.format {
+            reaper.get_set_project_info_string_set(
+                ctx,
+                ProjectInfoAttributeKey::RenderFormat,
+                format.as_str(),
+            )?;
+        }
+        if let Some(bounds) = settings.bounds {
+            if let RenderBounds::CustomTimeRange { start, end } = bounds {
+                reaper.get_set_project_info_set(
+                    ctx,
+                    ProjectInfoAttributeKey::RenderStartPos,
+                    start.get(),
+                );
+                reaper.get_set_project_info_set(
+                    ctx,
+                    ProjectInfoAttributeKey::RenderEndPos,
+                    end.get(),
+                );
+            }
+            reaper.get_set_project_info_set(
+                ctx,
+                ProjectInfoAttributeKey::RenderBoundsFlag,
+                bounds.to_raw() as f64,
+            );
+        }
+        if let Some(sample_rate) = settings.sample_rate {
+            reaper.get_set_project_info_set(
+                ctx,
+                ProjectInfoAttributeKey::RenderSampleRate,
+                sample_rate.get(),
+            );
+        }
+        if let Some(channel_count) = settings.channel_count {
+            reaper.get_set_project_info_set(
+                ctx,
+                ProjectInfoAttributeKey::RenderChannels,
+                channel_count as f64,
+            );
+        }
+        if let Some(add_to_project) = settings.add_to_project {
+            let current = reaper
+                .get_set_project_info_get(ctx, ProjectInfoAttributeKey::RenderAddToProject)
+                as i32;
+            let new = if add_to_project {
+                current | 1
+            } else {
+                current & !1
+            };
+            reaper.get_set_project_info_set(
+                ctx,
+                ProjectInfoAttributeKey::RenderAddToProject,
+                new as f64,
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Project info keys of the string settings which [`Project::render()`] might change.
+const SAVED_STRING_KEYS: [ProjectInfoAttributeKey; 3] = [
+    ProjectInfoAttributeKey::RenderFile,
+    ProjectInfoAttributeKey::RenderPattern,
+    ProjectInfoAttributeKey::RenderFormat,
+];
+
+/// Project info keys of the numeric settings which [`Project::render()`] might change.
+const SAVED_NUMBER_KEYS: [ProjectInfoAttributeKey; 6] = [
+    ProjectInfoAttributeKey::RenderStartPos,
+    ProjectInfoAttributeKey::RenderEndPos,
+    ProjectInfoAttributeKey::RenderBoundsFlag,
+    ProjectInfoAttributeKey::RenderSampleRate,
+    ProjectInfoAttributeKey::RenderChannels,
+    ProjectInfoAttributeKey::RenderAddToProject,
+];
+
+/// Render settings as they were before [`Project::render()`] applied its own ones.
+struct SavedRenderSettings {
+    strings: Vec<(ProjectInfoAttributeKey<'static>, ReaperString)>,
+    numbers: Vec<(ProjectInfoAttributeKey<'static>, f64)>,
+}
+
+impl SavedRenderSettings {
+    fn restore(self, project: Project) {
+        let reaper = Reaper::get().medium_reaper();
+        let ctx = project.context();
+        for (key, value) in self.strings {
+            // Restoring is best effort, it shouldn't hide the actual render result.
+            let _ = reaper.get_set_project_info_string_set(ctx, key, value);
+        }
+        for (key, value) in self.numbers {
+            reaper.get_set_project_info_set(ctx, key, value);
+        }
+    }
+}
+
+/// Used to detect whether a file has been (re)written.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct FileState {
+    modification_time: SystemTime,
+    size: u64,
+}
+
+fn file_state(file: &Utf8Path) -> Option<FileState> {
+    let metadata = std::fs::metadata(file).ok()?;
+    let state = FileState {
+        modification_time: metadata.modified().ok()?,
+        size: metadata.len(),
+    };
+    Some(state)
+}
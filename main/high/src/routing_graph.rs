@@ -0,0 +1,115 @@
+use crate::{Project, ReaperResult, Track, TrackRoute, TrackRoutePartner};
+use reaper_medium::{
+    MediaTrack, ReaperPanValue, ReaperVolumeValue, TrackSendDestinationChannels, TrackSendMode,
+    TrackSendSourceChannels,
+};
+use std::collections::HashMap;
+use std::iter;
+
+/// Snapshot of the signal routing of a project.
+///
+/// Contains one node for each track (including the master track) with all of its outgoing routes,
+/// that is, track sends and hardware outputs. Receives are the same routes seen from the other
+/// side, so they are available via [`incoming()`].
+///
+/// [`incoming()`]: #method.incoming
+#[derive(Clone, Debug)]
+pub struct RoutingGraph {
+    nodes: Vec<RoutingNode>,
+    node_index_by_track: HashMap<MediaTrack, usize>,
+}
+
+/// A track with its outgoing routes.
+#[derive(Clone, Debug)]
+pub struct RoutingNode {
+    pub track: Track,
+    pub edges: Vec<RoutingEdge>,
+}
+
+/// A single send or hardware output.
+#[derive(Clone, Debug)]
+pub struct RoutingEdge {
+    /// The route as seen from the sending track.
+    pub route: TrackRoute,
+    pub target: TrackRoutePartner,
+    pub volume: ReaperVolumeValue,
+    pub pan: ReaperPanValue,
+    pub is_muted: bool,
+    pub mode: TrackSendMode,
+    pub source_channels: TrackSendSourceChannels,
+    pub destination_channels: TrackSendDestinationChannels,
+}
+
+impl RoutingGraph {
+    /// Returns all nodes in project order, starting with the master track.
+    pub fn nodes(&self) -> &[RoutingNode] {
+        &self.nodes
+    }
+
+    pub fn node(&self, track: &Track) -> Option<&RoutingNode> {
+        let index = *self.node_index_by_track.get(&track.raw().ok()?)?;
+        Some(&self.nodes[index])
+    }
+
+    /// Returns the sends and hardware outputs of the given track.
+    pub fn outgoing(&self, track: &Track) -> &[RoutingEdge] {
+        match self.node(track) {
+            None => &[],
+            Some(node) => &node.edges,
+        }
+    }
+
+    /// Returns the sends which go to the given track, together with the sending track.
+    pub fn incoming<'a>(
+        &'a self,
+        track: &'a Track,
+    ) -> impl Iterator<Item = (&'a Track, &'a RoutingEdge)> + 'a {
+        self.nodes.iter().flat_map(move |node| {
+            node.edges
+                .iter()
+                .filter(
+                    move |edge| matches!(&edge.target, TrackRoutePartner::Track(t) if t == track),
+                )
+                .map(move |edge| (&node.track, edge))
+        })
+    }
+}
+
+impl Project {
+    /// Walks all track sends and hardware outputs of this project and returns them as graph.
+    pub fn routing_graph(self) -> ReaperResult<RoutingGraph> {
+        let tracks = iter::once(self.master_track()?).chain(self.tracks());
+        let mut nodes = Vec::new();
+        let mut node_index_by_track = HashMap::new();
+        for track in tracks {
+            let edges = track
+                .sends()
+                .map(create_edge)
+                .collect::<ReaperResult<Vec<_>>>()?;
+            node_index_by_track.insert(track.raw()?, nodes.len());
+            nodes.push(RoutingNode { track, edges });
+        }
+        let graph = RoutingGraph {
+            nodes,
+            node_index_by_track,
+        };
+        Ok(graph)
+    }
+}
+
+fn create_edge(route: TrackRoute) -> ReaperResult<RoutingEdge> {
+    let target = route
+        .partner()
+        .ok_or("couldn't determine target of route")?;
+    let edge = RoutingEdge {
+        target,
+        volume: route.volume()?,
+        pan: route.pan()?.reaper_value(),
+        is_muted: route.is_muted()?,
+        mode: route.send_mode(),
+        source_channels: route.source_channels(),
+        destination_channels: route.destination_channels(),
+        route,
+    };
+    Ok(edge)
+}
@@ -2,6 +2,7 @@
 use crate::mutex_util::lock_ignoring_poisoning;
 use crossbeam_channel::{Receiver, Sender};
 use std::error::Error;
+use std::time::{Duration, Instant};
 use {
     futures::{
         future::{BoxFuture, FutureExt},
@@ -15,18 +16,49 @@ use {
 };
 
 /// Task executor that receives tasks off of a channel and runs them.
+///
+/// Tasks are run in the order of their [`TaskPriority`]. Optionally, a time budget per run-loop
+/// cycle can be set. As soon as it's exhausted, tasks with a priority lower than
+/// [`TaskPriority::High`] are deferred to the next cycle. In order to not starve tasks with lower
+/// priority, a few of them are run in each cycle anyway (see [`MIN_TASKS_PER_PRIORITY`]).
 #[derive(Clone, Debug)]
 pub struct RunLoopExecutor {
-    ready_queue: Receiver<Arc<Task>>,
+    /// One queue per priority, highest priority first.
+    ready_queues: [Receiver<Arc<Task>>; TaskPriority::COUNT],
     bulk_size: usize,
+    time_budget: Option<Duration>,
 }
 
 /// `Spawner` spawns new futures onto the task channel.
 #[derive(Clone, Debug)]
 pub struct Spawner {
-    task_sender: Sender<Arc<Task>>,
+    task_senders: [Sender<Arc<Task>>; TaskPriority::COUNT],
+}
+
+/// Determines in which order spawned tasks are run and whether they may be deferred.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum TaskPriority {
+    /// Always run in the current cycle (as long as the bulk size allows), even if the time budget
+    /// is exhausted.
+    High,
+    #[default]
+    Normal,
+    /// Run only if there's time left after all tasks with higher priority have been run.
+    Low,
 }
 
+impl TaskPriority {
+    pub(crate) const COUNT: usize = 3;
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Number of queued tasks per priority which are run in each cycle even if the time budget or
+/// bulk size has been exhausted already by tasks with higher priority.
+pub const MIN_TASKS_PER_PRIORITY: usize = 1;
+
 pub(crate) type FutureResult = Result<(), Box<dyn Error>>;
 
 /// A future that can reschedule itself to be polled by an `Executor`.
@@ -44,25 +76,43 @@ struct Task {
     task_sender: Sender<Arc<Task>>,
 }
 
+/// Creates a spawner and an executor which runs at most `bulk_size` tasks per cycle.
 pub fn new_spawner_and_executor(bulk_size: usize) -> (Spawner, RunLoopExecutor) {
-    let (task_sender, ready_queue) = crossbeam_channel::unbounded();
+    let channels: [_; TaskPriority::COUNT] =
+        std::array::from_fn(|_| crossbeam_channel::unbounded());
     (
-        Spawner { task_sender },
+        Spawner {
+            task_senders: channels.clone().map(|(sender, _)| sender),
+        },
         RunLoopExecutor {
-            ready_queue,
+            ready_queues: channels.map(|(_, receiver)| receiver),
             bulk_size,
+            time_budget: None,
         },
     )
 }
 
 impl Spawner {
+    /// Spawns a future with [`TaskPriority::Normal`].
     pub fn spawn(&self, future: impl Future<Output = Result<(), Box<dyn Error>>> + 'static + Send) {
+        self.spawn_with_priority(TaskPriority::Normal, future);
+    }
+
+    /// Spawns a future with the given priority.
+    ///
+    /// The priority sticks with the task, so it also applies when the future is woken up again.
+    pub fn spawn_with_priority(
+        &self,
+        priority: TaskPriority,
+        future: impl Future<Output = Result<(), Box<dyn Error>>> + 'static + Send,
+    ) {
         let future = future.boxed();
+        let task_sender = self.task_senders[priority.index()].clone();
         let task = Arc::new(Task {
             future: Mutex::new(Some(future)),
-            task_sender: self.task_sender.clone(),
+            task_sender: task_sender.clone(),
         });
-        self.task_sender.send(task).expect("too many tasks queued");
+        task_sender.send(task).expect("too many tasks queued");
     }
 }
 
@@ -79,30 +129,137 @@ impl ArcWake for Task {
 }
 
 impl RunLoopExecutor {
+    /// Sets the maximum time which should be spent running tasks in one cycle.
+    ///
+    /// When exhausted, remaining tasks with a priority lower than [`TaskPriority::High`] are
+    /// deferred to the next cycle. A task which is already running is not interrupted, so the
+    /// budget can be exceeded by the duration of one poll. `None` (the default) means no limit.
+    pub fn set_time_budget(&mut self, time_budget: Option<Duration>) {
+        self.time_budget = time_budget;
+    }
+
     /// Returns number of discarded tasks.
     pub fn discard_tasks(&self) -> usize {
-        self.ready_queue.try_iter().count()
+        self.ready_queues
+            .iter()
+            .map(|queue| queue.try_iter().count())
+            .sum()
     }
 
     pub fn run(&self) {
-        for task in self.ready_queue.try_iter().take(self.bulk_size) {
-            // Take the future, and if it has not yet completed (is still Some),
-            // poll it in an attempt to complete it.
-            let mut future_slot = lock_ignoring_poisoning(&task.future);
-            if let Some(mut future) = future_slot.take() {
-                // Create a `LocalWaker` from the task itself
-                let waker = waker_ref(&task);
-                let context = &mut Context::from_waker(&waker);
-                // `BoxFuture<T>` is a type alias for
-                // `Pin<Box<dyn Future<Output = T> + Send + 'static>>`.
-                // We can get a `Pin<&mut dyn Future + Send + 'static>`
-                // from it by calling the `Pin::as_mut` method.
-                if future.as_mut().poll(context).is_pending() {
-                    // We're not done processing the future, so put it
-                    // back in its task to be run again in the future.
-                    *future_slot = Some(future);
-                }
+        run_prioritized(
+            &self.ready_queues,
+            self.bulk_size,
+            self.time_budget,
+            poll_task,
+        );
+    }
+}
+
+/// Runs the tasks of one cycle, highest priority first.
+///
+/// Used by both the shared and the local executor.
+pub(crate) fn run_prioritized<T>(
+    ready_queues: &[Receiver<T>; TaskPriority::COUNT],
+    bulk_size: usize,
+    time_budget: Option<Duration>,
+    mut poll: impl FnMut(&T),
+) {
+    let start = Instant::now();
+    let time_budget_exhausted = || match time_budget {
+        None => false,
+        Some(budget) => start.elapsed() >= budget,
+    };
+    let mut remaining_bulk_size = bulk_size;
+    for (index, queue) in ready_queues.iter().enumerate() {
+        let deferrable = index != TaskPriority::High.index();
+        // Don't use `try_iter()` here. It would also yield tasks which wake themselves up
+        // again during this cycle.
+        let queued_task_count = queue.len();
+        for i in 0..queued_task_count {
+            let guaranteed = i < MIN_TASKS_PER_PRIORITY;
+            if !guaranteed && (remaining_bulk_size == 0 || deferrable && time_budget_exhausted()) {
+                break;
             }
+            let Ok(task) = queue.try_recv() else {
+                break;
+            };
+            remaining_bulk_size = remaining_bulk_size.saturating_sub(1);
+            poll(&task);
+        }
+    }
+}
+
+fn poll_task(task: &Arc<Task>) {
+    // Take the future, and if it has not yet completed (is still Some),
+    // poll it in an attempt to complete it.
+    let mut future_slot = lock_ignoring_poisoning(&task.future);
+    if let Some(mut future) = future_slot.take() {
+        // Create a `LocalWaker` from the task itself
+        let waker = waker_ref(task);
+        let context = &mut Context::from_waker(&waker);
+        // `BoxFuture<T>` is a type alias for
+        // `Pin<Box<dyn Future<Output = T> + Send + 'static>>`.
+        // We can get a `Pin<&mut dyn Future + Send + 'static>`
+        // from it by calling the `Pin::as_mut` method.
+        if future.as_mut().poll(context).is_pending() {
+            // We're not done processing the future, so put it
+            // back in its task to be run again in the future.
+            *future_slot = Some(future);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities_and_time_budget() {
+        let (spawner, mut executor) = new_spawner_and_executor(10);
+        let log = Arc::new(Mutex::new(vec![]));
+        let priorities = [TaskPriority::Low, TaskPriority::Normal, TaskPriority::High];
+        for priority in priorities.into_iter().chain(priorities) {
+            let log = log.clone();
+            spawner.spawn_with_priority(priority, async move {
+                log.lock().unwrap().push(priority);
+                Ok(())
+            });
+        }
+        // Budget is exhausted right away, so all high-priority tasks run but only the minimum
+        // share of the others
+        executor.set_time_budget(Some(Duration::ZERO));
+        executor.run();
+        use TaskPriority::*;
+        assert_eq!(*log.lock().unwrap(), vec![High, High, Normal, Low]);
+        // Deferred tasks run in the next cycle
+        executor.set_time_budget(None);
+        executor.run();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![High, High, Normal, Low, Normal, Low]
+        );
+        assert_eq!(executor.discard_tasks(), 0);
+    }
+
+    #[test]
+    fn high_priority_doesnt_starve_others() {
+        let (spawner, executor) = new_spawner_and_executor(2);
+        for priority in [TaskPriority::Low, TaskPriority::High, TaskPriority::High] {
+            spawner.spawn_with_priority(priority, async { Ok(()) });
+        }
+        // High-priority tasks exhaust the bulk size but the low-priority task runs anyway
+        executor.run();
+        assert_eq!(executor.discard_tasks(), 0);
+    }
+
+    #[test]
+    fn bulk_size() {
+        let (spawner, executor) = new_spawner_and_executor(2);
+        for _ in 0..3 {
+            spawner.spawn(async { Ok(()) });
         }
+        executor.run();
+        assert_eq!(executor.discard_tasks(), 1);
     }
 }
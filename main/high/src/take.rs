@@ -1,7 +1,9 @@
-use crate::{FxChain, OwnedSource, Reaper, ReaperSource, Track};
+use crate::error::ReaperResult;
+use crate::{FxChain, Item, MidiSequence, OwnedSource, Reaper, ReaperSource, Track};
 use reaper_medium::{
     DurationInSeconds, FullPitchShiftMode, MediaItemTake, NativeColorValue, PlaybackSpeedFactor,
-    ReaperFunctionError, ReaperStringArg, ReaperVolumeValue, RgbColor, Semitones, TakeAttributeKey,
+    ProjectContext, ReaperFunctionError, ReaperStringArg, ReaperVolumeValue, RgbColor, Semitones,
+    TakeAttributeKey,
 };
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -20,6 +22,81 @@ impl Take {
         self.raw
     }
 
+    pub fn is_available(&self) -> bool {
+        Reaper::get()
+            .medium_reaper()
+            .validate_ptr_2(ProjectContext::CurrentProject, self.raw)
+    }
+
+    pub fn item(&self) -> Option<Item> {
+        let raw_item = unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_media_item_take_item(self.raw)?
+        };
+        Some(Item::new(raw_item))
+    }
+
+    /// Makes this take the active take of its item (creating an undo point).
+    pub fn activate(&self) {
+        let op = || unsafe {
+            Reaper::get().medium_reaper.set_active_take(self.raw);
+        };
+        match self.item() {
+            None => op(),
+            Some(item) => item.undoable("Activate take", || {
+                op();
+                item.update_in_project();
+            }),
+        }
+    }
+
+    /// Replaces the source of this take (creating an undo point) and returns the previous one.
+    pub fn edit_source(&self, source: OwnedSource) -> Option<OwnedSource> {
+        match self.item() {
+            None => self.set_source(source),
+            Some(item) => item.undoable("Set take source", || {
+                let previous = self.set_source(source);
+                item.update_in_project();
+                previous
+            }),
+        }
+    }
+
+    /// Loads all MIDI events of this take into an editable sequence.
+    ///
+    /// Returns an error if this is not a MIDI take.
+    pub fn midi_sequence(&self) -> ReaperResult<MidiSequence> {
+        let packed = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .midi_get_all_evts(self.raw())?
+        };
+        MidiSequence::from_packed_events(&packed)
+    }
+
+    /// Replaces all MIDI events of this take with the given sequence in one go (creating an undo
+    /// point).
+    pub fn set_midi_sequence(&self, sequence: &MidiSequence) -> ReaperResult<()> {
+        let packed = sequence.to_packed_events();
+        let op = || {
+            let reaper = Reaper::get().medium_reaper();
+            unsafe {
+                reaper.midi_set_all_evts(self.raw(), &packed)?;
+                reaper.midi_sort(self.raw());
+            }
+            Ok(())
+        };
+        match self.item() {
+            None => op(),
+            Some(item) => item.undoable("Edit MIDI events", || {
+                op()?;
+                item.update_in_project();
+                Ok(())
+            }),
+        }
+    }
+
     pub fn fx_chain(&self) -> FxChain {
         FxChain::from_take(*self)
     }
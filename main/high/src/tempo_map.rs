@@ -0,0 +1,131 @@
+use crate::{Project, Reaper, ReaperResult};
+use reaper_medium::{
+    Bpm, MeasureMode, PositionInBeats, PositionInQuarterNotes, PositionInSeconds,
+    TempoTimeSigMarker, TimeMap2TimeToBeatsResult, TimeSignature,
+};
+
+/// The tempo map of a project, consisting of tempo/time signature markers.
+///
+/// Editing functions create undo points.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TempoMap {
+    project: Project,
+}
+
+impl TempoMap {
+    pub(crate) fn new(project: Project) -> TempoMap {
+        TempoMap { project }
+    }
+
+    pub fn project(&self) -> Project {
+        self.project
+    }
+
+    pub fn marker_count(&self) -> u32 {
+        self.project.count_tempo_time_sig_markers()
+    }
+
+    pub fn markers(&self) -> impl ExactSizeIterator<Item = TempoTimeSigMarker> + '_ {
+        (0..self.marker_count()).map(move |i| {
+            self.marker_by_index(i)
+                .expect("tempo marker index out of range")
+        })
+    }
+
+    pub fn marker_by_index(&self, index: u32) -> Option<TempoTimeSigMarker> {
+        Reaper::get()
+            .medium_reaper()
+            .get_tempo_time_sig_marker(self.project.context(), index)
+            .ok()
+    }
+
+    /// Inserts a new tempo/time signature marker.
+    pub fn insert_marker(
+        &self,
+        position: PositionInSeconds,
+        tempo: Bpm,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperResult<()> {
+        self.edit("Insert tempo/time signature marker", || {
+            Reaper::get().medium_reaper().set_tempo_time_sig_marker(
+                self.project.context(),
+                None,
+                position,
+                tempo,
+                time_signature,
+                linear_tempo,
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Replaces the tempo/time signature marker at the given index.
+    pub fn set_marker(
+        &self,
+        index: u32,
+        position: PositionInSeconds,
+        tempo: Bpm,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperResult<()> {
+        self.edit("Edit tempo/time signature marker", || {
+            Reaper::get().medium_reaper().set_tempo_time_sig_marker(
+                self.project.context(),
+                Some(index),
+                position,
+                tempo,
+                time_signature,
+                linear_tempo,
+            )?;
+            Ok(())
+        })
+    }
+
+    pub fn delete_marker(&self, index: u32) -> ReaperResult<()> {
+        self.edit("Delete tempo/time signature marker", || {
+            Reaper::get()
+                .medium_reaper()
+                .delete_tempo_time_sig_marker(self.project.context(), index)?;
+            Ok(())
+        })
+    }
+
+    /// Returns the tempo at the given position, taking linear tempo changes into account.
+    pub fn tempo_at(&self, position: PositionInSeconds) -> Bpm {
+        Reaper::get()
+            .medium_reaper()
+            .time_map_2_get_divided_bpm_at_time(self.project.context(), position)
+    }
+
+    pub fn time_to_beats(&self, position: PositionInSeconds) -> TimeMap2TimeToBeatsResult {
+        self.project.beat_info_at(position)
+    }
+
+    /// Converts the given position in beats since project start to time.
+    pub fn beats_to_time(&self, position: PositionInBeats) -> PositionInSeconds {
+        Reaper::get().medium_reaper().time_map_2_beats_to_time(
+            self.project.context(),
+            MeasureMode::IgnoreMeasure,
+            position,
+        )
+    }
+
+    pub fn time_to_qn(&self, position: PositionInSeconds) -> PositionInQuarterNotes {
+        Reaper::get()
+            .medium_reaper()
+            .time_map_2_time_to_qn(self.project.context(), position)
+    }
+
+    pub fn qn_to_time(&self, position: PositionInQuarterNotes) -> PositionInSeconds {
+        Reaper::get()
+            .medium_reaper()
+            .time_map_2_qn_to_time(self.project.context(), position)
+    }
+
+    fn edit(&self, label: &'static str, op: impl FnOnce() -> ReaperResult<()>) -> ReaperResult<()> {
+        let result = self.project.undoable(label, op);
+        Reaper::get().medium_reaper().update_timeline();
+        result
+    }
+}
@@ -6,7 +6,8 @@ use crate::guid::Guid;
 use crate::track_route::TrackRoute;
 
 use crate::{
-    Chunk, ChunkRegion, Item, Pan, Project, Reaper, SendPartnerType, TrackRoutePartner, Width,
+    Chunk, ChunkRegion, Envelope, Item, Pan, Project, Reaper, SendPartnerType, TrackRoutePartner,
+    Width,
 };
 
 use crate::error::ReaperResult;
@@ -49,6 +50,8 @@ pub struct Track {
     // b) guid, mediaTrack (guid-based and loaded)
     // TODO-low This is not super cheap to copy. Do we really need to initialize this eagerly?
     guid: Guid,
+    /// Track cache generation in which the media track has been found valid the last time.
+    validated_generation: Cell<Option<u64>>,
 }
 
 unsafe impl Send for Track {}
@@ -71,6 +74,7 @@ impl Track {
             // them because I can neither compare MediaTrack* pointers nor GUIDs. Except
             // I extract the GUID eagerly.
             guid: get_media_track_guid(media_track),
+            validated_generation: Cell::new(None),
         }
     }
 
@@ -79,6 +83,7 @@ impl Track {
             media_track: Cell::new(None),
             rea_project: Cell::new(Some(project.raw())),
             guid,
+            validated_generation: Cell::new(None),
         }
     }
 
@@ -566,6 +571,69 @@ impl Track {
         }
     }
 
+    pub fn folder_state(&self) -> TrackFolderState {
+        TrackFolderState::from_depth_change(self.folder_depth_change())
+    }
+
+    /// Sets the folder state (`I_FOLDERDEPTH`).
+    ///
+    /// Be aware that this changes the hierarchy of all following tracks as well.
+    pub fn set_folder_state(&self, state: TrackFolderState) -> ReaperResult<()> {
+        self.load_and_check_if_necessary_or_err()?;
+        unsafe {
+            Reaper::get().medium_reaper().set_media_track_info_value(
+                self.raw_internal(),
+                TrackAttributeKey::FolderDepth,
+                state.to_depth_change() as f64,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.folder_state() == TrackFolderState::FolderParent
+    }
+
+    /// Returns the number of folders which contain this track (0 for top-level tracks).
+    pub fn folder_depth(&self) -> u32 {
+        iter::successors(self.parent(), |t| t.parent()).count() as u32
+    }
+
+    /// Returns the folder track which directly contains this track.
+    pub fn parent(&self) -> Option<Track> {
+        self.load_and_check_if_necessary_or_err().ok()?;
+        let raw_parent = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_set_media_track_info_get_par_track(self.raw_internal())?
+        };
+        Some(Track::new(raw_parent, self.rea_project.get()))
+    }
+
+    /// Returns the tracks which are direct children of this folder track.
+    ///
+    /// Returns an empty vector if this track is not a folder.
+    pub fn children(&self) -> Vec<Track> {
+        let index = match self.index() {
+            Some(i) if self.is_folder() => i,
+            _ => return vec![],
+        };
+        let following_tracks = self.project().tracks().skip(index as usize + 1);
+        let mut children = vec![];
+        // Relative depth, 1 means direct child
+        let mut depth = 1;
+        for track in following_tracks {
+            if depth == 1 {
+                children.push(track.clone());
+            }
+            depth += track.folder_depth_change();
+            if depth <= 0 {
+                break;
+            }
+        }
+        children
+    }
+
     pub fn channel_count(&self) -> u32 {
         if self.load_and_check_if_necessary_or_err().is_err() {
             return 0;
@@ -845,6 +913,18 @@ impl Track {
         }
     }
 
+    /// Sets the recording input and arms this track for recording (without gang or grouping).
+    pub fn arm_with_input(&self, input: RecordingInput) -> ReaperResult<()> {
+        self.load_and_check_if_necessary_or_err()?;
+        self.set_recording_input(Some(input));
+        self.arm(
+            false,
+            GangBehavior::DenyGang,
+            GroupingBehavior::PreventGrouping,
+        );
+        Ok(())
+    }
+
     // If supportAutoArm is false, auto-arm mode is disabled if it has been enabled before
     pub fn disarm(
         &self,
@@ -1535,25 +1615,48 @@ impl Track {
         }
     }
 
+    /// Checks validity using REAPER, so it's safe to pass the media track to REAPER afterwards.
+    ///
     /// Precondition: mediaTrack_ must be filled!
     fn is_valid(&self) -> bool {
+        self.is_valid_internal(false)
+    }
+
+    /// Like [`Self::is_valid()`] but trusts a validity check which has been done in the current
+    /// track cache generation already.
+    ///
+    /// The result is good enough for answering queries but must not be relied upon before passing
+    /// the media track to REAPER: Tracks removed without notifying control surfaces don't
+    /// increment the generation.
+    ///
+    /// Precondition: mediaTrack_ must be filled!
+    fn is_valid_cached(&self) -> bool {
+        self.is_valid_internal(true)
+    }
+
+    fn is_valid_internal(&self, use_cache: bool) -> bool {
         let media_track = match self.media_track.get() {
             None => panic!("Track can not be validated if mediaTrack not available"),
             Some(t) => t,
         };
         self.attempt_to_fill_project_if_necessary();
-        match self.rea_project.get() {
-            None => false,
-            Some(rea_project) => {
-                if Project::new(rea_project).is_available() {
-                    Reaper::get()
-                        .medium_reaper()
-                        .validate_ptr_2(Proj(rea_project), media_track)
-                } else {
-                    false
-                }
-            }
+        let Some(rea_project) = self.rea_project.get() else {
+            return false;
+        };
+        if !Project::new(rea_project).is_available() {
+            return false;
+        }
+        let reaper = Reaper::get();
+        let generation = reaper.track_cache().generation();
+        if use_cache && self.validated_generation.get() == Some(generation) {
+            return true;
         }
+        let valid = reaper
+            .medium_reaper()
+            .validate_ptr_2(Proj(rea_project), media_track);
+        self.validated_generation
+            .set(if valid { Some(generation) } else { None });
+        valid
     }
 
     /// Precondition: mediaTrack_ must be filled!
@@ -1575,6 +1678,7 @@ impl Track {
                 .medium_reaper()
                 .get_set_media_track_info_set_guid(self.raw_internal(), &guid.to_raw());
         }
+        Reaper::get().track_cache().invalidate();
     }
 
     fn load_by_guid(&self) -> bool {
@@ -1582,18 +1686,13 @@ impl Track {
             panic!("For loading per GUID, a project must be given");
         }
         // TODO-low Don't save ReaProject but Project as member
-        let guid = self.guid();
-        let track = self.project_unchecked().tracks().find(|t| t.guid() == guid);
-        match track {
-            Some(t) => {
-                self.media_track.replace(Some(t.raw_unchecked()));
-                true
-            }
-            None => {
-                self.media_track.replace(None);
-                false
-            }
-        }
+        let track_cache = Reaper::get().track_cache();
+        let media_track = track_cache.find_by_guid(self.project_unchecked(), self.guid());
+        self.media_track.replace(media_track);
+        // The GUID index has just been built or confirmed in the current generation
+        self.validated_generation
+            .set(media_track.map(|_| track_cache.generation()));
+        media_track.is_some()
     }
 
     pub fn is_available(&self) -> bool {
@@ -1602,7 +1701,7 @@ impl Track {
             self.load_by_guid()
         } else {
             // Loaded
-            self.is_valid()
+            self.is_valid_cached()
         }
     }
 
@@ -1675,6 +1774,33 @@ impl Track {
         }
     }
 
+    /// Returns all envelopes of this track, including FX parameter envelopes.
+    pub fn envelopes(&self) -> impl Iterator<Item = Envelope> + '_ {
+        if self.load_and_check_if_necessary_or_err().is_err() {
+            return Either::Left(iter::empty());
+        }
+        let raw = self.raw_internal();
+        let reaper = Reaper::get().medium_reaper();
+        let count = unsafe { reaper.count_track_envelopes(raw) };
+        // The envelope count might change while iterating
+        let iter = (0..count).filter_map(move |i| {
+            let raw_envelope = unsafe { reaper.get_track_envelope(raw, i)? };
+            Some(Envelope::new(raw_envelope, self.clone()))
+        });
+        Either::Right(iter)
+    }
+
+    /// Returns the track envelope with the given display name (e.g. "Volume").
+    pub fn envelope_by_name<'a>(&self, name: impl Into<ReaperStringArg<'a>>) -> Option<Envelope> {
+        self.load_and_check_if_necessary_or_err().ok()?;
+        let raw = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_track_envelope_by_name(self.raw_internal(), name)?
+        };
+        Some(Envelope::new(raw, self.clone()))
+    }
+
     pub fn normal_fx_chain(&self) -> FxChain {
         FxChain::from_track(self.clone(), false)
     }
@@ -1767,6 +1893,37 @@ pub enum GroupingBehavior {
     UseGrouping,
 }
 
+/// Folder state of a track, determining the track hierarchy.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TrackFolderState {
+    /// Normal track, neither opening nor closing a folder.
+    Normal,
+    /// Track is a folder parent.
+    FolderParent,
+    /// Track is the last track in the given number of innermost folders (at least 1).
+    LastInFolders(u32),
+}
+
+impl TrackFolderState {
+    fn from_depth_change(depth_change: i32) -> Self {
+        use TrackFolderState::*;
+        match depth_change {
+            x if x > 0 => FolderParent,
+            0 => Normal,
+            x => LastInFolders(x.unsigned_abs()),
+        }
+    }
+
+    fn to_depth_change(self) -> i32 {
+        use TrackFolderState::*;
+        match self {
+            Normal => 0,
+            FolderParent => 1,
+            LastInFolders(n) => -(n as i32),
+        }
+    }
+}
+
 pub struct TrackSetSmartOpts {
     pub gang_behavior: GangBehavior,
     pub grouping_behavior: GroupingBehavior,
@@ -0,0 +1,78 @@
+use crate::{Guid, Project, Reaper};
+use reaper_medium::{MediaTrack, ReaProject};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// Caches track lookups by GUID and track validity checks.
+///
+/// Both scanning a project for a GUID and validating a track pointer are linear in the number of
+/// tracks. Surfaces which touch hundreds of tracks per main loop cycle would otherwise spend most
+/// of their time doing that. Cached results stay valid until REAPER reports a change of the track
+/// list, which increments the generation.
+#[derive(Debug, Default)]
+pub(crate) struct TrackCache {
+    generation: Cell<u64>,
+    guid_indexes: RefCell<HashMap<ReaProject, GuidIndex>>,
+}
+
+#[derive(Debug)]
+struct GuidIndex {
+    generation: u64,
+    tracks: HashMap<Guid, MediaTrack>,
+}
+
+impl TrackCache {
+    /// Returns the current generation. Track availability checked within the same generation
+    /// doesn't need to be checked again. Before passing a track to REAPER, it's still validated.
+    pub fn generation(&self) -> u64 {
+        self.generation.get()
+    }
+
+    /// Must be called whenever tracks have been added, removed or reordered.
+    pub fn invalidate(&self) {
+        self.generation.set(self.generation.get() + 1);
+        self.guid_indexes.borrow_mut().clear();
+    }
+
+    /// Looks up the track with the given GUID, building the GUID index of the project if
+    /// necessary.
+    pub fn find_by_guid(&self, project: Project, guid: &Guid) -> Option<MediaTrack> {
+        let generation = self.generation();
+        let mut guid_indexes = self.guid_indexes.borrow_mut();
+        let index = guid_indexes
+            .entry(project.raw())
+            .or_insert_with(|| GuidIndex {
+                generation,
+                tracks: build_guid_index(project),
+            });
+        if index.generation != generation {
+            *index = GuidIndex {
+                generation,
+                tracks: build_guid_index(project),
+            };
+        }
+        index.tracks.get(guid).copied()
+    }
+}
+
+fn build_guid_index(project: Project) -> HashMap<Guid, MediaTrack> {
+    project
+        .tracks()
+        .map(|t| (*t.guid(), t.raw_unchecked()))
+        .collect()
+}
+
+impl Reaper {
+    /// Makes sure that cached track lookups are refreshed.
+    ///
+    /// This happens automatically when REAPER notifies control surfaces about track list changes.
+    /// Only call this if you add, remove or reorder tracks in a way which doesn't notify control
+    /// surfaces and keep using track handles right after that.
+    pub fn invalidate_track_cache(&self) {
+        self.track_cache.invalidate();
+    }
+
+    pub(crate) fn track_cache(&self) -> &TrackCache {
+        &self.track_cache
+    }
+}
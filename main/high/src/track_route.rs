@@ -2,8 +2,10 @@ use crate::{Pan, Reaper, Track};
 
 use crate::error::ReaperResult;
 use reaper_medium::{
-    AutomationMode, EditMode, MediaTrack, ReaperFunctionError, ReaperString, ReaperVolumeValue,
-    TrackSendAttributeKey, TrackSendCategory, TrackSendDirection, TrackSendRef, VolumeAndPan,
+    AutomationMode, EditMode, MediaTrack, ReaperFunctionError, ReaperPanValue, ReaperString,
+    ReaperVolumeValue, TrackSendAttributeKey, TrackSendCategory, TrackSendDestinationChannels,
+    TrackSendDirection, TrackSendMode, TrackSendRef, TrackSendSourceChannels, ValueChange,
+    VolumeAndPan,
 };
 use std::fmt;
 use TrackSendDirection::*;
@@ -116,6 +118,25 @@ impl TrackRoute {
         }
     }
 
+    /// Changes the volume and informs control surfaces about it, also supports relative changes.
+    ///
+    /// Returns the resulting volume.
+    pub fn csurf_on_volume_change(
+        &self,
+        value_change: ValueChange<ReaperVolumeValue>,
+    ) -> ReaperResult<ReaperVolumeValue> {
+        self.check_available()?;
+        let reaper = Reaper::get().medium_reaper();
+        let track = self.track().raw_unchecked();
+        let value = match self.direction {
+            Send => unsafe { reaper.csurf_on_send_volume_change(track, self.index, value_change) },
+            Receive => unsafe {
+                reaper.csurf_on_recv_volume_change(track, self.index, value_change)
+            },
+        };
+        Ok(value)
+    }
+
     fn category_with_index(&self) -> (TrackSendCategory, u32) {
         match self.direction {
             Receive => (TrackSendCategory::Receive, self.index),
@@ -138,18 +159,17 @@ impl TrackRoute {
     }
 
     pub fn name(&self) -> ReaperString {
-        const BUFFER_SIZE: u32 = 256;
         match self.direction {
             Send => unsafe {
                 Reaper::get()
                     .medium_reaper()
-                    .get_track_send_name(self.track().raw_unchecked(), self.index, BUFFER_SIZE)
+                    .get_track_send_name_auto(self.track().raw_unchecked(), self.index)
                     .expect("send doesn't exist")
             },
             Receive => unsafe {
                 Reaper::get()
                     .medium_reaper()
-                    .get_track_receive_name(self.track().raw_unchecked(), self.index, BUFFER_SIZE)
+                    .get_track_receive_name_auto(self.track().raw_unchecked(), self.index)
                     .expect("receive doesn't exist")
             },
         }
@@ -171,6 +191,23 @@ impl TrackRoute {
         Ok(())
     }
 
+    /// Changes the pan and informs control surfaces about it, also supports relative changes.
+    ///
+    /// Returns the resulting pan.
+    pub fn csurf_on_pan_change(
+        &self,
+        value_change: ValueChange<ReaperPanValue>,
+    ) -> ReaperResult<Pan> {
+        self.check_available()?;
+        let reaper = Reaper::get().medium_reaper();
+        let track = self.track().raw_unchecked();
+        let value = match self.direction {
+            Send => unsafe { reaper.csurf_on_send_pan_change(track, self.index, value_change) },
+            Receive => unsafe { reaper.csurf_on_recv_pan_change(track, self.index, value_change) },
+        };
+        Ok(Pan::from_reaper_value(value))
+    }
+
     pub fn is_muted(&self) -> ReaperResult<bool> {
         let res = match self.direction {
             Send => unsafe {
@@ -195,7 +232,7 @@ impl TrackRoute {
         self.set_muted(false)
     }
 
-    fn set_muted(&self, muted: bool) -> ReaperResult<()> {
+    pub fn set_muted(&self, muted: bool) -> ReaperResult<()> {
         if self.is_muted()? != muted {
             unsafe {
                 Reaper::get()
@@ -222,6 +259,36 @@ impl TrackRoute {
         self.set_prop_enabled(TrackSendAttributeKey::Phase, inverted)
     }
 
+    pub fn send_mode(&self) -> TrackSendMode {
+        let raw_mode = self.prop_numeric_value(TrackSendAttributeKey::SendMode) as i32;
+        TrackSendMode::from_raw(raw_mode)
+    }
+
+    pub fn set_send_mode(&self, mode: TrackSendMode) -> ReaperResult<()> {
+        self.set_prop_numeric_value(TrackSendAttributeKey::SendMode, mode.to_raw() as _)
+    }
+
+    pub fn source_channels(&self) -> TrackSendSourceChannels {
+        let raw = self.prop_numeric_value(TrackSendAttributeKey::SrcChan) as i32;
+        TrackSendSourceChannels::from_raw(raw)
+    }
+
+    pub fn set_source_channels(&self, channels: TrackSendSourceChannels) -> ReaperResult<()> {
+        self.set_raw_src_channel(channels.to_raw())
+    }
+
+    pub fn destination_channels(&self) -> TrackSendDestinationChannels {
+        let raw = self.prop_numeric_value(TrackSendAttributeKey::DstChan) as i32;
+        TrackSendDestinationChannels::from_raw(raw)
+    }
+
+    pub fn set_destination_channels(
+        &self,
+        channels: TrackSendDestinationChannels,
+    ) -> ReaperResult<()> {
+        self.set_prop_numeric_value(TrackSendAttributeKey::DstChan, channels.to_raw() as _)
+    }
+
     pub fn set_raw_midi_flags(&self, flags: u32) -> ReaperResult<()> {
         self.set_prop_numeric_value(TrackSendAttributeKey::MidiFlags, flags as _)
     }
@@ -288,6 +355,13 @@ impl TrackRoute {
         }
     }
 
+    fn check_available(&self) -> ReaperResult<()> {
+        if !self.is_available() {
+            return Err("track route not available".into());
+        }
+        Ok(())
+    }
+
     fn index_is_in_range(&self) -> bool {
         if !self.track.is_available() {
             return false;
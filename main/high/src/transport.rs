@@ -0,0 +1,100 @@
+use crate::{PlayRate, Project, Reaper};
+use reaper_medium::{PlayState, PositionInSeconds, SetEditCurPosOptions};
+
+/// Transport controls of a project.
+///
+/// Bundles everything which is needed to control playback, e.g. from a control surface.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Transport {
+    project: Project,
+}
+
+/// Snapshot of the transport state of a project.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TransportState {
+    pub play_state: PlayState,
+    pub repeat_is_enabled: bool,
+    pub play_rate: PlayRate,
+}
+
+/// Defines how to seek.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SeekOptions {
+    /// If `true` and the project is playing, playback continues at the new position only when the
+    /// current measure or region has finished (REAPER's "smooth seek").
+    pub smooth: bool,
+    /// Whether to scroll the arrange view so that the new position is visible.
+    pub move_view: bool,
+}
+
+impl Transport {
+    pub(crate) fn new(project: Project) -> Transport {
+        Transport { project }
+    }
+
+    pub fn project(&self) -> Project {
+        self.project
+    }
+
+    pub fn state(&self) -> TransportState {
+        TransportState {
+            play_state: self.project.play_state(),
+            repeat_is_enabled: self.project.repeat_is_enabled(),
+            play_rate: self.project.play_rate(),
+        }
+    }
+
+    pub fn play(&self) {
+        self.project.play();
+    }
+
+    pub fn stop(&self) {
+        self.project.stop();
+    }
+
+    /// Pauses playback. Doesn't toggle!
+    pub fn pause(&self) {
+        self.project.pause();
+    }
+
+    /// Starts recording (if not already recording).
+    ///
+    /// Only has an effect if this is the current project.
+    pub fn record(&self) {
+        if self.project != Reaper::get().current_project() || self.project.is_recording() {
+            return;
+        }
+        Reaper::get().medium_reaper().csurf_on_record();
+    }
+
+    pub fn toggle_repeat(&self) {
+        if self.project.repeat_is_enabled() {
+            self.project.disable_repeat();
+        } else {
+            self.project.enable_repeat();
+        }
+    }
+
+    pub fn play_rate(&self) -> PlayRate {
+        self.project.play_rate()
+    }
+
+    /// Sets the play rate.
+    ///
+    /// Due to REAPER API limitations, this always affects the current project.
+    pub fn set_play_rate(&self, play_rate: PlayRate) {
+        self.project.set_play_rate(play_rate);
+    }
+
+    /// Moves the edit cursor to the given position and, if playing, the play cursor as well.
+    pub fn seek(&self, position: PositionInSeconds, options: SeekOptions) {
+        let edit_cur_pos_options = SetEditCurPosOptions {
+            move_view: options.move_view,
+            seek_play: true,
+        };
+        Reaper::get().with_smooth_seek(options.smooth, || {
+            self.project
+                .set_edit_cursor_position(position, edit_cur_pos_options);
+        });
+    }
+}
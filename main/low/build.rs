@@ -10,6 +10,7 @@ fn main() {
     #[cfg(target_family = "unix")]
     compile_swell_dialog_generator_support();
 
+    #[cfg(feature = "cpp-glue")]
     compile_glue_code();
 }
 
@@ -39,6 +40,7 @@ fn compile_swell_dialog_generator_support() {
 
 /// Compiles C++ glue code. This is necessary to interact with those parts of the REAPER C++ API
 /// that use pure virtual interface classes and therefore the C++ ABI.
+#[cfg(feature = "cpp-glue")]
 fn compile_glue_code() {
     let mut build = cc::Build::new();
     build
@@ -826,10 +828,7 @@ mod codegen {
             syn::parse_quote! {
                 {
                     match self.pointers.#name {
-                        None => panic!(
-                            "Attempt to use a function that has not been loaded: {}",
-                            stringify!(#name)
-                        ),
+                        None => crate::function_not_available(stringify!(#name)),
                         Some(f) => #fn_ptr_call,
                     }
                 }
@@ -0,0 +1,21 @@
+use crate::PluginContext;
+
+/// Functions exposed by another extension, declared via [`extension_functions!`].
+///
+/// [`extension_functions!`]: macro.extension_functions.html
+pub trait ExtensionFunctions: Sized {
+    /// Names of all declared functions.
+    const FUNCTION_NAMES: &'static [&'static str];
+
+    /// Looks up all declared functions. Functions which are not available stay `None`.
+    fn load(plugin_context: &PluginContext) -> Self;
+
+    /// Returns the number of functions which have been found.
+    fn loaded_count(&self) -> u32;
+
+    /// Returns whether all declared functions have been found, e.g. because the exposing extension
+    /// is installed in a compatible version.
+    fn is_available(&self) -> bool {
+        self.loaded_count() as usize == Self::FUNCTION_NAMES.len()
+    }
+}
@@ -60,6 +60,10 @@
 //!       function → Rust function)
 //!     - Example: `control_surface.cpp` & `control_surface.rs`
 //!
+//! The glue code is compiled only if the `cpp-glue` feature is enabled (it is by default). If you
+//! only need plain function calls and want to build without C++ toolchain, disable default
+//! features. This skips the glue code and the APIs depending on it.
+//!
 //! [medium-level]: https://docs.rs/reaper-medium
 //! [`Reaper`]: struct.Reaper.html
 #[macro_use]
@@ -69,7 +73,9 @@ mod bindings;
 
 pub mod raw;
 
+#[cfg(feature = "cpp-glue")]
 mod control_surface;
+#[cfg(feature = "cpp-glue")]
 pub use control_surface::*;
 
 mod util;
@@ -83,6 +89,12 @@ pub use reaper::*;
 
 mod reaper_impl;
 
+mod registration;
+pub use registration::*;
+
+mod extension_functions;
+pub use extension_functions::*;
+
 mod swell;
 pub use swell::*;
 
@@ -91,24 +103,38 @@ pub use static_context::*;
 
 mod swell_impl;
 
+#[cfg(feature = "cpp-glue")]
 mod midi;
+#[cfg(feature = "cpp-glue")]
 pub use midi::*;
 
+#[cfg(feature = "cpp-glue")]
 mod resample;
+#[cfg(feature = "cpp-glue")]
 pub use resample::*;
 
+#[cfg(feature = "cpp-glue")]
 mod pitch_shift;
+#[cfg(feature = "cpp-glue")]
 pub use pitch_shift::*;
 
+#[cfg(feature = "cpp-glue")]
 mod pcm_source;
+#[cfg(feature = "cpp-glue")]
 pub use pcm_source::*;
 
+#[cfg(feature = "cpp-glue")]
 mod pcm_sink;
+#[cfg(feature = "cpp-glue")]
 pub use pcm_sink::*;
 
+#[cfg(feature = "cpp-glue")]
 mod project_state_context;
+#[cfg(feature = "cpp-glue")]
 pub use project_state_context::*;
 
 mod file_in_project_callback;
 
+mod project_config_extension;
+
 mod gaccel_register;
@@ -28,6 +28,41 @@ macro_rules! reaper_vst_plugin {
     };
 }
 
+/// Macro which gathers things that go into the static REAPER CLAP plug-in context.
+///
+/// This is the CLAP counterpart of [`reaper_vst_plugin!`]. It provides the module entry points
+/// which gather the handles exposed via [`static_plugin_context()`]. Pass them together with the
+/// `clap_host` pointer (available when the CLAP plug-in gets created) to
+/// [`PluginContext::from_clap_plugin()`].
+///
+/// # Example
+///
+/// ```
+/// use reaper_low::{reaper_clap_plugin, static_plugin_context, PluginContext};
+///
+/// reaper_clap_plugin!();
+///
+/// // Usually called in the CLAP plug-in factory's `create_plugin` function
+/// unsafe fn create_reaper_context(
+///     host: *const std::os::raw::c_void,
+/// ) -> Option<PluginContext> {
+///     PluginContext::from_clap_plugin(host, static_plugin_context()).ok()
+/// }
+/// ```
+///
+/// [`PluginContext::from_clap_plugin()`]:
+/// struct.PluginContext.html#method.from_clap_plugin
+/// [`static_plugin_context()`]: fn.static_plugin_context.html
+#[macro_export]
+macro_rules! reaper_clap_plugin {
+    () => {
+        mod reaper_clap_plugin {
+            reaper_low::dll_main!();
+            reaper_low::swell_dll_main!();
+        }
+    };
+}
+
 /// Macro which generates and exports the `DllMain` function on Windows.
 #[macro_export]
 macro_rules! dll_main {
@@ -107,3 +142,111 @@ macro_rules! swell_dll_main {
         }
     };
 }
+
+/// Macro which declares a struct for loading functions exposed by other extensions.
+///
+/// Extensions such as SWS, js_ReaScriptAPI or ReaImGui expose their functions via
+/// `plugin_register("API_...")`. This macro generates a struct with one function pointer per
+/// declared function (`None` if not available), a [`ExtensionFunctions`] implementation for loading
+/// them and one unsafe method per function for calling it.
+///
+/// The declared signatures are not checked against anything. Make sure they match the ones of the
+/// exposing extension (usually documented in its header file).
+///
+/// # Example
+///
+/// ```
+/// use reaper_low::{extension_functions, ExtensionFunctions, PluginContext};
+/// use std::os::raw::{c_char, c_int};
+///
+/// extension_functions! {
+///     /// Functions exposed by SWS.
+///     pub struct SwsFunctions {
+///         fn CF_GetSWSVersion(buf: *mut c_char, buf_sz: c_int);
+///         fn BR_GetMouseCursorContext_Position() -> f64;
+///     }
+/// }
+///
+/// fn sws_version(context: &PluginContext) -> Option<String> {
+///     let sws = SwsFunctions::load(context);
+///     if !sws.is_available() {
+///         return None;
+///     }
+///     let mut buf = [0 as c_char; 32];
+///     unsafe {
+///         sws.CF_GetSWSVersion(buf.as_mut_ptr(), buf.len() as _);
+///         let version = std::ffi::CStr::from_ptr(buf.as_ptr());
+///         Some(version.to_string_lossy().into_owned())
+///     }
+/// }
+/// ```
+///
+/// [`ExtensionFunctions`]: trait.ExtensionFunctions.html
+#[macro_export]
+macro_rules! extension_functions {
+    (
+        $(#[$struct_meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$fn_meta:meta])*
+                fn $fn_name:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)?;
+            )*
+        }
+    ) => {
+        $(#[$struct_meta])*
+        #[allow(non_snake_case)]
+        #[derive(Copy, Clone, Default)]
+        $vis struct $name {
+            $(
+                pub $fn_name: ::std::option::Option<unsafe extern "C" fn($($arg_ty),*) $(-> $ret)?>,
+            )*
+        }
+
+        #[allow(non_snake_case, clippy::too_many_arguments)]
+        impl $name {
+            $(
+                $(#[$fn_meta])*
+                ///
+                /// # Panics
+                ///
+                /// Panics if the function is not available.
+                ///
+                /// # Safety
+                ///
+                /// REAPER can crash if you pass an invalid pointer.
+                pub unsafe fn $fn_name(&self, $($arg: $arg_ty),*) $(-> $ret)? {
+                    match self.$fn_name {
+                        None => $crate::function_not_available(stringify!($fn_name)),
+                        Some(f) => f($($arg),*),
+                    }
+                }
+            )*
+        }
+
+        impl $crate::ExtensionFunctions for $name {
+            const FUNCTION_NAMES: &'static [&'static str] = &[$(stringify!($fn_name)),*];
+
+            fn load(plugin_context: &$crate::PluginContext) -> Self {
+                unsafe {
+                    Self {
+                        $(
+                            $fn_name: ::std::mem::transmute::<
+                                *mut ::std::os::raw::c_void,
+                                ::std::option::Option<
+                                    unsafe extern "C" fn($($arg_ty),*) $(-> $ret)?
+                                >,
+                            >(plugin_context.GetFunc(
+                                concat!(stringify!($fn_name), "\0").as_ptr() as _,
+                            )),
+                        )*
+                    }
+                }
+            }
+
+            fn loaded_count(&self) -> u32 {
+                let loaded: &[bool] = &[$(self.$fn_name.is_some()),*];
+                loaded.iter().filter(|l| **l).count() as u32
+            }
+        }
+    };
+}
@@ -38,6 +38,13 @@ pub enum TypeSpecificPluginContext {
     Extension(ExtensionPluginContext),
     /// This is a VST plug-in.
     Vst(VstPluginContext),
+    /// This is a CLAP plug-in.
+    ///
+    /// REAPER hands out the same plug-in info to CLAP plug-ins as to extension plug-ins.
+    Clap(ExtensionPluginContext),
+    /// This is a plug-in of some other type which obtained REAPER's function provider in a
+    /// host-specific way.
+    Other(OtherPluginContext),
 }
 
 impl TypeSpecificPluginContext {
@@ -50,6 +57,16 @@ impl TypeSpecificPluginContext {
     pub fn is_vst(&self) -> bool {
         matches!(self, Self::Vst(_))
     }
+
+    /// Returns `true` if this is a CLAP plug-in context.
+    pub fn is_clap(&self) -> bool {
+        matches!(self, Self::Clap(_))
+    }
+
+    /// Returns `true` if this is a context of some other plug-in type.
+    pub fn is_other(&self) -> bool {
+        matches!(self, Self::Other(_))
+    }
 }
 
 /// Additional data available in the context of extension plug-ins.
@@ -67,6 +84,12 @@ pub struct VstPluginContext {
     host_callback: HostCallbackProc,
 }
 
+/// Additional data available in the context of other plug-in types.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct OtherPluginContext {
+    get_func: GetFunc,
+}
+
 impl PluginContext {
     /// Creates a plug-in context from an extension entry point plug-in info.
     ///
@@ -148,6 +171,73 @@ impl PluginContext {
         })
     }
 
+    /// Creates a plug-in context from a CLAP host.
+    ///
+    /// It requires the `clap_host` pointer which the host passes to the `create_plugin` function
+    /// of the CLAP plug-in factory. REAPER's plug-in info is queried via the host extension
+    /// `cockos.reaper_extension`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host doesn't provide REAPER's plug-in info (e.g. because it's not
+    /// REAPER) or if the plug-in info is not suitable for loading REAPER functions.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn from_clap_plugin(
+        host: *const c_void,
+        static_context: StaticPluginContext,
+    ) -> Result<PluginContext, ContextFromClapPluginError> {
+        use ContextFromClapPluginError::*;
+        let host = (host as *const ClapHost).as_ref().ok_or(HostNotAvailable)?;
+        let get_extension = host.get_extension.ok_or(HostNotAvailable)?;
+        let rec = get_extension(host, CLAP_REAPER_EXTENSION_ID.as_ptr() as *const c_char)
+            as *const raw::reaper_plugin_info_t;
+        let rec = rec.as_ref().ok_or(ReaperExtensionNotAvailable)?;
+        if rec.caller_version != raw::REAPER_PLUGIN_VERSION as c_int {
+            return Err(CallerVersionIncompatible);
+        }
+        let get_func = rec.GetFunc.ok_or(FunctionProviderNotAvailable)?;
+        let register = rec.Register.ok_or(RegisterFunctionNotAvailable)?;
+        Ok(PluginContext {
+            type_specific: TypeSpecificPluginContext::Clap(ExtensionPluginContext {
+                caller_version: rec.caller_version,
+                hwnd_main: rec.hwnd_main,
+                register,
+                get_func,
+            }),
+            h_instance: static_context.h_instance,
+            get_swell_func_ptr: static_context.get_swell_func,
+            main_thread_id: std::thread::current().id(),
+        })
+    }
+
+    /// Creates a plug-in context from a REAPER function provider which has been obtained in a
+    /// host-specific way.
+    ///
+    /// This is for plug-in types which don't have a dedicated constructor, e.g. VST 3 plug-ins
+    /// (which can look up functions via `IReaperHostApplication::getReaperApi()`) or VST 2 plug-ins
+    /// not built with the [vst-rs](https://crates.io/crates/vst) crate. The given function must
+    /// behave like `GetFunc` in `reaper_plugin_info_t`: returning the function with the given name
+    /// or null if it doesn't exist. The static context is obtained as usual, e.g. via
+    /// [`reaper_vst_plugin!`] and [`static_plugin_context()`].
+    ///
+    /// This must be called in the main thread.
+    ///
+    /// [`static_plugin_context()`]: fn.static_plugin_context.html
+    pub fn from_function_provider(
+        get_func: unsafe extern "C" fn(name: *const c_char) -> *mut c_void,
+        static_context: StaticPluginContext,
+    ) -> PluginContext {
+        PluginContext {
+            type_specific: TypeSpecificPluginContext::Other(OtherPluginContext { get_func }),
+            h_instance: static_context.h_instance,
+            get_swell_func_ptr: static_context.get_swell_func,
+            main_thread_id: std::thread::current().id(),
+        }
+    }
+
     /// Returns a generic API function by its name.
     ///
     /// # Safety
@@ -157,7 +247,8 @@ impl PluginContext {
     pub unsafe fn GetFunc(&self, name: *const c_char) -> *mut c_void {
         use TypeSpecificPluginContext::*;
         match &self.type_specific {
-            Extension(context) => (context.get_func)(name),
+            Extension(context) | Clap(context) => (context.get_func)(name),
+            Other(context) => (context.get_func)(name),
             Vst(context) => {
                 // Invoke host callback
                 (context.host_callback)(
@@ -172,7 +263,7 @@ impl PluginContext {
         }
     }
 
-    /// On Windows, this returns the `HINSTANCE` passed to `DllMain` (VST plug-ins) or
+    /// On Windows, this returns the `HINSTANCE` passed to `DllMain` (VST and CLAP plug-ins) or
     /// `ReaperPluginEntry` (extension plug-ins).
     ///
     /// The returned `HINSTANCE` represents the handle of the module (DLL) containing the plug-in.
@@ -196,11 +287,19 @@ impl PluginContext {
     }
 
     /// Returns whether we are currently in the main thread.
+    ///
+    /// This is cheap. The ID of the current thread is looked up once per thread and then cached.
     pub fn is_in_main_thread(&self) -> bool {
-        std::thread::current().id() == self.main_thread_id
+        CURRENT_THREAD_ID.with(|id| *id == self.main_thread_id)
     }
 }
 
+thread_local! {
+    // `std::thread::current()` clones an `Arc`, which is too expensive for a check which runs on
+    // each main-thread-only API call.
+    static CURRENT_THREAD_ID: std::thread::ThreadId = std::thread::current().id();
+}
+
 impl ExtensionPluginContext {
     /// Returns the caller version from `reaper_plugin_info_t`.
     pub fn caller_version(&self) -> c_int {
@@ -287,11 +386,51 @@ pub enum ContextFromVstPluginError {
 
 impl std::error::Error for ContextFromVstPluginError {}
 
+/// An error which can occur when attempting to create a REAPER plug-in context from a CLAP plug-in.
+#[derive(Clone, Eq, PartialEq, Debug, Display)]
+pub enum ContextFromClapPluginError {
+    /// The `clap_host` pointer or its `get_extension` function is not set.
+    #[display(fmt = "host not available")]
+    HostNotAvailable,
+    /// The host doesn't provide the `cockos.reaper_extension` extension.
+    #[display(fmt = "REAPER extension not available")]
+    ReaperExtensionNotAvailable,
+    /// `caller_version` doesn't match `REAPER_PLUGIN_VERSION`.
+    #[display(fmt = "caller version incompatible")]
+    CallerVersionIncompatible,
+    /// `GetFunc` pointer is not set.
+    #[display(fmt = "function provider not available")]
+    FunctionProviderNotAvailable,
+    /// `Register` pointer is not set.
+    #[display(fmt = "register function not available")]
+    RegisterFunctionNotAvailable,
+}
+
+impl std::error::Error for ContextFromClapPluginError {}
+
+/// ID of the CLAP host extension which provides REAPER's `reaper_plugin_info_t`.
+const CLAP_REAPER_EXTENSION_ID: &[u8] = b"cockos.reaper_extension\0";
+
+/// Beginning of the `clap_host` struct from the CLAP API (we don't need the rest).
+#[repr(C)]
+struct ClapHost {
+    clap_version: [u32; 3],
+    host_data: *mut c_void,
+    name: *const c_char,
+    vendor: *const c_char,
+    url: *const c_char,
+    version: *const c_char,
+    get_extension: Option<
+        unsafe extern "C" fn(host: *const ClapHost, extension_id: *const c_char) -> *const c_void,
+    >,
+}
+
 /// Contains those parts of the REAPER plug-in context which must be obtained from static variables.
 ///
 /// An instance of this struct is returned by the function
 /// [`crate::static_plugin_context()`] which is generated by both the
-/// `reaper_extension_plugin` and [`crate::reaper_vst_plugin`] macros.
+/// `reaper_extension_plugin`, [`crate::reaper_vst_plugin`] and [`crate::reaper_clap_plugin`]
+/// macros.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct StaticPluginContext {
     /// `HINSTANCE` representing the handle of the module (DLL) containing the plug-in.
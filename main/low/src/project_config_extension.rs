@@ -0,0 +1,31 @@
+#![allow(non_snake_case, non_camel_case_types)]
+
+use crate::raw::ProjectStateContext;
+use std::ffi::{c_char, c_void};
+
+/// This structure is defined in <https://github.com/justinfrankel/reaper-sdk/blob/main/sdk/reaper_plugin.h>
+/// (see "projectconfig") but not picked up by the generated bindings.
+///
+/// **Keeping this particular field order is vital!**
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct project_config_extension_t {
+    pub ProcessExtensionLine: Option<
+        unsafe extern "C" fn(
+            line: *const c_char,
+            ctx: *mut ProjectStateContext,
+            isUndo: bool,
+            reg: *mut project_config_extension_t,
+        ) -> bool,
+    >,
+    pub SaveExtensionConfig: Option<
+        unsafe extern "C" fn(
+            ctx: *mut ProjectStateContext,
+            isUndo: bool,
+            reg: *mut project_config_extension_t,
+        ),
+    >,
+    pub BeginLoadProjectState:
+        Option<unsafe extern "C" fn(isUndo: bool, reg: *mut project_config_extension_t)>,
+    pub userData: *mut c_void,
+}
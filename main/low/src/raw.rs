@@ -11,10 +11,12 @@ pub use super::bindings::root::{
     IReaperPitchShift, KbdCmd, KbdSectionInfo, MIDI_event_t, MIDI_eventlist, MediaItem,
     MediaItem_Take, MediaTrack, PCM_sink, PCM_source, PCM_source_peaktransfer_t,
     PCM_source_transfer_t, ProjectStateContext, REAPER_Resample_Interface, ReaProject, ReaSample,
-    TrackEnvelope, WDL_HeapBuf, CSURF_EXT_RESET, CSURF_EXT_SETBPMANDPLAYRATE,
-    CSURF_EXT_SETFOCUSEDFX, CSURF_EXT_SETFXCHANGE, CSURF_EXT_SETFXENABLED, CSURF_EXT_SETFXOPEN,
-    CSURF_EXT_SETFXPARAM, CSURF_EXT_SETFXPARAM_RECFX, CSURF_EXT_SETINPUTMONITOR,
-    CSURF_EXT_SETLASTTOUCHEDFX, CSURF_EXT_SETPAN_EX, CSURF_EXT_SETPROJECTMARKERCHANGE,
+    TrackEnvelope, WDL_HeapBuf, CSURF_EXT_MIDI_DEVICE_REMAP, CSURF_EXT_RESET,
+    CSURF_EXT_SETAUTORECARM, CSURF_EXT_SETBPMANDPLAYRATE, CSURF_EXT_SETFOCUSEDFX,
+    CSURF_EXT_SETFXCHANGE, CSURF_EXT_SETFXENABLED, CSURF_EXT_SETFXOPEN, CSURF_EXT_SETFXPARAM,
+    CSURF_EXT_SETFXPARAM_RECFX, CSURF_EXT_SETINPUTMONITOR, CSURF_EXT_SETLASTTOUCHEDFX,
+    CSURF_EXT_SETLASTTOUCHEDTRACK, CSURF_EXT_SETMETRONOME, CSURF_EXT_SETMIXERSCROLL,
+    CSURF_EXT_SETPAN_EX, CSURF_EXT_SETPROJECTMARKERCHANGE, CSURF_EXT_SETRECMODE,
     CSURF_EXT_SETRECVPAN, CSURF_EXT_SETRECVVOLUME, CSURF_EXT_SETSENDPAN, CSURF_EXT_SETSENDVOLUME,
     CSURF_EXT_SUPPORTS_EXTENDED_TOUCH, CSURF_EXT_TRACKFX_PRESET_CHANGED, LN10_OVER_TWENTY,
     PCM_SOURCE_EXT_EXPORTTOFILE, PCM_SOURCE_EXT_GETHASH, PCM_SOURCE_EXT_GETPOOLEDMIDIID,
@@ -25,6 +27,11 @@ pub use super::bindings::root::{
 
 pub use super::file_in_project_callback::file_in_project_ex2_t;
 
+pub use super::project_config_extension::project_config_extension_t;
+
+/// Types defined by LICE, the graphics library which is part of WDL and exported by REAPER.
+pub use super::bindings::root::reaper_functions::{LICE_IBitmap, LICE_pixel};
+
 /// Structs, types and constants defined by `swell.h` (on Linux and Mac OS X) and
 /// `windows.h` (on Windows).
 ///
@@ -55,40 +62,41 @@ pub use super::bindings::root::{
     MB_OKCANCEL, MB_RETRYCANCEL, MB_YESNO, MB_YESNOCANCEL, MENUITEMINFO, MF_BITMAP, MF_BYCOMMAND,
     MF_BYPOSITION, MF_CHECKED, MF_DISABLED, MF_ENABLED, MF_GRAYED, MF_POPUP, MF_SEPARATOR,
     MF_STRING, MF_UNCHECKED, MIIM_BITMAP, MSG, PAINTSTRUCT, PCM_SINK_EXT_CREATESOURCE,
-    PCM_SOURCE_EXT_ADDMIDIEVENTS, PCM_SOURCE_EXT_NOTIFYPREVIEWPLAYPOS,
-    PCM_SOURCE_EXT_REMOVEFROMMIDIPOOL, POINT, RECT, SB_BOTH, SB_BOTTOM, SB_CTL, SB_ENDSCROLL,
-    SB_HORZ, SB_LEFT, SB_LINEDOWN, SB_LINELEFT, SB_LINERIGHT, SB_LINEUP, SB_PAGEDOWN, SB_PAGELEFT,
-    SB_PAGERIGHT, SB_PAGEUP, SB_RIGHT, SB_THUMBPOSITION, SB_THUMBTRACK, SB_TOP, SB_VERT,
-    SCROLLINFO, SIF_ALL, SIF_DISABLENOSCROLL, SIF_PAGE, SIF_POS, SIF_RANGE, SIF_TRACKPOS,
-    SM_CXHSCROLL, SM_CXSCREEN, SM_CXVSCROLL, SM_CYHSCROLL, SM_CYMENU, SM_CYSCREEN, SM_CYVSCROLL,
-    SRCCOPY, SRCCOPY_USEALPHACHAN, THREAD_BASE_PRIORITY_IDLE, THREAD_BASE_PRIORITY_LOWRT,
-    THREAD_BASE_PRIORITY_MAX, THREAD_BASE_PRIORITY_MIN, THREAD_PRIORITY_ABOVE_NORMAL,
-    THREAD_PRIORITY_BELOW_NORMAL, THREAD_PRIORITY_HIGHEST, THREAD_PRIORITY_IDLE,
-    THREAD_PRIORITY_LOWEST, THREAD_PRIORITY_NORMAL, THREAD_PRIORITY_TIME_CRITICAL, TPM_BOTTOMALIGN,
-    TPM_CENTERALIGN, TPM_HORIZONTAL, TPM_LEFTALIGN, TPM_LEFTBUTTON, TPM_NONOTIFY, TPM_RETURNCMD,
-    TPM_RIGHTALIGN, TPM_RIGHTBUTTON, TPM_TOPALIGN, TPM_VCENTERALIGN, TPM_VERTICAL, UINT, ULONG_PTR,
-    VK_ADD, VK_BACK, VK_CAPITAL, VK_CLEAR, VK_CONTROL, VK_DECIMAL, VK_DELETE, VK_DIVIDE, VK_DOWN,
-    VK_END, VK_ESCAPE, VK_F1, VK_F10, VK_F11, VK_F12, VK_F13, VK_F14, VK_F15, VK_F16, VK_F17,
-    VK_F18, VK_F19, VK_F2, VK_F20, VK_F21, VK_F22, VK_F23, VK_F24, VK_F3, VK_F4, VK_F5, VK_F6,
-    VK_F7, VK_F8, VK_F9, VK_HELP, VK_HOME, VK_INSERT, VK_LBUTTON, VK_LEFT, VK_LWIN, VK_MBUTTON,
-    VK_MENU, VK_MULTIPLY, VK_NEXT, VK_NUMLOCK, VK_NUMPAD0, VK_NUMPAD1, VK_NUMPAD2, VK_NUMPAD3,
-    VK_NUMPAD4, VK_NUMPAD5, VK_NUMPAD6, VK_NUMPAD7, VK_NUMPAD8, VK_NUMPAD9, VK_PAUSE, VK_PRINT,
-    VK_PRIOR, VK_RBUTTON, VK_RETURN, VK_RIGHT, VK_SCROLL, VK_SELECT, VK_SEPARATOR, VK_SHIFT,
-    VK_SNAPSHOT, VK_SPACE, VK_SUBTRACT, VK_TAB, VK_UP, WDL_INT64, WM_ACTIVATE, WM_ACTIVATEAPP,
-    WM_CAPTURECHANGED, WM_CHAR, WM_CLOSE, WM_COMMAND, WM_CONTEXTMENU, WM_COPYDATA, WM_CREATE,
-    WM_CTLCOLORBTN, WM_CTLCOLORDLG, WM_CTLCOLOREDIT, WM_CTLCOLORLISTBOX, WM_CTLCOLORMSGBOX,
-    WM_CTLCOLORSCROLLBAR, WM_CTLCOLORSTATIC, WM_DEADCHAR, WM_DESTROY, WM_DISPLAYCHANGE,
-    WM_DRAWITEM, WM_DROPFILES, WM_ERASEBKGND, WM_GESTURE, WM_GETFONT, WM_GETMINMAXINFO,
-    WM_GETOBJECT, WM_HSCROLL, WM_INITDIALOG, WM_INITMENUPOPUP, WM_KEYDOWN, WM_KEYFIRST, WM_KEYLAST,
-    WM_KEYUP, WM_LBUTTONDBLCLK, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MBUTTONDBLCLK, WM_MBUTTONDOWN,
-    WM_MBUTTONUP, WM_MOUSEACTIVATE, WM_MOUSEFIRST, WM_MOUSEHWHEEL, WM_MOUSELAST, WM_MOUSEMOVE,
-    WM_MOUSEWHEEL, WM_MOVE, WM_NCCALCSIZE, WM_NCDESTROY, WM_NCHITTEST, WM_NCLBUTTONDBLCLK,
-    WM_NCLBUTTONDOWN, WM_NCLBUTTONUP, WM_NCMBUTTONDBLCLK, WM_NCMBUTTONDOWN, WM_NCMBUTTONUP,
-    WM_NCMOUSEMOVE, WM_NCPAINT, WM_NCRBUTTONDBLCLK, WM_NCRBUTTONDOWN, WM_NCRBUTTONUP, WM_NOTIFY,
-    WM_PAINT, WM_RBUTTONDBLCLK, WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SETCURSOR, WM_SETFOCUS,
-    WM_SETFONT, WM_SETREDRAW, WM_SETTEXT, WM_SHOWWINDOW, WM_SIZE, WM_STYLECHANGED, WM_SYSCHAR,
-    WM_SYSCOMMAND, WM_SYSDEADCHAR, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_TIMER, WM_USER, WM_VSCROLL,
-    WPARAM, WS_CAPTION, WS_SYSMENU, WS_THICKFRAME,
+    PCM_SOURCE_EXT_ADDMIDIEVENTS, PCM_SOURCE_EXT_GETNOTATIONSETTINGS,
+    PCM_SOURCE_EXT_NOTIFYPREVIEWPLAYPOS, PCM_SOURCE_EXT_REMOVEFROMMIDIPOOL, POINT, RECT, SB_BOTH,
+    SB_BOTTOM, SB_CTL, SB_ENDSCROLL, SB_HORZ, SB_LEFT, SB_LINEDOWN, SB_LINELEFT, SB_LINERIGHT,
+    SB_LINEUP, SB_PAGEDOWN, SB_PAGELEFT, SB_PAGERIGHT, SB_PAGEUP, SB_RIGHT, SB_THUMBPOSITION,
+    SB_THUMBTRACK, SB_TOP, SB_VERT, SCROLLINFO, SIF_ALL, SIF_DISABLENOSCROLL, SIF_PAGE, SIF_POS,
+    SIF_RANGE, SIF_TRACKPOS, SM_CXHSCROLL, SM_CXSCREEN, SM_CXVSCROLL, SM_CYHSCROLL, SM_CYMENU,
+    SM_CYSCREEN, SM_CYVSCROLL, SRCCOPY, SRCCOPY_USEALPHACHAN, THREAD_BASE_PRIORITY_IDLE,
+    THREAD_BASE_PRIORITY_LOWRT, THREAD_BASE_PRIORITY_MAX, THREAD_BASE_PRIORITY_MIN,
+    THREAD_PRIORITY_ABOVE_NORMAL, THREAD_PRIORITY_BELOW_NORMAL, THREAD_PRIORITY_HIGHEST,
+    THREAD_PRIORITY_IDLE, THREAD_PRIORITY_LOWEST, THREAD_PRIORITY_NORMAL,
+    THREAD_PRIORITY_TIME_CRITICAL, TPM_BOTTOMALIGN, TPM_CENTERALIGN, TPM_HORIZONTAL, TPM_LEFTALIGN,
+    TPM_LEFTBUTTON, TPM_NONOTIFY, TPM_RETURNCMD, TPM_RIGHTALIGN, TPM_RIGHTBUTTON, TPM_TOPALIGN,
+    TPM_VCENTERALIGN, TPM_VERTICAL, UINT, ULONG_PTR, VK_ADD, VK_BACK, VK_CAPITAL, VK_CLEAR,
+    VK_CONTROL, VK_DECIMAL, VK_DELETE, VK_DIVIDE, VK_DOWN, VK_END, VK_ESCAPE, VK_F1, VK_F10,
+    VK_F11, VK_F12, VK_F13, VK_F14, VK_F15, VK_F16, VK_F17, VK_F18, VK_F19, VK_F2, VK_F20, VK_F21,
+    VK_F22, VK_F23, VK_F24, VK_F3, VK_F4, VK_F5, VK_F6, VK_F7, VK_F8, VK_F9, VK_HELP, VK_HOME,
+    VK_INSERT, VK_LBUTTON, VK_LEFT, VK_LWIN, VK_MBUTTON, VK_MENU, VK_MULTIPLY, VK_NEXT, VK_NUMLOCK,
+    VK_NUMPAD0, VK_NUMPAD1, VK_NUMPAD2, VK_NUMPAD3, VK_NUMPAD4, VK_NUMPAD5, VK_NUMPAD6, VK_NUMPAD7,
+    VK_NUMPAD8, VK_NUMPAD9, VK_PAUSE, VK_PRINT, VK_PRIOR, VK_RBUTTON, VK_RETURN, VK_RIGHT,
+    VK_SCROLL, VK_SELECT, VK_SEPARATOR, VK_SHIFT, VK_SNAPSHOT, VK_SPACE, VK_SUBTRACT, VK_TAB,
+    VK_UP, WDL_INT64, WM_ACTIVATE, WM_ACTIVATEAPP, WM_CAPTURECHANGED, WM_CHAR, WM_CLOSE,
+    WM_COMMAND, WM_CONTEXTMENU, WM_COPYDATA, WM_CREATE, WM_CTLCOLORBTN, WM_CTLCOLORDLG,
+    WM_CTLCOLOREDIT, WM_CTLCOLORLISTBOX, WM_CTLCOLORMSGBOX, WM_CTLCOLORSCROLLBAR,
+    WM_CTLCOLORSTATIC, WM_DEADCHAR, WM_DESTROY, WM_DISPLAYCHANGE, WM_DRAWITEM, WM_DROPFILES,
+    WM_ERASEBKGND, WM_GESTURE, WM_GETFONT, WM_GETMINMAXINFO, WM_GETOBJECT, WM_HSCROLL,
+    WM_INITDIALOG, WM_INITMENUPOPUP, WM_KEYDOWN, WM_KEYFIRST, WM_KEYLAST, WM_KEYUP,
+    WM_LBUTTONDBLCLK, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MBUTTONDBLCLK, WM_MBUTTONDOWN, WM_MBUTTONUP,
+    WM_MOUSEACTIVATE, WM_MOUSEFIRST, WM_MOUSEHWHEEL, WM_MOUSELAST, WM_MOUSEMOVE, WM_MOUSEWHEEL,
+    WM_MOVE, WM_NCCALCSIZE, WM_NCDESTROY, WM_NCHITTEST, WM_NCLBUTTONDBLCLK, WM_NCLBUTTONDOWN,
+    WM_NCLBUTTONUP, WM_NCMBUTTONDBLCLK, WM_NCMBUTTONDOWN, WM_NCMBUTTONUP, WM_NCMOUSEMOVE,
+    WM_NCPAINT, WM_NCRBUTTONDBLCLK, WM_NCRBUTTONDOWN, WM_NCRBUTTONUP, WM_NOTIFY, WM_PAINT,
+    WM_RBUTTONDBLCLK, WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SETCURSOR, WM_SETFOCUS, WM_SETFONT,
+    WM_SETREDRAW, WM_SETTEXT, WM_SHOWWINDOW, WM_SIZE, WM_STYLECHANGED, WM_SYSCHAR, WM_SYSCOMMAND,
+    WM_SYSDEADCHAR, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_TIMER, WM_USER, WM_VSCROLL, WPARAM, WS_CAPTION,
+    WS_SYSMENU, WS_THICKFRAME,
 };
 
 // Some constants which are calculated from other constants are not picked up by bindgen.
@@ -98,6 +106,81 @@ pub const TBM_SETPOS: u32 = WM_USER + 5;
 pub const TBM_SETRANGE: u32 = WM_USER + 6;
 pub const TBM_SETSEL: u32 = WM_USER + 10;
 
+// Screenset actions as defined in `reaper_plugin.h` (not picked up by bindgen).
+pub const SCREENSET_ACTION_GETHWND: c_int = 0;
+pub const SCREENSET_ACTION_IS_DOCKED: c_int = 1;
+pub const SCREENSET_ACTION_SWITCH_DOCK: c_int = 4;
+pub const SCREENSET_ACTION_LOAD_STATE: c_int = 0x100;
+pub const SCREENSET_ACTION_SAVE_STATE: c_int = 0x101;
+
+// FX embedding as defined in `reaper_plugin_fx_embed.h` (not part of the bundled headers).
+pub const REAPER_FXEMBED_WM_IS_SUPPORTED: c_int = 0x0000;
+pub const REAPER_FXEMBED_WM_CREATE: c_int = 0x0001;
+pub const REAPER_FXEMBED_WM_DESTROY: c_int = 0x0002;
+pub const REAPER_FXEMBED_WM_PAINT: c_int = 0x000F;
+pub const REAPER_FXEMBED_WM_SETCURSOR: c_int = 0x0020;
+pub const REAPER_FXEMBED_WM_GETMINMAXINFO: c_int = 0x0024;
+pub const REAPER_FXEMBED_WM_MOUSEMOVE: c_int = 0x0200;
+pub const REAPER_FXEMBED_WM_LBUTTONDOWN: c_int = 0x0201;
+pub const REAPER_FXEMBED_WM_LBUTTONUP: c_int = 0x0202;
+pub const REAPER_FXEMBED_WM_LBUTTONDBLCLK: c_int = 0x0203;
+pub const REAPER_FXEMBED_WM_RBUTTONDOWN: c_int = 0x0204;
+pub const REAPER_FXEMBED_WM_RBUTTONUP: c_int = 0x0205;
+pub const REAPER_FXEMBED_WM_RBUTTONDBLCLK: c_int = 0x0206;
+pub const REAPER_FXEMBED_WM_MOUSEWHEEL: c_int = 0x020A;
+pub const REAPER_FXEMBED_DRAWINFO_FLAG_PAINT_OPTIONAL: c_int = 1;
+pub const REAPER_FXEMBED_DRAWINFO_FLAG_LBUTTON_CAPTURED: c_int = 0x10000;
+pub const REAPER_FXEMBED_DRAWINFO_FLAG_RBUTTON_CAPTURED: c_int = 0x20000;
+pub const REAPER_FXEMBED_RETNOTIFY_HANDLED: c_int = 0x0000001;
+pub const REAPER_FXEMBED_RETNOTIFY_INVALIDATE: c_int = 0x1000000;
+/// VST 2 plug-ins announce embedded UI support by returning this value from `canDo` for
+/// [`REAPER_FXEMBED_VST_CAN_DO`].
+pub const REAPER_FXEMBED_VST_CAN_DO_RESULT: isize = 0xbeef_0000_u32 as isize;
+pub const REAPER_FXEMBED_VST_CAN_DO: &str = "hasCockosEmbeddedUI";
+/// The `index` of the `effVendorSpecific` calls which carry FX embedding messages (= the
+/// deprecated VST 2 opcode `effEditDraw`).
+///
+/// The message is passed as `opt`, `parm2` as `value` and `parm3` as `ptr`.
+pub const REAPER_FXEMBED_VST_INDEX: c_int = 16;
+
+/// Passed to FX embedding messages for painting, mouse events and cursor requests.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct REAPER_FXEMBED_DrawInfo {
+    /// 1 = TCP, 2 = MCP.
+    pub context: c_int,
+    /// 0 = unknown, otherwise 24.8 fixed point (256 = 100%).
+    pub dpi: c_int,
+    /// For `REAPER_FXEMBED_WM_MOUSEWHEEL`, typically 120 per step.
+    pub mousewheel_amt: c_int,
+    pub _res2: f64,
+    pub width: c_int,
+    pub height: c_int,
+    pub mouse_x: c_int,
+    pub mouse_y: c_int,
+    pub flags: c_int,
+    pub _res3: c_int,
+    pub spare: [*mut c_void; 6],
+}
+
+/// Filled by the plug-in in response to `REAPER_FXEMBED_WM_GETMINMAXINFO`.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct REAPER_FXEMBED_SizeHints {
+    /// 16.16 fixed point (65536 = 1:1, 32768 = 1:2 etc.).
+    pub preferred_aspect: c_int,
+    /// 16.16 fixed point.
+    pub minimum_aspect: c_int,
+    pub _res1: c_int,
+    pub _res2: c_int,
+    pub _res3: c_int,
+    pub _res4: c_int,
+    pub min_width: c_int,
+    pub min_height: c_int,
+    pub max_width: c_int,
+    pub max_height: c_int,
+}
+
 // Some constants/types are different in Unix/SWELL. Search for "these differ" in SWELL source code
 // for explanation.
 #[cfg(target_family = "unix")]
@@ -6027,10 +6027,7 @@ impl Reaper {
         tmpspace: *mut ::std::os::raw::c_void,
     ) {
         match self.pointers.__mergesort {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(__mergesort)
-            ),
+            None => crate::function_not_available(stringify!(__mergesort)),
             Some(f) => f(base, nmemb, size, cmpfunc, tmpspace),
         }
     }
@@ -6045,19 +6042,13 @@ impl Reaper {
         addtomainmenu: bool,
     ) -> bool {
         match self.pointers.AddCustomizableMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddCustomizableMenu)
-            ),
+            None => crate::function_not_available(stringify!(AddCustomizableMenu)),
             Some(f) => f(menuidstr, menuname, kbdsecname, addtomainmenu),
         }
     }
     pub fn AddExtensionsMainMenu(&self) -> bool {
         match self.pointers.AddExtensionsMainMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddExtensionsMainMenu)
-            ),
+            None => crate::function_not_available(stringify!(AddExtensionsMainMenu)),
             Some(f) => f(),
         }
     }
@@ -6066,10 +6057,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn AddMediaItemToTrack(&self, tr: *mut root::MediaTrack) -> *mut root::MediaItem {
         match self.pointers.AddMediaItemToTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddMediaItemToTrack)
-            ),
+            None => crate::function_not_available(stringify!(AddMediaItemToTrack)),
             Some(f) => f(tr),
         }
     }
@@ -6086,10 +6074,7 @@ impl Reaper {
         wantidx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.AddProjectMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddProjectMarker)
-            ),
+            None => crate::function_not_available(stringify!(AddProjectMarker)),
             Some(f) => f(proj, isrgn, pos, rgnend, name, wantidx),
         }
     }
@@ -6107,10 +6092,7 @@ impl Reaper {
         color: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.AddProjectMarker2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddProjectMarker2)
-            ),
+            None => crate::function_not_available(stringify!(AddProjectMarker2)),
             Some(f) => f(proj, isrgn, pos, rgnend, name, wantidx, color),
         }
     }
@@ -6125,10 +6107,7 @@ impl Reaper {
         commit: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.AddRemoveReaScript {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddRemoveReaScript)
-            ),
+            None => crate::function_not_available(stringify!(AddRemoveReaScript)),
             Some(f) => f(add, sectionID, scriptfn, commit),
         }
     }
@@ -6140,10 +6119,7 @@ impl Reaper {
         item: *mut root::MediaItem,
     ) -> *mut root::MediaItem_Take {
         match self.pointers.AddTakeToMediaItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddTakeToMediaItem)
-            ),
+            None => crate::function_not_available(stringify!(AddTakeToMediaItem)),
             Some(f) => f(item),
         }
     }
@@ -6160,10 +6136,7 @@ impl Reaper {
         lineartempochange: bool,
     ) -> bool {
         match self.pointers.AddTempoTimeSigMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddTempoTimeSigMarker)
-            ),
+            None => crate::function_not_available(stringify!(AddTempoTimeSigMarker)),
             Some(f) => f(
                 proj,
                 timepos,
@@ -6182,10 +6155,7 @@ impl Reaper {
         centermode: ::std::os::raw::c_int,
     ) {
         match self.pointers.adjustZoom {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(adjustZoom)
-            ),
+            None => crate::function_not_available(stringify!(adjustZoom)),
             Some(f) => f(amt, forceset, doupd, centermode),
         }
     }
@@ -6194,10 +6164,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn AnyTrackSolo(&self, proj: *mut root::ReaProject) -> bool {
         match self.pointers.AnyTrackSolo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AnyTrackSolo)
-            ),
+            None => crate::function_not_available(stringify!(AnyTrackSolo)),
             Some(f) => f(proj),
         }
     }
@@ -6206,19 +6173,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn APIExists(&self, function_name: *const ::std::os::raw::c_char) -> bool {
         match self.pointers.APIExists {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(APIExists)
-            ),
+            None => crate::function_not_available(stringify!(APIExists)),
             Some(f) => f(function_name),
         }
     }
     pub fn APITest(&self) {
         match self.pointers.APITest {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(APITest)
-            ),
+            None => crate::function_not_available(stringify!(APITest)),
             Some(f) => f(),
         }
     }
@@ -6236,10 +6197,7 @@ impl Reaper {
         copies: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.ApplyNudge {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ApplyNudge)
-            ),
+            None => crate::function_not_available(stringify!(ApplyNudge)),
             Some(f) => f(
                 project, nudgeflag, nudgewhat, nudgeunits, value, reverse, copies,
             ),
@@ -6254,46 +6212,31 @@ impl Reaper {
         sectionname: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.ArmCommand {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ArmCommand)
-            ),
+            None => crate::function_not_available(stringify!(ArmCommand)),
             Some(f) => f(cmd, sectionname),
         }
     }
     pub fn Audio_Init(&self) {
         match self.pointers.Audio_Init {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Audio_Init)
-            ),
+            None => crate::function_not_available(stringify!(Audio_Init)),
             Some(f) => f(),
         }
     }
     pub fn Audio_IsPreBuffer(&self) -> ::std::os::raw::c_int {
         match self.pointers.Audio_IsPreBuffer {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Audio_IsPreBuffer)
-            ),
+            None => crate::function_not_available(stringify!(Audio_IsPreBuffer)),
             Some(f) => f(),
         }
     }
     pub fn Audio_IsRunning(&self) -> ::std::os::raw::c_int {
         match self.pointers.Audio_IsRunning {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Audio_IsRunning)
-            ),
+            None => crate::function_not_available(stringify!(Audio_IsRunning)),
             Some(f) => f(),
         }
     }
     pub fn Audio_Quit(&self) {
         match self.pointers.Audio_Quit {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Audio_Quit)
-            ),
+            None => crate::function_not_available(stringify!(Audio_Quit)),
             Some(f) => f(),
         }
     }
@@ -6306,10 +6249,7 @@ impl Reaper {
         reg: *mut root::audio_hook_register_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.Audio_RegHardwareHook {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Audio_RegHardwareHook)
-            ),
+            None => crate::function_not_available(stringify!(Audio_RegHardwareHook)),
             Some(f) => f(isAdd, reg),
         }
     }
@@ -6321,10 +6261,7 @@ impl Reaper {
         accessor: *mut root::reaper_functions::AudioAccessor,
     ) -> bool {
         match self.pointers.AudioAccessorStateChanged {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AudioAccessorStateChanged)
-            ),
+            None => crate::function_not_available(stringify!(AudioAccessorStateChanged)),
             Some(f) => f(accessor),
         }
     }
@@ -6333,10 +6270,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn AudioAccessorUpdate(&self, accessor: *mut root::reaper_functions::AudioAccessor) {
         match self.pointers.AudioAccessorUpdate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AudioAccessorUpdate)
-            ),
+            None => crate::function_not_available(stringify!(AudioAccessorUpdate)),
             Some(f) => f(accessor),
         }
     }
@@ -6348,19 +6282,13 @@ impl Reaper {
         accessor: *mut root::reaper_functions::AudioAccessor,
     ) -> bool {
         match self.pointers.AudioAccessorValidateState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AudioAccessorValidateState)
-            ),
+            None => crate::function_not_available(stringify!(AudioAccessorValidateState)),
             Some(f) => f(accessor),
         }
     }
     pub fn BypassFxAllTracks(&self, bypass: ::std::os::raw::c_int) {
         match self.pointers.BypassFxAllTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BypassFxAllTracks)
-            ),
+            None => crate::function_not_available(stringify!(BypassFxAllTracks)),
             Some(f) => f(bypass),
         }
     }
@@ -6372,10 +6300,7 @@ impl Reaper {
         mediasource: *mut root::PCM_source,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CalcMediaSrcLoudness {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CalcMediaSrcLoudness)
-            ),
+            None => crate::function_not_available(stringify!(CalcMediaSrcLoudness)),
             Some(f) => f(mediasource),
         }
     }
@@ -6391,10 +6316,7 @@ impl Reaper {
         normalizeEnd: f64,
     ) -> f64 {
         match self.pointers.CalculateNormalization {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CalculateNormalization)
-            ),
+            None => crate::function_not_available(stringify!(CalculateNormalization)),
             Some(f) => f(
                 source,
                 normalizeTo,
@@ -6413,10 +6335,7 @@ impl Reaper {
         pksBlock: *mut root::PCM_source_peaktransfer_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CalculatePeaks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CalculatePeaks)
-            ),
+            None => crate::function_not_available(stringify!(CalculatePeaks)),
             Some(f) => f(srcBlock, pksBlock),
         }
     }
@@ -6429,37 +6348,25 @@ impl Reaper {
         pksBlock: *mut root::PCM_source_peaktransfer_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CalculatePeaksFloatSrcPtr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CalculatePeaksFloatSrcPtr)
-            ),
+            None => crate::function_not_available(stringify!(CalculatePeaksFloatSrcPtr)),
             Some(f) => f(srcBlock, pksBlock),
         }
     }
     pub fn ClearAllRecArmed(&self) {
         match self.pointers.ClearAllRecArmed {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ClearAllRecArmed)
-            ),
+            None => crate::function_not_available(stringify!(ClearAllRecArmed)),
             Some(f) => f(),
         }
     }
     pub fn ClearConsole(&self) {
         match self.pointers.ClearConsole {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ClearConsole)
-            ),
+            None => crate::function_not_available(stringify!(ClearConsole)),
             Some(f) => f(),
         }
     }
     pub fn ClearPeakCache(&self) {
         match self.pointers.ClearPeakCache {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ClearPeakCache)
-            ),
+            None => crate::function_not_available(stringify!(ClearPeakCache)),
             Some(f) => f(),
         }
     }
@@ -6474,10 +6381,7 @@ impl Reaper {
         bOut: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.ColorFromNative {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ColorFromNative)
-            ),
+            None => crate::function_not_available(stringify!(ColorFromNative)),
             Some(f) => f(col, rOut, gOut, bOut),
         }
     }
@@ -6488,10 +6392,7 @@ impl Reaper {
         b: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ColorToNative {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ColorToNative)
-            ),
+            None => crate::function_not_available(stringify!(ColorToNative)),
             Some(f) => f(r, g, b),
         }
     }
@@ -6504,10 +6405,7 @@ impl Reaper {
         cmdID: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountActionShortcuts {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountActionShortcuts)
-            ),
+            None => crate::function_not_available(stringify!(CountActionShortcuts)),
             Some(f) => f(section, cmdID),
         }
     }
@@ -6519,10 +6417,7 @@ impl Reaper {
         env: *mut root::TrackEnvelope,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountAutomationItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountAutomationItems)
-            ),
+            None => crate::function_not_available(stringify!(CountAutomationItems)),
             Some(f) => f(env),
         }
     }
@@ -6534,10 +6429,7 @@ impl Reaper {
         envelope: *mut root::TrackEnvelope,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountEnvelopePoints {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountEnvelopePoints)
-            ),
+            None => crate::function_not_available(stringify!(CountEnvelopePoints)),
             Some(f) => f(envelope),
         }
     }
@@ -6550,10 +6442,7 @@ impl Reaper {
         autoitem_idx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountEnvelopePointsEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountEnvelopePointsEx)
-            ),
+            None => crate::function_not_available(stringify!(CountEnvelopePointsEx)),
             Some(f) => f(envelope, autoitem_idx),
         }
     }
@@ -6562,10 +6451,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CountMediaItems(&self, proj: *mut root::ReaProject) -> ::std::os::raw::c_int {
         match self.pointers.CountMediaItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountMediaItems)
-            ),
+            None => crate::function_not_available(stringify!(CountMediaItems)),
             Some(f) => f(proj),
         }
     }
@@ -6579,10 +6465,7 @@ impl Reaper {
         num_regionsOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountProjectMarkers {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountProjectMarkers)
-            ),
+            None => crate::function_not_available(stringify!(CountProjectMarkers)),
             Some(f) => f(proj, num_markersOut, num_regionsOut),
         }
     }
@@ -6594,10 +6477,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountSelectedMediaItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountSelectedMediaItems)
-            ),
+            None => crate::function_not_available(stringify!(CountSelectedMediaItems)),
             Some(f) => f(proj),
         }
     }
@@ -6606,10 +6486,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CountSelectedTracks(&self, proj: *mut root::ReaProject) -> ::std::os::raw::c_int {
         match self.pointers.CountSelectedTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountSelectedTracks)
-            ),
+            None => crate::function_not_available(stringify!(CountSelectedTracks)),
             Some(f) => f(proj),
         }
     }
@@ -6622,10 +6499,7 @@ impl Reaper {
         wantmaster: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountSelectedTracks2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountSelectedTracks2)
-            ),
+            None => crate::function_not_available(stringify!(CountSelectedTracks2)),
             Some(f) => f(proj, wantmaster),
         }
     }
@@ -6637,10 +6511,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountTakeEnvelopes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountTakeEnvelopes)
-            ),
+            None => crate::function_not_available(stringify!(CountTakeEnvelopes)),
             Some(f) => f(take),
         }
     }
@@ -6649,10 +6520,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CountTakes(&self, item: *mut root::MediaItem) -> ::std::os::raw::c_int {
         match self.pointers.CountTakes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountTakes)
-            ),
+            None => crate::function_not_available(stringify!(CountTakes)),
             Some(f) => f(item),
         }
     }
@@ -6665,10 +6533,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountTCPFXParms {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountTCPFXParms)
-            ),
+            None => crate::function_not_available(stringify!(CountTCPFXParms)),
             Some(f) => f(project, track),
         }
     }
@@ -6680,10 +6545,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountTempoTimeSigMarkers {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountTempoTimeSigMarkers)
-            ),
+            None => crate::function_not_available(stringify!(CountTempoTimeSigMarkers)),
             Some(f) => f(proj),
         }
     }
@@ -6695,10 +6557,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountTrackEnvelopes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountTrackEnvelopes)
-            ),
+            None => crate::function_not_available(stringify!(CountTrackEnvelopes)),
             Some(f) => f(track),
         }
     }
@@ -6710,10 +6569,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CountTrackMediaItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountTrackMediaItems)
-            ),
+            None => crate::function_not_available(stringify!(CountTrackMediaItems)),
             Some(f) => f(track),
         }
     }
@@ -6722,10 +6578,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CountTracks(&self, projOptional: *mut root::ReaProject) -> ::std::os::raw::c_int {
         match self.pointers.CountTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CountTracks)
-            ),
+            None => crate::function_not_available(stringify!(CountTracks)),
             Some(f) => f(projOptional),
         }
     }
@@ -6738,19 +6591,13 @@ impl Reaper {
         callback: *mut ::std::os::raw::c_void,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.CreateLocalOscHandler {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateLocalOscHandler)
-            ),
+            None => crate::function_not_available(stringify!(CreateLocalOscHandler)),
             Some(f) => f(obj, callback),
         }
     }
     pub fn CreateMIDIInput(&self, dev: ::std::os::raw::c_int) -> *mut root::midi_Input {
         match self.pointers.CreateMIDIInput {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateMIDIInput)
-            ),
+            None => crate::function_not_available(stringify!(CreateMIDIInput)),
             Some(f) => f(dev),
         }
     }
@@ -6764,10 +6611,7 @@ impl Reaper {
         msoffset100: *mut ::std::os::raw::c_int,
     ) -> *mut root::midi_Output {
         match self.pointers.CreateMIDIOutput {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateMIDIOutput)
-            ),
+            None => crate::function_not_available(stringify!(CreateMIDIOutput)),
             Some(f) => f(dev, streamMode, msoffset100),
         }
     }
@@ -6782,10 +6626,7 @@ impl Reaper {
         qnInOptional: *const bool,
     ) -> *mut root::MediaItem {
         match self.pointers.CreateNewMIDIItemInProj {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateNewMIDIItemInProj)
-            ),
+            None => crate::function_not_available(stringify!(CreateNewMIDIItemInProj)),
             Some(f) => f(track, starttime, endtime, qnInOptional),
         }
     }
@@ -6797,10 +6638,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> *mut root::reaper_functions::AudioAccessor {
         match self.pointers.CreateTakeAudioAccessor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateTakeAudioAccessor)
-            ),
+            None => crate::function_not_available(stringify!(CreateTakeAudioAccessor)),
             Some(f) => f(take),
         }
     }
@@ -6812,10 +6650,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> *mut root::reaper_functions::AudioAccessor {
         match self.pointers.CreateTrackAudioAccessor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateTrackAudioAccessor)
-            ),
+            None => crate::function_not_available(stringify!(CreateTrackAudioAccessor)),
             Some(f) => f(track),
         }
     }
@@ -6828,19 +6663,13 @@ impl Reaper {
         desttrInOptional: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CreateTrackSend {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateTrackSend)
-            ),
+            None => crate::function_not_available(stringify!(CreateTrackSend)),
             Some(f) => f(tr, desttrInOptional),
         }
     }
     pub fn CSurf_FlushUndo(&self, force: bool) {
         match self.pointers.CSurf_FlushUndo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_FlushUndo)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_FlushUndo)),
             Some(f) => f(force),
         }
     }
@@ -6853,55 +6682,37 @@ impl Reaper {
         isPan: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.CSurf_GetTouchState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_GetTouchState)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_GetTouchState)),
             Some(f) => f(trackid, isPan),
         }
     }
     pub fn CSurf_GoEnd(&self) {
         match self.pointers.CSurf_GoEnd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_GoEnd)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_GoEnd)),
             Some(f) => f(),
         }
     }
     pub fn CSurf_GoStart(&self) {
         match self.pointers.CSurf_GoStart {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_GoStart)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_GoStart)),
             Some(f) => f(),
         }
     }
     pub fn CSurf_NumTracks(&self, mcpView: bool) -> ::std::os::raw::c_int {
         match self.pointers.CSurf_NumTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_NumTracks)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_NumTracks)),
             Some(f) => f(mcpView),
         }
     }
     pub fn CSurf_OnArrow(&self, whichdir: ::std::os::raw::c_int, wantzoom: bool) {
         match self.pointers.CSurf_OnArrow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnArrow)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnArrow)),
             Some(f) => f(whichdir, wantzoom),
         }
     }
     pub fn CSurf_OnFwd(&self, seekplay: ::std::os::raw::c_int) {
         match self.pointers.CSurf_OnFwd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnFwd)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnFwd)),
             Some(f) => f(seekplay),
         }
     }
@@ -6914,10 +6725,7 @@ impl Reaper {
         en: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.CSurf_OnFXChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnFXChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnFXChange)),
             Some(f) => f(trackid, en),
         }
     }
@@ -6930,10 +6738,7 @@ impl Reaper {
         monitor: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CSurf_OnInputMonitorChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnInputMonitorChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnInputMonitorChange)),
             Some(f) => f(trackid, monitor),
         }
     }
@@ -6947,10 +6752,7 @@ impl Reaper {
         allowgang: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CSurf_OnInputMonitorChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnInputMonitorChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnInputMonitorChangeEx)),
             Some(f) => f(trackid, monitor, allowgang),
         }
     }
@@ -6963,10 +6765,7 @@ impl Reaper {
         mute: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.CSurf_OnMuteChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnMuteChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnMuteChange)),
             Some(f) => f(trackid, mute),
         }
     }
@@ -6980,10 +6779,7 @@ impl Reaper {
         allowgang: bool,
     ) -> bool {
         match self.pointers.CSurf_OnMuteChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnMuteChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnMuteChangeEx)),
             Some(f) => f(trackid, mute, allowgang),
         }
     }
@@ -6996,10 +6792,7 @@ impl Reaper {
         arg: *const f32,
     ) {
         match self.pointers.CSurf_OnOscControlMessage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnOscControlMessage)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnOscControlMessage)),
             Some(f) => f(msg, arg),
         }
     }
@@ -7013,10 +6806,7 @@ impl Reaper {
         argstr: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.CSurf_OnOscControlMessage2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnOscControlMessage2)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnOscControlMessage2)),
             Some(f) => f(msg, arg, argstr),
         }
     }
@@ -7030,10 +6820,7 @@ impl Reaper {
         relative: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnPanChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnPanChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnPanChange)),
             Some(f) => f(trackid, pan, relative),
         }
     }
@@ -7048,37 +6835,25 @@ impl Reaper {
         allowGang: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnPanChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnPanChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnPanChangeEx)),
             Some(f) => f(trackid, pan, relative, allowGang),
         }
     }
     pub fn CSurf_OnPause(&self) {
         match self.pointers.CSurf_OnPause {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnPause)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnPause)),
             Some(f) => f(),
         }
     }
     pub fn CSurf_OnPlay(&self) {
         match self.pointers.CSurf_OnPlay {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnPlay)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnPlay)),
             Some(f) => f(),
         }
     }
     pub fn CSurf_OnPlayRateChange(&self, playrate: f64) {
         match self.pointers.CSurf_OnPlayRateChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnPlayRateChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnPlayRateChange)),
             Some(f) => f(playrate),
         }
     }
@@ -7091,10 +6866,7 @@ impl Reaper {
         recarm: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.CSurf_OnRecArmChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnRecArmChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnRecArmChange)),
             Some(f) => f(trackid, recarm),
         }
     }
@@ -7108,19 +6880,13 @@ impl Reaper {
         allowgang: bool,
     ) -> bool {
         match self.pointers.CSurf_OnRecArmChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnRecArmChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnRecArmChangeEx)),
             Some(f) => f(trackid, recarm, allowgang),
         }
     }
     pub fn CSurf_OnRecord(&self) {
         match self.pointers.CSurf_OnRecord {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnRecord)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnRecord)),
             Some(f) => f(),
         }
     }
@@ -7135,10 +6901,7 @@ impl Reaper {
         relative: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnRecvPanChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnRecvPanChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnRecvPanChange)),
             Some(f) => f(trackid, recv_index, pan, relative),
         }
     }
@@ -7153,37 +6916,25 @@ impl Reaper {
         relative: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnRecvVolumeChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnRecvVolumeChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnRecvVolumeChange)),
             Some(f) => f(trackid, recv_index, volume, relative),
         }
     }
     pub fn CSurf_OnRew(&self, seekplay: ::std::os::raw::c_int) {
         match self.pointers.CSurf_OnRew {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnRew)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnRew)),
             Some(f) => f(seekplay),
         }
     }
     pub fn CSurf_OnRewFwd(&self, seekplay: ::std::os::raw::c_int, dir: ::std::os::raw::c_int) {
         match self.pointers.CSurf_OnRewFwd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnRewFwd)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnRewFwd)),
             Some(f) => f(seekplay, dir),
         }
     }
     pub fn CSurf_OnScroll(&self, xdir: ::std::os::raw::c_int, ydir: ::std::os::raw::c_int) {
         match self.pointers.CSurf_OnScroll {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnScroll)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnScroll)),
             Some(f) => f(xdir, ydir),
         }
     }
@@ -7196,10 +6947,7 @@ impl Reaper {
         selected: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.CSurf_OnSelectedChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnSelectedChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnSelectedChange)),
             Some(f) => f(trackid, selected),
         }
     }
@@ -7214,10 +6962,7 @@ impl Reaper {
         relative: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnSendPanChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnSendPanChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnSendPanChange)),
             Some(f) => f(trackid, send_index, pan, relative),
         }
     }
@@ -7232,10 +6977,7 @@ impl Reaper {
         relative: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnSendVolumeChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnSendVolumeChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnSendVolumeChange)),
             Some(f) => f(trackid, send_index, volume, relative),
         }
     }
@@ -7248,10 +6990,7 @@ impl Reaper {
         solo: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.CSurf_OnSoloChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnSoloChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnSoloChange)),
             Some(f) => f(trackid, solo),
         }
     }
@@ -7265,28 +7004,19 @@ impl Reaper {
         allowgang: bool,
     ) -> bool {
         match self.pointers.CSurf_OnSoloChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnSoloChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnSoloChangeEx)),
             Some(f) => f(trackid, solo, allowgang),
         }
     }
     pub fn CSurf_OnStop(&self) {
         match self.pointers.CSurf_OnStop {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnStop)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnStop)),
             Some(f) => f(),
         }
     }
     pub fn CSurf_OnTempoChange(&self, bpm: f64) {
         match self.pointers.CSurf_OnTempoChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnTempoChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnTempoChange)),
             Some(f) => f(bpm),
         }
     }
@@ -7295,10 +7025,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CSurf_OnTrackSelection(&self, trackid: *mut root::MediaTrack) {
         match self.pointers.CSurf_OnTrackSelection {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnTrackSelection)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnTrackSelection)),
             Some(f) => f(trackid),
         }
     }
@@ -7312,10 +7039,7 @@ impl Reaper {
         relative: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnVolumeChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnVolumeChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnVolumeChange)),
             Some(f) => f(trackid, volume, relative),
         }
     }
@@ -7330,10 +7054,7 @@ impl Reaper {
         allowGang: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnVolumeChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnVolumeChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnVolumeChangeEx)),
             Some(f) => f(trackid, volume, relative, allowGang),
         }
     }
@@ -7347,10 +7068,7 @@ impl Reaper {
         relative: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnWidthChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnWidthChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnWidthChange)),
             Some(f) => f(trackid, width, relative),
         }
     }
@@ -7365,37 +7083,25 @@ impl Reaper {
         allowGang: bool,
     ) -> f64 {
         match self.pointers.CSurf_OnWidthChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnWidthChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnWidthChangeEx)),
             Some(f) => f(trackid, width, relative, allowGang),
         }
     }
     pub fn CSurf_OnZoom(&self, xdir: ::std::os::raw::c_int, ydir: ::std::os::raw::c_int) {
         match self.pointers.CSurf_OnZoom {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_OnZoom)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_OnZoom)),
             Some(f) => f(xdir, ydir),
         }
     }
     pub fn CSurf_ResetAllCachedVolPanStates(&self) {
         match self.pointers.CSurf_ResetAllCachedVolPanStates {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_ResetAllCachedVolPanStates)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_ResetAllCachedVolPanStates)),
             Some(f) => f(),
         }
     }
     pub fn CSurf_ScrubAmt(&self, amt: f64) {
         match self.pointers.CSurf_ScrubAmt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_ScrubAmt)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_ScrubAmt)),
             Some(f) => f(amt),
         }
     }
@@ -7408,10 +7114,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetAutoMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetAutoMode)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetAutoMode)),
             Some(f) => f(mode, ignoresurf),
         }
     }
@@ -7426,10 +7129,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetPlayState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetPlayState)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetPlayState)),
             Some(f) => f(play, pause, rec, ignoresurf),
         }
     }
@@ -7442,10 +7142,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetRepeatState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetRepeatState)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetRepeatState)),
             Some(f) => f(rep, ignoresurf),
         }
     }
@@ -7459,10 +7156,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetSurfaceMute {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetSurfaceMute)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetSurfaceMute)),
             Some(f) => f(trackid, mute, ignoresurf),
         }
     }
@@ -7476,10 +7170,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetSurfacePan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetSurfacePan)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetSurfacePan)),
             Some(f) => f(trackid, pan, ignoresurf),
         }
     }
@@ -7493,10 +7184,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetSurfaceRecArm {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetSurfaceRecArm)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetSurfaceRecArm)),
             Some(f) => f(trackid, recarm, ignoresurf),
         }
     }
@@ -7510,10 +7198,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetSurfaceSelected {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetSurfaceSelected)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetSurfaceSelected)),
             Some(f) => f(trackid, selected, ignoresurf),
         }
     }
@@ -7527,10 +7212,7 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetSurfaceSolo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetSurfaceSolo)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetSurfaceSolo)),
             Some(f) => f(trackid, solo, ignoresurf),
         }
     }
@@ -7544,19 +7226,13 @@ impl Reaper {
         ignoresurf: *mut root::IReaperControlSurface,
     ) {
         match self.pointers.CSurf_SetSurfaceVolume {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetSurfaceVolume)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetSurfaceVolume)),
             Some(f) => f(trackid, volume, ignoresurf),
         }
     }
     pub fn CSurf_SetTrackListChange(&self) {
         match self.pointers.CSurf_SetTrackListChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_SetTrackListChange)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_SetTrackListChange)),
             Some(f) => f(),
         }
     }
@@ -7566,10 +7242,7 @@ impl Reaper {
         mcpView: bool,
     ) -> *mut root::MediaTrack {
         match self.pointers.CSurf_TrackFromID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_TrackFromID)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_TrackFromID)),
             Some(f) => f(idx, mcpView),
         }
     }
@@ -7582,19 +7255,13 @@ impl Reaper {
         mcpView: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CSurf_TrackToID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CSurf_TrackToID)
-            ),
+            None => crate::function_not_available(stringify!(CSurf_TrackToID)),
             Some(f) => f(track, mcpView),
         }
     }
     pub fn DB2SLIDER(&self, x: f64) -> f64 {
         match self.pointers.DB2SLIDER {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DB2SLIDER)
-            ),
+            None => crate::function_not_available(stringify!(DB2SLIDER)),
             Some(f) => f(x),
         }
     }
@@ -7608,10 +7275,7 @@ impl Reaper {
         shortcutidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DeleteActionShortcut {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteActionShortcut)
-            ),
+            None => crate::function_not_available(stringify!(DeleteActionShortcut)),
             Some(f) => f(section, cmdID, shortcutidx),
         }
     }
@@ -7625,10 +7289,7 @@ impl Reaper {
         ptidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DeleteEnvelopePointEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteEnvelopePointEx)
-            ),
+            None => crate::function_not_available(stringify!(DeleteEnvelopePointEx)),
             Some(f) => f(envelope, autoitem_idx, ptidx),
         }
     }
@@ -7642,10 +7303,7 @@ impl Reaper {
         time_end: f64,
     ) -> bool {
         match self.pointers.DeleteEnvelopePointRange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteEnvelopePointRange)
-            ),
+            None => crate::function_not_available(stringify!(DeleteEnvelopePointRange)),
             Some(f) => f(envelope, time_start, time_end),
         }
     }
@@ -7660,10 +7318,7 @@ impl Reaper {
         time_end: f64,
     ) -> bool {
         match self.pointers.DeleteEnvelopePointRangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteEnvelopePointRangeEx)
-            ),
+            None => crate::function_not_available(stringify!(DeleteEnvelopePointRangeEx)),
             Some(f) => f(envelope, autoitem_idx, time_start, time_end),
         }
     }
@@ -7677,10 +7332,7 @@ impl Reaper {
         persist: bool,
     ) {
         match self.pointers.DeleteExtState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteExtState)
-            ),
+            None => crate::function_not_available(stringify!(DeleteExtState)),
             Some(f) => f(section, key, persist),
         }
     }
@@ -7694,10 +7346,7 @@ impl Reaper {
         isrgn: bool,
     ) -> bool {
         match self.pointers.DeleteProjectMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteProjectMarker)
-            ),
+            None => crate::function_not_available(stringify!(DeleteProjectMarker)),
             Some(f) => f(proj, markrgnindexnumber, isrgn),
         }
     }
@@ -7710,10 +7359,7 @@ impl Reaper {
         markrgnidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DeleteProjectMarkerByIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteProjectMarkerByIndex)
-            ),
+            None => crate::function_not_available(stringify!(DeleteProjectMarkerByIndex)),
             Some(f) => f(proj, markrgnidx),
         }
     }
@@ -7726,10 +7372,7 @@ impl Reaper {
         idx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DeleteTakeMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteTakeMarker)
-            ),
+            None => crate::function_not_available(stringify!(DeleteTakeMarker)),
             Some(f) => f(take, idx),
         }
     }
@@ -7743,10 +7386,7 @@ impl Reaper {
         countInOptional: *const ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.DeleteTakeStretchMarkers {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteTakeStretchMarkers)
-            ),
+            None => crate::function_not_available(stringify!(DeleteTakeStretchMarkers)),
             Some(f) => f(take, idx, countInOptional),
         }
     }
@@ -7759,10 +7399,7 @@ impl Reaper {
         markerindex: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DeleteTempoTimeSigMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteTempoTimeSigMarker)
-            ),
+            None => crate::function_not_available(stringify!(DeleteTempoTimeSigMarker)),
             Some(f) => f(project, markerindex),
         }
     }
@@ -7771,10 +7408,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DeleteTrack(&self, tr: *mut root::MediaTrack) {
         match self.pointers.DeleteTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteTrack)
-            ),
+            None => crate::function_not_available(stringify!(DeleteTrack)),
             Some(f) => f(tr),
         }
     }
@@ -7787,10 +7421,7 @@ impl Reaper {
         it: *mut root::MediaItem,
     ) -> bool {
         match self.pointers.DeleteTrackMediaItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteTrackMediaItem)
-            ),
+            None => crate::function_not_available(stringify!(DeleteTrackMediaItem)),
             Some(f) => f(tr, it),
         }
     }
@@ -7802,10 +7433,7 @@ impl Reaper {
         accessor: *mut root::reaper_functions::AudioAccessor,
     ) {
         match self.pointers.DestroyAudioAccessor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DestroyAudioAccessor)
-            ),
+            None => crate::function_not_available(stringify!(DestroyAudioAccessor)),
             Some(f) => f(accessor),
         }
     }
@@ -7814,10 +7442,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DestroyLocalOscHandler(&self, local_osc_handler: *mut ::std::os::raw::c_void) {
         match self.pointers.DestroyLocalOscHandler {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DestroyLocalOscHandler)
-            ),
+            None => crate::function_not_available(stringify!(DestroyLocalOscHandler)),
             Some(f) => f(local_osc_handler),
         }
     }
@@ -7832,10 +7457,7 @@ impl Reaper {
         shortcutidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DoActionShortcutDialog {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DoActionShortcutDialog)
-            ),
+            None => crate::function_not_available(stringify!(DoActionShortcutDialog)),
             Some(f) => f(hwnd, section, cmdID, shortcutidx),
         }
     }
@@ -7848,19 +7470,13 @@ impl Reaper {
         whichDock: ::std::os::raw::c_int,
     ) {
         match self.pointers.Dock_UpdateDockID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Dock_UpdateDockID)
-            ),
+            None => crate::function_not_available(stringify!(Dock_UpdateDockID)),
             Some(f) => f(ident_str, whichDock),
         }
     }
     pub fn DockGetPosition(&self, whichDock: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
         match self.pointers.DockGetPosition {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockGetPosition)
-            ),
+            None => crate::function_not_available(stringify!(DockGetPosition)),
             Some(f) => f(whichDock),
         }
     }
@@ -7873,10 +7489,7 @@ impl Reaper {
         isFloatingDockerOut: *mut bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.DockIsChildOfDock {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockIsChildOfDock)
-            ),
+            None => crate::function_not_available(stringify!(DockIsChildOfDock)),
             Some(f) => f(hwnd, isFloatingDockerOut),
         }
     }
@@ -7885,10 +7498,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DockWindowActivate(&self, hwnd: root::HWND) {
         match self.pointers.DockWindowActivate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockWindowActivate)
-            ),
+            None => crate::function_not_available(stringify!(DockWindowActivate)),
             Some(f) => f(hwnd),
         }
     }
@@ -7903,10 +7513,7 @@ impl Reaper {
         allowShow: bool,
     ) {
         match self.pointers.DockWindowAdd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockWindowAdd)
-            ),
+            None => crate::function_not_available(stringify!(DockWindowAdd)),
             Some(f) => f(hwnd, name, pos, allowShow),
         }
     }
@@ -7921,19 +7528,13 @@ impl Reaper {
         allowShow: bool,
     ) {
         match self.pointers.DockWindowAddEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockWindowAddEx)
-            ),
+            None => crate::function_not_available(stringify!(DockWindowAddEx)),
             Some(f) => f(hwnd, name, identstr, allowShow),
         }
     }
     pub fn DockWindowRefresh(&self) {
         match self.pointers.DockWindowRefresh {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockWindowRefresh)
-            ),
+            None => crate::function_not_available(stringify!(DockWindowRefresh)),
             Some(f) => f(),
         }
     }
@@ -7942,10 +7543,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DockWindowRefreshForHWND(&self, hwnd: root::HWND) {
         match self.pointers.DockWindowRefreshForHWND {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockWindowRefreshForHWND)
-            ),
+            None => crate::function_not_available(stringify!(DockWindowRefreshForHWND)),
             Some(f) => f(hwnd),
         }
     }
@@ -7954,10 +7552,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DockWindowRemove(&self, hwnd: root::HWND) {
         match self.pointers.DockWindowRemove {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DockWindowRemove)
-            ),
+            None => crate::function_not_available(stringify!(DockWindowRemove)),
             Some(f) => f(hwnd),
         }
     }
@@ -7970,10 +7565,7 @@ impl Reaper {
         destmenu: *mut ::std::os::raw::c_void,
     ) -> bool {
         match self.pointers.DuplicateCustomizableMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DuplicateCustomizableMenu)
-            ),
+            None => crate::function_not_available(stringify!(DuplicateCustomizableMenu)),
             Some(f) => f(srcmenu, destmenu),
         }
     }
@@ -7986,10 +7578,7 @@ impl Reaper {
         markerindex: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.EditTempoTimeSigMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EditTempoTimeSigMarker)
-            ),
+            None => crate::function_not_available(stringify!(EditTempoTimeSigMarker)),
             Some(f) => f(project, markerindex),
         }
     }
@@ -7998,10 +7587,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn EnsureNotCompletelyOffscreen(&self, rInOut: *mut root::RECT) {
         match self.pointers.EnsureNotCompletelyOffscreen {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnsureNotCompletelyOffscreen)
-            ),
+            None => crate::function_not_available(stringify!(EnsureNotCompletelyOffscreen)),
             Some(f) => f(rInOut),
         }
     }
@@ -8014,10 +7600,7 @@ impl Reaper {
         fileindex: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.EnumerateFiles {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumerateFiles)
-            ),
+            None => crate::function_not_available(stringify!(EnumerateFiles)),
             Some(f) => f(path, fileindex),
         }
     }
@@ -8030,10 +7613,7 @@ impl Reaper {
         subdirindex: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.EnumerateSubdirectories {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumerateSubdirectories)
-            ),
+            None => crate::function_not_available(stringify!(EnumerateSubdirectories)),
             Some(f) => f(path, subdirindex),
         }
     }
@@ -8047,10 +7627,7 @@ impl Reaper {
         identOut: *mut *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.EnumInstalledFX {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumInstalledFX)
-            ),
+            None => crate::function_not_available(stringify!(EnumInstalledFX)),
             Some(f) => f(index, nameOut, identOut),
         }
     }
@@ -8063,10 +7640,7 @@ impl Reaper {
         strOut: *mut *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.EnumPitchShiftModes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumPitchShiftModes)
-            ),
+            None => crate::function_not_available(stringify!(EnumPitchShiftModes)),
             Some(f) => f(mode, strOut),
         }
     }
@@ -8076,10 +7650,7 @@ impl Reaper {
         submode: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.EnumPitchShiftSubModes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumPitchShiftSubModes)
-            ),
+            None => crate::function_not_available(stringify!(EnumPitchShiftSubModes)),
             Some(f) => f(mode, submode),
         }
     }
@@ -8096,10 +7667,7 @@ impl Reaper {
         markrgnindexnumberOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.EnumProjectMarkers {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumProjectMarkers)
-            ),
+            None => crate::function_not_available(stringify!(EnumProjectMarkers)),
             Some(f) => f(
                 idx,
                 isrgnOut,
@@ -8124,10 +7692,7 @@ impl Reaper {
         markrgnindexnumberOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.EnumProjectMarkers2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumProjectMarkers2)
-            ),
+            None => crate::function_not_available(stringify!(EnumProjectMarkers2)),
             Some(f) => f(
                 proj,
                 idx,
@@ -8154,10 +7719,7 @@ impl Reaper {
         colorOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.EnumProjectMarkers3 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumProjectMarkers3)
-            ),
+            None => crate::function_not_available(stringify!(EnumProjectMarkers3)),
             Some(f) => f(
                 proj,
                 idx,
@@ -8180,10 +7742,7 @@ impl Reaper {
         projfnOutOptional_sz: ::std::os::raw::c_int,
     ) -> *mut root::ReaProject {
         match self.pointers.EnumProjects {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumProjects)
-            ),
+            None => crate::function_not_available(stringify!(EnumProjects)),
             Some(f) => f(idx, projfnOutOptional, projfnOutOptional_sz),
         }
     }
@@ -8201,10 +7760,7 @@ impl Reaper {
         valOutOptional_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.EnumProjExtState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumProjExtState)
-            ),
+            None => crate::function_not_available(stringify!(EnumProjExtState)),
             Some(f) => f(
                 proj,
                 extname,
@@ -8226,10 +7782,7 @@ impl Reaper {
         rendertrack: ::std::os::raw::c_int,
     ) -> *mut root::MediaTrack {
         match self.pointers.EnumRegionRenderMatrix {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumRegionRenderMatrix)
-            ),
+            None => crate::function_not_available(stringify!(EnumRegionRenderMatrix)),
             Some(f) => f(proj, regionindex, rendertrack),
         }
     }
@@ -8244,10 +7797,7 @@ impl Reaper {
         programName_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.EnumTrackMIDIProgramNames {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumTrackMIDIProgramNames)
-            ),
+            None => crate::function_not_available(stringify!(EnumTrackMIDIProgramNames)),
             Some(f) => f(track, programNumber, programName, programName_sz),
         }
     }
@@ -8263,10 +7813,7 @@ impl Reaper {
         programName_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.EnumTrackMIDIProgramNamesEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumTrackMIDIProgramNamesEx)
-            ),
+            None => crate::function_not_available(stringify!(EnumTrackMIDIProgramNamesEx)),
             Some(f) => f(proj, track, programNumber, programName, programName_sz),
         }
     }
@@ -8285,10 +7832,7 @@ impl Reaper {
         dddVdSOut: *mut f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.Envelope_Evaluate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Envelope_Evaluate)
-            ),
+            None => crate::function_not_available(stringify!(Envelope_Evaluate)),
             Some(f) => f(
                 envelope,
                 time,
@@ -8312,10 +7856,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.Envelope_FormatValue {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Envelope_FormatValue)
-            ),
+            None => crate::function_not_available(stringify!(Envelope_FormatValue)),
             Some(f) => f(env, value, bufOut, bufOut_sz),
         }
     }
@@ -8329,10 +7870,7 @@ impl Reaper {
         index2Out: *mut ::std::os::raw::c_int,
     ) -> *mut root::MediaItem_Take {
         match self.pointers.Envelope_GetParentTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Envelope_GetParentTake)
-            ),
+            None => crate::function_not_available(stringify!(Envelope_GetParentTake)),
             Some(f) => f(env, indexOut, index2Out),
         }
     }
@@ -8346,10 +7884,7 @@ impl Reaper {
         index2Out: *mut ::std::os::raw::c_int,
     ) -> *mut root::MediaTrack {
         match self.pointers.Envelope_GetParentTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Envelope_GetParentTrack)
-            ),
+            None => crate::function_not_available(stringify!(Envelope_GetParentTrack)),
             Some(f) => f(env, indexOut, index2Out),
         }
     }
@@ -8358,10 +7893,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Envelope_SortPoints(&self, envelope: *mut root::TrackEnvelope) -> bool {
         match self.pointers.Envelope_SortPoints {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Envelope_SortPoints)
-            ),
+            None => crate::function_not_available(stringify!(Envelope_SortPoints)),
             Some(f) => f(envelope),
         }
     }
@@ -8374,10 +7906,7 @@ impl Reaper {
         autoitem_idx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.Envelope_SortPointsEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Envelope_SortPointsEx)
-            ),
+            None => crate::function_not_available(stringify!(Envelope_SortPointsEx)),
             Some(f) => f(envelope, autoitem_idx),
         }
     }
@@ -8390,10 +7919,7 @@ impl Reaper {
         timeoutmsec: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.ExecProcess {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ExecProcess)
-            ),
+            None => crate::function_not_available(stringify!(ExecProcess)),
             Some(f) => f(cmdline, timeoutmsec),
         }
     }
@@ -8402,10 +7928,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn file_exists(&self, path: *const ::std::os::raw::c_char) -> bool {
         match self.pointers.file_exists {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(file_exists)
-            ),
+            None => crate::function_not_available(stringify!(file_exists)),
             Some(f) => f(path),
         }
     }
@@ -8418,10 +7941,7 @@ impl Reaper {
         time: f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.FindTempoTimeSigMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(FindTempoTimeSigMarker)
-            ),
+            None => crate::function_not_available(stringify!(FindTempoTimeSigMarker)),
             Some(f) => f(project, time),
         }
     }
@@ -8435,10 +7955,7 @@ impl Reaper {
         buf_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.format_timestr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(format_timestr)
-            ),
+            None => crate::function_not_available(stringify!(format_timestr)),
             Some(f) => f(tpos, buf, buf_sz),
         }
     }
@@ -8454,10 +7971,7 @@ impl Reaper {
         modeoverride: ::std::os::raw::c_int,
     ) {
         match self.pointers.format_timestr_len {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(format_timestr_len)
-            ),
+            None => crate::function_not_available(stringify!(format_timestr_len)),
             Some(f) => f(tpos, buf, buf_sz, offset, modeoverride),
         }
     }
@@ -8472,10 +7986,7 @@ impl Reaper {
         modeoverride: ::std::os::raw::c_int,
     ) {
         match self.pointers.format_timestr_pos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(format_timestr_pos)
-            ),
+            None => crate::function_not_available(stringify!(format_timestr_pos)),
             Some(f) => f(tpos, buf, buf_sz, modeoverride),
         }
     }
@@ -8484,10 +7995,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn FreeHeapPtr(&self, ptr: *mut ::std::os::raw::c_void) {
         match self.pointers.FreeHeapPtr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(FreeHeapPtr)
-            ),
+            None => crate::function_not_available(stringify!(FreeHeapPtr)),
             Some(f) => f(ptr),
         }
     }
@@ -8496,10 +8004,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn genGuid(&self, g: *mut root::GUID) {
         match self.pointers.genGuid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(genGuid)
-            ),
+            None => crate::function_not_available(stringify!(genGuid)),
             Some(f) => f(g),
         }
     }
@@ -8512,10 +8017,7 @@ impl Reaper {
         szOut: *mut ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.get_config_var {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(get_config_var)
-            ),
+            None => crate::function_not_available(stringify!(get_config_var)),
             Some(f) => f(name, szOut),
         }
     }
@@ -8529,19 +8031,13 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.get_config_var_string {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(get_config_var_string)
-            ),
+            None => crate::function_not_available(stringify!(get_config_var_string)),
             Some(f) => f(name, bufOut, bufOut_sz),
         }
     }
     pub fn get_ini_file(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.get_ini_file {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(get_ini_file)
-            ),
+            None => crate::function_not_available(stringify!(get_ini_file)),
             Some(f) => f(),
         }
     }
@@ -8554,10 +8050,7 @@ impl Reaper {
         szOut: *mut ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.get_midi_config_var {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(get_midi_config_var)
-            ),
+            None => crate::function_not_available(stringify!(get_midi_config_var)),
             Some(f) => f(name, szOut),
         }
     }
@@ -8573,10 +8066,7 @@ impl Reaper {
         descOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetActionShortcutDesc {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetActionShortcutDesc)
-            ),
+            None => crate::function_not_available(stringify!(GetActionShortcutDesc)),
             Some(f) => f(section, cmdID, shortcutidx, descOut, descOut_sz),
         }
     }
@@ -8585,10 +8075,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetActiveTake(&self, item: *mut root::MediaItem) -> *mut root::MediaItem_Take {
         match self.pointers.GetActiveTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetActiveTake)
-            ),
+            None => crate::function_not_available(stringify!(GetActiveTake)),
             Some(f) => f(item),
         }
     }
@@ -8600,19 +8087,13 @@ impl Reaper {
         ignoreProject: *mut root::ReaProject,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetAllProjectPlayStates {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAllProjectPlayStates)
-            ),
+            None => crate::function_not_available(stringify!(GetAllProjectPlayStates)),
             Some(f) => f(ignoreProject),
         }
     }
     pub fn GetAppVersion(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.GetAppVersion {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAppVersion)
-            ),
+            None => crate::function_not_available(stringify!(GetAppVersion)),
             Some(f) => f(),
         }
     }
@@ -8625,10 +8106,7 @@ impl Reaper {
         secOut_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetArmedCommand {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetArmedCommand)
-            ),
+            None => crate::function_not_available(stringify!(GetArmedCommand)),
             Some(f) => f(secOut, secOut_sz),
         }
     }
@@ -8640,10 +8118,7 @@ impl Reaper {
         accessor: *mut root::reaper_functions::AudioAccessor,
     ) -> f64 {
         match self.pointers.GetAudioAccessorEndTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAudioAccessorEndTime)
-            ),
+            None => crate::function_not_available(stringify!(GetAudioAccessorEndTime)),
             Some(f) => f(accessor),
         }
     }
@@ -8656,10 +8131,7 @@ impl Reaper {
         hashNeed128: *mut ::std::os::raw::c_char,
     ) {
         match self.pointers.GetAudioAccessorHash {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAudioAccessorHash)
-            ),
+            None => crate::function_not_available(stringify!(GetAudioAccessorHash)),
             Some(f) => f(accessor, hashNeed128),
         }
     }
@@ -8676,10 +8148,7 @@ impl Reaper {
         samplebuffer: *mut f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetAudioAccessorSamples {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAudioAccessorSamples)
-            ),
+            None => crate::function_not_available(stringify!(GetAudioAccessorSamples)),
             Some(f) => f(
                 accessor,
                 samplerate,
@@ -8698,10 +8167,7 @@ impl Reaper {
         accessor: *mut root::reaper_functions::AudioAccessor,
     ) -> f64 {
         match self.pointers.GetAudioAccessorStartTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAudioAccessorStartTime)
-            ),
+            None => crate::function_not_available(stringify!(GetAudioAccessorStartTime)),
             Some(f) => f(accessor),
         }
     }
@@ -8715,10 +8181,7 @@ impl Reaper {
         descOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetAudioDeviceInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAudioDeviceInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetAudioDeviceInfo)),
             Some(f) => f(attribute, descOut, descOut_sz),
         }
     }
@@ -8728,10 +8191,7 @@ impl Reaper {
         defval: ::std::os::raw::c_int,
     ) -> root::INT_PTR {
         match self.pointers.GetColorTheme {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetColorTheme)
-            ),
+            None => crate::function_not_available(stringify!(GetColorTheme)),
             Some(f) => f(idx, defval),
         }
     }
@@ -8743,10 +8203,7 @@ impl Reaper {
         szOut: *mut ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetColorThemeStruct {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetColorThemeStruct)
-            ),
+            None => crate::function_not_available(stringify!(GetColorThemeStruct)),
             Some(f) => f(szOut),
         }
     }
@@ -8758,55 +8215,37 @@ impl Reaper {
         ident_str: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetConfigWantsDock {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetConfigWantsDock)
-            ),
+            None => crate::function_not_available(stringify!(GetConfigWantsDock)),
             Some(f) => f(ident_str),
         }
     }
     pub fn GetContextMenu(&self, idx: ::std::os::raw::c_int) -> root::HMENU {
         match self.pointers.GetContextMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetContextMenu)
-            ),
+            None => crate::function_not_available(stringify!(GetContextMenu)),
             Some(f) => f(idx),
         }
     }
     pub fn GetCurrentProjectInLoadSave(&self) -> *mut root::ReaProject {
         match self.pointers.GetCurrentProjectInLoadSave {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCurrentProjectInLoadSave)
-            ),
+            None => crate::function_not_available(stringify!(GetCurrentProjectInLoadSave)),
             Some(f) => f(),
         }
     }
     pub fn GetCursorContext(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetCursorContext {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCursorContext)
-            ),
+            None => crate::function_not_available(stringify!(GetCursorContext)),
             Some(f) => f(),
         }
     }
     pub fn GetCursorContext2(&self, want_last_valid: bool) -> ::std::os::raw::c_int {
         match self.pointers.GetCursorContext2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCursorContext2)
-            ),
+            None => crate::function_not_available(stringify!(GetCursorContext2)),
             Some(f) => f(want_last_valid),
         }
     }
     pub fn GetCursorPosition(&self) -> f64 {
         match self.pointers.GetCursorPosition {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCursorPosition)
-            ),
+            None => crate::function_not_available(stringify!(GetCursorPosition)),
             Some(f) => f(),
         }
     }
@@ -8815,10 +8254,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetCursorPositionEx(&self, proj: *mut root::ReaProject) -> f64 {
         match self.pointers.GetCursorPositionEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCursorPositionEx)
-            ),
+            None => crate::function_not_available(stringify!(GetCursorPositionEx)),
             Some(f) => f(proj),
         }
     }
@@ -8830,10 +8266,7 @@ impl Reaper {
         item: *mut root::MediaItem,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetDisplayedMediaItemColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetDisplayedMediaItemColor)
-            ),
+            None => crate::function_not_available(stringify!(GetDisplayedMediaItemColor)),
             Some(f) => f(item),
         }
     }
@@ -8846,10 +8279,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetDisplayedMediaItemColor2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetDisplayedMediaItemColor2)
-            ),
+            None => crate::function_not_available(stringify!(GetDisplayedMediaItemColor2)),
             Some(f) => f(item, take),
         }
     }
@@ -8862,10 +8292,7 @@ impl Reaper {
         parmname: *const ::std::os::raw::c_char,
     ) -> f64 {
         match self.pointers.GetEnvelopeInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopeInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopeInfo_Value)),
             Some(f) => f(env, parmname),
         }
     }
@@ -8879,10 +8306,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetEnvelopeName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopeName)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopeName)),
             Some(f) => f(env, bufOut, bufOut_sz),
         }
     }
@@ -8900,10 +8324,7 @@ impl Reaper {
         selectedOut: *mut bool,
     ) -> bool {
         match self.pointers.GetEnvelopePoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopePoint)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopePoint)),
             Some(f) => f(
                 envelope,
                 ptidx,
@@ -8924,10 +8345,7 @@ impl Reaper {
         time: f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetEnvelopePointByTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopePointByTime)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopePointByTime)),
             Some(f) => f(envelope, time),
         }
     }
@@ -8941,10 +8359,7 @@ impl Reaper {
         time: f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetEnvelopePointByTimeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopePointByTimeEx)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopePointByTimeEx)),
             Some(f) => f(envelope, autoitem_idx, time),
         }
     }
@@ -8963,10 +8378,7 @@ impl Reaper {
         selectedOut: *mut bool,
     ) -> bool {
         match self.pointers.GetEnvelopePointEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopePointEx)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopePointEx)),
             Some(f) => f(
                 envelope,
                 autoitem_idx,
@@ -8987,10 +8399,7 @@ impl Reaper {
         env: *mut root::TrackEnvelope,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetEnvelopeScalingMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopeScalingMode)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopeScalingMode)),
             Some(f) => f(env),
         }
     }
@@ -9005,10 +8414,7 @@ impl Reaper {
         isundoOptional: bool,
     ) -> bool {
         match self.pointers.GetEnvelopeStateChunk {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopeStateChunk)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopeStateChunk)),
             Some(f) => f(env, strNeedBig, strNeedBig_sz, isundoOptional),
         }
     }
@@ -9020,19 +8426,13 @@ impl Reaper {
         env: *mut root::TrackEnvelope,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetEnvelopeUIState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetEnvelopeUIState)
-            ),
+            None => crate::function_not_available(stringify!(GetEnvelopeUIState)),
             Some(f) => f(env),
         }
     }
     pub fn GetExePath(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.GetExePath {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetExePath)
-            ),
+            None => crate::function_not_available(stringify!(GetExePath)),
             Some(f) => f(),
         }
     }
@@ -9045,10 +8445,7 @@ impl Reaper {
         key: *const ::std::os::raw::c_char,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetExtState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetExtState)
-            ),
+            None => crate::function_not_available(stringify!(GetExtState)),
             Some(f) => f(section, key),
         }
     }
@@ -9062,10 +8459,7 @@ impl Reaper {
         fxnumberOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetFocusedFX {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetFocusedFX)
-            ),
+            None => crate::function_not_available(stringify!(GetFocusedFX)),
             Some(f) => f(tracknumberOut, itemnumberOut, fxnumberOut),
         }
     }
@@ -9079,10 +8473,7 @@ impl Reaper {
         fxnumberOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetFocusedFX2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetFocusedFX2)
-            ),
+            None => crate::function_not_available(stringify!(GetFocusedFX2)),
             Some(f) => f(tracknumberOut, itemnumberOut, fxnumberOut),
         }
     }
@@ -9095,10 +8486,7 @@ impl Reaper {
         pathidx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetFreeDiskSpaceForRecordPath {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetFreeDiskSpaceForRecordPath)
-            ),
+            None => crate::function_not_available(stringify!(GetFreeDiskSpaceForRecordPath)),
             Some(f) => f(proj, pathidx),
         }
     }
@@ -9113,28 +8501,19 @@ impl Reaper {
         create: bool,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetFXEnvelope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetFXEnvelope)
-            ),
+            None => crate::function_not_available(stringify!(GetFXEnvelope)),
             Some(f) => f(track, fxindex, parameterindex, create),
         }
     }
     pub fn GetGlobalAutomationOverride(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetGlobalAutomationOverride {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetGlobalAutomationOverride)
-            ),
+            None => crate::function_not_available(stringify!(GetGlobalAutomationOverride)),
             Some(f) => f(),
         }
     }
     pub fn GetHZoomLevel(&self) -> f64 {
         match self.pointers.GetHZoomLevel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetHZoomLevel)
-            ),
+            None => crate::function_not_available(stringify!(GetHZoomLevel)),
             Some(f) => f(),
         }
     }
@@ -9146,10 +8525,7 @@ impl Reaper {
         name: *const ::std::os::raw::c_char,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetIconThemePointer {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetIconThemePointer)
-            ),
+            None => crate::function_not_available(stringify!(GetIconThemePointer)),
             Some(f) => f(name),
         }
     }
@@ -9162,10 +8538,7 @@ impl Reaper {
         dpisc: ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetIconThemePointerForDPI {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetIconThemePointerForDPI)
-            ),
+            None => crate::function_not_available(stringify!(GetIconThemePointerForDPI)),
             Some(f) => f(name, dpisc),
         }
     }
@@ -9177,19 +8550,13 @@ impl Reaper {
         szOut: *mut ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetIconThemeStruct {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetIconThemeStruct)
-            ),
+            None => crate::function_not_available(stringify!(GetIconThemeStruct)),
             Some(f) => f(szOut),
         }
     }
     pub fn GetInputActivityLevel(&self, input_id: ::std::os::raw::c_int) -> f64 {
         match self.pointers.GetInputActivityLevel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetInputActivityLevel)
-            ),
+            None => crate::function_not_available(stringify!(GetInputActivityLevel)),
             Some(f) => f(input_id),
         }
     }
@@ -9198,10 +8565,7 @@ impl Reaper {
         channelIndex: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetInputChannelName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetInputChannelName)
-            ),
+            None => crate::function_not_available(stringify!(GetInputChannelName)),
             Some(f) => f(channelIndex),
         }
     }
@@ -9214,10 +8578,7 @@ impl Reaper {
         outputLatencyOut: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.GetInputOutputLatency {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetInputOutputLatency)
-            ),
+            None => crate::function_not_available(stringify!(GetInputOutputLatency)),
             Some(f) => f(inputlatencyOut, outputLatencyOut),
         }
     }
@@ -9230,10 +8591,7 @@ impl Reaper {
         flagsOut: *mut ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.GetItemEditingTime2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetItemEditingTime2)
-            ),
+            None => crate::function_not_available(stringify!(GetItemEditingTime2)),
             Some(f) => f(which_itemOut, flagsOut),
         }
     }
@@ -9248,10 +8606,7 @@ impl Reaper {
         takeOutOptional: *mut *mut root::MediaItem_Take,
     ) -> *mut root::MediaItem {
         match self.pointers.GetItemFromPoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetItemFromPoint)
-            ),
+            None => crate::function_not_available(stringify!(GetItemFromPoint)),
             Some(f) => f(screen_x, screen_y, allow_locked, takeOutOptional),
         }
     }
@@ -9263,10 +8618,7 @@ impl Reaper {
         item: *mut root::MediaItem,
     ) -> *mut root::ReaProject {
         match self.pointers.GetItemProjectContext {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetItemProjectContext)
-            ),
+            None => crate::function_not_available(stringify!(GetItemProjectContext)),
             Some(f) => f(item),
         }
     }
@@ -9281,19 +8633,13 @@ impl Reaper {
         isundoOptional: bool,
     ) -> bool {
         match self.pointers.GetItemStateChunk {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetItemStateChunk)
-            ),
+            None => crate::function_not_available(stringify!(GetItemStateChunk)),
             Some(f) => f(item, strNeedBig, strNeedBig_sz, isundoOptional),
         }
     }
     pub fn GetLastColorThemeFile(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.GetLastColorThemeFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetLastColorThemeFile)
-            ),
+            None => crate::function_not_available(stringify!(GetLastColorThemeFile)),
             Some(f) => f(),
         }
     }
@@ -9308,10 +8654,7 @@ impl Reaper {
         regionidxOut: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.GetLastMarkerAndCurRegion {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetLastMarkerAndCurRegion)
-            ),
+            None => crate::function_not_available(stringify!(GetLastMarkerAndCurRegion)),
             Some(f) => f(proj, time, markeridxOut, regionidxOut),
         }
     }
@@ -9325,37 +8668,25 @@ impl Reaper {
         paramnumberOut: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetLastTouchedFX {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetLastTouchedFX)
-            ),
+            None => crate::function_not_available(stringify!(GetLastTouchedFX)),
             Some(f) => f(tracknumberOut, fxnumberOut, paramnumberOut),
         }
     }
     pub fn GetLastTouchedTrack(&self) -> *mut root::MediaTrack {
         match self.pointers.GetLastTouchedTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetLastTouchedTrack)
-            ),
+            None => crate::function_not_available(stringify!(GetLastTouchedTrack)),
             Some(f) => f(),
         }
     }
     pub fn GetMainHwnd(&self) -> root::HWND {
         match self.pointers.GetMainHwnd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMainHwnd)
-            ),
+            None => crate::function_not_available(stringify!(GetMainHwnd)),
             Some(f) => f(),
         }
     }
     pub fn GetMasterMuteSoloFlags(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetMasterMuteSoloFlags {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMasterMuteSoloFlags)
-            ),
+            None => crate::function_not_available(stringify!(GetMasterMuteSoloFlags)),
             Some(f) => f(),
         }
     }
@@ -9364,37 +8695,25 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetMasterTrack(&self, proj: *mut root::ReaProject) -> *mut root::MediaTrack {
         match self.pointers.GetMasterTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMasterTrack)
-            ),
+            None => crate::function_not_available(stringify!(GetMasterTrack)),
             Some(f) => f(proj),
         }
     }
     pub fn GetMasterTrackVisibility(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetMasterTrackVisibility {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMasterTrackVisibility)
-            ),
+            None => crate::function_not_available(stringify!(GetMasterTrackVisibility)),
             Some(f) => f(),
         }
     }
     pub fn GetMaxMidiInputs(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetMaxMidiInputs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMaxMidiInputs)
-            ),
+            None => crate::function_not_available(stringify!(GetMaxMidiInputs)),
             Some(f) => f(),
         }
     }
     pub fn GetMaxMidiOutputs(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetMaxMidiOutputs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMaxMidiOutputs)
-            ),
+            None => crate::function_not_available(stringify!(GetMaxMidiOutputs)),
             Some(f) => f(),
         }
     }
@@ -9409,10 +8728,7 @@ impl Reaper {
         bufOutNeedBig_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetMediaFileMetadata {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaFileMetadata)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaFileMetadata)),
             Some(f) => f(mediaSource, identifier, bufOutNeedBig, bufOutNeedBig_sz),
         }
     }
@@ -9425,10 +8741,7 @@ impl Reaper {
         itemidx: ::std::os::raw::c_int,
     ) -> *mut root::MediaItem {
         match self.pointers.GetMediaItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItem)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItem)),
             Some(f) => f(proj, itemidx),
         }
     }
@@ -9437,10 +8750,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetMediaItem_Track(&self, item: *mut root::MediaItem) -> *mut root::MediaTrack {
         match self.pointers.GetMediaItem_Track {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItem_Track)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItem_Track)),
             Some(f) => f(item),
         }
     }
@@ -9453,10 +8763,7 @@ impl Reaper {
         parmname: *const ::std::os::raw::c_char,
     ) -> f64 {
         match self.pointers.GetMediaItemInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemInfo_Value)),
             Some(f) => f(item, parmname),
         }
     }
@@ -9465,10 +8772,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetMediaItemNumTakes(&self, item: *mut root::MediaItem) -> ::std::os::raw::c_int {
         match self.pointers.GetMediaItemNumTakes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemNumTakes)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemNumTakes)),
             Some(f) => f(item),
         }
     }
@@ -9481,10 +8785,7 @@ impl Reaper {
         tk: ::std::os::raw::c_int,
     ) -> *mut root::MediaItem_Take {
         match self.pointers.GetMediaItemTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTake)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTake)),
             Some(f) => f(item, tk),
         }
     }
@@ -9496,10 +8797,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> *mut root::MediaItem {
         match self.pointers.GetMediaItemTake_Item {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTake_Item)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTake_Item)),
             Some(f) => f(take),
         }
     }
@@ -9517,10 +8815,7 @@ impl Reaper {
         buf: *mut f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetMediaItemTake_Peaks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTake_Peaks)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTake_Peaks)),
             Some(f) => f(
                 take,
                 peakrate,
@@ -9540,10 +8835,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> *mut root::PCM_source {
         match self.pointers.GetMediaItemTake_Source {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTake_Source)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTake_Source)),
             Some(f) => f(take),
         }
     }
@@ -9555,10 +8847,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> *mut root::MediaTrack {
         match self.pointers.GetMediaItemTake_Track {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTake_Track)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTake_Track)),
             Some(f) => f(take),
         }
     }
@@ -9571,10 +8860,7 @@ impl Reaper {
         guid: *const root::GUID,
     ) -> *mut root::MediaItem_Take {
         match self.pointers.GetMediaItemTakeByGUID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTakeByGUID)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTakeByGUID)),
             Some(f) => f(project, guid),
         }
     }
@@ -9587,10 +8873,7 @@ impl Reaper {
         parmname: *const ::std::os::raw::c_char,
     ) -> f64 {
         match self.pointers.GetMediaItemTakeInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTakeInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTakeInfo_Value)),
             Some(f) => f(take, parmname),
         }
     }
@@ -9599,10 +8882,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetMediaItemTrack(&self, item: *mut root::MediaItem) -> *mut root::MediaTrack {
         match self.pointers.GetMediaItemTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaItemTrack)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaItemTrack)),
             Some(f) => f(item),
         }
     }
@@ -9616,10 +8896,7 @@ impl Reaper {
         filenamebufOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetMediaSourceFileName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaSourceFileName)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaSourceFileName)),
             Some(f) => f(source, filenamebufOut, filenamebufOut_sz),
         }
     }
@@ -9632,10 +8909,7 @@ impl Reaper {
         lengthIsQNOut: *mut bool,
     ) -> f64 {
         match self.pointers.GetMediaSourceLength {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaSourceLength)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaSourceLength)),
             Some(f) => f(source, lengthIsQNOut),
         }
     }
@@ -9647,10 +8921,7 @@ impl Reaper {
         source: *mut root::PCM_source,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetMediaSourceNumChannels {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaSourceNumChannels)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaSourceNumChannels)),
             Some(f) => f(source),
         }
     }
@@ -9659,10 +8930,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetMediaSourceParent(&self, src: *mut root::PCM_source) -> *mut root::PCM_source {
         match self.pointers.GetMediaSourceParent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaSourceParent)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaSourceParent)),
             Some(f) => f(src),
         }
     }
@@ -9674,10 +8942,7 @@ impl Reaper {
         source: *mut root::PCM_source,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetMediaSourceSampleRate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaSourceSampleRate)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaSourceSampleRate)),
             Some(f) => f(source),
         }
     }
@@ -9691,10 +8956,7 @@ impl Reaper {
         typebufOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetMediaSourceType {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaSourceType)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaSourceType)),
             Some(f) => f(source, typebufOut, typebufOut_sz),
         }
     }
@@ -9707,10 +8969,7 @@ impl Reaper {
         parmname: *const ::std::os::raw::c_char,
     ) -> f64 {
         match self.pointers.GetMediaTrackInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMediaTrackInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(GetMediaTrackInfo_Value)),
             Some(f) => f(tr, parmname),
         }
     }
@@ -9724,10 +8983,7 @@ impl Reaper {
         nameout_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetMIDIInputName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMIDIInputName)
-            ),
+            None => crate::function_not_available(stringify!(GetMIDIInputName)),
             Some(f) => f(dev, nameout, nameout_sz),
         }
     }
@@ -9741,19 +8997,13 @@ impl Reaper {
         nameout_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetMIDIOutputName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMIDIOutputName)
-            ),
+            None => crate::function_not_available(stringify!(GetMIDIOutputName)),
             Some(f) => f(dev, nameout, nameout_sz),
         }
     }
     pub fn GetMixerScroll(&self) -> *mut root::MediaTrack {
         match self.pointers.GetMixerScroll {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMixerScroll)
-            ),
+            None => crate::function_not_available(stringify!(GetMixerScroll)),
             Some(f) => f(),
         }
     }
@@ -9768,10 +9018,7 @@ impl Reaper {
         actionOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetMouseModifier {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMouseModifier)
-            ),
+            None => crate::function_not_available(stringify!(GetMouseModifier)),
             Some(f) => f(context, modifier_flag, actionOut, actionOut_sz),
         }
     }
@@ -9784,46 +9031,31 @@ impl Reaper {
         yOut: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.GetMousePosition {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMousePosition)
-            ),
+            None => crate::function_not_available(stringify!(GetMousePosition)),
             Some(f) => f(xOut, yOut),
         }
     }
     pub fn GetNumAudioInputs(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetNumAudioInputs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetNumAudioInputs)
-            ),
+            None => crate::function_not_available(stringify!(GetNumAudioInputs)),
             Some(f) => f(),
         }
     }
     pub fn GetNumAudioOutputs(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetNumAudioOutputs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetNumAudioOutputs)
-            ),
+            None => crate::function_not_available(stringify!(GetNumAudioOutputs)),
             Some(f) => f(),
         }
     }
     pub fn GetNumMIDIInputs(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetNumMIDIInputs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetNumMIDIInputs)
-            ),
+            None => crate::function_not_available(stringify!(GetNumMIDIInputs)),
             Some(f) => f(),
         }
     }
     pub fn GetNumMIDIOutputs(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetNumMIDIOutputs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetNumMIDIOutputs)
-            ),
+            None => crate::function_not_available(stringify!(GetNumMIDIOutputs)),
             Some(f) => f(),
         }
     }
@@ -9835,28 +9067,19 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetNumTakeMarkers {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetNumTakeMarkers)
-            ),
+            None => crate::function_not_available(stringify!(GetNumTakeMarkers)),
             Some(f) => f(take),
         }
     }
     pub fn GetNumTracks(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetNumTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetNumTracks)
-            ),
+            None => crate::function_not_available(stringify!(GetNumTracks)),
             Some(f) => f(),
         }
     }
     pub fn GetOS(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.GetOS {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetOS)
-            ),
+            None => crate::function_not_available(stringify!(GetOS)),
             Some(f) => f(),
         }
     }
@@ -9865,19 +9088,13 @@ impl Reaper {
         channelIndex: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetOutputChannelName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetOutputChannelName)
-            ),
+            None => crate::function_not_available(stringify!(GetOutputChannelName)),
             Some(f) => f(channelIndex),
         }
     }
     pub fn GetOutputLatency(&self) -> f64 {
         match self.pointers.GetOutputLatency {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetOutputLatency)
-            ),
+            None => crate::function_not_available(stringify!(GetOutputLatency)),
             Some(f) => f(),
         }
     }
@@ -9886,10 +9103,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetParentTrack(&self, track: *mut root::MediaTrack) -> *mut root::MediaTrack {
         match self.pointers.GetParentTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetParentTrack)
-            ),
+            None => crate::function_not_available(stringify!(GetParentTrack)),
             Some(f) => f(track),
         }
     }
@@ -9903,10 +9117,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetPeakFileName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPeakFileName)
-            ),
+            None => crate::function_not_available(stringify!(GetPeakFileName)),
             Some(f) => f(fn_, bufOut, bufOut_sz),
         }
     }
@@ -9921,10 +9132,7 @@ impl Reaper {
         forWrite: bool,
     ) {
         match self.pointers.GetPeakFileNameEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPeakFileNameEx)
-            ),
+            None => crate::function_not_available(stringify!(GetPeakFileNameEx)),
             Some(f) => f(fn_, buf, buf_sz, forWrite),
         }
     }
@@ -9940,10 +9148,7 @@ impl Reaper {
         peaksfileextension: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.GetPeakFileNameEx2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPeakFileNameEx2)
-            ),
+            None => crate::function_not_available(stringify!(GetPeakFileNameEx2)),
             Some(f) => f(fn_, buf, buf_sz, forWrite, peaksfileextension),
         }
     }
@@ -9959,28 +9164,19 @@ impl Reaper {
         bmp: *mut root::reaper_functions::LICE_IBitmap,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetPeaksBitmap {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPeaksBitmap)
-            ),
+            None => crate::function_not_available(stringify!(GetPeaksBitmap)),
             Some(f) => f(pks, maxamp, w, h, bmp),
         }
     }
     pub fn GetPlayPosition(&self) -> f64 {
         match self.pointers.GetPlayPosition {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPlayPosition)
-            ),
+            None => crate::function_not_available(stringify!(GetPlayPosition)),
             Some(f) => f(),
         }
     }
     pub fn GetPlayPosition2(&self) -> f64 {
         match self.pointers.GetPlayPosition2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPlayPosition2)
-            ),
+            None => crate::function_not_available(stringify!(GetPlayPosition2)),
             Some(f) => f(),
         }
     }
@@ -9989,10 +9185,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetPlayPosition2Ex(&self, proj: *mut root::ReaProject) -> f64 {
         match self.pointers.GetPlayPosition2Ex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPlayPosition2Ex)
-            ),
+            None => crate::function_not_available(stringify!(GetPlayPosition2Ex)),
             Some(f) => f(proj),
         }
     }
@@ -10001,19 +9194,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetPlayPositionEx(&self, proj: *mut root::ReaProject) -> f64 {
         match self.pointers.GetPlayPositionEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPlayPositionEx)
-            ),
+            None => crate::function_not_available(stringify!(GetPlayPositionEx)),
             Some(f) => f(proj),
         }
     }
     pub fn GetPlayState(&self) -> ::std::os::raw::c_int {
         match self.pointers.GetPlayState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPlayState)
-            ),
+            None => crate::function_not_available(stringify!(GetPlayState)),
             Some(f) => f(),
         }
     }
@@ -10022,10 +9209,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetPlayStateEx(&self, proj: *mut root::ReaProject) -> ::std::os::raw::c_int {
         match self.pointers.GetPlayStateEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPlayStateEx)
-            ),
+            None => crate::function_not_available(stringify!(GetPlayStateEx)),
             Some(f) => f(proj),
         }
     }
@@ -10039,10 +9223,7 @@ impl Reaper {
         bs: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.GetPreferredDiskReadMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPreferredDiskReadMode)
-            ),
+            None => crate::function_not_available(stringify!(GetPreferredDiskReadMode)),
             Some(f) => f(mode, nb, bs),
         }
     }
@@ -10056,10 +9237,7 @@ impl Reaper {
         bs: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.GetPreferredDiskReadModePeak {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPreferredDiskReadModePeak)
-            ),
+            None => crate::function_not_available(stringify!(GetPreferredDiskReadModePeak)),
             Some(f) => f(mode, nb, bs),
         }
     }
@@ -10073,10 +9251,7 @@ impl Reaper {
         bs: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.GetPreferredDiskWriteMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPreferredDiskWriteMode)
-            ),
+            None => crate::function_not_available(stringify!(GetPreferredDiskWriteMode)),
             Some(f) => f(mode, nb, bs),
         }
     }
@@ -10085,10 +9260,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetProjectLength(&self, proj: *mut root::ReaProject) -> f64 {
         match self.pointers.GetProjectLength {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectLength)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectLength)),
             Some(f) => f(proj),
         }
     }
@@ -10102,10 +9274,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetProjectName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectName)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectName)),
             Some(f) => f(proj, bufOut, bufOut_sz),
         }
     }
@@ -10118,10 +9287,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetProjectPath {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectPath)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectPath)),
             Some(f) => f(bufOut, bufOut_sz),
         }
     }
@@ -10135,10 +9301,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetProjectPathEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectPathEx)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectPathEx)),
             Some(f) => f(proj, bufOut, bufOut_sz),
         }
     }
@@ -10150,10 +9313,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetProjectStateChangeCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectStateChangeCount)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectStateChangeCount)),
             Some(f) => f(proj),
         }
     }
@@ -10162,10 +9322,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetProjectTimeOffset(&self, proj: *mut root::ReaProject, rndframe: bool) -> f64 {
         match self.pointers.GetProjectTimeOffset {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectTimeOffset)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectTimeOffset)),
             Some(f) => f(proj, rndframe),
         }
     }
@@ -10174,10 +9331,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetProjectTimeSignature(&self, bpmOut: *mut f64, bpiOut: *mut f64) {
         match self.pointers.GetProjectTimeSignature {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectTimeSignature)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectTimeSignature)),
             Some(f) => f(bpmOut, bpiOut),
         }
     }
@@ -10191,10 +9345,7 @@ impl Reaper {
         bpiOut: *mut f64,
     ) {
         match self.pointers.GetProjectTimeSignature2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjectTimeSignature2)
-            ),
+            None => crate::function_not_available(stringify!(GetProjectTimeSignature2)),
             Some(f) => f(proj, bpmOut, bpiOut),
         }
     }
@@ -10210,19 +9361,13 @@ impl Reaper {
         valOutNeedBig_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetProjExtState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProjExtState)
-            ),
+            None => crate::function_not_available(stringify!(GetProjExtState)),
             Some(f) => f(proj, extname, key, valOutNeedBig, valOutNeedBig_sz),
         }
     }
     pub fn GetResourcePath(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.GetResourcePath {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetResourcePath)
-            ),
+            None => crate::function_not_available(stringify!(GetResourcePath)),
             Some(f) => f(),
         }
     }
@@ -10234,10 +9379,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetSelectedEnvelope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSelectedEnvelope)
-            ),
+            None => crate::function_not_available(stringify!(GetSelectedEnvelope)),
             Some(f) => f(proj),
         }
     }
@@ -10250,10 +9392,7 @@ impl Reaper {
         selitem: ::std::os::raw::c_int,
     ) -> *mut root::MediaItem {
         match self.pointers.GetSelectedMediaItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSelectedMediaItem)
-            ),
+            None => crate::function_not_available(stringify!(GetSelectedMediaItem)),
             Some(f) => f(proj, selitem),
         }
     }
@@ -10266,10 +9405,7 @@ impl Reaper {
         seltrackidx: ::std::os::raw::c_int,
     ) -> *mut root::MediaTrack {
         match self.pointers.GetSelectedTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSelectedTrack)
-            ),
+            None => crate::function_not_available(stringify!(GetSelectedTrack)),
             Some(f) => f(proj, seltrackidx),
         }
     }
@@ -10283,10 +9419,7 @@ impl Reaper {
         wantmaster: bool,
     ) -> *mut root::MediaTrack {
         match self.pointers.GetSelectedTrack2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSelectedTrack2)
-            ),
+            None => crate::function_not_available(stringify!(GetSelectedTrack2)),
             Some(f) => f(proj, seltrackidx, wantmaster),
         }
     }
@@ -10298,10 +9431,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetSelectedTrackEnvelope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSelectedTrackEnvelope)
-            ),
+            None => crate::function_not_available(stringify!(GetSelectedTrackEnvelope)),
             Some(f) => f(proj),
         }
     }
@@ -10318,10 +9448,7 @@ impl Reaper {
         end_timeInOut: *mut f64,
     ) {
         match self.pointers.GetSet_ArrangeView2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSet_ArrangeView2)
-            ),
+            None => crate::function_not_available(stringify!(GetSet_ArrangeView2)),
             Some(f) => f(
                 proj,
                 isSet,
@@ -10344,10 +9471,7 @@ impl Reaper {
         allowautoseek: bool,
     ) {
         match self.pointers.GetSet_LoopTimeRange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSet_LoopTimeRange)
-            ),
+            None => crate::function_not_available(stringify!(GetSet_LoopTimeRange)),
             Some(f) => f(isSet, isLoop, startOut, endOut, allowautoseek),
         }
     }
@@ -10364,10 +9488,7 @@ impl Reaper {
         allowautoseek: bool,
     ) {
         match self.pointers.GetSet_LoopTimeRange2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSet_LoopTimeRange2)
-            ),
+            None => crate::function_not_available(stringify!(GetSet_LoopTimeRange2)),
             Some(f) => f(proj, isSet, isLoop, startOut, endOut, allowautoseek),
         }
     }
@@ -10383,10 +9504,7 @@ impl Reaper {
         is_set: bool,
     ) -> f64 {
         match self.pointers.GetSetAutomationItemInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetAutomationItemInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetSetAutomationItemInfo)),
             Some(f) => f(env, autoitem_idx, desc, value, is_set),
         }
     }
@@ -10402,10 +9520,7 @@ impl Reaper {
         is_set: bool,
     ) -> bool {
         match self.pointers.GetSetAutomationItemInfo_String {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetAutomationItemInfo_String)
-            ),
+            None => crate::function_not_available(stringify!(GetSetAutomationItemInfo_String)),
             Some(f) => f(env, autoitem_idx, desc, valuestrNeedBig, is_set),
         }
     }
@@ -10420,10 +9535,7 @@ impl Reaper {
         setNewValue: bool,
     ) -> bool {
         match self.pointers.GetSetEnvelopeInfo_String {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetEnvelopeInfo_String)
-            ),
+            None => crate::function_not_available(stringify!(GetSetEnvelopeInfo_String)),
             Some(f) => f(env, parmname, stringNeedBig, setNewValue),
         }
     }
@@ -10437,10 +9549,7 @@ impl Reaper {
         str_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetSetEnvelopeState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetEnvelopeState)
-            ),
+            None => crate::function_not_available(stringify!(GetSetEnvelopeState)),
             Some(f) => f(env, str, str_sz),
         }
     }
@@ -10455,10 +9564,7 @@ impl Reaper {
         isundo: bool,
     ) -> bool {
         match self.pointers.GetSetEnvelopeState2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetEnvelopeState2)
-            ),
+            None => crate::function_not_available(stringify!(GetSetEnvelopeState2)),
             Some(f) => f(env, str, str_sz, isundo),
         }
     }
@@ -10472,10 +9578,7 @@ impl Reaper {
         str_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetSetItemState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetItemState)
-            ),
+            None => crate::function_not_available(stringify!(GetSetItemState)),
             Some(f) => f(item, str, str_sz),
         }
     }
@@ -10490,10 +9593,7 @@ impl Reaper {
         isundo: bool,
     ) -> bool {
         match self.pointers.GetSetItemState2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetItemState2)
-            ),
+            None => crate::function_not_available(stringify!(GetSetItemState2)),
             Some(f) => f(item, str, str_sz, isundo),
         }
     }
@@ -10507,10 +9607,7 @@ impl Reaper {
         setNewValue: *mut ::std::os::raw::c_void,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetSetMediaItemInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetMediaItemInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetSetMediaItemInfo)),
             Some(f) => f(item, parmname, setNewValue),
         }
     }
@@ -10525,10 +9622,7 @@ impl Reaper {
         setNewValue: bool,
     ) -> bool {
         match self.pointers.GetSetMediaItemInfo_String {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetMediaItemInfo_String)
-            ),
+            None => crate::function_not_available(stringify!(GetSetMediaItemInfo_String)),
             Some(f) => f(item, parmname, stringNeedBig, setNewValue),
         }
     }
@@ -10542,10 +9636,7 @@ impl Reaper {
         setNewValue: *mut ::std::os::raw::c_void,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetSetMediaItemTakeInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetMediaItemTakeInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetSetMediaItemTakeInfo)),
             Some(f) => f(tk, parmname, setNewValue),
         }
     }
@@ -10560,10 +9651,7 @@ impl Reaper {
         setNewValue: bool,
     ) -> bool {
         match self.pointers.GetSetMediaItemTakeInfo_String {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetMediaItemTakeInfo_String)
-            ),
+            None => crate::function_not_available(stringify!(GetSetMediaItemTakeInfo_String)),
             Some(f) => f(tk, parmname, stringNeedBig, setNewValue),
         }
     }
@@ -10577,10 +9665,7 @@ impl Reaper {
         setNewValue: *mut ::std::os::raw::c_void,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetSetMediaTrackInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetMediaTrackInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetSetMediaTrackInfo)),
             Some(f) => f(tr, parmname, setNewValue),
         }
     }
@@ -10595,10 +9680,7 @@ impl Reaper {
         setNewValue: bool,
     ) -> bool {
         match self.pointers.GetSetMediaTrackInfo_String {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetMediaTrackInfo_String)
-            ),
+            None => crate::function_not_available(stringify!(GetSetMediaTrackInfo_String)),
             Some(f) => f(tr, parmname, stringNeedBig, setNewValue),
         }
     }
@@ -10611,10 +9693,7 @@ impl Reaper {
         str: *const ::std::os::raw::c_char,
     ) -> *mut ::std::os::raw::c_char {
         match self.pointers.GetSetObjectState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetObjectState)
-            ),
+            None => crate::function_not_available(stringify!(GetSetObjectState)),
             Some(f) => f(obj, str),
         }
     }
@@ -10628,10 +9707,7 @@ impl Reaper {
         isundo: bool,
     ) -> *mut ::std::os::raw::c_char {
         match self.pointers.GetSetObjectState2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetObjectState2)
-            ),
+            None => crate::function_not_available(stringify!(GetSetObjectState2)),
             Some(f) => f(obj, str, isundo),
         }
     }
@@ -10646,10 +9722,7 @@ impl Reaper {
         author_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetSetProjectAuthor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetProjectAuthor)
-            ),
+            None => crate::function_not_available(stringify!(GetSetProjectAuthor)),
             Some(f) => f(proj, set, author, author_sz),
         }
     }
@@ -10665,10 +9738,7 @@ impl Reaper {
         swingamtInOutOptional: *mut f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetSetProjectGrid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetProjectGrid)
-            ),
+            None => crate::function_not_available(stringify!(GetSetProjectGrid)),
             Some(f) => f(
                 project,
                 set,
@@ -10689,10 +9759,7 @@ impl Reaper {
         is_set: bool,
     ) -> f64 {
         match self.pointers.GetSetProjectInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetProjectInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetSetProjectInfo)),
             Some(f) => f(project, desc, value, is_set),
         }
     }
@@ -10707,10 +9774,7 @@ impl Reaper {
         is_set: bool,
     ) -> bool {
         match self.pointers.GetSetProjectInfo_String {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetProjectInfo_String)
-            ),
+            None => crate::function_not_available(stringify!(GetSetProjectInfo_String)),
             Some(f) => f(project, desc, valuestrNeedBig, is_set),
         }
     }
@@ -10725,19 +9789,13 @@ impl Reaper {
         notesNeedBig_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.GetSetProjectNotes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetProjectNotes)
-            ),
+            None => crate::function_not_available(stringify!(GetSetProjectNotes)),
             Some(f) => f(proj, set, notesNeedBig, notesNeedBig_sz),
         }
     }
     pub fn GetSetRepeat(&self, val: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
         match self.pointers.GetSetRepeat {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetRepeat)
-            ),
+            None => crate::function_not_available(stringify!(GetSetRepeat)),
             Some(f) => f(val),
         }
     }
@@ -10750,10 +9808,7 @@ impl Reaper {
         val: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetSetRepeatEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetRepeatEx)
-            ),
+            None => crate::function_not_available(stringify!(GetSetRepeatEx)),
             Some(f) => f(proj, val),
         }
     }
@@ -10768,10 +9823,7 @@ impl Reaper {
         is_set: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetSetTempoTimeSigMarkerFlag {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTempoTimeSigMarkerFlag)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTempoTimeSigMarkerFlag)),
             Some(f) => f(project, point_index, flag, is_set),
         }
     }
@@ -10786,10 +9838,7 @@ impl Reaper {
         setvalue: ::std::os::raw::c_uint,
     ) -> ::std::os::raw::c_uint {
         match self.pointers.GetSetTrackGroupMembership {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackGroupMembership)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackGroupMembership)),
             Some(f) => f(tr, groupname, setmask, setvalue),
         }
     }
@@ -10805,10 +9854,7 @@ impl Reaper {
         setvalue: ::std::os::raw::c_uint,
     ) -> ::std::os::raw::c_uint {
         match self.pointers.GetSetTrackGroupMembershipEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackGroupMembershipEx)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackGroupMembershipEx)),
             Some(f) => f(tr, groupname, offset, setmask, setvalue),
         }
     }
@@ -10823,10 +9869,7 @@ impl Reaper {
         setvalue: ::std::os::raw::c_uint,
     ) -> ::std::os::raw::c_uint {
         match self.pointers.GetSetTrackGroupMembershipHigh {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackGroupMembershipHigh)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackGroupMembershipHigh)),
             Some(f) => f(tr, groupname, setmask, setvalue),
         }
     }
@@ -10841,10 +9884,7 @@ impl Reaper {
         filename: *const ::std::os::raw::c_char,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetSetTrackMIDISupportFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackMIDISupportFile)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackMIDISupportFile)),
             Some(f) => f(proj, track, which, filename),
         }
     }
@@ -10860,10 +9900,7 @@ impl Reaper {
         setNewValue: *mut ::std::os::raw::c_void,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetSetTrackSendInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackSendInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackSendInfo)),
             Some(f) => f(tr, category, sendidx, parmname, setNewValue),
         }
     }
@@ -10880,10 +9917,7 @@ impl Reaper {
         setNewValue: bool,
     ) -> bool {
         match self.pointers.GetSetTrackSendInfo_String {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackSendInfo_String)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackSendInfo_String)),
             Some(f) => f(tr, category, sendidx, parmname, stringNeedBig, setNewValue),
         }
     }
@@ -10897,10 +9931,7 @@ impl Reaper {
         str_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetSetTrackState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackState)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackState)),
             Some(f) => f(track, str, str_sz),
         }
     }
@@ -10915,10 +9946,7 @@ impl Reaper {
         isundo: bool,
     ) -> bool {
         match self.pointers.GetSetTrackState2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSetTrackState2)
-            ),
+            None => crate::function_not_available(stringify!(GetSetTrackState2)),
             Some(f) => f(track, str, str_sz, isundo),
         }
     }
@@ -10930,10 +9958,7 @@ impl Reaper {
         src: *mut root::PCM_source,
     ) -> *mut root::ReaProject {
         match self.pointers.GetSubProjectFromSource {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSubProjectFromSource)
-            ),
+            None => crate::function_not_available(stringify!(GetSubProjectFromSource)),
             Some(f) => f(src),
         }
     }
@@ -10946,10 +9971,7 @@ impl Reaper {
         takeidx: ::std::os::raw::c_int,
     ) -> *mut root::MediaItem_Take {
         match self.pointers.GetTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTake)
-            ),
+            None => crate::function_not_available(stringify!(GetTake)),
             Some(f) => f(item, takeidx),
         }
     }
@@ -10962,10 +9984,7 @@ impl Reaper {
         envidx: ::std::os::raw::c_int,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetTakeEnvelope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTakeEnvelope)
-            ),
+            None => crate::function_not_available(stringify!(GetTakeEnvelope)),
             Some(f) => f(take, envidx),
         }
     }
@@ -10978,10 +9997,7 @@ impl Reaper {
         envname: *const ::std::os::raw::c_char,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetTakeEnvelopeByName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTakeEnvelopeByName)
-            ),
+            None => crate::function_not_available(stringify!(GetTakeEnvelopeByName)),
             Some(f) => f(take, envname),
         }
     }
@@ -10997,10 +10013,7 @@ impl Reaper {
         colorOutOptional: *mut ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.GetTakeMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTakeMarker)
-            ),
+            None => crate::function_not_available(stringify!(GetTakeMarker)),
             Some(f) => f(take, idx, nameOut, nameOut_sz, colorOutOptional),
         }
     }
@@ -11012,10 +10025,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetTakeName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTakeName)
-            ),
+            None => crate::function_not_available(stringify!(GetTakeName)),
             Some(f) => f(take),
         }
     }
@@ -11027,10 +10037,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetTakeNumStretchMarkers {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTakeNumStretchMarkers)
-            ),
+            None => crate::function_not_available(stringify!(GetTakeNumStretchMarkers)),
             Some(f) => f(take),
         }
     }
@@ -11045,10 +10052,7 @@ impl Reaper {
         srcposOutOptional: *mut f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetTakeStretchMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTakeStretchMarker)
-            ),
+            None => crate::function_not_available(stringify!(GetTakeStretchMarker)),
             Some(f) => f(take, idx, posOut, srcposOutOptional),
         }
     }
@@ -11061,10 +10065,7 @@ impl Reaper {
         idx: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.GetTakeStretchMarkerSlope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTakeStretchMarkerSlope)
-            ),
+            None => crate::function_not_available(stringify!(GetTakeStretchMarkerSlope)),
             Some(f) => f(take, idx),
         }
     }
@@ -11080,10 +10081,7 @@ impl Reaper {
         parmidxOut: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetTCPFXParm {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTCPFXParm)
-            ),
+            None => crate::function_not_available(stringify!(GetTCPFXParm)),
             Some(f) => f(project, track, index, fxindexOut, parmidxOut),
         }
     }
@@ -11100,10 +10098,7 @@ impl Reaper {
         targetlenOut: *mut f64,
     ) -> bool {
         match self.pointers.GetTempoMatchPlayRate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTempoMatchPlayRate)
-            ),
+            None => crate::function_not_available(stringify!(GetTempoMatchPlayRate)),
             Some(f) => f(source, srcscale, position, mult, rateOut, targetlenOut),
         }
     }
@@ -11123,10 +10118,7 @@ impl Reaper {
         lineartempoOut: *mut bool,
     ) -> bool {
         match self.pointers.GetTempoTimeSigMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTempoTimeSigMarker)
-            ),
+            None => crate::function_not_available(stringify!(GetTempoTimeSigMarker)),
             Some(f) => f(
                 proj,
                 ptidx,
@@ -11149,10 +10141,7 @@ impl Reaper {
         flagsOptional: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetThemeColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetThemeColor)
-            ),
+            None => crate::function_not_available(stringify!(GetThemeColor)),
             Some(f) => f(ini_key, flagsOptional),
         }
     }
@@ -11167,10 +10156,7 @@ impl Reaper {
         infoOut_sz: ::std::os::raw::c_int,
     ) -> *mut root::MediaTrack {
         match self.pointers.GetThingFromPoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetThingFromPoint)
-            ),
+            None => crate::function_not_available(stringify!(GetThingFromPoint)),
             Some(f) => f(screen_x, screen_y, infoOut, infoOut_sz),
         }
     }
@@ -11179,10 +10165,7 @@ impl Reaper {
         command_id: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetToggleCommandState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetToggleCommandState)
-            ),
+            None => crate::function_not_available(stringify!(GetToggleCommandState)),
             Some(f) => f(command_id),
         }
     }
@@ -11195,10 +10178,7 @@ impl Reaper {
         command_id: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetToggleCommandState2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetToggleCommandState2)
-            ),
+            None => crate::function_not_available(stringify!(GetToggleCommandState2)),
             Some(f) => f(section, command_id),
         }
     }
@@ -11208,10 +10188,7 @@ impl Reaper {
         command_id: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetToggleCommandStateEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetToggleCommandStateEx)
-            ),
+            None => crate::function_not_available(stringify!(GetToggleCommandStateEx)),
             Some(f) => f(section_id, command_id),
         }
     }
@@ -11224,19 +10201,13 @@ impl Reaper {
         command_id: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetToggleCommandStateThroughHooks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetToggleCommandStateThroughHooks)
-            ),
+            None => crate::function_not_available(stringify!(GetToggleCommandStateThroughHooks)),
             Some(f) => f(section, command_id),
         }
     }
     pub fn GetTooltipWindow(&self) -> root::HWND {
         match self.pointers.GetTooltipWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTooltipWindow)
-            ),
+            None => crate::function_not_available(stringify!(GetTooltipWindow)),
             Some(f) => f(),
         }
     }
@@ -11253,10 +10224,7 @@ impl Reaper {
         parmOut: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetTouchedOrFocusedFX {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTouchedOrFocusedFX)
-            ),
+            None => crate::function_not_available(stringify!(GetTouchedOrFocusedFX)),
             Some(f) => f(mode, trackidxOut, itemidxOut, takeidxOut, fxidxOut, parmOut),
         }
     }
@@ -11269,10 +10237,7 @@ impl Reaper {
         trackidx: ::std::os::raw::c_int,
     ) -> *mut root::MediaTrack {
         match self.pointers.GetTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrack)
-            ),
+            None => crate::function_not_available(stringify!(GetTrack)),
             Some(f) => f(proj, trackidx),
         }
     }
@@ -11284,10 +10249,7 @@ impl Reaper {
         tr: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetTrackAutomationMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackAutomationMode)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackAutomationMode)),
             Some(f) => f(tr),
         }
     }
@@ -11296,10 +10258,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTrackColor(&self, track: *mut root::MediaTrack) -> ::std::os::raw::c_int {
         match self.pointers.GetTrackColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackColor)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackColor)),
             Some(f) => f(track),
         }
     }
@@ -11308,10 +10267,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTrackDepth(&self, track: *mut root::MediaTrack) -> ::std::os::raw::c_int {
         match self.pointers.GetTrackDepth {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackDepth)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackDepth)),
             Some(f) => f(track),
         }
     }
@@ -11324,10 +10280,7 @@ impl Reaper {
         envidx: ::std::os::raw::c_int,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetTrackEnvelope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackEnvelope)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackEnvelope)),
             Some(f) => f(track, envidx),
         }
     }
@@ -11340,10 +10293,7 @@ impl Reaper {
         cfgchunkname_or_guid: *const ::std::os::raw::c_char,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetTrackEnvelopeByChunkName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackEnvelopeByChunkName)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackEnvelopeByChunkName)),
             Some(f) => f(tr, cfgchunkname_or_guid),
         }
     }
@@ -11356,10 +10306,7 @@ impl Reaper {
         envname: *const ::std::os::raw::c_char,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.GetTrackEnvelopeByName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackEnvelopeByName)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackEnvelopeByName)),
             Some(f) => f(track, envname),
         }
     }
@@ -11373,10 +10320,7 @@ impl Reaper {
         infoOutOptional: *mut ::std::os::raw::c_int,
     ) -> *mut root::MediaTrack {
         match self.pointers.GetTrackFromPoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackFromPoint)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackFromPoint)),
             Some(f) => f(screen_x, screen_y, infoOutOptional),
         }
     }
@@ -11385,10 +10329,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTrackGUID(&self, tr: *mut root::MediaTrack) -> *mut root::GUID {
         match self.pointers.GetTrackGUID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackGUID)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackGUID)),
             Some(f) => f(tr),
         }
     }
@@ -11401,10 +10342,7 @@ impl Reaper {
         flags: *mut ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetTrackInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackInfo)),
             Some(f) => f(track, flags),
         }
     }
@@ -11417,10 +10355,7 @@ impl Reaper {
         itemidx: ::std::os::raw::c_int,
     ) -> *mut root::MediaItem {
         match self.pointers.GetTrackMediaItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackMediaItem)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackMediaItem)),
             Some(f) => f(tr, itemidx),
         }
     }
@@ -11435,10 +10370,7 @@ impl Reaper {
         bufOutWantNeedBig_sz: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetTrackMIDILyrics {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackMIDILyrics)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackMIDILyrics)),
             Some(f) => f(track, flag, bufOutWantNeedBig, bufOutWantNeedBig_sz),
         }
     }
@@ -11449,10 +10381,7 @@ impl Reaper {
         chan: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetTrackMIDINoteName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackMIDINoteName)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackMIDINoteName)),
             Some(f) => f(track, pitch, chan),
         }
     }
@@ -11467,10 +10396,7 @@ impl Reaper {
         chan: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetTrackMIDINoteNameEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackMIDINoteNameEx)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackMIDINoteNameEx)),
             Some(f) => f(proj, track, pitch, chan),
         }
     }
@@ -11485,10 +10411,7 @@ impl Reaper {
         note_hiOut: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.GetTrackMIDINoteRange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackMIDINoteRange)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackMIDINoteRange)),
             Some(f) => f(proj, track, note_loOut, note_hiOut),
         }
     }
@@ -11502,10 +10425,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetTrackName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackName)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackName)),
             Some(f) => f(track, bufOut, bufOut_sz),
         }
     }
@@ -11514,10 +10434,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTrackNumMediaItems(&self, tr: *mut root::MediaTrack) -> ::std::os::raw::c_int {
         match self.pointers.GetTrackNumMediaItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackNumMediaItems)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackNumMediaItems)),
             Some(f) => f(tr),
         }
     }
@@ -11530,10 +10447,7 @@ impl Reaper {
         category: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetTrackNumSends {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackNumSends)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackNumSends)),
             Some(f) => f(tr, category),
         }
     }
@@ -11548,10 +10462,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetTrackReceiveName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackReceiveName)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackReceiveName)),
             Some(f) => f(track, recv_index, bufOut, bufOut_sz),
         }
     }
@@ -11565,10 +10476,7 @@ impl Reaper {
         muteOut: *mut bool,
     ) -> bool {
         match self.pointers.GetTrackReceiveUIMute {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackReceiveUIMute)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackReceiveUIMute)),
             Some(f) => f(track, recv_index, muteOut),
         }
     }
@@ -11583,10 +10491,7 @@ impl Reaper {
         panOut: *mut f64,
     ) -> bool {
         match self.pointers.GetTrackReceiveUIVolPan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackReceiveUIVolPan)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackReceiveUIVolPan)),
             Some(f) => f(track, recv_index, volumeOut, panOut),
         }
     }
@@ -11601,10 +10506,7 @@ impl Reaper {
         parmname: *const ::std::os::raw::c_char,
     ) -> f64 {
         match self.pointers.GetTrackSendInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackSendInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackSendInfo_Value)),
             Some(f) => f(tr, category, sendidx, parmname),
         }
     }
@@ -11619,10 +10521,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetTrackSendName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackSendName)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackSendName)),
             Some(f) => f(track, send_index, bufOut, bufOut_sz),
         }
     }
@@ -11636,10 +10535,7 @@ impl Reaper {
         muteOut: *mut bool,
     ) -> bool {
         match self.pointers.GetTrackSendUIMute {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackSendUIMute)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackSendUIMute)),
             Some(f) => f(track, send_index, muteOut),
         }
     }
@@ -11654,10 +10550,7 @@ impl Reaper {
         panOut: *mut f64,
     ) -> bool {
         match self.pointers.GetTrackSendUIVolPan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackSendUIVolPan)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackSendUIVolPan)),
             Some(f) => f(track, send_index, volumeOut, panOut),
         }
     }
@@ -11670,10 +10563,7 @@ impl Reaper {
         flagsOut: *mut ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.GetTrackState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackState)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackState)),
             Some(f) => f(track, flagsOut),
         }
     }
@@ -11688,10 +10578,7 @@ impl Reaper {
         isundoOptional: bool,
     ) -> bool {
         match self.pointers.GetTrackStateChunk {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackStateChunk)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackStateChunk)),
             Some(f) => f(track, strNeedBig, strNeedBig_sz, isundoOptional),
         }
     }
@@ -11700,10 +10587,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTrackUIMute(&self, track: *mut root::MediaTrack, muteOut: *mut bool) -> bool {
         match self.pointers.GetTrackUIMute {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackUIMute)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackUIMute)),
             Some(f) => f(track, muteOut),
         }
     }
@@ -11718,10 +10602,7 @@ impl Reaper {
         panmodeOut: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetTrackUIPan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackUIPan)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackUIPan)),
             Some(f) => f(track, pan1Out, pan2Out, panmodeOut),
         }
     }
@@ -11735,10 +10616,7 @@ impl Reaper {
         panOut: *mut f64,
     ) -> bool {
         match self.pointers.GetTrackUIVolPan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTrackUIVolPan)
-            ),
+            None => crate::function_not_available(stringify!(GetTrackUIVolPan)),
             Some(f) => f(track, volumeOut, panOut),
         }
     }
@@ -11752,10 +10630,7 @@ impl Reaper {
         curtimeOut: *mut ::std::os::raw::c_uint,
     ) {
         match self.pointers.GetUnderrunTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetUnderrunTime)
-            ),
+            None => crate::function_not_available(stringify!(GetUnderrunTime)),
             Some(f) => f(audio_xrunOut, media_xrunOut, curtimeOut),
         }
     }
@@ -11769,10 +10644,7 @@ impl Reaper {
         defext: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.GetUserFileNameForRead {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetUserFileNameForRead)
-            ),
+            None => crate::function_not_available(stringify!(GetUserFileNameForRead)),
             Some(f) => f(filenameNeed4096, title, defext),
         }
     }
@@ -11788,10 +10660,7 @@ impl Reaper {
         retvals_csv_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.GetUserInputs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetUserInputs)
-            ),
+            None => crate::function_not_available(stringify!(GetUserInputs)),
             Some(f) => f(title, num_inputs, captions_csv, retvals_csv, retvals_csv_sz),
         }
     }
@@ -11805,10 +10674,7 @@ impl Reaper {
         use_timeline_order: bool,
     ) {
         match self.pointers.GoToMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GoToMarker)
-            ),
+            None => crate::function_not_available(stringify!(GoToMarker)),
             Some(f) => f(proj, marker_index, use_timeline_order),
         }
     }
@@ -11822,10 +10688,7 @@ impl Reaper {
         use_timeline_order: bool,
     ) {
         match self.pointers.GoToRegion {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GoToRegion)
-            ),
+            None => crate::function_not_available(stringify!(GoToRegion)),
             Some(f) => f(proj, region_index, use_timeline_order),
         }
     }
@@ -11838,19 +10701,13 @@ impl Reaper {
         colorOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GR_SelectColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GR_SelectColor)
-            ),
+            None => crate::function_not_available(stringify!(GR_SelectColor)),
             Some(f) => f(hwnd, colorOut),
         }
     }
     pub fn GSC_mainwnd(&self, t: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
         match self.pointers.GSC_mainwnd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GSC_mainwnd)
-            ),
+            None => crate::function_not_available(stringify!(GSC_mainwnd)),
             Some(f) => f(t),
         }
     }
@@ -11863,10 +10720,7 @@ impl Reaper {
         destNeed64: *mut ::std::os::raw::c_char,
     ) {
         match self.pointers.guidToString {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(guidToString)
-            ),
+            None => crate::function_not_available(stringify!(guidToString)),
             Some(f) => f(g, destNeed64),
         }
     }
@@ -11879,10 +10733,7 @@ impl Reaper {
         key: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.HasExtState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(HasExtState)
-            ),
+            None => crate::function_not_available(stringify!(HasExtState)),
             Some(f) => f(section, key),
         }
     }
@@ -11891,10 +10742,7 @@ impl Reaper {
         track: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.HasTrackMIDIPrograms {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(HasTrackMIDIPrograms)
-            ),
+            None => crate::function_not_available(stringify!(HasTrackMIDIPrograms)),
             Some(f) => f(track),
         }
     }
@@ -11907,10 +10755,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.HasTrackMIDIProgramsEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(HasTrackMIDIProgramsEx)
-            ),
+            None => crate::function_not_available(stringify!(HasTrackMIDIProgramsEx)),
             Some(f) => f(proj, track),
         }
     }
@@ -11923,10 +10768,7 @@ impl Reaper {
         is_temporary_help: bool,
     ) {
         match self.pointers.Help_Set {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Help_Set)
-            ),
+            None => crate::function_not_available(stringify!(Help_Set)),
             Some(f) => f(helpstring, is_temporary_help),
         }
     }
@@ -11939,10 +10781,7 @@ impl Reaper {
         block: *mut root::PCM_source_peaktransfer_t,
     ) {
         match self.pointers.HiresPeaksFromSource {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(HiresPeaksFromSource)
-            ),
+            None => crate::function_not_available(stringify!(HiresPeaksFromSource)),
             Some(f) => f(src, block),
         }
     }
@@ -11956,10 +10795,7 @@ impl Reaper {
         out_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.image_resolve_fn {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(image_resolve_fn)
-            ),
+            None => crate::function_not_available(stringify!(image_resolve_fn)),
             Some(f) => f(in_, out, out_sz),
         }
     }
@@ -11974,10 +10810,7 @@ impl Reaper {
         length: f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.InsertAutomationItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertAutomationItem)
-            ),
+            None => crate::function_not_available(stringify!(InsertAutomationItem)),
             Some(f) => f(env, pool_id, position, length),
         }
     }
@@ -11995,10 +10828,7 @@ impl Reaper {
         noSortInOptional: *mut bool,
     ) -> bool {
         match self.pointers.InsertEnvelopePoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertEnvelopePoint)
-            ),
+            None => crate::function_not_available(stringify!(InsertEnvelopePoint)),
             Some(f) => f(
                 envelope,
                 time,
@@ -12025,10 +10855,7 @@ impl Reaper {
         noSortInOptional: *mut bool,
     ) -> bool {
         match self.pointers.InsertEnvelopePointEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertEnvelopePointEx)
-            ),
+            None => crate::function_not_available(stringify!(InsertEnvelopePointEx)),
             Some(f) => f(
                 envelope,
                 autoitem_idx,
@@ -12050,10 +10877,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.InsertMedia {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertMedia)
-            ),
+            None => crate::function_not_available(stringify!(InsertMedia)),
             Some(f) => f(file, mode),
         }
     }
@@ -12069,19 +10893,13 @@ impl Reaper {
         pitchshift: f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.InsertMediaSection {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertMediaSection)
-            ),
+            None => crate::function_not_available(stringify!(InsertMediaSection)),
             Some(f) => f(file, mode, startpct, endpct, pitchshift),
         }
     }
     pub fn InsertTrackAtIndex(&self, idx: ::std::os::raw::c_int, wantDefaults: bool) {
         match self.pointers.InsertTrackAtIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertTrackAtIndex)
-            ),
+            None => crate::function_not_available(stringify!(InsertTrackAtIndex)),
             Some(f) => f(idx, wantDefaults),
         }
     }
@@ -12095,19 +10913,13 @@ impl Reaper {
         flags: ::std::os::raw::c_int,
     ) {
         match self.pointers.InsertTrackInProject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertTrackInProject)
-            ),
+            None => crate::function_not_available(stringify!(InsertTrackInProject)),
             Some(f) => f(proj, idx, flags),
         }
     }
     pub fn IsInRealTimeAudio(&self) -> ::std::os::raw::c_int {
         match self.pointers.IsInRealTimeAudio {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsInRealTimeAudio)
-            ),
+            None => crate::function_not_available(stringify!(IsInRealTimeAudio)),
             Some(f) => f(),
         }
     }
@@ -12120,10 +10932,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> bool {
         match self.pointers.IsItemTakeActiveForPlayback {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsItemTakeActiveForPlayback)
-            ),
+            None => crate::function_not_available(stringify!(IsItemTakeActiveForPlayback)),
             Some(f) => f(item, take),
         }
     }
@@ -12136,10 +10945,7 @@ impl Reaper {
         wantOthers: bool,
     ) -> bool {
         match self.pointers.IsMediaExtension {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsMediaExtension)
-            ),
+            None => crate::function_not_available(stringify!(IsMediaExtension)),
             Some(f) => f(ext, wantOthers),
         }
     }
@@ -12148,10 +10954,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsMediaItemSelected(&self, item: *mut root::MediaItem) -> bool {
         match self.pointers.IsMediaItemSelected {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsMediaItemSelected)
-            ),
+            None => crate::function_not_available(stringify!(IsMediaItemSelected)),
             Some(f) => f(item),
         }
     }
@@ -12160,19 +10963,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsProjectDirty(&self, proj: *mut root::ReaProject) -> ::std::os::raw::c_int {
         match self.pointers.IsProjectDirty {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsProjectDirty)
-            ),
+            None => crate::function_not_available(stringify!(IsProjectDirty)),
             Some(f) => f(proj),
         }
     }
     pub fn IsREAPER(&self) -> bool {
         match self.pointers.IsREAPER {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsREAPER)
-            ),
+            None => crate::function_not_available(stringify!(IsREAPER)),
             Some(f) => f(),
         }
     }
@@ -12181,10 +10978,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsTrackSelected(&self, track: *mut root::MediaTrack) -> bool {
         match self.pointers.IsTrackSelected {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsTrackSelected)
-            ),
+            None => crate::function_not_available(stringify!(IsTrackSelected)),
             Some(f) => f(track),
         }
     }
@@ -12193,10 +10987,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsTrackVisible(&self, track: *mut root::MediaTrack, mixer: bool) -> bool {
         match self.pointers.IsTrackVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsTrackVisible)
-            ),
+            None => crate::function_not_available(stringify!(IsTrackVisible)),
             Some(f) => f(track, mixer),
         }
     }
@@ -12205,10 +10996,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsWindowTextField(&self, hwnd: root::HWND) -> bool {
         match self.pointers.IsWindowTextField {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsWindowTextField)
-            ),
+            None => crate::function_not_available(stringify!(IsWindowTextField)),
             Some(f) => f(hwnd),
         }
     }
@@ -12220,10 +11008,7 @@ impl Reaper {
         guid: *const root::GUID,
     ) -> *mut root::reaper_functions::joystick_device {
         match self.pointers.joystick_create {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_create)
-            ),
+            None => crate::function_not_available(stringify!(joystick_create)),
             Some(f) => f(guid),
         }
     }
@@ -12232,10 +11017,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn joystick_destroy(&self, device: *mut root::reaper_functions::joystick_device) {
         match self.pointers.joystick_destroy {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_destroy)
-            ),
+            None => crate::function_not_available(stringify!(joystick_destroy)),
             Some(f) => f(device),
         }
     }
@@ -12248,10 +11030,7 @@ impl Reaper {
         namestrOutOptional: *mut *const ::std::os::raw::c_char,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.joystick_enum {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_enum)
-            ),
+            None => crate::function_not_available(stringify!(joystick_enum)),
             Some(f) => f(index, namestrOutOptional),
         }
     }
@@ -12264,10 +11043,7 @@ impl Reaper {
         axis: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.joystick_getaxis {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_getaxis)
-            ),
+            None => crate::function_not_available(stringify!(joystick_getaxis)),
             Some(f) => f(dev, axis),
         }
     }
@@ -12279,10 +11055,7 @@ impl Reaper {
         dev: *mut root::reaper_functions::joystick_device,
     ) -> ::std::os::raw::c_uint {
         match self.pointers.joystick_getbuttonmask {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_getbuttonmask)
-            ),
+            None => crate::function_not_available(stringify!(joystick_getbuttonmask)),
             Some(f) => f(dev),
         }
     }
@@ -12296,10 +11069,7 @@ impl Reaper {
         povsOutOptional: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.joystick_getinfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_getinfo)
-            ),
+            None => crate::function_not_available(stringify!(joystick_getinfo)),
             Some(f) => f(dev, axesOutOptional, povsOutOptional),
         }
     }
@@ -12312,10 +11082,7 @@ impl Reaper {
         pov: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.joystick_getpov {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_getpov)
-            ),
+            None => crate::function_not_available(stringify!(joystick_getpov)),
             Some(f) => f(dev, pov),
         }
     }
@@ -12327,10 +11094,7 @@ impl Reaper {
         dev: *mut root::reaper_functions::joystick_device,
     ) -> bool {
         match self.pointers.joystick_update {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(joystick_update)
-            ),
+            None => crate::function_not_available(stringify!(joystick_update)),
             Some(f) => f(dev),
         }
     }
@@ -12344,10 +11108,7 @@ impl Reaper {
         nameOut: *mut *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.kbd_enumerateActions {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_enumerateActions)
-            ),
+            None => crate::function_not_available(stringify!(kbd_enumerateActions)),
             Some(f) => f(section, idx, nameOut),
         }
     }
@@ -12356,10 +11117,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn kbd_formatKeyName(&self, ac: *mut root::ACCEL, s: *mut ::std::os::raw::c_char) {
         match self.pointers.kbd_formatKeyName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_formatKeyName)
-            ),
+            None => crate::function_not_available(stringify!(kbd_formatKeyName)),
             Some(f) => f(ac, s),
         }
     }
@@ -12373,10 +11131,7 @@ impl Reaper {
         section: *mut root::KbdSectionInfo,
     ) {
         match self.pointers.kbd_getCommandName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_getCommandName)
-            ),
+            None => crate::function_not_available(stringify!(kbd_getCommandName)),
             Some(f) => f(cmd, s, section),
         }
     }
@@ -12389,10 +11144,7 @@ impl Reaper {
         section: *mut root::KbdSectionInfo,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.kbd_getTextFromCmd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_getTextFromCmd)
-            ),
+            None => crate::function_not_available(stringify!(kbd_getTextFromCmd)),
             Some(f) => f(cmd, section),
         }
     }
@@ -12409,10 +11161,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> ::std::os::raw::c_int {
         match self.pointers.KBD_OnMainActionEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(KBD_OnMainActionEx)
-            ),
+            None => crate::function_not_available(stringify!(KBD_OnMainActionEx)),
             Some(f) => f(cmd, val, valhw, relmode, hwnd, proj),
         }
     }
@@ -12425,10 +11174,7 @@ impl Reaper {
         dev_index: ::std::os::raw::c_int,
     ) {
         match self.pointers.kbd_OnMidiEvent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_OnMidiEvent)
-            ),
+            None => crate::function_not_available(stringify!(kbd_OnMidiEvent)),
             Some(f) => f(evt, dev_index),
         }
     }
@@ -12441,10 +11187,7 @@ impl Reaper {
         dev_index: ::std::os::raw::c_int,
     ) {
         match self.pointers.kbd_OnMidiList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_OnMidiList)
-            ),
+            None => crate::function_not_available(stringify!(kbd_OnMidiList)),
             Some(f) => f(list, dev_index),
         }
     }
@@ -12457,10 +11200,7 @@ impl Reaper {
         section: *mut root::KbdSectionInfo,
     ) {
         match self.pointers.kbd_ProcessActionsMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_ProcessActionsMenu)
-            ),
+            None => crate::function_not_available(stringify!(kbd_ProcessActionsMenu)),
             Some(f) => f(menu, section),
         }
     }
@@ -12474,10 +11214,7 @@ impl Reaper {
         hwndCtx: root::HWND,
     ) -> bool {
         match self.pointers.kbd_processMidiEventActionEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_processMidiEventActionEx)
-            ),
+            None => crate::function_not_available(stringify!(kbd_processMidiEventActionEx)),
             Some(f) => f(evt, section, hwndCtx),
         }
     }
@@ -12486,10 +11223,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn kbd_reprocessMenu(&self, menu: root::HMENU, section: *mut root::KbdSectionInfo) {
         match self.pointers.kbd_reprocessMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_reprocessMenu)
-            ),
+            None => crate::function_not_available(stringify!(kbd_reprocessMenu)),
             Some(f) => f(menu, section),
         }
     }
@@ -12506,10 +11240,7 @@ impl Reaper {
         hwnd: root::HWND,
     ) -> bool {
         match self.pointers.kbd_RunCommandThroughHooks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_RunCommandThroughHooks)
-            ),
+            None => crate::function_not_available(stringify!(kbd_RunCommandThroughHooks)),
             Some(f) => f(section, actionCommandID, val, valhw, relmode, hwnd),
         }
     }
@@ -12523,10 +11254,7 @@ impl Reaper {
         section: *mut root::KbdSectionInfo,
     ) -> ::std::os::raw::c_int {
         match self.pointers.kbd_translateAccelerator {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(kbd_translateAccelerator)
-            ),
+            None => crate::function_not_available(stringify!(kbd_translateAccelerator)),
             Some(f) => f(hwnd, msg, section),
         }
     }
@@ -12535,10 +11263,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn LICE__Destroy(&self, bm: *mut root::reaper_functions::LICE_IBitmap) {
         match self.pointers.LICE__Destroy {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__Destroy)
-            ),
+            None => crate::function_not_available(stringify!(LICE__Destroy)),
             Some(f) => f(bm),
         }
     }
@@ -12547,10 +11272,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn LICE__DestroyFont(&self, font: *mut root::reaper_functions::LICE_IFont) {
         match self.pointers.LICE__DestroyFont {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__DestroyFont)
-            ),
+            None => crate::function_not_available(stringify!(LICE__DestroyFont)),
             Some(f) => f(font),
         }
     }
@@ -12567,10 +11289,7 @@ impl Reaper {
         dtFlags: root::UINT,
     ) -> ::std::os::raw::c_int {
         match self.pointers.LICE__DrawText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__DrawText)
-            ),
+            None => crate::function_not_available(stringify!(LICE__DrawText)),
             Some(f) => f(font, bm, str, strcnt, rect, dtFlags),
         }
     }
@@ -12582,10 +11301,7 @@ impl Reaper {
         bm: *mut root::reaper_functions::LICE_IBitmap,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.LICE__GetBits {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__GetBits)
-            ),
+            None => crate::function_not_available(stringify!(LICE__GetBits)),
             Some(f) => f(bm),
         }
     }
@@ -12594,10 +11310,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn LICE__GetDC(&self, bm: *mut root::reaper_functions::LICE_IBitmap) -> root::HDC {
         match self.pointers.LICE__GetDC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__GetDC)
-            ),
+            None => crate::function_not_available(stringify!(LICE__GetDC)),
             Some(f) => f(bm),
         }
     }
@@ -12609,10 +11322,7 @@ impl Reaper {
         bm: *mut root::reaper_functions::LICE_IBitmap,
     ) -> ::std::os::raw::c_int {
         match self.pointers.LICE__GetHeight {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__GetHeight)
-            ),
+            None => crate::function_not_available(stringify!(LICE__GetHeight)),
             Some(f) => f(bm),
         }
     }
@@ -12624,10 +11334,7 @@ impl Reaper {
         bm: *mut root::reaper_functions::LICE_IBitmap,
     ) -> ::std::os::raw::c_int {
         match self.pointers.LICE__GetRowSpan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__GetRowSpan)
-            ),
+            None => crate::function_not_available(stringify!(LICE__GetRowSpan)),
             Some(f) => f(bm),
         }
     }
@@ -12639,10 +11346,7 @@ impl Reaper {
         bm: *mut root::reaper_functions::LICE_IBitmap,
     ) -> ::std::os::raw::c_int {
         match self.pointers.LICE__GetWidth {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__GetWidth)
-            ),
+            None => crate::function_not_available(stringify!(LICE__GetWidth)),
             Some(f) => f(bm),
         }
     }
@@ -12651,10 +11355,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn LICE__IsFlipped(&self, bm: *mut root::reaper_functions::LICE_IBitmap) -> bool {
         match self.pointers.LICE__IsFlipped {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__IsFlipped)
-            ),
+            None => crate::function_not_available(stringify!(LICE__IsFlipped)),
             Some(f) => f(bm),
         }
     }
@@ -12668,10 +11369,7 @@ impl Reaper {
         h: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.LICE__resize {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__resize)
-            ),
+            None => crate::function_not_available(stringify!(LICE__resize)),
             Some(f) => f(bm, w, h),
         }
     }
@@ -12684,10 +11382,7 @@ impl Reaper {
         color: root::reaper_functions::LICE_pixel,
     ) -> root::reaper_functions::LICE_pixel {
         match self.pointers.LICE__SetBkColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__SetBkColor)
-            ),
+            None => crate::function_not_available(stringify!(LICE__SetBkColor)),
             Some(f) => f(font, color),
         }
     }
@@ -12701,10 +11396,7 @@ impl Reaper {
         flags: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE__SetFromHFont {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__SetFromHFont)
-            ),
+            None => crate::function_not_available(stringify!(LICE__SetFromHFont)),
             Some(f) => f(font, hfont, flags),
         }
     }
@@ -12717,10 +11409,7 @@ impl Reaper {
         color: root::reaper_functions::LICE_pixel,
     ) -> root::reaper_functions::LICE_pixel {
         match self.pointers.LICE__SetTextColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__SetTextColor)
-            ),
+            None => crate::function_not_available(stringify!(LICE__SetTextColor)),
             Some(f) => f(font, color),
         }
     }
@@ -12734,10 +11423,7 @@ impl Reaper {
         alpha: f32,
     ) {
         match self.pointers.LICE__SetTextCombineMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE__SetTextCombineMode)
-            ),
+            None => crate::function_not_available(stringify!(LICE__SetTextCombineMode)),
             Some(f) => f(ifont, mode, alpha),
         }
     }
@@ -12758,10 +11444,7 @@ impl Reaper {
         aa: bool,
     ) {
         match self.pointers.LICE_Arc {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_Arc)
-            ),
+            None => crate::function_not_available(stringify!(LICE_Arc)),
             Some(f) => f(dest, cx, cy, r, minAngle, maxAngle, color, alpha, mode, aa),
         }
     }
@@ -12782,10 +11465,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_Blit {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_Blit)
-            ),
+            None => crate::function_not_available(stringify!(LICE_Blit)),
             Some(f) => f(dest, src, dstx, dsty, srcx, srcy, srcw, srch, alpha, mode),
         }
     }
@@ -12804,10 +11484,7 @@ impl Reaper {
         srch: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_Blur {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_Blur)
-            ),
+            None => crate::function_not_available(stringify!(LICE_Blur)),
             Some(f) => f(dest, src, dstx, dsty, srcx, srcy, srcw, srch),
         }
     }
@@ -12827,10 +11504,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_BorderedRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_BorderedRect)
-            ),
+            None => crate::function_not_available(stringify!(LICE_BorderedRect)),
             Some(f) => f(dest, x, y, w, h, bgcolor, fgcolor, alpha, mode),
         }
     }
@@ -12849,10 +11523,7 @@ impl Reaper {
         aa: bool,
     ) {
         match self.pointers.LICE_Circle {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_Circle)
-            ),
+            None => crate::function_not_available(stringify!(LICE_Circle)),
             Some(f) => f(dest, cx, cy, r, color, alpha, mode, aa),
         }
     }
@@ -12865,10 +11536,7 @@ impl Reaper {
         color: root::reaper_functions::LICE_pixel,
     ) {
         match self.pointers.LICE_Clear {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_Clear)
-            ),
+            None => crate::function_not_available(stringify!(LICE_Clear)),
             Some(f) => f(dest, color),
         }
     }
@@ -12886,10 +11554,7 @@ impl Reaper {
         orbits: root::reaper_functions::LICE_pixel,
     ) {
         match self.pointers.LICE_ClearRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_ClearRect)
-            ),
+            None => crate::function_not_available(stringify!(LICE_ClearRect)),
             Some(f) => f(dest, x, y, w, h, mask, orbits),
         }
     }
@@ -12908,10 +11573,7 @@ impl Reaper {
         yHi: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.LICE_ClipLine {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_ClipLine)
-            ),
+            None => crate::function_not_available(stringify!(LICE_ClipLine)),
             Some(f) => f(pX1Out, pY1Out, pX2Out, pY2Out, xLo, yLo, xHi, yHi),
         }
     }
@@ -12923,10 +11585,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) -> root::reaper_functions::LICE_pixel {
         match self.pointers.LICE_CombinePixels {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_CombinePixels)
-            ),
+            None => crate::function_not_available(stringify!(LICE_CombinePixels)),
             Some(f) => f(dest, src, alpha, mode),
         }
     }
@@ -12939,10 +11598,7 @@ impl Reaper {
         src: *mut root::reaper_functions::LICE_IBitmap,
     ) {
         match self.pointers.LICE_Copy {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_Copy)
-            ),
+            None => crate::function_not_available(stringify!(LICE_Copy)),
             Some(f) => f(dest, src),
         }
     }
@@ -12953,19 +11609,13 @@ impl Reaper {
         h: ::std::os::raw::c_int,
     ) -> *mut root::reaper_functions::LICE_IBitmap {
         match self.pointers.LICE_CreateBitmap {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_CreateBitmap)
-            ),
+            None => crate::function_not_available(stringify!(LICE_CreateBitmap)),
             Some(f) => f(mode, w, h),
         }
     }
     pub fn LICE_CreateFont(&self) -> *mut root::reaper_functions::LICE_IFont {
         match self.pointers.LICE_CreateFont {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_CreateFont)
-            ),
+            None => crate::function_not_available(stringify!(LICE_CreateFont)),
             Some(f) => f(),
         }
     }
@@ -12990,10 +11640,7 @@ impl Reaper {
         tol: f64,
     ) {
         match self.pointers.LICE_DrawCBezier {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_DrawCBezier)
-            ),
+            None => crate::function_not_available(stringify!(LICE_DrawCBezier)),
             Some(f) => f(
                 dest, xstart, ystart, xctl1, yctl1, xctl2, yctl2, xend, yend, color, alpha, mode,
                 aa, tol,
@@ -13014,10 +11661,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_DrawChar {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_DrawChar)
-            ),
+            None => crate::function_not_available(stringify!(LICE_DrawChar)),
             Some(f) => f(bm, x, y, c, color, alpha, mode),
         }
     }
@@ -13037,10 +11681,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_DrawGlyph {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_DrawGlyph)
-            ),
+            None => crate::function_not_available(stringify!(LICE_DrawGlyph)),
             Some(f) => f(dest, x, y, color, alphas, glyph_w, glyph_h, alpha, mode),
         }
     }
@@ -13059,10 +11700,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_DrawRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_DrawRect)
-            ),
+            None => crate::function_not_available(stringify!(LICE_DrawRect)),
             Some(f) => f(dest, x, y, w, h, color, alpha, mode),
         }
     }
@@ -13080,10 +11718,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_DrawText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_DrawText)
-            ),
+            None => crate::function_not_available(stringify!(LICE_DrawText)),
             Some(f) => f(bm, x, y, string, color, alpha, mode),
         }
     }
@@ -13109,10 +11744,7 @@ impl Reaper {
         tol: f64,
     ) {
         match self.pointers.LICE_FillCBezier {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_FillCBezier)
-            ),
+            None => crate::function_not_available(stringify!(LICE_FillCBezier)),
             Some(f) => f(
                 dest, xstart, ystart, xctl1, yctl1, xctl2, yctl2, xend, yend, yfill, color, alpha,
                 mode, aa, tol,
@@ -13134,10 +11766,7 @@ impl Reaper {
         aa: bool,
     ) {
         match self.pointers.LICE_FillCircle {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_FillCircle)
-            ),
+            None => crate::function_not_available(stringify!(LICE_FillCircle)),
             Some(f) => f(dest, cx, cy, r, color, alpha, mode, aa),
         }
     }
@@ -13155,10 +11784,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_FillConvexPolygon {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_FillConvexPolygon)
-            ),
+            None => crate::function_not_available(stringify!(LICE_FillConvexPolygon)),
             Some(f) => f(dest, x, y, npoints, color, alpha, mode),
         }
     }
@@ -13177,10 +11803,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_FillRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_FillRect)
-            ),
+            None => crate::function_not_available(stringify!(LICE_FillRect)),
             Some(f) => f(dest, x, y, w, h, color, alpha, mode),
         }
     }
@@ -13201,10 +11824,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_FillTrapezoid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_FillTrapezoid)
-            ),
+            None => crate::function_not_available(stringify!(LICE_FillTrapezoid)),
             Some(f) => f(dest, x1a, x1b, y1, x2a, x2b, y2, color, alpha, mode),
         }
     }
@@ -13225,10 +11845,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_FillTriangle {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_FillTriangle)
-            ),
+            None => crate::function_not_available(stringify!(LICE_FillTriangle)),
             Some(f) => f(dest, x1, y1, x2, y2, x3, y3, color, alpha, mode),
         }
     }
@@ -13242,10 +11859,7 @@ impl Reaper {
         y: ::std::os::raw::c_int,
     ) -> root::reaper_functions::LICE_pixel {
         match self.pointers.LICE_GetPixel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_GetPixel)
-            ),
+            None => crate::function_not_available(stringify!(LICE_GetPixel)),
             Some(f) => f(bm, x, y),
         }
     }
@@ -13274,10 +11888,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_GradRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_GradRect)
-            ),
+            None => crate::function_not_available(stringify!(LICE_GradRect)),
             Some(f) => f(
                 dest, dstx, dsty, dstw, dsth, ir, ig, ib, ia, drdx, dgdx, dbdx, dadx, drdy, dgdy,
                 dbdy, dady, mode,
@@ -13300,10 +11911,7 @@ impl Reaper {
         aa: bool,
     ) {
         match self.pointers.LICE_Line {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_Line)
-            ),
+            None => crate::function_not_available(stringify!(LICE_Line)),
             Some(f) => f(dest, x1, y1, x2, y2, color, alpha, mode, aa),
         }
     }
@@ -13323,10 +11931,7 @@ impl Reaper {
         aa: bool,
     ) {
         match self.pointers.LICE_LineInt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_LineInt)
-            ),
+            None => crate::function_not_available(stringify!(LICE_LineInt)),
             Some(f) => f(dest, x1, y1, x2, y2, color, alpha, mode, aa),
         }
     }
@@ -13339,10 +11944,7 @@ impl Reaper {
         bmp: *mut root::reaper_functions::LICE_IBitmap,
     ) -> *mut root::reaper_functions::LICE_IBitmap {
         match self.pointers.LICE_LoadPNG {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_LoadPNG)
-            ),
+            None => crate::function_not_available(stringify!(LICE_LoadPNG)),
             Some(f) => f(filename, bmp),
         }
     }
@@ -13356,10 +11958,7 @@ impl Reaper {
         bmp: *mut root::reaper_functions::LICE_IBitmap,
     ) -> *mut root::reaper_functions::LICE_IBitmap {
         match self.pointers.LICE_LoadPNGFromResource {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_LoadPNGFromResource)
-            ),
+            None => crate::function_not_available(stringify!(LICE_LoadPNGFromResource)),
             Some(f) => f(hInst, resid, bmp),
         }
     }
@@ -13373,10 +11972,7 @@ impl Reaper {
         h: *mut ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_MeasureText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_MeasureText)
-            ),
+            None => crate::function_not_available(stringify!(LICE_MeasureText)),
             Some(f) => f(string, w, h),
         }
     }
@@ -13400,10 +11996,7 @@ impl Reaper {
         aadd: f32,
     ) {
         match self.pointers.LICE_MultiplyAddRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_MultiplyAddRect)
-            ),
+            None => crate::function_not_available(stringify!(LICE_MultiplyAddRect)),
             Some(f) => f(dest, x, y, w, h, rsc, gsc, bsc, asc, radd, gadd, badd, aadd),
         }
     }
@@ -13420,10 +12013,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_PutPixel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_PutPixel)
-            ),
+            None => crate::function_not_available(stringify!(LICE_PutPixel)),
             Some(f) => f(bm, x, y, color, alpha, mode),
         }
     }
@@ -13450,10 +12040,7 @@ impl Reaper {
         rotycent: f32,
     ) {
         match self.pointers.LICE_RotatedBlit {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_RotatedBlit)
-            ),
+            None => crate::function_not_available(stringify!(LICE_RotatedBlit)),
             Some(f) => f(
                 dest,
                 src,
@@ -13491,10 +12078,7 @@ impl Reaper {
         aa: bool,
     ) {
         match self.pointers.LICE_RoundRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_RoundRect)
-            ),
+            None => crate::function_not_available(stringify!(LICE_RoundRect)),
             Some(f) => f(drawbm, xpos, ypos, w, h, cornerradius, col, alpha, mode, aa),
         }
     }
@@ -13517,10 +12101,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_ScaledBlit {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_ScaledBlit)
-            ),
+            None => crate::function_not_available(stringify!(LICE_ScaledBlit)),
             Some(f) => f(
                 dest, src, dstx, dsty, dstw, dsth, srcx, srcy, srcw, srch, alpha, mode,
             ),
@@ -13539,10 +12120,7 @@ impl Reaper {
         keepmask: root::reaper_functions::LICE_pixel,
     ) {
         match self.pointers.LICE_SimpleFill {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_SimpleFill)
-            ),
+            None => crate::function_not_available(stringify!(LICE_SimpleFill)),
             Some(f) => f(dest, x, y, newcolor, comparemask, keepmask),
         }
     }
@@ -13562,10 +12140,7 @@ impl Reaper {
         wid: ::std::os::raw::c_int,
     ) {
         match self.pointers.LICE_ThickFLine {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LICE_ThickFLine)
-            ),
+            None => crate::function_not_available(stringify!(LICE_ThickFLine)),
             Some(f) => f(dest, x1, y1, x2, y2, color, alpha, mode, wid),
         }
     }
@@ -13579,10 +12154,7 @@ impl Reaper {
         flagsOptional: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.LocalizeString {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LocalizeString)
-            ),
+            None => crate::function_not_available(stringify!(LocalizeString)),
             Some(f) => f(src_string, section, flagsOptional),
         }
     }
@@ -13595,19 +12167,13 @@ impl Reaper {
         direction: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.Loop_OnArrow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Loop_OnArrow)
-            ),
+            None => crate::function_not_available(stringify!(Loop_OnArrow)),
             Some(f) => f(project, direction),
         }
     }
     pub fn Main_OnCommand(&self, command: ::std::os::raw::c_int, flag: ::std::os::raw::c_int) {
         match self.pointers.Main_OnCommand {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Main_OnCommand)
-            ),
+            None => crate::function_not_available(stringify!(Main_OnCommand)),
             Some(f) => f(command, flag),
         }
     }
@@ -13621,10 +12187,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) {
         match self.pointers.Main_OnCommandEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Main_OnCommandEx)
-            ),
+            None => crate::function_not_available(stringify!(Main_OnCommandEx)),
             Some(f) => f(command, flag, proj),
         }
     }
@@ -13633,10 +12196,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Main_openProject(&self, name: *const ::std::os::raw::c_char) {
         match self.pointers.Main_openProject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Main_openProject)
-            ),
+            None => crate::function_not_available(stringify!(Main_openProject)),
             Some(f) => f(name),
         }
     }
@@ -13649,10 +12209,7 @@ impl Reaper {
         forceSaveAsInOptional: bool,
     ) {
         match self.pointers.Main_SaveProject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Main_SaveProject)
-            ),
+            None => crate::function_not_available(stringify!(Main_SaveProject)),
             Some(f) => f(proj, forceSaveAsInOptional),
         }
     }
@@ -13666,19 +12223,13 @@ impl Reaper {
         options: ::std::os::raw::c_int,
     ) {
         match self.pointers.Main_SaveProjectEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Main_SaveProjectEx)
-            ),
+            None => crate::function_not_available(stringify!(Main_SaveProjectEx)),
             Some(f) => f(proj, filename, options),
         }
     }
     pub fn Main_UpdateLoopInfo(&self, ignoremask: ::std::os::raw::c_int) {
         match self.pointers.Main_UpdateLoopInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Main_UpdateLoopInfo)
-            ),
+            None => crate::function_not_available(stringify!(Main_UpdateLoopInfo)),
             Some(f) => f(ignoremask),
         }
     }
@@ -13687,10 +12238,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MarkProjectDirty(&self, proj: *mut root::ReaProject) {
         match self.pointers.MarkProjectDirty {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MarkProjectDirty)
-            ),
+            None => crate::function_not_available(stringify!(MarkProjectDirty)),
             Some(f) => f(proj),
         }
     }
@@ -13703,10 +12251,7 @@ impl Reaper {
         item: *mut root::MediaItem,
     ) {
         match self.pointers.MarkTrackItemsDirty {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MarkTrackItemsDirty)
-            ),
+            None => crate::function_not_available(stringify!(MarkTrackItemsDirty)),
             Some(f) => f(track, item),
         }
     }
@@ -13715,10 +12260,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Master_GetPlayRate(&self, project: *mut root::ReaProject) -> f64 {
         match self.pointers.Master_GetPlayRate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Master_GetPlayRate)
-            ),
+            None => crate::function_not_available(stringify!(Master_GetPlayRate)),
             Some(f) => f(project),
         }
     }
@@ -13727,37 +12269,25 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Master_GetPlayRateAtTime(&self, time_s: f64, proj: *mut root::ReaProject) -> f64 {
         match self.pointers.Master_GetPlayRateAtTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Master_GetPlayRateAtTime)
-            ),
+            None => crate::function_not_available(stringify!(Master_GetPlayRateAtTime)),
             Some(f) => f(time_s, proj),
         }
     }
     pub fn Master_GetTempo(&self) -> f64 {
         match self.pointers.Master_GetTempo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Master_GetTempo)
-            ),
+            None => crate::function_not_available(stringify!(Master_GetTempo)),
             Some(f) => f(),
         }
     }
     pub fn Master_NormalizePlayRate(&self, playrate: f64, isnormalized: bool) -> f64 {
         match self.pointers.Master_NormalizePlayRate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Master_NormalizePlayRate)
-            ),
+            None => crate::function_not_available(stringify!(Master_NormalizePlayRate)),
             Some(f) => f(playrate, isnormalized),
         }
     }
     pub fn Master_NormalizeTempo(&self, bpm: f64, isnormalized: bool) -> f64 {
         match self.pointers.Master_NormalizeTempo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Master_NormalizeTempo)
-            ),
+            None => crate::function_not_available(stringify!(Master_NormalizeTempo)),
             Some(f) => f(bpm, isnormalized),
         }
     }
@@ -13771,10 +12301,7 @@ impl Reaper {
         type_: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MB {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MB)
-            ),
+            None => crate::function_not_available(stringify!(MB)),
             Some(f) => f(msg, title, type_),
         }
     }
@@ -13787,10 +12314,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MediaItemDescendsFromTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MediaItemDescendsFromTrack)
-            ),
+            None => crate::function_not_available(stringify!(MediaItemDescendsFromTrack)),
             Some(f) => f(item, track),
         }
     }
@@ -13805,10 +12329,7 @@ impl Reaper {
         hashOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.Menu_GetHash {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Menu_GetHash)
-            ),
+            None => crate::function_not_available(stringify!(Menu_GetHash)),
             Some(f) => f(menuname, flag, hashOut, hashOut_sz),
         }
     }
@@ -13823,10 +12344,7 @@ impl Reaper {
         textsyxevtcntOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MIDI_CountEvts {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_CountEvts)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_CountEvts)),
             Some(f) => f(take, notecntOut, ccevtcntOut, textsyxevtcntOut),
         }
     }
@@ -13839,10 +12357,7 @@ impl Reaper {
         ccidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_DeleteCC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_DeleteCC)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_DeleteCC)),
             Some(f) => f(take, ccidx),
         }
     }
@@ -13855,10 +12370,7 @@ impl Reaper {
         evtidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_DeleteEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_DeleteEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_DeleteEvt)),
             Some(f) => f(take, evtidx),
         }
     }
@@ -13871,10 +12383,7 @@ impl Reaper {
         noteidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_DeleteNote {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_DeleteNote)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_DeleteNote)),
             Some(f) => f(take, noteidx),
         }
     }
@@ -13887,10 +12396,7 @@ impl Reaper {
         textsyxevtidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_DeleteTextSysexEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_DeleteTextSysexEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_DeleteTextSysexEvt)),
             Some(f) => f(take, textsyxevtidx),
         }
     }
@@ -13899,10 +12405,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MIDI_DisableSort(&self, take: *mut root::MediaItem_Take) {
         match self.pointers.MIDI_DisableSort {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_DisableSort)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_DisableSort)),
             Some(f) => f(take),
         }
     }
@@ -13915,10 +12418,7 @@ impl Reaper {
         ccidx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MIDI_EnumSelCC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_EnumSelCC)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_EnumSelCC)),
             Some(f) => f(take, ccidx),
         }
     }
@@ -13931,10 +12431,7 @@ impl Reaper {
         evtidx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MIDI_EnumSelEvts {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_EnumSelEvts)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_EnumSelEvts)),
             Some(f) => f(take, evtidx),
         }
     }
@@ -13947,10 +12444,7 @@ impl Reaper {
         noteidx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MIDI_EnumSelNotes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_EnumSelNotes)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_EnumSelNotes)),
             Some(f) => f(take, noteidx),
         }
     }
@@ -13963,19 +12457,13 @@ impl Reaper {
         textsyxidx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MIDI_EnumSelTextSysexEvts {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_EnumSelTextSysexEvts)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_EnumSelTextSysexEvts)),
             Some(f) => f(take, textsyxidx),
         }
     }
     pub fn MIDI_eventlist_Create(&self) -> *mut root::MIDI_eventlist {
         match self.pointers.MIDI_eventlist_Create {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_eventlist_Create)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_eventlist_Create)),
             Some(f) => f(),
         }
     }
@@ -13984,10 +12472,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MIDI_eventlist_Destroy(&self, evtlist: *mut root::MIDI_eventlist) {
         match self.pointers.MIDI_eventlist_Destroy {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_eventlist_Destroy)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_eventlist_Destroy)),
             Some(f) => f(evtlist),
         }
     }
@@ -14001,10 +12486,7 @@ impl Reaper {
         bufOutNeedBig_sz: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetAllEvts {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetAllEvts)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetAllEvts)),
             Some(f) => f(take, bufOutNeedBig, bufOutNeedBig_sz),
         }
     }
@@ -14024,10 +12506,7 @@ impl Reaper {
         msg3Out: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetCC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetCC)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetCC)),
             Some(f) => f(
                 take,
                 ccidx,
@@ -14052,10 +12531,7 @@ impl Reaper {
         beztensionOut: *mut f64,
     ) -> bool {
         match self.pointers.MIDI_GetCCShape {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetCCShape)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetCCShape)),
             Some(f) => f(take, ccidx, shapeOut, beztensionOut),
         }
     }
@@ -14073,10 +12549,7 @@ impl Reaper {
         msgOut_sz: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetEvt)),
             Some(f) => f(
                 take,
                 evtidx,
@@ -14098,10 +12571,7 @@ impl Reaper {
         noteLenOutOptional: *mut f64,
     ) -> f64 {
         match self.pointers.MIDI_GetGrid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetGrid)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetGrid)),
             Some(f) => f(take, swingOutOptional, noteLenOutOptional),
         }
     }
@@ -14116,10 +12586,7 @@ impl Reaper {
         hashOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetHash {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetHash)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetHash)),
             Some(f) => f(take, notesonly, hashOut, hashOut_sz),
         }
     }
@@ -14139,10 +12606,7 @@ impl Reaper {
         velOut: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetNote {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetNote)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetNote)),
             Some(f) => f(
                 take,
                 noteidx,
@@ -14165,10 +12629,7 @@ impl Reaper {
         ppqpos: f64,
     ) -> f64 {
         match self.pointers.MIDI_GetPPQPos_EndOfMeasure {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetPPQPos_EndOfMeasure)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetPPQPos_EndOfMeasure)),
             Some(f) => f(take, ppqpos),
         }
     }
@@ -14181,10 +12642,7 @@ impl Reaper {
         ppqpos: f64,
     ) -> f64 {
         match self.pointers.MIDI_GetPPQPos_StartOfMeasure {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetPPQPos_StartOfMeasure)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetPPQPos_StartOfMeasure)),
             Some(f) => f(take, ppqpos),
         }
     }
@@ -14197,10 +12655,7 @@ impl Reaper {
         projqn: f64,
     ) -> f64 {
         match self.pointers.MIDI_GetPPQPosFromProjQN {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetPPQPosFromProjQN)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetPPQPosFromProjQN)),
             Some(f) => f(take, projqn),
         }
     }
@@ -14213,10 +12668,7 @@ impl Reaper {
         projtime: f64,
     ) -> f64 {
         match self.pointers.MIDI_GetPPQPosFromProjTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetPPQPosFromProjTime)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetPPQPosFromProjTime)),
             Some(f) => f(take, projtime),
         }
     }
@@ -14229,10 +12681,7 @@ impl Reaper {
         ppqpos: f64,
     ) -> f64 {
         match self.pointers.MIDI_GetProjQNFromPPQPos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetProjQNFromPPQPos)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetProjQNFromPPQPos)),
             Some(f) => f(take, ppqpos),
         }
     }
@@ -14245,10 +12694,7 @@ impl Reaper {
         ppqpos: f64,
     ) -> f64 {
         match self.pointers.MIDI_GetProjTimeFromPPQPos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetProjTimeFromPPQPos)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetProjTimeFromPPQPos)),
             Some(f) => f(take, ppqpos),
         }
     }
@@ -14266,10 +12712,7 @@ impl Reaper {
         projLoopCntOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MIDI_GetRecentInputEvent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetRecentInputEvent)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetRecentInputEvent)),
             Some(f) => f(
                 idx,
                 bufOut,
@@ -14293,10 +12736,7 @@ impl Reaper {
         nameOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetScale {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetScale)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetScale)),
             Some(f) => f(take, rootOut, scaleOut, nameOut, nameOut_sz),
         }
     }
@@ -14315,10 +12755,7 @@ impl Reaper {
         msgOptional_sz: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetTextSysexEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetTextSysexEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetTextSysexEvt)),
             Some(f) => f(
                 take,
                 textsyxevtidx,
@@ -14342,10 +12779,7 @@ impl Reaper {
         hashOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_GetTrackHash {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_GetTrackHash)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_GetTrackHash)),
             Some(f) => f(track, notesonly, hashOut, hashOut_sz),
         }
     }
@@ -14355,10 +12789,7 @@ impl Reaper {
         force_reinit_output: ::std::os::raw::c_int,
     ) {
         match self.pointers.midi_init {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(midi_init)
-            ),
+            None => crate::function_not_available(stringify!(midi_init)),
             Some(f) => f(force_reinit_input, force_reinit_output),
         }
     }
@@ -14377,10 +12808,7 @@ impl Reaper {
         msg3: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_InsertCC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_InsertCC)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_InsertCC)),
             Some(f) => f(take, selected, muted, ppqpos, chanmsg, chan, msg2, msg3),
         }
     }
@@ -14397,10 +12825,7 @@ impl Reaper {
         bytestr_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_InsertEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_InsertEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_InsertEvt)),
             Some(f) => f(take, selected, muted, ppqpos, bytestr, bytestr_sz),
         }
     }
@@ -14420,10 +12845,7 @@ impl Reaper {
         noSortInOptional: *const bool,
     ) -> bool {
         match self.pointers.MIDI_InsertNote {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_InsertNote)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_InsertNote)),
             Some(f) => f(
                 take,
                 selected,
@@ -14451,10 +12873,7 @@ impl Reaper {
         bytestr_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_InsertTextSysexEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_InsertTextSysexEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_InsertTextSysexEvt)),
             Some(f) => f(take, selected, muted, ppqpos, type_, bytestr, bytestr_sz),
         }
     }
@@ -14463,19 +12882,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MIDI_RefreshEditors(&self, tk: *mut root::MediaItem_Take) {
         match self.pointers.MIDI_RefreshEditors {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_RefreshEditors)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_RefreshEditors)),
             Some(f) => f(tk),
         }
     }
     pub fn midi_reinit(&self) {
         match self.pointers.midi_reinit {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(midi_reinit)
-            ),
+            None => crate::function_not_available(stringify!(midi_reinit)),
             Some(f) => f(),
         }
     }
@@ -14484,10 +12897,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MIDI_SelectAll(&self, take: *mut root::MediaItem_Take, select: bool) {
         match self.pointers.MIDI_SelectAll {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SelectAll)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SelectAll)),
             Some(f) => f(take, select),
         }
     }
@@ -14501,10 +12911,7 @@ impl Reaper {
         buf_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDI_SetAllEvts {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SetAllEvts)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SetAllEvts)),
             Some(f) => f(take, buf, buf_sz),
         }
     }
@@ -14525,10 +12932,7 @@ impl Reaper {
         noSortInOptional: *const bool,
     ) -> bool {
         match self.pointers.MIDI_SetCC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SetCC)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SetCC)),
             Some(f) => f(
                 take,
                 ccidx,
@@ -14555,10 +12959,7 @@ impl Reaper {
         noSortInOptional: *const bool,
     ) -> bool {
         match self.pointers.MIDI_SetCCShape {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SetCCShape)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SetCCShape)),
             Some(f) => f(take, ccidx, shape, beztension, noSortInOptional),
         }
     }
@@ -14577,10 +12978,7 @@ impl Reaper {
         noSortInOptional: *const bool,
     ) -> bool {
         match self.pointers.MIDI_SetEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SetEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SetEvt)),
             Some(f) => f(
                 take,
                 evtidx,
@@ -14603,10 +13001,7 @@ impl Reaper {
         endQN: f64,
     ) -> bool {
         match self.pointers.MIDI_SetItemExtents {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SetItemExtents)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SetItemExtents)),
             Some(f) => f(item, startQN, endQN),
         }
     }
@@ -14627,10 +13022,7 @@ impl Reaper {
         noSortInOptional: *const bool,
     ) -> bool {
         match self.pointers.MIDI_SetNote {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SetNote)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SetNote)),
             Some(f) => f(
                 take,
                 noteidx,
@@ -14661,10 +13053,7 @@ impl Reaper {
         noSortInOptional: *const bool,
     ) -> bool {
         match self.pointers.MIDI_SetTextSysexEvt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_SetTextSysexEvt)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_SetTextSysexEvt)),
             Some(f) => f(
                 take,
                 textsyxevtidx,
@@ -14683,10 +13072,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MIDI_Sort(&self, take: *mut root::MediaItem_Take) {
         match self.pointers.MIDI_Sort {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDI_Sort)
-            ),
+            None => crate::function_not_available(stringify!(MIDI_Sort)),
             Some(f) => f(take),
         }
     }
@@ -14700,19 +13086,13 @@ impl Reaper {
         editable_only: bool,
     ) -> *mut root::MediaItem_Take {
         match self.pointers.MIDIEditor_EnumTakes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_EnumTakes)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_EnumTakes)),
             Some(f) => f(midieditor, takeindex, editable_only),
         }
     }
     pub fn MIDIEditor_GetActive(&self) -> root::HWND {
         match self.pointers.MIDIEditor_GetActive {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_GetActive)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_GetActive)),
             Some(f) => f(),
         }
     }
@@ -14721,10 +13101,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MIDIEditor_GetMode(&self, midieditor: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.MIDIEditor_GetMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_GetMode)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_GetMode)),
             Some(f) => f(midieditor),
         }
     }
@@ -14737,10 +13114,7 @@ impl Reaper {
         setting_desc: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MIDIEditor_GetSetting_int {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_GetSetting_int)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_GetSetting_int)),
             Some(f) => f(midieditor, setting_desc),
         }
     }
@@ -14755,10 +13129,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDIEditor_GetSetting_str {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_GetSetting_str)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_GetSetting_str)),
             Some(f) => f(midieditor, setting_desc, bufOut, bufOut_sz),
         }
     }
@@ -14767,10 +13138,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn MIDIEditor_GetTake(&self, midieditor: root::HWND) -> *mut root::MediaItem_Take {
         match self.pointers.MIDIEditor_GetTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_GetTake)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_GetTake)),
             Some(f) => f(midieditor),
         }
     }
@@ -14780,10 +13148,7 @@ impl Reaper {
         islistviewcommand: bool,
     ) -> bool {
         match self.pointers.MIDIEditor_LastFocused_OnCommand {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_LastFocused_OnCommand)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_LastFocused_OnCommand)),
             Some(f) => f(command_id, islistviewcommand),
         }
     }
@@ -14796,10 +13161,7 @@ impl Reaper {
         command_id: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDIEditor_OnCommand {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_OnCommand)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_OnCommand)),
             Some(f) => f(midieditor, command_id),
         }
     }
@@ -14813,10 +13175,7 @@ impl Reaper {
         setting: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.MIDIEditor_SetSetting_int {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditor_SetSetting_int)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditor_SetSetting_int)),
             Some(f) => f(midieditor, setting_desc, setting),
         }
     }
@@ -14831,10 +13190,7 @@ impl Reaper {
         is_set: bool,
     ) {
         match self.pointers.MIDIEditorFlagsForTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MIDIEditorFlagsForTrack)
-            ),
+            None => crate::function_not_available(stringify!(MIDIEditorFlagsForTrack)),
             Some(f) => f(track, pitchwheelrangeInOut, flagsInOut, is_set),
         }
     }
@@ -14843,10 +13199,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn mkpanstr(&self, strNeed64: *mut ::std::os::raw::c_char, pan: f64) {
         match self.pointers.mkpanstr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(mkpanstr)
-            ),
+            None => crate::function_not_available(stringify!(mkpanstr)),
             Some(f) => f(strNeed64, pan),
         }
     }
@@ -14855,10 +13208,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn mkvolpanstr(&self, strNeed64: *mut ::std::os::raw::c_char, vol: f64, pan: f64) {
         match self.pointers.mkvolpanstr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(mkvolpanstr)
-            ),
+            None => crate::function_not_available(stringify!(mkvolpanstr)),
             Some(f) => f(strNeed64, vol, pan),
         }
     }
@@ -14867,19 +13217,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn mkvolstr(&self, strNeed64: *mut ::std::os::raw::c_char, vol: f64) {
         match self.pointers.mkvolstr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(mkvolstr)
-            ),
+            None => crate::function_not_available(stringify!(mkvolstr)),
             Some(f) => f(strNeed64, vol),
         }
     }
     pub fn MoveEditCursor(&self, adjamt: f64, dosel: bool) {
         match self.pointers.MoveEditCursor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MoveEditCursor)
-            ),
+            None => crate::function_not_available(stringify!(MoveEditCursor)),
             Some(f) => f(adjamt, dosel),
         }
     }
@@ -14892,19 +13236,13 @@ impl Reaper {
         desttr: *mut root::MediaTrack,
     ) -> bool {
         match self.pointers.MoveMediaItemToTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MoveMediaItemToTrack)
-            ),
+            None => crate::function_not_available(stringify!(MoveMediaItemToTrack)),
             Some(f) => f(item, desttr),
         }
     }
     pub fn MuteAllTracks(&self, mute: bool) {
         match self.pointers.MuteAllTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MuteAllTracks)
-            ),
+            None => crate::function_not_available(stringify!(MuteAllTracks)),
             Some(f) => f(mute),
         }
     }
@@ -14918,10 +13256,7 @@ impl Reaper {
         wantWorkArea: bool,
     ) {
         match self.pointers.my_getViewport {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(my_getViewport)
-            ),
+            None => crate::function_not_available(stringify!(my_getViewport)),
             Some(f) => f(r, sr, wantWorkArea),
         }
     }
@@ -14933,19 +13268,13 @@ impl Reaper {
         command_name: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.NamedCommandLookup {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(NamedCommandLookup)
-            ),
+            None => crate::function_not_available(stringify!(NamedCommandLookup)),
             Some(f) => f(command_name),
         }
     }
     pub fn OnPauseButton(&self) {
         match self.pointers.OnPauseButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OnPauseButton)
-            ),
+            None => crate::function_not_available(stringify!(OnPauseButton)),
             Some(f) => f(),
         }
     }
@@ -14954,19 +13283,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn OnPauseButtonEx(&self, proj: *mut root::ReaProject) {
         match self.pointers.OnPauseButtonEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OnPauseButtonEx)
-            ),
+            None => crate::function_not_available(stringify!(OnPauseButtonEx)),
             Some(f) => f(proj),
         }
     }
     pub fn OnPlayButton(&self) {
         match self.pointers.OnPlayButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OnPlayButton)
-            ),
+            None => crate::function_not_available(stringify!(OnPlayButton)),
             Some(f) => f(),
         }
     }
@@ -14975,19 +13298,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn OnPlayButtonEx(&self, proj: *mut root::ReaProject) {
         match self.pointers.OnPlayButtonEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OnPlayButtonEx)
-            ),
+            None => crate::function_not_available(stringify!(OnPlayButtonEx)),
             Some(f) => f(proj),
         }
     }
     pub fn OnStopButton(&self) {
         match self.pointers.OnStopButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OnStopButton)
-            ),
+            None => crate::function_not_available(stringify!(OnStopButton)),
             Some(f) => f(),
         }
     }
@@ -14996,10 +13313,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn OnStopButtonEx(&self, proj: *mut root::ReaProject) {
         match self.pointers.OnStopButtonEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OnStopButtonEx)
-            ),
+            None => crate::function_not_available(stringify!(OnStopButtonEx)),
             Some(f) => f(proj),
         }
     }
@@ -15008,10 +13322,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn OpenColorThemeFile(&self, fn_: *const ::std::os::raw::c_char) -> bool {
         match self.pointers.OpenColorThemeFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OpenColorThemeFile)
-            ),
+            None => crate::function_not_available(stringify!(OpenColorThemeFile)),
             Some(f) => f(fn_),
         }
     }
@@ -15024,10 +13335,7 @@ impl Reaper {
         play: bool,
     ) -> root::HWND {
         match self.pointers.OpenMediaExplorer {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OpenMediaExplorer)
-            ),
+            None => crate::function_not_available(stringify!(OpenMediaExplorer)),
             Some(f) => f(mediafn, play),
         }
     }
@@ -15040,10 +13348,7 @@ impl Reaper {
         valueInOptional: *const f64,
     ) {
         match self.pointers.OscLocalMessageToHost {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OscLocalMessageToHost)
-            ),
+            None => crate::function_not_available(stringify!(OscLocalMessageToHost)),
             Some(f) => f(message, valueInOptional),
         }
     }
@@ -15052,10 +13357,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn parse_timestr(&self, buf: *const ::std::os::raw::c_char) -> f64 {
         match self.pointers.parse_timestr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(parse_timestr)
-            ),
+            None => crate::function_not_available(stringify!(parse_timestr)),
             Some(f) => f(buf),
         }
     }
@@ -15069,10 +13371,7 @@ impl Reaper {
         modeoverride: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.parse_timestr_len {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(parse_timestr_len)
-            ),
+            None => crate::function_not_available(stringify!(parse_timestr_len)),
             Some(f) => f(buf, offset, modeoverride),
         }
     }
@@ -15085,10 +13384,7 @@ impl Reaper {
         modeoverride: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.parse_timestr_pos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(parse_timestr_pos)
-            ),
+            None => crate::function_not_available(stringify!(parse_timestr_pos)),
             Some(f) => f(buf, modeoverride),
         }
     }
@@ -15097,10 +13393,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn parsepanstr(&self, str: *const ::std::os::raw::c_char) -> f64 {
         match self.pointers.parsepanstr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(parsepanstr)
-            ),
+            None => crate::function_not_available(stringify!(parsepanstr)),
             Some(f) => f(str),
         }
     }
@@ -15117,10 +13410,7 @@ impl Reaper {
         buildpeaks: bool,
     ) -> *mut root::PCM_sink {
         match self.pointers.PCM_Sink_Create {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Sink_Create)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Sink_Create)),
             Some(f) => f(filename, cfg, cfg_sz, nch, srate, buildpeaks),
         }
     }
@@ -15138,10 +13428,7 @@ impl Reaper {
         buildpeaks: bool,
     ) -> *mut root::PCM_sink {
         match self.pointers.PCM_Sink_CreateEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Sink_CreateEx)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Sink_CreateEx)),
             Some(f) => f(proj, filename, cfg, cfg_sz, nch, srate, buildpeaks),
         }
     }
@@ -15157,10 +13444,7 @@ impl Reaper {
         div: ::std::os::raw::c_int,
     ) -> *mut root::PCM_sink {
         match self.pointers.PCM_Sink_CreateMIDIFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Sink_CreateMIDIFile)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Sink_CreateMIDIFile)),
             Some(f) => f(filename, cfg, cfg_sz, bpm, div),
         }
     }
@@ -15177,10 +13461,7 @@ impl Reaper {
         div: ::std::os::raw::c_int,
     ) -> *mut root::PCM_sink {
         match self.pointers.PCM_Sink_CreateMIDIFileEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Sink_CreateMIDIFileEx)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Sink_CreateMIDIFileEx)),
             Some(f) => f(proj, filename, cfg, cfg_sz, bpm, div),
         }
     }
@@ -15193,10 +13474,7 @@ impl Reaper {
         descstrOut: *mut *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_uint {
         match self.pointers.PCM_Sink_Enum {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Sink_Enum)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Sink_Enum)),
             Some(f) => f(idx, descstrOut),
         }
     }
@@ -15209,10 +13487,7 @@ impl Reaper {
         data_sz: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.PCM_Sink_GetExtension {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Sink_GetExtension)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Sink_GetExtension)),
             Some(f) => f(data, data_sz),
         }
     }
@@ -15226,10 +13501,7 @@ impl Reaper {
         hwndParent: root::HWND,
     ) -> root::HWND {
         match self.pointers.PCM_Sink_ShowConfig {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Sink_ShowConfig)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Sink_ShowConfig)),
             Some(f) => f(cfg, cfg_sz, hwndParent),
         }
     }
@@ -15242,10 +13514,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PCM_Source_BuildPeaks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_BuildPeaks)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_BuildPeaks)),
             Some(f) => f(src, mode),
         }
     }
@@ -15257,10 +13526,7 @@ impl Reaper {
         filename: *const ::std::os::raw::c_char,
     ) -> *mut root::PCM_source {
         match self.pointers.PCM_Source_CreateFromFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_CreateFromFile)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_CreateFromFile)),
             Some(f) => f(filename),
         }
     }
@@ -15273,10 +13539,7 @@ impl Reaper {
         forcenoMidiImp: bool,
     ) -> *mut root::PCM_source {
         match self.pointers.PCM_Source_CreateFromFileEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_CreateFromFileEx)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_CreateFromFileEx)),
             Some(f) => f(filename, forcenoMidiImp),
         }
     }
@@ -15289,10 +13552,7 @@ impl Reaper {
         fn_: *const ::std::os::raw::c_char,
     ) -> *mut root::PCM_source {
         match self.pointers.PCM_Source_CreateFromSimple {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_CreateFromSimple)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_CreateFromSimple)),
             Some(f) => f(dec, fn_),
         }
     }
@@ -15304,10 +13564,7 @@ impl Reaper {
         sourcetype: *const ::std::os::raw::c_char,
     ) -> *mut root::PCM_source {
         match self.pointers.PCM_Source_CreateFromType {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_CreateFromType)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_CreateFromType)),
             Some(f) => f(sourcetype),
         }
     }
@@ -15316,10 +13573,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn PCM_Source_Destroy(&self, src: *mut root::PCM_source) {
         match self.pointers.PCM_Source_Destroy {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_Destroy)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_Destroy)),
             Some(f) => f(src),
         }
     }
@@ -15337,10 +13591,7 @@ impl Reaper {
         buf: *mut f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PCM_Source_GetPeaks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_GetPeaks)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_GetPeaks)),
             Some(f) => f(
                 src,
                 peakrate,
@@ -15363,10 +13614,7 @@ impl Reaper {
         revOut: *mut bool,
     ) -> bool {
         match self.pointers.PCM_Source_GetSectionInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PCM_Source_GetSectionInfo)
-            ),
+            None => crate::function_not_available(stringify!(PCM_Source_GetSectionInfo)),
             Some(f) => f(src, offsOut, lenOut, revOut),
         }
     }
@@ -15381,10 +13629,7 @@ impl Reaper {
         nch: ::std::os::raw::c_int,
     ) -> *mut root::REAPER_PeakBuild_Interface {
         match self.pointers.PeakBuild_Create {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PeakBuild_Create)
-            ),
+            None => crate::function_not_available(stringify!(PeakBuild_Create)),
             Some(f) => f(src, fn_, srate, nch),
         }
     }
@@ -15400,10 +13645,7 @@ impl Reaper {
         flags: ::std::os::raw::c_int,
     ) -> *mut root::REAPER_PeakBuild_Interface {
         match self.pointers.PeakBuild_CreateEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PeakBuild_CreateEx)
-            ),
+            None => crate::function_not_available(stringify!(PeakBuild_CreateEx)),
             Some(f) => f(src, fn_, srate, nch, flags),
         }
     }
@@ -15417,10 +13659,7 @@ impl Reaper {
         nch: ::std::os::raw::c_int,
     ) -> *mut root::REAPER_PeakGet_Interface {
         match self.pointers.PeakGet_Create {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PeakGet_Create)
-            ),
+            None => crate::function_not_available(stringify!(PeakGet_Create)),
             Some(f) => f(fn_, srate, nch),
         }
     }
@@ -15436,10 +13675,7 @@ impl Reaper {
         submode_sel: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PitchShiftSubModeMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PitchShiftSubModeMenu)
-            ),
+            None => crate::function_not_available(stringify!(PitchShiftSubModeMenu)),
             Some(f) => f(hwnd, x, y, mode, submode_sel),
         }
     }
@@ -15451,10 +13687,7 @@ impl Reaper {
         preview: *mut root::preview_register_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PlayPreview {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PlayPreview)
-            ),
+            None => crate::function_not_available(stringify!(PlayPreview)),
             Some(f) => f(preview),
         }
     }
@@ -15468,10 +13701,7 @@ impl Reaper {
         measure_align: f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PlayPreviewEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PlayPreviewEx)
-            ),
+            None => crate::function_not_available(stringify!(PlayPreviewEx)),
             Some(f) => f(preview, bufflags, measure_align),
         }
     }
@@ -15483,10 +13713,7 @@ impl Reaper {
         preview: *mut root::preview_register_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PlayTrackPreview {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PlayTrackPreview)
-            ),
+            None => crate::function_not_available(stringify!(PlayTrackPreview)),
             Some(f) => f(preview),
         }
     }
@@ -15499,10 +13726,7 @@ impl Reaper {
         preview: *mut root::preview_register_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PlayTrackPreview2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PlayTrackPreview2)
-            ),
+            None => crate::function_not_available(stringify!(PlayTrackPreview2)),
             Some(f) => f(proj, preview),
         }
     }
@@ -15517,10 +13741,7 @@ impl Reaper {
         measure_align: f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PlayTrackPreview2Ex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PlayTrackPreview2Ex)
-            ),
+            None => crate::function_not_available(stringify!(PlayTrackPreview2Ex)),
             Some(f) => f(proj, preview, flags, measure_align),
         }
     }
@@ -15532,28 +13753,21 @@ impl Reaper {
         name: *const ::std::os::raw::c_char,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.plugin_getapi {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(plugin_getapi)
-            ),
+            None => crate::function_not_available(stringify!(plugin_getapi)),
             Some(f) => f(name),
         }
     }
     pub fn plugin_getFilterList(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.plugin_getFilterList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(plugin_getFilterList)
-            ),
+            None => crate::function_not_available(stringify!(plugin_getFilterList)),
             Some(f) => f(),
         }
     }
     pub fn plugin_getImportableProjectFilterList(&self) -> *const ::std::os::raw::c_char {
         match self.pointers.plugin_getImportableProjectFilterList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(plugin_getImportableProjectFilterList)
-            ),
+            None => {
+                crate::function_not_available(stringify!(plugin_getImportableProjectFilterList))
+            }
             Some(f) => f(),
         }
     }
@@ -15566,28 +13780,19 @@ impl Reaper {
         infostruct: *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int {
         match self.pointers.plugin_register {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(plugin_register)
-            ),
+            None => crate::function_not_available(stringify!(plugin_register)),
             Some(f) => f(name, infostruct),
         }
     }
     pub fn PluginWantsAlwaysRunFx(&self, amt: ::std::os::raw::c_int) {
         match self.pointers.PluginWantsAlwaysRunFx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PluginWantsAlwaysRunFx)
-            ),
+            None => crate::function_not_available(stringify!(PluginWantsAlwaysRunFx)),
             Some(f) => f(amt),
         }
     }
     pub fn PreventUIRefresh(&self, prevent_count: ::std::os::raw::c_int) {
         match self.pointers.PreventUIRefresh {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PreventUIRefresh)
-            ),
+            None => crate::function_not_available(stringify!(PreventUIRefresh)),
             Some(f) => f(prevent_count),
         }
     }
@@ -15600,10 +13805,7 @@ impl Reaper {
         idx: ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.projectconfig_var_addr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(projectconfig_var_addr)
-            ),
+            None => crate::function_not_available(stringify!(projectconfig_var_addr)),
             Some(f) => f(proj, idx),
         }
     }
@@ -15616,10 +13818,7 @@ impl Reaper {
         szOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.projectconfig_var_getoffs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(projectconfig_var_getoffs)
-            ),
+            None => crate::function_not_available(stringify!(projectconfig_var_getoffs)),
             Some(f) => f(name, szOut),
         }
     }
@@ -15630,19 +13829,13 @@ impl Reaper {
         section_id: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.PromptForAction {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PromptForAction)
-            ),
+            None => crate::function_not_available(stringify!(PromptForAction)),
             Some(f) => f(session_mode, init_id, section_id),
         }
     }
     pub fn realloc_cmd_clear(&self, tok: ::std::os::raw::c_int) {
         match self.pointers.realloc_cmd_clear {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(realloc_cmd_clear)
-            ),
+            None => crate::function_not_available(stringify!(realloc_cmd_clear)),
             Some(f) => f(tok),
         }
     }
@@ -15656,10 +13849,7 @@ impl Reaper {
         new_size: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.realloc_cmd_ptr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(realloc_cmd_ptr)
-            ),
+            None => crate::function_not_available(stringify!(realloc_cmd_ptr)),
             Some(f) => f(ptr, ptr_size, new_size),
         }
     }
@@ -15672,10 +13862,7 @@ impl Reaper {
         ptr_size: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.realloc_cmd_register_buf {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(realloc_cmd_register_buf)
-            ),
+            None => crate::function_not_available(stringify!(realloc_cmd_register_buf)),
             Some(f) => f(ptr, ptr_size),
         }
     }
@@ -15684,10 +13871,7 @@ impl Reaper {
         version: ::std::os::raw::c_int,
     ) -> *mut root::IReaperPitchShift {
         match self.pointers.ReaperGetPitchShiftAPI {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ReaperGetPitchShiftAPI)
-            ),
+            None => crate::function_not_available(stringify!(ReaperGetPitchShiftAPI)),
             Some(f) => f(version),
         }
     }
@@ -15696,10 +13880,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ReaScriptError(&self, errmsg: *const ::std::os::raw::c_char) {
         match self.pointers.ReaScriptError {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ReaScriptError)
-            ),
+            None => crate::function_not_available(stringify!(ReaScriptError)),
             Some(f) => f(errmsg),
         }
     }
@@ -15712,28 +13893,19 @@ impl Reaper {
         ignored: usize,
     ) -> ::std::os::raw::c_int {
         match self.pointers.RecursiveCreateDirectory {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RecursiveCreateDirectory)
-            ),
+            None => crate::function_not_available(stringify!(RecursiveCreateDirectory)),
             Some(f) => f(path, ignored),
         }
     }
     pub fn reduce_open_files(&self, flags: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
         match self.pointers.reduce_open_files {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(reduce_open_files)
-            ),
+            None => crate::function_not_available(stringify!(reduce_open_files)),
             Some(f) => f(flags),
         }
     }
     pub fn RefreshToolbar(&self, command_id: ::std::os::raw::c_int) {
         match self.pointers.RefreshToolbar {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RefreshToolbar)
-            ),
+            None => crate::function_not_available(stringify!(RefreshToolbar)),
             Some(f) => f(command_id),
         }
     }
@@ -15743,10 +13915,7 @@ impl Reaper {
         command_id: ::std::os::raw::c_int,
     ) {
         match self.pointers.RefreshToolbar2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RefreshToolbar2)
-            ),
+            None => crate::function_not_available(stringify!(RefreshToolbar2)),
             Some(f) => f(section_id, command_id),
         }
     }
@@ -15760,10 +13929,7 @@ impl Reaper {
         out_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.relative_fn {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(relative_fn)
-            ),
+            None => crate::function_not_available(stringify!(relative_fn)),
             Some(f) => f(in_, out, out_sz),
         }
     }
@@ -15777,10 +13943,7 @@ impl Reaper {
         sendidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.RemoveTrackSend {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RemoveTrackSend)
-            ),
+            None => crate::function_not_available(stringify!(RemoveTrackSend)),
             Some(f) => f(tr, category, sendidx),
         }
     }
@@ -15796,10 +13959,7 @@ impl Reaper {
         playrate: f64,
     ) -> bool {
         match self.pointers.RenderFileSection {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RenderFileSection)
-            ),
+            None => crate::function_not_available(stringify!(RenderFileSection)),
             Some(f) => f(
                 source_filename,
                 target_filename,
@@ -15815,28 +13975,19 @@ impl Reaper {
         makePrevFolder: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.ReorderSelectedTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ReorderSelectedTracks)
-            ),
+            None => crate::function_not_available(stringify!(ReorderSelectedTracks)),
             Some(f) => f(beforeTrackIdx, makePrevFolder),
         }
     }
     pub fn Resample_EnumModes(&self, mode: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char {
         match self.pointers.Resample_EnumModes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Resample_EnumModes)
-            ),
+            None => crate::function_not_available(stringify!(Resample_EnumModes)),
             Some(f) => f(mode),
         }
     }
     pub fn Resampler_Create(&self) -> *mut root::REAPER_Resample_Interface {
         match self.pointers.Resampler_Create {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Resampler_Create)
-            ),
+            None => crate::function_not_available(stringify!(Resampler_Create)),
             Some(f) => f(),
         }
     }
@@ -15850,10 +14001,7 @@ impl Reaper {
         out_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.resolve_fn {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(resolve_fn)
-            ),
+            None => crate::function_not_available(stringify!(resolve_fn)),
             Some(f) => f(in_, out, out_sz),
         }
     }
@@ -15868,10 +14016,7 @@ impl Reaper {
         checkSubDirOptional: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.resolve_fn2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(resolve_fn2)
-            ),
+            None => crate::function_not_available(stringify!(resolve_fn2)),
             Some(f) => f(in_, out, out_sz, checkSubDirOptional),
         }
     }
@@ -15887,10 +14032,7 @@ impl Reaper {
         targets_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ResolveRenderPattern {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ResolveRenderPattern)
-            ),
+            None => crate::function_not_available(stringify!(ResolveRenderPattern)),
             Some(f) => f(project, path, pattern, targets, targets_sz),
         }
     }
@@ -15899,28 +14041,19 @@ impl Reaper {
         command_id: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.ReverseNamedCommandLookup {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ReverseNamedCommandLookup)
-            ),
+            None => crate::function_not_available(stringify!(ReverseNamedCommandLookup)),
             Some(f) => f(command_id),
         }
     }
     pub fn ScaleFromEnvelopeMode(&self, scaling_mode: ::std::os::raw::c_int, val: f64) -> f64 {
         match self.pointers.ScaleFromEnvelopeMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ScaleFromEnvelopeMode)
-            ),
+            None => crate::function_not_available(stringify!(ScaleFromEnvelopeMode)),
             Some(f) => f(scaling_mode, val),
         }
     }
     pub fn ScaleToEnvelopeMode(&self, scaling_mode: ::std::os::raw::c_int, val: f64) -> f64 {
         match self.pointers.ScaleToEnvelopeMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ScaleToEnvelopeMode)
-            ),
+            None => crate::function_not_available(stringify!(ScaleToEnvelopeMode)),
             Some(f) => f(scaling_mode, val),
         }
     }
@@ -15934,10 +14067,7 @@ impl Reaper {
         param: *mut ::std::os::raw::c_void,
     ) {
         match self.pointers.screenset_register {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(screenset_register)
-            ),
+            None => crate::function_not_available(stringify!(screenset_register)),
             Some(f) => f(id, callbackFunc, param),
         }
     }
@@ -15951,10 +14081,7 @@ impl Reaper {
         param: *mut ::std::os::raw::c_void,
     ) {
         match self.pointers.screenset_registerNew {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(screenset_registerNew)
-            ),
+            None => crate::function_not_available(stringify!(screenset_registerNew)),
             Some(f) => f(id, callbackFunc, param),
         }
     }
@@ -15963,10 +14090,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn screenset_unregister(&self, id: *mut ::std::os::raw::c_char) {
         match self.pointers.screenset_unregister {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(screenset_unregister)
-            ),
+            None => crate::function_not_available(stringify!(screenset_unregister)),
             Some(f) => f(id),
         }
     }
@@ -15975,10 +14099,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn screenset_unregisterByParam(&self, param: *mut ::std::os::raw::c_void) {
         match self.pointers.screenset_unregisterByParam {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(screenset_unregisterByParam)
-            ),
+            None => crate::function_not_available(stringify!(screenset_unregisterByParam)),
             Some(f) => f(param),
         }
     }
@@ -15987,10 +14108,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn screenset_updateLastFocus(&self, prevWin: root::HWND) {
         match self.pointers.screenset_updateLastFocus {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(screenset_updateLastFocus)
-            ),
+            None => crate::function_not_available(stringify!(screenset_updateLastFocus)),
             Some(f) => f(prevWin),
         }
     }
@@ -15999,10 +14117,7 @@ impl Reaper {
         uniqueID: ::std::os::raw::c_int,
     ) -> *mut root::KbdSectionInfo {
         match self.pointers.SectionFromUniqueID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SectionFromUniqueID)
-            ),
+            None => crate::function_not_available(stringify!(SectionFromUniqueID)),
             Some(f) => f(uniqueID),
         }
     }
@@ -16011,10 +14126,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SelectAllMediaItems(&self, proj: *mut root::ReaProject, selected: bool) {
         match self.pointers.SelectAllMediaItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SelectAllMediaItems)
-            ),
+            None => crate::function_not_available(stringify!(SelectAllMediaItems)),
             Some(f) => f(proj, selected),
         }
     }
@@ -16023,10 +14135,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SelectProjectInstance(&self, proj: *mut root::ReaProject) {
         match self.pointers.SelectProjectInstance {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SelectProjectInstance)
-            ),
+            None => crate::function_not_available(stringify!(SelectProjectInstance)),
             Some(f) => f(proj),
         }
     }
@@ -16040,10 +14149,7 @@ impl Reaper {
         msglen: ::std::os::raw::c_int,
     ) {
         match self.pointers.SendLocalOscMessage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SendLocalOscMessage)
-            ),
+            None => crate::function_not_available(stringify!(SendLocalOscMessage)),
             Some(f) => f(local_osc_handler, msg, msglen),
         }
     }
@@ -16057,10 +14163,7 @@ impl Reaper {
         msg_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.SendMIDIMessageToHardware {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SendMIDIMessageToHardware)
-            ),
+            None => crate::function_not_available(stringify!(SendMIDIMessageToHardware)),
             Some(f) => f(output, msg, msg_sz),
         }
     }
@@ -16069,19 +14172,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetActiveTake(&self, take: *mut root::MediaItem_Take) {
         match self.pointers.SetActiveTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetActiveTake)
-            ),
+            None => crate::function_not_available(stringify!(SetActiveTake)),
             Some(f) => f(take),
         }
     }
     pub fn SetAutomationMode(&self, mode: ::std::os::raw::c_int, onlySel: bool) {
         match self.pointers.SetAutomationMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetAutomationMode)
-            ),
+            None => crate::function_not_available(stringify!(SetAutomationMode)),
             Some(f) => f(mode, onlySel),
         }
     }
@@ -16090,10 +14187,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetCurrentBPM(&self, __proj: *mut root::ReaProject, bpm: f64, wantUndo: bool) {
         match self.pointers.SetCurrentBPM {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetCurrentBPM)
-            ),
+            None => crate::function_not_available(stringify!(SetCurrentBPM)),
             Some(f) => f(__proj, bpm, wantUndo),
         }
     }
@@ -16106,19 +14200,13 @@ impl Reaper {
         envInOptional: *mut root::TrackEnvelope,
     ) {
         match self.pointers.SetCursorContext {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetCursorContext)
-            ),
+            None => crate::function_not_available(stringify!(SetCursorContext)),
             Some(f) => f(mode, envInOptional),
         }
     }
     pub fn SetEditCurPos(&self, time: f64, moveview: bool, seekplay: bool) {
         match self.pointers.SetEditCurPos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetEditCurPos)
-            ),
+            None => crate::function_not_available(stringify!(SetEditCurPos)),
             Some(f) => f(time, moveview, seekplay),
         }
     }
@@ -16133,10 +14221,7 @@ impl Reaper {
         seekplay: bool,
     ) {
         match self.pointers.SetEditCurPos2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetEditCurPos2)
-            ),
+            None => crate::function_not_available(stringify!(SetEditCurPos2)),
             Some(f) => f(proj, time, moveview, seekplay),
         }
     }
@@ -16155,10 +14240,7 @@ impl Reaper {
         noSortInOptional: *mut bool,
     ) -> bool {
         match self.pointers.SetEnvelopePoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetEnvelopePoint)
-            ),
+            None => crate::function_not_available(stringify!(SetEnvelopePoint)),
             Some(f) => f(
                 envelope,
                 ptidx,
@@ -16187,10 +14269,7 @@ impl Reaper {
         noSortInOptional: *mut bool,
     ) -> bool {
         match self.pointers.SetEnvelopePointEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetEnvelopePointEx)
-            ),
+            None => crate::function_not_available(stringify!(SetEnvelopePointEx)),
             Some(f) => f(
                 envelope,
                 autoitem_idx,
@@ -16214,10 +14293,7 @@ impl Reaper {
         isundoOptional: bool,
     ) -> bool {
         match self.pointers.SetEnvelopeStateChunk {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetEnvelopeStateChunk)
-            ),
+            None => crate::function_not_available(stringify!(SetEnvelopeStateChunk)),
             Some(f) => f(env, str, isundoOptional),
         }
     }
@@ -16232,19 +14308,13 @@ impl Reaper {
         persist: bool,
     ) {
         match self.pointers.SetExtState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetExtState)
-            ),
+            None => crate::function_not_available(stringify!(SetExtState)),
             Some(f) => f(section, key, value, persist),
         }
     }
     pub fn SetGlobalAutomationOverride(&self, mode: ::std::os::raw::c_int) {
         match self.pointers.SetGlobalAutomationOverride {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetGlobalAutomationOverride)
-            ),
+            None => crate::function_not_available(stringify!(SetGlobalAutomationOverride)),
             Some(f) => f(mode),
         }
     }
@@ -16258,19 +14328,13 @@ impl Reaper {
         isundoOptional: bool,
     ) -> bool {
         match self.pointers.SetItemStateChunk {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetItemStateChunk)
-            ),
+            None => crate::function_not_available(stringify!(SetItemStateChunk)),
             Some(f) => f(item, str, isundoOptional),
         }
     }
     pub fn SetMasterTrackVisibility(&self, flag: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
         match self.pointers.SetMasterTrackVisibility {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMasterTrackVisibility)
-            ),
+            None => crate::function_not_available(stringify!(SetMasterTrackVisibility)),
             Some(f) => f(flag),
         }
     }
@@ -16284,10 +14348,7 @@ impl Reaper {
         newvalue: f64,
     ) -> bool {
         match self.pointers.SetMediaItemInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMediaItemInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(SetMediaItemInfo_Value)),
             Some(f) => f(item, parmname, newvalue),
         }
     }
@@ -16301,10 +14362,7 @@ impl Reaper {
         refreshUI: bool,
     ) -> bool {
         match self.pointers.SetMediaItemLength {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMediaItemLength)
-            ),
+            None => crate::function_not_available(stringify!(SetMediaItemLength)),
             Some(f) => f(item, length, refreshUI),
         }
     }
@@ -16318,10 +14376,7 @@ impl Reaper {
         refreshUI: bool,
     ) -> bool {
         match self.pointers.SetMediaItemPosition {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMediaItemPosition)
-            ),
+            None => crate::function_not_available(stringify!(SetMediaItemPosition)),
             Some(f) => f(item, position, refreshUI),
         }
     }
@@ -16330,10 +14385,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetMediaItemSelected(&self, item: *mut root::MediaItem, selected: bool) {
         match self.pointers.SetMediaItemSelected {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMediaItemSelected)
-            ),
+            None => crate::function_not_available(stringify!(SetMediaItemSelected)),
             Some(f) => f(item, selected),
         }
     }
@@ -16346,10 +14398,7 @@ impl Reaper {
         source: *mut root::PCM_source,
     ) -> bool {
         match self.pointers.SetMediaItemTake_Source {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMediaItemTake_Source)
-            ),
+            None => crate::function_not_available(stringify!(SetMediaItemTake_Source)),
             Some(f) => f(take, source),
         }
     }
@@ -16363,10 +14412,7 @@ impl Reaper {
         newvalue: f64,
     ) -> bool {
         match self.pointers.SetMediaItemTakeInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMediaItemTakeInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(SetMediaItemTakeInfo_Value)),
             Some(f) => f(take, parmname, newvalue),
         }
     }
@@ -16380,10 +14426,7 @@ impl Reaper {
         newvalue: f64,
     ) -> bool {
         match self.pointers.SetMediaTrackInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMediaTrackInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(SetMediaTrackInfo_Value)),
             Some(f) => f(tr, parmname, newvalue),
         }
     }
@@ -16392,10 +14435,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetMIDIEditorGrid(&self, project: *mut root::ReaProject, division: f64) {
         match self.pointers.SetMIDIEditorGrid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMIDIEditorGrid)
-            ),
+            None => crate::function_not_available(stringify!(SetMIDIEditorGrid)),
             Some(f) => f(project, division),
         }
     }
@@ -16407,10 +14447,7 @@ impl Reaper {
         leftmosttrack: *mut root::MediaTrack,
     ) -> *mut root::MediaTrack {
         match self.pointers.SetMixerScroll {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMixerScroll)
-            ),
+            None => crate::function_not_available(stringify!(SetMixerScroll)),
             Some(f) => f(leftmosttrack),
         }
     }
@@ -16424,10 +14461,7 @@ impl Reaper {
         action: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.SetMouseModifier {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMouseModifier)
-            ),
+            None => crate::function_not_available(stringify!(SetMouseModifier)),
             Some(f) => f(context, modifier_flag, action),
         }
     }
@@ -16436,10 +14470,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetOnlyTrackSelected(&self, track: *mut root::MediaTrack) {
         match self.pointers.SetOnlyTrackSelected {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetOnlyTrackSelected)
-            ),
+            None => crate::function_not_available(stringify!(SetOnlyTrackSelected)),
             Some(f) => f(track),
         }
     }
@@ -16448,10 +14479,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetProjectGrid(&self, project: *mut root::ReaProject, division: f64) {
         match self.pointers.SetProjectGrid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjectGrid)
-            ),
+            None => crate::function_not_available(stringify!(SetProjectGrid)),
             Some(f) => f(project, division),
         }
     }
@@ -16467,10 +14495,7 @@ impl Reaper {
         name: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.SetProjectMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjectMarker)
-            ),
+            None => crate::function_not_available(stringify!(SetProjectMarker)),
             Some(f) => f(markrgnindexnumber, isrgn, pos, rgnend, name),
         }
     }
@@ -16487,10 +14512,7 @@ impl Reaper {
         name: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.SetProjectMarker2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjectMarker2)
-            ),
+            None => crate::function_not_available(stringify!(SetProjectMarker2)),
             Some(f) => f(proj, markrgnindexnumber, isrgn, pos, rgnend, name),
         }
     }
@@ -16508,10 +14530,7 @@ impl Reaper {
         color: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SetProjectMarker3 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjectMarker3)
-            ),
+            None => crate::function_not_available(stringify!(SetProjectMarker3)),
             Some(f) => f(proj, markrgnindexnumber, isrgn, pos, rgnend, name, color),
         }
     }
@@ -16530,10 +14549,7 @@ impl Reaper {
         flags: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SetProjectMarker4 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjectMarker4)
-            ),
+            None => crate::function_not_available(stringify!(SetProjectMarker4)),
             Some(f) => f(
                 proj,
                 markrgnindexnumber,
@@ -16561,10 +14577,7 @@ impl Reaper {
         color: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SetProjectMarkerByIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjectMarkerByIndex)
-            ),
+            None => crate::function_not_available(stringify!(SetProjectMarkerByIndex)),
             Some(f) => f(proj, markrgnidx, isrgn, pos, rgnend, IDnumber, name, color),
         }
     }
@@ -16584,10 +14597,7 @@ impl Reaper {
         flags: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SetProjectMarkerByIndex2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjectMarkerByIndex2)
-            ),
+            None => crate::function_not_available(stringify!(SetProjectMarkerByIndex2)),
             Some(f) => f(
                 proj, markrgnidx, isrgn, pos, rgnend, IDnumber, name, color, flags,
             ),
@@ -16604,10 +14614,7 @@ impl Reaper {
         value: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetProjExtState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProjExtState)
-            ),
+            None => crate::function_not_available(stringify!(SetProjExtState)),
             Some(f) => f(proj, extname, key, value),
         }
     }
@@ -16622,10 +14629,7 @@ impl Reaper {
         flag: ::std::os::raw::c_int,
     ) {
         match self.pointers.SetRegionRenderMatrix {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetRegionRenderMatrix)
-            ),
+            None => crate::function_not_available(stringify!(SetRegionRenderMatrix)),
             Some(f) => f(proj, regionindex, track, flag),
         }
     }
@@ -16634,10 +14638,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetRenderLastError(&self, errorstr: *const ::std::os::raw::c_char) {
         match self.pointers.SetRenderLastError {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetRenderLastError)
-            ),
+            None => crate::function_not_available(stringify!(SetRenderLastError)),
             Some(f) => f(errorstr),
         }
     }
@@ -16653,10 +14654,7 @@ impl Reaper {
         colorInOptional: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetTakeMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTakeMarker)
-            ),
+            None => crate::function_not_available(stringify!(SetTakeMarker)),
             Some(f) => f(take, idx, nameIn, srcposInOptional, colorInOptional),
         }
     }
@@ -16671,10 +14669,7 @@ impl Reaper {
         srcposInOptional: *const f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetTakeStretchMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTakeStretchMarker)
-            ),
+            None => crate::function_not_available(stringify!(SetTakeStretchMarker)),
             Some(f) => f(take, idx, pos, srcposInOptional),
         }
     }
@@ -16688,10 +14683,7 @@ impl Reaper {
         slope: f64,
     ) -> bool {
         match self.pointers.SetTakeStretchMarkerSlope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTakeStretchMarkerSlope)
-            ),
+            None => crate::function_not_available(stringify!(SetTakeStretchMarkerSlope)),
             Some(f) => f(take, idx, slope),
         }
     }
@@ -16711,10 +14703,7 @@ impl Reaper {
         lineartempo: bool,
     ) -> bool {
         match self.pointers.SetTempoTimeSigMarker {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTempoTimeSigMarker)
-            ),
+            None => crate::function_not_available(stringify!(SetTempoTimeSigMarker)),
             Some(f) => f(
                 proj,
                 ptidx,
@@ -16738,10 +14727,7 @@ impl Reaper {
         flagsOptional: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetThemeColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetThemeColor)
-            ),
+            None => crate::function_not_available(stringify!(SetThemeColor)),
             Some(f) => f(ini_key, color, flagsOptional),
         }
     }
@@ -16752,10 +14738,7 @@ impl Reaper {
         state: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SetToggleCommandState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetToggleCommandState)
-            ),
+            None => crate::function_not_available(stringify!(SetToggleCommandState)),
             Some(f) => f(section_id, command_id, state),
         }
     }
@@ -16768,10 +14751,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.SetTrackAutomationMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackAutomationMode)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackAutomationMode)),
             Some(f) => f(tr, mode),
         }
     }
@@ -16780,10 +14760,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetTrackColor(&self, track: *mut root::MediaTrack, color: ::std::os::raw::c_int) {
         match self.pointers.SetTrackColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackColor)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackColor)),
             Some(f) => f(track, color),
         }
     }
@@ -16797,10 +14774,7 @@ impl Reaper {
         str: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.SetTrackMIDILyrics {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackMIDILyrics)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackMIDILyrics)),
             Some(f) => f(track, flag, str),
         }
     }
@@ -16815,10 +14789,7 @@ impl Reaper {
         name: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.SetTrackMIDINoteName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackMIDINoteName)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackMIDINoteName)),
             Some(f) => f(track, pitch, chan, name),
         }
     }
@@ -16834,10 +14805,7 @@ impl Reaper {
         name: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.SetTrackMIDINoteNameEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackMIDINoteNameEx)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackMIDINoteNameEx)),
             Some(f) => f(proj, track, pitch, chan, name),
         }
     }
@@ -16846,10 +14814,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetTrackSelected(&self, track: *mut root::MediaTrack, selected: bool) {
         match self.pointers.SetTrackSelected {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackSelected)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackSelected)),
             Some(f) => f(track, selected),
         }
     }
@@ -16865,10 +14830,7 @@ impl Reaper {
         newvalue: f64,
     ) -> bool {
         match self.pointers.SetTrackSendInfo_Value {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackSendInfo_Value)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackSendInfo_Value)),
             Some(f) => f(tr, category, sendidx, parmname, newvalue),
         }
     }
@@ -16883,10 +14845,7 @@ impl Reaper {
         isend: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SetTrackSendUIPan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackSendUIPan)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackSendUIPan)),
             Some(f) => f(track, send_idx, pan, isend),
         }
     }
@@ -16901,10 +14860,7 @@ impl Reaper {
         isend: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SetTrackSendUIVol {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackSendUIVol)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackSendUIVol)),
             Some(f) => f(track, send_idx, vol, isend),
         }
     }
@@ -16918,10 +14874,7 @@ impl Reaper {
         isundoOptional: bool,
     ) -> bool {
         match self.pointers.SetTrackStateChunk {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackStateChunk)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackStateChunk)),
             Some(f) => f(track, str, isundoOptional),
         }
     }
@@ -16935,10 +14888,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetTrackUIInputMonitor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUIInputMonitor)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUIInputMonitor)),
             Some(f) => f(track, monitor, igngroupflags),
         }
     }
@@ -16952,10 +14902,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetTrackUIMute {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUIMute)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUIMute)),
             Some(f) => f(track, mute, igngroupflags),
         }
     }
@@ -16971,10 +14918,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.SetTrackUIPan {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUIPan)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUIPan)),
             Some(f) => f(track, pan, relative, done, igngroupflags),
         }
     }
@@ -16988,10 +14932,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetTrackUIPolarity {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUIPolarity)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUIPolarity)),
             Some(f) => f(track, polarity, igngroupflags),
         }
     }
@@ -17005,10 +14946,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetTrackUIRecArm {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUIRecArm)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUIRecArm)),
             Some(f) => f(track, recarm, igngroupflags),
         }
     }
@@ -17022,10 +14960,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SetTrackUISolo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUISolo)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUISolo)),
             Some(f) => f(track, solo, igngroupflags),
         }
     }
@@ -17041,10 +14976,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.SetTrackUIVolume {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUIVolume)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUIVolume)),
             Some(f) => f(track, volume, relative, done, igngroupflags),
         }
     }
@@ -17060,10 +14992,7 @@ impl Reaper {
         igngroupflags: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.SetTrackUIWidth {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTrackUIWidth)
-            ),
+            None => crate::function_not_available(stringify!(SetTrackUIWidth)),
             Some(f) => f(track, width, relative, done, igngroupflags),
         }
     }
@@ -17072,10 +15001,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ShowActionList(&self, section: *mut root::KbdSectionInfo, callerWnd: root::HWND) {
         match self.pointers.ShowActionList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ShowActionList)
-            ),
+            None => crate::function_not_available(stringify!(ShowActionList)),
             Some(f) => f(section, callerWnd),
         }
     }
@@ -17084,10 +15010,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ShowConsoleMsg(&self, msg: *const ::std::os::raw::c_char) {
         match self.pointers.ShowConsoleMsg {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ShowConsoleMsg)
-            ),
+            None => crate::function_not_available(stringify!(ShowConsoleMsg)),
             Some(f) => f(msg),
         }
     }
@@ -17101,10 +15024,7 @@ impl Reaper {
         type_: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ShowMessageBox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ShowMessageBox)
-            ),
+            None => crate::function_not_available(stringify!(ShowMessageBox)),
             Some(f) => f(msg, title, type_),
         }
     }
@@ -17122,10 +15042,7 @@ impl Reaper {
         ctx3Optional: ::std::os::raw::c_int,
     ) {
         match self.pointers.ShowPopupMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ShowPopupMenu)
-            ),
+            None => crate::function_not_available(stringify!(ShowPopupMenu)),
             Some(f) => f(
                 name,
                 x,
@@ -17139,10 +15056,7 @@ impl Reaper {
     }
     pub fn SLIDER2DB(&self, y: f64) -> f64 {
         match self.pointers.SLIDER2DB {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SLIDER2DB)
-            ),
+            None => crate::function_not_available(stringify!(SLIDER2DB)),
             Some(f) => f(y),
         }
     }
@@ -17151,28 +15065,19 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SnapToGrid(&self, project: *mut root::ReaProject, time_pos: f64) -> f64 {
         match self.pointers.SnapToGrid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SnapToGrid)
-            ),
+            None => crate::function_not_available(stringify!(SnapToGrid)),
             Some(f) => f(project, time_pos),
         }
     }
     pub fn SoloAllTracks(&self, solo: ::std::os::raw::c_int) {
         match self.pointers.SoloAllTracks {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SoloAllTracks)
-            ),
+            None => crate::function_not_available(stringify!(SoloAllTracks)),
             Some(f) => f(solo),
         }
     }
     pub fn Splash_GetWnd(&self) -> root::HWND {
         match self.pointers.Splash_GetWnd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Splash_GetWnd)
-            ),
+            None => crate::function_not_available(stringify!(Splash_GetWnd)),
             Some(f) => f(),
         }
     }
@@ -17185,10 +15090,7 @@ impl Reaper {
         position: f64,
     ) -> *mut root::MediaItem {
         match self.pointers.SplitMediaItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SplitMediaItem)
-            ),
+            None => crate::function_not_available(stringify!(SplitMediaItem)),
             Some(f) => f(item, position),
         }
     }
@@ -17200,10 +15102,7 @@ impl Reaper {
         preview: *mut root::preview_register_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.StopPreview {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(StopPreview)
-            ),
+            None => crate::function_not_available(stringify!(StopPreview)),
             Some(f) => f(preview),
         }
     }
@@ -17215,10 +15114,7 @@ impl Reaper {
         preview: *mut root::preview_register_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.StopTrackPreview {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(StopTrackPreview)
-            ),
+            None => crate::function_not_available(stringify!(StopTrackPreview)),
             Some(f) => f(preview),
         }
     }
@@ -17231,10 +15127,7 @@ impl Reaper {
         preview: *mut root::preview_register_t,
     ) -> ::std::os::raw::c_int {
         match self.pointers.StopTrackPreview2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(StopTrackPreview2)
-            ),
+            None => crate::function_not_available(stringify!(StopTrackPreview2)),
             Some(f) => f(proj, preview),
         }
     }
@@ -17243,10 +15136,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn stringToGuid(&self, str: *const ::std::os::raw::c_char, g: *mut root::GUID) {
         match self.pointers.stringToGuid {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(stringToGuid)
-            ),
+            None => crate::function_not_available(stringify!(stringToGuid)),
             Some(f) => f(str, g),
         }
     }
@@ -17258,10 +15148,7 @@ impl Reaper {
         msg3: ::std::os::raw::c_int,
     ) {
         match self.pointers.StuffMIDIMessage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(StuffMIDIMessage)
-            ),
+            None => crate::function_not_available(stringify!(StuffMIDIMessage)),
             Some(f) => f(mode, msg1, msg2, msg3),
         }
     }
@@ -17275,10 +15162,7 @@ impl Reaper {
         instantiate: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_AddByName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_AddByName)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_AddByName)),
             Some(f) => f(take, fxname, instantiate),
         }
     }
@@ -17294,10 +15178,7 @@ impl Reaper {
         is_move: bool,
     ) {
         match self.pointers.TakeFX_CopyToTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_CopyToTake)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_CopyToTake)),
             Some(f) => f(src_take, src_fx, dest_take, dest_fx, is_move),
         }
     }
@@ -17313,10 +15194,7 @@ impl Reaper {
         is_move: bool,
     ) {
         match self.pointers.TakeFX_CopyToTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_CopyToTrack)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_CopyToTrack)),
             Some(f) => f(src_take, src_fx, dest_track, dest_fx, is_move),
         }
     }
@@ -17329,10 +15207,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_Delete {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_Delete)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_Delete)),
             Some(f) => f(take, fx),
         }
     }
@@ -17346,10 +15221,7 @@ impl Reaper {
         param: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_EndParamEdit {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_EndParamEdit)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_EndParamEdit)),
             Some(f) => f(take, fx, param),
         }
     }
@@ -17366,10 +15238,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_FormatParamValue {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_FormatParamValue)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_FormatParamValue)),
             Some(f) => f(take, fx, param, val, bufOut, bufOut_sz),
         }
     }
@@ -17386,10 +15255,7 @@ impl Reaper {
         buf_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_FormatParamValueNormalized {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_FormatParamValueNormalized)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_FormatParamValueNormalized)),
             Some(f) => f(take, fx, param, value, buf, buf_sz),
         }
     }
@@ -17401,10 +15267,7 @@ impl Reaper {
         take: *mut root::MediaItem_Take,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_GetChainVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetChainVisible)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetChainVisible)),
             Some(f) => f(take),
         }
     }
@@ -17413,10 +15276,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TakeFX_GetCount(&self, take: *mut root::MediaItem_Take) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_GetCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetCount)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetCount)),
             Some(f) => f(take),
         }
     }
@@ -17429,10 +15289,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetEnabled {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetEnabled)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetEnabled)),
             Some(f) => f(take, fx),
         }
     }
@@ -17447,10 +15304,7 @@ impl Reaper {
         create: bool,
     ) -> *mut root::TrackEnvelope {
         match self.pointers.TakeFX_GetEnvelope {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetEnvelope)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetEnvelope)),
             Some(f) => f(take, fxindex, parameterindex, create),
         }
     }
@@ -17463,10 +15317,7 @@ impl Reaper {
         index: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.TakeFX_GetFloatingWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetFloatingWindow)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetFloatingWindow)),
             Some(f) => f(take, index),
         }
     }
@@ -17482,10 +15333,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetFormattedParamValue {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetFormattedParamValue)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetFormattedParamValue)),
             Some(f) => f(take, fx, param, bufOut, bufOut_sz),
         }
     }
@@ -17498,10 +15346,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> *mut root::GUID {
         match self.pointers.TakeFX_GetFXGUID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetFXGUID)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetFXGUID)),
             Some(f) => f(take, fx),
         }
     }
@@ -17516,10 +15361,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetFXName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetFXName)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetFXName)),
             Some(f) => f(take, fx, bufOut, bufOut_sz),
         }
     }
@@ -17534,10 +15376,7 @@ impl Reaper {
         outputPinsOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_GetIOSize {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetIOSize)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetIOSize)),
             Some(f) => f(take, fx, inputPinsOut, outputPinsOut),
         }
     }
@@ -17553,10 +15392,7 @@ impl Reaper {
         bufOutNeedBig_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetNamedConfigParm {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetNamedConfigParm)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetNamedConfigParm)),
             Some(f) => f(take, fx, parmname, bufOutNeedBig, bufOutNeedBig_sz),
         }
     }
@@ -17569,10 +15405,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_GetNumParams {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetNumParams)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetNumParams)),
             Some(f) => f(take, fx),
         }
     }
@@ -17585,10 +15418,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetOffline {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetOffline)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetOffline)),
             Some(f) => f(take, fx),
         }
     }
@@ -17601,10 +15431,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetOpen {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetOpen)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetOpen)),
             Some(f) => f(take, fx),
         }
     }
@@ -17620,10 +15447,7 @@ impl Reaper {
         maxvalOut: *mut f64,
     ) -> f64 {
         match self.pointers.TakeFX_GetParam {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetParam)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetParam)),
             Some(f) => f(take, fx, param, minvalOut, maxvalOut),
         }
     }
@@ -17641,10 +15465,7 @@ impl Reaper {
         istoggleOut: *mut bool,
     ) -> bool {
         match self.pointers.TakeFX_GetParameterStepSizes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetParameterStepSizes)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetParameterStepSizes)),
             Some(f) => f(
                 take,
                 fx,
@@ -17669,10 +15490,7 @@ impl Reaper {
         midvalOut: *mut f64,
     ) -> f64 {
         match self.pointers.TakeFX_GetParamEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetParamEx)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetParamEx)),
             Some(f) => f(take, fx, param, minvalOut, maxvalOut, midvalOut),
         }
     }
@@ -17686,10 +15504,7 @@ impl Reaper {
         ident_str: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_GetParamFromIdent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetParamFromIdent)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetParamFromIdent)),
             Some(f) => f(take, fx, ident_str),
         }
     }
@@ -17705,10 +15520,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetParamIdent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetParamIdent)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetParamIdent)),
             Some(f) => f(take, fx, param, bufOut, bufOut_sz),
         }
     }
@@ -17724,10 +15536,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetParamName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetParamName)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetParamName)),
             Some(f) => f(take, fx, param, bufOut, bufOut_sz),
         }
     }
@@ -17741,10 +15550,7 @@ impl Reaper {
         param: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.TakeFX_GetParamNormalized {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetParamNormalized)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetParamNormalized)),
             Some(f) => f(take, fx, param),
         }
     }
@@ -17760,10 +15566,7 @@ impl Reaper {
         high32Out: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_GetPinMappings {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetPinMappings)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetPinMappings)),
             Some(f) => f(take, fx, isoutput, pin, high32Out),
         }
     }
@@ -17778,10 +15581,7 @@ impl Reaper {
         presetnameOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_GetPreset {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetPreset)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetPreset)),
             Some(f) => f(take, fx, presetnameOut, presetnameOut_sz),
         }
     }
@@ -17795,10 +15595,7 @@ impl Reaper {
         numberOfPresetsOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TakeFX_GetPresetIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetPresetIndex)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetPresetIndex)),
             Some(f) => f(take, fx, numberOfPresetsOut),
         }
     }
@@ -17813,10 +15610,7 @@ impl Reaper {
         fnOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.TakeFX_GetUserPresetFilename {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_GetUserPresetFilename)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_GetUserPresetFilename)),
             Some(f) => f(take, fx, fnOut, fnOut_sz),
         }
     }
@@ -17830,10 +15624,7 @@ impl Reaper {
         presetmove: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_NavigatePresets {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_NavigatePresets)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_NavigatePresets)),
             Some(f) => f(take, fx, presetmove),
         }
     }
@@ -17847,10 +15638,7 @@ impl Reaper {
         enabled: bool,
     ) {
         match self.pointers.TakeFX_SetEnabled {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetEnabled)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetEnabled)),
             Some(f) => f(take, fx, enabled),
         }
     }
@@ -17865,10 +15653,7 @@ impl Reaper {
         value: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.TakeFX_SetNamedConfigParm {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetNamedConfigParm)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetNamedConfigParm)),
             Some(f) => f(take, fx, parmname, value),
         }
     }
@@ -17882,10 +15667,7 @@ impl Reaper {
         offline: bool,
     ) {
         match self.pointers.TakeFX_SetOffline {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetOffline)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetOffline)),
             Some(f) => f(take, fx, offline),
         }
     }
@@ -17899,10 +15681,7 @@ impl Reaper {
         open: bool,
     ) {
         match self.pointers.TakeFX_SetOpen {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetOpen)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetOpen)),
             Some(f) => f(take, fx, open),
         }
     }
@@ -17917,10 +15696,7 @@ impl Reaper {
         val: f64,
     ) -> bool {
         match self.pointers.TakeFX_SetParam {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetParam)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetParam)),
             Some(f) => f(take, fx, param, val),
         }
     }
@@ -17935,10 +15711,7 @@ impl Reaper {
         value: f64,
     ) -> bool {
         match self.pointers.TakeFX_SetParamNormalized {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetParamNormalized)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetParamNormalized)),
             Some(f) => f(take, fx, param, value),
         }
     }
@@ -17955,10 +15728,7 @@ impl Reaper {
         hi32bits: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_SetPinMappings {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetPinMappings)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetPinMappings)),
             Some(f) => f(take, fx, isoutput, pin, low32bits, hi32bits),
         }
     }
@@ -17972,10 +15742,7 @@ impl Reaper {
         presetname: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.TakeFX_SetPreset {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetPreset)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetPreset)),
             Some(f) => f(take, fx, presetname),
         }
     }
@@ -17989,10 +15756,7 @@ impl Reaper {
         idx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TakeFX_SetPresetByIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_SetPresetByIndex)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_SetPresetByIndex)),
             Some(f) => f(take, fx, idx),
         }
     }
@@ -18006,10 +15770,7 @@ impl Reaper {
         showFlag: ::std::os::raw::c_int,
     ) {
         match self.pointers.TakeFX_Show {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeFX_Show)
-            ),
+            None => crate::function_not_available(stringify!(TakeFX_Show)),
             Some(f) => f(take, index, showFlag),
         }
     }
@@ -18018,10 +15779,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TakeIsMIDI(&self, take: *mut root::MediaItem_Take) -> bool {
         match self.pointers.TakeIsMIDI {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TakeIsMIDI)
-            ),
+            None => crate::function_not_available(stringify!(TakeIsMIDI)),
             Some(f) => f(take),
         }
     }
@@ -18036,10 +15794,7 @@ impl Reaper {
         nameOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.ThemeLayout_GetLayout {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ThemeLayout_GetLayout)
-            ),
+            None => crate::function_not_available(stringify!(ThemeLayout_GetLayout)),
             Some(f) => f(section, idx, nameOut, nameOut_sz),
         }
     }
@@ -18056,10 +15811,7 @@ impl Reaper {
         maxValueOutOptional: *mut ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.ThemeLayout_GetParameter {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ThemeLayout_GetParameter)
-            ),
+            None => crate::function_not_available(stringify!(ThemeLayout_GetParameter)),
             Some(f) => f(
                 wp,
                 descOutOptional,
@@ -18072,10 +15824,7 @@ impl Reaper {
     }
     pub fn ThemeLayout_RefreshAll(&self) {
         match self.pointers.ThemeLayout_RefreshAll {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ThemeLayout_RefreshAll)
-            ),
+            None => crate::function_not_available(stringify!(ThemeLayout_RefreshAll)),
             Some(f) => f(),
         }
     }
@@ -18088,10 +15837,7 @@ impl Reaper {
         layout: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.ThemeLayout_SetLayout {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ThemeLayout_SetLayout)
-            ),
+            None => crate::function_not_available(stringify!(ThemeLayout_SetLayout)),
             Some(f) => f(section, layout),
         }
     }
@@ -18102,19 +15848,13 @@ impl Reaper {
         persist: bool,
     ) -> bool {
         match self.pointers.ThemeLayout_SetParameter {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ThemeLayout_SetParameter)
-            ),
+            None => crate::function_not_available(stringify!(ThemeLayout_SetParameter)),
             Some(f) => f(wp, value, persist),
         }
     }
     pub fn time_precise(&self) -> f64 {
         match self.pointers.time_precise {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(time_precise)
-            ),
+            None => crate::function_not_available(stringify!(time_precise)),
             Some(f) => f(),
         }
     }
@@ -18128,10 +15868,7 @@ impl Reaper {
         measuresInOptional: *const ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.TimeMap2_beatsToTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap2_beatsToTime)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap2_beatsToTime)),
             Some(f) => f(proj, tpos, measuresInOptional),
         }
     }
@@ -18144,10 +15881,7 @@ impl Reaper {
         time: f64,
     ) -> f64 {
         match self.pointers.TimeMap2_GetDividedBpmAtTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap2_GetDividedBpmAtTime)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap2_GetDividedBpmAtTime)),
             Some(f) => f(proj, time),
         }
     }
@@ -18156,10 +15890,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TimeMap2_GetNextChangeTime(&self, proj: *mut root::ReaProject, time: f64) -> f64 {
         match self.pointers.TimeMap2_GetNextChangeTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap2_GetNextChangeTime)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap2_GetNextChangeTime)),
             Some(f) => f(proj, time),
         }
     }
@@ -18168,10 +15899,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TimeMap2_QNToTime(&self, proj: *mut root::ReaProject, qn: f64) -> f64 {
         match self.pointers.TimeMap2_QNToTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap2_QNToTime)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap2_QNToTime)),
             Some(f) => f(proj, qn),
         }
     }
@@ -18188,10 +15916,7 @@ impl Reaper {
         cdenomOutOptional: *mut ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.TimeMap2_timeToBeats {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap2_timeToBeats)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap2_timeToBeats)),
             Some(f) => f(
                 proj,
                 tpos,
@@ -18207,10 +15932,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TimeMap2_timeToQN(&self, proj: *mut root::ReaProject, tpos: f64) -> f64 {
         match self.pointers.TimeMap2_timeToQN {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap2_timeToQN)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap2_timeToQN)),
             Some(f) => f(proj, tpos),
         }
     }
@@ -18223,19 +15945,13 @@ impl Reaper {
         dropFrameOut: *mut bool,
     ) -> f64 {
         match self.pointers.TimeMap_curFrameRate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_curFrameRate)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_curFrameRate)),
             Some(f) => f(proj, dropFrameOut),
         }
     }
     pub fn TimeMap_GetDividedBpmAtTime(&self, time: f64) -> f64 {
         match self.pointers.TimeMap_GetDividedBpmAtTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_GetDividedBpmAtTime)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_GetDividedBpmAtTime)),
             Some(f) => f(time),
         }
     }
@@ -18253,10 +15969,7 @@ impl Reaper {
         tempoOut: *mut f64,
     ) -> f64 {
         match self.pointers.TimeMap_GetMeasureInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_GetMeasureInfo)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_GetMeasureInfo)),
             Some(f) => f(
                 proj,
                 measure,
@@ -18279,10 +15992,7 @@ impl Reaper {
         pattern_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TimeMap_GetMetronomePattern {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_GetMetronomePattern)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_GetMetronomePattern)),
             Some(f) => f(proj, time, pattern, pattern_sz),
         }
     }
@@ -18298,10 +16008,7 @@ impl Reaper {
         tempoOut: *mut f64,
     ) {
         match self.pointers.TimeMap_GetTimeSigAtTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_GetTimeSigAtTime)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_GetTimeSigAtTime)),
             Some(f) => f(proj, time, timesig_numOut, timesig_denomOut, tempoOut),
         }
     }
@@ -18316,19 +16023,13 @@ impl Reaper {
         qnMeasureEndOutOptional: *mut f64,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TimeMap_QNToMeasures {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_QNToMeasures)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_QNToMeasures)),
             Some(f) => f(proj, qn, qnMeasureStartOutOptional, qnMeasureEndOutOptional),
         }
     }
     pub fn TimeMap_QNToTime(&self, qn: f64) -> f64 {
         match self.pointers.TimeMap_QNToTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_QNToTime)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_QNToTime)),
             Some(f) => f(qn),
         }
     }
@@ -18337,19 +16038,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TimeMap_QNToTime_abs(&self, proj: *mut root::ReaProject, qn: f64) -> f64 {
         match self.pointers.TimeMap_QNToTime_abs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_QNToTime_abs)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_QNToTime_abs)),
             Some(f) => f(proj, qn),
         }
     }
     pub fn TimeMap_timeToQN(&self, tpos: f64) -> f64 {
         match self.pointers.TimeMap_timeToQN {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_timeToQN)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_timeToQN)),
             Some(f) => f(tpos),
         }
     }
@@ -18358,10 +16053,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TimeMap_timeToQN_abs(&self, proj: *mut root::ReaProject, tpos: f64) -> f64 {
         match self.pointers.TimeMap_timeToQN_abs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TimeMap_timeToQN_abs)
-            ),
+            None => crate::function_not_available(stringify!(TimeMap_timeToQN_abs)),
             Some(f) => f(proj, tpos),
         }
     }
@@ -18374,10 +16066,7 @@ impl Reaper {
         send_idx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.ToggleTrackSendUIMute {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ToggleTrackSendUIMute)
-            ),
+            None => crate::function_not_available(stringify!(ToggleTrackSendUIMute)),
             Some(f) => f(track, send_idx),
         }
     }
@@ -18391,10 +16080,7 @@ impl Reaper {
         clear: bool,
     ) -> f64 {
         match self.pointers.Track_GetPeakHoldDB {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Track_GetPeakHoldDB)
-            ),
+            None => crate::function_not_available(stringify!(Track_GetPeakHoldDB)),
             Some(f) => f(track, channel, clear),
         }
     }
@@ -18407,10 +16093,7 @@ impl Reaper {
         channel: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.Track_GetPeakInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Track_GetPeakInfo)
-            ),
+            None => crate::function_not_available(stringify!(Track_GetPeakInfo)),
             Some(f) => f(track, channel),
         }
     }
@@ -18425,10 +16108,7 @@ impl Reaper {
         topmost: bool,
     ) {
         match self.pointers.TrackCtl_SetToolTip {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackCtl_SetToolTip)
-            ),
+            None => crate::function_not_available(stringify!(TrackCtl_SetToolTip)),
             Some(f) => f(fmt, xpos, ypos, topmost),
         }
     }
@@ -18443,10 +16123,7 @@ impl Reaper {
         instantiate: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_AddByName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_AddByName)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_AddByName)),
             Some(f) => f(track, fxname, recFX, instantiate),
         }
     }
@@ -18462,10 +16139,7 @@ impl Reaper {
         is_move: bool,
     ) {
         match self.pointers.TrackFX_CopyToTake {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_CopyToTake)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_CopyToTake)),
             Some(f) => f(src_track, src_fx, dest_take, dest_fx, is_move),
         }
     }
@@ -18481,10 +16155,7 @@ impl Reaper {
         is_move: bool,
     ) {
         match self.pointers.TrackFX_CopyToTrack {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_CopyToTrack)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_CopyToTrack)),
             Some(f) => f(src_track, src_fx, dest_track, dest_fx, is_move),
         }
     }
@@ -18497,10 +16168,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_Delete {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_Delete)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_Delete)),
             Some(f) => f(track, fx),
         }
     }
@@ -18514,10 +16182,7 @@ impl Reaper {
         param: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_EndParamEdit {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_EndParamEdit)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_EndParamEdit)),
             Some(f) => f(track, fx, param),
         }
     }
@@ -18534,10 +16199,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_FormatParamValue {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_FormatParamValue)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_FormatParamValue)),
             Some(f) => f(track, fx, param, val, bufOut, bufOut_sz),
         }
     }
@@ -18554,10 +16216,7 @@ impl Reaper {
         buf_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_FormatParamValueNormalized {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_FormatParamValueNormalized)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_FormatParamValueNormalized)),
             Some(f) => f(track, fx, param, value, buf, buf_sz),
         }
     }
@@ -18571,10 +16230,7 @@ impl Reaper {
         instantiate: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetByName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetByName)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetByName)),
             Some(f) => f(track, fxname, instantiate),
         }
     }
@@ -18586,10 +16242,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetChainVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetChainVisible)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetChainVisible)),
             Some(f) => f(track),
         }
     }
@@ -18598,10 +16251,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TrackFX_GetCount(&self, track: *mut root::MediaTrack) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetCount)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetCount)),
             Some(f) => f(track),
         }
     }
@@ -18614,10 +16264,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetEnabled {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetEnabled)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetEnabled)),
             Some(f) => f(track, fx),
         }
     }
@@ -18630,10 +16277,7 @@ impl Reaper {
         instantiate: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetEQ {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetEQ)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetEQ)),
             Some(f) => f(track, instantiate),
         }
     }
@@ -18648,10 +16292,7 @@ impl Reaper {
         bandidx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetEQBandEnabled {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetEQBandEnabled)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetEQBandEnabled)),
             Some(f) => f(track, fxidx, bandtype, bandidx),
         }
     }
@@ -18669,10 +16310,7 @@ impl Reaper {
         normvalOut: *mut f64,
     ) -> bool {
         match self.pointers.TrackFX_GetEQParam {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetEQParam)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetEQParam)),
             Some(f) => f(
                 track,
                 fxidx,
@@ -18693,10 +16331,7 @@ impl Reaper {
         index: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.TrackFX_GetFloatingWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetFloatingWindow)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetFloatingWindow)),
             Some(f) => f(track, index),
         }
     }
@@ -18712,10 +16347,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetFormattedParamValue {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetFormattedParamValue)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetFormattedParamValue)),
             Some(f) => f(track, fx, param, bufOut, bufOut_sz),
         }
     }
@@ -18728,10 +16360,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> *mut root::GUID {
         match self.pointers.TrackFX_GetFXGUID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetFXGUID)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetFXGUID)),
             Some(f) => f(track, fx),
         }
     }
@@ -18746,10 +16375,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetFXName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetFXName)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetFXName)),
             Some(f) => f(track, fx, bufOut, bufOut_sz),
         }
     }
@@ -18761,10 +16387,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetInstrument {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetInstrument)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetInstrument)),
             Some(f) => f(track),
         }
     }
@@ -18779,10 +16402,7 @@ impl Reaper {
         outputPinsOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetIOSize {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetIOSize)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetIOSize)),
             Some(f) => f(track, fx, inputPinsOut, outputPinsOut),
         }
     }
@@ -18798,10 +16418,7 @@ impl Reaper {
         bufOutNeedBig_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetNamedConfigParm {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetNamedConfigParm)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetNamedConfigParm)),
             Some(f) => f(track, fx, parmname, bufOutNeedBig, bufOutNeedBig_sz),
         }
     }
@@ -18814,10 +16431,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetNumParams {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetNumParams)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetNumParams)),
             Some(f) => f(track, fx),
         }
     }
@@ -18830,10 +16444,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetOffline {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetOffline)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetOffline)),
             Some(f) => f(track, fx),
         }
     }
@@ -18846,10 +16457,7 @@ impl Reaper {
         fx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetOpen {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetOpen)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetOpen)),
             Some(f) => f(track, fx),
         }
     }
@@ -18865,10 +16473,7 @@ impl Reaper {
         maxvalOut: *mut f64,
     ) -> f64 {
         match self.pointers.TrackFX_GetParam {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetParam)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetParam)),
             Some(f) => f(track, fx, param, minvalOut, maxvalOut),
         }
     }
@@ -18886,10 +16491,7 @@ impl Reaper {
         istoggleOut: *mut bool,
     ) -> bool {
         match self.pointers.TrackFX_GetParameterStepSizes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetParameterStepSizes)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetParameterStepSizes)),
             Some(f) => f(
                 track,
                 fx,
@@ -18914,10 +16516,7 @@ impl Reaper {
         midvalOut: *mut f64,
     ) -> f64 {
         match self.pointers.TrackFX_GetParamEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetParamEx)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetParamEx)),
             Some(f) => f(track, fx, param, minvalOut, maxvalOut, midvalOut),
         }
     }
@@ -18931,10 +16530,7 @@ impl Reaper {
         ident_str: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetParamFromIdent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetParamFromIdent)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetParamFromIdent)),
             Some(f) => f(track, fx, ident_str),
         }
     }
@@ -18950,10 +16546,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetParamIdent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetParamIdent)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetParamIdent)),
             Some(f) => f(track, fx, param, bufOut, bufOut_sz),
         }
     }
@@ -18969,10 +16562,7 @@ impl Reaper {
         bufOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetParamName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetParamName)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetParamName)),
             Some(f) => f(track, fx, param, bufOut, bufOut_sz),
         }
     }
@@ -18986,10 +16576,7 @@ impl Reaper {
         param: ::std::os::raw::c_int,
     ) -> f64 {
         match self.pointers.TrackFX_GetParamNormalized {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetParamNormalized)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetParamNormalized)),
             Some(f) => f(track, fx, param),
         }
     }
@@ -19005,10 +16592,7 @@ impl Reaper {
         high32Out: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetPinMappings {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetPinMappings)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetPinMappings)),
             Some(f) => f(tr, fx, isoutput, pin, high32Out),
         }
     }
@@ -19023,10 +16607,7 @@ impl Reaper {
         presetnameOut_sz: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_GetPreset {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetPreset)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetPreset)),
             Some(f) => f(track, fx, presetnameOut, presetnameOut_sz),
         }
     }
@@ -19040,10 +16621,7 @@ impl Reaper {
         numberOfPresetsOut: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetPresetIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetPresetIndex)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetPresetIndex)),
             Some(f) => f(track, fx, numberOfPresetsOut),
         }
     }
@@ -19055,10 +16633,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetRecChainVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetRecChainVisible)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetRecChainVisible)),
             Some(f) => f(track),
         }
     }
@@ -19070,10 +16645,7 @@ impl Reaper {
         track: *mut root::MediaTrack,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackFX_GetRecCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetRecCount)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetRecCount)),
             Some(f) => f(track),
         }
     }
@@ -19088,10 +16660,7 @@ impl Reaper {
         fnOut_sz: ::std::os::raw::c_int,
     ) {
         match self.pointers.TrackFX_GetUserPresetFilename {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_GetUserPresetFilename)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_GetUserPresetFilename)),
             Some(f) => f(track, fx, fnOut, fnOut_sz),
         }
     }
@@ -19105,10 +16674,7 @@ impl Reaper {
         presetmove: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_NavigatePresets {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_NavigatePresets)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_NavigatePresets)),
             Some(f) => f(track, fx, presetmove),
         }
     }
@@ -19122,10 +16688,7 @@ impl Reaper {
         enabled: bool,
     ) {
         match self.pointers.TrackFX_SetEnabled {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetEnabled)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetEnabled)),
             Some(f) => f(track, fx, enabled),
         }
     }
@@ -19141,10 +16704,7 @@ impl Reaper {
         enable: bool,
     ) -> bool {
         match self.pointers.TrackFX_SetEQBandEnabled {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetEQBandEnabled)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetEQBandEnabled)),
             Some(f) => f(track, fxidx, bandtype, bandidx, enable),
         }
     }
@@ -19162,10 +16722,7 @@ impl Reaper {
         isnorm: bool,
     ) -> bool {
         match self.pointers.TrackFX_SetEQParam {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetEQParam)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetEQParam)),
             Some(f) => f(track, fxidx, bandtype, bandidx, paramtype, val, isnorm),
         }
     }
@@ -19180,10 +16737,7 @@ impl Reaper {
         value: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.TrackFX_SetNamedConfigParm {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetNamedConfigParm)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetNamedConfigParm)),
             Some(f) => f(track, fx, parmname, value),
         }
     }
@@ -19197,10 +16751,7 @@ impl Reaper {
         offline: bool,
     ) {
         match self.pointers.TrackFX_SetOffline {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetOffline)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetOffline)),
             Some(f) => f(track, fx, offline),
         }
     }
@@ -19214,10 +16765,7 @@ impl Reaper {
         open: bool,
     ) {
         match self.pointers.TrackFX_SetOpen {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetOpen)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetOpen)),
             Some(f) => f(track, fx, open),
         }
     }
@@ -19232,10 +16780,7 @@ impl Reaper {
         val: f64,
     ) -> bool {
         match self.pointers.TrackFX_SetParam {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetParam)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetParam)),
             Some(f) => f(track, fx, param, val),
         }
     }
@@ -19250,10 +16795,7 @@ impl Reaper {
         value: f64,
     ) -> bool {
         match self.pointers.TrackFX_SetParamNormalized {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetParamNormalized)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetParamNormalized)),
             Some(f) => f(track, fx, param, value),
         }
     }
@@ -19270,10 +16812,7 @@ impl Reaper {
         hi32bits: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_SetPinMappings {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetPinMappings)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetPinMappings)),
             Some(f) => f(tr, fx, isoutput, pin, low32bits, hi32bits),
         }
     }
@@ -19287,10 +16826,7 @@ impl Reaper {
         presetname: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.TrackFX_SetPreset {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetPreset)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetPreset)),
             Some(f) => f(track, fx, presetname),
         }
     }
@@ -19304,10 +16840,7 @@ impl Reaper {
         idx: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.TrackFX_SetPresetByIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_SetPresetByIndex)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_SetPresetByIndex)),
             Some(f) => f(track, fx, idx),
         }
     }
@@ -19321,37 +16854,25 @@ impl Reaper {
         showFlag: ::std::os::raw::c_int,
     ) {
         match self.pointers.TrackFX_Show {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackFX_Show)
-            ),
+            None => crate::function_not_available(stringify!(TrackFX_Show)),
             Some(f) => f(track, index, showFlag),
         }
     }
     pub fn TrackList_AdjustWindows(&self, isMinor: bool) {
         match self.pointers.TrackList_AdjustWindows {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackList_AdjustWindows)
-            ),
+            None => crate::function_not_available(stringify!(TrackList_AdjustWindows)),
             Some(f) => f(isMinor),
         }
     }
     pub fn TrackList_UpdateAllExternalSurfaces(&self) {
         match self.pointers.TrackList_UpdateAllExternalSurfaces {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackList_UpdateAllExternalSurfaces)
-            ),
+            None => crate::function_not_available(stringify!(TrackList_UpdateAllExternalSurfaces)),
             Some(f) => f(),
         }
     }
     pub fn Undo_BeginBlock(&self) {
         match self.pointers.Undo_BeginBlock {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_BeginBlock)
-            ),
+            None => crate::function_not_available(stringify!(Undo_BeginBlock)),
             Some(f) => f(),
         }
     }
@@ -19360,10 +16881,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Undo_BeginBlock2(&self, proj: *mut root::ReaProject) {
         match self.pointers.Undo_BeginBlock2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_BeginBlock2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_BeginBlock2)),
             Some(f) => f(proj),
         }
     }
@@ -19375,10 +16893,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.Undo_CanRedo2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_CanRedo2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_CanRedo2)),
             Some(f) => f(proj),
         }
     }
@@ -19390,10 +16905,7 @@ impl Reaper {
         proj: *mut root::ReaProject,
     ) -> *const ::std::os::raw::c_char {
         match self.pointers.Undo_CanUndo2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_CanUndo2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_CanUndo2)),
             Some(f) => f(proj),
         }
     }
@@ -19402,10 +16914,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Undo_DoRedo2(&self, proj: *mut root::ReaProject) -> ::std::os::raw::c_int {
         match self.pointers.Undo_DoRedo2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_DoRedo2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_DoRedo2)),
             Some(f) => f(proj),
         }
     }
@@ -19414,10 +16923,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Undo_DoUndo2(&self, proj: *mut root::ReaProject) -> ::std::os::raw::c_int {
         match self.pointers.Undo_DoUndo2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_DoUndo2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_DoUndo2)),
             Some(f) => f(proj),
         }
     }
@@ -19430,10 +16936,7 @@ impl Reaper {
         extraflags: ::std::os::raw::c_int,
     ) {
         match self.pointers.Undo_EndBlock {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_EndBlock)
-            ),
+            None => crate::function_not_available(stringify!(Undo_EndBlock)),
             Some(f) => f(descchange, extraflags),
         }
     }
@@ -19447,10 +16950,7 @@ impl Reaper {
         extraflags: ::std::os::raw::c_int,
     ) {
         match self.pointers.Undo_EndBlock2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_EndBlock2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_EndBlock2)),
             Some(f) => f(proj, descchange, extraflags),
         }
     }
@@ -19459,10 +16959,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Undo_OnStateChange(&self, descchange: *const ::std::os::raw::c_char) {
         match self.pointers.Undo_OnStateChange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_OnStateChange)
-            ),
+            None => crate::function_not_available(stringify!(Undo_OnStateChange)),
             Some(f) => f(descchange),
         }
     }
@@ -19475,10 +16972,7 @@ impl Reaper {
         descchange: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.Undo_OnStateChange2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_OnStateChange2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_OnStateChange2)),
             Some(f) => f(proj, descchange),
         }
     }
@@ -19492,10 +16986,7 @@ impl Reaper {
         item: *mut root::MediaItem,
     ) {
         match self.pointers.Undo_OnStateChange_Item {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_OnStateChange_Item)
-            ),
+            None => crate::function_not_available(stringify!(Undo_OnStateChange_Item)),
             Some(f) => f(proj, name, item),
         }
     }
@@ -19509,10 +17000,7 @@ impl Reaper {
         trackparm: ::std::os::raw::c_int,
     ) {
         match self.pointers.Undo_OnStateChangeEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_OnStateChangeEx)
-            ),
+            None => crate::function_not_available(stringify!(Undo_OnStateChangeEx)),
             Some(f) => f(descchange, whichStates, trackparm),
         }
     }
@@ -19527,10 +17015,7 @@ impl Reaper {
         trackparm: ::std::os::raw::c_int,
     ) {
         match self.pointers.Undo_OnStateChangeEx2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Undo_OnStateChangeEx2)
-            ),
+            None => crate::function_not_available(stringify!(Undo_OnStateChangeEx2)),
             Some(f) => f(proj, descchange, whichStates, trackparm),
         }
     }
@@ -19540,19 +17025,13 @@ impl Reaper {
         writeamt: ::std::os::raw::c_int,
     ) {
         match self.pointers.update_disk_counters {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(update_disk_counters)
-            ),
+            None => crate::function_not_available(stringify!(update_disk_counters)),
             Some(f) => f(readamt, writeamt),
         }
     }
     pub fn UpdateArrange(&self) {
         match self.pointers.UpdateArrange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(UpdateArrange)
-            ),
+            None => crate::function_not_available(stringify!(UpdateArrange)),
             Some(f) => f(),
         }
     }
@@ -19561,10 +17040,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn UpdateItemInProject(&self, item: *mut root::MediaItem) {
         match self.pointers.UpdateItemInProject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(UpdateItemInProject)
-            ),
+            None => crate::function_not_available(stringify!(UpdateItemInProject)),
             Some(f) => f(item),
         }
     }
@@ -19573,19 +17049,13 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn UpdateItemLanes(&self, proj: *mut root::ReaProject) -> bool {
         match self.pointers.UpdateItemLanes {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(UpdateItemLanes)
-            ),
+            None => crate::function_not_available(stringify!(UpdateItemLanes)),
             Some(f) => f(proj),
         }
     }
     pub fn UpdateTimeline(&self) {
         match self.pointers.UpdateTimeline {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(UpdateTimeline)
-            ),
+            None => crate::function_not_available(stringify!(UpdateTimeline)),
             Some(f) => f(),
         }
     }
@@ -19598,10 +17068,7 @@ impl Reaper {
         ctypename: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.ValidatePtr {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ValidatePtr)
-            ),
+            None => crate::function_not_available(stringify!(ValidatePtr)),
             Some(f) => f(pointer, ctypename),
         }
     }
@@ -19615,10 +17082,7 @@ impl Reaper {
         ctypename: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.ValidatePtr2 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ValidatePtr2)
-            ),
+            None => crate::function_not_available(stringify!(ValidatePtr2)),
             Some(f) => f(proj, pointer, ctypename),
         }
     }
@@ -19631,10 +17095,7 @@ impl Reaper {
         pageByName: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.ViewPrefs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ViewPrefs)
-            ),
+            None => crate::function_not_available(stringify!(ViewPrefs)),
             Some(f) => f(page, pageByName),
         }
     }
@@ -19657,10 +17118,7 @@ impl Reaper {
         mode: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.WDL_VirtualWnd_ScaledBlitBG {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WDL_VirtualWnd_ScaledBlitBG)
-            ),
+            None => crate::function_not_available(stringify!(WDL_VirtualWnd_ScaledBlitBG)),
             Some(f) => f(
                 dest, src, destx, desty, destw, desth, clipx, clipy, clipw, cliph, alpha, mode,
             ),
@@ -19668,19 +17126,13 @@ impl Reaper {
     }
     pub fn GetMidiInput(&self, idx: ::std::os::raw::c_int) -> *mut root::midi_Input {
         match self.pointers.GetMidiInput {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMidiInput)
-            ),
+            None => crate::function_not_available(stringify!(GetMidiInput)),
             Some(f) => f(idx),
         }
     }
     pub fn GetMidiOutput(&self, idx: ::std::os::raw::c_int) -> *mut root::midi_Output {
         match self.pointers.GetMidiOutput {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMidiOutput)
-            ),
+            None => crate::function_not_available(stringify!(GetMidiOutput)),
             Some(f) => f(idx),
         }
     }
@@ -19694,10 +17146,7 @@ impl Reaper {
         flag: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.fxDoReaperPresetAction {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(fxDoReaperPresetAction)
-            ),
+            None => crate::function_not_available(stringify!(fxDoReaperPresetAction)),
             Some(f) => f(fx, name, flag),
         }
     }
@@ -19715,10 +17164,7 @@ impl Reaper {
         extra_flags: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.AddCustomMenuOrToolbarItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddCustomMenuOrToolbarItem)
-            ),
+            None => crate::function_not_available(stringify!(AddCustomMenuOrToolbarItem)),
             Some(f) => f(
                 menuname,
                 pos,
@@ -19740,10 +17186,7 @@ impl Reaper {
         extra_flags: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DeleteCustomMenuOrToolbarItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteCustomMenuOrToolbarItem)
-            ),
+            None => crate::function_not_available(stringify!(DeleteCustomMenuOrToolbarItem)),
             Some(f) => f(menuname, pos, extra_flags),
         }
     }
@@ -19760,10 +17203,7 @@ impl Reaper {
         iconFnOutOptional: *mut *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.GetCustomMenuOrToolbarItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCustomMenuOrToolbarItem)
-            ),
+            None => crate::function_not_available(stringify!(GetCustomMenuOrToolbarItem)),
             Some(f) => f(
                 menuname,
                 pos,
@@ -19788,10 +17228,7 @@ impl Reaper {
         sf: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.AdvancePlaybackPosition {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AdvancePlaybackPosition)
-            ),
+            None => crate::function_not_available(stringify!(AdvancePlaybackPosition)),
             Some(f) => f(__proj, opos, npos, loopcnt, srate, max_spls, sf),
         }
     }
@@ -19804,10 +17241,7 @@ impl Reaper {
         something: *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_longlong {
         match self.pointers.GetPlayLoopCnt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPlayLoopCnt)
-            ),
+            None => crate::function_not_available(stringify!(GetPlayLoopCnt)),
             Some(f) => f(__proj, something),
         }
     }
@@ -19816,10 +17250,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn InitializeCoolSB(&self, hwnd: root::HWND) -> root::BOOL {
         match self.pointers.InitializeCoolSB {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InitializeCoolSB)
-            ),
+            None => crate::function_not_available(stringify!(InitializeCoolSB)),
             Some(f) => f(hwnd),
         }
     }
@@ -19828,10 +17259,7 @@ impl Reaper {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn UninitializeCoolSB(&self, hwnd: root::HWND) -> root::HRESULT {
         match self.pointers.UninitializeCoolSB {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(UninitializeCoolSB)
-            ),
+            None => crate::function_not_available(stringify!(UninitializeCoolSB)),
             Some(f) => f(hwnd),
         }
     }
@@ -19845,10 +17273,7 @@ impl Reaper {
         size: root::UINT,
     ) -> root::BOOL {
         match self.pointers.CoolSB_SetMinThumbSize {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_SetMinThumbSize)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_SetMinThumbSize)),
             Some(f) => f(hwnd, wBar, size),
         }
     }
@@ -19862,10 +17287,7 @@ impl Reaper {
         lpsi: root::LPSCROLLINFO,
     ) -> root::BOOL {
         match self.pointers.CoolSB_GetScrollInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_GetScrollInfo)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_GetScrollInfo)),
             Some(f) => f(hwnd, fnBar, lpsi),
         }
     }
@@ -19880,10 +17302,7 @@ impl Reaper {
         fRedraw: root::BOOL,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CoolSB_SetScrollInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_SetScrollInfo)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_SetScrollInfo)),
             Some(f) => f(hwnd, fnBar, lpsi, fRedraw),
         }
     }
@@ -19898,10 +17317,7 @@ impl Reaper {
         fRedraw: root::BOOL,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CoolSB_SetScrollPos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_SetScrollPos)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_SetScrollPos)),
             Some(f) => f(hwnd, nBar, nPos, fRedraw),
         }
     }
@@ -19917,10 +17333,7 @@ impl Reaper {
         fRedraw: root::BOOL,
     ) -> ::std::os::raw::c_int {
         match self.pointers.CoolSB_SetScrollRange {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_SetScrollRange)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_SetScrollRange)),
             Some(f) => f(hwnd, nBar, nMinPos, nMaxPos, fRedraw),
         }
     }
@@ -19934,10 +17347,7 @@ impl Reaper {
         fShow: root::BOOL,
     ) -> root::BOOL {
         match self.pointers.CoolSB_ShowScrollBar {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_ShowScrollBar)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_ShowScrollBar)),
             Some(f) => f(hwnd, wBar, fShow),
         }
     }
@@ -19950,10 +17360,7 @@ impl Reaper {
         active: root::BOOL,
     ) -> root::BOOL {
         match self.pointers.CoolSB_SetResizingThumb {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_SetResizingThumb)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_SetResizingThumb)),
             Some(f) => f(hwnd, active),
         }
     }
@@ -19966,10 +17373,7 @@ impl Reaper {
         idx: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.CoolSB_SetThemeIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CoolSB_SetThemeIndex)
-            ),
+            None => crate::function_not_available(stringify!(CoolSB_SetThemeIndex)),
             Some(f) => f(hwnd, idx),
         }
     }
@@ -0,0 +1,72 @@
+use crate::Reaper;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+
+type PluginRegisterFn = unsafe extern "C" fn(name: *const c_char, infostruct: *mut c_void) -> c_int;
+
+impl Reaper {
+    /// Registers something via `plugin_register()` and returns a handle which unregisters it again
+    /// when dropped.
+    ///
+    /// This is meant as escape hatch for registration keys which aren't supported by the
+    /// medium-level API yet. If the registration shouldn't be undone automatically, pass the handle
+    /// to [`std::mem::forget()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `plugin_register()` has not been loaded.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if `info` doesn't point to what REAPER expects for the given key or if it
+    /// dangles while the registration is active. So you must ensure that the registered thing
+    /// lives at least as long as the returned handle and has a stable address in memory.
+    pub unsafe fn register_raw(&self, key: &CStr, info: *mut c_void) -> RegistrationHandle {
+        let plugin_register = self
+            .pointers
+            .plugin_register
+            .unwrap_or_else(|| crate::function_not_available("plugin_register"));
+        let result = plugin_register(key.as_ptr(), info);
+        let mut unregister_key = b"-".to_vec();
+        unregister_key.extend_from_slice(key.to_bytes());
+        RegistrationHandle {
+            plugin_register,
+            unregister_key: CString::new(unregister_key).expect("key already was a C string"),
+            info,
+            result,
+        }
+    }
+}
+
+/// Represents a registration done via [`Reaper::register_raw()`].
+///
+/// The registration is undone (by passing the key prefixed with `-`) when this handle is dropped.
+#[derive(Debug)]
+pub struct RegistrationHandle {
+    plugin_register: PluginRegisterFn,
+    unregister_key: CString,
+    info: *mut c_void,
+    result: c_int,
+}
+
+impl RegistrationHandle {
+    /// Returns the registration key (without `-` prefix).
+    pub fn key(&self) -> &CStr {
+        CStr::from_bytes_with_nul(&self.unregister_key.as_bytes_with_nul()[1..])
+            .expect("key is a C string")
+    }
+
+    /// Returns what `plugin_register()` returned when registering.
+    ///
+    /// The meaning depends on the key. For most keys, 0 means that the registration failed.
+    pub fn result(&self) -> c_int {
+        self.result
+    }
+}
+
+impl Drop for RegistrationHandle {
+    fn drop(&mut self) {
+        unsafe {
+            (self.plugin_register)(self.unregister_key.as_ptr(), self.info);
+        }
+    }
+}
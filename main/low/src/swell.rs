@@ -2091,10 +2091,7 @@ impl Swell {
         l: ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_char {
         match self.pointers.lstrcpyn {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(lstrcpyn)
-            ),
+            None => crate::function_not_available(stringify!(lstrcpyn)),
             Some(f) => f(dest, src, l),
         }
     }
@@ -2106,30 +2103,21 @@ impl Swell {
         arg3: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MulDiv {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MulDiv)
-            ),
+            None => crate::function_not_available(stringify!(MulDiv)),
             Some(f) => f(arg1, arg2, arg3),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn Sleep(&self, ms: ::std::os::raw::c_int) {
         match self.pointers.Sleep {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Sleep)
-            ),
+            None => crate::function_not_available(stringify!(Sleep)),
             Some(f) => f(ms),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetTickCount(&self) -> root::DWORD {
         match self.pointers.GetTickCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTickCount)
-            ),
+            None => crate::function_not_available(stringify!(GetTickCount)),
             Some(f) => f(),
         }
     }
@@ -2145,10 +2133,7 @@ impl Swell {
         lpLastWriteTime: *mut root::FILETIME,
     ) -> root::BOOL {
         match self.pointers.GetFileTime {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetFileTime)
-            ),
+            None => crate::function_not_available(stringify!(GetFileTime)),
             Some(f) => f(filedes, lpCreationTime, lpLastAccessTime, lpLastWriteTime),
         }
     }
@@ -2164,10 +2149,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> root::BOOL {
         match self.pointers.WritePrivateProfileString {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WritePrivateProfileString)
-            ),
+            None => crate::function_not_available(stringify!(WritePrivateProfileString)),
             Some(f) => f(appname, keyname, val, fn_),
         }
     }
@@ -2185,10 +2167,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> root::DWORD {
         match self.pointers.GetPrivateProfileString {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPrivateProfileString)
-            ),
+            None => crate::function_not_available(stringify!(GetPrivateProfileString)),
             Some(f) => f(appname, keyname, def, ret, retsize, fn_),
         }
     }
@@ -2204,10 +2183,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetPrivateProfileInt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPrivateProfileInt)
-            ),
+            None => crate::function_not_available(stringify!(GetPrivateProfileInt)),
             Some(f) => f(appname, keyname, def, fn_),
         }
     }
@@ -2224,10 +2200,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> root::BOOL {
         match self.pointers.GetPrivateProfileStruct {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPrivateProfileStruct)
-            ),
+            None => crate::function_not_available(stringify!(GetPrivateProfileStruct)),
             Some(f) => f(appname, keyname, buf, bufsz, fn_),
         }
     }
@@ -2244,10 +2217,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> root::BOOL {
         match self.pointers.WritePrivateProfileStruct {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WritePrivateProfileStruct)
-            ),
+            None => crate::function_not_available(stringify!(WritePrivateProfileStruct)),
             Some(f) => f(appname, keyname, buf, bufsz, fn_),
         }
     }
@@ -2262,10 +2232,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> root::BOOL {
         match self.pointers.WritePrivateProfileSection {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WritePrivateProfileSection)
-            ),
+            None => crate::function_not_available(stringify!(WritePrivateProfileSection)),
             Some(f) => f(appname, strings, fn_),
         }
     }
@@ -2281,10 +2248,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> root::DWORD {
         match self.pointers.GetPrivateProfileSection {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetPrivateProfileSection)
-            ),
+            None => crate::function_not_available(stringify!(GetPrivateProfileSection)),
             Some(f) => f(appname, strout, strout_len, fn_),
         }
     }
@@ -2299,10 +2263,7 @@ impl Swell {
         nSize: root::DWORD,
     ) -> root::DWORD {
         match self.pointers.GetModuleFileName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetModuleFileName)
-            ),
+            None => crate::function_not_available(stringify!(GetModuleFileName)),
             Some(f) => f(hInst, fn_, nSize),
         }
     }
@@ -2312,10 +2273,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_PtInRect(&self, r: *const root::RECT, p: root::POINT) -> root::BOOL {
         match self.pointers.SWELL_PtInRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_PtInRect)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_PtInRect)),
             Some(f) => f(r, p),
         }
     }
@@ -2333,10 +2291,7 @@ impl Swell {
         blah: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.ShellExecute {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ShellExecute)
-            ),
+            None => crate::function_not_available(stringify!(ShellExecute)),
             Some(f) => f(hwndDlg, action, content1, content2, content3, blah),
         }
     }
@@ -2352,10 +2307,7 @@ impl Swell {
         type_: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.MessageBox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MessageBox)
-            ),
+            None => crate::function_not_available(stringify!(MessageBox)),
             Some(f) => f(hwndParent, text, caption, type_),
         }
     }
@@ -2372,10 +2324,7 @@ impl Swell {
         extlist: *const ::std::os::raw::c_char,
     ) -> *mut ::std::os::raw::c_char {
         match self.pointers.BrowseForFiles {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BrowseForFiles)
-            ),
+            None => crate::function_not_available(stringify!(BrowseForFiles)),
             Some(f) => f(text, initialdir, initialfile, allowmul, extlist),
         }
     }
@@ -2393,10 +2342,7 @@ impl Swell {
         fnsize: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.BrowseForSaveFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BrowseForSaveFile)
-            ),
+            None => crate::function_not_available(stringify!(BrowseForSaveFile)),
             Some(f) => f(text, initialdir, initialfile, extlist, fn_, fnsize),
         }
     }
@@ -2412,10 +2358,7 @@ impl Swell {
         fnsize: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.BrowseForDirectory {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BrowseForDirectory)
-            ),
+            None => crate::function_not_available(stringify!(BrowseForDirectory)),
             Some(f) => f(text, initialdir, fn_, fnsize),
         }
     }
@@ -2430,10 +2373,7 @@ impl Swell {
         reshead: *mut root::SWELL_DialogResourceIndex,
     ) {
         match self.pointers.BrowseFile_SetTemplate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BrowseFile_SetTemplate)
-            ),
+            None => crate::function_not_available(stringify!(BrowseFile_SetTemplate)),
             Some(f) => f(dlgid, dlgProc, reshead),
         }
     }
@@ -2443,10 +2383,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetDlgItem(&self, arg1: root::HWND, arg2: ::std::os::raw::c_int) -> root::HWND {
         match self.pointers.GetDlgItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetDlgItem)
-            ),
+            None => crate::function_not_available(stringify!(GetDlgItem)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -2456,10 +2393,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ShowWindow(&self, arg1: root::HWND, arg2: ::std::os::raw::c_int) {
         match self.pointers.ShowWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ShowWindow)
-            ),
+            None => crate::function_not_available(stringify!(ShowWindow)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -2469,10 +2403,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DestroyWindow(&self, hwnd: root::HWND) {
         match self.pointers.DestroyWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DestroyWindow)
-            ),
+            None => crate::function_not_available(stringify!(DestroyWindow)),
             Some(f) => f(hwnd),
         }
     }
@@ -2486,20 +2417,14 @@ impl Swell {
         gi: *mut root::GESTUREINFO,
     ) -> root::BOOL {
         match self.pointers.SWELL_GetGestureInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetGestureInfo)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetGestureInfo)),
             Some(f) => f(lParam, gi),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_HideApp(&self) {
         match self.pointers.SWELL_HideApp {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_HideApp)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_HideApp)),
             Some(f) => f(),
         }
     }
@@ -2514,10 +2439,7 @@ impl Swell {
         text: *const ::std::os::raw::c_char,
     ) -> root::BOOL {
         match self.pointers.SetDlgItemText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetDlgItemText)
-            ),
+            None => crate::function_not_available(stringify!(SetDlgItemText)),
             Some(f) => f(arg1, idx, text),
         }
     }
@@ -2533,10 +2455,7 @@ impl Swell {
         issigned: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.SetDlgItemInt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetDlgItemInt)
-            ),
+            None => crate::function_not_available(stringify!(SetDlgItemInt)),
             Some(f) => f(arg1, idx, val, issigned),
         }
     }
@@ -2552,10 +2471,7 @@ impl Swell {
         issigned: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetDlgItemInt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetDlgItemInt)
-            ),
+            None => crate::function_not_available(stringify!(GetDlgItemInt)),
             Some(f) => f(arg1, idx, translated, issigned),
         }
     }
@@ -2571,10 +2487,7 @@ impl Swell {
         textlen: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.GetDlgItemText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetDlgItemText)
-            ),
+            None => crate::function_not_available(stringify!(GetDlgItemText)),
             Some(f) => f(arg1, idx, text, textlen),
         }
     }
@@ -2584,10 +2497,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetWindowTextLength(&self, arg1: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.GetWindowTextLength {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetWindowTextLength)
-            ),
+            None => crate::function_not_available(stringify!(GetWindowTextLength)),
             Some(f) => f(arg1),
         }
     }
@@ -2602,10 +2512,7 @@ impl Swell {
         check: ::std::os::raw::c_int,
     ) {
         match self.pointers.CheckDlgButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CheckDlgButton)
-            ),
+            None => crate::function_not_available(stringify!(CheckDlgButton)),
             Some(f) => f(hwnd, idx, check),
         }
     }
@@ -2619,10 +2526,7 @@ impl Swell {
         idx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.IsDlgButtonChecked {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsDlgButtonChecked)
-            ),
+            None => crate::function_not_available(stringify!(IsDlgButtonChecked)),
             Some(f) => f(hwnd, idx),
         }
     }
@@ -2632,10 +2536,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn EnableWindow(&self, hwnd: root::HWND, enable: ::std::os::raw::c_int) {
         match self.pointers.EnableWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnableWindow)
-            ),
+            None => crate::function_not_available(stringify!(EnableWindow)),
             Some(f) => f(hwnd, enable),
         }
     }
@@ -2645,20 +2546,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetFocus(&self, hwnd: root::HWND) {
         match self.pointers.SetFocus {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetFocus)
-            ),
+            None => crate::function_not_available(stringify!(SetFocus)),
             Some(f) => f(hwnd),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetFocus(&self) -> root::HWND {
         match self.pointers.GetFocus {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetFocus)
-            ),
+            None => crate::function_not_available(stringify!(GetFocus)),
             Some(f) => f(),
         }
     }
@@ -2668,20 +2563,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetForegroundWindow(&self, hwnd: root::HWND) {
         match self.pointers.SetForegroundWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetForegroundWindow)
-            ),
+            None => crate::function_not_available(stringify!(SetForegroundWindow)),
             Some(f) => f(hwnd),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetForegroundWindow(&self) -> root::HWND {
         match self.pointers.GetForegroundWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetForegroundWindow)
-            ),
+            None => crate::function_not_available(stringify!(GetForegroundWindow)),
             Some(f) => f(),
         }
     }
@@ -2691,30 +2580,21 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetCapture(&self, hwnd: root::HWND) -> root::HWND {
         match self.pointers.SetCapture {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetCapture)
-            ),
+            None => crate::function_not_available(stringify!(SetCapture)),
             Some(f) => f(hwnd),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetCapture(&self) -> root::HWND {
         match self.pointers.GetCapture {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCapture)
-            ),
+            None => crate::function_not_available(stringify!(GetCapture)),
             Some(f) => f(),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn ReleaseCapture(&self) {
         match self.pointers.ReleaseCapture {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ReleaseCapture)
-            ),
+            None => crate::function_not_available(stringify!(ReleaseCapture)),
             Some(f) => f(),
         }
     }
@@ -2728,10 +2608,7 @@ impl Swell {
         hwndChild: root::HWND,
     ) -> ::std::os::raw::c_int {
         match self.pointers.IsChild {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsChild)
-            ),
+            None => crate::function_not_available(stringify!(IsChild)),
             Some(f) => f(hwndParent, hwndChild),
         }
     }
@@ -2741,10 +2618,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetParent(&self, hwnd: root::HWND) -> root::HWND {
         match self.pointers.GetParent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetParent)
-            ),
+            None => crate::function_not_available(stringify!(GetParent)),
             Some(f) => f(hwnd),
         }
     }
@@ -2754,10 +2628,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetParent(&self, hwnd: root::HWND, newPar: root::HWND) -> root::HWND {
         match self.pointers.SetParent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetParent)
-            ),
+            None => crate::function_not_available(stringify!(SetParent)),
             Some(f) => f(hwnd, newPar),
         }
     }
@@ -2767,10 +2638,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetWindow(&self, hwnd: root::HWND, what: ::std::os::raw::c_int) -> root::HWND {
         match self.pointers.GetWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetWindow)
-            ),
+            None => crate::function_not_available(stringify!(GetWindow)),
             Some(f) => f(hwnd, what),
         }
     }
@@ -2786,10 +2654,7 @@ impl Swell {
         lp: root::LPARAM,
     ) -> root::BOOL {
         match self.pointers.EnumWindows {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumWindows)
-            ),
+            None => crate::function_not_available(stringify!(EnumWindows)),
             Some(f) => f(proc_, lp),
         }
     }
@@ -2805,10 +2670,7 @@ impl Swell {
         title: *const ::std::os::raw::c_char,
     ) -> root::HWND {
         match self.pointers.FindWindowEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(FindWindowEx)
-            ),
+            None => crate::function_not_available(stringify!(FindWindowEx)),
             Some(f) => f(par, lastw, classname, title),
         }
     }
@@ -2818,10 +2680,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ClientToScreen(&self, hwnd: root::HWND, p: *mut root::POINT) {
         match self.pointers.ClientToScreen {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ClientToScreen)
-            ),
+            None => crate::function_not_available(stringify!(ClientToScreen)),
             Some(f) => f(hwnd, p),
         }
     }
@@ -2831,10 +2690,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ScreenToClient(&self, hwnd: root::HWND, p: *mut root::POINT) {
         match self.pointers.ScreenToClient {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ScreenToClient)
-            ),
+            None => crate::function_not_available(stringify!(ScreenToClient)),
             Some(f) => f(hwnd, p),
         }
     }
@@ -2844,10 +2700,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetWindowRect(&self, hwnd: root::HWND, r: *mut root::RECT) -> bool {
         match self.pointers.GetWindowRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetWindowRect)
-            ),
+            None => crate::function_not_available(stringify!(GetWindowRect)),
             Some(f) => f(hwnd, r),
         }
     }
@@ -2857,10 +2710,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetWindowContentViewRect(&self, hwnd: root::HWND, r: *mut root::RECT) {
         match self.pointers.GetWindowContentViewRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetWindowContentViewRect)
-            ),
+            None => crate::function_not_available(stringify!(GetWindowContentViewRect)),
             Some(f) => f(hwnd, r),
         }
     }
@@ -2870,10 +2720,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetClientRect(&self, hwnd: root::HWND, r: *mut root::RECT) {
         match self.pointers.GetClientRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetClientRect)
-            ),
+            None => crate::function_not_available(stringify!(GetClientRect)),
             Some(f) => f(hwnd, r),
         }
     }
@@ -2883,10 +2730,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn WindowFromPoint(&self, p: root::POINT) -> root::HWND {
         match self.pointers.WindowFromPoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WindowFromPoint)
-            ),
+            None => crate::function_not_available(stringify!(WindowFromPoint)),
             Some(f) => f(p),
         }
     }
@@ -2901,10 +2745,7 @@ impl Swell {
         dy: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.WinOffsetRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WinOffsetRect)
-            ),
+            None => crate::function_not_available(stringify!(WinOffsetRect)),
             Some(f) => f(lprc, dx, dy),
         }
     }
@@ -2921,10 +2762,7 @@ impl Swell {
         yBottom: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.WinSetRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WinSetRect)
-            ),
+            None => crate::function_not_available(stringify!(WinSetRect)),
             Some(f) => f(lprc, xLeft, yTop, xRight, yBottom),
         }
     }
@@ -2939,10 +2777,7 @@ impl Swell {
         in2: *const root::RECT,
     ) {
         match self.pointers.WinUnionRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WinUnionRect)
-            ),
+            None => crate::function_not_available(stringify!(WinUnionRect)),
             Some(f) => f(out, in1, in2),
         }
     }
@@ -2957,10 +2792,7 @@ impl Swell {
         in2: *const root::RECT,
     ) -> ::std::os::raw::c_int {
         match self.pointers.WinIntersectRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WinIntersectRect)
-            ),
+            None => crate::function_not_available(stringify!(WinIntersectRect)),
             Some(f) => f(out, in1, in2),
         }
     }
@@ -2979,10 +2811,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) {
         match self.pointers.SetWindowPos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetWindowPos)
-            ),
+            None => crate::function_not_available(stringify!(SetWindowPos)),
             Some(f) => f(hwnd, unused, x, y, cx, cy, flags),
         }
     }
@@ -2996,10 +2825,7 @@ impl Swell {
         newlevel: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_SetWindowLevel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetWindowLevel)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetWindowLevel)),
             Some(f) => f(hwnd, newlevel),
         }
     }
@@ -3014,10 +2840,7 @@ impl Swell {
         eraseBk: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.InvalidateRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InvalidateRect)
-            ),
+            None => crate::function_not_available(stringify!(InvalidateRect)),
             Some(f) => f(hwnd, r, eraseBk),
         }
     }
@@ -3027,10 +2850,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn UpdateWindow(&self, hwnd: root::HWND) {
         match self.pointers.UpdateWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(UpdateWindow)
-            ),
+            None => crate::function_not_available(stringify!(UpdateWindow)),
             Some(f) => f(hwnd),
         }
     }
@@ -3044,10 +2864,7 @@ impl Swell {
         idx: ::std::os::raw::c_int,
     ) -> root::LONG_PTR {
         match self.pointers.GetWindowLong {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetWindowLong)
-            ),
+            None => crate::function_not_available(stringify!(GetWindowLong)),
             Some(f) => f(hwnd, idx),
         }
     }
@@ -3062,10 +2879,7 @@ impl Swell {
         val: root::LONG_PTR,
     ) -> root::LONG_PTR {
         match self.pointers.SetWindowLong {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetWindowLong)
-            ),
+            None => crate::function_not_available(stringify!(SetWindowLong)),
             Some(f) => f(hwnd, idx, val),
         }
     }
@@ -3082,10 +2896,7 @@ impl Swell {
         lpClipRect: *const root::RECT,
     ) -> root::BOOL {
         match self.pointers.ScrollWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ScrollWindow)
-            ),
+            None => crate::function_not_available(stringify!(ScrollWindow)),
             Some(f) => f(hwnd, xamt, yamt, lpRect, lpClipRect),
         }
     }
@@ -3100,10 +2911,7 @@ impl Swell {
         arg3: root::LPARAM,
     ) -> ::std::os::raw::c_int {
         match self.pointers.EnumPropsEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumPropsEx)
-            ),
+            None => crate::function_not_available(stringify!(EnumPropsEx)),
             Some(f) => f(arg1, arg2, arg3),
         }
     }
@@ -3117,10 +2925,7 @@ impl Swell {
         arg2: *const ::std::os::raw::c_char,
     ) -> root::HANDLE {
         match self.pointers.GetProp {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProp)
-            ),
+            None => crate::function_not_available(stringify!(GetProp)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -3135,10 +2940,7 @@ impl Swell {
         arg3: root::HANDLE,
     ) -> root::BOOL {
         match self.pointers.SetProp {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetProp)
-            ),
+            None => crate::function_not_available(stringify!(SetProp)),
             Some(f) => f(arg1, arg2, arg3),
         }
     }
@@ -3152,10 +2954,7 @@ impl Swell {
         arg2: *const ::std::os::raw::c_char,
     ) -> root::HANDLE {
         match self.pointers.RemoveProp {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RemoveProp)
-            ),
+            None => crate::function_not_available(stringify!(RemoveProp)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -3165,10 +2964,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsWindowVisible(&self, hwnd: root::HWND) -> bool {
         match self.pointers.IsWindowVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsWindowVisible)
-            ),
+            None => crate::function_not_available(stringify!(IsWindowVisible)),
             Some(f) => f(hwnd),
         }
     }
@@ -3178,10 +2974,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsWindow(&self, hwnd: root::HWND) -> bool {
         match self.pointers.IsWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsWindow)
-            ),
+            None => crate::function_not_available(stringify!(IsWindow)),
             Some(f) => f(hwnd),
         }
     }
@@ -3197,10 +2990,7 @@ impl Swell {
         tProc: root::TIMERPROC,
     ) -> root::UINT_PTR {
         match self.pointers.SetTimer {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTimer)
-            ),
+            None => crate::function_not_available(stringify!(SetTimer)),
             Some(f) => f(hwnd, timerid, rate, tProc),
         }
     }
@@ -3210,10 +3000,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn KillTimer(&self, hwnd: root::HWND, timerid: root::UINT_PTR) -> root::BOOL {
         match self.pointers.KillTimer {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(KillTimer)
-            ),
+            None => crate::function_not_available(stringify!(KillTimer)),
             Some(f) => f(hwnd, timerid),
         }
     }
@@ -3228,10 +3015,7 @@ impl Swell {
         style: ::std::os::raw::c_int,
     ) {
         match self.pointers.ListView_SetExtendedListViewStyleEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetExtendedListViewStyleEx)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetExtendedListViewStyleEx)),
             Some(f) => f(h, mask, style),
         }
     }
@@ -3246,10 +3030,7 @@ impl Swell {
         lvc: *const root::LVCOLUMN,
     ) {
         match self.pointers.ListView_InsertColumn {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_InsertColumn)
-            ),
+            None => crate::function_not_available(stringify!(ListView_InsertColumn)),
             Some(f) => f(h, pos, lvc),
         }
     }
@@ -3259,10 +3040,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_DeleteColumn(&self, h: root::HWND, pos: ::std::os::raw::c_int) -> bool {
         match self.pointers.ListView_DeleteColumn {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_DeleteColumn)
-            ),
+            None => crate::function_not_available(stringify!(ListView_DeleteColumn)),
             Some(f) => f(h, pos),
         }
     }
@@ -3277,10 +3055,7 @@ impl Swell {
         lvc: *const root::LVCOLUMN,
     ) {
         match self.pointers.ListView_SetColumn {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetColumn)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetColumn)),
             Some(f) => f(h, pos, lvc),
         }
     }
@@ -3294,10 +3069,7 @@ impl Swell {
         pos: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetColumnWidth {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetColumnWidth)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetColumnWidth)),
             Some(f) => f(h, pos),
         }
     }
@@ -3311,10 +3083,7 @@ impl Swell {
         item: *const root::LVITEM,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ListView_InsertItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_InsertItem)
-            ),
+            None => crate::function_not_available(stringify!(ListView_InsertItem)),
             Some(f) => f(h, item),
         }
     }
@@ -3330,10 +3099,7 @@ impl Swell {
         txt: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.ListView_SetItemText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetItemText)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetItemText)),
             Some(f) => f(h, ipos, cpos, txt),
         }
     }
@@ -3343,10 +3109,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_SetItem(&self, h: root::HWND, item: *mut root::LVITEM) -> bool {
         match self.pointers.ListView_SetItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetItem)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetItem)),
             Some(f) => f(h, item),
         }
     }
@@ -3361,10 +3124,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetNextItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetNextItem)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetNextItem)),
             Some(f) => f(h, istart, flags),
         }
     }
@@ -3374,10 +3134,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_GetItem(&self, h: root::HWND, item: *mut root::LVITEM) -> bool {
         match self.pointers.ListView_GetItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetItem)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetItem)),
             Some(f) => f(h, item),
         }
     }
@@ -3392,10 +3149,7 @@ impl Swell {
         mask: root::UINT,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetItemState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetItemState)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetItemState)),
             Some(f) => f(h, ipos, mask),
         }
     }
@@ -3405,10 +3159,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_DeleteItem(&self, h: root::HWND, ipos: ::std::os::raw::c_int) {
         match self.pointers.ListView_DeleteItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_DeleteItem)
-            ),
+            None => crate::function_not_available(stringify!(ListView_DeleteItem)),
             Some(f) => f(h, ipos),
         }
     }
@@ -3418,10 +3169,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_DeleteAllItems(&self, h: root::HWND) {
         match self.pointers.ListView_DeleteAllItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_DeleteAllItems)
-            ),
+            None => crate::function_not_available(stringify!(ListView_DeleteAllItems)),
             Some(f) => f(h),
         }
     }
@@ -3431,10 +3179,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_GetSelectedCount(&self, h: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetSelectedCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetSelectedCount)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetSelectedCount)),
             Some(f) => f(h),
         }
     }
@@ -3444,10 +3189,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_GetItemCount(&self, h: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetItemCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetItemCount)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetItemCount)),
             Some(f) => f(h),
         }
     }
@@ -3457,10 +3199,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_GetSelectionMark(&self, h: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetSelectionMark {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetSelectionMark)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetSelectionMark)),
             Some(f) => f(h),
         }
     }
@@ -3475,10 +3214,7 @@ impl Swell {
         wid: ::std::os::raw::c_int,
     ) {
         match self.pointers.ListView_SetColumnWidth {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetColumnWidth)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetColumnWidth)),
             Some(f) => f(h, colpos, wid),
         }
     }
@@ -3494,10 +3230,7 @@ impl Swell {
         statemask: root::UINT,
     ) -> bool {
         match self.pointers.ListView_SetItemState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetItemState)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetItemState)),
             Some(f) => f(h, item, state, statemask),
         }
     }
@@ -3512,10 +3245,7 @@ impl Swell {
         enditem: ::std::os::raw::c_int,
     ) {
         match self.pointers.ListView_RedrawItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_RedrawItems)
-            ),
+            None => crate::function_not_available(stringify!(ListView_RedrawItems)),
             Some(f) => f(h, startitem, enditem),
         }
     }
@@ -3525,10 +3255,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_SetItemCount(&self, h: root::HWND, cnt: ::std::os::raw::c_int) {
         match self.pointers.ListView_SetItemCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetItemCount)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetItemCount)),
             Some(f) => f(h, cnt),
         }
     }
@@ -3543,10 +3270,7 @@ impl Swell {
         pok: root::BOOL,
     ) {
         match self.pointers.ListView_EnsureVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_EnsureVisible)
-            ),
+            None => crate::function_not_available(stringify!(ListView_EnsureVisible)),
             Some(f) => f(h, i, pok),
         }
     }
@@ -3561,10 +3285,7 @@ impl Swell {
         which: ::std::os::raw::c_int,
     ) {
         match self.pointers.ListView_SetImageList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetImageList)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetImageList)),
             Some(f) => f(h, imagelist, which),
         }
     }
@@ -3578,10 +3299,7 @@ impl Swell {
         pinf: *mut root::LVHITTESTINFO,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ListView_SubItemHitTest {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SubItemHitTest)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SubItemHitTest)),
             Some(f) => f(h, pinf),
         }
     }
@@ -3598,10 +3316,7 @@ impl Swell {
         textmax: ::std::os::raw::c_int,
     ) {
         match self.pointers.ListView_GetItemText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetItemText)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetItemText)),
             Some(f) => f(hwnd, item, subitem, text, textmax),
         }
     }
@@ -3616,10 +3331,7 @@ impl Swell {
         parm: root::LPARAM,
     ) {
         match self.pointers.ListView_SortItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SortItems)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SortItems)),
             Some(f) => f(hwnd, compf, parm),
         }
     }
@@ -3634,10 +3346,7 @@ impl Swell {
         yscroll: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.ListView_Scroll {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_Scroll)
-            ),
+            None => crate::function_not_available(stringify!(ListView_Scroll)),
             Some(f) => f(h, xscroll, yscroll),
         }
     }
@@ -3647,10 +3356,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_GetTopIndex(&self, h: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetTopIndex {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetTopIndex)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetTopIndex)),
             Some(f) => f(h),
         }
     }
@@ -3660,10 +3366,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_GetCountPerPage(&self, h: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.ListView_GetCountPerPage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetCountPerPage)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetCountPerPage)),
             Some(f) => f(h),
         }
     }
@@ -3678,10 +3381,7 @@ impl Swell {
         arr: *mut ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.ListView_SetColumnOrderArray {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetColumnOrderArray)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetColumnOrderArray)),
             Some(f) => f(h, cnt, arr),
         }
     }
@@ -3696,10 +3396,7 @@ impl Swell {
         arr: *mut ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.ListView_GetColumnOrderArray {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetColumnOrderArray)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetColumnOrderArray)),
             Some(f) => f(h, cnt, arr),
         }
     }
@@ -3709,10 +3406,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_GetHeader(&self, h: root::HWND) -> root::HWND {
         match self.pointers.ListView_GetHeader {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetHeader)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetHeader)),
             Some(f) => f(h),
         }
     }
@@ -3722,10 +3416,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn Header_GetItemCount(&self, h: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.Header_GetItemCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Header_GetItemCount)
-            ),
+            None => crate::function_not_available(stringify!(Header_GetItemCount)),
             Some(f) => f(h),
         }
     }
@@ -3740,10 +3431,7 @@ impl Swell {
         hi: *mut root::HDITEM,
     ) -> root::BOOL {
         match self.pointers.Header_GetItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Header_GetItem)
-            ),
+            None => crate::function_not_available(stringify!(Header_GetItem)),
             Some(f) => f(h, col, hi),
         }
     }
@@ -3758,10 +3446,7 @@ impl Swell {
         hi: *mut root::HDITEM,
     ) -> root::BOOL {
         match self.pointers.Header_SetItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Header_SetItem)
-            ),
+            None => crate::function_not_available(stringify!(Header_SetItem)),
             Some(f) => f(h, col, hi),
         }
     }
@@ -3777,10 +3462,7 @@ impl Swell {
         code: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.ListView_GetItemRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetItemRect)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetItemRect)),
             Some(f) => f(h, item, r, code),
         }
     }
@@ -3797,10 +3479,7 @@ impl Swell {
         r: *mut root::RECT,
     ) -> bool {
         match self.pointers.ListView_GetSubItemRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_GetSubItemRect)
-            ),
+            None => crate::function_not_available(stringify!(ListView_GetSubItemRect)),
             Some(f) => f(h, item, subitem, code, r),
         }
     }
@@ -3814,10 +3493,7 @@ impl Swell {
         pinf: *mut root::LVHITTESTINFO,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ListView_HitTest {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_HitTest)
-            ),
+            None => crate::function_not_available(stringify!(ListView_HitTest)),
             Some(f) => f(h, pinf),
         }
     }
@@ -3827,20 +3503,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetListViewHeaderHeight(&self, h: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_GetListViewHeaderHeight {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetListViewHeaderHeight)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetListViewHeaderHeight)),
             Some(f) => f(h),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn ImageList_CreateEx(&self) -> root::HIMAGELIST {
         match self.pointers.ImageList_CreateEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ImageList_CreateEx)
-            ),
+            None => crate::function_not_available(stringify!(ImageList_CreateEx)),
             Some(f) => f(),
         }
     }
@@ -3854,10 +3524,7 @@ impl Swell {
         idx: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.ImageList_Remove {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ImageList_Remove)
-            ),
+            None => crate::function_not_available(stringify!(ImageList_Remove)),
             Some(f) => f(list, idx),
         }
     }
@@ -3872,10 +3539,7 @@ impl Swell {
         image: root::HICON,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ImageList_ReplaceIcon {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ImageList_ReplaceIcon)
-            ),
+            None => crate::function_not_available(stringify!(ImageList_ReplaceIcon)),
             Some(f) => f(list, offset, image),
         }
     }
@@ -3890,10 +3554,7 @@ impl Swell {
         mask: root::HBITMAP,
     ) -> ::std::os::raw::c_int {
         match self.pointers.ImageList_Add {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ImageList_Add)
-            ),
+            None => crate::function_not_available(stringify!(ImageList_Add)),
             Some(f) => f(list, image, mask),
         }
     }
@@ -3903,10 +3564,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ImageList_Destroy(&self, arg1: root::HIMAGELIST) {
         match self.pointers.ImageList_Destroy {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ImageList_Destroy)
-            ),
+            None => crate::function_not_available(stringify!(ImageList_Destroy)),
             Some(f) => f(arg1),
         }
     }
@@ -3916,10 +3574,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TabCtrl_GetItemCount(&self, hwnd: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.TabCtrl_GetItemCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TabCtrl_GetItemCount)
-            ),
+            None => crate::function_not_available(stringify!(TabCtrl_GetItemCount)),
             Some(f) => f(hwnd),
         }
     }
@@ -3933,10 +3588,7 @@ impl Swell {
         idx: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.TabCtrl_DeleteItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TabCtrl_DeleteItem)
-            ),
+            None => crate::function_not_available(stringify!(TabCtrl_DeleteItem)),
             Some(f) => f(hwnd, idx),
         }
     }
@@ -3951,10 +3603,7 @@ impl Swell {
         item: *mut root::TCITEM,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TabCtrl_InsertItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TabCtrl_InsertItem)
-            ),
+            None => crate::function_not_available(stringify!(TabCtrl_InsertItem)),
             Some(f) => f(hwnd, idx, item),
         }
     }
@@ -3968,10 +3617,7 @@ impl Swell {
         idx: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TabCtrl_SetCurSel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TabCtrl_SetCurSel)
-            ),
+            None => crate::function_not_available(stringify!(TabCtrl_SetCurSel)),
             Some(f) => f(hwnd, idx),
         }
     }
@@ -3981,10 +3627,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TabCtrl_GetCurSel(&self, hwnd: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.TabCtrl_GetCurSel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TabCtrl_GetCurSel)
-            ),
+            None => crate::function_not_available(stringify!(TabCtrl_GetCurSel)),
             Some(f) => f(hwnd),
         }
     }
@@ -3999,10 +3642,7 @@ impl Swell {
         r: *mut root::RECT,
     ) -> root::BOOL {
         match self.pointers.TabCtrl_AdjustRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TabCtrl_AdjustRect)
-            ),
+            None => crate::function_not_available(stringify!(TabCtrl_AdjustRect)),
             Some(f) => f(hwnd, fLarger, r),
         }
     }
@@ -4016,10 +3656,7 @@ impl Swell {
         ins: *mut root::TV_INSERTSTRUCT,
     ) -> root::HTREEITEM {
         match self.pointers.TreeView_InsertItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_InsertItem)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_InsertItem)),
             Some(f) => f(hwnd, ins),
         }
     }
@@ -4034,10 +3671,7 @@ impl Swell {
         flag: root::UINT,
     ) -> root::BOOL {
         match self.pointers.TreeView_Expand {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_Expand)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_Expand)),
             Some(f) => f(hwnd, item, flag),
         }
     }
@@ -4047,10 +3681,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_GetSelection(&self, hwnd: root::HWND) -> root::HTREEITEM {
         match self.pointers.TreeView_GetSelection {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_GetSelection)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_GetSelection)),
             Some(f) => f(hwnd),
         }
     }
@@ -4060,10 +3691,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_DeleteItem(&self, hwnd: root::HWND, item: root::HTREEITEM) {
         match self.pointers.TreeView_DeleteItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_DeleteItem)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_DeleteItem)),
             Some(f) => f(hwnd, item),
         }
     }
@@ -4073,10 +3701,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_DeleteAllItems(&self, hwnd: root::HWND) {
         match self.pointers.TreeView_DeleteAllItems {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_DeleteAllItems)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_DeleteAllItems)),
             Some(f) => f(hwnd),
         }
     }
@@ -4086,10 +3711,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_SelectItem(&self, hwnd: root::HWND, item: root::HTREEITEM) {
         match self.pointers.TreeView_SelectItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_SelectItem)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_SelectItem)),
             Some(f) => f(hwnd, item),
         }
     }
@@ -4099,10 +3721,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_EnsureVisible(&self, hwnd: root::HWND, item: root::HTREEITEM) {
         match self.pointers.TreeView_EnsureVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_EnsureVisible)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_EnsureVisible)),
             Some(f) => f(hwnd, item),
         }
     }
@@ -4112,10 +3731,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_GetItem(&self, hwnd: root::HWND, pitem: root::LPTVITEM) -> root::BOOL {
         match self.pointers.TreeView_GetItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_GetItem)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_GetItem)),
             Some(f) => f(hwnd, pitem),
         }
     }
@@ -4125,10 +3741,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_SetItem(&self, hwnd: root::HWND, pitem: root::LPTVITEM) -> root::BOOL {
         match self.pointers.TreeView_SetItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_SetItem)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_SetItem)),
             Some(f) => f(hwnd, pitem),
         }
     }
@@ -4142,10 +3755,7 @@ impl Swell {
         hti: *mut root::TVHITTESTINFO,
     ) -> root::HTREEITEM {
         match self.pointers.TreeView_HitTest {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_HitTest)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_HitTest)),
             Some(f) => f(hwnd, hti),
         }
     }
@@ -4159,10 +3769,7 @@ impl Swell {
         indent: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.TreeView_SetIndent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_SetIndent)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_SetIndent)),
             Some(f) => f(hwnd, indent),
         }
     }
@@ -4176,10 +3783,7 @@ impl Swell {
         item: root::HTREEITEM,
     ) -> root::HTREEITEM {
         match self.pointers.TreeView_GetParent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_GetParent)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_GetParent)),
             Some(f) => f(hwnd, item),
         }
     }
@@ -4193,10 +3797,7 @@ impl Swell {
         item: root::HTREEITEM,
     ) -> root::HTREEITEM {
         match self.pointers.TreeView_GetChild {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_GetChild)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_GetChild)),
             Some(f) => f(hwnd, item),
         }
     }
@@ -4210,10 +3811,7 @@ impl Swell {
         item: root::HTREEITEM,
     ) -> root::HTREEITEM {
         match self.pointers.TreeView_GetNextSibling {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_GetNextSibling)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_GetNextSibling)),
             Some(f) => f(hwnd, item),
         }
     }
@@ -4223,10 +3821,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_GetRoot(&self, hwnd: root::HWND) -> root::HTREEITEM {
         match self.pointers.TreeView_GetRoot {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_GetRoot)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_GetRoot)),
             Some(f) => f(hwnd),
         }
     }
@@ -4236,10 +3831,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_SetBkColor(&self, hwnd: root::HWND, color: ::std::os::raw::c_int) {
         match self.pointers.TreeView_SetBkColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_SetBkColor)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_SetBkColor)),
             Some(f) => f(hwnd, color),
         }
     }
@@ -4249,10 +3841,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn TreeView_SetTextColor(&self, hwnd: root::HWND, color: ::std::os::raw::c_int) {
         match self.pointers.TreeView_SetTextColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TreeView_SetTextColor)
-            ),
+            None => crate::function_not_available(stringify!(TreeView_SetTextColor)),
             Some(f) => f(hwnd, color),
         }
     }
@@ -4262,10 +3851,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_SetBkColor(&self, hwnd: root::HWND, color: ::std::os::raw::c_int) {
         match self.pointers.ListView_SetBkColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetBkColor)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetBkColor)),
             Some(f) => f(hwnd, color),
         }
     }
@@ -4275,10 +3861,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_SetTextBkColor(&self, hwnd: root::HWND, color: ::std::os::raw::c_int) {
         match self.pointers.ListView_SetTextBkColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetTextBkColor)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetTextBkColor)),
             Some(f) => f(hwnd, color),
         }
     }
@@ -4288,10 +3871,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_SetTextColor(&self, hwnd: root::HWND, color: ::std::os::raw::c_int) {
         match self.pointers.ListView_SetTextColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetTextColor)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetTextColor)),
             Some(f) => f(hwnd, color),
         }
     }
@@ -4301,10 +3881,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ListView_SetGridColor(&self, hwnd: root::HWND, color: ::std::os::raw::c_int) {
         match self.pointers.ListView_SetGridColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetGridColor)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetGridColor)),
             Some(f) => f(hwnd, color),
         }
     }
@@ -4319,10 +3896,7 @@ impl Swell {
         ncolors: ::std::os::raw::c_int,
     ) {
         match self.pointers.ListView_SetSelColors {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ListView_SetSelColors)
-            ),
+            None => crate::function_not_available(stringify!(ListView_SetSelColors)),
             Some(f) => f(hwnd, colors, ncolors),
         }
     }
@@ -4332,10 +3906,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_ModalWindowStart(&self, hwnd: root::HWND) -> *mut ::std::os::raw::c_void {
         match self.pointers.SWELL_ModalWindowStart {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_ModalWindowStart)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_ModalWindowStart)),
             Some(f) => f(hwnd),
         }
     }
@@ -4349,10 +3920,7 @@ impl Swell {
         ret: *mut ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.SWELL_ModalWindowRun {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_ModalWindowRun)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_ModalWindowRun)),
             Some(f) => f(ctx, ret),
         }
     }
@@ -4362,10 +3930,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_ModalWindowEnd(&self, ctx: *mut ::std::os::raw::c_void) {
         match self.pointers.SWELL_ModalWindowEnd {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_ModalWindowEnd)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_ModalWindowEnd)),
             Some(f) => f(ctx),
         }
     }
@@ -4375,20 +3940,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_CloseWindow(&self, hwnd: root::HWND) {
         match self.pointers.SWELL_CloseWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_CloseWindow)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_CloseWindow)),
             Some(f) => f(hwnd),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn CreatePopupMenu(&self) -> root::HMENU {
         match self.pointers.CreatePopupMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreatePopupMenu)
-            ),
+            None => crate::function_not_available(stringify!(CreatePopupMenu)),
             Some(f) => f(),
         }
     }
@@ -4398,10 +3957,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CreatePopupMenuEx(&self, title: *const ::std::os::raw::c_char) -> root::HMENU {
         match self.pointers.CreatePopupMenuEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreatePopupMenuEx)
-            ),
+            None => crate::function_not_available(stringify!(CreatePopupMenuEx)),
             Some(f) => f(title),
         }
     }
@@ -4411,10 +3967,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DestroyMenu(&self, hMenu: root::HMENU) {
         match self.pointers.DestroyMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DestroyMenu)
-            ),
+            None => crate::function_not_available(stringify!(DestroyMenu)),
             Some(f) => f(hMenu),
         }
     }
@@ -4430,10 +3983,7 @@ impl Swell {
         tagid: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.AddMenuItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddMenuItem)
-            ),
+            None => crate::function_not_available(stringify!(AddMenuItem)),
             Some(f) => f(hMenu, pos, name, tagid),
         }
     }
@@ -4443,10 +3993,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetSubMenu(&self, hMenu: root::HMENU, pos: ::std::os::raw::c_int) -> root::HMENU {
         match self.pointers.GetSubMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSubMenu)
-            ),
+            None => crate::function_not_available(stringify!(GetSubMenu)),
             Some(f) => f(hMenu, pos),
         }
     }
@@ -4456,10 +4003,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetMenuItemCount(&self, hMenu: root::HMENU) -> ::std::os::raw::c_int {
         match self.pointers.GetMenuItemCount {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMenuItemCount)
-            ),
+            None => crate::function_not_available(stringify!(GetMenuItemCount)),
             Some(f) => f(hMenu),
         }
     }
@@ -4473,10 +4017,7 @@ impl Swell {
         pos: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetMenuItemID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMenuItemID)
-            ),
+            None => crate::function_not_available(stringify!(GetMenuItemID)),
             Some(f) => f(hMenu, pos),
         }
     }
@@ -4493,10 +4034,7 @@ impl Swell {
         mask: ::std::os::raw::c_uint,
     ) -> bool {
         match self.pointers.SetMenuItemModifier {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMenuItemModifier)
-            ),
+            None => crate::function_not_available(stringify!(SetMenuItemModifier)),
             Some(f) => f(hMenu, idx, flag, code, mask),
         }
     }
@@ -4512,10 +4050,7 @@ impl Swell {
         text: *const ::std::os::raw::c_char,
     ) -> bool {
         match self.pointers.SetMenuItemText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMenuItemText)
-            ),
+            None => crate::function_not_available(stringify!(SetMenuItemText)),
             Some(f) => f(hMenu, idx, flag, text),
         }
     }
@@ -4530,10 +4065,7 @@ impl Swell {
         en: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.EnableMenuItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnableMenuItem)
-            ),
+            None => crate::function_not_available(stringify!(EnableMenuItem)),
             Some(f) => f(hMenu, idx, en),
         }
     }
@@ -4548,10 +4080,7 @@ impl Swell {
         flag: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.DeleteMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteMenu)
-            ),
+            None => crate::function_not_available(stringify!(DeleteMenu)),
             Some(f) => f(hMenu, idx, flag),
         }
     }
@@ -4566,10 +4095,7 @@ impl Swell {
         chk: ::std::os::raw::c_int,
     ) -> bool {
         match self.pointers.CheckMenuItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CheckMenuItem)
-            ),
+            None => crate::function_not_available(stringify!(CheckMenuItem)),
             Some(f) => f(hMenu, idx, chk),
         }
     }
@@ -4585,10 +4111,7 @@ impl Swell {
         mi: *mut root::MENUITEMINFO,
     ) {
         match self.pointers.InsertMenuItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InsertMenuItem)
-            ),
+            None => crate::function_not_available(stringify!(InsertMenuItem)),
             Some(f) => f(hMenu, pos, byPos, mi),
         }
     }
@@ -4605,10 +4128,7 @@ impl Swell {
         str: *const ::std::os::raw::c_char,
     ) {
         match self.pointers.SWELL_InsertMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_InsertMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_InsertMenu)),
             Some(f) => f(menu, pos, flag, idx, str),
         }
     }
@@ -4624,10 +4144,7 @@ impl Swell {
         mi: *mut root::MENUITEMINFO,
     ) -> root::BOOL {
         match self.pointers.GetMenuItemInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMenuItemInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetMenuItemInfo)),
             Some(f) => f(hMenu, pos, byPos, mi),
         }
     }
@@ -4643,10 +4160,7 @@ impl Swell {
         mi: *mut root::MENUITEMINFO,
     ) -> root::BOOL {
         match self.pointers.SetMenuItemInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMenuItemInfo)
-            ),
+            None => crate::function_not_available(stringify!(SetMenuItemInfo)),
             Some(f) => f(hMenu, pos, byPos, mi),
         }
     }
@@ -4656,10 +4170,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DrawMenuBar(&self, arg1: root::HWND) {
         match self.pointers.DrawMenuBar {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DrawMenuBar)
-            ),
+            None => crate::function_not_available(stringify!(DrawMenuBar)),
             Some(f) => f(arg1),
         }
     }
@@ -4673,10 +4184,7 @@ impl Swell {
         resid: *const ::std::os::raw::c_char,
     ) -> root::HMENU {
         match self.pointers.SWELL_LoadMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_LoadMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_LoadMenu)),
             Some(f) => f(head, resid),
         }
     }
@@ -4695,10 +4203,7 @@ impl Swell {
         r: *const root::RECT,
     ) -> ::std::os::raw::c_int {
         match self.pointers.TrackPopupMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(TrackPopupMenu)
-            ),
+            None => crate::function_not_available(stringify!(TrackPopupMenu)),
             Some(f) => f(hMenu, flags, xpos, ypos, resvd, hwnd, r),
         }
     }
@@ -4708,10 +4213,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetMenuDestination(&self, menu: root::HMENU, hwnd: root::HWND) {
         match self.pointers.SWELL_SetMenuDestination {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetMenuDestination)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetMenuDestination)),
             Some(f) => f(menu, hwnd),
         }
     }
@@ -4721,10 +4223,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_DuplicateMenu(&self, menu: root::HMENU) -> root::HMENU {
         match self.pointers.SWELL_DuplicateMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_DuplicateMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_DuplicateMenu)),
             Some(f) => f(menu),
         }
     }
@@ -4734,10 +4233,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetMenu(&self, hwnd: root::HWND, menu: root::HMENU) -> root::BOOL {
         match self.pointers.SetMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetMenu)
-            ),
+            None => crate::function_not_available(stringify!(SetMenu)),
             Some(f) => f(hwnd, menu),
         }
     }
@@ -4747,20 +4243,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetMenu(&self, hwnd: root::HWND) -> root::HMENU {
         match self.pointers.GetMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMenu)
-            ),
+            None => crate::function_not_available(stringify!(GetMenu)),
             Some(f) => f(hwnd),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_GetDefaultWindowMenu(&self) -> root::HMENU {
         match self.pointers.SWELL_GetDefaultWindowMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetDefaultWindowMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetDefaultWindowMenu)),
             Some(f) => f(),
         }
     }
@@ -4770,20 +4260,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetDefaultWindowMenu(&self, arg1: root::HMENU) {
         match self.pointers.SWELL_SetDefaultWindowMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetDefaultWindowMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetDefaultWindowMenu)),
             Some(f) => f(arg1),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_GetDefaultModalWindowMenu(&self) -> root::HMENU {
         match self.pointers.SWELL_GetDefaultModalWindowMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetDefaultModalWindowMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetDefaultModalWindowMenu)),
             Some(f) => f(),
         }
     }
@@ -4793,20 +4277,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetDefaultModalWindowMenu(&self, arg1: root::HMENU) {
         match self.pointers.SWELL_SetDefaultModalWindowMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetDefaultModalWindowMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetDefaultModalWindowMenu)),
             Some(f) => f(arg1),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_GetCurrentMenu(&self) -> root::HMENU {
         match self.pointers.SWELL_GetCurrentMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetCurrentMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetCurrentMenu)),
             Some(f) => f(),
         }
     }
@@ -4816,10 +4294,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetCurrentMenu(&self, arg1: root::HMENU) {
         match self.pointers.SWELL_SetCurrentMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetCurrentMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetCurrentMenu)),
             Some(f) => f(arg1),
         }
     }
@@ -4836,10 +4311,7 @@ impl Swell {
         param: root::LPARAM,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_DialogBox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_DialogBox)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_DialogBox)),
             Some(f) => f(reshead, resid, parent, dlgproc, param),
         }
     }
@@ -4856,10 +4328,7 @@ impl Swell {
         param: root::LPARAM,
     ) -> root::HWND {
         match self.pointers.SWELL_CreateDialog {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_CreateDialog)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_CreateDialog)),
             Some(f) => f(reshead, resid, parent, dlgproc, param),
         }
     }
@@ -4869,10 +4338,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_RegisterCustomControlCreator(&self, proc_: root::SWELL_ControlCreatorProc) {
         match self.pointers.SWELL_RegisterCustomControlCreator {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_RegisterCustomControlCreator)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_RegisterCustomControlCreator)),
             Some(f) => f(proc_),
         }
     }
@@ -4885,10 +4351,7 @@ impl Swell {
         proc_: root::SWELL_ControlCreatorProc,
     ) {
         match self.pointers.SWELL_UnregisterCustomControlCreator {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_UnregisterCustomControlCreator)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_UnregisterCustomControlCreator)),
             Some(f) => f(proc_),
         }
     }
@@ -4904,10 +4367,7 @@ impl Swell {
         lParam: root::LPARAM,
     ) -> root::LRESULT {
         match self.pointers.DefWindowProc {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DefWindowProc)
-            ),
+            None => crate::function_not_available(stringify!(DefWindowProc)),
             Some(f) => f(hwnd, msg, wParam, lParam),
         }
     }
@@ -4917,10 +4377,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn EndDialog(&self, arg1: root::HWND, arg2: ::std::os::raw::c_int) {
         match self.pointers.EndDialog {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EndDialog)
-            ),
+            None => crate::function_not_available(stringify!(EndDialog)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -4934,10 +4391,7 @@ impl Swell {
         onlyIfEnabled: bool,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_GetDefaultButtonID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetDefaultButtonID)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetDefaultButtonID)),
             Some(f) => f(hwndDlg, onlyIfEnabled),
         }
     }
@@ -4953,10 +4407,7 @@ impl Swell {
         arg4: root::LPARAM,
     ) -> root::LRESULT {
         match self.pointers.SendMessage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SendMessage)
-            ),
+            None => crate::function_not_available(stringify!(SendMessage)),
             Some(f) => f(arg1, arg2, arg3, arg4),
         }
     }
@@ -4971,10 +4422,7 @@ impl Swell {
         arg3: root::LPARAM,
     ) {
         match self.pointers.SWELL_BroadcastMessage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_BroadcastMessage)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_BroadcastMessage)),
             Some(f) => f(arg1, arg2, arg3),
         }
     }
@@ -4990,20 +4438,14 @@ impl Swell {
         lParam: root::LPARAM,
     ) -> root::BOOL {
         match self.pointers.PostMessage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PostMessage)
-            ),
+            None => crate::function_not_available(stringify!(PostMessage)),
             Some(f) => f(hwnd, msg, wParam, lParam),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_MessageQueue_Flush(&self) {
         match self.pointers.SWELL_MessageQueue_Flush {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MessageQueue_Flush)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MessageQueue_Flush)),
             Some(f) => f(),
         }
     }
@@ -5013,10 +4455,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_MessageQueue_Clear(&self, h: root::HWND) {
         match self.pointers.SWELL_MessageQueue_Clear {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MessageQueue_Clear)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MessageQueue_Clear)),
             Some(f) => f(h),
         }
     }
@@ -5031,20 +4470,14 @@ impl Swell {
         newflags: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_KeyToASCII {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_KeyToASCII)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_KeyToASCII)),
             Some(f) => f(wParam, lParam, newflags),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetAsyncKeyState(&self, key: ::std::os::raw::c_int) -> root::WORD {
         match self.pointers.GetAsyncKeyState {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetAsyncKeyState)
-            ),
+            None => crate::function_not_available(stringify!(GetAsyncKeyState)),
             Some(f) => f(key),
         }
     }
@@ -5054,20 +4487,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetCursorPos(&self, pt: *mut root::POINT) {
         match self.pointers.GetCursorPos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCursorPos)
-            ),
+            None => crate::function_not_available(stringify!(GetCursorPos)),
             Some(f) => f(pt),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetMessagePos(&self) -> root::DWORD {
         match self.pointers.GetMessagePos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMessagePos)
-            ),
+            None => crate::function_not_available(stringify!(GetMessagePos)),
             Some(f) => f(),
         }
     }
@@ -5077,10 +4504,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_LoadCursor(&self, idx: *const ::std::os::raw::c_char) -> root::HCURSOR {
         match self.pointers.SWELL_LoadCursor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_LoadCursor)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_LoadCursor)),
             Some(f) => f(idx),
         }
     }
@@ -5090,10 +4514,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetCursor(&self, curs: root::HCURSOR) {
         match self.pointers.SWELL_SetCursor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetCursor)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetCursor)),
             Some(f) => f(curs),
         }
     }
@@ -5103,40 +4524,28 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_EnableRightClickEmulate(&self, enable: root::BOOL) {
         match self.pointers.SWELL_EnableRightClickEmulate {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_EnableRightClickEmulate)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_EnableRightClickEmulate)),
             Some(f) => f(enable),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_GetCursor(&self) -> root::HCURSOR {
         match self.pointers.SWELL_GetCursor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetCursor)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetCursor)),
             Some(f) => f(),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_GetLastSetCursor(&self) -> root::HCURSOR {
         match self.pointers.SWELL_GetLastSetCursor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetLastSetCursor)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetLastSetCursor)),
             Some(f) => f(),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_IsCursorVisible(&self) -> bool {
         match self.pointers.SWELL_IsCursorVisible {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_IsCursorVisible)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_IsCursorVisible)),
             Some(f) => f(),
         }
     }
@@ -5146,10 +4555,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_ShowCursor(&self, bShow: root::BOOL) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_ShowCursor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_ShowCursor)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_ShowCursor)),
             Some(f) => f(bShow),
         }
     }
@@ -5160,10 +4566,7 @@ impl Swell {
         Y: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.SWELL_SetCursorPos {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetCursorPos)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetCursorPos)),
             Some(f) => f(X, Y),
         }
     }
@@ -5178,10 +4581,7 @@ impl Swell {
         wantWork: bool,
     ) {
         match self.pointers.SWELL_GetViewPort {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetViewPort)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetViewPort)),
             Some(f) => f(r, sourcerect, wantWork),
         }
     }
@@ -5191,20 +4591,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn OpenClipboard(&self, hwndDlg: root::HWND) -> bool {
         match self.pointers.OpenClipboard {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(OpenClipboard)
-            ),
+            None => crate::function_not_available(stringify!(OpenClipboard)),
             Some(f) => f(hwndDlg),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn CloseClipboard(&self) {
         match self.pointers.CloseClipboard {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CloseClipboard)
-            ),
+            None => crate::function_not_available(stringify!(CloseClipboard)),
             Some(f) => f(),
         }
     }
@@ -5214,20 +4608,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetClipboardData(&self, type_: root::UINT) -> root::HANDLE {
         match self.pointers.GetClipboardData {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetClipboardData)
-            ),
+            None => crate::function_not_available(stringify!(GetClipboardData)),
             Some(f) => f(type_),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn EmptyClipboard(&self) {
         match self.pointers.EmptyClipboard {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EmptyClipboard)
-            ),
+            None => crate::function_not_available(stringify!(EmptyClipboard)),
             Some(f) => f(),
         }
     }
@@ -5237,10 +4625,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetClipboardData(&self, type_: root::UINT, h: root::HANDLE) {
         match self.pointers.SetClipboardData {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetClipboardData)
-            ),
+            None => crate::function_not_available(stringify!(SetClipboardData)),
             Some(f) => f(type_, h),
         }
     }
@@ -5253,10 +4638,7 @@ impl Swell {
         desc: *const ::std::os::raw::c_char,
     ) -> root::UINT {
         match self.pointers.RegisterClipboardFormat {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RegisterClipboardFormat)
-            ),
+            None => crate::function_not_available(stringify!(RegisterClipboardFormat)),
             Some(f) => f(desc),
         }
     }
@@ -5266,10 +4648,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn EnumClipboardFormats(&self, lastfmt: root::UINT) -> root::UINT {
         match self.pointers.EnumClipboardFormats {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumClipboardFormats)
-            ),
+            None => crate::function_not_available(stringify!(EnumClipboardFormats)),
             Some(f) => f(lastfmt),
         }
     }
@@ -5280,10 +4659,7 @@ impl Swell {
         sz: ::std::os::raw::c_int,
     ) -> root::HANDLE {
         match self.pointers.GlobalAlloc {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GlobalAlloc)
-            ),
+            None => crate::function_not_available(stringify!(GlobalAlloc)),
             Some(f) => f(flags, sz),
         }
     }
@@ -5293,10 +4669,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GlobalLock(&self, h: root::HANDLE) -> *mut ::std::os::raw::c_void {
         match self.pointers.GlobalLock {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GlobalLock)
-            ),
+            None => crate::function_not_available(stringify!(GlobalLock)),
             Some(f) => f(h),
         }
     }
@@ -5306,10 +4679,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GlobalSize(&self, h: root::HANDLE) -> ::std::os::raw::c_int {
         match self.pointers.GlobalSize {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GlobalSize)
-            ),
+            None => crate::function_not_available(stringify!(GlobalSize)),
             Some(f) => f(h),
         }
     }
@@ -5319,10 +4689,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GlobalUnlock(&self, h: root::HANDLE) {
         match self.pointers.GlobalUnlock {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GlobalUnlock)
-            ),
+            None => crate::function_not_available(stringify!(GlobalUnlock)),
             Some(f) => f(h),
         }
     }
@@ -5332,10 +4699,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GlobalFree(&self, h: root::HANDLE) {
         match self.pointers.GlobalFree {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GlobalFree)
-            ),
+            None => crate::function_not_available(stringify!(GlobalFree)),
             Some(f) => f(h),
         }
     }
@@ -5353,10 +4717,7 @@ impl Swell {
         tidOut: *mut root::DWORD,
     ) -> root::HANDLE {
         match self.pointers.CreateThread {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateThread)
-            ),
+            None => crate::function_not_available(stringify!(CreateThread)),
             Some(f) => f(TA, stackSize, ThreadProc, parm, cf, tidOut),
         }
     }
@@ -5372,10 +4733,7 @@ impl Swell {
         ignored: *const ::std::os::raw::c_char,
     ) -> root::HANDLE {
         match self.pointers.CreateEvent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateEvent)
-            ),
+            None => crate::function_not_available(stringify!(CreateEvent)),
             Some(f) => f(SA, manualReset, initialSig, ignored),
         }
     }
@@ -5391,20 +4749,14 @@ impl Swell {
         ignored: *const ::std::os::raw::c_char,
     ) -> root::HANDLE {
         match self.pointers.CreateEventAsSocket {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateEventAsSocket)
-            ),
+            None => crate::function_not_available(stringify!(CreateEventAsSocket)),
             Some(f) => f(SA, manualReset, initialSig, ignored),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetCurrentThreadId(&self) -> root::DWORD {
         match self.pointers.GetCurrentThreadId {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetCurrentThreadId)
-            ),
+            None => crate::function_not_available(stringify!(GetCurrentThreadId)),
             Some(f) => f(),
         }
     }
@@ -5414,10 +4766,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn WaitForSingleObject(&self, hand: root::HANDLE, msTO: root::DWORD) -> root::DWORD {
         match self.pointers.WaitForSingleObject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WaitForSingleObject)
-            ),
+            None => crate::function_not_available(stringify!(WaitForSingleObject)),
             Some(f) => f(hand, msTO),
         }
     }
@@ -5432,10 +4781,7 @@ impl Swell {
         msTO: root::DWORD,
     ) -> root::DWORD {
         match self.pointers.WaitForAnySocketObject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(WaitForAnySocketObject)
-            ),
+            None => crate::function_not_available(stringify!(WaitForAnySocketObject)),
             Some(f) => f(numObjs, objs, msTO),
         }
     }
@@ -5445,10 +4791,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CloseHandle(&self, hand: root::HANDLE) -> root::BOOL {
         match self.pointers.CloseHandle {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CloseHandle)
-            ),
+            None => crate::function_not_available(stringify!(CloseHandle)),
             Some(f) => f(hand),
         }
     }
@@ -5462,10 +4805,7 @@ impl Swell {
         prio: ::std::os::raw::c_int,
     ) -> root::BOOL {
         match self.pointers.SetThreadPriority {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetThreadPriority)
-            ),
+            None => crate::function_not_available(stringify!(SetThreadPriority)),
             Some(f) => f(evt, prio),
         }
     }
@@ -5475,10 +4815,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetEvent(&self, evt: root::HANDLE) -> root::BOOL {
         match self.pointers.SetEvent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetEvent)
-            ),
+            None => crate::function_not_available(stringify!(SetEvent)),
             Some(f) => f(evt),
         }
     }
@@ -5488,20 +4825,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ResetEvent(&self, evt: root::HANDLE) -> root::BOOL {
         match self.pointers.ResetEvent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ResetEvent)
-            ),
+            None => crate::function_not_available(stringify!(ResetEvent)),
             Some(f) => f(evt),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_CreateProcessFromPID(&self, pid: ::std::os::raw::c_int) -> root::HANDLE {
         match self.pointers.SWELL_CreateProcessFromPID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_CreateProcessFromPID)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_CreateProcessFromPID)),
             Some(f) => f(pid),
         }
     }
@@ -5516,10 +4847,7 @@ impl Swell {
         params: *mut *const ::std::os::raw::c_char,
     ) -> root::HANDLE {
         match self.pointers.SWELL_CreateProcess {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_CreateProcess)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_CreateProcess)),
             Some(f) => f(exe, nparams, params),
         }
     }
@@ -5529,10 +4857,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetProcessExitCode(&self, hand: root::HANDLE) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_GetProcessExitCode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetProcessExitCode)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetProcessExitCode)),
             Some(f) => f(hand),
         }
     }
@@ -5546,10 +4871,7 @@ impl Swell {
         symbolsAsGlobals: bool,
     ) -> root::HINSTANCE {
         match self.pointers.LoadLibraryGlobals {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LoadLibraryGlobals)
-            ),
+            None => crate::function_not_available(stringify!(LoadLibraryGlobals)),
             Some(f) => f(fileName, symbolsAsGlobals),
         }
     }
@@ -5559,10 +4881,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn LoadLibrary(&self, fileName: *const ::std::os::raw::c_char) -> root::HINSTANCE {
         match self.pointers.LoadLibrary {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LoadLibrary)
-            ),
+            None => crate::function_not_available(stringify!(LoadLibrary)),
             Some(f) => f(fileName),
         }
     }
@@ -5576,10 +4895,7 @@ impl Swell {
         procName: *const ::std::os::raw::c_char,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.GetProcAddress {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetProcAddress)
-            ),
+            None => crate::function_not_available(stringify!(GetProcAddress)),
             Some(f) => f(hInst, procName),
         }
     }
@@ -5589,10 +4905,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn FreeLibrary(&self, hInst: root::HINSTANCE) -> root::BOOL {
         match self.pointers.FreeLibrary {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(FreeLibrary)
-            ),
+            None => crate::function_not_available(stringify!(FreeLibrary)),
             Some(f) => f(hInst),
         }
     }
@@ -5602,10 +4915,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetBundle(&self, hInst: root::HINSTANCE) -> *mut ::std::os::raw::c_void {
         match self.pointers.SWELL_GetBundle {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetBundle)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetBundle)),
             Some(f) => f(hInst),
         }
     }
@@ -5620,10 +4930,7 @@ impl Swell {
         h: ::std::os::raw::c_int,
     ) -> root::HDC {
         match self.pointers.SWELL_CreateMemContext {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_CreateMemContext)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_CreateMemContext)),
             Some(f) => f(hdc, w, h),
         }
     }
@@ -5633,10 +4940,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_DeleteGfxContext(&self, arg1: root::HDC) {
         match self.pointers.SWELL_DeleteGfxContext {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_DeleteGfxContext)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_DeleteGfxContext)),
             Some(f) => f(arg1),
         }
     }
@@ -5646,10 +4950,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetCtxGC(&self, ctx: root::HDC) -> *mut ::std::os::raw::c_void {
         match self.pointers.SWELL_GetCtxGC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetCtxGC)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetCtxGC)),
             Some(f) => f(ctx),
         }
     }
@@ -5659,10 +4960,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetCtxFrameBuffer(&self, ctx: root::HDC) -> *mut ::std::os::raw::c_void {
         match self.pointers.SWELL_GetCtxFrameBuffer {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetCtxFrameBuffer)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetCtxFrameBuffer)),
             Some(f) => f(ctx),
         }
     }
@@ -5672,10 +4970,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_PushClipRegion(&self, ctx: root::HDC) {
         match self.pointers.SWELL_PushClipRegion {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_PushClipRegion)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_PushClipRegion)),
             Some(f) => f(ctx),
         }
     }
@@ -5685,10 +4980,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetClipRegion(&self, ctx: root::HDC, r: *const root::RECT) {
         match self.pointers.SWELL_SetClipRegion {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetClipRegion)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetClipRegion)),
             Some(f) => f(ctx, r),
         }
     }
@@ -5698,10 +4990,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_PopClipRegion(&self, ctx: root::HDC) {
         match self.pointers.SWELL_PopClipRegion {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_PopClipRegion)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_PopClipRegion)),
             Some(f) => f(ctx),
         }
     }
@@ -5711,10 +5000,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CreateFontIndirect(&self, arg1: *mut root::LOGFONT) -> root::HFONT {
         match self.pointers.CreateFontIndirect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateFontIndirect)
-            ),
+            None => crate::function_not_available(stringify!(CreateFontIndirect)),
             Some(f) => f(arg1),
         }
     }
@@ -5740,10 +5026,7 @@ impl Swell {
         lfFaceName: *const ::std::os::raw::c_char,
     ) -> root::HFONT {
         match self.pointers.CreateFont {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateFont)
-            ),
+            None => crate::function_not_available(stringify!(CreateFont)),
             Some(f) => f(
                 lfHeight,
                 lfWidth,
@@ -5770,20 +5053,14 @@ impl Swell {
         col: ::std::os::raw::c_int,
     ) -> root::HPEN {
         match self.pointers.CreatePen {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreatePen)
-            ),
+            None => crate::function_not_available(stringify!(CreatePen)),
             Some(f) => f(attr, wid, col),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn CreateSolidBrush(&self, col: ::std::os::raw::c_int) -> root::HBRUSH {
         match self.pointers.CreateSolidBrush {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateSolidBrush)
-            ),
+            None => crate::function_not_available(stringify!(CreateSolidBrush)),
             Some(f) => f(col),
         }
     }
@@ -5796,20 +5073,14 @@ impl Swell {
         alpha: f32,
     ) -> root::HPEN {
         match self.pointers.CreatePenAlpha {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreatePenAlpha)
-            ),
+            None => crate::function_not_available(stringify!(CreatePenAlpha)),
             Some(f) => f(attr, wid, col, alpha),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn CreateSolidBrushAlpha(&self, col: ::std::os::raw::c_int, alpha: f32) -> root::HBRUSH {
         match self.pointers.CreateSolidBrushAlpha {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateSolidBrushAlpha)
-            ),
+            None => crate::function_not_available(stringify!(CreateSolidBrushAlpha)),
             Some(f) => f(col, alpha),
         }
     }
@@ -5819,20 +5090,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SelectObject(&self, ctx: root::HDC, pen: root::HGDIOBJ) -> root::HGDIOBJ {
         match self.pointers.SelectObject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SelectObject)
-            ),
+            None => crate::function_not_available(stringify!(SelectObject)),
             Some(f) => f(ctx, pen),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetStockObject(&self, wh: ::std::os::raw::c_int) -> root::HGDIOBJ {
         match self.pointers.GetStockObject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetStockObject)
-            ),
+            None => crate::function_not_available(stringify!(GetStockObject)),
             Some(f) => f(wh),
         }
     }
@@ -5842,10 +5107,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DeleteObject(&self, arg1: root::HGDIOBJ) {
         match self.pointers.DeleteObject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DeleteObject)
-            ),
+            None => crate::function_not_available(stringify!(DeleteObject)),
             Some(f) => f(arg1),
         }
     }
@@ -5855,10 +5117,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_FillRect(&self, ctx: root::HDC, r: *const root::RECT, br: root::HBRUSH) {
         match self.pointers.SWELL_FillRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_FillRect)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_FillRect)),
             Some(f) => f(ctx, r, br),
         }
     }
@@ -5875,10 +5134,7 @@ impl Swell {
         b: ::std::os::raw::c_int,
     ) {
         match self.pointers.Rectangle {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Rectangle)
-            ),
+            None => crate::function_not_available(stringify!(Rectangle)),
             Some(f) => f(ctx, l, t, r, b),
         }
     }
@@ -5895,10 +5151,7 @@ impl Swell {
         b: ::std::os::raw::c_int,
     ) {
         match self.pointers.Ellipse {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Ellipse)
-            ),
+            None => crate::function_not_available(stringify!(Ellipse)),
             Some(f) => f(ctx, l, t, r, b),
         }
     }
@@ -5913,10 +5166,7 @@ impl Swell {
         npts: ::std::os::raw::c_int,
     ) {
         match self.pointers.SWELL_Polygon {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_Polygon)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_Polygon)),
             Some(f) => f(ctx, pts, npts),
         }
     }
@@ -5932,10 +5182,7 @@ impl Swell {
         op: *mut root::POINT,
     ) {
         match self.pointers.MoveToEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(MoveToEx)
-            ),
+            None => crate::function_not_available(stringify!(MoveToEx)),
             Some(f) => f(ctx, x, y, op),
         }
     }
@@ -5950,10 +5197,7 @@ impl Swell {
         y: ::std::os::raw::c_int,
     ) {
         match self.pointers.SWELL_LineTo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_LineTo)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_LineTo)),
             Some(f) => f(ctx, x, y),
         }
     }
@@ -5969,10 +5213,7 @@ impl Swell {
         c: ::std::os::raw::c_int,
     ) {
         match self.pointers.SWELL_SetPixel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetPixel)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetPixel)),
             Some(f) => f(ctx, x, y, c),
         }
     }
@@ -5987,10 +5228,7 @@ impl Swell {
         np: ::std::os::raw::c_int,
     ) {
         match self.pointers.PolyBezierTo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PolyBezierTo)
-            ),
+            None => crate::function_not_available(stringify!(PolyBezierTo)),
             Some(f) => f(ctx, pts, np),
         }
     }
@@ -6007,10 +5245,7 @@ impl Swell {
         align: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_DrawText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_DrawText)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_DrawText)),
             Some(f) => f(ctx, buf, len, r, align),
         }
     }
@@ -6020,10 +5255,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetTextColor(&self, ctx: root::HDC, col: ::std::os::raw::c_int) {
         match self.pointers.SetTextColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetTextColor)
-            ),
+            None => crate::function_not_available(stringify!(SetTextColor)),
             Some(f) => f(ctx, col),
         }
     }
@@ -6033,10 +5265,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTextColor(&self, ctx: root::HDC) -> ::std::os::raw::c_int {
         match self.pointers.GetTextColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTextColor)
-            ),
+            None => crate::function_not_available(stringify!(GetTextColor)),
             Some(f) => f(ctx),
         }
     }
@@ -6046,10 +5275,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetBkColor(&self, ctx: root::HDC, col: ::std::os::raw::c_int) {
         match self.pointers.SetBkColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetBkColor)
-            ),
+            None => crate::function_not_available(stringify!(SetBkColor)),
             Some(f) => f(ctx, col),
         }
     }
@@ -6059,10 +5285,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetBkMode(&self, ctx: root::HDC, col: ::std::os::raw::c_int) {
         match self.pointers.SetBkMode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetBkMode)
-            ),
+            None => crate::function_not_available(stringify!(SetBkMode)),
             Some(f) => f(ctx, col),
         }
     }
@@ -6079,10 +5302,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetGlyphIndicesW {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetGlyphIndicesW)
-            ),
+            None => crate::function_not_available(stringify!(GetGlyphIndicesW)),
             Some(f) => f(ctx, buf, len, indices, flags),
         }
     }
@@ -6101,10 +5321,7 @@ impl Swell {
         yrnd: ::std::os::raw::c_int,
     ) {
         match self.pointers.RoundRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RoundRect)
-            ),
+            None => crate::function_not_available(stringify!(RoundRect)),
             Some(f) => f(ctx, x, y, x2, y2, xrnd, yrnd),
         }
     }
@@ -6120,10 +5337,7 @@ impl Swell {
         nseg: ::std::os::raw::c_int,
     ) {
         match self.pointers.PolyPolyline {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PolyPolyline)
-            ),
+            None => crate::function_not_available(stringify!(PolyPolyline)),
             Some(f) => f(ctx, pts, cnts, nseg),
         }
     }
@@ -6133,10 +5347,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTextMetrics(&self, ctx: root::HDC, tm: *mut root::TEXTMETRIC) -> root::BOOL {
         match self.pointers.GetTextMetrics {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTextMetrics)
-            ),
+            None => crate::function_not_available(stringify!(GetTextMetrics)),
             Some(f) => f(ctx, tm),
         }
     }
@@ -6151,10 +5362,7 @@ impl Swell {
         lpFaceName: root::LPTSTR,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetTextFace {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTextFace)
-            ),
+            None => crate::function_not_available(stringify!(GetTextFace)),
             Some(f) => f(ctx, nCount, lpFaceName),
         }
     }
@@ -6169,10 +5377,7 @@ impl Swell {
         _bm: *mut ::std::os::raw::c_void,
     ) -> root::BOOL {
         match self.pointers.GetObject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetObject)
-            ),
+            None => crate::function_not_available(stringify!(GetObject)),
             Some(f) => f(icon, bmsz, _bm),
         }
     }
@@ -6182,10 +5387,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn CreateIconIndirect(&self, iconinfo: *mut root::ICONINFO) -> root::HICON {
         match self.pointers.CreateIconIndirect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateIconIndirect)
-            ),
+            None => crate::function_not_available(stringify!(CreateIconIndirect)),
             Some(f) => f(iconinfo),
         }
     }
@@ -6199,10 +5401,7 @@ impl Swell {
         alphaFromMask: bool,
     ) -> root::HICON {
         match self.pointers.LoadNamedImage {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(LoadNamedImage)
-            ),
+            None => crate::function_not_available(stringify!(LoadNamedImage)),
             Some(f) => f(name, alphaFromMask),
         }
     }
@@ -6212,10 +5411,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DrawImageInRect(&self, ctx: root::HDC, img: root::HICON, r: *const root::RECT) {
         match self.pointers.DrawImageInRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DrawImageInRect)
-            ),
+            None => crate::function_not_available(stringify!(DrawImageInRect)),
             Some(f) => f(ctx, img, r),
         }
     }
@@ -6236,10 +5432,7 @@ impl Swell {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.BitBlt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BitBlt)
-            ),
+            None => crate::function_not_available(stringify!(BitBlt)),
             Some(f) => f(hdcOut, x, y, w, h, hdcIn, xin, yin, mode),
         }
     }
@@ -6262,10 +5455,7 @@ impl Swell {
         mode: ::std::os::raw::c_int,
     ) {
         match self.pointers.StretchBlt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(StretchBlt)
-            ),
+            None => crate::function_not_available(stringify!(StretchBlt)),
             Some(f) => f(hdcOut, x, y, w, h, hdcIn, xin, yin, srcw, srch, mode),
         }
     }
@@ -6286,20 +5476,14 @@ impl Swell {
         srcspan: ::std::os::raw::c_int,
     ) {
         match self.pointers.StretchBltFromMem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(StretchBltFromMem)
-            ),
+            None => crate::function_not_available(stringify!(StretchBltFromMem)),
             Some(f) => f(hdcOut, x, y, w, h, bits, srcw, srch, srcspan),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_GetScaling256(&self) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_GetScaling256 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetScaling256)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetScaling256)),
             Some(f) => f(),
         }
     }
@@ -6313,20 +5497,14 @@ impl Swell {
         v: *mut ::std::os::raw::c_void,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.SWELL_ExtendedAPI {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_ExtendedAPI)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_ExtendedAPI)),
             Some(f) => f(key, v),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetSysColor(&self, idx: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
         match self.pointers.GetSysColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSysColor)
-            ),
+            None => crate::function_not_available(stringify!(GetSysColor)),
             Some(f) => f(idx),
         }
     }
@@ -6343,10 +5521,7 @@ impl Swell {
         bits: *mut ::std::os::raw::c_uchar,
     ) -> root::HBITMAP {
         match self.pointers.CreateBitmap {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CreateBitmap)
-            ),
+            None => crate::function_not_available(stringify!(CreateBitmap)),
             Some(f) => f(width, height, numplanes, bitsperpixel, bits),
         }
     }
@@ -6356,10 +5531,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetOpaque(&self, h: root::HWND, isopaque: bool) {
         match self.pointers.SetOpaque {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetOpaque)
-            ),
+            None => crate::function_not_available(stringify!(SetOpaque)),
             Some(f) => f(h, isopaque),
         }
     }
@@ -6369,10 +5541,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SetAllowNoMiddleManRendering(&self, h: root::HWND, allow: bool) {
         match self.pointers.SetAllowNoMiddleManRendering {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetAllowNoMiddleManRendering)
-            ),
+            None => crate::function_not_available(stringify!(SetAllowNoMiddleManRendering)),
             Some(f) => f(h, allow),
         }
     }
@@ -6382,10 +5551,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetViewGL(&self, h: root::HWND, wantGL: ::std::os::raw::c_char) {
         match self.pointers.SWELL_SetViewGL {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetViewGL)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetViewGL)),
             Some(f) => f(h, wantGL),
         }
     }
@@ -6395,10 +5561,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetViewGL(&self, h: root::HWND) -> bool {
         match self.pointers.SWELL_GetViewGL {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetViewGL)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetViewGL)),
             Some(f) => f(h),
         }
     }
@@ -6408,10 +5571,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetGLContextToView(&self, h: root::HWND) -> bool {
         match self.pointers.SWELL_SetGLContextToView {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetGLContextToView)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetGLContextToView)),
             Some(f) => f(h),
         }
     }
@@ -6421,10 +5581,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn BeginPaint(&self, arg1: root::HWND, arg2: *mut root::PAINTSTRUCT) -> root::HDC {
         match self.pointers.BeginPaint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BeginPaint)
-            ),
+            None => crate::function_not_available(stringify!(BeginPaint)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -6434,10 +5591,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn EndPaint(&self, arg1: root::HWND, arg2: *mut root::PAINTSTRUCT) -> root::BOOL {
         match self.pointers.EndPaint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EndPaint)
-            ),
+            None => crate::function_not_available(stringify!(EndPaint)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -6447,10 +5601,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetDC(&self, arg1: root::HWND) -> root::HDC {
         match self.pointers.GetDC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetDC)
-            ),
+            None => crate::function_not_available(stringify!(GetDC)),
             Some(f) => f(arg1),
         }
     }
@@ -6460,10 +5611,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetWindowDC(&self, arg1: root::HWND) -> root::HDC {
         match self.pointers.GetWindowDC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetWindowDC)
-            ),
+            None => crate::function_not_available(stringify!(GetWindowDC)),
             Some(f) => f(arg1),
         }
     }
@@ -6473,10 +5621,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn ReleaseDC(&self, arg1: root::HWND, arg2: root::HDC) {
         match self.pointers.ReleaseDC {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ReleaseDC)
-            ),
+            None => crate::function_not_available(stringify!(ReleaseDC)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -6491,10 +5636,7 @@ impl Swell {
         level: ::std::os::raw::c_int,
     ) {
         match self.pointers.SWELL_FillDialogBackground {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_FillDialogBackground)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_FillDialogBackground)),
             Some(f) => f(hdc, r, level),
         }
     }
@@ -6504,20 +5646,14 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_CloneGDIObject(&self, a: root::HGDIOBJ) -> root::HGDIOBJ {
         match self.pointers.SWELL_CloneGDIObject {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_CloneGDIObject)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_CloneGDIObject)),
             Some(f) => f(a),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn GetSystemMetrics(&self, arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
         match self.pointers.GetSystemMetrics {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetSystemMetrics)
-            ),
+            None => crate::function_not_available(stringify!(GetSystemMetrics)),
             Some(f) => f(arg1),
         }
     }
@@ -6527,10 +5663,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DragQueryPoint(&self, arg1: root::HDROP, arg2: root::LPPOINT) -> root::BOOL {
         match self.pointers.DragQueryPoint {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DragQueryPoint)
-            ),
+            None => crate::function_not_available(stringify!(DragQueryPoint)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -6540,10 +5673,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn DragFinish(&self, arg1: root::HDROP) {
         match self.pointers.DragFinish {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DragFinish)
-            ),
+            None => crate::function_not_available(stringify!(DragFinish)),
             Some(f) => f(arg1),
         }
     }
@@ -6559,10 +5689,7 @@ impl Swell {
         arg4: root::UINT,
     ) -> root::UINT {
         match self.pointers.DragQueryFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(DragQueryFile)
-            ),
+            None => crate::function_not_available(stringify!(DragQueryFile)),
             Some(f) => f(arg1, arg2, arg3, arg4),
         }
     }
@@ -6580,10 +5707,7 @@ impl Swell {
         >,
     ) {
         match self.pointers.SWELL_InitiateDragDrop {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_InitiateDragDrop)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_InitiateDragDrop)),
             Some(f) => f(arg1, srcrect, srcfn, callback),
         }
     }
@@ -6600,20 +5724,14 @@ impl Swell {
         icon: root::HICON,
     ) {
         match self.pointers.SWELL_InitiateDragDropOfFileList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_InitiateDragDropOfFileList)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_InitiateDragDropOfFileList)),
             Some(f) => f(arg1, srcrect, srclist, srccount, icon),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_FinishDragDrop(&self) {
         match self.pointers.SWELL_FinishDragDrop {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_FinishDragDrop)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_FinishDragDrop)),
             Some(f) => f(),
         }
     }
@@ -6628,10 +5746,7 @@ impl Swell {
         handle: *mut *mut ::std::os::raw::c_void,
     ) {
         match self.pointers.SWELL_DrawFocusRect {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_DrawFocusRect)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_DrawFocusRect)),
             Some(f) => f(hwndPar, rct, handle),
         }
     }
@@ -6650,10 +5765,7 @@ impl Swell {
         dosizetofit: bool,
     ) {
         match self.pointers.SWELL_MakeSetCurParms {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeSetCurParms)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeSetCurParms)),
             Some(f) => f(xscale, yscale, xtrans, ytrans, parent, doauto, dosizetofit),
         }
     }
@@ -6673,10 +5785,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeButton)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeButton)),
             Some(f) => f(def, label, idx, x, y, w, h, flags),
         }
     }
@@ -6691,10 +5800,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeEditField {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeEditField)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeEditField)),
             Some(f) => f(idx, x, y, w, h, flags),
         }
     }
@@ -6714,10 +5820,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeLabel {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeLabel)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeLabel)),
             Some(f) => f(align, label, idx, x, y, w, h, flags),
         }
     }
@@ -6738,10 +5841,7 @@ impl Swell {
         exstyle: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeControl {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeControl)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeControl)),
             Some(f) => f(cname, idx, classname, style, x, y, w, h, exstyle),
         }
     }
@@ -6756,10 +5856,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeCombo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeCombo)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeCombo)),
             Some(f) => f(idx, x, y, w, h, flags),
         }
     }
@@ -6778,10 +5875,7 @@ impl Swell {
         style: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeGroupBox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeGroupBox)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeGroupBox)),
             Some(f) => f(name, idx, x, y, w, h, style),
         }
     }
@@ -6800,10 +5894,7 @@ impl Swell {
         flags: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeCheckBox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeCheckBox)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeCheckBox)),
             Some(f) => f(name, idx, x, y, w, h, flags),
         }
     }
@@ -6818,10 +5909,7 @@ impl Swell {
         styles: ::std::os::raw::c_int,
     ) -> root::HWND {
         match self.pointers.SWELL_MakeListBox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_MakeListBox)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_MakeListBox)),
             Some(f) => f(idx, x, y, w, h, styles),
         }
     }
@@ -6837,10 +5925,7 @@ impl Swell {
         flags: ::std::os::raw::c_uint,
     ) {
         match self.pointers.SWELL_Menu_AddMenuItem {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_Menu_AddMenuItem)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_Menu_AddMenuItem)),
             Some(f) => f(hMenu, name, idx, flags),
         }
     }
@@ -6855,10 +5940,7 @@ impl Swell {
         listsz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_GenerateMenuFromList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GenerateMenuFromList)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GenerateMenuFromList)),
             Some(f) => f(hMenu, list, listsz),
         }
     }
@@ -6872,10 +5954,7 @@ impl Swell {
         listsz: ::std::os::raw::c_int,
     ) {
         match self.pointers.SWELL_GenerateDialogFromList {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GenerateDialogFromList)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GenerateDialogFromList)),
             Some(f) => f(list, listsz),
         }
     }
@@ -6886,20 +5965,14 @@ impl Swell {
         mask: ::std::os::raw::c_uint,
     ) -> ::std::os::raw::c_uint {
         match self.pointers._controlfp {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(_controlfp)
-            ),
+            None => crate::function_not_available(stringify!(_controlfp)),
             Some(f) => f(flag, mask),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_Internal_PostMessage_Init(&self) {
         match self.pointers.SWELL_Internal_PostMessage_Init {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_Internal_PostMessage_Init)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_Internal_PostMessage_Init)),
             Some(f) => f(),
         }
     }
@@ -6912,10 +5985,7 @@ impl Swell {
         fn_: *const ::std::os::raw::c_char,
     ) -> root::HCURSOR {
         match self.pointers.SWELL_LoadCursorFromFile {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_LoadCursorFromFile)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_LoadCursorFromFile)),
             Some(f) => f(fn_),
         }
     }
@@ -6925,10 +5995,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetWindowWantRaiseAmt(&self, h: root::HWND, amt: ::std::os::raw::c_int) {
         match self.pointers.SWELL_SetWindowWantRaiseAmt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetWindowWantRaiseAmt)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetWindowWantRaiseAmt)),
             Some(f) => f(h, amt),
         }
     }
@@ -6938,10 +6005,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetWindowWantRaiseAmt(&self, arg1: root::HWND) -> ::std::os::raw::c_int {
         match self.pointers.SWELL_GetWindowWantRaiseAmt {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetWindowWantRaiseAmt)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetWindowWantRaiseAmt)),
             Some(f) => f(arg1),
         }
     }
@@ -6955,10 +6019,7 @@ impl Swell {
         mask: ::std::os::raw::c_int,
     ) {
         match self.pointers.SWELL_SetListViewFastClickMask {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetListViewFastClickMask)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetListViewFastClickMask)),
             Some(f) => f(hList, mask),
         }
     }
@@ -6968,10 +6029,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn GetTempPath(&self, sz: ::std::os::raw::c_int, buf: *mut ::std::os::raw::c_char) {
         match self.pointers.GetTempPath {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetTempPath)
-            ),
+            None => crate::function_not_available(stringify!(GetTempPath)),
             Some(f) => f(sz, buf),
         }
     }
@@ -6985,20 +6043,14 @@ impl Swell {
         argv: *mut *mut *mut ::std::os::raw::c_char,
     ) {
         match self.pointers.SWELL_initargs {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_initargs)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_initargs)),
             Some(f) => f(argc, argv),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_RunMessageLoop(&self) {
         match self.pointers.SWELL_RunMessageLoop {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_RunMessageLoop)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_RunMessageLoop)),
             Some(f) => f(),
         }
     }
@@ -7013,10 +6065,7 @@ impl Swell {
         arg1: *const root::RECT,
     ) -> root::HWND {
         match self.pointers.SWELL_CreateXBridgeWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_CreateXBridgeWindow)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_CreateXBridgeWindow)),
             Some(f) => f(viewpar, wref, arg1),
         }
     }
@@ -7030,10 +6079,7 @@ impl Swell {
         type_: *const ::std::os::raw::c_char,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.SWELL_GetOSWindow {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetOSWindow)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetOSWindow)),
             Some(f) => f(hwnd, type_),
         }
     }
@@ -7046,10 +6092,7 @@ impl Swell {
         type_: *const ::std::os::raw::c_char,
     ) -> *mut ::std::os::raw::c_void {
         match self.pointers.SWELL_GetOSEvent {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetOSEvent)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetOSEvent)),
             Some(f) => f(type_),
         }
     }
@@ -7059,10 +6102,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GenerateGUID(&self, g: *mut ::std::os::raw::c_void) -> bool {
         match self.pointers.SWELL_GenerateGUID {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GenerateGUID)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GenerateGUID)),
             Some(f) => f(g),
         }
     }
@@ -7079,10 +6119,7 @@ impl Swell {
         lParam: root::LPARAM,
     ) -> root::BOOL {
         match self.pointers.EnumChildWindows {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumChildWindows)
-            ),
+            None => crate::function_not_available(stringify!(EnumChildWindows)),
             Some(f) => f(hwnd, cwEnumFunc, lParam),
         }
     }
@@ -7092,10 +6129,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_IsGroupBox(&self, arg1: root::HWND) -> root::BOOL {
         match self.pointers.SWELL_IsGroupBox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_IsGroupBox)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_IsGroupBox)),
             Some(f) => f(arg1),
         }
     }
@@ -7105,10 +6139,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_IsButton(&self, arg1: root::HWND) -> root::BOOL {
         match self.pointers.SWELL_IsButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_IsButton)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_IsButton)),
             Some(f) => f(arg1),
         }
     }
@@ -7118,10 +6149,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_IsStaticText(&self, arg1: root::HWND) -> root::BOOL {
         match self.pointers.SWELL_IsStaticText {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_IsStaticText)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_IsStaticText)),
             Some(f) => f(arg1),
         }
     }
@@ -7131,10 +6159,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_GetDesiredControlSize(&self, hwnd: root::HWND, r: *mut root::RECT) {
         match self.pointers.SWELL_GetDesiredControlSize {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_GetDesiredControlSize)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_GetDesiredControlSize)),
             Some(f) => f(hwnd, r),
         }
     }
@@ -7149,10 +6174,7 @@ impl Swell {
         pdv: *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int {
         match self.pointers.AddFontResourceEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(AddFontResourceEx)
-            ),
+            None => crate::function_not_available(stringify!(AddFontResourceEx)),
             Some(f) => f(str, fl, pdv),
         }
     }
@@ -7168,10 +6190,7 @@ impl Swell {
         hotspot_y: ::std::os::raw::c_int,
     ) {
         match self.pointers.SWELL_Register_Cursor_Resource {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_Register_Cursor_Resource)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_Register_Cursor_Resource)),
             Some(f) => f(idx, name, hotspot_x, hotspot_y),
         }
     }
@@ -7187,10 +6206,7 @@ impl Swell {
         custom: *mut root::COLORREF,
     ) -> bool {
         match self.pointers.SWELL_ChooseColor {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_ChooseColor)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_ChooseColor)),
             Some(f) => f(arg1, arg2, ncustom, custom),
         }
     }
@@ -7200,10 +6216,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_ChooseFont(&self, arg1: root::HWND, arg2: *mut root::LOGFONT) -> bool {
         match self.pointers.SWELL_ChooseFont {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_ChooseFont)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_ChooseFont)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -7213,10 +6226,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn IsWindowEnabled(&self, arg1: root::HWND) -> bool {
         match self.pointers.IsWindowEnabled {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(IsWindowEnabled)
-            ),
+            None => crate::function_not_available(stringify!(IsWindowEnabled)),
             Some(f) => f(arg1),
         }
     }
@@ -7231,10 +6241,7 @@ impl Swell {
         arg3: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int {
         match self.pointers.GetClassName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetClassName)
-            ),
+            None => crate::function_not_available(stringify!(GetClassName)),
             Some(f) => f(arg1, arg2, arg3),
         }
     }
@@ -7244,10 +6251,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_SetClassName(&self, arg1: root::HWND, arg2: *const ::std::os::raw::c_char) {
         match self.pointers.SWELL_SetClassName {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_SetClassName)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_SetClassName)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -7257,10 +6261,7 @@ impl Swell {
     #[doc = r" REAPER can crash if you pass an invalid pointer."]
     pub unsafe fn SWELL_DisableContextMenu(&self, arg1: root::HWND, arg2: bool) {
         match self.pointers.SWELL_DisableContextMenu {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_DisableContextMenu)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_DisableContextMenu)),
             Some(f) => f(arg1, arg2),
         }
     }
@@ -7276,10 +6277,7 @@ impl Swell {
         arg4: root::LPARAM,
     ) -> root::BOOL {
         match self.pointers.EnumDisplayMonitors {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(EnumDisplayMonitors)
-            ),
+            None => crate::function_not_available(stringify!(EnumDisplayMonitors)),
             Some(f) => f(arg1, arg2, arg3, arg4),
         }
     }
@@ -7293,20 +6291,14 @@ impl Swell {
         arg2: *mut ::std::os::raw::c_void,
     ) -> root::BOOL {
         match self.pointers.GetMonitorInfo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(GetMonitorInfo)
-            ),
+            None => crate::function_not_available(stringify!(GetMonitorInfo)),
             Some(f) => f(arg1, arg2),
         }
     }
     #[cfg(target_family = "unix")]
     pub fn SWELL_osx_is_dark_mode(&self, mode: ::std::os::raw::c_int) -> bool {
         match self.pointers.SWELL_osx_is_dark_mode {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SWELL_osx_is_dark_mode)
-            ),
+            None => crate::function_not_available(stringify!(SWELL_osx_is_dark_mode)),
             Some(f) => f(mode),
         }
     }
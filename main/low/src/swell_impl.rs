@@ -111,6 +111,54 @@ impl Swell {
         }
     }
 
+    /// Creates a modal dialog and returns when it's closed (the result is the value passed to
+    /// `EndDialog`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn DialogBoxParam(
+        &self,
+        hinst: root::HINSTANCE,
+        resid: *const ::std::os::raw::c_char,
+        par: root::HWND,
+        dlgproc: root::DLGPROC,
+        param: root::LPARAM,
+    ) -> ::std::os::raw::c_int {
+        #[cfg(target_family = "unix")]
+        {
+            self.SWELL_DialogBox(
+                root::SWELL_curmodule_dialogresource_head,
+                resid,
+                par,
+                dlgproc,
+                param,
+            )
+        }
+        #[cfg(target_family = "windows")]
+        #[allow(clippy::cast_ptr_alignment)]
+        {
+            // See CreateDialogParam regarding the calling convention of dlgproc.
+            winapi::um::winuser::DialogBoxParamW(
+                hinst as _,
+                resid as _,
+                par as _,
+                std::mem::transmute::<
+                    Option<unsafe extern "C" fn(root::HWND, u32, usize, isize) -> isize>,
+                    Option<
+                        unsafe extern "system" fn(
+                            *mut winapi::shared::windef::HWND__,
+                            u32,
+                            usize,
+                            isize,
+                        ) -> isize,
+                    >,
+                >(dlgproc),
+                param,
+            ) as _
+        }
+    }
+
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid pointer.
@@ -557,6 +605,192 @@ impl Swell {
         len as _
     }
 
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn SetDlgItemText(
+        &self,
+        arg1: root::HWND,
+        idx: ::std::os::raw::c_int,
+        text: *const ::std::os::raw::c_char,
+    ) -> root::BOOL {
+        let utf16_string = utf8_to_16(text);
+        let result = winapi::um::winuser::SetDlgItemTextW(arg1 as _, idx, utf16_string.as_ptr());
+        std::mem::drop(utf16_string);
+        result as _
+    }
+
+    /// Attention: Whereas the Windows original returns a length, this just returns success (in
+    /// order to conform to SWELL).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn GetDlgItemText(
+        &self,
+        arg1: root::HWND,
+        idx: ::std::os::raw::c_int,
+        text: *mut ::std::os::raw::c_char,
+        textlen: ::std::os::raw::c_int,
+    ) -> root::BOOL {
+        let len = with_utf16_to_8(text, textlen, |buffer, max_size| {
+            winapi::um::winuser::GetDlgItemTextW(arg1 as _, idx, buffer, max_size) as _
+        });
+        if len == 0 {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Only `mask`, `fmt`, `cx`, `pszText` and `iSubItem` are taken into account.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn ListView_InsertColumn(
+        &self,
+        h: root::HWND,
+        pos: ::std::os::raw::c_int,
+        lvc: *const root::LVCOLUMN,
+    ) {
+        use winapi::um::commctrl;
+        let lvc = &*lvc;
+        let utf16_string = if lvc.pszText.is_null() {
+            None
+        } else {
+            Some(utf8_to_16(lvc.pszText))
+        };
+        let mut utf16_lvc: commctrl::LVCOLUMNW = std::mem::zeroed();
+        utf16_lvc.mask = lvc.mask as _;
+        utf16_lvc.fmt = lvc.fmt;
+        utf16_lvc.cx = lvc.cx;
+        utf16_lvc.iSubItem = lvc.iSubItem;
+        if let Some(s) = &utf16_string {
+            utf16_lvc.pszText = s.as_ptr() as _;
+        }
+        winapi::um::winuser::SendMessageW(
+            h as _,
+            commctrl::LVM_INSERTCOLUMNW,
+            pos as _,
+            &utf16_lvc as *const _ as _,
+        );
+    }
+
+    /// Only `mask`, `iItem`, `iSubItem`, `pszText`, `iImage` and `lParam` are taken into
+    /// account. Item states are not supported because their values differ between SWELL and
+    /// Windows.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn ListView_InsertItem(
+        &self,
+        h: root::HWND,
+        item: *const root::LVITEM,
+    ) -> ::std::os::raw::c_int {
+        use winapi::um::commctrl;
+        let item = &*item;
+        let utf16_string = if item.pszText.is_null() {
+            None
+        } else {
+            Some(utf8_to_16(item.pszText))
+        };
+        let mut utf16_item: commctrl::LVITEMW = std::mem::zeroed();
+        utf16_item.mask = (item.mask as u32) & !commctrl::LVIF_STATE;
+        utf16_item.iItem = item.iItem;
+        utf16_item.iSubItem = item.iSubItem;
+        utf16_item.iImage = item.iImage;
+        utf16_item.lParam = item.lParam;
+        if let Some(s) = &utf16_string {
+            utf16_item.pszText = s.as_ptr() as _;
+        }
+        winapi::um::winuser::SendMessageW(
+            h as _,
+            commctrl::LVM_INSERTITEMW,
+            0,
+            &utf16_item as *const _ as _,
+        ) as _
+    }
+
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn ListView_SetItemText(
+        &self,
+        h: root::HWND,
+        ipos: ::std::os::raw::c_int,
+        cpos: ::std::os::raw::c_int,
+        txt: *const ::std::os::raw::c_char,
+    ) {
+        use winapi::um::commctrl;
+        let utf16_string = utf8_to_16(txt);
+        let mut utf16_item: commctrl::LVITEMW = std::mem::zeroed();
+        utf16_item.iSubItem = cpos;
+        utf16_item.pszText = utf16_string.as_ptr() as _;
+        winapi::um::winuser::SendMessageW(
+            h as _,
+            commctrl::LVM_SETITEMTEXTW,
+            ipos as _,
+            &utf16_item as *const _ as _,
+        );
+    }
+
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn ListView_GetItemText(
+        &self,
+        hwnd: root::HWND,
+        item: ::std::os::raw::c_int,
+        subitem: ::std::os::raw::c_int,
+        text: *mut ::std::os::raw::c_char,
+        textmax: ::std::os::raw::c_int,
+    ) {
+        use winapi::um::commctrl;
+        let len = with_utf16_to_8(text, textmax, |buffer, max_size| {
+            let mut utf16_item: commctrl::LVITEMW = std::mem::zeroed();
+            utf16_item.iSubItem = subitem;
+            utf16_item.pszText = buffer;
+            utf16_item.cchTextMax = max_size;
+            winapi::um::winuser::SendMessageW(
+                hwnd as _,
+                commctrl::LVM_GETITEMTEXTW,
+                item as _,
+                &mut utf16_item as *mut _ as _,
+            ) as _
+        });
+        if len == 0 && textmax > 0 {
+            *text = 0;
+        }
+    }
+
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn ListView_DeleteItem(&self, h: root::HWND, ipos: ::std::os::raw::c_int) {
+        winapi::um::winuser::SendMessageW(
+            h as _,
+            winapi::um::commctrl::LVM_DELETEITEM,
+            ipos as _,
+            0,
+        );
+    }
+
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn ListView_DeleteAllItems(&self, h: root::HWND) {
+        winapi::um::winuser::SendMessageW(h as _, winapi::um::commctrl::LVM_DELETEALLITEMS, 0, 0);
+    }
+
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid pointer.
+    pub unsafe fn ListView_GetItemCount(&self, h: root::HWND) -> ::std::os::raw::c_int {
+        winapi::um::winuser::SendMessageW(h as _, winapi::um::commctrl::LVM_GETITEMCOUNT, 0, 0) as _
+    }
+
     /// On Windows this is a constant but in SWELL this is a macro which translates to a function
     /// call.
     pub fn CF_TEXT(&self) -> root::UINT {
@@ -638,8 +872,15 @@ pub(crate) unsafe fn with_utf16_to_8(
 #[cfg(target_family = "windows")]
 fn lparam_is_string(msg: root::UINT) -> bool {
     use crate::raw;
-    // There are probably more than just those two. Add as soon as needed.
-    matches!(msg, raw::CB_INSERTSTRING | raw::CB_ADDSTRING)
+    // There are probably more than just those. Add as soon as needed.
+    matches!(
+        msg,
+        raw::CB_INSERTSTRING
+            | raw::CB_ADDSTRING
+            | raw::CB_FINDSTRING
+            | raw::CB_FINDSTRINGEXACT
+            | raw::WM_SETTEXT
+    )
 }
 
 /// cbSize doesn't matter.
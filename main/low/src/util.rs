@@ -1,8 +1,12 @@
 use super::raw::{reaper_plugin_info_t, HINSTANCE};
 use super::PluginContext;
 use crate::StaticPluginContext;
+use derive_more::Display;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::Cell;
 use std::error::Error;
-use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::panic::{catch_unwind, AssertUnwindSafe, Location, PanicInfo};
 
 /// This function catches panics before they reach REAPER.
 ///
@@ -11,19 +15,144 @@ use std::panic::{catch_unwind, AssertUnwindSafe};
 /// order to prevent REAPER from crashing if a non-recoverable error occurs in the plug-in (a
 /// panic).
 ///
-/// Right now this doesn't do anything else than calling `catch_unwind()` but it might do more in
-/// future. Please note that logging is *not* supposed to be done here. It should be done in the
-/// panic hook instead.
+/// Panics are not reported here. Register a handler via [`set_panic_handler()`] in order to log
+/// them or inform the user. It will know that the panic is caught by the firewall.
 pub fn firewall<F: FnOnce() -> R, R>(f: F) -> Option<R> {
-    catch_unwind(AssertUnwindSafe(f)).ok()
+    FIREWALL_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    FIREWALL_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    result.ok()
 }
 
+thread_local! {
+    /// Number of nested [`firewall()`] calls in the current thread.
+    static FIREWALL_DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Registers a function which is called whenever a panic occurs, e.g. in order to show an error
+/// dialog or write a crash log.
+///
+/// The handler is called right when the panic occurs, before unwinding. So the stack of the
+/// panicking thread is still intact, which makes it the right place to capture a backtrace. The
+/// report contains one already if backtraces are enabled via environment variables.
+///
+/// This replaces the current panic hook (and with it the handler registered before, if any).
+pub fn set_panic_handler(handler: impl Fn(&PanicReport) + Send + Sync + 'static) {
+    std::panic::set_hook(Box::new(move |info| {
+        let report = PanicReport {
+            panic_info: info,
+            backtrace: Backtrace::capture(),
+            is_caught_by_firewall: FIREWALL_DEPTH.with(|depth| depth.get() > 0),
+        };
+        handler(&report);
+    }));
+}
+
+/// Information about a panic, passed to the handler registered via [`set_panic_handler()`].
+#[derive(Debug)]
+pub struct PanicReport<'a> {
+    pub panic_info: &'a PanicInfo<'a>,
+    /// Backtrace of the panicking thread.
+    ///
+    /// Captured via [`Backtrace::capture()`], so it's only available if enabled via the
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variable. Use
+    /// [`Backtrace::force_capture()`] in the handler if you always need one.
+    pub backtrace: Backtrace,
+    /// Whether the panic occurred within [`firewall()`], which means that it will be caught and
+    /// REAPER will continue to run.
+    pub is_caught_by_firewall: bool,
+}
+
+impl<'a> PanicReport<'a> {
+    /// Returns the panic payload, usually a `&str` or `String`.
+    pub fn payload(&self) -> &'a (dyn Any + Send) {
+        self.panic_info.payload()
+    }
+
+    /// Returns the location at which the panic occurred.
+    pub fn location(&self) -> Option<&'a Location<'a>> {
+        self.panic_info.location()
+    }
+
+    /// Returns the panic message if the payload is a string.
+    pub fn message(&self) -> Option<&'a str> {
+        let payload = self.payload();
+        match payload.downcast_ref::<&str>() {
+            Some(msg) => Some(msg),
+            None => payload.downcast_ref::<String>().map(String::as_str),
+        }
+    }
+}
+
+/// Executes the given function and returns an error instead of panicking if it attempts to call a
+/// REAPER function which is not available (e.g. because the user runs an older REAPER version).
+///
+/// This works across higher-level APIs as well, so you can wrap a whole sequence of calls and
+/// degrade gracefully if one of them is missing. Missing functions don't trigger the panic handler
+/// within `f`. Other panics are propagated as usual.
+///
+/// Please note that this relies on unwinding. It doesn't work with `panic = "abort"`.
+pub fn catch_function_not_available<R>(
+    f: impl FnOnce() -> R,
+) -> Result<R, FunctionNotAvailableError> {
+    AVAILABILITY_CHECK_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    AVAILABILITY_CHECK_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    match result {
+        Ok(r) => Ok(r),
+        Err(payload) => match payload.downcast::<FunctionNotAvailableError>() {
+            Ok(e) => Err(*e),
+            Err(payload) => std::panic::resume_unwind(payload),
+        },
+    }
+}
+
+thread_local! {
+    /// Number of nested [`catch_function_not_available()`] calls in the current thread.
+    static AVAILABILITY_CHECK_DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Reports an attempt to call the function with the given name although it has not been loaded.
+///
+/// This panics. Within [`catch_function_not_available()`], the panic is turned into a
+/// [`FunctionNotAvailableError`]. The function wrappers in this crate call this, so if you write
+/// your own wrappers around function pointers, you should call it as well.
+pub fn function_not_available(name: &'static str) -> ! {
+    if AVAILABILITY_CHECK_DEPTH.with(|depth| depth.get() > 0) {
+        // Unlike `panic!`, this doesn't invoke the panic hook
+        std::panic::resume_unwind(Box::new(FunctionNotAvailableError { name }));
+    }
+    panic!("Attempt to use a function that has not been loaded: {name}")
+}
+
+/// An error which occurs when attempting to call a REAPER function which is not available.
+///
+/// See [`catch_function_not_available()`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Display)]
+#[display(fmt = "REAPER function not available: {name}")]
+pub struct FunctionNotAvailableError {
+    name: &'static str,
+}
+
+impl FunctionNotAvailableError {
+    /// Returns the name of the missing function.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl Error for FunctionNotAvailableError {}
+
 /// This is a convenience function for bootstrapping extension plug-ins.
 ///
 /// This function basically translates the REAPER extension plug-in main entry point signature
 /// (`ReaperPluginEntry()`) to a typical Rust main entry point signature (`main()`). It is
 /// intended to be used by macros in the `reaper-macros` crate.
 ///
+/// REAPER calls the entry point a second time with a null `rec` when unloading the plug-in (e.g.
+/// on exit). In that case, the given `shutdown` function is called, if any. That's the right
+/// place to unregister control surfaces and hooks and to flush state.
+///
 /// # Safety
 ///
 /// REAPER can crash if you pass an invalid pointer.
@@ -32,10 +161,14 @@ pub unsafe fn bootstrap_extension_plugin(
     rec: *mut reaper_plugin_info_t,
     static_context: StaticPluginContext,
     init: fn(PluginContext) -> Result<(), Box<dyn Error>>,
+    shutdown: Option<fn()>,
 ) -> i32 {
     // TODO-low Log early errors
     firewall(|| {
         if rec.is_null() {
+            if let Some(shutdown) = shutdown {
+                shutdown();
+            }
             return 0;
         }
         let rec = *rec;
@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// Generates command ID constants from the given action list file.
+pub fn generate_command_ids(path: &syn::LitStr) -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = PathBuf::from(manifest_dir).join(path.value());
+    let content = std::fs::read_to_string(&full_path).map_err(|e| {
+        syn::Error::new(
+            path.span(),
+            format!("couldn't read action list {}: {e}", full_path.display()),
+        )
+    })?;
+    let mut used_names = HashSet::new();
+    let constants = parse_action_list(&content).map(|(id, description)| {
+        let mut name = constant_name(description);
+        if !used_names.insert(name.clone()) {
+            // Several actions can have the same description
+            name = format!("{name}_{id}");
+            used_names.insert(name.clone());
+        }
+        let ident = format_ident!("{}", name);
+        quote! {
+            #[doc = #description]
+            pub const #ident: ::reaper_medium::CommandId =
+                unsafe { ::reaper_medium::CommandId::new_unchecked(#id) };
+        }
+    });
+    let full_path = full_path.to_string_lossy().to_string();
+    let tokens = quote! {
+        // Makes Cargo recompile if the action list changes
+        const _: &[u8] = include_bytes!(#full_path);
+        #(#constants)*
+    };
+    Ok(tokens)
+}
+
+/// Returns the command ID and description of each native action in the main section.
+///
+/// Expects the format of REAPER's tab-separated action list export (`section`, `command ID`,
+/// `description`). The section column is optional. Custom actions and actions registered by
+/// extensions (which don't have a numeric ID) are skipped, as well as empty lines and comments
+/// starting with `#`.
+fn parse_action_list(content: &str) -> impl Iterator<Item = (u32, &str)> {
+    content.lines().filter_map(|line| {
+        if line.trim_start().starts_with('#') {
+            return None;
+        }
+        let columns: Vec<_> = line.split('\t').map(str::trim).collect();
+        let (id, description) = match columns.as_slice() {
+            [section, id, description, ..] if *section == "Main" => (*id, *description),
+            [id, description] => (*id, *description),
+            _ => return None,
+        };
+        let id: u32 = id.parse().ok().filter(|id| *id > 0)?;
+        if description.is_empty() {
+            return None;
+        }
+        Some((id, description))
+    })
+}
+
+/// Converts an action description such as `Transport: Play/stop` into a constant name such as
+/// `TRANSPORT_PLAY_STOP`.
+fn constant_name(description: &str) -> String {
+    let mut name = String::new();
+    for c in description.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_uppercase());
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    let name = name.trim_end_matches('_');
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("ACTION_{name}")
+    } else {
+        name.to_string()
+    }
+}
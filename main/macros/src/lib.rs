@@ -9,6 +9,8 @@ use darling::FromMeta;
 use proc_macro::TokenStream;
 use quote::quote;
 
+mod command_ids;
+
 /// Macro for easily bootstrapping a REAPER extension plug-in.
 ///
 /// Use the macro like this:
@@ -26,6 +28,24 @@ use quote::quote;
 /// }
 /// ```
 ///
+/// If you need to clean up when REAPER unloads the plug-in (e.g. on exit), pass the name of a
+/// shutdown function:
+///
+/// ```no_run
+/// use std::error::Error;
+/// use reaper_macros::reaper_extension_plugin;
+/// use reaper_low::PluginContext;
+///
+/// #[reaper_extension_plugin(shutdown = "plugin_shutdown")]
+/// fn plugin_main(context: PluginContext) -> Result<(), Box<dyn Error>> {
+///     Ok(())
+/// }
+///
+/// fn plugin_shutdown() {
+///     // Unregister control surfaces and hooks, flush state ...
+/// }
+/// ```
+///
 /// If you want to start with a preconfigured high-level `Reaper` instance right away, use the macro
 /// like this (please note that the high-level API has not been published yet):
 ///
@@ -64,14 +84,25 @@ pub fn reaper_extension_plugin(attr: TokenStream, input: TokenStream) -> TokenSt
         }
         1 => {
             // One function parameter. Must be a low-level plug-in.
-            generate_low_level_plugin_code(main_function)
+            generate_low_level_plugin_code(args, main_function)
         }
         _ => panic!("REAPER extension plugin function must have "),
     }
 }
 
-fn generate_low_level_plugin_code(main_function: syn::ItemFn) -> TokenStream {
+fn generate_low_level_plugin_code(
+    args: ReaperExtensionPluginMacroArgs,
+    main_function: syn::ItemFn,
+) -> TokenStream {
     let main_function_name = &main_function.sig.ident;
+    let shutdown_function = match args.shutdown {
+        None => quote! { None },
+        Some(name) => {
+            let path: syn::Path =
+                syn::parse_str(&name).expect("shutdown must be the name of a function");
+            quote! { Some(#path) }
+        }
+    };
     let tokens = quote! {
         reaper_low::dll_main!();
         reaper_low::swell_dll_main!();
@@ -82,7 +113,7 @@ fn generate_low_level_plugin_code(main_function: syn::ItemFn) -> TokenStream {
         #[no_mangle]
         unsafe extern "C" fn ReaperPluginEntry(h_instance: ::reaper_low::raw::HINSTANCE, rec: *mut ::reaper_low::raw::reaper_plugin_info_t) -> ::std::os::raw::c_int {
             let static_context = reaper_low::static_plugin_context();
-            ::reaper_low::bootstrap_extension_plugin(h_instance, rec, static_context, #main_function_name)
+            ::reaper_low::bootstrap_extension_plugin(h_instance, rec, static_context, #main_function_name, #shutdown_function)
         }
 
         #main_function
@@ -103,8 +134,12 @@ fn generate_high_level_plugin_code(
         .expect("support_email_address missing");
     let update_url = args.update_url.expect("update_url missing");
     let main_function_name = &main_function.sig.ident;
+    let low_level_args = match args.shutdown {
+        None => quote! {},
+        Some(name) => quote! { (shutdown = #name) },
+    };
     let tokens = quote! {
-        #[::reaper_macros::reaper_extension_plugin]
+        #[::reaper_macros::reaper_extension_plugin #low_level_args]
         fn low_level_plugin_main(context: ::reaper_low::PluginContext) -> Result<(), Box<dyn std::error::Error>> {
             let plugin_info = ::reaper_high::PluginInfo {
                 plugin_name: #plugin_name.to_string(),
@@ -122,6 +157,34 @@ fn generate_high_level_plugin_code(
     tokens.into()
 }
 
+/// Macro for generating typed command ID constants from a REAPER action list.
+///
+/// Export the action list in REAPER ("Actions → Show action list… → Export → Export all
+/// actions"), check in the file (or a subset of its lines) and refer to it with a path relative to
+/// the crate's `Cargo.toml`:
+///
+/// ```ignore
+/// mod actions {
+///     reaper_macros::command_ids!("resources/actions.txt");
+/// }
+///
+/// // "Transport: Play/stop"
+/// reaper.main_on_command_ex(actions::TRANSPORT_PLAY_STOP, 0, CurrentProject);
+/// ```
+///
+/// The file is expected to contain tab-separated lines with section, command ID and description,
+/// such as `Main<TAB>40044<TAB>Transport: Play/stop`. The section column may be omitted. Only
+/// actions of the main section with numeric command IDs are considered. Each one results in a
+/// `pub const` of type `reaper_medium::CommandId`, named after the action description. If two
+/// actions have the same description, the command ID is appended to the name of the second one.
+#[proc_macro]
+pub fn command_ids(input: TokenStream) -> TokenStream {
+    let path = syn::parse_macro_input!(input as syn::LitStr);
+    command_ids::generate_command_ids(&path)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 /// Arguments passed to the [`reaper_extension_plugin`] macro.
 ///
 /// [`reaper_extension_plugin`]: macro.reaper_extension_plugin.html
@@ -141,6 +204,10 @@ struct ReaperExtensionPluginMacroArgs {
     ///
     /// Necessary for high-level plug-in.
     update_url: Option<String>,
+    /// Name of a function which will be called when REAPER unloads the plug-in.
+    ///
+    /// Optional.
+    shutdown: Option<String>,
 }
 
 #[cfg(doctest)]
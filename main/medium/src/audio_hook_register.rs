@@ -2,10 +2,15 @@ use crate::{decode_user_data, encode_user_data, Hz};
 use reaper_low::raw::audio_hook_register_t;
 use reaper_low::{firewall, raw};
 
+use std::cell::UnsafeCell;
+use std::ffi::c_void;
 use std::fmt;
 use std::fmt::Debug;
 use std::os::raw::c_int;
-use std::ptr::{null_mut, NonNull};
+use std::ptr::{addr_of_mut, NonNull};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, Ordering};
+use std::sync::Arc;
 
 /// Consumers need to implement this trait in order to be called back in the real-time audio thread.
 ///
@@ -68,10 +73,16 @@ extern "C" fn delegating_on_audio_buffer<T: OnAudioBuffer>(
     srate: f64,
     reg: *mut audio_hook_register_t,
 ) {
+    // The callback can be replaced from the main thread at any time (see
+    // `OwnedAudioHookRegister::replace_callback()`), so we announce that it's in use before loading
+    // the user data pointer.
+    let call_count = unsafe { &*((*reg).userdata2 as *const AtomicU32) };
+    call_count.fetch_add(1, Ordering::SeqCst);
     // TODO-low Check performance implications for firewall call
     firewall(|| {
         let reg = unsafe { NonNull::new_unchecked(reg) };
-        let callback_struct: &mut T = decode_user_data(unsafe { reg.as_ref() }.userdata1);
+        let user_data = unsafe { user_data_slot(reg.as_ptr()) }.load(Ordering::SeqCst);
+        let callback_struct: &mut T = decode_user_data(user_data);
         callback_struct.call(OnAudioBufferArgs {
             is_post,
             len: len as u32,
@@ -79,11 +90,24 @@ extern "C" fn delegating_on_audio_buffer<T: OnAudioBuffer>(
             reg: &AudioHookRegister::new(reg),
         });
     });
+    call_count.fetch_sub(1, Ordering::SeqCst);
+}
+
+/// Gives atomic access to the pointer to the callback.
+///
+/// # Safety
+///
+/// The given pointer must be valid.
+unsafe fn user_data_slot<'a>(reg: *mut audio_hook_register_t) -> &'a AtomicPtr<c_void> {
+    &*(addr_of_mut!((*reg).userdata1) as *const AtomicPtr<c_void>)
 }
 
 pub(crate) struct OwnedAudioHookRegister {
     inner: raw::audio_hook_register_t,
     callback: Box<dyn OnAudioBuffer>,
+    /// Number of callback invocations in progress. Boxed because it's passed to REAPER as user
+    /// data.
+    call_count: Box<AtomicU32>,
 }
 
 impl Debug for OwnedAudioHookRegister {
@@ -114,6 +138,7 @@ impl OwnedAudioHookRegister {
     where
         T: OnAudioBuffer + 'static,
     {
+        let call_count = Box::new(AtomicU32::new(0));
         OwnedAudioHookRegister {
             inner: audio_hook_register_t {
                 OnAudioBuffer: Some(delegating_on_audio_buffer::<T>),
@@ -122,18 +147,39 @@ impl OwnedAudioHookRegister {
                 // the original type correctly without needing the vtable part of the fat
                 // pointer.
                 userdata1: encode_user_data(&callback),
-                userdata2: null_mut(),
+                userdata2: call_count.as_ref() as *const _ as *mut c_void,
                 input_nch: 0,
                 output_nch: 0,
                 GetBuffer: None,
             },
             callback,
+            call_count,
         }
     }
 
     pub fn into_callback(self) -> Box<dyn OnAudioBuffer> {
         self.callback
     }
+
+    /// Replaces the callback while the register stays registered and returns the old one.
+    ///
+    /// The new callback must have the same type as the original one because the delegating
+    /// function is specific to that type. If the old callback is being invoked in the audio thread
+    /// at the time of calling this, this waits until the invocation has finished, so the old
+    /// callback can be dropped safely afterwards. That takes one audio callback at most.
+    pub fn replace_callback<T>(&mut self, callback: Box<T>) -> Box<dyn OnAudioBuffer>
+    where
+        T: OnAudioBuffer + 'static,
+    {
+        let new_user_data = encode_user_data(&callback);
+        let old_callback = std::mem::replace(&mut self.callback, callback);
+        unsafe { user_data_slot(&mut self.inner) }.store(new_user_data, Ordering::SeqCst);
+        // From now on, new invocations use the new callback. Wait for old ones to finish.
+        while self.call_count.load(Ordering::SeqCst) > 0 {
+            std::hint::spin_loop();
+        }
+        old_callback
+    }
 }
 
 impl AsRef<raw::audio_hook_register_t> for OwnedAudioHookRegister {
@@ -141,3 +187,151 @@ impl AsRef<raw::audio_hook_register_t> for OwnedAudioHookRegister {
         &self.inner
     }
 }
+
+/// Handle of an audio hook which has been registered via
+/// [`audio_reg_hardware_hook_add_ordered()`].
+///
+/// The audio hook stays active as long as this handle is alive. Dropping it deactivates the hook,
+/// which means it won't be called back anymore (although a callback invocation which is in progress
+/// at the time of dropping will still complete). The callback itself is dropped by the session at
+/// the next ordered registration or removal or when the session is dropped. Pass the handle to
+/// [`audio_reg_hardware_hook_remove_ordered()`] in order to drop the callback immediately.
+///
+/// [`audio_reg_hardware_hook_add_ordered()`]:
+/// struct.ReaperSession.html#method.audio_reg_hardware_hook_add_ordered
+/// [`audio_reg_hardware_hook_remove_ordered()`]:
+/// struct.ReaperSession.html#method.audio_reg_hardware_hook_remove_ordered
+#[derive(Debug)]
+pub struct OrderedAudioHookHandle {
+    active: Arc<AtomicBool>,
+}
+
+impl Drop for OrderedAudioHookHandle {
+    fn drop(&mut self) {
+        self.active.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Audio hook which invokes multiple audio hooks in a deterministic order.
+///
+/// This is registered at REAPER as one single audio hook. It's never modified while being
+/// registered. Instead, the session keeps a copy, modifies that copy and swaps it in via
+/// [`OwnedAudioHookRegister::replace_callback()`]. The entries are shared between both copies.
+#[derive(Clone, Default)]
+pub(crate) struct OrderedAudioHookChain {
+    /// Sorted by order, with registration order as tie breaker.
+    ///
+    /// `Rc` is fine because reference counts are only changed in the main thread.
+    entries: Vec<Rc<OrderedAudioHookEntry>>,
+}
+
+impl Debug for OrderedAudioHookChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderedAudioHookChain")
+            .field("entry_count", &self.entries.len())
+            .finish()
+    }
+}
+
+struct OrderedAudioHookEntry {
+    order: i32,
+    active: Arc<AtomicBool>,
+    /// Only accessed by the registered chain in the audio thread. The session just keeps it alive.
+    callback: UnsafeCell<Box<dyn OnAudioBuffer>>,
+}
+
+impl OrderedAudioHookChain {
+    /// Inserts the given callback according to its order.
+    pub fn insert(
+        &mut self,
+        order: i32,
+        callback: Box<dyn OnAudioBuffer>,
+    ) -> OrderedAudioHookHandle {
+        let active = Arc::new(AtomicBool::new(true));
+        let entry = OrderedAudioHookEntry {
+            order,
+            active: active.clone(),
+            callback: UnsafeCell::new(callback),
+        };
+        let index = self.entries.partition_point(|e| e.order <= order);
+        self.entries.insert(index, Rc::new(entry));
+        OrderedAudioHookHandle { active }
+    }
+
+    /// Removes callbacks whose handle is gone.
+    pub fn remove_inactive(&mut self) {
+        self.entries.retain(|e| e.active.load(Ordering::Relaxed));
+    }
+}
+
+impl OnAudioBuffer for OrderedAudioHookChain {
+    fn call(&mut self, args: OnAudioBufferArgs) {
+        for entry in &self.entries {
+            if !entry.active.load(Ordering::Relaxed) {
+                continue;
+            }
+            // Safe because only the registered chain calls the callbacks and REAPER calls the
+            // audio hook from one thread at a time.
+            let callback = unsafe { &mut *entry.callback.get() };
+            callback.call(OnAudioBufferArgs {
+                is_post: args.is_post,
+                len: args.len,
+                srate: args.srate,
+                reg: args.reg,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU64;
+
+    struct Counter(Rc<AtomicU64>);
+
+    impl OnAudioBuffer for Counter {
+        fn call(&mut self, _: OnAudioBufferArgs) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn replace_callback() {
+        let old_count = Rc::new(AtomicU64::new(0));
+        let new_count = Rc::new(AtomicU64::new(0));
+        let mut register = Box::new(OwnedAudioHookRegister::new(Box::new(Counter(
+            old_count.clone(),
+        ))));
+        let invoke = |register: &mut OwnedAudioHookRegister| {
+            let on_audio_buffer = register.inner.OnAudioBuffer.unwrap();
+            unsafe { on_audio_buffer(false, 64, 48000.0, &mut register.inner) };
+        };
+        invoke(&mut register);
+        let old_callback = register.replace_callback(Box::new(Counter(new_count.clone())));
+        invoke(&mut register);
+        invoke(&mut register);
+        drop(old_callback);
+        assert_eq!(old_count.load(Ordering::Relaxed), 1);
+        assert_eq!(new_count.load(Ordering::Relaxed), 2);
+        assert_eq!(Rc::strong_count(&old_count), 1);
+    }
+
+    #[test]
+    fn ordered_audio_hook_chain() {
+        let count = Rc::new(AtomicU64::new(0));
+        let mut session_copy = OrderedAudioHookChain::default();
+        let late = session_copy.insert(1, Box::new(Counter(count.clone())));
+        let early = session_copy.insert(0, Box::new(Counter(count.clone())));
+        assert_eq!(session_copy.entries[0].order, 0);
+        let registered = session_copy.clone();
+        assert_eq!(Rc::strong_count(&count), 3);
+        drop(late);
+        session_copy.remove_inactive();
+        // Still alive because the registered chain could be in use
+        assert_eq!(Rc::strong_count(&count), 3);
+        drop(registered);
+        assert_eq!(Rc::strong_count(&count), 2);
+        drop(early);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::ReaperStr;
+
+/// Data type of a REAPER configuration variable (preference) as returned by
+/// [`get_config_var()`].
+///
+/// [`get_config_var()`]: struct.Reaper.html#method.get_config_var
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ConfigVarType {
+    /// 32-bit integer (often used as bit field).
+    Int,
+    /// 64-bit floating point number.
+    Double,
+}
+
+impl ConfigVarType {
+    /// Returns the size of values of this type in bytes.
+    pub fn size(self) -> u32 {
+        use ConfigVarType::*;
+        match self {
+            Int => 4,
+            Double => 8,
+        }
+    }
+
+    /// Returns the type of the given configuration variable if it's a well-known one.
+    ///
+    /// This list is not exhaustive. Returns `None` for unknown configuration variables.
+    pub fn of_known_config_var(name: &ReaperStr) -> Option<ConfigVarType> {
+        use ConfigVarType::*;
+        // Compare bytes so that names which are not valid UTF-8 don't cause a panic
+        let var_type = match name.as_c_str().to_bytes() {
+            b"projtimemode" | b"seekmodes" | b"mixeruiflag" | b"mixeruiflag2" | b"autoxfade"
+            | b"envattach" | b"itemclickmovecurs" | b"undomask" | b"showpeaks" | b"tcpalign" => Int,
+            b"defsplitxfadelen" => Double,
+            _ => return None,
+        };
+        Some(var_type)
+    }
+}
@@ -118,6 +118,11 @@ pub trait ControlSurface: Debug {
     /// the parameter on the given track should still write automation or not.
     ///
     /// The main use case are touch-sensitive motor faders.
+    ///
+    /// REAPER asks for parameters other than volume and pan only if
+    /// [`ext_supports_extended_touch()`] returns `1`.
+    ///
+    /// [`ext_supports_extended_touch()`]: #method.ext_supports_extended_touch
     fn get_touch_state(&self, args: GetTouchStateArgs) -> bool {
         let _ = args;
         false
@@ -281,6 +286,43 @@ pub trait ControlSurface: Debug {
     fn ext_set_project_marker_change(&self, _: ExtSetProjectMarkerChangeArgs) -> i32 {
         0
     }
+
+    /// Called when the metronome has been enabled or disabled.
+    fn ext_set_metronome(&self, args: ExtSetMetronomeArgs) -> i32 {
+        let _ = args;
+        0
+    }
+
+    /// Called when auto-record-arm has been enabled or disabled.
+    fn ext_set_auto_rec_arm(&self, args: ExtSetAutoRecArmArgs) -> i32 {
+        let _ = args;
+        0
+    }
+
+    /// Called when the behavior of recording over existing items has changed.
+    fn ext_set_rec_mode(&self, args: ExtSetRecModeArgs) -> i32 {
+        let _ = args;
+        0
+    }
+
+    /// Called when a track has been touched.
+    fn ext_set_last_touched_track(&self, args: ExtSetLastTouchedTrackArgs) -> i32 {
+        let _ = args;
+        0
+    }
+
+    /// Called when the mixer has been scrolled.
+    fn ext_set_mixer_scroll(&self, args: ExtSetMixerScrollArgs) -> i32 {
+        let _ = args;
+        0
+    }
+
+    /// Called when the index of a MIDI device has changed (e.g. because devices have been added
+    /// or removed).
+    fn ext_midi_device_remap(&self, args: ExtMidiDeviceRemapArgs) -> i32 {
+        let _ = args;
+        0
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -365,7 +407,7 @@ pub enum TouchedParameterType {
 
 impl TouchedParameterType {
     /// Converts an integer as returned by the low-level API to a type.
-    fn from_raw(value: i32) -> TouchedParameterType {
+    pub fn from_raw(value: i32) -> TouchedParameterType {
         use TouchedParameterType::*;
         match value {
             0 => Volume,
@@ -374,6 +416,17 @@ impl TouchedParameterType {
             x => Unknown(Hidden(x)),
         }
     }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use TouchedParameterType::*;
+        match self {
+            Volume => 0,
+            Pan => 1,
+            Width => 2,
+            Unknown(Hidden(x)) => x,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -500,6 +553,64 @@ pub struct ExtResetArgs;
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct ExtSetProjectMarkerChangeArgs;
 
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtSetMetronomeArgs {
+    pub is_enabled: bool,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtSetAutoRecArmArgs {
+    pub is_enabled: bool,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtSetRecModeArgs {
+    pub mode: OverlapRecordingMode,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtSetLastTouchedTrackArgs {
+    pub track: Option<MediaTrack>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtSetMixerScrollArgs {
+    /// Leftmost track visible in the mixer.
+    pub track: Option<MediaTrack>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtMidiDeviceRemapArgs {
+    /// `true` if this is about a MIDI output device, `false` if about a MIDI input device.
+    pub is_output: bool,
+    pub old_index: i32,
+    pub new_index: i32,
+}
+
+/// Defines what happens when recording over existing items.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum OverlapRecordingMode {
+    /// Splits existing items and creates new takes.
+    AutoSplitAndCreateTakes,
+    /// Replaces existing material (tape mode).
+    Replace,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl OverlapRecordingMode {
+    /// Converts an integer as returned by the low-level API to an overlap recording mode.
+    pub fn from_raw(v: i32) -> OverlapRecordingMode {
+        use OverlapRecordingMode::*;
+        match v {
+            0 => AutoSplitAndCreateTakes,
+            1 => Replace,
+            x => Unknown(Hidden(x)),
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct ExtSetBpmAndPlayRateArgs {
     pub tempo: Option<Bpm>,
@@ -862,7 +973,6 @@ impl reaper_low::IReaperControlSurface for ControlSurfaceAdapter {
         parm3: *mut c_void,
     ) -> i32 {
         let result = unsafe {
-            // TODO-low Delegate all known CSURF_EXT_ constants
             match call {
                 raw::CSURF_EXT_SETINPUTMONITOR => {
                     let recmon: i32 = deref_as(parm2).expect("recmon pointer is null");
@@ -1013,6 +1123,40 @@ impl reaper_low::IReaperControlSurface for ControlSurfaceAdapter {
                 raw::CSURF_EXT_SETPROJECTMARKERCHANGE => self
                     .delegate
                     .ext_set_project_marker_change(ExtSetProjectMarkerChangeArgs),
+                raw::CSURF_EXT_SETMETRONOME => {
+                    self.delegate.ext_set_metronome(ExtSetMetronomeArgs {
+                        is_enabled: interpret_as_bool(parm1),
+                    })
+                }
+                raw::CSURF_EXT_SETAUTORECARM => {
+                    self.delegate.ext_set_auto_rec_arm(ExtSetAutoRecArmArgs {
+                        is_enabled: interpret_as_bool(parm1),
+                    })
+                }
+                raw::CSURF_EXT_SETRECMODE => {
+                    let mode: i32 = deref_as(parm1).expect("record mode pointer is null");
+                    self.delegate.ext_set_rec_mode(ExtSetRecModeArgs {
+                        mode: OverlapRecordingMode::from_raw(mode),
+                    })
+                }
+                raw::CSURF_EXT_SETLASTTOUCHEDTRACK => {
+                    self.delegate
+                        .ext_set_last_touched_track(ExtSetLastTouchedTrackArgs {
+                            track: MediaTrack::new(parm1 as *mut raw::MediaTrack),
+                        })
+                }
+                raw::CSURF_EXT_SETMIXERSCROLL => {
+                    self.delegate.ext_set_mixer_scroll(ExtSetMixerScrollArgs {
+                        track: MediaTrack::new(parm1 as *mut raw::MediaTrack),
+                    })
+                }
+                raw::CSURF_EXT_MIDI_DEVICE_REMAP => {
+                    self.delegate.ext_midi_device_remap(ExtMidiDeviceRemapArgs {
+                        is_output: interpret_as_bool(parm1),
+                        old_index: parm2 as isize as i32,
+                        new_index: parm3 as isize as i32,
+                    })
+                }
                 _ => 0,
             }
         };
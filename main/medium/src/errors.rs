@@ -8,15 +8,70 @@ use std::fmt::{Debug, Display};
 ///
 /// The error message is not very specific most of the time because REAPER functions usually don't
 /// give information about the cause of the error.
+///
+/// This error consists of nothing but a [kind](ReaperFunctionErrorKind) and a static message. It's
+/// `Copy` and creating it never allocates, so it's safe to create and return it in real-time
+/// threads.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Display, Error)]
 #[display(fmt = "REAPER function failed: {message}")]
 pub struct ReaperFunctionError {
+    kind: ReaperFunctionErrorKind,
     message: &'static str,
 }
 
+/// The rough category of a [`ReaperFunctionError`].
+///
+/// Because REAPER rarely tells why a function failed, the category is often just a well-founded
+/// guess derived from the function's semantics. Branch on it rather than on the message.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ReaperFunctionErrorKind {
+    /// The object which the function should act on (e.g. an FX, a send or a marker) doesn't
+    /// exist (anymore).
+    NotFound,
+    /// An argument was invalid, e.g. an unknown attribute key or a chunk which couldn't be parsed.
+    InvalidArgument,
+    /// The function is not supported by the object it was called on (e.g. a PCM source which
+    /// doesn't understand a certain extension).
+    NotSupported,
+    /// The function is not available in the running REAPER version.
+    VersionTooOld,
+    /// REAPER reported that the function failed without any hint about the cause.
+    Unknown,
+}
+
 impl ReaperFunctionError {
+    /// Creates an error of kind [`ReaperFunctionErrorKind::Unknown`].
+    ///
+    /// Use this only if the REAPER function can fail for several unrelated reasons (e.g. a failed
+    /// registration or allocation, or an FX which might not exist or might not support the
+    /// request). Picking one of the specific kinds in such cases would mislead callers which
+    /// branch on it.
     pub(crate) const fn new(message: &'static str) -> ReaperFunctionError {
-        ReaperFunctionError { message }
+        Self::with_kind(ReaperFunctionErrorKind::Unknown, message)
+    }
+
+    pub(crate) const fn not_found(message: &'static str) -> ReaperFunctionError {
+        Self::with_kind(ReaperFunctionErrorKind::NotFound, message)
+    }
+
+    pub(crate) const fn invalid_argument(message: &'static str) -> ReaperFunctionError {
+        Self::with_kind(ReaperFunctionErrorKind::InvalidArgument, message)
+    }
+
+    pub(crate) const fn not_supported(message: &'static str) -> ReaperFunctionError {
+        Self::with_kind(ReaperFunctionErrorKind::NotSupported, message)
+    }
+
+    const fn with_kind(
+        kind: ReaperFunctionErrorKind,
+        message: &'static str,
+    ) -> ReaperFunctionError {
+        ReaperFunctionError { kind, message }
+    }
+
+    /// Returns the kind of error.
+    pub const fn kind(&self) -> ReaperFunctionErrorKind {
+        self.kind
     }
 
     /// Returns the error message.
@@ -37,8 +92,26 @@ impl From<&'static str> for ReaperFunctionError {
     }
 }
 
+/// Converts the error returned by [`Reaper::checked()`](crate::Reaper::checked) into an error of
+/// kind [`ReaperFunctionErrorKind::VersionTooOld`].
+impl From<reaper_low::FunctionNotAvailableError> for ReaperFunctionError {
+    fn from(_: reaper_low::FunctionNotAvailableError) -> Self {
+        Self::with_kind(
+            ReaperFunctionErrorKind::VersionTooOld,
+            "function not available in this REAPER version",
+        )
+    }
+}
+
 pub(crate) type ReaperFunctionResult<T> = Result<T, ReaperFunctionError>;
 
+/// An error which occurs when attempting to execute main-thread-only functions from another thread.
+///
+/// See [`Reaper::try_in_main_thread()`](crate::Reaper::try_in_main_thread).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Display, Error)]
+#[display(fmt = "main-thread-only function called from wrong thread")]
+pub struct WrongThreadError;
+
 /// An error which can occur when converting from a type with a greater value range to one with a
 /// smaller one.
 ///
@@ -55,6 +55,34 @@ pub enum TrackArea {
     Mcp = 2,
 }
 
+/// Kind of item editing operation which is currently going on.
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum ItemEditingOperation {
+    /// Resizing an item edge.
+    EdgeResize = 1,
+    /// Changing a fade.
+    FadeChange = 2,
+    /// Moving an item.
+    Move = 4,
+    /// Slip-editing an item.
+    SlipEdit = 8,
+}
+
+/// Defines the format of the lyrics returned by [`get_track_midi_lyrics()`].
+///
+/// [`get_track_midi_lyrics()`]: struct.Reaper.html#method.get_track_midi_lyrics
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum TrackMidiLyricsFlag {
+    /// Adds double tabs at the end of each measure and triple tabs when skipping measures.
+    MeasureSeparators = 1,
+    /// Precedes each lyric with its beat position in the project.
+    BeatPositions = 2,
+}
+
 /// Defines how REAPER will buffer when playing previews.
 #[enumflags2::bitflags]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -130,3 +158,38 @@ pub enum SetTrackUiFlags {
 pub enum GetThemeColorFlags {
     OriginalColor = 1,
 }
+
+/// Influences how a string is localized.
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum LocalizeFlag {
+    /// Only uses the translation if its format specifiers (`%d`, `%s`, ...) match the original.
+    VerifyFormats = 1,
+    /// Doesn't cache the result.
+    ///
+    /// Must be set if the source string is not static.
+    NoCache = 2,
+}
+
+/// Additional options when editing a marker or region.
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum SetProjectMarkerFlag {
+    /// Clears the name (otherwise an empty name leaves the name unchanged).
+    ClearName = 1,
+}
+
+/// Options for saving a project.
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum SaveProjectFlag {
+    /// Saves the selected tracks as track template instead of saving the project.
+    SaveSelectedTracksAsTrackTemplate = 1,
+    /// Includes media when saving a track template.
+    IncludeMediaWithTrackTemplate = 2,
+    /// Includes envelopes when saving a track template.
+    IncludeEnvelopesWithTrackTemplate = 4,
+}
@@ -5,7 +5,7 @@ use crate::{
 use reaper_low::raw::{HWND, INT_PTR, MSG};
 use reaper_low::{firewall, raw};
 use std::ffi::c_char;
-use std::os::raw::c_int;
+use std::os::raw::{c_int, c_void};
 use std::ptr::{null, NonNull};
 
 /// Consumers need to implement this trait in order to define what should happen when a certain
@@ -240,3 +240,77 @@ pub(crate) extern "C" fn delegating_hook_post_command_2<T: HookPostCommand2>(
         );
     });
 }
+
+/// Consumers need to implement this trait in order to let REAPER save and restore the position
+/// and visibility of their windows as part of screensets and project layouts.
+///
+/// Each window is identified by the ID which has been passed when registering it.
+pub trait ScreensetCallback {
+    /// Returns the window with the given ID (if it's currently open).
+    fn get_window(id: &ReaperStr) -> Option<Hwnd>;
+
+    /// Returns whether the window with the given ID is currently docked.
+    fn is_docked(id: &ReaperStr) -> bool;
+
+    /// Docks the window with the given ID if it's undocked and vice versa.
+    fn switch_dock(id: &ReaperStr);
+
+    /// Restores the window with the given ID from the given state.
+    ///
+    /// If `state` is `None`, the window should be hidden.
+    fn load_state(id: &ReaperStr, state: Option<&[u8]>);
+
+    /// Writes the state of the window with the given ID into the given buffer and returns the
+    /// number of bytes written.
+    fn save_state(id: &ReaperStr, buffer: &mut [u8]) -> usize;
+}
+
+pub(crate) extern "C" fn delegating_screenset_callback<T: ScreensetCallback>(
+    action: c_int,
+    id: *const c_char,
+    _param: *mut c_void,
+    action_parm: *mut c_void,
+    action_parm_size: c_int,
+) -> raw::LRESULT {
+    firewall(|| {
+        let id = unsafe { ReaperStr::from_ptr(id) };
+        match action {
+            raw::SCREENSET_ACTION_GETHWND => T::get_window(id)
+                .map(|w| w.as_ptr() as raw::LRESULT)
+                .unwrap_or(0),
+            raw::SCREENSET_ACTION_IS_DOCKED => T::is_docked(id) as raw::LRESULT,
+            raw::SCREENSET_ACTION_SWITCH_DOCK => {
+                T::switch_dock(id);
+                0
+            }
+            raw::SCREENSET_ACTION_LOAD_STATE => {
+                let state = if action_parm.is_null() || action_parm_size <= 0 {
+                    None
+                } else {
+                    Some(unsafe {
+                        std::slice::from_raw_parts(
+                            action_parm as *const u8,
+                            action_parm_size as usize,
+                        )
+                    })
+                };
+                T::load_state(id, state);
+                0
+            }
+            raw::SCREENSET_ACTION_SAVE_STATE => {
+                if action_parm.is_null() || action_parm_size <= 0 {
+                    return 0;
+                }
+                let buffer = unsafe {
+                    std::slice::from_raw_parts_mut(
+                        action_parm as *mut u8,
+                        action_parm_size as usize,
+                    )
+                };
+                T::save_state(id, buffer) as raw::LRESULT
+            }
+            _ => 0,
+        }
+    })
+    .unwrap_or(0)
+}
@@ -0,0 +1,369 @@
+use crate::{Hidden, LiceBitmap};
+use enumflags2::BitFlags;
+use reaper_low::raw;
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+
+/// The VST 2 `canDo` string with which REAPER asks whether a plug-in supports embedded UIs.
+pub const FX_EMBED_CAN_DO: &str = raw::REAPER_FXEMBED_VST_CAN_DO;
+
+/// The value which a VST 2 plug-in must return from `canDo` for [`FX_EMBED_CAN_DO`] in order to
+/// announce embedded UI support.
+pub const FX_EMBED_CAN_DO_RESULT: isize = raw::REAPER_FXEMBED_VST_CAN_DO_RESULT;
+
+/// Consumers can implement this trait in order to let their VST plug-in draw a small UI directly
+/// into REAPER's track control panel (TCP) or mixer control panel (MCP), like ReaEQ does.
+///
+/// For this to work, the plug-in needs to return [`FX_EMBED_CAN_DO_RESULT`] when being asked for
+/// [`FX_EMBED_CAN_DO`] and forward its vendor-specific calls to [`dispatch_fx_embed_message()`].
+pub trait FxEmbedUi {
+    /// Return `false` if embedding is supported in general but not available at the moment.
+    fn is_available(&mut self) -> bool {
+        true
+    }
+
+    /// Called when embedding begins.
+    fn create(&mut self) {}
+
+    /// Called when embedding ends.
+    fn destroy(&mut self) {}
+
+    /// Draws the UI into the given bitmap.
+    ///
+    /// Return `true` if drawing occurred. If the draw info contains
+    /// [`FxEmbedDrawFlag::PaintOptional`] and nothing changed since the last draw, you can skip
+    /// drawing and return `false`.
+    fn paint(&mut self, args: FxEmbedPaintArgs) -> bool;
+
+    /// Return size hints or `None` if there are no preferences.
+    fn get_size_hints(&mut self) -> Option<FxEmbedSizeHints> {
+        None
+    }
+
+    /// Set the mouse cursor and return `true` or return `false` if REAPER should use the default
+    /// cursor.
+    fn set_cursor(&mut self, info: FxEmbedDrawInfo) -> bool {
+        let _ = info;
+        false
+    }
+
+    /// Called on mouse events. Mouse capture is set on button down and released on button up.
+    ///
+    /// Return `true` if REAPER should redraw the UI as soon as possible.
+    fn mouse_event(&mut self, args: FxEmbedMouseEventArgs) -> bool {
+        let _ = args;
+        false
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FxEmbedPaintArgs {
+    pub bitmap: LiceBitmap,
+    pub info: FxEmbedDrawInfo,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FxEmbedMouseEventArgs {
+    pub kind: FxEmbedMouseEventKind,
+    pub info: FxEmbedDrawInfo,
+}
+
+/// Information about the area in which the embedded UI is displayed.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FxEmbedDrawInfo {
+    pub context: FxEmbedContext,
+    /// UI scale factor (1.0 = 100%) or `None` if unknown.
+    pub scale: Option<f64>,
+    pub width: u32,
+    pub height: u32,
+    /// Only meaningful for mouse events and cursor requests.
+    pub mouse_x: i32,
+    /// Only meaningful for mouse events and cursor requests.
+    pub mouse_y: i32,
+    /// Mouse wheel movement (only meaningful for [`FxEmbedMouseEventKind::Wheel`]), typically 120
+    /// per step.
+    pub mouse_wheel_amount: i32,
+    pub flags: BitFlags<FxEmbedDrawFlag>,
+}
+
+impl FxEmbedDrawInfo {
+    /// Converts the low-level draw info to a medium-level one.
+    pub fn from_raw(raw: &raw::REAPER_FXEMBED_DrawInfo) -> Self {
+        Self {
+            context: FxEmbedContext::from_raw(raw.context),
+            scale: if raw.dpi > 0 {
+                Some(raw.dpi as f64 / 256.0)
+            } else {
+                None
+            },
+            width: raw.width.max(0) as u32,
+            height: raw.height.max(0) as u32,
+            mouse_x: raw.mouse_x,
+            mouse_y: raw.mouse_y,
+            mouse_wheel_amount: raw.mousewheel_amt,
+            flags: BitFlags::from_bits_truncate(raw.flags as u32),
+        }
+    }
+}
+
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum FxEmbedDrawFlag {
+    /// Redrawing is optional, e.g. because it's just a timer-based refresh.
+    PaintOptional = raw::REAPER_FXEMBED_DRAWINFO_FLAG_PAINT_OPTIONAL as u32,
+    /// The left mouse button is down and captured.
+    LeftButtonCaptured = raw::REAPER_FXEMBED_DRAWINFO_FLAG_LBUTTON_CAPTURED as u32,
+    /// The right mouse button is down and captured.
+    RightButtonCaptured = raw::REAPER_FXEMBED_DRAWINFO_FLAG_RBUTTON_CAPTURED as u32,
+}
+
+/// Where the embedded UI is displayed.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FxEmbedContext {
+    TrackControlPanel,
+    MixerControlPanel,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl FxEmbedContext {
+    /// Converts an integer as returned by the low-level API to an embedding context.
+    pub fn from_raw(v: i32) -> Self {
+        use FxEmbedContext::*;
+        match v {
+            1 => TrackControlPanel,
+            2 => MixerControlPanel,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use FxEmbedContext::*;
+        match self {
+            TrackControlPanel => 1,
+            MixerControlPanel => 2,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FxEmbedMouseEventKind {
+    Move,
+    LeftButtonDown,
+    LeftButtonUp,
+    LeftButtonDoubleClick,
+    RightButtonDown,
+    RightButtonUp,
+    RightButtonDoubleClick,
+    Wheel,
+}
+
+impl FxEmbedMouseEventKind {
+    /// Converts an FX embedding message as passed by REAPER to a mouse event kind.
+    ///
+    /// Returns `None` if the message is not a mouse message.
+    pub fn from_raw(msg: i32) -> Option<Self> {
+        use FxEmbedMouseEventKind::*;
+        let kind = match msg {
+            raw::REAPER_FXEMBED_WM_MOUSEMOVE => Move,
+            raw::REAPER_FXEMBED_WM_LBUTTONDOWN => LeftButtonDown,
+            raw::REAPER_FXEMBED_WM_LBUTTONUP => LeftButtonUp,
+            raw::REAPER_FXEMBED_WM_LBUTTONDBLCLK => LeftButtonDoubleClick,
+            raw::REAPER_FXEMBED_WM_RBUTTONDOWN => RightButtonDown,
+            raw::REAPER_FXEMBED_WM_RBUTTONUP => RightButtonUp,
+            raw::REAPER_FXEMBED_WM_RBUTTONDBLCLK => RightButtonDoubleClick,
+            raw::REAPER_FXEMBED_WM_MOUSEWHEEL => Wheel,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// Size preferences of the embedded UI. These are just hints, the actual size can vary.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct FxEmbedSizeHints {
+    /// Preferred ratio of width to height.
+    pub preferred_aspect_ratio: Option<f64>,
+    /// Minimum ratio of width to height.
+    pub minimum_aspect_ratio: Option<f64>,
+    pub min_width: u32,
+    pub min_height: u32,
+    pub max_width: u32,
+    pub max_height: u32,
+}
+
+impl FxEmbedSizeHints {
+    /// Converts this value to the struct expected by the low-level API.
+    pub fn to_raw(&self) -> raw::REAPER_FXEMBED_SizeHints {
+        let fixed = |ratio: Option<f64>| ratio.map_or(0, |r| (r * 65536.0).round() as i32);
+        raw::REAPER_FXEMBED_SizeHints {
+            preferred_aspect: fixed(self.preferred_aspect_ratio),
+            minimum_aspect: fixed(self.minimum_aspect_ratio),
+            min_width: self.min_width as i32,
+            min_height: self.min_height as i32,
+            max_width: self.max_width as i32,
+            max_height: self.max_height as i32,
+            ..Default::default()
+        }
+    }
+}
+
+/// Dispatches a VST 2 vendor-specific call (`effVendorSpecific`) to the given embedded UI.
+///
+/// Returns `None` if the call is not an FX embedding message. In that case the plug-in should
+/// handle it in whatever way it did before.
+///
+/// # Safety
+///
+/// REAPER can crash if you pass arguments which didn't come from REAPER.
+pub unsafe fn dispatch_fx_embed_message(
+    ui: &mut impl FxEmbedUi,
+    index: i32,
+    value: isize,
+    ptr: *mut c_void,
+    opt: f32,
+) -> Option<isize> {
+    if index != raw::REAPER_FXEMBED_VST_INDEX {
+        return None;
+    }
+    let draw_info = || {
+        let raw_info = (ptr as *const raw::REAPER_FXEMBED_DrawInfo).as_ref()?;
+        Some(FxEmbedDrawInfo::from_raw(raw_info))
+    };
+    let result = match opt as i32 {
+        raw::REAPER_FXEMBED_WM_IS_SUPPORTED => {
+            if ui.is_available() {
+                1
+            } else {
+                -1
+            }
+        }
+        raw::REAPER_FXEMBED_WM_CREATE => {
+            ui.create();
+            0
+        }
+        raw::REAPER_FXEMBED_WM_DESTROY => {
+            ui.destroy();
+            0
+        }
+        raw::REAPER_FXEMBED_WM_PAINT => {
+            let (Some(bitmap), Some(info)) =
+                (NonNull::new(value as *mut raw::LICE_IBitmap), draw_info())
+            else {
+                return Some(0);
+            };
+            ui.paint(FxEmbedPaintArgs { bitmap, info }) as isize
+        }
+        raw::REAPER_FXEMBED_WM_GETMINMAXINFO => {
+            let Some(target) = (ptr as *mut raw::REAPER_FXEMBED_SizeHints).as_mut() else {
+                return Some(0);
+            };
+            let Some(hints) = ui.get_size_hints() else {
+                return Some(0);
+            };
+            *target = hints.to_raw();
+            1
+        }
+        raw::REAPER_FXEMBED_WM_SETCURSOR => match draw_info() {
+            Some(info) if ui.set_cursor(info) => raw::REAPER_FXEMBED_RETNOTIFY_HANDLED as isize,
+            _ => 0,
+        },
+        msg => {
+            let (Some(kind), Some(info)) = (FxEmbedMouseEventKind::from_raw(msg), draw_info())
+            else {
+                return Some(0);
+            };
+            if ui.mouse_event(FxEmbedMouseEventArgs { kind, info }) {
+                raw::REAPER_FXEMBED_RETNOTIFY_INVALIDATE as isize
+            } else {
+                0
+            }
+        }
+    };
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::null_mut;
+
+    #[derive(Default)]
+    struct TestUi {
+        last_mouse_event: Option<FxEmbedMouseEventArgs>,
+    }
+
+    impl FxEmbedUi for TestUi {
+        fn paint(&mut self, _: FxEmbedPaintArgs) -> bool {
+            true
+        }
+
+        fn get_size_hints(&mut self) -> Option<FxEmbedSizeHints> {
+            Some(FxEmbedSizeHints {
+                preferred_aspect_ratio: Some(2.0),
+                min_width: 50,
+                ..Default::default()
+            })
+        }
+
+        fn mouse_event(&mut self, args: FxEmbedMouseEventArgs) -> bool {
+            self.last_mouse_event = Some(args);
+            true
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        let mut ui = TestUi::default();
+        let index = raw::REAPER_FXEMBED_VST_INDEX;
+        unsafe {
+            assert_eq!(
+                dispatch_fx_embed_message(&mut ui, 0, 0, null_mut(), 0.0),
+                None
+            );
+            assert_eq!(
+                dispatch_fx_embed_message(&mut ui, index, 0, null_mut(), 0.0),
+                Some(1)
+            );
+            // Size hints
+            let mut hints = raw::REAPER_FXEMBED_SizeHints::default();
+            let msg = raw::REAPER_FXEMBED_WM_GETMINMAXINFO as f32;
+            let ptr = &mut hints as *mut _ as *mut c_void;
+            assert_eq!(
+                dispatch_fx_embed_message(&mut ui, index, 0, ptr, msg),
+                Some(1)
+            );
+            assert_eq!(hints.preferred_aspect, 131072);
+            assert_eq!(hints.min_width, 50);
+            // Mouse event
+            let mut info = raw::REAPER_FXEMBED_DrawInfo {
+                context: 2,
+                dpi: 512,
+                mousewheel_amt: 0,
+                _res2: 0.0,
+                width: 100,
+                height: 40,
+                mouse_x: 10,
+                mouse_y: 20,
+                flags: raw::REAPER_FXEMBED_DRAWINFO_FLAG_LBUTTON_CAPTURED,
+                _res3: 0,
+                spare: [null_mut(); 6],
+            };
+            let msg = raw::REAPER_FXEMBED_WM_LBUTTONUP as f32;
+            let ptr = &mut info as *mut _ as *mut c_void;
+            assert_eq!(
+                dispatch_fx_embed_message(&mut ui, index, 0, ptr, msg),
+                Some(raw::REAPER_FXEMBED_RETNOTIFY_INVALIDATE as isize)
+            );
+        }
+        let event = ui.last_mouse_event.unwrap();
+        assert_eq!(event.kind, FxEmbedMouseEventKind::LeftButtonUp);
+        assert_eq!(event.info.context, FxEmbedContext::MixerControlPanel);
+        assert_eq!(event.info.scale, Some(2.0));
+        assert_eq!(event.info.flags, FxEmbedDrawFlag::LeftButtonCaptured);
+    }
+}
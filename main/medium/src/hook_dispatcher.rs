@@ -0,0 +1,258 @@
+use crate::{CommandId, HookCommand, HookPostCommand};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Handle of a closure which has been registered via [`plugin_register_add_hook_command_fn()`].
+///
+/// [`plugin_register_add_hook_command_fn()`]:
+/// struct.ReaperSession.html#method.plugin_register_add_hook_command_fn
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct HookCommandSubscription {
+    command_id: CommandId,
+    id: usize,
+}
+
+impl HookCommandSubscription {
+    /// Returns the command ID which is handled by the closure.
+    pub fn command_id(&self) -> CommandId {
+        self.command_id
+    }
+}
+
+/// Handle of a closure which has been registered via
+/// [`plugin_register_add_hook_post_command_fn()`].
+///
+/// [`plugin_register_add_hook_post_command_fn()`]:
+/// struct.ReaperSession.html#method.plugin_register_add_hook_post_command_fn
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct HookPostCommandSubscription(usize);
+
+type HookCommandFn = dyn FnMut(i32) -> bool;
+
+type HookPostCommandFn = dyn FnMut(CommandId, i32);
+
+// REAPER doesn't pass any user data to hook commands, so the closures must be reachable globally.
+// Hooks are only called in the main thread.
+thread_local! {
+    static HOOK_COMMAND_HANDLERS: RefCell<CommandHandlers> = RefCell::new(Default::default());
+    static HOOK_POST_COMMAND_SUBSCRIBERS: RefCell<Subscribers<HookPostCommandFn>> =
+        RefCell::new(Subscribers::default());
+}
+
+/// Closures which are invoked by a dispatcher which is registered at REAPER only once.
+struct Subscribers<F: ?Sized> {
+    next_id: usize,
+    entries: Vec<(usize, Rc<RefCell<F>>)>,
+}
+
+impl<F: ?Sized> Default for Subscribers<F> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            entries: vec![],
+        }
+    }
+}
+
+impl<F: ?Sized> Subscribers<F> {
+    fn add(&mut self, callback: Rc<RefCell<F>>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push((id, callback));
+        id
+    }
+
+    fn remove(&mut self, id: usize) -> bool {
+        let count_before = self.entries.len();
+        self.entries.retain(|(i, _)| *i != id);
+        self.entries.len() < count_before
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all subscribers but keeps generating new IDs, so that old handles stay obsolete.
+    fn clear(&mut self) -> Vec<(usize, Rc<RefCell<F>>)> {
+        std::mem::take(&mut self.entries)
+    }
+
+    /// Returns the current subscribers.
+    ///
+    /// Dispatching must happen on such a snapshot because subscribers are allowed to subscribe or
+    /// unsubscribe while being invoked.
+    fn snapshot(&self) -> Vec<Rc<RefCell<F>>> {
+        self.entries.iter().map(|(_, f)| f.clone()).collect()
+    }
+}
+
+/// Closures which handle specific commands, invoked by a dispatcher which is registered at REAPER
+/// only once.
+#[derive(Default)]
+struct CommandHandlers {
+    next_id: usize,
+    handlers: HashMap<CommandId, (usize, Rc<RefCell<HookCommandFn>>)>,
+}
+
+/// Returns whether this is the first handler or `None` if the command has a handler already.
+pub(crate) fn subscribe_hook_command(
+    command_id: CommandId,
+    callback: impl FnMut(i32) -> bool + 'static,
+) -> Option<(HookCommandSubscription, bool)> {
+    HOOK_COMMAND_HANDLERS.with(|handlers| {
+        let mut handlers = handlers.borrow_mut();
+        if handlers.handlers.contains_key(&command_id) {
+            return None;
+        }
+        let is_first = handlers.handlers.is_empty();
+        let id = handlers.next_id;
+        handlers.next_id += 1;
+        handlers
+            .handlers
+            .insert(command_id, (id, Rc::new(RefCell::new(callback))));
+        Some((HookCommandSubscription { command_id, id }, is_first))
+    })
+}
+
+/// Returns whether this was the last handler.
+pub(crate) fn unsubscribe_hook_command(subscription: HookCommandSubscription) -> bool {
+    HOOK_COMMAND_HANDLERS.with(|handlers| {
+        let mut handlers = handlers.borrow_mut();
+        match handlers.handlers.get(&subscription.command_id) {
+            Some((id, _)) if *id == subscription.id => {}
+            // Already unsubscribed
+            _ => return false,
+        }
+        handlers.handlers.remove(&subscription.command_id);
+        handlers.handlers.is_empty()
+    })
+}
+
+/// Removes all closure-based command handlers.
+///
+/// Must be called when the dispatcher is unregistered for good, otherwise a later registration
+/// wouldn't register the dispatcher again.
+pub(crate) fn clear_hook_command_handlers() {
+    // Keeps generating new IDs, so that old handles stay obsolete
+    let removed = HOOK_COMMAND_HANDLERS.with(|h| std::mem::take(&mut h.borrow_mut().handlers));
+    // Closures are dropped outside of the borrow
+    drop(removed);
+}
+
+/// Hook command which is registered at REAPER on behalf of all closure-based command handlers.
+pub(crate) struct HookCommandDispatcher;
+
+impl HookCommand for HookCommandDispatcher {
+    fn call(command_id: CommandId, flag: i32) -> bool {
+        let handler = HOOK_COMMAND_HANDLERS.with(|handlers| {
+            let handlers = handlers.borrow();
+            let (_, handler) = handlers.handlers.get(&command_id)?;
+            Some(handler.clone())
+        });
+        let Some(handler) = handler else {
+            return false;
+        };
+        // A command which executes itself is not handled recursively.
+        let Ok(mut callback) = handler.try_borrow_mut() else {
+            return false;
+        };
+        callback(flag)
+    }
+}
+
+/// Returns whether this is the first subscriber.
+pub(crate) fn subscribe_hook_post_command(
+    callback: impl FnMut(CommandId, i32) + 'static,
+) -> (HookPostCommandSubscription, bool) {
+    HOOK_POST_COMMAND_SUBSCRIBERS.with(|subscribers| {
+        let mut subscribers = subscribers.borrow_mut();
+        let is_first = subscribers.is_empty();
+        let id = subscribers.add(Rc::new(RefCell::new(callback)));
+        (HookPostCommandSubscription(id), is_first)
+    })
+}
+
+/// Returns whether this was the last subscriber.
+pub(crate) fn unsubscribe_hook_post_command(subscription: HookPostCommandSubscription) -> bool {
+    HOOK_POST_COMMAND_SUBSCRIBERS.with(|subscribers| {
+        let mut subscribers = subscribers.borrow_mut();
+        subscribers.remove(subscription.0) && subscribers.is_empty()
+    })
+}
+
+/// Removes all closure-based subscribers.
+///
+/// Must be called when the dispatcher is unregistered for good, otherwise a later registration
+/// wouldn't register the dispatcher again.
+pub(crate) fn clear_hook_post_command_subscribers() {
+    let removed = HOOK_POST_COMMAND_SUBSCRIBERS.with(|s| s.borrow_mut().clear());
+    // Closures are dropped outside of the borrow
+    drop(removed);
+}
+
+/// Hook post command which is registered at REAPER on behalf of all closure-based subscribers.
+pub(crate) struct HookPostCommandDispatcher;
+
+impl HookPostCommand for HookPostCommandDispatcher {
+    fn call(command_id: CommandId, flag: i32) {
+        let snapshot = HOOK_POST_COMMAND_SUBSCRIBERS.with(|s| s.borrow().snapshot());
+        for subscriber in snapshot {
+            // A subscriber which executes an action itself is not invoked recursively.
+            if let Ok(mut callback) = subscriber.try_borrow_mut() {
+                callback(command_id, flag);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn dispatch_hook_post_command() {
+        let sum = Rc::new(Cell::new(0));
+        let (first, is_first) = subscribe_hook_post_command({
+            let sum = sum.clone();
+            move |command_id, _| sum.set(sum.get() + command_id.get())
+        });
+        assert!(is_first);
+        let (second, is_first) = subscribe_hook_post_command({
+            let sum = sum.clone();
+            move |command_id, _| sum.set(sum.get() + 10 * command_id.get())
+        });
+        assert!(!is_first);
+        HookPostCommandDispatcher::call(CommandId::new(2), 0);
+        assert_eq!(sum.get(), 22);
+        assert!(!unsubscribe_hook_post_command(first));
+        HookPostCommandDispatcher::call(CommandId::new(1), 0);
+        assert_eq!(sum.get(), 32);
+        assert!(unsubscribe_hook_post_command(second));
+        assert!(!unsubscribe_hook_post_command(second));
+    }
+
+    #[test]
+    fn dispatch_hook_command() {
+        let command_1 = CommandId::new(1);
+        let command_2 = CommandId::new(2);
+        let (first, is_first) = subscribe_hook_command(command_1, |flag| flag == 5).unwrap();
+        assert!(is_first);
+        assert!(subscribe_hook_command(command_1, |_| true).is_none());
+        let (second, is_first) = subscribe_hook_command(command_2, |_| true).unwrap();
+        assert!(!is_first);
+        assert!(HookCommandDispatcher::call(command_1, 5));
+        assert!(!HookCommandDispatcher::call(command_1, 0));
+        assert!(HookCommandDispatcher::call(command_2, 0));
+        assert!(!HookCommandDispatcher::call(CommandId::new(3), 0));
+        assert!(!unsubscribe_hook_command(first));
+        assert!(!HookCommandDispatcher::call(command_1, 5));
+        // A stale handle doesn't remove a newer handler of the same command
+        let (third, _) = subscribe_hook_command(command_1, |_| true).unwrap();
+        assert!(!unsubscribe_hook_command(first));
+        assert!(HookCommandDispatcher::call(command_1, 0));
+        assert!(!unsubscribe_hook_command(third));
+        assert!(unsubscribe_hook_command(second));
+    }
+}
@@ -70,6 +70,10 @@ impl<T: AsRef<R>, R> Keeper<T, R> {
         stable_ptr_to_data
     }
 
+    pub fn get_mut(&mut self, handle: Handle<R>) -> Option<&mut T> {
+        self.map.get_mut(&handle).map(|boxed| boxed.as_mut())
+    }
+
     pub fn release(&mut self, handle: Handle<R>) -> Option<T> {
         self.map.remove(&handle).map(|boxed| *boxed)
     }
@@ -97,6 +101,10 @@ impl<T: AsRef<R>, R> SharedKeeper<T, R> {
         stable_ptr_to_data
     }
 
+    pub fn get(&self, handle: Handle<R>) -> Option<&Arc<T>> {
+        self.map.get(&handle)
+    }
+
     pub fn release(&mut self, handle: Handle<R>) -> Option<Arc<T>> {
         self.map.remove(&handle)
     }
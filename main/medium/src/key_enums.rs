@@ -1,4 +1,4 @@
-use crate::{concat_reaper_strs, ReaperStr, ReaperStringArg};
+use crate::{concat_reaper_strs, ReaperStr, ReaperString, ReaperStringArg};
 
 use std::borrow::Cow;
 
@@ -568,6 +568,8 @@ pub enum ItemAttributeKey<'a> {
     Track,
     /// Muted (item solo overrides). Setting this value will clear `MuteSolo`.
     Mute,
+    /// Selected in arrange view.
+    Selected,
     /// Loop source.
     LoopSrc,
     /// Item timebase.
@@ -648,6 +650,7 @@ impl<'a> ItemAttributeKey<'a> {
             Position => reaper_str!("D_POSITION").into(),
             Length => reaper_str!("D_LENGTH").into(),
             Mute => reaper_str!("B_MUTE").into(),
+            Selected => reaper_str!("B_UISEL").into(),
             LoopSrc => reaper_str!("B_LOOPSRC").into(),
             BeatAttachMode => reaper_str!("C_BEATATTACHMODE").into(),
             AutoStretch => reaper_str!("C_AUTOSTRETCH").into(),
@@ -821,16 +824,66 @@ impl<'a> TrackSendAttributeKey<'a> {
     }
 }
 
-/// Project info attribute key which you can pass to [`get_set_project_info_string_set()`],
-/// for example.
+/// Project info attribute key which you can pass to [`get_set_project_info_string_set()`] or
+/// [`get_set_project_info_set()`], for example.
+///
+/// Some keys refer to string attributes, others to numeric ones.
 ///
 /// [`get_set_project_info_string_set()`]: struct.Reaper.html#method.get_set_project_info_string_set
+/// [`get_set_project_info_set()`]: struct.Reaper.html#method.get_set_project_info_set
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub enum ProjectInfoAttributeKey<'a> {
     /// Render directory.
     RenderFile,
     /// Render file name (may contain wildcards).
     RenderPattern,
+    /// Base64-encoded sink configuration of the primary render format.
+    RenderFormat,
+    /// Base64-encoded sink configuration of the secondary render format (empty if none).
+    RenderFormat2,
+    /// Semicolon-separated list of files that would be written when rendering with the most
+    /// recent render settings (read-only).
+    RenderTargets,
+    /// Numeric render settings.
+    ///
+    /// - &1 → stems (selected tracks)
+    /// - &2 → multichannel tracks to multichannel files
+    /// - &4 → use render matrix
+    /// - &8 → mono tracks to mono files
+    /// - &16 → selected media items
+    /// - &32 → selected media items via master
+    /// - &64 → selected tracks via master
+    RenderSettings,
+    /// Numeric render bounds.
+    ///
+    /// - 0 → custom time range
+    /// - 1 → entire project
+    /// - 2 → time selection
+    /// - 3 → all project regions
+    /// - 4 → selected media items
+    /// - 5 → selected project regions
+    RenderBoundsFlag,
+    /// Start position of the custom time range in seconds (numeric).
+    RenderStartPos,
+    /// End position of the custom time range in seconds (numeric).
+    RenderEndPos,
+    /// Sample rate in Hz, 0 → use project sample rate (numeric).
+    RenderSampleRate,
+    /// Number of channels (numeric).
+    RenderChannels,
+    /// Whether to render a tail (numeric, bit mask of bounds for which the tail is rendered).
+    RenderTailFlag,
+    /// Length of the tail in milliseconds (numeric).
+    RenderTailMs,
+    /// Whether to add the rendered files to the project (numeric, &1 → add, &2 → don't render
+    /// files that would be overwritten).
+    RenderAddToProject,
+    /// Project sample rate in Hz (numeric, only relevant if [`ProjectSampleRateUse`] is set).
+    ///
+    /// [`ProjectSampleRateUse`]: #variant.ProjectSampleRateUse
+    ProjectSampleRate,
+    /// Whether the project sample rate overrides the one of the audio device (numeric, 0 or 1).
+    ProjectSampleRateUse,
     /// If a variant is missing in this enum, you can use this custom one as a resort.
     ///
     /// Use [`custom()`] to create this variant.
@@ -852,6 +905,20 @@ impl<'a> ProjectInfoAttributeKey<'a> {
         match self {
             RenderFile => reaper_str!("RENDER_FILE").into(),
             RenderPattern => reaper_str!("RENDER_PATTERN").into(),
+            RenderFormat => reaper_str!("RENDER_FORMAT").into(),
+            RenderFormat2 => reaper_str!("RENDER_FORMAT2").into(),
+            RenderTargets => reaper_str!("RENDER_TARGETS").into(),
+            RenderSettings => reaper_str!("RENDER_SETTINGS").into(),
+            RenderBoundsFlag => reaper_str!("RENDER_BOUNDSFLAG").into(),
+            RenderStartPos => reaper_str!("RENDER_STARTPOS").into(),
+            RenderEndPos => reaper_str!("RENDER_ENDPOS").into(),
+            RenderSampleRate => reaper_str!("RENDER_SRATE").into(),
+            RenderChannels => reaper_str!("RENDER_CHANNELS").into(),
+            RenderTailFlag => reaper_str!("RENDER_TAILFLAG").into(),
+            RenderTailMs => reaper_str!("RENDER_TAILMS").into(),
+            RenderAddToProject => reaper_str!("RENDER_ADDTOPROJ").into(),
+            ProjectSampleRate => reaper_str!("PROJECT_SRATE").into(),
+            ProjectSampleRateUse => reaper_str!("PROJECT_SRATE_USE").into(),
             Custom(key) => key,
         }
     }
@@ -910,6 +977,197 @@ impl<'a> EnvChunkName<'a> {
     }
 }
 
+/// Key of a named FX configuration parameter which you can pass to
+/// [`track_fx_get_named_config_parm_typed()`], for example.
+///
+/// [`track_fx_get_named_config_parm_typed()`]: struct.Reaper.html#method.track_fx_get_named_config_parm_typed
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NamedConfigParmKey<'a> {
+    /// Type of the FX, e.g. "VST", "VST3", "JS", "AU", "CLAP" (read-only).
+    ///
+    /// String
+    FxType,
+    /// Identifier of the FX, e.g. the file name of a VST plug-in (read-only).
+    ///
+    /// String
+    FxIdent,
+    /// Plug-in delay compensation in samples (read-only).
+    ///
+    /// Integer
+    Pdc,
+    /// Base64-encoded VST chunk.
+    ///
+    /// String
+    VstChunk,
+    /// PDC mode of the FX chain (only valid for the first FX in the chain).
+    ///
+    /// Integer
+    ChainPdcMode,
+    /// Original name of the FX (read-only).
+    ///
+    /// String
+    OriginalName,
+    /// Name given to the FX by the user (empty if not renamed).
+    ///
+    /// String
+    RenamedName,
+    /// Number of FX within a container (REAPER 7+, read-only).
+    ///
+    /// Integer
+    ContainerCount,
+    /// Encoded FX location of the item at the given index within a container (REAPER 7+,
+    /// read-only).
+    ///
+    /// Integer
+    ContainerItem(u32),
+    /// Per-parameter configuration, e.g. parameter modulation.
+    Param(u32, FxParamConfigKey<'a>),
+    /// If a variant is missing in this enum, you can use this custom one as a resort.
+    ///
+    /// Use [`custom()`] to create this variant.
+    ///
+    /// [`custom()`]: #method.custom
+    Custom(Cow<'a, ReaperStr>),
+}
+
+impl<'a> NamedConfigParmKey<'a> {
+    /// Convenience function for creating a [`Custom`] key.
+    ///
+    /// [`Custom`]: #variant.Custom
+    pub fn custom(key: impl Into<ReaperStringArg<'a>>) -> NamedConfigParmKey<'a> {
+        NamedConfigParmKey::Custom(key.into().into_inner())
+    }
+
+    /// Returns in which format REAPER delivers values for this key.
+    pub fn value_kind(&self) -> NamedConfigParmValueKind {
+        use NamedConfigParmKey::*;
+        use NamedConfigParmValueKind as K;
+        match self {
+            FxType | FxIdent | VstChunk | OriginalName | RenamedName | Custom(_) => K::String,
+            Pdc | ChainPdcMode | ContainerCount | ContainerItem(_) => K::Integer,
+            Param(_, key) => key.value_kind(),
+        }
+    }
+
+    pub(crate) fn into_raw(self) -> Cow<'a, ReaperStr> {
+        use NamedConfigParmKey::*;
+        match self {
+            FxType => reaper_str!("fx_type").into(),
+            FxIdent => reaper_str!("fx_ident").into(),
+            Pdc => reaper_str!("pdc").into(),
+            VstChunk => reaper_str!("vst_chunk").into(),
+            ChainPdcMode => reaper_str!("chain_pdc_mode").into(),
+            OriginalName => reaper_str!("original_name").into(),
+            RenamedName => reaper_str!("renamed_name").into(),
+            ContainerCount => reaper_str!("container_count").into(),
+            ContainerItem(index) => {
+                ReaperString::from_string(format!("container_item.{index}")).into()
+            }
+            Param(index, key) => {
+                let prefix = ReaperString::from_string(format!("param.{index}."));
+                concat_reaper_strs(prefix.as_reaper_str(), key.into_raw().as_ref()).into()
+            }
+            Custom(key) => key,
+        }
+    }
+}
+
+/// Per-parameter key for [`NamedConfigParmKey::Param`].
+///
+/// [`NamedConfigParmKey::Param`]: enum.NamedConfigParmKey.html#variant.Param
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FxParamConfigKey<'a> {
+    /// Whether parameter modulation is active.
+    ///
+    /// Boolean
+    ModActive,
+    /// Baseline value of the parameter modulation.
+    ///
+    /// Floating point
+    ModBaseline,
+    /// Whether LFO modulation is active.
+    ///
+    /// Boolean
+    LfoActive,
+    /// Whether audio control signal modulation is active.
+    ///
+    /// Boolean
+    AcsActive,
+    /// Whether the parameter is linked to another parameter.
+    ///
+    /// Boolean
+    PlinkActive,
+    /// Index of the FX whose parameter is linked (-1 for MIDI, -100 for none).
+    ///
+    /// Integer
+    PlinkEffect,
+    /// Index of the linked parameter.
+    ///
+    /// Integer
+    PlinkParam,
+    /// Scale of the parameter link.
+    ///
+    /// Floating point
+    PlinkScale,
+    /// Offset of the parameter link.
+    ///
+    /// Floating point
+    PlinkOffset,
+    /// If a variant is missing in this enum, you can use this custom one as a resort.
+    ///
+    /// Use [`custom()`] to create this variant.
+    ///
+    /// [`custom()`]: #method.custom
+    Custom(Cow<'a, ReaperStr>),
+}
+
+impl<'a> FxParamConfigKey<'a> {
+    /// Convenience function for creating a [`Custom`] key.
+    ///
+    /// [`Custom`]: #variant.Custom
+    pub fn custom(key: impl Into<ReaperStringArg<'a>>) -> FxParamConfigKey<'a> {
+        FxParamConfigKey::Custom(key.into().into_inner())
+    }
+
+    /// Returns in which format REAPER delivers values for this key.
+    pub fn value_kind(&self) -> NamedConfigParmValueKind {
+        use FxParamConfigKey::*;
+        use NamedConfigParmValueKind as K;
+        match self {
+            ModActive | LfoActive | AcsActive | PlinkActive => K::Boolean,
+            PlinkEffect | PlinkParam => K::Integer,
+            ModBaseline | PlinkScale | PlinkOffset => K::FloatingPoint,
+            Custom(_) => K::String,
+        }
+    }
+
+    pub(crate) fn into_raw(self) -> Cow<'a, ReaperStr> {
+        use FxParamConfigKey::*;
+        match self {
+            ModActive => reaper_str!("mod.active").into(),
+            ModBaseline => reaper_str!("mod.baseline").into(),
+            LfoActive => reaper_str!("lfo.active").into(),
+            AcsActive => reaper_str!("acs.active").into(),
+            PlinkActive => reaper_str!("plink.active").into(),
+            PlinkEffect => reaper_str!("plink.effect").into(),
+            PlinkParam => reaper_str!("plink.param").into(),
+            PlinkScale => reaper_str!("plink.scale").into(),
+            PlinkOffset => reaper_str!("plink.offset").into(),
+            Custom(key) => key,
+        }
+    }
+}
+
+/// Format in which REAPER delivers the value of a named FX configuration parameter.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NamedConfigParmValueKind {
+    String,
+    Integer,
+    FloatingPoint,
+    /// Delivered as "0" or "1".
+    Boolean,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -939,4 +1197,24 @@ mod tests {
             reaper_str!("BLA")
         );
     }
+
+    #[test]
+    fn serialize_named_config_parm_key() {
+        use NamedConfigParmKey::*;
+        assert_eq!(FxIdent.into_raw().as_ref(), reaper_str!("fx_ident"));
+        assert_eq!(
+            ContainerItem(3).into_raw().as_ref(),
+            reaper_str!("container_item.3")
+        );
+        assert_eq!(
+            Param(5, FxParamConfigKey::PlinkEffect).into_raw().as_ref(),
+            reaper_str!("param.5.plink.effect")
+        );
+        assert_eq!(
+            Param(0, FxParamConfigKey::custom("lfo.speed"))
+                .into_raw()
+                .as_ref(),
+            reaper_str!("param.0.lfo.speed")
+        );
+    }
 }
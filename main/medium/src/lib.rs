@@ -356,12 +356,18 @@ pub use accelerator_register::*;
 mod file_in_project_hook;
 pub use file_in_project_hook::*;
 
+mod project_config_extension;
+pub use project_config_extension::*;
+
 mod preview_register;
 pub use preview_register::*;
 
 mod audio_hook_register;
 pub use audio_hook_register::*;
 
+mod hook_dispatcher;
+pub use hook_dispatcher::*;
+
 mod keeper;
 
 mod control_surface;
@@ -373,6 +379,9 @@ pub use midi::*;
 mod pcm_source;
 pub use pcm_source::*;
 
+mod pcm_source_reader;
+pub use pcm_source_reader::*;
+
 mod pcm_sink;
 pub use pcm_sink::*;
 
@@ -382,12 +391,30 @@ pub use pitch_shift::*;
 mod resample;
 pub use resample::*;
 
+mod lice;
+pub use lice::*;
+
+mod fx_embed;
+pub use fx_embed::*;
+
+mod config_var;
+pub use config_var::*;
+
 mod reaper_session;
 pub use reaper_session::*;
 
+mod registration;
+pub use registration::*;
+
 mod reaper;
 pub use reaper::*;
 
+mod shared_reaper;
+pub use shared_reaper::*;
+
+mod valid_track;
+pub use valid_track::*;
+
 mod util;
 use util::*;
 
@@ -421,3 +448,6 @@ pub use mutex::*;
 
 mod project_state_context;
 pub use project_state_context::*;
+
+mod ui_refresh;
+pub use ui_refresh::*;
@@ -0,0 +1,105 @@
+use enumflags2::BitFlags;
+use reaper_low::raw;
+
+/// Defines what kind of LICE bitmap should be created.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LiceBitmapKind {
+    /// A bitmap which lives in memory only.
+    Memory,
+    /// A system bitmap, which additionally provides a device context.
+    System,
+}
+
+impl LiceBitmapKind {
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use LiceBitmapKind::*;
+        match self {
+            Memory => 0,
+            System => 1,
+        }
+    }
+}
+
+/// Defines how source pixels are combined with the pixels of the destination bitmap.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LiceBlendMode {
+    /// Replaces the destination pixels.
+    Copy,
+    /// Adds source to destination.
+    Add,
+    /// Dodges the destination with the source.
+    Dodge,
+    /// Multiplies source with destination.
+    Multiply,
+    /// Overlays the source onto the destination.
+    Overlay,
+    /// Interprets the source as hue/saturation/value adjustment.
+    HsvAdjust,
+}
+
+impl LiceBlendMode {
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use LiceBlendMode::*;
+        match self {
+            Copy => 0,
+            Add => 1,
+            Dodge => 2,
+            Multiply => 3,
+            Overlay => 4,
+            HsvAdjust => 5,
+        }
+    }
+}
+
+/// Additional options when drawing to a LICE bitmap.
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum LiceBlitFlag {
+    /// Uses bilinear filtering (only relevant when scaling).
+    FilterBilinear = 0x100,
+    /// Takes the alpha channel of the source into account.
+    UseSourceAlpha = 0x10000,
+}
+
+/// Combines blend mode and flags into the integer expected by the low-level API.
+pub(crate) fn lice_mode_to_raw(mode: LiceBlendMode, flags: BitFlags<LiceBlitFlag>) -> i32 {
+    mode.to_raw() | flags.bits() as i32
+}
+
+/// A pixel color as used by LICE (packed ARGB).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct LicePixel(pub(crate) raw::LICE_pixel);
+
+impl LicePixel {
+    /// Creates a pixel from the given color components.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> LicePixel {
+        LicePixel((b as u32) | ((g as u32) << 8) | ((r as u32) << 16) | ((a as u32) << 24))
+    }
+
+    /// Creates a fully opaque pixel from the given color components.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> LicePixel {
+        LicePixel::rgba(r, g, b, 0xff)
+    }
+
+    /// Creates a pixel from the raw packed value.
+    pub const fn new(raw: raw::LICE_pixel) -> LicePixel {
+        LicePixel(raw)
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub const fn to_raw(self) -> raw::LICE_pixel {
+        self.0
+    }
+}
+
+/// A rectangle within a LICE bitmap.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct LiceRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
@@ -19,3 +19,29 @@ macro_rules! reaper_str {
         result
     }};
 }
+
+/// Marks a string literal as translatable and returns its localized version as `ReaperString`.
+///
+/// The first argument is the medium-level `Reaper` instance, the second one the string literal
+/// and the third one the language pack section (also a literal).
+///
+/// # Example
+///
+/// ```no_run
+/// # let session = reaper_medium::ReaperSession::default();
+/// # let reaper = session.reaper();
+/// use reaper_medium::localize;
+///
+/// let text = localize!(reaper, "Hello REAPER!", "my_extension");
+/// ```
+#[macro_export]
+macro_rules! localize {
+    ($reaper:expr, $lit:literal, $section:literal) => {
+        $reaper.localize_string(
+            $crate::reaper_str!($lit),
+            $crate::reaper_str!($section),
+            Default::default(),
+            |s| s.to_reaper_string(),
+        )
+    };
+}
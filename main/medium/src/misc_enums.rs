@@ -1,6 +1,7 @@
 use crate::{
     BookmarkId, CommandId, Handle, Hidden, Hwnd, InsertMediaFlag, KbdSectionInfo, MediaTrack,
-    MidiOutputDeviceId, ReaProject, ReaperPanValue, ReaperStr, ReaperStringArg, ReaperWidthValue,
+    MidiOutputDeviceId, NamedConfigParmValueKind, ReaProject, ReaperPanValue, ReaperStr,
+    ReaperString, ReaperStringArg, ReaperWidthValue,
 };
 
 use crate::util::concat_reaper_strs;
@@ -345,6 +346,76 @@ impl MeasureAlignment {
     }
 }
 
+/// Defines where a preview register sends its audio.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PreviewOutput {
+    /// Plays through the preview track.
+    ///
+    /// Only has an effect with track previews.
+    PreviewTrack,
+    /// Plays directly through hardware outputs.
+    HardwareOutput {
+        /// Zero-based index of the first output channel.
+        first_channel_index: u32,
+        /// If `true`, plays in mono on the first channel only.
+        mono: bool,
+    },
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl PreviewOutput {
+    /// Converts an integer as returned by the low-level API to a preview output.
+    pub fn from_raw(v: i32) -> Self {
+        use PreviewOutput::*;
+        match v {
+            -1 => PreviewTrack,
+            x if x >= 0 => HardwareOutput {
+                first_channel_index: (x & 0x3ff) as u32,
+                mono: (x & 1024) != 0,
+            },
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use PreviewOutput::*;
+        match self {
+            PreviewTrack => -1,
+            HardwareOutput {
+                first_channel_index,
+                mono,
+            } => {
+                let mono_flag = if mono { 1024 } else { 0 };
+                (first_channel_index & 0x3ff) as i32 | mono_flag
+            }
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Refers to a note or CC whose name can be customized per track.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MidiNoteNameTarget {
+    /// A note, identified by its key number.
+    Note(U7),
+    /// A CC, identified by its controller number.
+    Cc(U7),
+}
+
+impl MidiNoteNameTarget {
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use MidiNoteNameTarget::*;
+        match self {
+            Note(key_number) => key_number.get() as i32,
+            Cc(controller_number) => 128 + controller_number.get() as i32,
+        }
+    }
+}
+
 /// Determines if and how to show/hide a FX user interface.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum FxShowInstruction {
@@ -557,17 +628,77 @@ pub enum TrackFxLocation {
     ///
     /// On the master track (if applicable) this represents an index in the monitoring FX chain.
     InputFxChain(u32),
+    /// FX within a container (REAPER 7+) in the normal FX chain.
+    ///
+    /// The value is the container address as encoded by REAPER. Use [`from_container_path()`] or
+    /// [`track_fx_get_container_item()`] to obtain it.
+    ///
+    /// [`from_container_path()`]: #method.from_container_path
+    /// [`track_fx_get_container_item()`]: struct.Reaper.html#method.track_fx_get_container_item
+    NormalFxChainContainerItem(u32),
+    /// FX within a container (REAPER 7+) in the input FX chain.
+    ///
+    /// See [`NormalFxChainContainerItem`].
+    ///
+    /// [`NormalFxChainContainerItem`]: #variant.NormalFxChainContainerItem
+    InputFxChainContainerItem(u32),
     /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
     /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
     Unknown(Hidden<i32>),
 }
 
 impl TrackFxLocation {
+    /// Creates the location of an FX which is possibly nested in containers.
+    ///
+    /// The path starts at the top-level FX chain. Each segment contains the index of the FX (or
+    /// container) within its parent and the number of FX in that parent. The last segment denotes
+    /// the addressed FX itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is empty or the encoded address doesn't fit into REAPER's addressing
+    /// scheme.
+    pub fn from_container_path(
+        chain_type: TrackFxChainType,
+        path: &[FxContainerPathSegment],
+    ) -> TrackFxLocation {
+        use TrackFxLocation::*;
+        let (last, parents) = path.split_last().expect("container path must not be empty");
+        if parents.is_empty() {
+            return match chain_type {
+                TrackFxChainType::NormalFxChain => NormalFxChain(last.index),
+                TrackFxChainType::InputFxChain => InputFxChain(last.index),
+            };
+        }
+        // REAPER encodes nested FX as sum of (index + 1) * product of (count + 1) of all
+        // enclosing levels.
+        let mut address: u64 = 0;
+        let mut multiplier: u64 = 1;
+        for segment in path {
+            address += (segment.index as u64 + 1) * multiplier;
+            multiplier *= segment.count as u64 + 1;
+        }
+        let address = u32::try_from(address)
+            .ok()
+            .filter(|a| *a < 0x0100_0000)
+            .expect("container path too deep to be addressed");
+        match chain_type {
+            TrackFxChainType::NormalFxChain => NormalFxChainContainerItem(address),
+            TrackFxChainType::InputFxChain => InputFxChainContainerItem(address),
+        }
+    }
+
     /// Converts an integer as returned by the low-level API to a track FX location.
     pub fn from_raw(v: i32) -> TrackFxLocation {
         use TrackFxLocation::*;
         if let Ok(v) = u32::try_from(v) {
-            if v >= 0x0100_0000 {
+            if v >= 0x0400_0000 {
+                Unknown(Hidden(v as i32))
+            } else if v >= 0x0300_0000 {
+                InputFxChainContainerItem(v - 0x0300_0000)
+            } else if v >= 0x0200_0000 {
+                NormalFxChainContainerItem(v - 0x0200_0000)
+            } else if v >= 0x0100_0000 {
                 InputFxChain(v - 0x0100_0000)
             } else {
                 NormalFxChain(v)
@@ -583,10 +714,34 @@ impl TrackFxLocation {
         let positive = match self {
             InputFxChain(idx) => 0x0100_0000 + idx,
             NormalFxChain(idx) => idx,
+            NormalFxChainContainerItem(address) => 0x0200_0000 + address,
+            InputFxChainContainerItem(address) => 0x0300_0000 + address,
             Unknown(Hidden(x)) => return x,
         };
         positive as i32
     }
+
+    /// Returns whether this FX is located within a container.
+    pub fn is_in_container(self) -> bool {
+        use TrackFxLocation::*;
+        matches!(
+            self,
+            NormalFxChainContainerItem(_) | InputFxChainContainerItem(_)
+        )
+    }
+}
+
+/// One step on the way from the top-level FX chain to an FX nested in containers.
+///
+/// See [`TrackFxLocation::from_container_path()`].
+///
+/// [`TrackFxLocation::from_container_path()`]: enum.TrackFxLocation.html#method.from_container_path
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FxContainerPathSegment {
+    /// Index of the FX within its parent (top-level FX chain or container).
+    pub index: u32,
+    /// Number of FX within the parent.
+    pub count: u32,
 }
 
 /// Determines the behavior when adding or querying FX.
@@ -1403,6 +1558,170 @@ impl FadeShape {
     }
 }
 
+/// Shape of the curve between an envelope point and the next one.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EnvelopePointShape {
+    Linear,
+    Square,
+    SlowStartEnd,
+    FastStart,
+    FastEnd,
+    Bezier,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl EnvelopePointShape {
+    /// Converts an integer as returned by the low-level API to an envelope point shape.
+    pub fn from_raw(v: i32) -> Self {
+        use EnvelopePointShape::*;
+        match v {
+            0 => Linear,
+            1 => Square,
+            2 => SlowStartEnd,
+            3 => FastStart,
+            4 => FastEnd,
+            5 => Bezier,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use EnvelopePointShape::*;
+        match self {
+            Linear => 0,
+            Square => 1,
+            SlowStartEnd => 2,
+            FastStart => 3,
+            FastEnd => 4,
+            Bezier => 5,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Defines at which point of the signal flow a track send taps the audio.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TrackSendMode {
+    PostFader,
+    PreFx,
+    /// Post-FX (pre-fader).
+    ///
+    /// REAPER also reports the deprecated raw value 2 as this variant.
+    PostFx,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl TrackSendMode {
+    /// Converts an integer as returned by the low-level API to a send mode.
+    pub fn from_raw(v: i32) -> Self {
+        use TrackSendMode::*;
+        match v {
+            0 => PostFader,
+            1 => PreFx,
+            2 | 3 => PostFx,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use TrackSendMode::*;
+        match self {
+            PostFader => 0,
+            PreFx => 1,
+            PostFx => 3,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Source audio channels of a track send, receive or hardware output.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TrackSendSourceChannels {
+    /// No audio is sent.
+    None,
+    /// A single channel, given by its zero-based index.
+    Mono(u32),
+    /// A range of channels starting at the given zero-based index.
+    ///
+    /// The count is 2 for a stereo pair. Higher counts must be even.
+    Multi { first: u32, count: u32 },
+}
+
+impl TrackSendSourceChannels {
+    /// Converts an integer as returned by the low-level API (`I_SRCCHAN`) to source channels.
+    pub fn from_raw(v: i32) -> Self {
+        use TrackSendSourceChannels::*;
+        if v < 0 {
+            return None;
+        }
+        let first = (v & 0x3ff) as u32;
+        match v >> 10 {
+            0 => Multi { first, count: 2 },
+            1 => Mono(first),
+            n => Multi {
+                first,
+                count: n as u32 * 2,
+            },
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API (`I_SRCCHAN`).
+    pub fn to_raw(self) -> i32 {
+        use TrackSendSourceChannels::*;
+        match self {
+            None => -1,
+            Mono(first) => first as i32 | 1024,
+            Multi { first, count } => {
+                let code = if count <= 2 { 0 } else { count / 2 };
+                first as i32 | (code as i32) << 10
+            }
+        }
+    }
+}
+
+/// Destination audio channels of a track send, receive or hardware output.
+///
+/// The number of channels is determined by the source channels.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TrackSendDestinationChannels {
+    /// Zero-based index of the first destination channel.
+    pub first: u32,
+    /// Whether the source is mixed down to a single channel.
+    pub mono: bool,
+    /// Whether the output goes to ReaRoute (hardware outputs only).
+    pub rearoute: bool,
+}
+
+impl TrackSendDestinationChannels {
+    /// Converts an integer as returned by the low-level API (`I_DSTCHAN`) to destination
+    /// channels.
+    pub fn from_raw(v: i32) -> Self {
+        Self {
+            first: (v & 0x1ff) as u32,
+            mono: v & 1024 != 0,
+            rearoute: v & 512 != 0,
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API (`I_DSTCHAN`).
+    pub fn to_raw(self) -> i32 {
+        let mut raw = self.first as i32;
+        if self.mono {
+            raw |= 1024;
+        }
+        if self.rearoute {
+            raw |= 512;
+        }
+        raw
+    }
+}
+
 /// Track pan.
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum Pan {
@@ -1987,3 +2306,36 @@ pub struct CommandItem<S1, S2> {
     /// Example: `toolbar_add.png`
     pub icon_file_name: Option<S2>,
 }
+
+/// Value of a named FX configuration parameter, parsed according to its
+/// [`NamedConfigParmValueKind`].
+///
+/// [`NamedConfigParmValueKind`]: enum.NamedConfigParmValueKind.html
+#[derive(Clone, PartialEq, Debug)]
+pub enum NamedConfigParmValue {
+    String(ReaperString),
+    Integer(i32),
+    FloatingPoint(f64),
+    Boolean(bool),
+}
+
+impl NamedConfigParmValue {
+    /// Parses the given string returned by REAPER according to the given kind.
+    ///
+    /// Returns `None` if the string doesn't have the expected format.
+    pub fn parse(kind: NamedConfigParmValueKind, value: ReaperString) -> Option<Self> {
+        use NamedConfigParmValue::*;
+        let parsed = match kind {
+            NamedConfigParmValueKind::String => String(value),
+            NamedConfigParmValueKind::Integer => Integer(value.to_str().trim().parse().ok()?),
+            NamedConfigParmValueKind::FloatingPoint => {
+                FloatingPoint(value.to_str().trim().parse().ok()?)
+            }
+            NamedConfigParmValueKind::Boolean => {
+                let number: f64 = value.to_str().trim().parse().ok()?;
+                Boolean(number != 0.0)
+            }
+        };
+        Some(parsed)
+    }
+}
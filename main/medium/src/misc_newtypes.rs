@@ -21,6 +21,8 @@ pub use reaper_common_types::PositionInPulsesPerQuarterNote;
 pub use reaper_common_types::PositionInQuarterNotes;
 pub use reaper_common_types::PositionInSeconds;
 pub use reaper_common_types::Semitones;
+pub use reaper_common_types::TimeRange;
+pub use reaper_common_types::TimeSignature;
 
 /// A command ID.
 ///
@@ -186,6 +186,122 @@ impl PcmSourcePeakTransfer {
     pub fn as_ptr(&self) -> NonNull<raw::PCM_source_peaktransfer_t> {
         NonNull::from(&self.0)
     }
+
+    /// Returns the start time of the requested peaks.
+    pub fn start_time(&self) -> PositionInSeconds {
+        unsafe { PositionInSeconds::new_unchecked(self.0.start_time) }
+    }
+
+    /// Returns the number of peak points per second.
+    pub fn peak_rate(&self) -> Hz {
+        unsafe { Hz::new_unchecked(self.0.peakrate) }
+    }
+
+    /// Returns the number of requested peak points.
+    pub fn num_peak_points(&self) -> u32 {
+        self.0.numpeak_points.max(0) as u32
+    }
+
+    /// Returns the number of channels for which peaks are requested.
+    pub fn nch_peaks(&self) -> u32 {
+        self.0.nchpeaks.max(0) as u32
+    }
+
+    /// Returns the number of peak points actually delivered.
+    pub fn peaks_out(&self) -> u32 {
+        self.0.peaks_out.max(0) as u32
+    }
+
+    /// Sets the number of peak points actually delivered.
+    pub fn set_peaks_out(&mut self, count: u32) {
+        self.0.peaks_out = count as i32;
+    }
+
+    /// Returns whether the requester also wants minimum values.
+    pub fn wants_min_values(&self) -> bool {
+        !self.0.peaks_minvals.is_null()
+    }
+
+    /// Returns the buffer for the (maximum) peak values.
+    ///
+    /// Peaks are interleaved, that is, the buffer contains [`nch_peaks()`] values per peak point.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the transfer doesn't point to a buffer big enough for the requested
+    /// peak points and channels.
+    ///
+    /// [`nch_peaks()`]: #method.nch_peaks
+    pub unsafe fn peaks_mut(&mut self) -> &mut [f64] {
+        if self.0.peaks.is_null() {
+            return &mut [];
+        }
+        std::slice::from_raw_parts_mut(self.0.peaks, self.peak_buffer_len())
+    }
+
+    /// Returns the buffer for the minimum peak values if the requester wants them.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the transfer doesn't point to a buffer big enough for the requested
+    /// peak points and channels.
+    pub unsafe fn peaks_min_mut(&mut self) -> Option<&mut [f64]> {
+        if self.0.peaks_minvals.is_null() {
+            return None;
+        }
+        Some(std::slice::from_raw_parts_mut(
+            self.0.peaks_minvals,
+            self.peak_buffer_len(),
+        ))
+    }
+
+    /// Fills the requested peaks by asking the given function for the peak of each point and
+    /// channel and marks them as delivered.
+    ///
+    /// The function is called with the start time of the peak point and the channel index. If the
+    /// requester doesn't want minimum values, the minimum returned by the function is ignored.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the transfer doesn't point to a buffer big enough for the requested
+    /// peak points and channels.
+    pub unsafe fn fill_peaks(&mut self, mut get_peak: impl FnMut(PositionInSeconds, u32) -> Peak) {
+        let point_count = self.num_peak_points();
+        let channel_count = self.nch_peaks();
+        let start_time = self.start_time().get();
+        let peak_rate = self.peak_rate().get();
+        let peaks = self.0.peaks;
+        let peaks_min = self.0.peaks_minvals;
+        if peaks.is_null() {
+            return;
+        }
+        for point in 0..point_count {
+            let time = PositionInSeconds::new_panic(start_time + point as f64 / peak_rate);
+            for channel in 0..channel_count {
+                let peak = get_peak(time, channel);
+                let i = (point * channel_count + channel) as usize;
+                *peaks.add(i) = peak.max;
+                if !peaks_min.is_null() {
+                    *peaks_min.add(i) = peak.min;
+                }
+            }
+        }
+        self.0.peaks_out = point_count as i32;
+        if !peaks_min.is_null() {
+            self.0.peaks_minvals_used = 1;
+        }
+    }
+
+    fn peak_buffer_len(&self) -> usize {
+        (self.num_peak_points() * self.nch_peaks()) as usize
+    }
+}
+
+/// Maximum and minimum sample value within one peak point.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Peak {
+    pub max: f64,
+    pub min: f64,
 }
 
 // Case 3: Internals exposed: no | vtable: yes
@@ -394,7 +510,7 @@ impl BorrowedPcmSource {
     /// Returns an error if this source doesn't return a valid duration.
     pub fn get_length(&self) -> ReaperFunctionResult<DurationInSeconds> {
         DurationInSeconds::new(self.0.GetLength())
-            .map_err(|_| ReaperFunctionError::new("source doesn't return length"))
+            .map_err(|_| ReaperFunctionError::not_supported("source doesn't return length"))
     }
 
     /// Returns length in beats if supported.
@@ -437,8 +553,8 @@ impl BorrowedPcmSource {
     /// # Safety
     ///
     /// API still unstable.
-    pub unsafe fn get_peak_info(&self, block: &PcmSourcePeakTransfer) {
-        self.0.GetPeakInfo(block.as_ptr().as_ptr());
+    pub unsafe fn get_peak_info(&self, block: &mut PcmSourcePeakTransfer) {
+        self.0.GetPeakInfo(&mut block.0);
     }
 
     /// Unstable!!!
@@ -521,7 +637,7 @@ impl BorrowedPcmSource {
             )
         });
         if supported == 0 {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_supported(
                 "PCM_SOURCE_EXT_GETPOOLEDMIDIID not supported by source",
             ));
         }
@@ -552,7 +668,7 @@ impl BorrowedPcmSource {
             )
         };
         if supported == 0 {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_supported(
                 "PCM_SOURCE_EXT_GETPOOLEDMIDIID not supported by source",
             ));
         }
@@ -578,7 +694,7 @@ impl BorrowedPcmSource {
             )
         };
         if supported == 0 {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_supported(
                 "PCM_SOURCE_EXT_EXPORTTOFILE not supported by source",
             ));
         }
@@ -600,7 +716,7 @@ impl BorrowedPcmSource {
             )
         };
         if supported == 0 {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_supported(
                 "PCM_SOURCE_EXT_REMOVEFROMMIDIPOOL not supported by source",
             ));
         }
@@ -630,7 +746,7 @@ impl BorrowedPcmSource {
             )
         };
         if supported == 0 {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_supported(
                 "PCM_SOURCE_EXT_SETPREVIEWTEMPO not supported by source",
             ));
         }
@@ -654,7 +770,7 @@ impl BorrowedPcmSource {
             null_mut(),
         );
         if supported == 0 {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_supported(
                 "PCM_SOURCE_EXT_OPENEDITOR not supported by source",
             ));
         }
@@ -811,6 +927,49 @@ pub trait CustomPcmSource {
         let _ = args;
         0
     }
+
+    /// If this source represents pooled MIDI data, return information about it.
+    ///
+    /// The ID shouldn't be longer than 39 bytes. Return `None` if not supported.
+    fn ext_get_pooled_midi_id(&mut self) -> Option<ExtGetPooledMidiIdResult> {
+        None
+    }
+
+    /// Return `true` if setting the preview tempo is supported. Otherwise the call is forwarded to
+    /// [`extended()`](Self::extended).
+    fn ext_set_preview_tempo(&mut self, args: ExtSetPreviewTempoArgs) -> bool {
+        let _ = args;
+        false
+    }
+
+    /// Return the number of semitones by which the notation should be transposed for display.
+    ///
+    /// Return `None` if not supported.
+    fn ext_get_notation_display_transpose(&mut self) -> Option<i32> {
+        None
+    }
+
+    /// Return the clefs to be used for notation.
+    ///
+    /// Return `None` if not supported.
+    fn ext_get_notation_clefs(&mut self) -> Option<NotationClefs> {
+        None
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ExtSetPreviewTempoArgs {
+    /// `None` means the source should follow the project tempo again.
+    pub tempo: Option<Bpm>,
+}
+
+/// Clefs used in notation view.
+///
+/// Values are the single-character clef identifiers which REAPER uses internally.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct NotationClefs {
+    pub clef_1: u8,
+    pub clef_2: u8,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -838,9 +997,9 @@ pub struct GetSamplesArgs<'a> {
     pub block: &'a mut PcmSourceTransfer,
 }
 
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(PartialEq, Debug)]
 pub struct GetPeakInfoArgs<'a> {
-    pub block: &'a PcmSourcePeakTransfer,
+    pub block: &'a mut PcmSourcePeakTransfer,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -875,6 +1034,81 @@ impl<S: CustomPcmSource> PcmSourceAdapter<S> {
     pub fn new(delegate: S) -> Self {
         Self { delegate }
     }
+
+    /// Dispatches the `Extended()` calls for which there's a type-safe `ext_` method.
+    ///
+    /// Returns `None` if the call should be forwarded to the generic `extended()` method.
+    unsafe fn handle_typed_extended(
+        &mut self,
+        call: i32,
+        parm1: *mut c_void,
+        parm2: *mut c_void,
+        parm3: *mut c_void,
+    ) -> Option<i32> {
+        match call {
+            raw::PCM_SOURCE_EXT_GETPOOLEDMIDIID => {
+                let result = self.delegate.ext_get_pooled_midi_id()?;
+                if !parm1.is_null() {
+                    // REAPER passes a buffer with room for a GUID string (38 characters plus NUL).
+                    let id = result.id.as_c_str().to_bytes();
+                    let len = id.len().min(39);
+                    let buffer = parm1 as *mut u8;
+                    std::ptr::copy_nonoverlapping(id.as_ptr(), buffer, len);
+                    *buffer.add(len) = 0;
+                }
+                if !parm2.is_null() {
+                    *(parm2 as *mut i32) = result.user_count;
+                }
+                if !parm3.is_null() {
+                    *(parm3 as *mut *mut raw::MediaItem_Take) =
+                        result.first_user.map(|t| t.as_ptr()).unwrap_or(null_mut());
+                }
+                Some(1)
+            }
+            raw::PCM_SOURCE_EXT_SETPREVIEWTEMPO => {
+                let tempo = if parm1.is_null() {
+                    None
+                } else {
+                    let bpm = *(parm1 as *const f64);
+                    if bpm > 0.0 {
+                        Bpm::new(bpm).ok()
+                    } else {
+                        None
+                    }
+                };
+                let supported = self
+                    .delegate
+                    .ext_set_preview_tempo(ExtSetPreviewTempoArgs { tempo });
+                // Unclaimed calls fall through to extended()
+                if supported {
+                    Some(1)
+                } else {
+                    None
+                }
+            }
+            raw::PCM_SOURCE_EXT_GETNOTATIONSETTINGS => match parm1 as isize {
+                1 => {
+                    let transpose = self.delegate.ext_get_notation_display_transpose()?;
+                    if !parm2.is_null() {
+                        *(parm2 as *mut i32) = transpose;
+                    }
+                    Some(1)
+                }
+                2 => {
+                    let clefs = self.delegate.ext_get_notation_clefs()?;
+                    if !parm2.is_null() {
+                        *(parm2 as *mut u8) = clefs.clef_1;
+                    }
+                    if !parm3.is_null() {
+                        *(parm3 as *mut u8) = clefs.clef_2;
+                    }
+                    Some(1)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 impl<S: CustomPcmSource> reaper_low::PCM_source for PcmSourceAdapter<S> {
@@ -990,7 +1224,7 @@ impl<S: CustomPcmSource> reaper_low::PCM_source for PcmSourceAdapter<S> {
         if block.is_null() {
             panic!("called PCM_source::GetPeakInfo() with null block")
         }
-        let block = PcmSourcePeakTransfer::ref_cast(unsafe { &*block });
+        let block = PcmSourcePeakTransfer::ref_cast_mut(unsafe { &mut *block });
         let args = GetPeakInfoArgs { block };
         self.delegate.get_peak_info(args);
     }
@@ -1049,6 +1283,10 @@ impl<S: CustomPcmSource> reaper_low::PCM_source for PcmSourceAdapter<S> {
         parm2: *mut c_void,
         parm3: *mut c_void,
     ) -> i32 {
+        let handled = unsafe { self.handle_typed_extended(call, parm1, parm2, parm3) };
+        if let Some(result) = handled {
+            return result;
+        }
         unsafe {
             self.delegate.extended(ExtendedArgs {
                 call,
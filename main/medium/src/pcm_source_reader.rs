@@ -0,0 +1,203 @@
+use crate::{BorrowedPcmSource, Hz, PcmSourceTransfer, PositionInSeconds};
+
+/// Reads audio samples from a PCM source block by block.
+///
+/// Takes care of filling the transfer struct, advancing the position and detecting the end of the
+/// source. Each block is delivered in interleaved form but can be deinterleaved easily.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn analyze(source: &reaper_medium::BorrowedPcmSource) -> Option<()> {
+/// use reaper_medium::PcmSourceReader;
+///
+/// let mut reader = PcmSourceReader::with_native_format(source, 1024)?;
+/// let mut peak = 0.0f64;
+/// while let Some(block) = reader.read_block() {
+///     for sample in block.channel(0) {
+///         peak = peak.max(sample.abs());
+///     }
+/// }
+/// # Some(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PcmSourceReader<'a> {
+    source: &'a BorrowedPcmSource,
+    sample_rate: Hz,
+    channel_count: u32,
+    block_frame_count: u32,
+    /// Frame index of the next block.
+    ///
+    /// We count frames instead of seconds in order to avoid accumulating rounding errors.
+    next_frame: u64,
+    /// Length of the source in frames, if known.
+    frame_count: Option<u64>,
+    buffer: Vec<f64>,
+}
+
+impl<'a> PcmSourceReader<'a> {
+    /// Creates a reader which reads the given source with the given sample rate and channel
+    /// count, delivering at most `block_frame_count` frames per block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel_count` or `block_frame_count` is 0.
+    pub fn new(
+        source: &'a BorrowedPcmSource,
+        sample_rate: Hz,
+        channel_count: u32,
+        block_frame_count: u32,
+    ) -> Self {
+        assert!(channel_count > 0, "channel count must be greater than 0");
+        assert!(
+            block_frame_count > 0,
+            "block frame count must be greater than 0"
+        );
+        let frame_count = source
+            .get_length()
+            .ok()
+            .map(|length| (length.get() * sample_rate.get()).ceil() as u64);
+        Self {
+            source,
+            sample_rate,
+            channel_count,
+            block_frame_count,
+            next_frame: 0,
+            frame_count,
+            buffer: vec![0.0; (block_frame_count * channel_count) as usize],
+        }
+    }
+
+    /// Creates a reader which reads the given source with its own sample rate and channel count.
+    ///
+    /// Returns `None` if the source doesn't report a sample rate or channel count (e.g. MIDI).
+    pub fn with_native_format(
+        source: &'a BorrowedPcmSource,
+        block_frame_count: u32,
+    ) -> Option<Self> {
+        let sample_rate = source.get_sample_rate()?;
+        let channel_count = source.get_num_channels().filter(|n| *n > 0)?;
+        Some(Self::new(
+            source,
+            sample_rate,
+            channel_count,
+            block_frame_count,
+        ))
+    }
+
+    /// Returns the sample rate used for reading.
+    pub fn sample_rate(&self) -> Hz {
+        self.sample_rate
+    }
+
+    /// Returns the number of channels delivered per frame.
+    pub fn channel_count(&self) -> u32 {
+        self.channel_count
+    }
+
+    /// Returns the position of the next block.
+    pub fn position(&self) -> PositionInSeconds {
+        self.frame_to_position(self.next_frame)
+    }
+
+    /// Moves the read position to the given position.
+    pub fn seek(&mut self, position: PositionInSeconds) {
+        self.next_frame = (position.get().max(0.0) * self.sample_rate.get()).round() as u64;
+    }
+
+    /// Reads the next block.
+    ///
+    /// Returns `None` when the end of the source has been reached.
+    pub fn read_block(&mut self) -> Option<PcmSourceBlock<'_>> {
+        let remaining_frames = match self.frame_count {
+            None => self.block_frame_count as u64,
+            Some(count) => count.checked_sub(self.next_frame).filter(|r| *r > 0)?,
+        };
+        let frames_to_read = remaining_frames.min(self.block_frame_count as u64) as u32;
+        let start = self.frame_to_position(self.next_frame);
+        let mut transfer = PcmSourceTransfer::default();
+        transfer.set_time_s(start);
+        transfer.set_sample_rate(self.sample_rate);
+        let samples_out = unsafe {
+            transfer.set_nch(self.channel_count as i32);
+            transfer.set_length(frames_to_read as i32);
+            transfer.set_samples(self.buffer.as_mut_ptr());
+            self.source.get_samples(&transfer);
+            transfer.samples_out()
+        };
+        if samples_out <= 0 {
+            return None;
+        }
+        let frame_count = (samples_out as u32).min(frames_to_read);
+        self.next_frame += frame_count as u64;
+        let block = PcmSourceBlock {
+            start,
+            channel_count: self.channel_count,
+            samples: &self.buffer[..(frame_count * self.channel_count) as usize],
+        };
+        Some(block)
+    }
+
+    fn frame_to_position(&self, frame: u64) -> PositionInSeconds {
+        PositionInSeconds::new_panic(frame as f64 / self.sample_rate.get())
+    }
+}
+
+/// A block of samples delivered by [`PcmSourceReader`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PcmSourceBlock<'a> {
+    start: PositionInSeconds,
+    channel_count: u32,
+    samples: &'a [f64],
+}
+
+impl<'a> PcmSourceBlock<'a> {
+    /// Returns the position of the first frame within the source.
+    pub fn start(&self) -> PositionInSeconds {
+        self.start
+    }
+
+    /// Returns the number of frames in this block.
+    pub fn frame_count(&self) -> u32 {
+        self.samples.len() as u32 / self.channel_count
+    }
+
+    /// Returns the number of channels.
+    pub fn channel_count(&self) -> u32 {
+        self.channel_count
+    }
+
+    /// Returns all samples in interleaved form.
+    pub fn interleaved(&self) -> &'a [f64] {
+        self.samples
+    }
+
+    /// Returns the samples of the given channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channel index is out of range.
+    pub fn channel(&self, index: u32) -> impl Iterator<Item = f64> + 'a {
+        assert!(index < self.channel_count, "channel index out of range");
+        self.samples
+            .iter()
+            .skip(index as usize)
+            .step_by(self.channel_count as usize)
+            .copied()
+    }
+
+    /// Deinterleaves the samples by appending the samples of each channel to the corresponding
+    /// vector.
+    ///
+    /// Channels for which no vector is given are skipped.
+    pub fn deinterleave_into(&self, channels: &mut [Vec<f64>]) {
+        for (i, channel) in channels
+            .iter_mut()
+            .enumerate()
+            .take(self.channel_count as usize)
+        {
+            channel.extend(self.channel(i as u32));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ReaperPitchShift;
+use crate::{FullPitchShiftMode, Hz, ReaperPitchShift};
 use reaper_low::raw;
 use ref_cast::RefCast;
 use std::ops::{Deref, DerefMut};
@@ -10,9 +10,14 @@ use std::ptr::NonNull;
 /// Owned REAPER pitch shift instance.
 ///
 /// This one automatically destroys the associated C++ `IReaperPitchShift` when dropped.
+///
+/// Unlike [`BorrowedReaperPitchShift`], it remembers the configured channel count, which is why
+/// setting it and streaming samples is only possible via the owned instance.
 #[derive(Eq, PartialEq, Hash, Debug)]
-#[repr(transparent)]
-pub struct OwnedReaperPitchShift(ReaperPitchShift);
+pub struct OwnedReaperPitchShift {
+    raw: ReaperPitchShift,
+    channel_count: Option<u32>,
+}
 
 unsafe impl Send for OwnedReaperPitchShift {}
 
@@ -29,27 +34,113 @@ impl OwnedReaperPitchShift {
     /// You must guarantee that the given instance is currently owner-less, otherwise double-free or
     /// use-after-free can occur.
     pub unsafe fn from_raw(raw: ReaperPitchShift) -> Self {
-        Self(raw)
+        Self {
+            raw,
+            channel_count: None,
+        }
+    }
+
+    /// Returns the channel count which has been set via [`set_nch()`], if any.
+    ///
+    /// [`set_nch()`]: #method.set_nch
+    pub fn channel_count(&self) -> Option<u32> {
+        self.channel_count
+    }
+
+    /// Sets the number of channels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel_count` is 0.
+    pub fn set_nch(&mut self, channel_count: u32) {
+        assert!(channel_count > 0, "channel count must be greater than 0");
+        self.as_mut().0.set_nch(channel_count as i32);
+        self.channel_count = Some(channel_count);
+    }
+
+    /// Feeds the given interleaved input samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no channel count has been set via [`set_nch()`] or if the length of `input` is
+    /// not `frame_count` times the channel count.
+    ///
+    /// [`set_nch()`]: #method.set_nch
+    pub fn feed(&mut self, input: &[f64], frame_count: u32) {
+        let len = self.sample_count(frame_count);
+        assert_eq!(
+            input.len(),
+            len,
+            "input doesn't match frame and channel count"
+        );
+        if frame_count == 0 {
+            return;
+        }
+        let raw = &mut self.as_mut().0;
+        let buffer = raw.GetBuffer(frame_count as i32);
+        if buffer.is_null() {
+            return;
+        }
+        // Safe because REAPER's buffer takes `frame_count` frames of the configured channel count
+        unsafe {
+            std::ptr::copy_nonoverlapping(input.as_ptr(), buffer, len);
+        }
+        raw.BufferDone(frame_count as i32);
+    }
+
+    /// Writes at most `frame_count` processed interleaved frames into `out` and returns the number
+    /// of frames written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no channel count has been set via [`set_nch()`] or if `out` is shorter than
+    /// `frame_count` times the channel count.
+    ///
+    /// [`set_nch()`]: #method.set_nch
+    pub fn get_samples(&mut self, out: &mut [f64], frame_count: u32) -> u32 {
+        let len = self.sample_count(frame_count);
+        assert!(
+            out.len() >= len,
+            "output buffer too small for frame and channel count"
+        );
+        if frame_count == 0 {
+            return 0;
+        }
+        // Safe because we checked that `out` can take `frame_count` frames of the configured
+        // channel count
+        let written = unsafe {
+            self.as_mut()
+                .0
+                .GetSamples(frame_count as i32, out.as_mut_ptr())
+        };
+        written.max(0) as u32
+    }
+
+    fn sample_count(&self, frame_count: u32) -> usize {
+        let channel_count = self
+            .channel_count
+            .expect("channel count must be set via set_nch() before streaming samples");
+        frame_count as usize * channel_count as usize
     }
 }
 
 impl Drop for OwnedReaperPitchShift {
     fn drop(&mut self) {
         unsafe {
-            reaper_low::delete_cpp_reaper_pitch_shift(self.0);
+            reaper_low::delete_cpp_reaper_pitch_shift(self.raw);
         }
     }
 }
 
 impl AsRef<BorrowedReaperPitchShift> for OwnedReaperPitchShift {
     fn as_ref(&self) -> &BorrowedReaperPitchShift {
-        BorrowedReaperPitchShift::from_raw(unsafe { self.0.as_ref() })
+        BorrowedReaperPitchShift::from_raw(unsafe { self.raw.as_ref() })
     }
 }
 
 impl AsMut<BorrowedReaperPitchShift> for OwnedReaperPitchShift {
     fn as_mut(&mut self) -> &mut BorrowedReaperPitchShift {
-        BorrowedReaperPitchShift::from_raw_mut(unsafe { self.0.as_mut() })
+        BorrowedReaperPitchShift::from_raw_mut(unsafe { self.raw.as_mut() })
     }
 }
 
@@ -82,6 +173,49 @@ impl BorrowedReaperPitchShift {
     pub fn as_ptr(&self) -> ReaperPitchShift {
         NonNull::from(self.as_ref())
     }
+
+    /// Sets the sample rate.
+    pub fn set_srate(&mut self, srate: Hz) {
+        self.0.set_srate(srate.get());
+    }
+
+    /// Sets the pitch shift ratio (1.0 means no shift, 2.0 means one octave up).
+    pub fn set_shift(&mut self, ratio: f64) {
+        self.0.set_shift(ratio);
+    }
+
+    /// Sets the formant shift ratio (1.0 means no shift).
+    pub fn set_formant_shift(&mut self, ratio: f64) {
+        self.0.set_formant_shift(ratio);
+    }
+
+    /// Sets the time stretch ratio (1.0 means no stretch).
+    pub fn set_tempo(&mut self, ratio: f64) {
+        self.0.set_tempo(ratio);
+    }
+
+    /// Sets the pitch shift algorithm.
+    pub fn set_quality_parameter(&mut self, mode: FullPitchShiftMode) {
+        self.0.SetQualityParameter(mode.to_raw());
+    }
+
+    /// Resets the internal state, e.g. when starting to process a new stream.
+    pub fn reset(&mut self) {
+        self.0.Reset();
+    }
+
+    /// Returns whether the internal state is reset (nothing fed yet).
+    pub fn is_reset(&mut self) -> bool {
+        self.0.IsReset()
+    }
+
+    /// Signals that no more input follows, so that the remaining samples can be retrieved via
+    /// [`get_samples()`].
+    ///
+    /// [`get_samples()`]: OwnedReaperPitchShift::get_samples
+    pub fn flush_samples(&mut self) {
+        self.0.FlushSamples();
+    }
 }
 
 impl AsRef<raw::IReaperPitchShift> for BorrowedReaperPitchShift {
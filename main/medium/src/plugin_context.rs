@@ -6,6 +6,7 @@ use reaper_low::raw;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 use std::ptr::{null_mut, NonNull};
+use std::sync::atomic::{AtomicBool, Ordering};
 use vst::api::AEffect;
 
 /// This represents the context in which this REAPER plug-in runs.
@@ -36,7 +37,20 @@ impl<'a, UsageScope> PluginContext<'a, UsageScope> {
         unsafe { self.low.GetFunc(name.into().as_ptr()) }
     }
 
-    /// On Windows, this returns the `HINSTANCE` passed to `DllMain` (VST plug-ins) or
+    /// Loads functions which other extensions (e.g. SWS, js_ReaScriptAPI or ReaImGui) expose via
+    /// `plugin_register("API_...")`.
+    ///
+    /// The functions are declared using [`reaper_low::extension_functions!`]. Check
+    /// [`is_available()`](reaper_low::ExtensionFunctions::is_available) before calling them.
+    pub fn load_extension_functions<T: reaper_low::ExtensionFunctions>(&self) -> T
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        T::load(self.low)
+    }
+
+    /// On Windows, this returns the `HINSTANCE` passed to `DllMain` (VST and CLAP plug-ins) or
     /// `ReaperPluginEntry` (extension plug-ins).
     ///
     /// The returned `HINSTANCE` represents the handle of the module (DLL) containing the plug-in.
@@ -66,6 +80,8 @@ impl<'a, UsageScope> PluginContext<'a, UsageScope> {
         match self.low.type_specific() {
             Extension(low) => TypeSpecificPluginContext::Extension(ExtensionPluginContext { low }),
             Vst(low) => TypeSpecificPluginContext::Vst(VstPluginContext { low }),
+            Clap(low) => TypeSpecificPluginContext::Clap(ExtensionPluginContext { low }),
+            Other(_) => TypeSpecificPluginContext::Other,
         }
     }
 
@@ -73,10 +89,40 @@ impl<'a, UsageScope> PluginContext<'a, UsageScope> {
     where
         UsageScope: MainThreadOnly,
     {
+        require_main_thread(self.low);
+    }
+}
+
+static MAIN_THREAD_CHECK_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Disables the check whether main-thread-only functions are called from the main thread (in
+/// builds without debug assertions).
+///
+/// This saves a bit of overhead per call. Only do this if you are absolutely sure that your
+/// plug-in never calls a main-thread-only function from another thread.
+///
+/// # Safety
+///
+/// Calling a main-thread-only function from another thread is undefined behavior after calling
+/// this, so REAPER can crash instead of your plug-in panicking.
+pub unsafe fn disable_main_thread_check() {
+    MAIN_THREAD_CHECK_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Panics if not called from the main thread.
+///
+/// After [`disable_main_thread_check()`], this only checks if debug assertions are enabled.
+pub(crate) fn require_main_thread(context: &reaper_low::PluginContext) {
+    if MAIN_THREAD_CHECK_DISABLED.load(Ordering::Relaxed) {
+        debug_assert!(
+            context.is_in_main_thread(),
+            "called main-thread-only function from wrong thread"
+        );
+    } else {
         assert!(
-            self.is_in_main_thread(),
+            context.is_in_main_thread(),
             "called main-thread-only function from wrong thread"
-        )
+        );
     }
 }
 
@@ -87,6 +133,13 @@ pub enum TypeSpecificPluginContext<'a> {
     Extension(ExtensionPluginContext<'a>),
     /// This is a VST plug-in.
     Vst(VstPluginContext<'a>),
+    /// This is a CLAP plug-in.
+    ///
+    /// REAPER hands out the same plug-in info to CLAP plug-ins as to extension plug-ins.
+    Clap(ExtensionPluginContext<'a>),
+    /// This is a plug-in of some other type which obtained REAPER's function provider in a
+    /// host-specific way.
+    Other,
 }
 
 /// Additional data available in the context of extension plug-ins.
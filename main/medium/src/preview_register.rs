@@ -1,7 +1,7 @@
 use crate::mutex::ReaperMutex;
 use crate::{
     destroy_mutex_primitive, initialize_mutex_primitive, FlexibleOwnedPcmSource, MediaTrack,
-    PositionInSeconds, ReaperMutexPrimitive, ReaperVolumeValue,
+    PositionInSeconds, PreviewOutput, ReaperMutexPrimitive, ReaperVolumeValue,
 };
 use reaper_low::raw;
 use std::fmt;
@@ -97,16 +97,42 @@ impl OwnedPreviewRegister {
         self.register.preview_track = track.map(|t| t.as_ptr() as _).unwrap_or(null_mut());
     }
 
-    /// Unstable!!!
-    // TODO-high-unstable Improve API. This can be either a track index or a HW output channel or
-    //  none. preview_track only has an effect if this is none.
+    /// Returns where this register sends its audio.
+    pub fn output(&self) -> PreviewOutput {
+        PreviewOutput::from_raw(self.register.m_out_chan)
+    }
+
+    /// Sets where this register sends its audio.
+    ///
+    /// The preview track is only taken into account if this is [`PreviewOutput::PreviewTrack`].
+    pub fn set_output(&mut self, output: PreviewOutput) {
+        self.register.m_out_chan = output.to_raw();
+    }
+
+    /// Returns the raw output channel.
+    ///
+    /// Prefer [`output()`](Self::output).
     pub fn out_chan(&self) -> i32 {
         self.register.m_out_chan
     }
 
+    /// Sets the raw output channel.
+    ///
+    /// Prefer [`set_output()`](Self::set_output).
     pub fn set_out_chan(&mut self, value: i32) {
         self.register.m_out_chan = value;
     }
+
+    /// Returns the current peak volumes of the left and right channel.
+    ///
+    /// These are written by REAPER while the preview is playing.
+    pub fn peak_volumes(&self) -> (ReaperVolumeValue, ReaperVolumeValue) {
+        let [left, right] = self.register.peakvol;
+        (
+            ReaperVolumeValue::new_panic(left),
+            ReaperVolumeValue::new_panic(right),
+        )
+    }
 }
 
 impl Default for OwnedPreviewRegister {
@@ -0,0 +1,136 @@
+use crate::{decode_user_data, encode_user_data, BorrowedProjectStateContext, ReaperStr};
+use reaper_low::{firewall, raw};
+use std::fmt;
+use std::fmt::Debug;
+use std::os::raw::c_char;
+
+/// Consumers need to implement this trait in order to take part in loading and saving projects.
+///
+/// See [`register_project_config_extension()`].
+///
+/// [`register_project_config_extension()`]:
+/// struct.ReaperSession.html#method.register_project_config_extension
+pub trait ProjectConfigExtension {
+    /// Called for each line of a project or undo state which REAPER itself doesn't understand.
+    ///
+    /// Should return `true` if the line (and maybe subsequent lines read from the context) has been
+    /// processed.
+    fn process_extension_line(&mut self, args: ProcessExtensionLineArgs) -> bool {
+        let _ = args;
+        false
+    }
+
+    /// Called when saving a project or undo state. Own lines can be written to the context.
+    fn save_extension_config(&mut self, args: SaveExtensionConfigArgs) {
+        let _ = args;
+    }
+
+    /// Called when loading a project or undo state, before any call of
+    /// [`process_extension_line()`](Self::process_extension_line). Also called when a new project
+    /// is created.
+    ///
+    /// Use [`Reaper::get_current_project_in_load_save()`] to find out which project is affected.
+    ///
+    /// [`Reaper::get_current_project_in_load_save()`]:
+    /// struct.Reaper.html#method.get_current_project_in_load_save
+    fn begin_load_project_state(&mut self, args: BeginLoadProjectStateArgs) {
+        let _ = args;
+    }
+}
+
+pub struct ProcessExtensionLineArgs<'a> {
+    pub line: &'a ReaperStr,
+    pub context: &'a BorrowedProjectStateContext,
+    pub is_undo: bool,
+}
+
+pub struct SaveExtensionConfigArgs<'a> {
+    pub context: &'a BorrowedProjectStateContext,
+    pub is_undo: bool,
+}
+
+pub struct BeginLoadProjectStateArgs {
+    pub is_undo: bool,
+}
+
+pub(crate) struct OwnedProjectConfigExtension {
+    inner: raw::project_config_extension_t,
+    // Only accessed by REAPER via user data pointer
+    _callback: Box<dyn ProjectConfigExtension>,
+}
+
+impl Debug for OwnedProjectConfigExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // ProjectConfigExtension doesn't generally implement Debug.
+        f.debug_struct("OwnedProjectConfigExtension")
+            .field("inner", &self.inner)
+            .field("callback", &"<omitted>")
+            .finish()
+    }
+}
+
+impl OwnedProjectConfigExtension {
+    pub fn new<T>(callback: Box<T>) -> OwnedProjectConfigExtension
+    where
+        T: ProjectConfigExtension + 'static,
+    {
+        OwnedProjectConfigExtension {
+            inner: raw::project_config_extension_t {
+                ProcessExtensionLine: Some(delegating_process_extension_line::<T>),
+                SaveExtensionConfig: Some(delegating_save_extension_config::<T>),
+                BeginLoadProjectState: Some(delegating_begin_load_project_state::<T>),
+                // Not a fat pointer. Thanks to generics the delegating functions know the concrete
+                // type.
+                userData: encode_user_data(&callback),
+            },
+            _callback: callback,
+        }
+    }
+}
+
+impl AsRef<raw::project_config_extension_t> for OwnedProjectConfigExtension {
+    fn as_ref(&self) -> &raw::project_config_extension_t {
+        &self.inner
+    }
+}
+
+extern "C" fn delegating_process_extension_line<T: ProjectConfigExtension>(
+    line: *const c_char,
+    ctx: *mut raw::ProjectStateContext,
+    is_undo: bool,
+    reg: *mut raw::project_config_extension_t,
+) -> bool {
+    firewall(|| {
+        let callback: &mut T = decode_user_data(unsafe { (*reg).userData });
+        callback.process_extension_line(ProcessExtensionLineArgs {
+            line: unsafe { ReaperStr::from_ptr(line) },
+            context: BorrowedProjectStateContext::from_raw(unsafe { &*ctx }),
+            is_undo,
+        })
+    })
+    .unwrap_or(false)
+}
+
+extern "C" fn delegating_save_extension_config<T: ProjectConfigExtension>(
+    ctx: *mut raw::ProjectStateContext,
+    is_undo: bool,
+    reg: *mut raw::project_config_extension_t,
+) {
+    firewall(|| {
+        let callback: &mut T = decode_user_data(unsafe { (*reg).userData });
+        callback.save_extension_config(SaveExtensionConfigArgs {
+            context: BorrowedProjectStateContext::from_raw(unsafe { &*ctx }),
+            is_undo,
+        });
+    });
+}
+
+extern "C" fn delegating_begin_load_project_state<T: ProjectConfigExtension>(
+    is_undo: bool,
+    reg: *mut raw::project_config_extension_t,
+) {
+    firewall(|| {
+        let callback: &mut T = decode_user_data(unsafe { (*reg).userData });
+        callback.begin_load_project_state(BeginLoadProjectStateArgs { is_undo });
+    });
+}
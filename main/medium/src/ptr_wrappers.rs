@@ -316,3 +316,6 @@ pub type ReaperPitchShift = NonNull<raw::IReaperPitchShift>;
 
 /// Pointer to a REAPER resample instance.
 pub type ReaperResample = NonNull<raw::REAPER_Resample_Interface>;
+
+/// Pointer to a LICE bitmap.
+pub type LiceBitmap = NonNull<raw::LICE_IBitmap>;
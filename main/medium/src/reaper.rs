@@ -6,50 +6,55 @@ use reaper_low::{raw, register_plugin_destroy_hook};
 
 use crate::ProjectContext::CurrentProject;
 use crate::{
-    require_media_track_panic, Accel, ActionValueChange, AddFxBehavior,
+    require_main_thread, require_media_track_panic, Accel, ActionValueChange, AddFxBehavior,
     AdvancePlaybackPositionEvent, AudioDeviceAttributeKey, AutoSeekBehavior, AutomationMode,
-    BeatAttachMode, BookmarkId, BookmarkRef, Bpm, ChunkCacheHint, CommandId, CommandItem, Db,
-    DurationInSeconds, EditMode, EnvChunkName, FadeCurvature, FadeShape, FullPitchShiftMode,
-    FxAddByNameBehavior, FxChainVisibility, FxPresetRef, FxShowInstruction, GangBehavior,
-    GetThemeColorFlags, GlobalAutomationModeOverride, HelpMode, Hidden, Hwnd, InitialAction,
-    InputMonitoringMode, InsertMediaFlag, InsertMediaMode, ItemAttributeKey, ItemGroupId,
-    KbdSectionInfo, MarkerOrRegionPosition, MasterTrackBehavior, MeasureMode, MediaItem,
-    MediaItemTake, MediaTrack, MenuOrToolbarItem, MessageBoxResult, MessageBoxType,
-    MidiImportBehavior, MidiInput, MidiInputDeviceId, MidiOutput, MidiOutputDeviceId, NativeColor,
-    NormalizedPlayRate, NotificationBehavior, OpenMediaExplorerMode, OpenProjectBehavior,
-    OwnedPcmSource, OwnedReaperPitchShift, OwnedReaperResample, PanMode, ParamId, PcmSource,
-    PeakFileMode, PitchShiftMode, PitchShiftSubMode, PlaybackSpeedFactor, PluginContext,
-    PositionDescriptor, PositionInBeats, PositionInPulsesPerQuarterNote, PositionInQuarterNotes,
-    PositionInSeconds, Progress, ProjectContext, ProjectInfoAttributeKey, ProjectRef,
+    BeatAttachMode, BookmarkId, BookmarkRef, Bpm, ChunkCacheHint, CommandId, CommandItem,
+    ConfigVarType, Db, DurationInSeconds, EditMode, EnvChunkName, EnvelopePointShape,
+    FadeCurvature, FadeShape, FullPitchShiftMode, FxAddByNameBehavior, FxChainVisibility,
+    FxPresetRef, FxShowInstruction, GangBehavior, GetThemeColorFlags, GlobalAutomationModeOverride,
+    HelpMode, Hidden, Hwnd, InitialAction, InputMonitoringMode, InsertMediaFlag, InsertMediaMode,
+    ItemAttributeKey, ItemEditingOperation, ItemGroupId, KbdSectionInfo, LiceBitmap,
+    LiceBitmapKind, LiceBlendMode, LiceBlitFlag, LicePixel, LiceRect, LocalizeFlag,
+    MarkerOrRegionPosition, MasterTrackBehavior, MeasureMode, MediaItem, MediaItemTake, MediaTrack,
+    MenuOrToolbarItem, MessageBoxResult, MessageBoxType, MidiImportBehavior, MidiInput,
+    MidiInputDeviceId, MidiNoteNameTarget, MidiOutput, MidiOutputDeviceId, NamedConfigParmKey,
+    NamedConfigParmValue, NativeColor, NormalizedPlayRate, NotificationBehavior,
+    OpenMediaExplorerMode, OpenProjectBehavior, OwnedPcmSource, OwnedReaperPitchShift,
+    OwnedReaperResample, PanMode, ParamId, PcmSource, PeakFileMode, PitchShiftMode,
+    PitchShiftSubMode, PlaybackSpeedFactor, PluginContext, PositionDescriptor, PositionInBeats,
+    PositionInPulsesPerQuarterNote, PositionInQuarterNotes, PositionInSeconds,
+    PreventUiRefreshGuard, Progress, ProjectContext, ProjectInfoAttributeKey, ProjectRef,
     PromptForActionResult, ReaProject, ReaperFunctionError, ReaperFunctionResult,
     ReaperNormalizedFxParamValue, ReaperPanLikeValue, ReaperPanValue, ReaperPointer, ReaperStr,
     ReaperString, ReaperStringArg, ReaperVersion, ReaperVolumeValue, ReaperWidthValue,
     RecordArmMode, RecordingInput, RecordingMode, ReorderTracksBehavior, RequiredViewMode,
-    ResampleMode, SectionContext, SectionId, SendTarget, SetTrackUiFlags, SoloMode,
-    StuffMidiMessageTarget, SubMenuStart, TakeAttributeKey, TimeModeOverride, TimeRangeType,
-    TrackArea, TrackAttributeKey, TrackDefaultsBehavior, TrackEnvelope, TrackFxChainType,
-    TrackFxLocation, TrackLocation, TrackMuteOperation, TrackMuteState, TrackPolarity,
-    TrackPolarityOperation, TrackRecArmOperation, TrackSendAttributeKey, TrackSendCategory,
-    TrackSendDirection, TrackSendRef, TrackSoloOperation, TransferBehavior, UiRefreshBehavior,
-    UndoBehavior, UndoScope, ValueChange, VolumeSliderValue, WindowContext,
+    ResampleMode, SaveProjectFlag, SectionContext, SectionId, SendTarget, SetProjectMarkerFlag,
+    SetTrackUiFlags, SoloMode, StuffMidiMessageTarget, SubMenuStart, TakeAttributeKey,
+    TimeModeOverride, TimeRange, TimeRangeType, TimeSignature, TrackArea, TrackAttributeKey,
+    TrackDefaultsBehavior, TrackEnvelope, TrackFxChainType, TrackFxLocation, TrackLocation,
+    TrackMidiLyricsFlag, TrackMuteOperation, TrackMuteState, TrackPolarity, TrackPolarityOperation,
+    TrackRecArmOperation, TrackSendAttributeKey, TrackSendCategory, TrackSendDirection,
+    TrackSendRef, TrackSoloOperation, TransferBehavior, UiRefreshBehavior, UndoBehavior, UndoScope,
+    ValueChange, VolumeSliderValue, WindowContext, WrongThreadError,
 };
 pub use reaper_common_types::RgbColor;
 use reaper_common_types::{Hz, Semitones};
 
-use helgoboss_midi::ShortMessage;
+use helgoboss_midi::{Channel, ShortMessage};
 use reaper_low::raw::GUID;
 
+use crate::lice::lice_mode_to_raw;
 use crate::ptr_wrappers::require_hwnd_panic;
 use crate::util::{
-    create_passing_c_str, with_buffer, with_string_buffer, with_string_buffer_cstring,
-    with_string_buffer_prefilled,
+    create_passing_c_str, with_auto_sized_string_buffer, with_auto_sized_string_buffer_cstring,
+    with_buffer, with_checked_auto_sized_string_buffer, with_string_buffer,
+    with_string_buffer_cstring, with_string_buffer_prefilled,
 };
 use camino::{Utf8Path, Utf8PathBuf};
 use enumflags2::BitFlags;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
-use std::num::NonZeroU32;
 
 /// Represents a privilege to execute functions which are safe to execute from any thread.
 pub trait AnyThread: private::Sealed {}
@@ -92,7 +97,8 @@ impl AnyThread for RealTimeAudioThreadScope {}
 /// it, e.g. in order to make all functions accessible somewhere else. This is sometimes easier than
 /// passing references around. Don't do it too often though. It's just a bitwise copy of all
 /// function pointers, but there are around 800 of them, so each copy will occupy about 7 kB of
-/// memory on a 64-bit system.
+/// memory on a 64-bit system. If you need to hand out REAPER access to many places, use
+/// [`SharedReaper`] instead, which is just a reference-counted pointer.
 ///
 /// # Panics
 ///
@@ -249,6 +255,45 @@ where
         &self.features
     }
 
+    /// Executes the given function and returns an error instead of panicking if it uses a REAPER
+    /// function which is not available in the running REAPER version.
+    ///
+    /// This lets you degrade gracefully on older REAPER versions without checking function
+    /// pointers of the low-level API yourself. See [`reaper_low::catch_function_not_available()`]
+    /// for details and limitations.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// let reaper = session.reaper();
+    /// match reaper.checked(|r| r.get_app_version()) {
+    ///     Ok(version) => println!("REAPER {version}"),
+    ///     Err(e) => println!("Can't determine version: {e}"),
+    /// }
+    /// ```
+    pub fn checked<R>(
+        &self,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Result<R, reaper_low::FunctionNotAvailableError> {
+        reaper_low::catch_function_not_available(|| f(self))
+    }
+
+    /// Executes the given function if called from the main thread and returns an error otherwise.
+    ///
+    /// Main-thread-only functions panic if called from another thread. Use this if you want to
+    /// surface such misuse as recoverable error instead, e.g. in the API of a library which builds
+    /// on this crate.
+    pub fn try_in_main_thread<R>(&self, f: impl FnOnce(&Self) -> R) -> Result<R, WrongThreadError>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        if !self.low.plugin_context().is_in_main_thread() {
+            return Err(WrongThreadError);
+        }
+        Ok(f(self))
+    }
+
     /// Returns the requested project and optionally its file name.
     ///
     /// With `buffer_size` you can tell REAPER how many bytes of the file name you want. If you
@@ -456,6 +501,17 @@ where
         self.low.UpdateArrange();
     }
 
+    /// Prevents UI refreshes until the returned guard is dropped.
+    ///
+    /// Useful to speed up operations which modify many things at once. Calls can be nested.
+    pub fn prevent_ui_refresh(&self) -> PreventUiRefreshGuard<'_>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        PreventUiRefreshGuard::new(&self.low)
+    }
+
     pub fn get_theme_color<'a>(
         &self,
         ini_key: impl Into<ReaperStringArg<'a>>,
@@ -470,7 +526,9 @@ where
                 .GetThemeColor(ini_key.into().as_ptr(), flags.bits() as _)
         };
         if color == -1 {
-            return Err(ReaperFunctionError::new("failed to get theme color"));
+            return Err(ReaperFunctionError::invalid_argument(
+                "failed to get theme color",
+            ));
         }
         Ok(NativeColor::new(color))
     }
@@ -697,6 +755,112 @@ where
         PositionInPulsesPerQuarterNote::new_panic(pos)
     }
 
+    /// Returns whether the given take contains MIDI.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_is_midi(&self, take: MediaItemTake) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TakeIsMIDI(take.as_ptr())
+    }
+
+    /// Returns all MIDI events of the given take in REAPER's packed event list format.
+    ///
+    /// Each event consists of a 32-bit little-endian tick offset relative to the previous event, a
+    /// flag byte (`&1` → selected, `&2` → muted, high 4 bits → CC shape), a 32-bit little-endian
+    /// message length and the message bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the take is not a MIDI take or if its events don't fit into 4 MB.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_get_all_evts(&self, take: MediaItemTake) -> ReaperFunctionResult<Vec<u8>>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        if !self.low.TakeIsMIDI(take.as_ptr()) {
+            return Err(ReaperFunctionError::invalid_argument(
+                "couldn't get MIDI events because take is not a MIDI take",
+            ));
+        }
+        // REAPER reports a too small buffer by returning false, so we grow the buffer until a sane
+        // limit is reached.
+        let mut buffer_size = 64 * 1024;
+        while buffer_size <= 4 * 1024 * 1024 {
+            let mut buffer: Vec<u8> = vec![0; buffer_size];
+            let mut size = buffer_size as i32;
+            let successful = self.low.MIDI_GetAllEvts(
+                take.as_ptr(),
+                buffer.as_mut_ptr() as *mut c_char,
+                &mut size,
+            );
+            if successful {
+                buffer.truncate(size.max(0) as usize);
+                return Ok(buffer);
+            }
+            buffer_size *= 4;
+        }
+        Err(ReaperFunctionError::not_supported(
+            "couldn't get MIDI events because there are too many of them",
+        ))
+    }
+
+    /// Replaces all MIDI events of the given take with the given packed event list.
+    ///
+    /// See [`midi_get_all_evts()`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the take is not a MIDI take or the event list is malformed.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`midi_get_all_evts()`]: #method.midi_get_all_evts
+    pub unsafe fn midi_set_all_evts(
+        &self,
+        take: MediaItemTake,
+        events: &[u8],
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self.low.MIDI_SetAllEvts(
+            take.as_ptr(),
+            events.as_ptr() as *const c_char,
+            events.len() as i32,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::invalid_argument(
+                "couldn't set MIDI events (maybe take is not a MIDI take)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sorts the MIDI events of the given take.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_sort(&self, take: MediaItemTake)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.MIDI_Sort(take.as_ptr());
+    }
+
     /// Gets a media item take attribute as numerical value.
     ///
     /// # Safety
@@ -740,7 +904,7 @@ where
             new_value,
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::invalid_argument(
                 "couldn't set take attribute (maybe attribute key is invalid)",
             ));
         }
@@ -772,7 +936,7 @@ where
             new_value,
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::invalid_argument(
                 "couldn't set item attribute (maybe attribute key is invalid)",
             ));
         }
@@ -1536,6 +1700,48 @@ where
         NativeColorValue::from_raw(raw)
     }
 
+    /// Returns the color which is used to display the given item.
+    ///
+    /// Depending on the user preference, this is the custom color of the active take, of the item
+    /// or of the track. Returns `None` if the item is displayed without custom color.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn get_displayed_media_item_color(&self, item: MediaItem) -> Option<NativeColor>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw = self.low.GetDisplayedMediaItemColor(item.as_ptr());
+        convert_displayed_color(raw)
+    }
+
+    /// Returns the color which is used to display the given item or take.
+    ///
+    /// Depending on the user preference, this is the custom color of the given take, of the item
+    /// or of the track. If you don't pass a take, the active take is considered. Returns `None` if
+    /// displayed without custom color.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item or take.
+    pub unsafe fn get_displayed_media_item_color_2(
+        &self,
+        item: MediaItem,
+        take: Option<MediaItemTake>,
+    ) -> Option<NativeColor>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw = self.low.GetDisplayedMediaItemColor2(
+            item.as_ptr(),
+            take.map(|t| t.as_ptr()).unwrap_or(null_mut()),
+        );
+        convert_displayed_color(raw)
+    }
+
     /// Convenience function which sets the item's custom color (`I_CUSTOMCOLOR`).
     ///
     /// # Safety
@@ -1660,11 +1866,149 @@ where
             true,
         );
         if !successful {
-            return Err(ReaperFunctionError::new("couldn't set project info string"));
+            return Err(ReaperFunctionError::invalid_argument(
+                "couldn't set project info string",
+            ));
         }
         Ok(())
     }
 
+    /// Returns a project info string attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attribute is not a string attribute or unknown.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_project_info_string_get(
+        &self,
+        project: ProjectContext,
+        attribute_key: ProjectInfoAttributeKey,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_set_project_info_string_get_unchecked(project, attribute_key) }
+    }
+
+    /// Like [`get_set_project_info_string_get()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_project_info_string_get()`]: #method.get_set_project_info_string_get
+    pub unsafe fn get_set_project_info_string_get_unchecked(
+        &self,
+        project: ProjectContext,
+        attribute_key: ProjectInfoAttributeKey,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        // REAPER doesn't let us pass the buffer size, so we need to choose a generous one.
+        let (value, successful) = with_string_buffer(64 * 1024, |buffer, _| {
+            self.low.GetSetProjectInfo_String(
+                project.to_raw(),
+                attribute_key.into_raw().as_ptr(),
+                buffer,
+                false,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::invalid_argument(
+                "couldn't get project info string",
+            ));
+        }
+        Ok(value)
+    }
+
+    /// Returns a numeric project info attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_project_info_get(
+        &self,
+        project: ProjectContext,
+        attribute_key: ProjectInfoAttributeKey,
+    ) -> f64
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_set_project_info_get_unchecked(project, attribute_key) }
+    }
+
+    /// Like [`get_set_project_info_get()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_project_info_get()`]: #method.get_set_project_info_get
+    pub unsafe fn get_set_project_info_get_unchecked(
+        &self,
+        project: ProjectContext,
+        attribute_key: ProjectInfoAttributeKey,
+    ) -> f64
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.GetSetProjectInfo(
+            project.to_raw(),
+            attribute_key.into_raw().as_ptr(),
+            0.0,
+            false,
+        )
+    }
+
+    /// Sets a numeric project info attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_project_info_set(
+        &self,
+        project: ProjectContext,
+        attribute_key: ProjectInfoAttributeKey,
+        value: f64,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_set_project_info_set_unchecked(project, attribute_key, value) }
+    }
+
+    /// Like [`get_set_project_info_set()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_project_info_set()`]: #method.get_set_project_info_set
+    pub unsafe fn get_set_project_info_set_unchecked(
+        &self,
+        project: ProjectContext,
+        attribute_key: ProjectInfoAttributeKey,
+        value: f64,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.GetSetProjectInfo(
+            project.to_raw(),
+            attribute_key.into_raw().as_ptr(),
+            value,
+            true,
+        );
+    }
+
     /// Convenience function which returns the given track's input monitoring mode (`I_RECMON`).
     ///
     /// # Safety
@@ -1746,75 +2090,177 @@ where
         self.get_set_media_track_info(track, TrackAttributeKey::ShowInTcp, &show as *const _ as _);
     }
 
-    /// Convenience function which returns the given track's pan mode (I_PANMODE).
-    ///
-    /// Returns `None` if the track uses the project default.
+    /// Convenience function which returns whether the track is shown in the mixer
+    /// (`B_SHOWINMIXER`).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_pan_mode(&self, track: MediaTrack) -> Option<PanMode>
+    pub unsafe fn get_set_media_track_info_get_show_in_mixer(&self, track: MediaTrack) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::PanMode, null_mut());
-        let ipanmode = deref_as::<i32>(ptr).expect("I_PANMODE pointer is null");
-        if ipanmode == -1 {
-            return None;
-        }
-        Some(PanMode::from_raw(ipanmode))
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::ShowInMixer, null_mut());
+        deref_as::<bool>(ptr).expect("B_SHOWINMIXER pointer is null")
     }
 
-    /// Convenience function which returns the given track's pan (D_PAN).
+    /// Convenience function which returns whether the track is shown in the arrange view
+    /// (`B_SHOWINTCP`).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_pan(&self, track: MediaTrack) -> ReaperPanValue
+    pub unsafe fn get_set_media_track_info_get_show_in_tcp(&self, track: MediaTrack) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Pan, null_mut());
-        let pan = deref_as::<f64>(ptr).expect("I_PAN pointer is null");
-        ReaperPanValue::new_panic(pan)
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::ShowInTcp, null_mut());
+        deref_as::<bool>(ptr).expect("B_SHOWINTCP pointer is null")
     }
 
-    /// Convenience function which returns the given track's dual-pan position 1 (D_DUALPANL).
+    /// Convenience function which returns whether the track sends to its parent or the master
+    /// track (`B_MAINSEND`).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_dual_pan_l(
-        &self,
-        track: MediaTrack,
-    ) -> ReaperPanValue
+    pub unsafe fn get_set_media_track_info_get_main_send(&self, track: MediaTrack) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanL, null_mut());
-        let pan = deref_as::<f64>(ptr).expect("D_DUALPANL pointer is null");
-        ReaperPanValue::new_panic(pan)
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::MainSend, null_mut());
+        deref_as::<bool>(ptr).expect("B_MAINSEND pointer is null")
     }
 
-    /// Convenience function which returns the given track's dual-pan position 2 (D_DUALPANR).
+    /// Convenience function which sets whether the track sends to its parent or the master track
+    /// (`B_MAINSEND`).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_dual_pan_r(
-        &self,
-        track: MediaTrack,
-    ) -> ReaperPanValue
+    pub unsafe fn get_set_media_track_info_set_main_send(&self, track: MediaTrack, enabled: bool)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanR, null_mut());
-        let pan = deref_as::<f64>(ptr).expect("D_DUALPANR pointer is null");
-        ReaperPanValue::new_panic(pan)
+        self.get_set_media_track_info(
+            track,
+            TrackAttributeKey::MainSend,
+            &enabled as *const _ as _,
+        );
+    }
+
+    /// Convenience function which returns the channel offset of the track's send to its parent
+    /// (`C_MAINSEND_OFFS`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_main_send_offs(&self, track: MediaTrack) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::MainSendOffs, null_mut());
+        let offset = deref_as::<i8>(ptr).expect("C_MAINSEND_OFFS pointer is null");
+        offset.max(0) as u32
+    }
+
+    /// Convenience function which sets the channel offset of the track's send to its parent
+    /// (`C_MAINSEND_OFFS`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset is greater than 127.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_set_main_send_offs(&self, track: MediaTrack, offset: u32)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let value = i8::try_from(offset).expect("main send offset too high");
+        self.get_set_media_track_info(
+            track,
+            TrackAttributeKey::MainSendOffs,
+            &value as *const _ as _,
+        );
+    }
+
+    /// Convenience function which returns the given track's pan mode (I_PANMODE).
+    ///
+    /// Returns `None` if the track uses the project default.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_pan_mode(&self, track: MediaTrack) -> Option<PanMode>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::PanMode, null_mut());
+        let ipanmode = deref_as::<i32>(ptr).expect("I_PANMODE pointer is null");
+        if ipanmode == -1 {
+            return None;
+        }
+        Some(PanMode::from_raw(ipanmode))
+    }
+
+    /// Convenience function which returns the given track's pan (D_PAN).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_pan(&self, track: MediaTrack) -> ReaperPanValue
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Pan, null_mut());
+        let pan = deref_as::<f64>(ptr).expect("I_PAN pointer is null");
+        ReaperPanValue::new_panic(pan)
+    }
+
+    /// Convenience function which returns the given track's dual-pan position 1 (D_DUALPANL).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_dual_pan_l(
+        &self,
+        track: MediaTrack,
+    ) -> ReaperPanValue
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanL, null_mut());
+        let pan = deref_as::<f64>(ptr).expect("D_DUALPANL pointer is null");
+        ReaperPanValue::new_panic(pan)
+    }
+
+    /// Convenience function which returns the given track's dual-pan position 2 (D_DUALPANR).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_dual_pan_r(
+        &self,
+        track: MediaTrack,
+    ) -> ReaperPanValue
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanR, null_mut());
+        let pan = deref_as::<f64>(ptr).expect("D_DUALPANR pointer is null");
+        ReaperPanValue::new_panic(pan)
     }
 
     /// Convenience function which returns the given track's width (D_WIDTH).
@@ -1917,6 +2363,114 @@ where
         deref_as::<GUID>(ptr).expect("GUID pointer is null")
     }
 
+    /// Convenience function which returns the current vertical position of the given track's
+    /// control panel in pixels, relative to the top of the arrange view (`I_TCPY`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_tcp_y(&self, track: MediaTrack) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_set_media_track_info_get_i32(track, TrackAttributeKey::TcpY)
+    }
+
+    /// Convenience function which returns the current height of the given track's control panel
+    /// in pixels, excluding envelopes (`I_TCPH`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_tcp_h(&self, track: MediaTrack) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_set_media_track_info_get_i32(track, TrackAttributeKey::TcpH)
+            .max(0) as u32
+    }
+
+    /// Convenience function which returns the current height of the given track in the arrange
+    /// view in pixels, including envelopes (`I_WNDH`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_wnd_h(&self, track: MediaTrack) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_set_media_track_info_get_i32(track, TrackAttributeKey::WndH)
+            .max(0) as u32
+    }
+
+    /// Convenience function which returns the current horizontal position of the given track's
+    /// mixer control panel in pixels, relative to the mixer container (`I_MCPX`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_mcp_x(&self, track: MediaTrack) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_set_media_track_info_get_i32(track, TrackAttributeKey::McpX)
+    }
+
+    /// Convenience function which returns the current vertical position of the given track's
+    /// mixer control panel in pixels, relative to the mixer container (`I_MCPY`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_mcp_y(&self, track: MediaTrack) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_set_media_track_info_get_i32(track, TrackAttributeKey::McpY)
+    }
+
+    /// Convenience function which returns the current width of the given track's mixer control
+    /// panel in pixels (`I_MCPW`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_mcp_w(&self, track: MediaTrack) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_set_media_track_info_get_i32(track, TrackAttributeKey::McpW)
+            .max(0) as u32
+    }
+
+    /// Convenience function which returns the current height of the given track's mixer control
+    /// panel in pixels (`I_MCPH`).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_mcp_h(&self, track: MediaTrack) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_set_media_track_info_get_i32(track, TrackAttributeKey::McpH)
+            .max(0) as u32
+    }
+
+    unsafe fn get_set_media_track_info_get_i32(
+        &self,
+        track: MediaTrack,
+        attribute_key: TrackAttributeKey,
+    ) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, attribute_key, null_mut());
+        deref_as::<i32>(ptr).expect("attribute pointer is null")
+    }
+
     /// Returns whether we are in the real-time audio thread.
     ///
     /// *Real-time* means somewhere between [`OnAudioBuffer`] calls, not in some worker or
@@ -1938,6 +2492,80 @@ where
         self.low.Audio_IsRunning() != 0
     }
 
+    /// Returns the user-configured preferred disk read mode.
+    ///
+    /// The result contains the parameters that should be passed to `WDL_FileRead`.
+    pub fn get_preferred_disk_read_mode(&self) -> DiskReadMode
+    where
+        UsageScope: AnyThread,
+    {
+        let mut mode = MaybeUninit::zeroed();
+        let mut buffer_count = MaybeUninit::zeroed();
+        let mut buffer_size = MaybeUninit::zeroed();
+        unsafe {
+            self.low.GetPreferredDiskReadMode(
+                mode.as_mut_ptr(),
+                buffer_count.as_mut_ptr(),
+                buffer_size.as_mut_ptr(),
+            );
+            DiskReadMode {
+                mode: mode.assume_init(),
+                buffer_count: buffer_count.assume_init(),
+                buffer_size: buffer_size.assume_init(),
+            }
+        }
+    }
+
+    /// Returns the user-configured preferred disk read mode for use when building peaks.
+    ///
+    /// The result contains the parameters that should be passed to `WDL_FileRead`.
+    pub fn get_preferred_disk_read_mode_peak(&self) -> DiskReadMode
+    where
+        UsageScope: AnyThread,
+    {
+        let mut mode = MaybeUninit::zeroed();
+        let mut buffer_count = MaybeUninit::zeroed();
+        let mut buffer_size = MaybeUninit::zeroed();
+        unsafe {
+            self.low.GetPreferredDiskReadModePeak(
+                mode.as_mut_ptr(),
+                buffer_count.as_mut_ptr(),
+                buffer_size.as_mut_ptr(),
+            );
+            DiskReadMode {
+                mode: mode.assume_init(),
+                buffer_count: buffer_count.assume_init(),
+                buffer_size: buffer_size.assume_init(),
+            }
+        }
+    }
+
+    /// Returns the user-configured preferred disk write mode.
+    ///
+    /// The result contains the parameters that should be passed to `WDL_FileWrite`.
+    pub fn get_preferred_disk_write_mode(&self) -> DiskWriteMode
+    where
+        UsageScope: AnyThread,
+    {
+        let mut mode = MaybeUninit::zeroed();
+        // REAPER writes two values: The initial and the maximum buffer count.
+        let mut buffer_counts = [0i32; 2];
+        let mut buffer_size = MaybeUninit::zeroed();
+        unsafe {
+            self.low.GetPreferredDiskWriteMode(
+                mode.as_mut_ptr(),
+                buffer_counts.as_mut_ptr(),
+                buffer_size.as_mut_ptr(),
+            );
+            DiskWriteMode {
+                mode: mode.assume_init(),
+                initial_buffer_count: buffer_counts[0],
+                max_buffer_count: buffer_counts[1],
+                buffer_size: buffer_size.assume_init(),
+            }
+        }
+    }
+
     /// Starts playing.
     pub fn csurf_on_play(&self)
     where
@@ -2341,7 +2969,9 @@ where
                 .PCM_Source_CreateFromType(source_type.into().as_ptr())
         };
         NonNull::new(ptr)
-            .ok_or_else(|| ReaperFunctionError::new("couldn't create PCM source from type"))
+            .ok_or_else(|| {
+                ReaperFunctionError::invalid_argument("couldn't create PCM source from type")
+            })
             .map(OwnedPcmSource)
     }
 
@@ -2459,10 +3089,10 @@ where
             full_beats: PositionInBeats::new_panic(full_beats.assume_init()),
             measure_index: measures.assume_init(),
             beats_since_measure: PositionInBeats::new_panic(beats_within_measure),
-            time_signature: TimeSignature {
-                numerator: NonZeroU32::new(measure_length.assume_init() as _).unwrap(),
-                denominator: NonZeroU32::new(common_denom.assume_init() as _).unwrap(),
-            },
+            time_signature: TimeSignature::new_panic(
+                measure_length.assume_init() as _,
+                common_denom.assume_init() as _,
+            ),
         }
     }
 
@@ -2516,10 +3146,10 @@ where
             start_time: PositionInSeconds::new_panic(start_time),
             start_qn: PositionInQuarterNotes::new_panic(start_qn.assume_init()),
             end_qn: PositionInQuarterNotes::new_panic(end_qn.assume_init()),
-            time_signature: TimeSignature {
-                numerator: NonZeroU32::new(num.assume_init() as _).unwrap(),
-                denominator: NonZeroU32::new(denom.assume_init() as _).unwrap(),
-            },
+            time_signature: TimeSignature::new_panic(
+                num.assume_init() as _,
+                denom.assume_init() as _,
+            ),
             tempo: Bpm::new_panic(bpm.assume_init()),
         }
     }
@@ -3101,7 +3731,7 @@ where
             required_view_mode == RequiredViewMode::ListView,
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "no MIDI editor with this view mode open",
             ));
         }
@@ -3127,7 +3757,7 @@ where
                 .MIDIEditor_OnCommand(midi_editor.as_ptr(), command_id.to_raw())
         };
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "given window is not an open MIDI editor",
             ));
         }
@@ -3361,6 +3991,68 @@ where
         Some(CommandId(raw_id))
     }
 
+    /// Registers the given ReaScript file (Lua, EEL or Python) as action in the given section.
+    ///
+    /// If `commit` is `true`, the action list is saved to `reaper-kb.ini`. When registering many
+    /// scripts at once, pass `true` for the last one only.
+    ///
+    /// Returns the command ID of the new action. If the script is already registered, its existing
+    /// command ID is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the script couldn't be registered.
+    pub fn add_reascript(
+        &self,
+        section_id: SectionId,
+        file: &Utf8Path,
+        commit: bool,
+    ) -> ReaperFunctionResult<CommandId>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let file = convert_path_to_reaper_string(file);
+        let raw_id = unsafe {
+            self.low
+                .AddRemoveReaScript(true, section_id.to_raw(), file.as_ptr(), commit)
+        };
+        if raw_id <= 0 {
+            return Err(ReaperFunctionError::new("couldn't register ReaScript"));
+        }
+        Ok(CommandId(raw_id as u32))
+    }
+
+    /// Unregisters the given ReaScript file from the given section.
+    ///
+    /// See [`add_reascript()`] for the meaning of `commit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the script wasn't registered.
+    ///
+    /// [`add_reascript()`]: #method.add_reascript
+    pub fn remove_reascript(
+        &self,
+        section_id: SectionId,
+        file: &Utf8Path,
+        commit: bool,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let file = convert_path_to_reaper_string(file);
+        let result = unsafe {
+            self.low
+                .AddRemoveReaScript(false, section_id.to_raw(), file.as_ptr(), commit)
+        };
+        if result <= 0 {
+            return Err(ReaperFunctionError::new("couldn't unregister ReaScript"));
+        }
+        Ok(())
+    }
+
     /// Returns a project configuration variable descriptor to be used with
     /// [`project_config_var_addr`]
     ///
@@ -3423,19 +4115,136 @@ where
         NonNull::new(ptr)
     }
 
-    /// Opens a file picker.
+    /// Returns the value of the project configuration variable with the given name as integer.
     ///
-    /// Returns `None` if the user canceled the dialog.
-    pub fn get_user_file_name_for_read<'a>(
+    /// # Errors
+    ///
+    /// Returns an error if the variable doesn't exist or is not an integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_project_config_var_int<'a>(
         &self,
-        path: &Utf8Path,
-        title: impl Into<ReaperStringArg<'a>>,
-        defext: impl Into<ReaperStringArg<'a>>,
-    ) -> Option<Utf8PathBuf>
+        project: ProjectContext,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<i32>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
+        let ptr = self.get_typed_project_config_var(project, name, ConfigVarType::Int)?;
+        Ok(unsafe { *ptr.cast::<i32>().as_ptr() })
+    }
+
+    /// Returns the value of the project configuration variable with the given name as floating
+    /// point number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable doesn't exist or is not a floating point number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_project_config_var_double<'a>(
+        &self,
+        project: ProjectContext,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<f64>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let ptr = self.get_typed_project_config_var(project, name, ConfigVarType::Double)?;
+        Ok(unsafe { *ptr.cast::<f64>().as_ptr() })
+    }
+
+    /// Sets the integer project configuration variable with the given name to the given value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable doesn't exist or is not an integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn set_project_config_var_int<'a>(
+        &self,
+        project: ProjectContext,
+        name: impl Into<ReaperStringArg<'a>>,
+        value: i32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let ptr = self.get_typed_project_config_var(project, name, ConfigVarType::Int)?;
+        unsafe {
+            *ptr.cast::<i32>().as_ptr() = value;
+        }
+        Ok(())
+    }
+
+    /// Sets the floating point project configuration variable with the given name to the given
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable doesn't exist or is not a floating point number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn set_project_config_var_double<'a>(
+        &self,
+        project: ProjectContext,
+        name: impl Into<ReaperStringArg<'a>>,
+        value: f64,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let ptr = self.get_typed_project_config_var(project, name, ConfigVarType::Double)?;
+        unsafe {
+            *ptr.cast::<f64>().as_ptr() = value;
+        }
+        Ok(())
+    }
+
+    fn get_typed_project_config_var<'a>(
+        &self,
+        project: ProjectContext,
+        name: impl Into<ReaperStringArg<'a>>,
+        expected_type: ConfigVarType,
+    ) -> ReaperFunctionResult<NonNull<c_void>>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let name = name.into();
+        check_known_config_var_type(name.as_reaper_str(), expected_type)?;
+        let result = self
+            .project_config_var_get_offs(name)
+            .ok_or_else(|| ReaperFunctionError::not_found("config variable doesn't exist"))?;
+        if result.size != expected_type.size() {
+            return Err(ReaperFunctionError::invalid_argument(
+                "config variable has unexpected size",
+            ));
+        }
+        self.project_config_var_addr(project, result.offset)
+            .ok_or_else(|| ReaperFunctionError::not_found("config variable doesn't exist"))
+    }
+
+    /// Opens a file picker.
+    ///
+    /// Returns `None` if the user canceled the dialog.
+    pub fn get_user_file_name_for_read<'a>(
+        &self,
+        path: &Utf8Path,
+        title: impl Into<ReaperStringArg<'a>>,
+        defext: impl Into<ReaperStringArg<'a>>,
+    ) -> Option<Utf8PathBuf>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
         let (file, successful) =
             with_string_buffer_prefilled(path.to_string(), 4096, |buffer, _| unsafe {
                 self.low.GetUserFileNameForRead(
@@ -3485,6 +4294,127 @@ where
         Some(res)
     }
 
+    /// Returns the value of the REAPER preference with the given name as integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preference doesn't exist or is not an integer.
+    pub fn get_config_var_int<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<i32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let ptr = self.get_typed_config_var(name, ConfigVarType::Int)?;
+        Ok(unsafe { *ptr.cast::<i32>().as_ptr() })
+    }
+
+    /// Returns the value of the REAPER preference with the given name as floating point number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preference doesn't exist or is not a floating point number.
+    pub fn get_config_var_double<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<f64>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let ptr = self.get_typed_config_var(name, ConfigVarType::Double)?;
+        Ok(unsafe { *ptr.cast::<f64>().as_ptr() })
+    }
+
+    /// Returns the value of the REAPER preference with the given name formatted as string.
+    ///
+    /// Works for string preferences but also for numeric ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preference doesn't exist.
+    pub fn get_config_var_string<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let name = name.into();
+        let (value, successful) = with_auto_sized_string_buffer(|buffer, max_size| unsafe {
+            self.low
+                .get_config_var_string(name.as_ptr(), buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "config variable doesn't exist",
+            ));
+        }
+        Ok(value)
+    }
+
+    /// Sets the integer REAPER preference with the given name to the given value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preference doesn't exist or is not an integer.
+    pub fn set_config_var_int<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+        value: i32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let ptr = self.get_typed_config_var(name, ConfigVarType::Int)?;
+        unsafe {
+            *ptr.cast::<i32>().as_ptr() = value;
+        }
+        Ok(())
+    }
+
+    /// Sets the floating point REAPER preference with the given name to the given value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preference doesn't exist or is not a floating point number.
+    pub fn set_config_var_double<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+        value: f64,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let ptr = self.get_typed_config_var(name, ConfigVarType::Double)?;
+        unsafe {
+            *ptr.cast::<f64>().as_ptr() = value;
+        }
+        Ok(())
+    }
+
+    fn get_typed_config_var<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+        expected_type: ConfigVarType,
+    ) -> ReaperFunctionResult<NonNull<c_void>>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let name = name.into();
+        check_known_config_var_type(name.as_reaper_str(), expected_type)?;
+        let result = self
+            .get_config_var(name)
+            .ok_or_else(|| ReaperFunctionError::not_found("config variable doesn't exist"))?;
+        if result.size != expected_type.size() {
+            return Err(ReaperFunctionError::invalid_argument(
+                "config variable has unexpected size",
+            ));
+        }
+        Ok(result.value)
+    }
+
     /// Clears the ReaScript console.
     pub fn clear_console(&self)
     where
@@ -3846,7 +4776,7 @@ where
             .low
             .ReorderSelectedTracks(index as i32, behavior.to_raw());
         if !successful {
-            return Err(ReaperFunctionError::new("no track selected"));
+            return Err(ReaperFunctionError::not_found("no track selected"));
         }
         Ok(())
     }
@@ -3915,6 +4845,24 @@ where
         }
     }
 
+    /// Like [`get_midi_input_name()`] but determines the necessary buffer size automatically.
+    ///
+    /// [`get_midi_input_name()`]: #method.get_midi_input_name
+    pub fn get_midi_input_name_auto(&self, device_id: MidiInputDeviceId) -> GetMidiDevNameResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (name, is_present) = with_auto_sized_string_buffer_cstring(|buffer, max_size| unsafe {
+            self.low
+                .GetMIDIInputName(device_id.to_raw(), buffer, max_size)
+        });
+        GetMidiDevNameResult {
+            is_present,
+            name: if name.is_empty() { None } else { Some(name) },
+        }
+    }
+
     /// Returns information about the given MIDI output device.
     ///
     /// With `buffer_size` you can tell REAPER how many bytes of the device name you want.
@@ -3956,6 +4904,24 @@ where
         }
     }
 
+    /// Like [`get_midi_output_name()`] but determines the necessary buffer size automatically.
+    ///
+    /// [`get_midi_output_name()`]: #method.get_midi_output_name
+    pub fn get_midi_output_name_auto(&self, device_id: MidiOutputDeviceId) -> GetMidiDevNameResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (name, is_present) = with_auto_sized_string_buffer_cstring(|buffer, max_size| unsafe {
+            self.low
+                .GetMIDIOutputName(device_id.to_raw(), buffer, max_size)
+        });
+        GetMidiDevNameResult {
+            is_present,
+            name: if name.is_empty() { None } else { Some(name) },
+        }
+    }
+
     /// Returns a new pitch shift API instance.
     ///
     /// Version must be [raw::REAPER_PITCHSHIFT_API_VER].
@@ -4048,115 +5014,347 @@ where
         Some(name)
     }
 
-    // Return type Option or Result can't be easily chosen here because if instantiate is 0, it
-    // should be Option, if it's -1 or > 0, it should be Result. So we just keep the i32. That's
-    // also one reason why we just publish the convenience functions.
-    unsafe fn track_fx_add_by_name<'a>(
+    /// Creates a new LICE bitmap with the given size.
+    ///
+    /// The bitmap is not destroyed automatically. Use [`lice_destroy_bitmap()`] when you don't
+    /// need it anymore.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bitmap couldn't be created.
+    ///
+    /// [`lice_destroy_bitmap()`]: #method.lice_destroy_bitmap
+    pub fn lice_create_bitmap(
         &self,
-        track: MediaTrack,
-        fx_name: impl Into<ReaperStringArg<'a>>,
-        fx_chain_type: TrackFxChainType,
-        behavior: FxAddByNameBehavior,
-    ) -> i32
+        kind: LiceBitmapKind,
+        width: u32,
+        height: u32,
+    ) -> ReaperFunctionResult<LiceBitmap>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.TrackFX_AddByName(
-            track.as_ptr(),
-            fx_name.into().as_ptr(),
-            fx_chain_type == TrackFxChainType::InputFxChain,
-            behavior.to_raw(),
-        )
+        let raw = self
+            .low
+            .LICE_CreateBitmap(kind.to_raw(), width as i32, height as i32);
+        NonNull::new(raw).ok_or_else(|| ReaperFunctionError::new("couldn't create LICE bitmap"))
     }
 
-    /// Returns the index of the first FX instance in a track or monitoring FX chain.
-    ///
-    /// The FX name can have a prefix to further specify its type: `VST3:` | `VST2:` | `VST:` |
-    /// `AU:` | `JS:` | `DX:`
+    /// Destroys the given LICE bitmap.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_add_by_name_query<'a>(
-        &self,
-        track: MediaTrack,
-        fx_name: impl Into<ReaperStringArg<'a>>,
-        fx_chain_type: TrackFxChainType,
-    ) -> Option<u32>
+    /// REAPER can crash if you pass an invalid bitmap or use the bitmap after destroying it.
+    pub unsafe fn lice_destroy_bitmap(&self, bitmap: LiceBitmap)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, FxAddByNameBehavior::Query) {
-            -1 => None,
-            idx if idx >= 0 => Some(idx as u32),
-            _ => unreachable!(),
-        }
+        self.low.LICE__Destroy(bitmap.as_ptr());
     }
 
-    /// Returns the parameter index corresponding to the given identifier.
+    /// Returns the width of the given LICE bitmap.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_param_from_ident(
-        &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        ident: ParamId,
-    ) -> Option<u32>
+    /// REAPER can crash if you pass an invalid bitmap.
+    pub unsafe fn lice_get_width(&self, bitmap: LiceBitmap) -> u32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        match self.low.TrackFX_GetParamFromIdent(
-            track.as_ptr(),
-            fx_location.to_raw(),
-            ident.into_raw().as_ptr(),
-        ) {
-            -1 => None,
-            idx if idx >= 0 => Some(idx as u32),
-            _ => unreachable!(),
-        }
+        self.low.LICE__GetWidth(bitmap.as_ptr()) as u32
     }
 
-    /// Adds an instance of an FX to a track or monitoring FX chain.
-    ///
-    /// See [`track_fx_add_by_name_query()`] for possible FX name prefixes.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the FX couldn't be added (e.g. if no such FX is installed).
+    /// Returns the height of the given LICE bitmap.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    ///
-    /// [`track_fx_add_by_name_query()`]: #method.track_fx_add_by_name_query
-    pub unsafe fn track_fx_add_by_name_add<'a>(
-        &self,
-        track: MediaTrack,
-        fx_name: impl Into<ReaperStringArg<'a>>,
-        fx_chain_type: TrackFxChainType,
-        behavior: AddFxBehavior,
-    ) -> ReaperFunctionResult<u32>
+    /// REAPER can crash if you pass an invalid bitmap.
+    pub unsafe fn lice_get_height(&self, bitmap: LiceBitmap) -> u32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, behavior.into()) {
-            -1 => Err(ReaperFunctionError::new("FX couldn't be added")),
-            idx if idx >= 0 => Ok(idx as u32),
-            _ => unreachable!(),
-        }
+        self.low.LICE__GetHeight(bitmap.as_ptr()) as u32
     }
 
-    /// Returns whether the given track FX is enabled.
+    /// Resizes the given LICE bitmap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bitmap couldn't be resized.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
+    /// REAPER can crash if you pass an invalid bitmap.
+    pub unsafe fn lice_resize(
+        &self,
+        bitmap: LiceBitmap,
+        width: u32,
+        height: u32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self
+            .low
+            .LICE__resize(bitmap.as_ptr(), width as i32, height as i32);
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't resize LICE bitmap"));
+        }
+        Ok(())
+    }
+
+    /// Fills the complete LICE bitmap with the given color.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid bitmap.
+    pub unsafe fn lice_clear(&self, bitmap: LiceBitmap, color: LicePixel)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.LICE_Clear(bitmap.as_ptr(), color.to_raw());
+    }
+
+    /// Copies the given area of the source bitmap to the given position of the destination bitmap.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid bitmap.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn lice_blit(
+        &self,
+        dest: LiceBitmap,
+        src: LiceBitmap,
+        dest_x: i32,
+        dest_y: i32,
+        src_rect: LiceRect,
+        alpha: f32,
+        mode: LiceBlendMode,
+        flags: BitFlags<LiceBlitFlag>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.LICE_Blit(
+            dest.as_ptr(),
+            src.as_ptr(),
+            dest_x,
+            dest_y,
+            src_rect.x,
+            src_rect.y,
+            src_rect.width as i32,
+            src_rect.height as i32,
+            alpha,
+            lice_mode_to_raw(mode, flags),
+        );
+    }
+
+    /// Draws a line from `(x1, y1)` to `(x2, y2)`.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid bitmap.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn lice_line(
+        &self,
+        bitmap: LiceBitmap,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        color: LicePixel,
+        alpha: f32,
+        mode: LiceBlendMode,
+        anti_aliased: bool,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.LICE_Line(
+            bitmap.as_ptr(),
+            x1,
+            y1,
+            x2,
+            y2,
+            color.to_raw(),
+            alpha,
+            mode.to_raw(),
+            anti_aliased,
+        );
+    }
+
+    /// Fills the given rectangle with the given color.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid bitmap.
+    pub unsafe fn lice_fill_rect(
+        &self,
+        bitmap: LiceBitmap,
+        rect: LiceRect,
+        color: LicePixel,
+        alpha: f32,
+        mode: LiceBlendMode,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.LICE_FillRect(
+            bitmap.as_ptr(),
+            rect.x,
+            rect.y,
+            rect.width as i32,
+            rect.height as i32,
+            color.to_raw(),
+            alpha,
+            mode.to_raw(),
+        );
+    }
+
+    /// Draws the given text at the given position using the built-in LICE font.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid bitmap.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn lice_draw_text<'a>(
+        &self,
+        bitmap: LiceBitmap,
+        x: i32,
+        y: i32,
+        text: impl Into<ReaperStringArg<'a>>,
+        color: LicePixel,
+        alpha: f32,
+        mode: LiceBlendMode,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.LICE_DrawText(
+            bitmap.as_ptr(),
+            x,
+            y,
+            text.into().as_ptr(),
+            color.to_raw(),
+            alpha,
+            mode.to_raw(),
+        );
+    }
+
+    // Return type Option or Result can't be easily chosen here because if instantiate is 0, it
+    // should be Option, if it's -1 or > 0, it should be Result. So we just keep the i32. That's
+    // also one reason why we just publish the convenience functions.
+    unsafe fn track_fx_add_by_name<'a>(
+        &self,
+        track: MediaTrack,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        fx_chain_type: TrackFxChainType,
+        behavior: FxAddByNameBehavior,
+    ) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TrackFX_AddByName(
+            track.as_ptr(),
+            fx_name.into().as_ptr(),
+            fx_chain_type == TrackFxChainType::InputFxChain,
+            behavior.to_raw(),
+        )
+    }
+
+    /// Returns the index of the first FX instance in a track or monitoring FX chain.
+    ///
+    /// The FX name can have a prefix to further specify its type: `VST3:` | `VST2:` | `VST:` |
+    /// `AU:` | `JS:` | `DX:`
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_add_by_name_query<'a>(
+        &self,
+        track: MediaTrack,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        fx_chain_type: TrackFxChainType,
+    ) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, FxAddByNameBehavior::Query) {
+            -1 => None,
+            idx if idx >= 0 => Some(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the parameter index corresponding to the given identifier.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_param_from_ident(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        ident: ParamId,
+    ) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.low.TrackFX_GetParamFromIdent(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            ident.into_raw().as_ptr(),
+        ) {
+            -1 => None,
+            idx if idx >= 0 => Some(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Adds an instance of an FX to a track or monitoring FX chain.
+    ///
+    /// See [`track_fx_add_by_name_query()`] for possible FX name prefixes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX couldn't be added (e.g. if no such FX is installed).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    ///
+    /// [`track_fx_add_by_name_query()`]: #method.track_fx_add_by_name_query
+    pub unsafe fn track_fx_add_by_name_add<'a>(
+        &self,
+        track: MediaTrack,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        fx_chain_type: TrackFxChainType,
+        behavior: AddFxBehavior,
+    ) -> ReaperFunctionResult<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, behavior.into()) {
+            -1 => Err(ReaperFunctionError::not_found("FX couldn't be added")),
+            idx if idx >= 0 => Ok(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns whether the given track FX is enabled.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
     pub unsafe fn track_fx_get_enabled(
         &self,
         track: MediaTrack,
@@ -4219,7 +5417,39 @@ where
                 .TrackFX_GetFXName(track.as_ptr(), fx_location.to_raw(), buffer, max_size)
         });
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
+                "couldn't get FX name (probably FX doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Like [`track_fx_get_fx_name()`] but determines the necessary buffer size automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    ///
+    /// [`track_fx_get_fx_name()`]: #method.track_fx_get_fx_name
+    pub unsafe fn track_fx_get_fx_name_auto(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (name, successful) = with_auto_sized_string_buffer(|buffer, max_size| {
+            self.low
+                .TrackFX_GetFXName(track.as_ptr(), fx_location.to_raw(), buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get FX name (probably FX doesn't exist)",
             ));
         }
@@ -4260,7 +5490,39 @@ where
                 .GetTrackSendName(track.as_ptr(), send_index as i32, buffer, max_size)
         });
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
+                "couldn't get send name (probably send doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Like [`get_track_send_name()`] but determines the necessary buffer size automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the track send doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    ///
+    /// [`get_track_send_name()`]: #method.get_track_send_name
+    pub unsafe fn get_track_send_name_auto(
+        &self,
+        track: MediaTrack,
+        send_index: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (name, successful) = with_auto_sized_string_buffer(|buffer, max_size| {
+            self.low
+                .GetTrackSendName(track.as_ptr(), send_index as i32, buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get send name (probably send doesn't exist)",
             ));
         }
@@ -4298,33 +5560,191 @@ where
                 .GetTrackReceiveName(track.as_ptr(), receive_index as i32, buffer, max_size)
         });
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get receive name (probably receive doesn't exist)",
             ));
         }
         Ok(name)
     }
 
-    /// Returns the index of the first track FX that is a virtual instrument.
+    /// Like [`get_track_receive_name()`] but determines the necessary buffer size automatically.
     ///
-    /// Doesn't look in the input FX chain.
+    /// # Errors
+    ///
+    /// Returns an error if the track receive doesn't exist.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_instrument(&self, track: MediaTrack) -> Option<u32>
+    ///
+    /// [`get_track_receive_name()`]: #method.get_track_receive_name
+    pub unsafe fn get_track_receive_name_auto(
+        &self,
+        track: MediaTrack,
+        receive_index: u32,
+    ) -> ReaperFunctionResult<ReaperString>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let index = self.low.TrackFX_GetInstrument(track.as_ptr());
-        if index == -1 {
-            return None;
+        let (name, successful) = with_auto_sized_string_buffer(|buffer, max_size| {
+            self.low
+                .GetTrackReceiveName(track.as_ptr(), receive_index as i32, buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't get receive name (probably receive doesn't exist)",
+            ));
         }
-        Some(index as u32)
+        Ok(name)
     }
 
-    /// Enables or disables a track FX.
+    /// Grants temporary access to the custom name of the given note or CC on the given track.
+    ///
+    /// The name is passed as `None` if no custom name has been assigned.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project or track.
+    pub unsafe fn get_track_midi_note_name_ex<R>(
+        &self,
+        project: ProjectContext,
+        track: MediaTrack,
+        target: MidiNoteNameTarget,
+        channel: Channel,
+        use_name: impl FnOnce(Option<&ReaperStr>) -> R,
+    ) -> R
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.GetTrackMIDINoteNameEx(
+            project.to_raw(),
+            track.as_ptr(),
+            target.to_raw(),
+            channel.get() as _,
+        );
+        let passing_c_str =
+            create_passing_c_str(ptr).filter(|s| !s.as_c_str().to_bytes().is_empty());
+        use_name(passing_c_str)
+    }
+
+    /// Assigns a custom name to the given note or CC on the given track.
+    ///
+    /// If you pass `None` as channel, the name is assigned to all channels. Pass an empty name in
+    /// order to remove the custom name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project or track.
+    pub unsafe fn set_track_midi_note_name_ex<'a>(
+        &self,
+        project: ProjectContext,
+        track: MediaTrack,
+        target: MidiNoteNameTarget,
+        channel: Option<Channel>,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self.low.SetTrackMIDINoteNameEx(
+            project.to_raw(),
+            track.as_ptr(),
+            target.to_raw(),
+            channel.map(|ch| ch.get() as i32).unwrap_or(-1),
+            name.into().as_ptr(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set MIDI note name"));
+        }
+        Ok(())
+    }
+
+    /// Returns all MIDI lyrics on the given track as one string with tabs between each word.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the track doesn't contain any lyrics).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_track_midi_lyrics(
+        &self,
+        track: MediaTrack,
+        flags: BitFlags<TrackMidiLyricsFlag>,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (lyrics, successful) = with_auto_sized_string_buffer(|buffer, max_size| {
+            let mut size = max_size;
+            self.low
+                .GetTrackMIDILyrics(track.as_ptr(), flags.bits() as i32, buffer, &mut size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't get MIDI lyrics"));
+        }
+        Ok(lyrics)
+    }
+
+    /// Sets all MIDI lyrics on the given track.
+    ///
+    /// The lyrics are stuffed into any MIDI items found in range. They are passed in as beat
+    /// position, tab, text, tab (e.g. `"1.1.2\tLyric for measure 1 beat 2\t"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn set_track_midi_lyrics<'a>(
+        &self,
+        track: MediaTrack,
+        lyrics: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self
+            .low
+            .SetTrackMIDILyrics(track.as_ptr(), 0, lyrics.into().as_ptr());
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set MIDI lyrics"));
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the first track FX that is a virtual instrument.
+    ///
+    /// Doesn't look in the input FX chain.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_instrument(&self, track: MediaTrack) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let index = self.low.TrackFX_GetInstrument(track.as_ptr());
+        if index == -1 {
+            return None;
+        }
+        Some(index as u32)
+    }
+
+    /// Enables or disables a track FX.
     ///
     /// # Safety
     ///
@@ -4397,8 +5817,9 @@ where
 
     /// Returns the current project if it's just being loaded or saved.
     ///
-    /// This is usually only used from `project_config_extension_t`.
-    // TODO-low `project_config_extension_t` is not yet ported
+    /// This is usually only used from a [`ProjectConfigExtension`].
+    ///
+    /// [`ProjectConfigExtension`]: trait.ProjectConfigExtension.html
     pub fn get_current_project_in_load_save(&self) -> Option<ReaProject>
     where
         UsageScope: MainThreadOnly,
@@ -4445,7 +5866,7 @@ where
             )
         });
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get FX parameter name (probably FX or parameter doesn't exist)",
             ));
         }
@@ -4490,7 +5911,7 @@ where
             )
         });
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't format current FX parameter value (probably FX or parameter doesn't exist)",
             ));
         }
@@ -4582,7 +6003,7 @@ where
             param_value.get(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't set FX parameter value (probably FX or parameter doesn't exist)",
             ));
         }
@@ -4618,7 +6039,7 @@ where
             self.low
                 .TrackFX_EndParamEdit(track.as_ptr(), fx_location.to_raw(), param_index as i32);
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't end FX parameter edit (probably FX or parameter doesn't exist)",
             ));
         }
@@ -4881,7 +6302,7 @@ where
             .low
             .TrackFX_Delete(track.as_ptr(), fx_location.to_raw());
         if !succesful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't delete FX (probably FX doesn't exist)",
             ));
         }
@@ -5054,6 +6475,114 @@ where
         }
     }
 
+    /// Like [`track_fx_get_named_config_parm`](Self::track_fx_get_named_config_parm)
+    /// but taking a known key and parsing the result according to its format.
+    ///
+    /// The buffer size is determined automatically, so this also works for big values such as
+    /// [`NamedConfigParmKey::VstChunk`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given FX doesn't have this named parameter, doesn't support named
+    /// parameters, if the returned value doesn't have the expected format or if it's bigger than
+    /// 256 MB.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_named_config_parm_typed(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        key: NamedConfigParmKey,
+    ) -> ReaperFunctionResult<NamedConfigParmValue>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let kind = key.value_kind();
+        let raw_key = key.into_raw();
+        let (value, successful) = with_checked_auto_sized_string_buffer(
+            NAMED_CONFIG_PARM_MAX_SIZE,
+            |buffer, max_size| {
+                self.low.TrackFX_GetNamedConfigParm(
+                    track.as_ptr(),
+                    fx_location.to_raw(),
+                    raw_key.as_ptr(),
+                    buffer,
+                    max_size,
+                )
+            },
+        )
+        .ok_or_else(|| ReaperFunctionError::new("named parameter value is too large"))?;
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get named parameter value",
+            ));
+        }
+        NamedConfigParmValue::parse(kind, value)
+            .ok_or_else(|| ReaperFunctionError::new("named parameter value has unexpected format"))
+    }
+
+    /// Returns the number of FX within the given container (REAPER 7+).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given FX is not a container.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_container_count(
+        &self,
+        track: MediaTrack,
+        container_location: TrackFxLocation,
+    ) -> ReaperFunctionResult<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let value = self.track_fx_get_named_config_parm_typed(
+            track,
+            container_location,
+            NamedConfigParmKey::ContainerCount,
+        )?;
+        match value {
+            NamedConfigParmValue::Integer(count) if count >= 0 => Ok(count as u32),
+            _ => Err(ReaperFunctionError::new("couldn't get container count")),
+        }
+    }
+
+    /// Returns the location of the FX at the given index within the given container (REAPER 7+).
+    ///
+    /// The returned location can be passed to all functions taking a [`TrackFxLocation`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given FX is not a container or the index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_container_item(
+        &self,
+        track: MediaTrack,
+        container_location: TrackFxLocation,
+        item_index: u32,
+    ) -> ReaperFunctionResult<TrackFxLocation>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let value = self.track_fx_get_named_config_parm_typed(
+            track,
+            container_location,
+            NamedConfigParmKey::ContainerItem(item_index),
+        )?;
+        match value {
+            NamedConfigParmValue::Integer(raw) if raw >= 0 => Ok(TrackFxLocation::from_raw(raw)),
+            _ => Err(ReaperFunctionError::new("couldn't get container item")),
+        }
+    }
+
     /// Sets a plug-in specific named configuration value.
     ///
     /// Named parameters are a vendor-specific VST extension from Cockos (see
@@ -5382,6 +6911,81 @@ where
         self.low.IsProjectDirty(project.to_raw()) != 0
     }
 
+    /// Saves the given project.
+    ///
+    /// If `force_save_as` is `true` or the project hasn't been saved before, REAPER shows the
+    /// "Save as" dialog.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn main_save_project(&self, project: ProjectContext, force_save_as: bool)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.require_valid_project(project);
+        unsafe {
+            self.main_save_project_unchecked(project, force_save_as);
+        }
+    }
+
+    /// Like [`main_save_project()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`main_save_project()`]: #method.main_save_project
+    pub unsafe fn main_save_project_unchecked(&self, project: ProjectContext, force_save_as: bool)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.Main_SaveProject(project.to_raw(), force_save_as);
+    }
+
+    /// Saves the given project to the given file without showing a dialog.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn main_save_project_ex(
+        &self,
+        project: ProjectContext,
+        file: &Utf8Path,
+        flags: BitFlags<SaveProjectFlag>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.require_valid_project(project);
+        unsafe {
+            self.main_save_project_ex_unchecked(project, file, flags);
+        }
+    }
+
+    /// Like [`main_save_project_ex()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`main_save_project_ex()`]: #method.main_save_project_ex
+    pub unsafe fn main_save_project_ex_unchecked(
+        &self,
+        project: ProjectContext,
+        file: &Utf8Path,
+        flags: BitFlags<SaveProjectFlag>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let file = convert_path_to_reaper_string(file);
+        self.low
+            .Main_SaveProjectEx(project.to_raw(), file.as_ptr(), flags.bits() as i32);
+    }
+
     /// Notifies all control surfaces that something in the track list has changed.
     ///
     /// Behavior not confirmed.
@@ -5544,6 +7148,33 @@ where
         TrackEnvelope::new(ptr)
     }
 
+    /// Returns the number of envelopes of the given track.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn count_track_envelopes(&self, track: MediaTrack) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CountTrackEnvelopes(track.as_ptr()).max(0) as u32
+    }
+
+    /// Returns the envelope of the given track at the given index.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_track_envelope(&self, track: MediaTrack, index: u32) -> Option<TrackEnvelope>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.GetTrackEnvelope(track.as_ptr(), index as i32);
+        TrackEnvelope::new(ptr)
+    }
+
     /// Returns the track envelope for the given track and envelope display name.
     ///
     /// For getting common envelopes (like volume or pan) using
@@ -5569,7 +7200,249 @@ where
         TrackEnvelope::new(ptr)
     }
 
-    /// Returns the current peak volume for the given track channel.
+    /// Returns the envelope for the given FX parameter.
+    ///
+    /// If `create` is `true`, the envelope is created if it doesn't exist yet.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_fx_envelope(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+        create: bool,
+    ) -> Option<TrackEnvelope>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.GetFXEnvelope(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            param_index as i32,
+            create,
+        );
+        TrackEnvelope::new(ptr)
+    }
+
+    /// Returns the name of the given envelope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name couldn't be determined.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn get_envelope_name(
+        &self,
+        envelope: TrackEnvelope,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (name, successful) = with_auto_sized_string_buffer(|buffer, max_size| {
+            self.low
+                .GetEnvelopeName(envelope.as_ptr(), buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't get envelope name"));
+        }
+        Ok(name)
+    }
+
+    /// Convenience function which returns the take of the given envelope (`P_TAKE`).
+    ///
+    /// Returns `None` if the given envelope is not a take envelope.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn get_envelope_info_get_take(
+        &self,
+        envelope: TrackEnvelope,
+    ) -> Option<MediaItemTake>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let value = self
+            .low
+            .GetEnvelopeInfo_Value(envelope.as_ptr(), reaper_str!("P_TAKE").as_ptr());
+        MediaItemTake::new(value as usize as *mut raw::MediaItem_Take)
+    }
+
+    /// Returns the number of points in the given envelope.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn count_envelope_points(&self, envelope: TrackEnvelope) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CountEnvelopePoints(envelope.as_ptr()).max(0) as u32
+    }
+
+    /// Returns the envelope point at the given index.
+    ///
+    /// Values are in the envelope's native scaling (e.g. fader scaling for volume envelopes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn get_envelope_point(
+        &self,
+        envelope: TrackEnvelope,
+        point_index: u32,
+    ) -> ReaperFunctionResult<EnvelopePoint>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut time = MaybeUninit::zeroed();
+        let mut value = MaybeUninit::zeroed();
+        let mut shape = MaybeUninit::zeroed();
+        let mut tension = MaybeUninit::zeroed();
+        let mut selected = MaybeUninit::zeroed();
+        let successful = self.low.GetEnvelopePoint(
+            envelope.as_ptr(),
+            point_index as i32,
+            time.as_mut_ptr(),
+            value.as_mut_ptr(),
+            shape.as_mut_ptr(),
+            tension.as_mut_ptr(),
+            selected.as_mut_ptr(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't get envelope point",
+            ));
+        }
+        let point = EnvelopePoint {
+            time: PositionInSeconds::new_panic(time.assume_init()),
+            value: value.assume_init(),
+            shape: EnvelopePointShape::from_raw(shape.assume_init()),
+            tension: tension.assume_init(),
+            is_selected: selected.assume_init(),
+        };
+        Ok(point)
+    }
+
+    /// Inserts a point into the given envelope and sorts the points afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the point couldn't be inserted.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn insert_envelope_point(
+        &self,
+        envelope: TrackEnvelope,
+        point: EnvelopePoint,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self.low.InsertEnvelopePoint(
+            envelope.as_ptr(),
+            point.time.get(),
+            point.value,
+            point.shape.to_raw(),
+            point.tension,
+            point.is_selected,
+            null_mut(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't insert envelope point"));
+        }
+        Ok(())
+    }
+
+    /// Deletes all envelope points with `start <= time < end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the points couldn't be deleted.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn delete_envelope_point_range(
+        &self,
+        envelope: TrackEnvelope,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful =
+            self.low
+                .DeleteEnvelopePointRange(envelope.as_ptr(), start.get(), end.get());
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't delete envelope point range",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Evaluates the given envelope at the given position.
+    ///
+    /// `samples_requested` is the number of samples for which the caller would like to reuse the
+    /// result. The value is in the envelope's native scaling.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn envelope_evaluate(
+        &self,
+        envelope: TrackEnvelope,
+        position: PositionInSeconds,
+        sample_rate: Hz,
+        samples_requested: u32,
+    ) -> EnvelopeEvaluateResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut value = MaybeUninit::zeroed();
+        let mut first_derivative = MaybeUninit::zeroed();
+        let mut second_derivative = MaybeUninit::zeroed();
+        let mut third_derivative = MaybeUninit::zeroed();
+        let samples_valid = self.low.Envelope_Evaluate(
+            envelope.as_ptr(),
+            position.get(),
+            sample_rate.get(),
+            samples_requested as i32,
+            value.as_mut_ptr(),
+            first_derivative.as_mut_ptr(),
+            second_derivative.as_mut_ptr(),
+            third_derivative.as_mut_ptr(),
+        );
+        EnvelopeEvaluateResult {
+            value: value.assume_init(),
+            first_derivative: first_derivative.assume_init(),
+            second_derivative: second_derivative.assume_init(),
+            third_derivative: third_derivative.assume_init(),
+            samples_valid: samples_valid.max(0) as u32,
+        }
+    }
+
+    /// Returns the current peak volume for the given track channel.
     ///
     /// # Safety
     ///
@@ -5676,7 +7549,9 @@ where
             param_index.as_mut_ptr(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new("couldn't get TCP FX param info"));
+            return Err(ReaperFunctionError::not_found(
+                "couldn't get TCP FX param info",
+            ));
         }
         let fx_index = fx_index.assume_init();
         let result = GetTcpFxParmResult {
@@ -5750,7 +7625,7 @@ where
             .low
             .TrackFX_GetFXGUID(track.as_ptr(), fx_location.to_raw());
         deref(ptr).ok_or_else(|| {
-            ReaperFunctionError::new("couldn't get FX GUID (probably FX doesn't exist)")
+            ReaperFunctionError::not_found("couldn't get FX GUID (probably FX doesn't exist)")
         })
     }
 
@@ -5785,6 +7660,49 @@ where
         ReaperNormalizedFxParamValue::new(raw_value)
     }
 
+    /// Reads the current values of all parameters of the given track FX in REAPER-normalized form.
+    ///
+    /// The given vector is cleared and then filled with one value per parameter, so you can reuse
+    /// it in order to avoid allocations when polling parameter values repeatedly. This is faster
+    /// than calling [`track_fx_get_param_normalized()`] for each parameter.
+    ///
+    /// See [`track_fx_get_param_normalized()`] about the meaning of values lower than zero. If the
+    /// FX doesn't exist, the vector will be empty.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    ///
+    /// [`track_fx_get_param_normalized()`]: #method.track_fx_get_param_normalized
+    pub unsafe fn track_fx_get_all_param_values(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        values: &mut Vec<ReaperNormalizedFxParamValue>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        values.clear();
+        let param_count = self.track_fx_get_num_params(track, fx_location);
+        if param_count == 0 {
+            return;
+        }
+        let get_param_normalized = self
+            .low
+            .pointers()
+            .TrackFX_GetParamNormalized
+            .unwrap_or_else(|| reaper_low::function_not_available("TrackFX_GetParamNormalized"));
+        let raw_fx_location = fx_location.to_raw();
+        values.extend((0..param_count as i32).map(|i| {
+            ReaperNormalizedFxParamValue::new(get_param_normalized(
+                track.as_ptr(),
+                raw_fx_location,
+                i,
+            ))
+        }));
+    }
+
     /// Returns the master track of the given project.
     ///
     /// # Panics
@@ -5853,163 +7771,499 @@ where
         unsafe { self.get_project_path_ex_unchecked(project, buffer_size) }
     }
 
-    /// Like [`get_project_path_ex()`] but doesn't check if project is valid.
+    /// Like [`get_project_path_ex()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_project_path_ex()`]: #method.get_project_path_ex
+    pub unsafe fn get_project_path_ex_unchecked(
+        &self,
+        project: ProjectContext,
+        buffer_size: u32,
+    ) -> Utf8PathBuf
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (reaper_string, _) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low
+                .GetProjectPathEx(project.to_raw(), buffer, max_size)
+        });
+        let owned_string = reaper_string.into_string();
+        Utf8PathBuf::from(owned_string)
+    }
+
+    /// Creates a marker or region.
+    ///
+    /// Returns the index of the created marker/region.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn add_project_marker_2<'a>(
+        &self,
+        project: ProjectContext,
+        pos: MarkerOrRegionPosition,
+        name: impl Into<ReaperStringArg<'a>>,
+        at_index: Option<u32>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.add_project_marker_2_unchecked(project, pos, name, at_index, color) }
+    }
+
+    /// Like [`add_project_marker_2()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`add_project_marker_2()`]: #method.add_project_marker_2
+    pub unsafe fn add_project_marker_2_unchecked<'a>(
+        &self,
+        project: ProjectContext,
+        pos: MarkerOrRegionPosition,
+        name: impl Into<ReaperStringArg<'a>>,
+        at_index: Option<u32>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (is_region, start, end) = match pos {
+            MarkerOrRegionPosition::Marker(p) => (false, p.get(), 0.0),
+            MarkerOrRegionPosition::Region(s, e) => (true, s.get(), e.get()),
+        };
+        let index = self.low.AddProjectMarker2(
+            project.to_raw(),
+            is_region,
+            start,
+            end,
+            name.into().as_ptr(),
+            at_index.map(|i| i as i32).unwrap_or(-1),
+            color.map(|c| c.to_raw()).unwrap_or(0),
+        );
+        if index < 0 {
+            return Err(ReaperFunctionError::new("failed to add project marker"));
+        }
+        Ok(index as u32)
+    }
+
+    /// Edits the marker or region at the given index (counting both markers and regions).
+    ///
+    /// An empty name leaves the name unchanged unless [`SetProjectMarkerFlag::ClearName`] is
+    /// given. Passing `None` as color leaves the color unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the marker or region couldn't be edited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    ///
+    /// [`SetProjectMarkerFlag::ClearName`]: enum.SetProjectMarkerFlag.html#variant.ClearName
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_project_marker_by_index_2<'a>(
+        &self,
+        project: ProjectContext,
+        index: u32,
+        pos: MarkerOrRegionPosition,
+        id: BookmarkId,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+        flags: BitFlags<SetProjectMarkerFlag>,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe {
+            self.set_project_marker_by_index_2_unchecked(
+                project, index, pos, id, name, color, flags,
+            )
+        }
+    }
+
+    /// Like [`set_project_marker_by_index_2()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`set_project_marker_by_index_2()`]: #method.set_project_marker_by_index_2
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn set_project_marker_by_index_2_unchecked<'a>(
+        &self,
+        project: ProjectContext,
+        index: u32,
+        pos: MarkerOrRegionPosition,
+        id: BookmarkId,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+        flags: BitFlags<SetProjectMarkerFlag>,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (is_region, start, end) = match pos {
+            MarkerOrRegionPosition::Marker(p) => (false, p.get(), 0.0),
+            MarkerOrRegionPosition::Region(s, e) => (true, s.get(), e.get()),
+        };
+        let successful = self.low.SetProjectMarkerByIndex2(
+            project.to_raw(),
+            index as i32,
+            is_region,
+            start,
+            end,
+            id.get() as i32,
+            name.into().as_ptr(),
+            color.map(|c| c.to_raw()).unwrap_or(0),
+            flags.bits() as i32,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't edit project marker",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Deletes the marker or region at the given index (counting both markers and regions).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the marker or region couldn't be deleted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn delete_project_marker_by_index(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.delete_project_marker_by_index_unchecked(project, index) }
+    }
+
+    /// Like [`delete_project_marker_by_index()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`delete_project_marker_by_index()`]: #method.delete_project_marker_by_index
+    pub unsafe fn delete_project_marker_by_index_unchecked(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self
+            .low
+            .DeleteProjectMarkerByIndex(project.to_raw(), index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't delete project marker",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the master tempo of the current project.
+    pub fn master_get_tempo(&self) -> Bpm
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        Bpm::new_panic(self.low.Master_GetTempo())
+    }
+
+    /// Sets the current tempo of the given project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn set_current_bpm(&self, project: ProjectContext, tempo: Bpm, undo_behavior: UndoBehavior)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe {
+            self.set_current_bpm_unchecked(project, tempo, undo_behavior);
+        }
+    }
+
+    /// Like [`set_current_bpm()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`set_current_bpm()`]: #method.set_current_bpm
+    pub unsafe fn set_current_bpm_unchecked(
+        &self,
+        project: ProjectContext,
+        tempo: Bpm,
+        undo_behavior: UndoBehavior,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.SetCurrentBPM(
+            project.to_raw(),
+            tempo.get(),
+            undo_behavior == UndoBehavior::AddUndoPoint,
+        );
+    }
+
+    /// Count the number of tempo/time signature markers in the project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn count_tempo_time_sig_markers(&self, project: ProjectContext) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.count_tempo_time_sig_markers_unchecked(project) }
+    }
+
+    /// Like [`set_current_bpm()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_project_path_ex()`]: #method.get_project_path_ex
-    pub unsafe fn get_project_path_ex_unchecked(
-        &self,
-        project: ProjectContext,
-        buffer_size: u32,
-    ) -> Utf8PathBuf
+    /// [`set_current_bpm()`]: #method.set_current_bpm
+    pub unsafe fn count_tempo_time_sig_markers_unchecked(&self, project: ProjectContext) -> u32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let (reaper_string, _) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low
-                .GetProjectPathEx(project.to_raw(), buffer, max_size)
-        });
-        let owned_string = reaper_string.into_string();
-        Utf8PathBuf::from(owned_string)
+        self.low.CountTempoTimeSigMarkers(project.to_raw()) as u32
     }
 
-    /// Creates a marker or region.
+    /// Returns the tempo/time signature marker at the given index.
     ///
-    /// Returns the index of the created marker/region.
+    /// # Errors
+    ///
+    /// Returns an error if the marker doesn't exist.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn add_project_marker_2<'a>(
+    pub fn get_tempo_time_sig_marker(
         &self,
         project: ProjectContext,
-        pos: MarkerOrRegionPosition,
-        name: impl Into<ReaperStringArg<'a>>,
-        at_index: Option<u32>,
-        color: Option<NativeColor>,
-    ) -> ReaperFunctionResult<u32>
+        marker_index: u32,
+    ) -> ReaperFunctionResult<TempoTimeSigMarker>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.add_project_marker_2_unchecked(project, pos, name, at_index, color) }
+        unsafe { self.get_tempo_time_sig_marker_unchecked(project, marker_index) }
     }
 
-    /// Like [`add_project_marker_2()`] but doesn't check if project is valid.
+    /// Like [`get_tempo_time_sig_marker()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`add_project_marker_2()`]: #method.add_project_marker_2
-    pub unsafe fn add_project_marker_2_unchecked<'a>(
+    /// [`get_tempo_time_sig_marker()`]: #method.get_tempo_time_sig_marker
+    pub unsafe fn get_tempo_time_sig_marker_unchecked(
         &self,
         project: ProjectContext,
-        pos: MarkerOrRegionPosition,
-        name: impl Into<ReaperStringArg<'a>>,
-        at_index: Option<u32>,
-        color: Option<NativeColor>,
-    ) -> ReaperFunctionResult<u32>
+        marker_index: u32,
+    ) -> ReaperFunctionResult<TempoTimeSigMarker>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let (is_region, start, end) = match pos {
-            MarkerOrRegionPosition::Marker(p) => (false, p.get(), 0.0),
-            MarkerOrRegionPosition::Region(s, e) => (true, s.get(), e.get()),
-        };
-        let index = self.low.AddProjectMarker2(
+        let mut time_pos = MaybeUninit::zeroed();
+        let mut measure_pos = MaybeUninit::zeroed();
+        let mut beat_pos = MaybeUninit::zeroed();
+        let mut bpm = MaybeUninit::zeroed();
+        let mut time_sig_num = MaybeUninit::zeroed();
+        let mut time_sig_denom = MaybeUninit::zeroed();
+        let mut linear_tempo = MaybeUninit::zeroed();
+        let successful = self.low.GetTempoTimeSigMarker(
             project.to_raw(),
-            is_region,
-            start,
-            end,
-            name.into().as_ptr(),
-            at_index.map(|i| i as i32).unwrap_or(-1),
-            color.map(|c| c.to_raw()).unwrap_or(0),
+            marker_index as i32,
+            time_pos.as_mut_ptr(),
+            measure_pos.as_mut_ptr(),
+            beat_pos.as_mut_ptr(),
+            bpm.as_mut_ptr(),
+            time_sig_num.as_mut_ptr(),
+            time_sig_denom.as_mut_ptr(),
+            linear_tempo.as_mut_ptr(),
         );
-        if index < 0 {
-            return Err(ReaperFunctionError::new("failed to add project marker"));
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't get tempo/time signature marker (maybe it doesn't exist)",
+            ));
         }
-        Ok(index as u32)
-    }
-
-    /// Returns the master tempo of the current project.
-    pub fn master_get_tempo(&self) -> Bpm
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        Bpm::new_panic(self.low.Master_GetTempo())
+        let time_signature = TimeSignature::new(
+            time_sig_num.assume_init() as u32,
+            time_sig_denom.assume_init() as u32,
+        )
+        .ok();
+        let marker = TempoTimeSigMarker {
+            position: PositionInSeconds::new_panic(time_pos.assume_init()),
+            measure_index: measure_pos.assume_init(),
+            beats_since_measure: PositionInBeats::new_panic(beat_pos.assume_init()),
+            tempo: Bpm::new_panic(bpm.assume_init()),
+            time_signature,
+            linear_tempo: linear_tempo.assume_init(),
+        };
+        Ok(marker)
     }
 
-    /// Sets the current tempo of the given project.
+    /// Modifies the tempo/time signature marker at the given index or, if `marker_index` is
+    /// `None`, inserts a new one.
+    ///
+    /// If `time_signature` is `None`, the marker doesn't change the time signature. You probably
+    /// want to call [`update_timeline()`] afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the marker doesn't exist.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn set_current_bpm(&self, project: ProjectContext, tempo: Bpm, undo_behavior: UndoBehavior)
+    ///
+    /// [`update_timeline()`]: #method.update_timeline
+    pub fn set_tempo_time_sig_marker(
+        &self,
+        project: ProjectContext,
+        marker_index: Option<u32>,
+        position: PositionInSeconds,
+        tempo: Bpm,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
         unsafe {
-            self.set_current_bpm_unchecked(project, tempo, undo_behavior);
+            self.set_tempo_time_sig_marker_unchecked(
+                project,
+                marker_index,
+                position,
+                tempo,
+                time_signature,
+                linear_tempo,
+            )
         }
     }
 
-    /// Like [`set_current_bpm()`] but doesn't check if project is valid.
+    /// Like [`set_tempo_time_sig_marker()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`set_current_bpm()`]: #method.set_current_bpm
-    pub unsafe fn set_current_bpm_unchecked(
+    /// [`set_tempo_time_sig_marker()`]: #method.set_tempo_time_sig_marker
+    pub unsafe fn set_tempo_time_sig_marker_unchecked(
         &self,
         project: ProjectContext,
+        marker_index: Option<u32>,
+        position: PositionInSeconds,
         tempo: Bpm,
-        undo_behavior: UndoBehavior,
-    ) where
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.SetCurrentBPM(
+        let (num, denom) = match time_signature {
+            None => (0, 0),
+            Some(ts) => (ts.numerator.get() as i32, ts.denominator.get() as i32),
+        };
+        let successful = self.low.SetTempoTimeSigMarker(
             project.to_raw(),
+            marker_index.map(|i| i as i32).unwrap_or(-1),
+            position.get(),
+            -1,
+            -1.0,
             tempo.get(),
-            undo_behavior == UndoBehavior::AddUndoPoint,
+            num,
+            denom,
+            linear_tempo,
         );
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't set tempo/time signature marker (maybe it doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Count the number of tempo/time signature markers in the project.
+    /// Deletes the tempo/time signature marker at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the marker doesn't exist.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn count_tempo_time_sig_markers(&self, project: ProjectContext) -> u32
+    pub fn delete_tempo_time_sig_marker(
+        &self,
+        project: ProjectContext,
+        marker_index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.count_tempo_time_sig_markers_unchecked(project) }
+        unsafe { self.delete_tempo_time_sig_marker_unchecked(project, marker_index) }
     }
 
-    /// Like [`set_current_bpm()`] but doesn't check if project is valid.
+    /// Like [`delete_tempo_time_sig_marker()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`set_current_bpm()`]: #method.set_current_bpm
-    pub unsafe fn count_tempo_time_sig_markers_unchecked(&self, project: ProjectContext) -> u32
+    /// [`delete_tempo_time_sig_marker()`]: #method.delete_tempo_time_sig_marker
+    pub unsafe fn delete_tempo_time_sig_marker_unchecked(
+        &self,
+        project: ProjectContext,
+        marker_index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CountTempoTimeSigMarkers(project.to_raw()) as u32
+        let successful = self
+            .low
+            .DeleteTempoTimeSigMarker(project.to_raw(), marker_index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't delete tempo/time signature marker (maybe it doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
     /// Converts the given playback speed factor to a normalized play rate.
@@ -6165,7 +8419,9 @@ where
         }
         let guid = unsafe { guid.assume_init() };
         if guid == ZERO_GUID {
-            return Err(ReaperFunctionError::new("GUID string is invalid"));
+            return Err(ReaperFunctionError::invalid_argument(
+                "GUID string is invalid",
+            ));
         }
         Ok(guid)
     }
@@ -6371,6 +8627,96 @@ where
         self.low.SetMediaItemSelected(item.as_ptr(), selected);
     }
 
+    /// Splits the given item at the given position.
+    ///
+    /// Returns the new right-hand item. The given item becomes the left-hand item.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position is not within the item.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn split_media_item(
+        &self,
+        item: MediaItem,
+        position: PositionInSeconds,
+    ) -> ReaperFunctionResult<MediaItem>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.SplitMediaItem(item.as_ptr(), position.get());
+        MediaItem::new(ptr)
+            .ok_or_else(|| ReaperFunctionError::invalid_argument("couldn't split item"))
+    }
+
+    /// Moves the given item to the given track.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item or track.
+    pub unsafe fn move_media_item_to_track(
+        &self,
+        item: MediaItem,
+        track: MediaTrack,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self.low.MoveMediaItemToTrack(item.as_ptr(), track.as_ptr());
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't move item to track"));
+        }
+        Ok(())
+    }
+
+    /// Makes the given take the active take of its item.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn set_active_take(&self, take: MediaItemTake)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.SetActiveTake(take.as_ptr());
+    }
+
+    /// Returns the item to which the given take belongs.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn get_media_item_take_item(&self, take: MediaItemTake) -> Option<MediaItem>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.GetMediaItemTake_Item(take.as_ptr());
+        MediaItem::new(ptr)
+    }
+
+    /// Updates the display of the given item in the arrange view.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn update_item_in_project(&self, item: MediaItem)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.UpdateItemInProject(item.as_ptr());
+    }
+
     /// Sets a track attribute as numerical value.
     ///
     /// # Errors
@@ -6396,7 +8742,7 @@ where
             new_value,
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::invalid_argument(
                 "couldn't set track attribute (maybe attribute key is invalid)",
             ));
         }
@@ -6432,7 +8778,7 @@ where
             new_value,
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::invalid_argument(
                 "couldn't set track send attribute (maybe attribute key is invalid)",
             ));
         }
@@ -7031,6 +9377,30 @@ where
         NonNull::new(ptr)
     }
 
+    /// Returns the time of the currently relevant item edit.
+    ///
+    /// If the user is editing an item (e.g. resizing it), this returns the time of that edit and
+    /// information about the edited item. Otherwise it returns the edit cursor position or the
+    /// start of the item.
+    pub fn get_item_editing_time_2(&self) -> GetItemEditingTime2Result
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut source = MaybeUninit::zeroed();
+        let mut flags = MaybeUninit::zeroed();
+        unsafe {
+            let time = self
+                .low
+                .GetItemEditingTime2(source.as_mut_ptr(), flags.as_mut_ptr());
+            GetItemEditingTime2Result {
+                time: PositionInSeconds::new_panic(time),
+                source: NonNull::new(source.assume_init()),
+                operations: BitFlags::from_bits_truncate(flags.assume_init() as u32),
+            }
+        }
+    }
+
     /// Returns the project which contains this item.
     ///
     /// # Safety
@@ -7194,7 +9564,7 @@ where
             null_mut(),
         ) as *mut raw::MediaTrack;
         MediaTrack::new(ptr).ok_or_else(|| {
-            ReaperFunctionError::new("couldn't get source track (maybe send doesn't exist)")
+            ReaperFunctionError::not_found("couldn't get source track (maybe send doesn't exist)")
         })
     }
 
@@ -7228,7 +9598,9 @@ where
             null_mut(),
         ) as *mut raw::MediaTrack;
         MediaTrack::new(ptr).ok_or_else(|| {
-            ReaperFunctionError::new("couldn't get destination track (maybe send doesn't exist)")
+            ReaperFunctionError::not_found(
+                "couldn't get destination track (maybe send doesn't exist)",
+            )
         })
     }
 
@@ -7560,7 +9932,7 @@ where
             cache_hint == ChunkCacheHint::UndoMode,
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::invalid_argument(
                 "couldn't set track chunk (maybe chunk was invalid)",
             ));
         }
@@ -7681,54 +10053,107 @@ where
     /// When choosing the send index, keep in mind that the hardware output sends (if any) come
     /// first.
     ///
-    /// Returns the new value. If the send doesn't exist, returns 0.0 (which
+    /// Returns the new value. If the send doesn't exist, returns 0.0 (which
+    /// can also be a valid value that has been set, so that's not very useful).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn csurf_on_send_volume_change(
+        &self,
+        track: MediaTrack,
+        send_index: u32,
+        value_change: ValueChange<ReaperVolumeValue>,
+    ) -> ReaperVolumeValue
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw = self.low.CSurf_OnSendVolumeChange(
+            track.as_ptr(),
+            send_index as i32,
+            value_change.value(),
+            value_change.is_relative(),
+        );
+        ReaperVolumeValue::new_panic(raw)
+    }
+
+    /// Sets the pan of the given track send or hardware output send.
+    ///
+    /// When choosing the send index, keep in mind that the hardware output sends (if any) come
+    /// first.
+    ///
+    /// Returns the new value.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn csurf_on_send_pan_change(
+        &self,
+        track: MediaTrack,
+        send_index: u32,
+        value_change: ValueChange<ReaperPanValue>,
+    ) -> ReaperPanValue
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw = self.low.CSurf_OnSendPanChange(
+            track.as_ptr(),
+            send_index as i32,
+            value_change.value(),
+            value_change.is_relative(),
+        );
+        ReaperPanValue::new_panic(raw)
+    }
+
+    /// Sets the volume of the given track receive.
+    ///
+    /// Returns the new value. If the receive doesn't exist, returns 0.0 (which
     /// can also be a valid value that has been set, so that's not very useful).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn csurf_on_send_volume_change(
+    pub unsafe fn csurf_on_recv_volume_change(
         &self,
         track: MediaTrack,
-        send_index: u32,
+        receive_index: u32,
         value_change: ValueChange<ReaperVolumeValue>,
     ) -> ReaperVolumeValue
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw = self.low.CSurf_OnSendVolumeChange(
+        let raw = self.low.CSurf_OnRecvVolumeChange(
             track.as_ptr(),
-            send_index as i32,
+            receive_index as i32,
             value_change.value(),
             value_change.is_relative(),
         );
         ReaperVolumeValue::new_panic(raw)
     }
 
-    /// Sets the pan of the given track send or hardware output send.
-    ///
-    /// When choosing the send index, keep in mind that the hardware output sends (if any) come
-    /// first.
+    /// Sets the pan of the given track receive.
     ///
     /// Returns the new value.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn csurf_on_send_pan_change(
+    pub unsafe fn csurf_on_recv_pan_change(
         &self,
         track: MediaTrack,
-        send_index: u32,
+        receive_index: u32,
         value_change: ValueChange<ReaperPanValue>,
     ) -> ReaperPanValue
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw = self.low.CSurf_OnSendPanChange(
+        let raw = self.low.CSurf_OnRecvPanChange(
             track.as_ptr(),
-            send_index as i32,
+            receive_index as i32,
             value_change.value(),
             value_change.is_relative(),
         );
@@ -7762,6 +10187,68 @@ where
             .map(use_action_name)
     }
 
+    /// Returns the number of keyboard shortcuts assigned to the given action.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid section.
+    pub unsafe fn count_action_shortcuts(
+        &self,
+        section: SectionContext,
+        command_id: CommandId,
+    ) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let count = self
+            .low
+            .CountActionShortcuts(section.to_raw(), command_id.get() as _);
+        count.max(0) as u32
+    }
+
+    /// Returns a human-readable description of the keyboard shortcut with the given index assigned
+    /// to the given action.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the description you want.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's no shortcut with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid section.
+    pub unsafe fn get_action_shortcut_desc(
+        &self,
+        section: SectionContext,
+        command_id: CommandId,
+        shortcut_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (desc, successful) = with_string_buffer(buffer_size, |buffer, max| {
+            self.low.GetActionShortcutDesc(
+                section.to_raw(),
+                command_id.get() as _,
+                shortcut_index as _,
+                buffer,
+                max,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::not_found("shortcut doesn't exist"));
+        }
+        Ok(desc)
+    }
+
     /// Grants temporary access to the name of the given input channel.
     pub fn get_input_channel_name<R>(
         &self,
@@ -7792,6 +10279,88 @@ where
         use_resource_path(path)
     }
 
+    /// Grants temporary access to the extended state value stored under the given section and key.
+    ///
+    /// Passes `None` if there's no such value.
+    pub fn get_ext_state<'a, R>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        use_value: impl FnOnce(Option<&ReaperStr>) -> R,
+    ) -> R
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let section = section.into();
+        let key = key.into();
+        let exists = unsafe { self.low.HasExtState(section.as_ptr(), key.as_ptr()) };
+        if !exists {
+            return use_value(None);
+        }
+        let ptr = unsafe { self.low.GetExtState(section.as_ptr(), key.as_ptr()) };
+        let passing_c_str = unsafe { create_passing_c_str(ptr) };
+        use_value(passing_c_str)
+    }
+
+    /// Stores the given extended state value under the given section and key.
+    ///
+    /// If `persist` is `true`, the value is written to `reaper-extstate.ini` and is available
+    /// again the next time REAPER is started. The value must not contain line breaks in that case.
+    pub fn set_ext_state<'a>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        value: impl Into<ReaperStringArg<'a>>,
+        persist: bool,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        unsafe {
+            self.low.SetExtState(
+                section.into().as_ptr(),
+                key.into().as_ptr(),
+                value.into().as_ptr(),
+                persist,
+            );
+        }
+    }
+
+    /// Returns whether there's an extended state value stored under the given section and key.
+    pub fn has_ext_state<'a>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+    ) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        unsafe {
+            self.low
+                .HasExtState(section.into().as_ptr(), key.into().as_ptr())
+        }
+    }
+
+    /// Deletes the extended state value stored under the given section and key.
+    ///
+    /// If `persist` is `true`, the value is also removed from `reaper-extstate.ini`.
+    pub fn delete_ext_state<'a>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        persist: bool,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        unsafe {
+            self.low
+                .DeleteExtState(section.into().as_ptr(), key.into().as_ptr(), persist);
+        }
+    }
+
     /// Grants temporary access to the name of the given take.
     ///
     /// # Error
@@ -7810,7 +10379,7 @@ where
             let ptr = self.low.GetTakeName(take.as_ptr());
             create_passing_c_str(ptr as *const c_char)
         };
-        use_name(passing_c_str.ok_or_else(|| ReaperFunctionError::new("invalid take")))
+        use_name(passing_c_str.ok_or_else(|| ReaperFunctionError::not_found("invalid take")))
     }
 
     /// Returns the current on/off state of a toggleable action.
@@ -7863,6 +10432,18 @@ where
         Some(result != 0)
     }
 
+    /// Makes REAPER refresh the toolbar buttons associated with the given action.
+    ///
+    /// Should be called whenever the on/off state of a toggleable action has changed.
+    pub fn refresh_toolbar_2(&self, section_id: SectionId, command_id: CommandId)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .RefreshToolbar2(section_id.to_raw(), command_id.to_raw());
+    }
+
     /// Grants temporary access to the name of the command registered under the given command ID.
     ///
     /// The string will *not* start with `_` (e.g. it will return `SWS_ABOUT`).
@@ -7913,7 +10494,7 @@ where
             pan.as_mut_ptr(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get track send volume and pan (probably send doesn't exist)",
             ));
         }
@@ -7952,7 +10533,7 @@ where
             pan.as_mut_ptr(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get track receive volume and pan (probably receive doesn't exist)",
             ));
         }
@@ -7990,7 +10571,7 @@ where
             self.low
                 .GetTrackSendUIMute(track.as_ptr(), send_index as i32, muted.as_mut_ptr());
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get track send mute state (probably send doesn't exist)",
             ));
         }
@@ -8024,7 +10605,7 @@ where
             muted.as_mut_ptr(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't get track receive mute state (probably receive doesn't exist)",
             ));
         }
@@ -8053,7 +10634,7 @@ where
             .low
             .ToggleTrackSendUIMute(track.as_ptr(), send.to_raw());
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't toggle track send mute state (probably send doesn't exist)",
             ));
         }
@@ -8087,7 +10668,7 @@ where
             edit_mode.to_raw(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't set track send volume (probably send doesn't exist)",
             ));
         }
@@ -8121,7 +10702,7 @@ where
             edit_mode.to_raw(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't set track send pan (probably send doesn't exist)",
             ));
         }
@@ -8192,7 +10773,7 @@ where
             preset.to_raw(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't activate FX preset by index (maybe FX or preset doesn't exist)",
             ));
         }
@@ -8226,7 +10807,7 @@ where
             presetname.into().as_ptr(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't select FX preset by its name (maybe FX or preset doesn't exist)",
             ));
         }
@@ -8271,7 +10852,7 @@ where
             self.low
                 .TrackFX_NavigatePresets(track.as_ptr(), fx_location.to_raw(), increment);
         if !successful {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "couldn't navigate FX presets (maybe FX doesn't exist)",
             ));
         }
@@ -8329,6 +10910,69 @@ where
         }
     }
 
+    /// Like [`track_fx_get_preset()`] but determines the necessary buffer size for the preset name
+    /// automatically.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    ///
+    /// [`track_fx_get_preset()`]: #method.track_fx_get_preset
+    pub unsafe fn track_fx_get_preset_auto(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> TrackFxGetPresetResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (name, state_matches_preset) = with_auto_sized_string_buffer(|buffer, max_size| {
+            self.low
+                .TrackFX_GetPreset(track.as_ptr(), fx_location.to_raw(), buffer, max_size)
+        });
+        TrackFxGetPresetResult {
+            state_matches_preset,
+            name: if name.is_empty() { None } else { Some(name) },
+        }
+    }
+
+    /// Returns the path of the file in which REAPER stores the user presets of the given track FX.
+    ///
+    /// The file doesn't necessarily exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error e.g. if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_user_preset_filename(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> ReaperFunctionResult<Utf8PathBuf>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (file_name, _) = with_string_buffer(4096, |buffer, max_size| {
+            self.low.TrackFX_GetUserPresetFilename(
+                track.as_ptr(),
+                fx_location.to_raw(),
+                buffer,
+                max_size,
+            )
+        });
+        if file_name.is_empty() {
+            return Err(ReaperFunctionError::not_found(
+                "couldn't get user preset file name (maybe FX doesn't exist)",
+            ));
+        }
+        Ok(Utf8PathBuf::from(file_name.into_string()))
+    }
+
     /// Grants temporary access to an already open MIDI input device.
     ///
     /// Passes `None` to the given function if the device doesn't exist, is not connected or is not
@@ -8874,14 +11518,112 @@ where
         Ok(())
     }
 
+    /// Grants temporary access to the localized version of the given string, according to the
+    /// currently loaded REAPER language pack.
+    ///
+    /// If no translation is available, the source string itself is passed.
+    ///
+    /// If the source string is not a static literal, you must pass [`LocalizeFlag::NoCache`].
+    /// For static literals, consider using the [`localize!`] macro.
+    ///
+    /// [`localize!`]: crate::localize
+    pub fn localize_string<'a, R>(
+        &self,
+        src_string: impl Into<ReaperStringArg<'a>>,
+        section: impl Into<ReaperStringArg<'a>>,
+        flags: BitFlags<LocalizeFlag>,
+        use_localized: impl FnOnce(&ReaperStr) -> R,
+    ) -> R
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let src_string = src_string.into();
+        let ptr = unsafe {
+            self.low.LocalizeString(
+                src_string.as_ptr(),
+                section.into().as_ptr(),
+                flags.bits() as i32,
+            )
+        };
+        match unsafe { create_passing_c_str(ptr) } {
+            None => use_localized(src_string.as_reaper_str()),
+            Some(localized) => use_localized(localized),
+        }
+    }
+
+    /// Reallocates a "NeedBig" string buffer which REAPER passed to an exported API function.
+    ///
+    /// API functions registered via [`plugin_register_add_api_and_def()`] can return strings of
+    /// arbitrary length to ReaScript callers by declaring an output argument whose name ends with
+    /// `NeedBig` (e.g. `char* bufOutNeedBig, int bufOutNeedBig_sz`). REAPER passes a buffer which
+    /// is possibly too small. This function resizes it to `new_size` bytes and updates both the
+    /// buffer pointer and the buffer size in place. The previous content is not preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer couldn't be reallocated.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass pointers that don't refer to a buffer and buffer size
+    /// which REAPER passed to the currently executing API function.
+    ///
+    /// [`plugin_register_add_api_and_def()`]: crate::ReaperSession::plugin_register_add_api_and_def
+    pub unsafe fn realloc_cmd_ptr(
+        &self,
+        buffer: *mut *mut c_char,
+        buffer_size: *mut c_int,
+        new_size: u32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self
+            .low
+            .realloc_cmd_ptr(buffer, buffer_size, new_size as c_int);
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't reallocate buffer"));
+        }
+        Ok(())
+    }
+
+    /// Writes the given text into a "NeedBig" string buffer which REAPER passed to an exported API
+    /// function, reallocating the buffer via [`realloc_cmd_ptr()`] if it's too small.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer was too small and couldn't be reallocated.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`realloc_cmd_ptr()`].
+    ///
+    /// [`realloc_cmd_ptr()`]: #method.realloc_cmd_ptr
+    pub unsafe fn realloc_cmd_write_string<'a>(
+        &self,
+        buffer: *mut *mut c_char,
+        buffer_size: *mut c_int,
+        text: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let text = text.into();
+        let bytes = text.as_reaper_str().as_c_str().to_bytes_with_nul();
+        if (*buffer).is_null() || *buffer_size < bytes.len() as c_int {
+            self.realloc_cmd_ptr(buffer, buffer_size, bytes.len() as u32)?;
+        }
+        std::ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, *buffer, bytes.len());
+        Ok(())
+    }
+
     fn require_main_thread(&self)
     where
         UsageScope: AnyThread,
     {
-        assert!(
-            self.low.plugin_context().is_in_main_thread(),
-            "called main-thread-only function from wrong thread"
-        )
+        require_main_thread(self.low.plugin_context());
     }
 
     pub(crate) fn require_valid_project(&self, project: ProjectContext)
@@ -8924,6 +11666,33 @@ pub enum GetParameterStepSizesResult {
     Toggle,
 }
 
+/// A point of an envelope.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EnvelopePoint {
+    /// Position of the point.
+    pub time: PositionInSeconds,
+    /// Value in the envelope's native scaling.
+    pub value: f64,
+    /// Shape of the curve to the next point.
+    pub shape: EnvelopePointShape,
+    /// Tension of the curve (only relevant for bezier shapes), from -1.0 to 1.0.
+    pub tension: f64,
+    /// Whether the point is selected.
+    pub is_selected: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EnvelopeEvaluateResult {
+    /// Value at the evaluated position.
+    pub value: f64,
+    /// Change of the value per sample.
+    pub first_derivative: f64,
+    pub second_derivative: f64,
+    pub third_derivative: f64,
+    /// Number of samples for which the result stays valid.
+    pub samples_valid: u32,
+}
+
 /// Each of these values can be negative! They are not normalized.
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct GetParamExResult {
@@ -9046,6 +11815,40 @@ pub struct GetLoopTimeRange2Result {
     pub end: PositionInSeconds,
 }
 
+impl From<GetLoopTimeRange2Result> for TimeRange {
+    fn from(r: GetLoopTimeRange2Result) -> Self {
+        // REAPER doesn't report reversed ranges, but we don't want to panic if it ever does
+        TimeRange::new_panic(r.start.min(r.end), r.start.max(r.end))
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GetItemEditingTime2Result {
+    /// Time of the relevant edit.
+    pub time: PositionInSeconds,
+    /// Source of the edited item (if applicable).
+    pub source: Option<PcmSource>,
+    /// Kind of editing operations which are going on.
+    pub operations: BitFlags<ItemEditingOperation>,
+}
+
+/// Disk read parameters as configured by the user, meant to be passed to `WDL_FileRead`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DiskReadMode {
+    pub mode: i32,
+    pub buffer_count: i32,
+    pub buffer_size: i32,
+}
+
+/// Disk write parameters as configured by the user, meant to be passed to `WDL_FileWrite`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DiskWriteMode {
+    pub mode: i32,
+    pub initial_buffer_count: i32,
+    pub max_buffer_count: i32,
+    pub buffer_size: i32,
+}
+
 /// The arrange view start/end time for the given screen coordinates.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct GetSetArrangeView2Result {
@@ -9089,13 +11892,20 @@ pub struct TimeMapQnToMeasuresResult {
     pub end: PositionInQuarterNotes,
 }
 
-/// Time signature.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub struct TimeSignature {
-    /// Measure length in beats.
-    pub numerator: NonZeroU32,
-    /// What musical unit one beat stands for.
-    pub denominator: NonZeroU32,
+/// A tempo/time signature marker.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TempoTimeSigMarker {
+    /// Position of the marker in seconds.
+    pub position: PositionInSeconds,
+    /// Index of the measure in which the marker is located.
+    pub measure_index: i32,
+    /// Position in beats within that measure.
+    pub beats_since_measure: PositionInBeats,
+    pub tempo: Bpm,
+    /// `None` if the marker doesn't change the time signature.
+    pub time_signature: Option<TimeSignature>,
+    /// Whether the tempo changes gradually towards the next marker.
+    pub linear_tempo: bool,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -9292,6 +12102,27 @@ unsafe fn deref_as<T: Copy>(ptr: *mut c_void) -> Option<T> {
     deref(ptr as *const T)
 }
 
+fn check_known_config_var_type(
+    name: &ReaperStr,
+    expected_type: ConfigVarType,
+) -> ReaperFunctionResult<()> {
+    match ConfigVarType::of_known_config_var(name) {
+        Some(known_type) if known_type != expected_type => Err(
+            ReaperFunctionError::invalid_argument("config variable has a different type"),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Converts a color as returned by `GetDisplayedMediaItemColor` (`ColorToNative(r,g,b)|0x01000000`,
+/// where 0 means "no color").
+fn convert_displayed_color(raw: i32) -> Option<NativeColor> {
+    if raw == 0 {
+        return None;
+    }
+    Some(NativeColor::new(raw & 0xffffff))
+}
+
 fn convert_tracknumber_to_track_location(tracknumber: u32) -> TrackLocation {
     if tracknumber == 0 {
         TrackLocation::MasterTrack
@@ -9300,6 +12131,9 @@ fn convert_tracknumber_to_track_location(tracknumber: u32) -> TrackLocation {
     }
 }
 
+/// Maximum size of a named config parameter value (chunks can be really big).
+const NAMED_CONFIG_PARM_MAX_SIZE: usize = 256 * 1024 * 1024;
+
 const ZERO_GUID: GUID = GUID {
     Data1: 0,
     Data2: 0,
@@ -1,4 +1,4 @@
-use std::ptr::NonNull;
+use std::ptr::{null_mut, NonNull};
 
 use reaper_low::{
     create_cpp_to_rust_control_surface, delete_cpp_control_surface, raw, IReaperControlSurface,
@@ -12,22 +12,33 @@ use crate::{
     delegating_hook_post_command, delegating_hook_post_command_2, delegating_toggle_action,
     AcceleratorPosition, BufferingBehavior, CommandId, ControlSurface, ControlSurfaceAdapter,
     FileInProjectCallback, GenericRegistrationHandle, Handle, HookCommand, HookCommand2,
-    HookCustomMenu, HookPostCommand, HookPostCommand2, HwndInfo, MainThreadScope, MeasureAlignment,
-    OnAudioBuffer, OwnedAcceleratorRegister, OwnedAudioHookRegister, OwnedGaccelRegister,
-    OwnedPreviewRegister, PluginRegistration, ProjectContext, ReaProject, RealTimeAudioThreadScope,
-    Reaper, ReaperFunctionError, ReaperFunctionResult, ReaperMutex, ReaperString, ReaperStringArg,
-    RegistrationHandle, RegistrationObject, ToggleAction, ToolbarIconMap, TranslateAccel,
+    HookCommandSubscription, HookCustomMenu, HookPostCommand, HookPostCommand2,
+    HookPostCommandSubscription, HwndInfo, MainThreadScope, MeasureAlignment, OnAudioBuffer,
+    OrderedAudioHookHandle, OwnedAcceleratorRegister, OwnedAudioHookRegister, OwnedGaccelRegister,
+    OwnedPreviewRegister, PluginRegistration, ProjectConfigExtension, ProjectContext, ReaProject,
+    RealTimeAudioThreadScope, Reaper, ReaperFunctionError, ReaperFunctionResult, ReaperMutex,
+    ReaperString, ReaperStringArg, Registration, RegistrationHandle, RegistrationObject,
+    ScreensetCallback, SharedReaper, ToggleAction, ToolbarIconMap, TranslateAccel,
 };
 use reaper_low::raw::audio_hook_register_t;
 
+use crate::audio_hook_register::OrderedAudioHookChain;
 use crate::file_in_project_hook::OwnedFileInProjectHook;
 use crate::fn_traits::{
     delegating_hook_custom_menu, delegating_hwnd_info, delegating_hwnd_info_since_723,
-    delegating_toolbar_icon_map,
+    delegating_screenset_callback, delegating_toolbar_icon_map,
 };
+use crate::hook_dispatcher::{
+    clear_hook_command_handlers, clear_hook_post_command_subscribers, subscribe_hook_command,
+    subscribe_hook_post_command, unsubscribe_hook_command, unsubscribe_hook_post_command,
+    HookCommandDispatcher, HookPostCommandDispatcher,
+};
+use crate::project_config_extension::OwnedProjectConfigExtension;
+use crate::registration::RegistrationSlot;
 use enumflags2::BitFlags;
 use std::collections::{HashMap, HashSet};
 use std::os::raw::{c_char, c_void};
+use std::rc::Rc;
 use std::sync::Arc;
 
 /// This is the main hub for accessing medium-level API functions.
@@ -67,7 +78,7 @@ use std::sync::Arc;
 /// [`reaper()`]: #method.reaper
 #[derive(Debug, Default)]
 pub struct ReaperSession {
-    reaper: Reaper<MainThreadScope>,
+    reaper: SharedReaper<MainThreadScope>,
     /// Provides a safe place in memory for registered actions.
     gaccel_registers: Keeper<OwnedGaccelRegister, raw::gaccel_register_t>,
     /// Provides a safe place in memory for accelerator registers.
@@ -81,6 +92,11 @@ pub struct ReaperSession {
     // We don't need to box the string because it's content is something which is on the heap
     // already and doesn't change its address when moved.
     command_names: HashSet<ReaperString>,
+    /// Provides a safe place in memory for screenset IDs.
+    ///
+    /// Also used for keeping track of screenset registrations so they can be unregistered
+    /// automatically on drop.
+    screenset_ids: HashSet<ReaperString>,
     /// Provides a safe place in memory for API definition string structs.
     api_defs: Vec<Vec<c_char>>,
     /// Provides a safe place in memory for each registered audio hook.
@@ -99,15 +115,23 @@ pub struct ReaperSession {
     /// Also used for keeping track of registrations so they can be unregistered automatically on
     /// drop.
     plugin_registrations: HashSet<PluginRegistration>,
+    /// Audio hook which invokes all audio hooks registered in a deterministic order.
+    ordered_audio_hook_chain: Option<RegistrationHandle<OrderedAudioHookChain>>,
+    /// Copy of the ordered audio hook chain which can be modified and then swapped in.
+    ordered_audio_hooks: OrderedAudioHookChain,
     /// Keep track of audio hook registrations so they can be unregistered automatically on drop.
     audio_hook_registrations: HashSet<Handle<raw::audio_hook_register_t>>,
     /// Keep track of playing preview registers so they can be unregistered automatically on drop.
     playing_preview_registers: HashSet<Handle<raw::preview_register_t>>,
     /// Keep track of playing track preview registers so they can be unregistered automatically on drop.
     playing_track_preview_registers: HashSet<(ProjectContext, Handle<raw::preview_register_t>)>,
+    /// Registrations handed out as [`Registration`] so they can be undone automatically on drop,
+    /// even if leaked.
+    registrations: Vec<Rc<RegistrationSlot>>,
 }
 
-// The raw pointers contained in the session don't do harm when sent to another thread.
+// The raw pointers contained in the session don't do harm when sent to another thread. The
+// registration slots are shared with registrations, which are only used in the main thread.
 unsafe impl Send for ReaperSession {}
 
 impl ReaperSession {
@@ -116,19 +140,23 @@ impl ReaperSession {
     /// [low-level `Reaper`]: https://docs.rs/reaper-low
     pub fn new(low: reaper_low::Reaper) -> ReaperSession {
         ReaperSession {
-            reaper: Reaper::new(low),
+            reaper: SharedReaper::new(Reaper::new(low)),
             gaccel_registers: Default::default(),
             accelerator_registers: Default::default(),
             file_in_project_hooks: Default::default(),
             preview_registers: Default::default(),
             command_names: Default::default(),
+            screenset_ids: Default::default(),
             api_defs: Default::default(),
             audio_hook_registers: Default::default(),
             csurf_insts: Default::default(),
             plugin_registrations: Default::default(),
+            ordered_audio_hook_chain: None,
+            ordered_audio_hooks: Default::default(),
             audio_hook_registrations: Default::default(),
             playing_preview_registers: Default::default(),
             playing_track_preview_registers: Default::default(),
+            registrations: Default::default(),
         }
     }
 
@@ -151,10 +179,19 @@ impl ReaperSession {
     /// let standalone_reaper = session.reaper().clone();
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// If you need them in many places, prefer [`shared_reaper()`](Self::shared_reaper), which
+    /// doesn't copy the function pointers.
     pub fn reaper(&self) -> &Reaper<MainThreadScope> {
         &self.reaper
     }
 
+    /// Returns a cheaply cloneable handle to all REAPER functions which can be safely executed in
+    /// the main thread.
+    pub fn shared_reaper(&self) -> SharedReaper<MainThreadScope> {
+        self.reaper.clone()
+    }
+
     /// Creates a new container of REAPER functions with only those unlocked that can be safely
     /// executed in the real-time audio thread.
     pub fn create_real_time_reaper(&self) -> Reaper<RealTimeAudioThreadScope> {
@@ -293,6 +330,65 @@ impl ReaperSession {
         }
     }
 
+    /// Registers a closure which handles the action with the given command ID.
+    ///
+    /// In contrast to [`plugin_register_add_hook_command()`], this can be called multiple times
+    /// and the closure can capture state. This makes it possible for independent parts of a
+    /// plug-in to handle their own actions. All closures are invoked by one single hook command
+    /// registered at REAPER, which dispatches by command ID.
+    ///
+    /// The closure receives the flag and must return `true` to indicate that the command has been
+    /// processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command ID has a closure-based handler already or if the
+    /// registration failed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let mut session = reaper_medium::ReaperSession::default();
+    /// let command_id = session.plugin_register_add_command_id("MY_COMMAND")?;
+    /// let subscription = session.plugin_register_add_hook_command_fn(command_id, |_flag| {
+    ///     println!("Executing my command!");
+    ///     true
+    /// })?;
+    /// session.plugin_register_remove_hook_command_fn(subscription);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`plugin_register_add_hook_command()`]: #method.plugin_register_add_hook_command
+    pub fn plugin_register_add_hook_command_fn(
+        &mut self,
+        command_id: CommandId,
+        callback: impl FnMut(i32) -> bool + 'static,
+    ) -> ReaperFunctionResult<HookCommandSubscription> {
+        let (subscription, is_first) = subscribe_hook_command(command_id, callback).ok_or(
+            ReaperFunctionError::invalid_argument("command has a handler already"),
+        )?;
+        if is_first {
+            if let Err(e) = self.plugin_register_add_hook_command::<HookCommandDispatcher>() {
+                unsubscribe_hook_command(subscription);
+                return Err(e);
+            }
+        }
+        Ok(subscription)
+    }
+
+    /// Unregisters a closure which has been registered via
+    /// [`plugin_register_add_hook_command_fn()`].
+    ///
+    /// [`plugin_register_add_hook_command_fn()`]: #method.plugin_register_add_hook_command_fn
+    pub fn plugin_register_remove_hook_command_fn(
+        &mut self,
+        subscription: HookCommandSubscription,
+    ) {
+        if unsubscribe_hook_command(subscription) {
+            self.plugin_register_remove_hook_command::<HookCommandDispatcher>();
+        }
+    }
+
     /// Registers a hook command that supports MIDI CC/mousewheel actions.
     ///
     /// See [`plugin_register_add_hook_command`](#method.plugin_register_add_hook_command) for
@@ -468,6 +564,61 @@ impl ReaperSession {
         }
     }
 
+    /// Registers a closure which is called whenever a normal action of the main section has been
+    /// performed.
+    ///
+    /// In contrast to [`plugin_register_add_hook_post_command()`], this can be called multiple
+    /// times and the closure can capture state. All closures are invoked by one single hook post
+    /// command registered at REAPER.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let mut session = reaper_medium::ReaperSession::default();
+    /// let reaper = session.reaper().clone();
+    /// let subscription = session.plugin_register_add_hook_post_command_fn(move |command_id, _| {
+    ///     reaper.show_console_msg(format!("Executed {command_id}\n"));
+    /// })?;
+    /// session.plugin_register_remove_hook_post_command_fn(subscription);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`plugin_register_add_hook_post_command()`]:
+    /// #method.plugin_register_add_hook_post_command
+    pub fn plugin_register_add_hook_post_command_fn(
+        &mut self,
+        callback: impl FnMut(CommandId, i32) + 'static,
+    ) -> ReaperFunctionResult<HookPostCommandSubscription> {
+        let (subscription, is_first) = subscribe_hook_post_command(callback);
+        if is_first {
+            if let Err(e) =
+                self.plugin_register_add_hook_post_command::<HookPostCommandDispatcher>()
+            {
+                unsubscribe_hook_post_command(subscription);
+                return Err(e);
+            }
+        }
+        Ok(subscription)
+    }
+
+    /// Unregisters a closure which has been registered via
+    /// [`plugin_register_add_hook_post_command_fn()`].
+    ///
+    /// [`plugin_register_add_hook_post_command_fn()`]:
+    /// #method.plugin_register_add_hook_post_command_fn
+    pub fn plugin_register_remove_hook_post_command_fn(
+        &mut self,
+        subscription: HookPostCommandSubscription,
+    ) {
+        if unsubscribe_hook_post_command(subscription) {
+            self.plugin_register_remove_hook_post_command::<HookPostCommandDispatcher>();
+        }
+    }
+
     /// Registers a hook post command 2.
     ///
     /// REAPER calls hook post commands 2 whenever a MIDI CC/mousewheel action has been performed.
@@ -547,9 +698,14 @@ impl ReaperSession {
 
     /// Unstable!!!
     ///
+    /// If the function should return strings of arbitrary length, declare a `NeedBig` output
+    /// buffer argument and fill it using [`Reaper::realloc_cmd_write_string()`].
+    ///
     /// # Safety
     ///
     /// You must ensure that the given function pointer is valid.
+    ///
+    /// [`Reaper::realloc_cmd_write_string()`]: crate::Reaper::realloc_cmd_write_string
     // TODO-high-unstable Better API (maybe a builder) and doc. Also because current one is prone to
     //  breaking changes.
     // TODO-low Add function for removal
@@ -597,6 +753,48 @@ impl ReaperSession {
         Ok(())
     }
 
+    /// Registers a window so that its position and visibility are saved in screensets and project
+    /// layouts.
+    ///
+    /// The given ID must uniquely identify the window. REAPER calls the [`ScreensetCallback`]
+    /// implementation with this ID whenever it needs to save or restore the window state.
+    ///
+    /// Registering the same ID again replaces the previous registration.
+    ///
+    /// [`ScreensetCallback`]: trait.ScreensetCallback.html
+    pub fn screenset_register_new<'a, T: ScreensetCallback>(
+        &mut self,
+        id: impl Into<ReaperStringArg<'a>>,
+    ) {
+        let id = id.into().into_inner().to_reaper_string();
+        unsafe {
+            self.reaper.low().screenset_registerNew(
+                id.as_ptr() as *mut c_char,
+                Some(delegating_screenset_callback::<T>),
+                null_mut(),
+            );
+        }
+        // Replace (not insert) because REAPER now refers to the new string
+        self.screenset_ids.replace(id);
+    }
+
+    /// Unregisters a window which has been registered via [`screenset_register_new()`].
+    ///
+    /// [`screenset_register_new()`]: #method.screenset_register_new
+    pub fn screenset_unregister<'a>(&mut self, id: impl Into<ReaperStringArg<'a>>) {
+        let id = id.into().into_inner().to_reaper_string();
+        unsafe {
+            self.screenset_unregister_unchecked(&id);
+        }
+        self.screenset_ids.remove(&id);
+    }
+
+    unsafe fn screenset_unregister_unchecked(&self, id: &ReaperString) {
+        self.reaper
+            .low()
+            .screenset_unregister(id.as_ptr() as *mut c_char);
+    }
+
     /// Registers a an action into the main section.
     ///
     /// This consists of a command ID, a description and a default binding for it. It doesn't
@@ -943,7 +1141,7 @@ impl ReaperSession {
         let result = if self.reaper.project_is_valid(project) {
             unsafe { self.stop_track_preview_2_unchecked(project, handle) }
         } else {
-            Err(ReaperFunctionError::new(
+            Err(ReaperFunctionError::not_found(
                 "project not valid anymore, preview stopped already",
             ))
         };
@@ -954,6 +1152,23 @@ impl ReaperSession {
         result
     }
 
+    /// Returns the register of a preview that you have played with [`play_preview_ex()`] or
+    /// [`play_track_preview_2_ex()`].
+    ///
+    /// Lock the returned mutex in order to safely read or modify the position, looping or
+    /// volume of the register while REAPER is playing it.
+    ///
+    /// Returns `None` if the preview has been stopped already.
+    ///
+    /// [`play_preview_ex()`]: #method.play_preview_ex
+    /// [`play_track_preview_2_ex()`]: #method.play_track_preview_2_ex
+    pub fn playing_preview_register(
+        &self,
+        handle: Handle<raw::preview_register_t>,
+    ) -> Option<Arc<ReaperMutex<OwnedPreviewRegister>>> {
+        self.preview_registers.get(handle).cloned()
+    }
+
     /// Unregisters an action.
     pub fn plugin_register_remove_gaccel(&mut self, handle: Handle<raw::gaccel_register_t>) {
         unsafe { self.plugin_register_remove(RegistrationObject::Gaccel(handle)) };
@@ -1007,7 +1222,7 @@ impl ReaperSession {
         let owned_hook = self
             .file_in_project_hooks
             .get(handle.key())
-            .ok_or(ReaperFunctionError::new("hook not available anymore in reaper-rs, and therefore can't be registered anymore in REAPER as well"))?;
+            .ok_or(ReaperFunctionError::not_found("hook not available anymore in reaper-rs, and therefore can't be registered anymore in REAPER as well"))?;
         let plugin_register_arg = owned_hook.create_plugin_register_arg();
         // Unregister the low-level register from REAPER
         let result = unsafe {
@@ -1023,7 +1238,7 @@ impl ReaperSession {
             ?plugin_register_arg
         );
         if result < 256 {
-            return Err(ReaperFunctionError::new(
+            return Err(ReaperFunctionError::not_found(
                 "project file combination was not registered anymore in REAPER",
             ));
         }
@@ -1315,10 +1530,269 @@ impl ReaperSession {
         let callback = unsafe { handle.restore_original() };
         Some(callback)
     }
+
+    /// Replaces the callback of an audio hook without unregistering it.
+    ///
+    /// In contrast to removing the audio hook and adding a new one, this doesn't let REAPER miss
+    /// any audio buffer, which makes it suitable for hot-reloading audio processing logic. If the
+    /// old callback is being invoked at the time of calling this, this function waits until the
+    /// invocation has finished.
+    ///
+    /// Returns a new handle (the given one becomes obsolete) and the old callback.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audio hook is not registered.
+    pub fn audio_reg_hardware_hook_replace<T>(
+        &mut self,
+        handle: RegistrationHandle<T>,
+        new_callback: Box<T>,
+    ) -> ReaperFunctionResult<(RegistrationHandle<T>, Box<T>)>
+    where
+        T: OnAudioBuffer + 'static,
+    {
+        let reaper_ptr = handle.key().cast();
+        let register = self
+            .audio_hook_registers
+            .get_mut(reaper_ptr)
+            .ok_or(ReaperFunctionError::not_found("audio hook not registered"))?;
+        let new_callback_thin_ptr: NonNull<T> = new_callback.as_ref().into();
+        let old_dyn_callback = register.replace_callback(new_callback);
+        // Restore the original type of the old callback (see audio_reg_hardware_hook_remove())
+        Box::leak(old_dyn_callback);
+        let old_callback = unsafe { handle.restore_original() };
+        let new_handle = RegistrationHandle::new(new_callback_thin_ptr, reaper_ptr.cast());
+        Ok((new_handle, old_callback))
+    }
+
+    /// Registers an audio hook which is invoked in a deterministic order relative to other audio
+    /// hooks registered via this function.
+    ///
+    /// Hooks with a lower `order` are called first. Hooks with the same `order` are called in
+    /// registration order. This makes it possible for independent parts of a plug-in (e.g.
+    /// metering and MIDI processing) to have their own audio hooks.
+    ///
+    /// All ordered audio hooks share one audio hook registration at REAPER. Adding or removing a
+    /// hook doesn't unregister it, so REAPER doesn't miss any audio buffer.
+    ///
+    /// The hook stays registered as long as the returned handle is alive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    pub fn audio_reg_hardware_hook_add_ordered<T>(
+        &mut self,
+        order: i32,
+        callback: Box<T>,
+    ) -> ReaperFunctionResult<OrderedAudioHookHandle>
+    where
+        T: OnAudioBuffer + 'static,
+    {
+        self.ordered_audio_hooks.remove_inactive();
+        let handle = self.ordered_audio_hooks.insert(order, callback);
+        if let Err(e) = self.sync_ordered_audio_hook_chain() {
+            drop(handle);
+            self.ordered_audio_hooks.remove_inactive();
+            return Err(e);
+        }
+        Ok(handle)
+    }
+
+    /// Unregisters an audio hook which has been registered via
+    /// [`audio_reg_hardware_hook_add_ordered()`] and drops its callback.
+    ///
+    /// [`audio_reg_hardware_hook_add_ordered()`]: #method.audio_reg_hardware_hook_add_ordered
+    pub fn audio_reg_hardware_hook_remove_ordered(&mut self, handle: OrderedAudioHookHandle) {
+        drop(handle);
+        self.ordered_audio_hooks.remove_inactive();
+        // Can't fail because the chain is registered already
+        let _ = self.sync_ordered_audio_hook_chain();
+    }
+
+    /// Registers the given control surface and returns a registration which unregisters it on
+    /// drop.
+    ///
+    /// In contrast to [`plugin_register_add_csurf_inst()`], the control surface can't be handed
+    /// back. Don't drop the registration from within one of the control surface's own callbacks
+    /// (see [`plugin_register_remove_csurf_inst()`] for the reason).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    ///
+    /// [`plugin_register_add_csurf_inst()`]: #method.plugin_register_add_csurf_inst
+    /// [`plugin_register_remove_csurf_inst()`]: #method.plugin_register_remove_csurf_inst
+    pub fn register_control_surface<T>(
+        &mut self,
+        control_surface: Box<T>,
+    ) -> ReaperFunctionResult<Registration>
+    where
+        T: ControlSurface + 'static,
+    {
+        let low_cs = ControlSurfaceAdapter::new(control_surface, &self.reaper.get_app_version());
+        let double_boxed_low_cs: Box<Box<dyn IReaperControlSurface>> = Box::new(Box::new(low_cs));
+        let cpp_cs =
+            unsafe { create_cpp_to_rust_control_surface(double_boxed_low_cs.as_ref().into()) };
+        let handle = unsafe {
+            self.reaper
+                .low()
+                .register_raw(c_str_macro::c_str!("csurf_inst"), cpp_cs.as_ptr() as _)
+        };
+        let registration_failed = handle.result() == 0;
+        let unregister = move || {
+            // Unregister from REAPER before removing anything from memory
+            drop(handle);
+            unsafe { delete_cpp_control_surface(cpp_cs) };
+            drop(double_boxed_low_cs);
+        };
+        if registration_failed {
+            unregister();
+            return Err(ReaperFunctionError::new(
+                "couldn't register control surface",
+            ));
+        }
+        Ok(self.keep_registration(unregister))
+    }
+
+    /// Registers the given audio hook and returns a registration which unregisters it on drop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    pub fn register_audio_hook<T>(&mut self, callback: Box<T>) -> ReaperFunctionResult<Registration>
+    where
+        T: OnAudioBuffer + 'static,
+    {
+        let register = Box::new(OwnedAudioHookRegister::new(callback));
+        let reaper_ptr: NonNull<audio_hook_register_t> = register.as_ref().as_ref().into();
+        let low = *self.reaper.low();
+        let result = unsafe { low.Audio_RegHardwareHook(true, reaper_ptr.as_ptr()) };
+        if result == 0 {
+            return Err(ReaperFunctionError::new("couldn't register audio hook"));
+        }
+        let unregister = move || {
+            // REAPER makes sure that the audio hook is not running anymore when this returns
+            unsafe { low.Audio_RegHardwareHook(false, reaper_ptr.as_ptr()) };
+            drop(register);
+        };
+        Ok(self.keep_registration(unregister))
+    }
+
+    /// Registers the given hook command and returns a registration which unregisters it on drop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    pub fn register_hook_command<T: HookCommand>(&mut self) -> ReaperFunctionResult<Registration> {
+        let handle = unsafe {
+            self.reaper.low().register_raw(
+                c_str_macro::c_str!("hookcommand"),
+                delegating_hook_command::<T> as *mut c_void,
+            )
+        };
+        if handle.result() == 0 {
+            return Err(ReaperFunctionError::new("couldn't register hook command"));
+        }
+        Ok(self.keep_registration(move || drop(handle)))
+    }
+
+    /// Registers the given key binding and returns a registration which unregisters it on drop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    pub fn register_gaccel(
+        &mut self,
+        register: OwnedGaccelRegister,
+    ) -> ReaperFunctionResult<Registration> {
+        let register = Box::new(register);
+        let raw_register: &raw::gaccel_register_t = register.as_ref().as_ref();
+        let handle = unsafe {
+            self.reaper.low().register_raw(
+                c_str_macro::c_str!("gaccel"),
+                raw_register as *const _ as *mut c_void,
+            )
+        };
+        let registration_failed = handle.result() == 0;
+        let unregister = move || {
+            drop(handle);
+            drop(register);
+        };
+        if registration_failed {
+            unregister();
+            return Err(ReaperFunctionError::new("couldn't register key binding"));
+        }
+        Ok(self.keep_registration(unregister))
+    }
+
+    /// Registers the given project config extension and returns a registration which unregisters
+    /// it on drop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    pub fn register_project_config_extension<T>(
+        &mut self,
+        extension: Box<T>,
+    ) -> ReaperFunctionResult<Registration>
+    where
+        T: ProjectConfigExtension + 'static,
+    {
+        let extension = Box::new(OwnedProjectConfigExtension::new(extension));
+        let raw_extension: &raw::project_config_extension_t = extension.as_ref().as_ref();
+        let handle = unsafe {
+            self.reaper.low().register_raw(
+                c_str_macro::c_str!("projectconfig"),
+                raw_extension as *const _ as *mut c_void,
+            )
+        };
+        let registration_failed = handle.result() == 0;
+        let unregister = move || {
+            drop(handle);
+            drop(extension);
+        };
+        if registration_failed {
+            unregister();
+            return Err(ReaperFunctionError::new(
+                "couldn't register project config extension",
+            ));
+        }
+        Ok(self.keep_registration(unregister))
+    }
+
+    /// Makes the session undo the registration on drop unless the returned registration has done
+    /// it already.
+    fn keep_registration(&mut self, unregister: impl FnOnce() + 'static) -> Registration {
+        self.registrations.retain(|slot| !slot.is_unregistered());
+        let slot = Rc::new(RegistrationSlot::new(unregister));
+        self.registrations.push(slot.clone());
+        Registration::new(slot)
+    }
+
+    /// Makes REAPER use the current ordered audio hooks, registering the chain if necessary.
+    fn sync_ordered_audio_hook_chain(&mut self) -> ReaperFunctionResult<()> {
+        let chain = Box::new(self.ordered_audio_hooks.clone());
+        let chain_handle = match self.ordered_audio_hook_chain.take() {
+            None => self.audio_reg_hardware_hook_add(chain)?,
+            Some(handle) => {
+                // The old chain is dropped after the audio thread has finished using it. This
+                // frees removed callbacks.
+                let (new_handle, _old_chain) = self
+                    .audio_reg_hardware_hook_replace(handle, chain)
+                    .expect("ordered audio hook chain was not registered");
+                new_handle
+            }
+        };
+        self.ordered_audio_hook_chain = Some(chain_handle);
+        Ok(())
+    }
 }
 
 impl Drop for ReaperSession {
     fn drop(&mut self) {
+        for slot in self.registrations.drain(..).rev() {
+            slot.unregister();
+        }
         for (project, handle) in self.playing_track_preview_registers.clone() {
             unsafe {
                 let _ = self.stop_track_preview_2_unchecked(project, handle);
@@ -1334,6 +1808,30 @@ impl Drop for ReaperSession {
                 self.audio_reg_hardware_hook_remove_unchecked(handle);
             }
         }
+        for id in self.screenset_ids.clone() {
+            unsafe {
+                self.screenset_unregister_unchecked(&id);
+            }
+        }
+        // The closure-based handlers and subscribers are global because REAPER doesn't pass any
+        // user data. If we registered the dispatcher, we are responsible for them.
+        let command_dispatcher =
+            RegistrationObject::HookCommand(delegating_hook_command::<HookCommandDispatcher>);
+        if self
+            .plugin_registrations
+            .contains(&command_dispatcher.into_raw())
+        {
+            clear_hook_command_handlers();
+        }
+        let post_command_dispatcher = RegistrationObject::HookPostCommand(
+            delegating_hook_post_command::<HookPostCommandDispatcher>,
+        );
+        if self
+            .plugin_registrations
+            .contains(&post_command_dispatcher.into_raw())
+        {
+            clear_hook_post_command_subscribers();
+        }
         for reg in self.plugin_registrations.clone() {
             unsafe {
                 self.plugin_register_remove_internal(reg);
@@ -1341,3 +1839,100 @@ impl Drop for ReaperSession {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reaper_low::StaticPluginContext;
+    use std::cell::{Cell, RefCell};
+    use std::ffi::CStr;
+    use std::os::raw::c_int;
+    use std::rc::Rc;
+
+    thread_local! {
+        static REGISTERED_KEYS: RefCell<Vec<String>> = RefCell::new(vec![]);
+    }
+
+    extern "C" fn get_app_version() -> *const c_char {
+        c_str_macro::c_str!("7.0").as_ptr()
+    }
+
+    extern "C" fn plugin_register(name: *const c_char, _infostruct: *mut c_void) -> c_int {
+        let name = unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .to_string();
+        REGISTERED_KEYS.with(|keys| keys.borrow_mut().push(name));
+        1
+    }
+
+    unsafe extern "C" fn get_func(name: *const c_char) -> *mut c_void {
+        match CStr::from_ptr(name).to_bytes() {
+            b"GetAppVersion" => get_app_version as *mut c_void,
+            b"plugin_register" => plugin_register as *mut c_void,
+            _ => null_mut(),
+        }
+    }
+
+    /// Creates a session which just records the keys passed to `plugin_register()`.
+    fn fake_session() -> ReaperSession {
+        let context =
+            PluginContext::from_function_provider(get_func, StaticPluginContext::default());
+        ReaperSession::load(context)
+    }
+
+    fn take_registered_keys() -> Vec<String> {
+        REGISTERED_KEYS.with(|keys| keys.take())
+    }
+
+    #[test]
+    fn hook_post_command_fn_after_session_drop() {
+        let calls = Rc::new(Cell::new(0));
+        let mut session = fake_session();
+        let old_calls = calls.clone();
+        session
+            .plugin_register_add_hook_post_command_fn(move |_, _| {
+                old_calls.set(old_calls.get() + 1)
+            })
+            .unwrap();
+        drop(session);
+        assert_eq!(
+            take_registered_keys(),
+            vec!["hookpostcommand", "-hookpostcommand"]
+        );
+        let mut session = fake_session();
+        let new_calls = calls.clone();
+        session
+            .plugin_register_add_hook_post_command_fn(move |_, _| {
+                new_calls.set(new_calls.get() + 10)
+            })
+            .unwrap();
+        // The dispatcher is registered again and only the new closure is invoked
+        assert_eq!(take_registered_keys(), vec!["hookpostcommand"]);
+        HookPostCommandDispatcher::call(CommandId::new(1), 0);
+        assert_eq!(calls.get(), 10);
+    }
+
+    #[test]
+    fn hook_command_fn_after_session_drop() {
+        let command_id = CommandId::new(1);
+        let mut session = fake_session();
+        let old_subscription = session
+            .plugin_register_add_hook_command_fn(command_id, |_| false)
+            .unwrap();
+        drop(session);
+        assert_eq!(take_registered_keys(), vec!["hookcommand", "-hookcommand"]);
+        let mut session = fake_session();
+        // Doesn't fail with "command has a handler already"
+        let new_subscription = session
+            .plugin_register_add_hook_command_fn(command_id, |_| true)
+            .unwrap();
+        // The dispatcher is registered again and only the new closure is invoked
+        assert_eq!(take_registered_keys(), vec!["hookcommand"]);
+        assert!(HookCommandDispatcher::call(command_id, 0));
+        // The old handle doesn't remove the new closure
+        session.plugin_register_remove_hook_command_fn(old_subscription);
+        assert!(HookCommandDispatcher::call(command_id, 0));
+        session.plugin_register_remove_hook_command_fn(new_subscription);
+        assert_eq!(take_registered_keys(), vec!["-hookcommand"]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Keeps something registered at REAPER (e.g. a control surface) for as long as it's alive.
+///
+/// Dropping it unregisters the thing again. That's important whenever the registering party can go
+/// away while REAPER keeps running, e.g. when a VST plug-in instance is removed. If the
+/// registration should rather last until the end of the session, call [`leak()`](Self::leak)
+/// explicitly.
+///
+/// The [`ReaperSession`](crate::ReaperSession) which created the registration undoes it when
+/// dropped, even if it has been leaked. Dropping the registration after that does nothing.
+///
+/// This is the medium-level counterpart of [`reaper_low::RegistrationHandle`].
+#[must_use = "dropping the registration unregisters immediately, call leak() to keep it"]
+#[derive(Debug)]
+pub struct Registration {
+    slot: Option<Rc<RegistrationSlot>>,
+}
+
+impl Registration {
+    pub(crate) fn new(slot: Rc<RegistrationSlot>) -> Self {
+        Self { slot: Some(slot) }
+    }
+
+    /// Keeps the thing registered until the session ends.
+    pub fn leak(mut self) {
+        self.slot = None;
+    }
+
+    /// Same as [`leak()`](Self::leak).
+    pub fn forget(self) {
+        self.leak();
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            slot.unregister();
+        }
+    }
+}
+
+/// Holds the function which undoes a registration.
+///
+/// Shared between the [`Registration`] and the session, so whoever comes first undoes it.
+pub(crate) struct RegistrationSlot {
+    unregister: RefCell<Option<Box<dyn FnOnce()>>>,
+}
+
+impl RegistrationSlot {
+    pub fn new(unregister: impl FnOnce() + 'static) -> Self {
+        Self {
+            unregister: RefCell::new(Some(Box::new(unregister))),
+        }
+    }
+
+    pub fn unregister(&self) {
+        // Take it out first so that the function can't observe the slot being borrowed
+        let unregister = self.unregister.borrow_mut().take();
+        if let Some(unregister) = unregister {
+            unregister();
+        }
+    }
+
+    pub fn is_unregistered(&self) -> bool {
+        self.unregister.borrow().is_none()
+    }
+}
+
+impl fmt::Debug for RegistrationSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegistrationSlot")
+            .field("is_unregistered", &self.is_unregistered())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn counting_slot(count: &Rc<Cell<u32>>) -> Rc<RegistrationSlot> {
+        let count = count.clone();
+        Rc::new(RegistrationSlot::new(move || count.set(count.get() + 1)))
+    }
+
+    #[test]
+    fn unregister_on_drop() {
+        let count = Rc::new(Cell::new(0));
+        let slot = counting_slot(&count);
+        drop(Registration::new(slot.clone()));
+        assert_eq!(count.get(), 1);
+        assert!(slot.is_unregistered());
+        // The session doesn't unregister again
+        slot.unregister();
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn leaked_registration_is_undone_by_session() {
+        let count = Rc::new(Cell::new(0));
+        let slot = counting_slot(&count);
+        Registration::new(slot.clone()).leak();
+        assert_eq!(count.get(), 0);
+        slot.unregister();
+        assert_eq!(count.get(), 1);
+    }
+}
@@ -1,8 +1,8 @@
-use crate::ReaperResample;
+use crate::{DurationInSeconds, Hz, ReaperFunctionError, ReaperFunctionResult, ReaperResample};
 use reaper_low::raw;
 use ref_cast::RefCast;
 use std::ops::{Deref, DerefMut};
-use std::ptr::NonNull;
+use std::ptr::{null_mut, NonNull};
 
 // Case 3: Internals exposed: no | vtable: yes
 // ===========================================
@@ -82,6 +82,161 @@ impl BorrowedReaperResample {
     pub fn as_ptr(&self) -> ReaperResample {
         NonNull::from(self.as_ref())
     }
+
+    /// Sets the input and output sample rate.
+    pub fn set_rates(&mut self, rate_in: Hz, rate_out: Hz) {
+        self.0.SetRates(rate_in.get(), rate_out.get());
+    }
+
+    /// Resets the internal state, e.g. when starting to process a new stream.
+    pub fn reset(&mut self) {
+        self.0.Reset();
+    }
+
+    /// Returns the latency of the samples which are currently buffered.
+    ///
+    /// Don't call this before having resampled anything.
+    pub fn get_current_latency(&mut self) -> DurationInSeconds {
+        DurationInSeconds::new_panic(self.0.GetCurrentLatency())
+    }
+
+    /// Returns a buffer which must be filled with interleaved input samples in order to produce
+    /// the given number of output frames.
+    ///
+    /// The returned input has room for exactly the number of input frames which the resampler
+    /// wants (times `channel_count`). Fill it, then call [`ResampleInput::resample_out()`]. The
+    /// input remembers the frame and channel counts, so they can't get out of sync.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `channel_count` is 0.
+    pub fn resample_prepare(
+        &mut self,
+        out_frame_count: u32,
+        channel_count: u32,
+    ) -> ReaperFunctionResult<ResampleInput<'_>> {
+        if channel_count == 0 {
+            return Err(ReaperFunctionError::invalid_argument(
+                "channel count must be greater than 0",
+            ));
+        }
+        let mut in_buffer = null_mut();
+        let in_frame_count = unsafe {
+            self.0
+                .ResamplePrepare(out_frame_count as i32, channel_count as i32, &mut in_buffer)
+        };
+        let in_frame_count = if in_buffer.is_null() {
+            0
+        } else {
+            in_frame_count.max(0) as u32
+        };
+        let input = ResampleInput {
+            resample: self,
+            buffer: in_buffer,
+            in_frame_count,
+            out_frame_count,
+            channel_count,
+        };
+        Ok(input)
+    }
+
+    /// Convenience function which combines [`resample_prepare()`] and
+    /// [`ResampleInput::resample_out()`].
+    ///
+    /// `fill_input` is called with the input buffer and must return the number of frames written.
+    /// The number of output frames is derived from the size of `out`.
+    ///
+    /// Returns the number of output frames written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `channel_count` is 0 or if `fill_input` returns more frames than fit
+    /// into the buffer.
+    ///
+    /// [`resample_prepare()`]: #method.resample_prepare
+    pub fn resample(
+        &mut self,
+        out: &mut [f64],
+        channel_count: u32,
+        fill_input: impl FnOnce(&mut [f64]) -> u32,
+    ) -> ReaperFunctionResult<u32> {
+        let out_frame_count = (out.len() / channel_count.max(1) as usize) as u32;
+        let mut input = self.resample_prepare(out_frame_count, channel_count)?;
+        let in_frame_count = fill_input(input.buffer());
+        input.resample_out(out, in_frame_count)
+    }
+
+    /// Flushes the remaining buffered samples into `out` (interleaved) at the end of a stream.
+    ///
+    /// Returns the number of output frames written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `channel_count` is 0.
+    pub fn flush(&mut self, out: &mut [f64], channel_count: u32) -> ReaperFunctionResult<u32> {
+        self.resample(out, channel_count, |_| 0)
+    }
+}
+
+/// Input buffer of a resampler, returned by [`BorrowedReaperResample::resample_prepare()`].
+#[derive(Debug)]
+pub struct ResampleInput<'a> {
+    resample: &'a mut BorrowedReaperResample,
+    buffer: *mut f64,
+    in_frame_count: u32,
+    out_frame_count: u32,
+    channel_count: u32,
+}
+
+impl ResampleInput<'_> {
+    /// Returns the buffer which must be filled with interleaved input samples.
+    pub fn buffer(&mut self) -> &mut [f64] {
+        if self.buffer.is_null() {
+            return &mut [];
+        }
+        let len = self.in_frame_count as usize * self.channel_count as usize;
+        unsafe { std::slice::from_raw_parts_mut(self.buffer, len) }
+    }
+
+    /// Returns the number of input frames which the resampler wants.
+    pub fn frame_count(&self) -> u32 {
+        self.in_frame_count
+    }
+
+    /// Resamples the input samples which have been written into the buffer and writes the result
+    /// into `out` (interleaved).
+    ///
+    /// `in_frame_count` is the number of input frames which have actually been written. Pass
+    /// fewer frames than requested (e.g. 0) at the end of the stream in order to flush the
+    /// remaining buffered samples.
+    ///
+    /// Returns the number of output frames written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `in_frame_count` is greater than the number of frames which fit into
+    /// the input buffer or if `out` is too small to hold the requested number of output frames.
+    pub fn resample_out(self, out: &mut [f64], in_frame_count: u32) -> ReaperFunctionResult<u32> {
+        if in_frame_count > self.in_frame_count {
+            return Err(ReaperFunctionError::invalid_argument(
+                "more input frames than requested by resampler",
+            ));
+        }
+        if out.len() < self.out_frame_count as usize * self.channel_count as usize {
+            return Err(ReaperFunctionError::invalid_argument(
+                "output buffer too small",
+            ));
+        }
+        let written = unsafe {
+            self.resample.0.ResampleOut(
+                out.as_mut_ptr(),
+                in_frame_count as i32,
+                self.out_frame_count as i32,
+                self.channel_count as i32,
+            )
+        };
+        Ok(written.max(0) as u32)
+    }
 }
 
 impl AsRef<raw::REAPER_Resample_Interface> for BorrowedReaperResample {
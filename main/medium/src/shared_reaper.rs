@@ -0,0 +1,54 @@
+use crate::{MainThreadScope, Reaper};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A cheaply cloneable handle to a [`Reaper`] instance.
+///
+/// Cloning a [`Reaper`] copies all of its function pointers (about 7 kB on a 64-bit system).
+/// Cloning a `SharedReaper` just increments a reference count, which makes it the better choice if
+/// you need to pass REAPER access into many closures or other long-living objects.
+///
+/// It dereferences to [`Reaper`], so all functions are available directly.
+///
+/// # Example
+///
+/// ```no_run
+/// # let session = reaper_medium::ReaperSession::default();
+/// let reaper = session.shared_reaper();
+/// let callback = move || reaper.show_console_msg("Hello");
+/// # callback();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SharedReaper<UsageScope = MainThreadScope>(Arc<Reaper<UsageScope>>);
+
+impl<UsageScope> SharedReaper<UsageScope> {
+    /// Moves the given instance into a shared handle.
+    pub fn new(reaper: Reaper<UsageScope>) -> Self {
+        Self(Arc::new(reaper))
+    }
+
+    /// Returns whether both handles point to the same instance.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<UsageScope> Deref for SharedReaper<UsageScope> {
+    type Target = Reaper<UsageScope>;
+
+    fn deref(&self) -> &Reaper<UsageScope> {
+        &self.0
+    }
+}
+
+impl<UsageScope> AsRef<Reaper<UsageScope>> for SharedReaper<UsageScope> {
+    fn as_ref(&self) -> &Reaper<UsageScope> {
+        &self.0
+    }
+}
+
+impl<UsageScope> From<Reaper<UsageScope>> for SharedReaper<UsageScope> {
+    fn from(reaper: Reaper<UsageScope>) -> Self {
+        Self::new(reaper)
+    }
+}
@@ -0,0 +1,31 @@
+use std::marker::PhantomData;
+
+/// Prevents REAPER from refreshing its UI as long as it's alive (RAII).
+///
+/// Created via [`Reaper::prevent_ui_refresh()`]. Guards can be nested. UI refresh is allowed again
+/// as soon as all guards are dropped, even if the current thread panics in-between.
+///
+/// [`Reaper::prevent_ui_refresh()`]: crate::Reaper::prevent_ui_refresh
+#[must_use = "UI refresh is allowed again as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct PreventUiRefreshGuard<'a> {
+    low: &'a reaper_low::Reaper,
+    // Must be dropped in the main thread.
+    _p: PhantomData<*const ()>,
+}
+
+impl<'a> PreventUiRefreshGuard<'a> {
+    pub(crate) fn new(low: &'a reaper_low::Reaper) -> Self {
+        low.PreventUIRefresh(1);
+        Self {
+            low,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl Drop for PreventUiRefreshGuard<'_> {
+    fn drop(&mut self) {
+        self.low.PreventUIRefresh(-1);
+    }
+}
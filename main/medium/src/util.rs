@@ -56,6 +56,88 @@ fn with_string_buffer_internal<T>(
     (cstring, result)
 }
 
+/// Size of the stack buffer with which [`with_auto_sized_string_buffer()`] makes its first attempt.
+const AUTO_SIZED_BUFFER_INITIAL_SIZE: usize = 256;
+
+/// Size at which [`with_auto_sized_string_buffer()`] stops growing the buffer.
+const AUTO_SIZED_BUFFER_MAX_SIZE: usize = 1024 * 1024;
+
+/// Like [`with_string_buffer()`] but doesn't require the caller to choose a buffer size.
+///
+/// Tries with a small stack buffer first. If the result looks truncated, it tries again with a
+/// heap buffer of double the size and so on, up to a fixed maximum size. That's why the closure
+/// must be callable multiple times.
+pub fn with_auto_sized_string_buffer<T>(
+    fill_buffer: impl FnMut(*mut c_char, i32) -> T,
+) -> (ReaperString, T) {
+    let (cstring, result) = with_auto_sized_string_buffer_cstring(fill_buffer);
+    (ReaperString::new(cstring), result)
+}
+
+/// Like [`with_auto_sized_string_buffer()`] but returns a [`CString`].
+pub fn with_auto_sized_string_buffer_cstring<T>(
+    fill_buffer: impl FnMut(*mut c_char, i32) -> T,
+) -> (CString, T) {
+    let (cstring, result, _) =
+        with_auto_sized_string_buffer_internal(AUTO_SIZED_BUFFER_MAX_SIZE, fill_buffer);
+    (cstring, result)
+}
+
+/// Like [`with_auto_sized_string_buffer()`] but with a custom maximum size and returning `None`
+/// instead of a truncated string if the result doesn't fit into a buffer of that size.
+pub fn with_checked_auto_sized_string_buffer<T>(
+    max_size: usize,
+    fill_buffer: impl FnMut(*mut c_char, i32) -> T,
+) -> Option<(ReaperString, T)> {
+    let (cstring, result, truncated) =
+        with_auto_sized_string_buffer_internal(max_size, fill_buffer);
+    if truncated {
+        return None;
+    }
+    Some((ReaperString::new(cstring), result))
+}
+
+fn with_auto_sized_string_buffer_internal<T>(
+    max_size: usize,
+    mut fill_buffer: impl FnMut(*mut c_char, i32) -> T,
+) -> (CString, T, bool) {
+    let mut stack_buffer = [0u8; AUTO_SIZED_BUFFER_INITIAL_SIZE];
+    let result = fill_buffer(
+        stack_buffer.as_mut_ptr() as *mut c_char,
+        AUTO_SIZED_BUFFER_INITIAL_SIZE as i32,
+    );
+    if !is_probably_truncated(&stack_buffer) {
+        return (cstring_from_buffer(&stack_buffer), result, false);
+    }
+    let mut size = AUTO_SIZED_BUFFER_INITIAL_SIZE * 2;
+    loop {
+        let mut heap_buffer: Vec<u8> = vec![0; size];
+        let result = fill_buffer(heap_buffer.as_mut_ptr() as *mut c_char, size as i32);
+        let truncated = is_probably_truncated(&heap_buffer);
+        if size >= max_size || !truncated {
+            return (cstring_from_buffer(&heap_buffer), result, truncated);
+        }
+        size *= 2;
+    }
+}
+
+/// Returns whether the string in the given buffer might have been truncated.
+///
+/// REAPER doesn't tell us if it truncated a string. If the string reaches the end of the buffer
+/// (allowing for a few bytes which might have been dropped in order to not cut a UTF-8 sequence in
+/// half), we assume it was truncated. Sometimes this causes an unnecessary retry but that's okay.
+fn is_probably_truncated(buffer: &[u8]) -> bool {
+    match buffer.iter().position(|b| *b == 0) {
+        None => true,
+        Some(nul_index) => nul_index + 4 >= buffer.len(),
+    }
+}
+
+fn cstring_from_buffer(buffer: &[u8]) -> CString {
+    let nul_index = buffer.iter().position(|b| *b == 0).unwrap_or(buffer.len());
+    CString::new(&buffer[..nul_index]).expect("impossible")
+}
+
 pub fn with_buffer<T>(
     max_size: u32,
     fill_buffer: impl FnOnce(*mut c_char, i32) -> T,
@@ -78,3 +160,53 @@ pub fn decode_user_data<'a, U>(data: *mut c_void) -> &'a mut U {
     let data = data as *mut U;
     unsafe { &mut *data }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_sized_string_buffer_grows_until_string_fits() {
+        let text = "x".repeat(1000);
+        let mut attempts = 0;
+        let (result, _) = with_auto_sized_string_buffer_cstring(|buffer, max_size| {
+            attempts += 1;
+            // Simulate REAPER's truncating copy.
+            let len = text.len().min(max_size as usize - 1);
+            unsafe {
+                std::ptr::copy_nonoverlapping(text.as_ptr(), buffer as *mut u8, len);
+                *buffer.add(len) = 0;
+            }
+        });
+        assert_eq!(result.to_str().unwrap(), text);
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn auto_sized_string_buffer_uses_stack_buffer_for_short_strings() {
+        let mut attempts = 0;
+        let (result, _) = with_auto_sized_string_buffer_cstring(|buffer, _| {
+            attempts += 1;
+            unsafe {
+                std::ptr::copy_nonoverlapping(b"ReaEQ\0".as_ptr(), buffer as *mut u8, 6);
+            }
+        });
+        assert_eq!(result.to_str().unwrap(), "ReaEQ");
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn checked_auto_sized_string_buffer_rejects_truncated_strings() {
+        let text = "x".repeat(5000);
+        let fill = |buffer: *mut c_char, max_size: i32| {
+            let len = text.len().min(max_size as usize - 1);
+            unsafe {
+                std::ptr::copy_nonoverlapping(text.as_ptr(), buffer as *mut u8, len);
+                *buffer.add(len) = 0;
+            }
+        };
+        assert!(with_checked_auto_sized_string_buffer(4096, fill).is_none());
+        let (result, _) = with_checked_auto_sized_string_buffer(8192, fill).unwrap();
+        assert_eq!(result.to_str(), text);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    MainThreadOnly, MediaTrack, ProjectContext, ReaProject, Reaper, ReaperFunctionError,
+    ReaperFunctionResult, ReaperStr, ReaperStringArg, SoloMode, TrackAttributeKey,
+    TrackSendCategory, VolumeAndPan,
+};
+use reaper_low::raw::GUID;
+use std::cell::Cell;
+
+/// Describes which track should be looked up by [`Reaper::with_valid_track()`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TrackLookup<'a> {
+    /// A track pointer which might not be valid anymore.
+    Ptr(MediaTrack),
+    /// The GUID of a track (which might not exist).
+    Guid(&'a GUID),
+}
+
+impl From<MediaTrack> for TrackLookup<'_> {
+    fn from(track: MediaTrack) -> Self {
+        TrackLookup::Ptr(track)
+    }
+}
+
+impl<'a> From<&'a GUID> for TrackLookup<'a> {
+    fn from(guid: &'a GUID) -> Self {
+        TrackLookup::Guid(guid)
+    }
+}
+
+impl<UsageScope> Reaper<UsageScope> {
+    /// Looks up the given track in the given project, validates it and passes it to the given
+    /// function as [`ValidTrack`].
+    ///
+    /// This lets you call many track functions without any `unsafe` block. The track pointer is
+    /// validated again only if the project state has changed in the meantime, so this is also
+    /// cheaper than validating the pointer before each call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the track doesn't exist (anymore).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use reaper_medium::ProjectContext::CurrentProject;
+    /// let reaper = session.reaper();
+    /// let track = reaper.get_track(CurrentProject, 0).ok_or("no track")?;
+    /// let fx_count = reaper.with_valid_track(CurrentProject, track, |t| t.track_fx_get_count())?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_valid_track<'a, R>(
+        &self,
+        project: ProjectContext,
+        track: impl Into<TrackLookup<'a>>,
+        f: impl FnOnce(ValidTrack<'_, UsageScope>) -> R,
+    ) -> ReaperFunctionResult<R>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let track = match track.into() {
+            TrackLookup::Ptr(track) => {
+                if !self.validate_ptr_2(project, track) {
+                    return Err(ReaperFunctionError::not_found("track doesn't exist"));
+                }
+                track
+            }
+            TrackLookup::Guid(guid) => self
+                .find_track_by_guid(project, guid)
+                .ok_or(ReaperFunctionError::not_found("track doesn't exist"))?,
+        };
+        // Remember the actual project, not just the context, because the current project might
+        // change while the token exists.
+        let project = unsafe { self.get_set_media_track_info_get_project(track) }.ok_or(
+            ReaperFunctionError::not_found("track doesn't belong to a project"),
+        )?;
+        let valid_track = ValidTrack {
+            reaper: self,
+            project,
+            track,
+            state_change_count: Cell::new(
+                self.get_project_state_change_count(ProjectContext::Proj(project)),
+            ),
+        };
+        Ok(f(valid_track))
+    }
+
+    fn find_track_by_guid(&self, project: ProjectContext, guid: &GUID) -> Option<MediaTrack>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let master_track = self.get_master_track(project);
+        let normal_tracks =
+            (0..self.count_tracks(project)).filter_map(|i| self.get_track(project, i));
+        std::iter::once(master_track)
+            .chain(normal_tracks)
+            .find(|t| unsafe { self.get_set_media_track_info_get_guid(*t) } == *guid)
+    }
+}
+
+/// A track which has been validated by [`Reaper::with_valid_track()`].
+///
+/// Its methods are safe variants of the track functions in [`Reaper`]. Before each call, they
+/// compare the project state change count with the one of the last validation and validate the
+/// track pointer again if it differs (removing a track always changes the project state).
+///
+/// # Panics
+///
+/// The methods panic if the track or its project doesn't exist anymore, e.g. because the track
+/// has been removed within [`Reaper::with_valid_track()`].
+#[derive(Debug)]
+pub struct ValidTrack<'a, UsageScope> {
+    reaper: &'a Reaper<UsageScope>,
+    project: ReaProject,
+    track: MediaTrack,
+    /// Project state change count at the time of the last validation.
+    state_change_count: Cell<u32>,
+}
+
+impl<UsageScope> Clone for ValidTrack<'_, UsageScope> {
+    fn clone(&self) -> Self {
+        Self {
+            reaper: self.reaper,
+            project: self.project,
+            track: self.track,
+            state_change_count: self.state_change_count.clone(),
+        }
+    }
+}
+
+impl<UsageScope: MainThreadOnly> ValidTrack<'_, UsageScope> {
+    /// Returns the raw track pointer.
+    pub fn raw(&self) -> MediaTrack {
+        self.track
+    }
+
+    /// Returns the track pointer after making sure that it's still valid.
+    fn track(&self) -> MediaTrack {
+        let project = ProjectContext::Proj(self.project);
+        let state_change_count = self.reaper.get_project_state_change_count(project);
+        if state_change_count != self.state_change_count.get() {
+            assert!(
+                self.reaper.validate_ptr_2(project, self.track),
+                "track doesn't exist anymore"
+            );
+            self.state_change_count.set(state_change_count);
+        }
+        self.track
+    }
+
+    /// See [`Reaper::get_set_media_track_info_get_name()`].
+    pub fn get_set_media_track_info_get_name<R>(
+        &self,
+        use_name: impl FnOnce(&ReaperStr) -> R,
+    ) -> Option<R> {
+        unsafe {
+            self.reaper
+                .get_set_media_track_info_get_name(self.track(), use_name)
+        }
+    }
+
+    /// See [`Reaper::get_set_media_track_info_set_name()`].
+    pub fn get_set_media_track_info_set_name<'a>(&self, name: impl Into<ReaperStringArg<'a>>) {
+        unsafe {
+            self.reaper
+                .get_set_media_track_info_set_name(self.track(), name)
+        }
+    }
+
+    /// See [`Reaper::get_set_media_track_info_get_guid()`].
+    pub fn get_set_media_track_info_get_guid(&self) -> GUID {
+        unsafe { self.reaper.get_set_media_track_info_get_guid(self.track()) }
+    }
+
+    /// See [`Reaper::get_set_media_track_info_get_solo()`].
+    pub fn get_set_media_track_info_get_solo(&self) -> SoloMode {
+        unsafe { self.reaper.get_set_media_track_info_get_solo(self.track()) }
+    }
+
+    /// See [`Reaper::get_media_track_info_value()`].
+    pub fn get_media_track_info_value(&self, attribute_key: TrackAttributeKey) -> f64 {
+        unsafe {
+            self.reaper
+                .get_media_track_info_value(self.track(), attribute_key)
+        }
+    }
+
+    /// See [`Reaper::set_media_track_info_value()`].
+    pub fn set_media_track_info_value(
+        &self,
+        attribute_key: TrackAttributeKey,
+        new_value: f64,
+    ) -> ReaperFunctionResult<()> {
+        unsafe {
+            self.reaper
+                .set_media_track_info_value(self.track(), attribute_key, new_value)
+        }
+    }
+
+    /// See [`Reaper::get_track_ui_vol_pan()`].
+    pub fn get_track_ui_vol_pan(&self) -> ReaperFunctionResult<VolumeAndPan> {
+        unsafe { self.reaper.get_track_ui_vol_pan(self.track()) }
+    }
+
+    /// See [`Reaper::get_track_ui_mute()`].
+    pub fn get_track_ui_mute(&self) -> ReaperFunctionResult<bool> {
+        unsafe { self.reaper.get_track_ui_mute(self.track()) }
+    }
+
+    /// See [`Reaper::set_track_selected()`].
+    pub fn set_track_selected(&self, is_selected: bool) {
+        unsafe { self.reaper.set_track_selected(self.track(), is_selected) }
+    }
+
+    /// See [`Reaper::track_fx_get_count()`].
+    pub fn track_fx_get_count(&self) -> u32 {
+        unsafe { self.reaper.track_fx_get_count(self.track()) }
+    }
+
+    /// See [`Reaper::get_track_num_sends()`].
+    pub fn get_track_num_sends(&self, category: TrackSendCategory) -> u32 {
+        unsafe { self.reaper.get_track_num_sends(self.track(), category) }
+    }
+
+    /// See [`Reaper::count_track_media_items()`].
+    pub fn count_track_media_items(&self) -> u32 {
+        unsafe { self.reaper.count_track_media_items(self.track()) }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Item;
+use futures::io::{AsyncBufRead, AsyncBufReadExt};
+
+/// This is the async variant of [`StreamingParser`](crate::StreamingParser).
+///
+/// Reads from a [`futures::io::AsyncBufRead`] source, so it doesn't block while waiting for
+/// input. Tokio readers can be used via the compatibility layer of `tokio-util`.
+///
+/// Pros and cons are the same as with the blocking variant.
+pub struct AsyncStreamingParser<S> {
+    source: S,
+    buffer: String,
+}
+
+impl<S> AsyncStreamingParser<S> {
+    /// Creates the async streaming parser.
+    pub fn new(source: S, initial_capacity: usize) -> Self {
+        Self {
+            source,
+            buffer: String::with_capacity(initial_capacity),
+        }
+    }
+}
+
+impl<S: AsyncBufRead + Unpin> AsyncStreamingParser<S> {
+    /// Repeatedly call `next` to read the events.
+    pub async fn next(&mut self) -> Option<Item<'_>> {
+        self.buffer.clear();
+        if self.source.read_line(&mut self.buffer).await.ok()? == 0 {
+            return None;
+        }
+        Some(Item::parse_from_line(&self.buffer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Element;
+    use futures::executor::block_on;
+    use futures::io::Cursor;
+
+    #[test]
+    fn async_streaming_basics() {
+        let text = include_str!("examples/fx-chain-tag.rpp");
+        let mut events = AsyncStreamingParser::new(Cursor::new(text.as_bytes()), 1000);
+        block_on(async {
+            let Some(Item::StartTag(Element("FXCHAIN", mut values))) = events.next().await else {
+                panic!();
+            };
+            assert_eq!(values.next(), None);
+        });
+    }
+}
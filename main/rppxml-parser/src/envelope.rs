@@ -0,0 +1,171 @@
+use crate::{Attribute, DecodeError};
+use std::fmt::{Display, Formatter};
+
+/// An envelope point as contained in a `PT` line of an envelope chunk.
+///
+/// Format: `PT position value shape [time_signature selected unknown tension]`. The optional
+/// fields are only written if necessary.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct EnvelopePointLine {
+    /// Position in seconds.
+    pub position: f64,
+    /// Value in the envelope's native scaling.
+    pub value: f64,
+    pub shape: EnvelopePointLineShape,
+    /// Encoded time signature (only used in tempo envelopes), 0 if none.
+    pub time_signature: i32,
+    pub is_selected: bool,
+    /// Field with unknown meaning, preserved as it is.
+    pub unknown: i32,
+    /// Bezier tension between -1.0 and 1.0 (only relevant for the bezier shape).
+    pub tension: f64,
+}
+
+/// Shape of the curve between an envelope point and the next one.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum EnvelopePointLineShape {
+    #[default]
+    Linear,
+    Square,
+    SlowStartEnd,
+    FastStart,
+    FastEnd,
+    Bezier,
+    Unknown(i32),
+}
+
+impl EnvelopePointLineShape {
+    pub fn from_raw(v: i32) -> Self {
+        use EnvelopePointLineShape::*;
+        match v {
+            0 => Linear,
+            1 => Square,
+            2 => SlowStartEnd,
+            3 => FastStart,
+            4 => FastEnd,
+            5 => Bezier,
+            x => Unknown(x),
+        }
+    }
+
+    pub fn to_raw(self) -> i32 {
+        use EnvelopePointLineShape::*;
+        match self {
+            Linear => 0,
+            Square => 1,
+            SlowStartEnd => 2,
+            FastStart => 3,
+            FastEnd => 4,
+            Bezier => 5,
+            Unknown(x) => x,
+        }
+    }
+}
+
+impl EnvelopePointLine {
+    /// Parses a line such as `PT 1.5 0.25 0`.
+    pub fn parse(line: &str) -> Result<Self, DecodeError> {
+        let mut fields = line.split_whitespace();
+        if fields.next() != Some("PT") {
+            return Err(DecodeError::new("not an envelope point line"));
+        }
+        Self::from_values(fields)
+    }
+
+    /// Creates the point from the values of a `PT` attribute.
+    pub fn from_attribute(attribute: &Attribute) -> Result<Self, DecodeError> {
+        if attribute.name != "PT" {
+            return Err(DecodeError::new("not an envelope point attribute"));
+        }
+        Self::from_values(attribute.values.iter().map(String::as_str))
+    }
+
+    /// Converts this point into a `PT` attribute.
+    pub fn to_attribute(&self) -> Attribute {
+        Attribute {
+            name: "PT".to_string(),
+            values: self.values(),
+        }
+    }
+
+    fn from_values<'a>(mut values: impl Iterator<Item = &'a str>) -> Result<Self, DecodeError> {
+        let position = parse_field(values.next(), "invalid envelope point position")?;
+        let value = parse_field(values.next(), "invalid envelope point value")?;
+        let mut optional = |error_message| match values.next() {
+            None => Ok(None),
+            v => parse_field(v, error_message).map(Some),
+        };
+        let shape = optional("invalid envelope point shape")?.unwrap_or(0);
+        let time_signature = optional("invalid envelope point time signature")?.unwrap_or(0);
+        let selected: i32 = optional("invalid envelope point selected flag")?.unwrap_or(0);
+        let unknown = optional("invalid envelope point unknown field")?.unwrap_or(0);
+        let tension = match values.next() {
+            None => 0.0,
+            v => parse_field(v, "invalid envelope point tension")?,
+        };
+        Ok(Self {
+            position,
+            value,
+            shape: EnvelopePointLineShape::from_raw(shape),
+            time_signature,
+            is_selected: selected != 0,
+            unknown,
+            tension,
+        })
+    }
+
+    fn values(&self) -> Vec<String> {
+        let mut values = vec![
+            self.position.to_string(),
+            self.value.to_string(),
+            self.shape.to_raw().to_string(),
+        ];
+        if self.time_signature != 0 || self.is_selected || self.unknown != 0 || self.tension != 0.0
+        {
+            values.push(self.time_signature.to_string());
+            values.push(i32::from(self.is_selected).to_string());
+            values.push(self.unknown.to_string());
+            if self.tension != 0.0 {
+                values.push(self.tension.to_string());
+            }
+        }
+        values
+    }
+}
+
+/// Formats the point as `PT` line (without indentation).
+impl Display for EnvelopePointLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PT {}", self.values().join(" "))
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(
+    value: Option<&str>,
+    error_message: &'static str,
+) -> Result<T, DecodeError> {
+    let value = value.ok_or(DecodeError::new(error_message))?;
+    value.parse().map_err(|_| DecodeError::new(error_message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope_point_lines() {
+        let point = EnvelopePointLine::parse("PT 2.5 0.75 5 0 1 0 -0.3").unwrap();
+        assert_eq!(point.position, 2.5);
+        assert_eq!(point.shape, EnvelopePointLineShape::Bezier);
+        assert!(point.is_selected);
+        assert_eq!(point.tension, -0.3);
+        assert_eq!(point.to_string(), "PT 2.5 0.75 5 0 1 0 -0.3");
+        let simple = EnvelopePointLine::parse("PT 0 1 0").unwrap();
+        assert_eq!(simple.to_string(), "PT 0 1 0");
+        assert_eq!(
+            EnvelopePointLine::from_attribute(&simple.to_attribute()).unwrap(),
+            simple
+        );
+        assert!(EnvelopePointLine::parse("PT 0").is_err());
+    }
+}
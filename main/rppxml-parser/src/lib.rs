@@ -2,6 +2,34 @@ use splitty::SplitUnquotedChar;
 use std::fmt::{Debug, Formatter};
 use std::io::BufRead;
 
+#[cfg(feature = "async")]
+mod async_streaming;
+#[cfg(feature = "async")]
+pub use async_streaming::*;
+
+mod envelope;
+pub use envelope::*;
+
+mod midi_source;
+pub use midi_source::*;
+
+mod patch;
+pub use patch::*;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "serde")]
+pub use serde_support::*;
+
+mod tree;
+pub use tree::*;
+
+mod validation;
+pub use validation::*;
+
+mod vst;
+pub use vst::*;
+
 /// This is a streaming pull parser.
 ///
 /// Pros:
@@ -0,0 +1,183 @@
+use crate::{decode_base64_blobs, encode_base64_blobs, DecodeError};
+
+/// A MIDI event as contained in a `<SOURCE MIDI` chunk.
+///
+/// Short events are written as `E` lines (e.g. `E 480 90 3c 60`), long events such as SysEx or
+/// text events as `<X` tags containing base64-encoded data. A lowercase letter marks the event as
+/// selected, a trailing `m` as muted.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MidiSourceEvent {
+    /// Distance to the previous event in ticks (PPQ).
+    pub delta: u64,
+    /// Status byte followed by the data bytes.
+    pub message: Vec<u8>,
+    pub is_selected: bool,
+    pub is_muted: bool,
+    /// Fields following the message of short events (e.g. the CC shape) or following the delta of
+    /// long events, preserved as they are.
+    pub extra: Vec<String>,
+}
+
+impl MidiSourceEvent {
+    pub fn status(&self) -> Option<u8> {
+        self.message.first().copied()
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.message.get(1..).unwrap_or_default()
+    }
+
+    /// Returns whether this event is written as `<X` tag instead of an `E` line.
+    pub fn is_long(&self) -> bool {
+        self.message.len() > 3 || self.status().map_or(true, |s| s >= 0xf0)
+    }
+}
+
+/// Decodes the MIDI events from the lines of a `<SOURCE MIDI` chunk.
+///
+/// Lines which don't represent events (e.g. `HASDATA` or `CCINTERP`) are skipped. Pass the raw
+/// lines (e.g. via `str::lines()`) because the order of events matters.
+pub fn decode_midi_source_events<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<MidiSourceEvent>, DecodeError> {
+    let mut events = vec![];
+    let mut lines = lines.into_iter().map(str::trim);
+    while let Some(line) = lines.next() {
+        if let Some(remainder) = line.strip_prefix('<') {
+            let Some((flags, fields)) = parse_event_start(remainder, 'X') else {
+                continue;
+            };
+            let (delta, extra) = parse_delta(fields)?;
+            let data_lines = lines.by_ref().take_while(|l| !l.starts_with('>'));
+            let message = decode_base64_blobs(data_lines)?.concat();
+            events.push(MidiSourceEvent {
+                delta,
+                message,
+                is_selected: flags.is_selected,
+                is_muted: flags.is_muted,
+                extra,
+            });
+        } else if let Some((flags, fields)) = parse_event_start(line, 'E') {
+            let (delta, mut rest) = parse_delta(fields)?;
+            if rest.len() < 3 {
+                return Err(DecodeError::new("MIDI event line has not enough bytes"));
+            }
+            let extra = rest.split_off(3);
+            let message = rest
+                .iter()
+                .map(|b| u8::from_str_radix(b, 16))
+                .collect::<Result<_, _>>()
+                .map_err(|_| DecodeError::new("MIDI event line contains invalid byte"))?;
+            events.push(MidiSourceEvent {
+                delta,
+                message,
+                is_selected: flags.is_selected,
+                is_muted: flags.is_muted,
+                extra,
+            });
+        }
+    }
+    Ok(events)
+}
+
+/// Encodes the given MIDI events into lines for a `<SOURCE MIDI` chunk (without indentation).
+///
+/// This is the inverse of [`decode_midi_source_events`].
+pub fn encode_midi_source_events<'a>(
+    events: impl IntoIterator<Item = &'a MidiSourceEvent>,
+) -> Vec<String> {
+    let mut lines = vec![];
+    for event in events {
+        let letter = match (event.is_long(), event.is_selected) {
+            (false, false) => "E",
+            (false, true) => "e",
+            (true, false) => "X",
+            (true, true) => "x",
+        };
+        let muted = if event.is_muted { "m" } else { "" };
+        let mut line = format!("{letter}{muted} {}", event.delta);
+        if event.is_long() {
+            for field in &event.extra {
+                line.push(' ');
+                line.push_str(field);
+            }
+            lines.push(format!("<{line}"));
+            lines.extend(encode_base64_blobs([&event.message]));
+            lines.push(">".to_string());
+        } else {
+            for byte in &event.message {
+                line.push_str(&format!(" {byte:02x}"));
+            }
+            for field in &event.extra {
+                line.push(' ');
+                line.push_str(field);
+            }
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+struct EventFlags {
+    is_selected: bool,
+    is_muted: bool,
+}
+
+/// Parses the event letter (e.g. `E`, `e`, `Em` or `em`) and returns the remaining fields.
+fn parse_event_start(line: &str, letter: char) -> Option<(EventFlags, Vec<&str>)> {
+    let mut fields = line.split_whitespace();
+    let marker = fields.next()?;
+    let mut chars = marker.chars();
+    let first = chars.next()?;
+    let is_selected = if first == letter {
+        false
+    } else if first == letter.to_ascii_lowercase() {
+        true
+    } else {
+        return None;
+    };
+    let is_muted = match chars.as_str() {
+        "" => false,
+        "m" => true,
+        _ => return None,
+    };
+    let flags = EventFlags {
+        is_selected,
+        is_muted,
+    };
+    Some((flags, fields.collect()))
+}
+
+fn parse_delta(fields: Vec<&str>) -> Result<(u64, Vec<String>), DecodeError> {
+    let (delta, rest) = fields
+        .split_first()
+        .ok_or_else(|| DecodeError::new("MIDI event without delta"))?;
+    let delta = delta
+        .parse()
+        .map_err(|_| DecodeError::new("MIDI event has invalid delta"))?;
+    Ok((delta, rest.iter().map(|f| f.to_string()).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn midi_source_roundtrip() {
+        let chunk = "<SOURCE MIDI\n  HASDATA 1 960 QN\n  E 0 90 3c 60\n  em 480 80 3c 00\n  \
+                     <X 0 0\n    8H73\n  >\n  E 960 b0 7b 00 1\n>";
+        let events = decode_midi_source_events(chunk.lines()).unwrap();
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0].message, vec![0x90, 0x3c, 0x60]);
+        assert!(events[1].is_selected && events[1].is_muted);
+        assert_eq!(events[1].delta, 480);
+        assert_eq!(events[2].message, vec![0xf0, 0x7e, 0xf7]);
+        assert_eq!(events[3].extra, vec!["1"]);
+        let lines = encode_midi_source_events(&events);
+        assert_eq!(lines[1], "em 480 80 3c 00");
+        assert_eq!(
+            decode_midi_source_events(lines.iter().map(String::as_str)).unwrap(),
+            events
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::Event;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// A set of edits to be applied to a chunk in one pass.
+///
+/// Edits are recorded as byte ranges of the original chunk, typically taken from the `start` and
+/// `end` offsets of the events delivered by [`OneShotParser`](crate::OneShotParser). Everything
+/// which is not touched by an edit stays exactly as it is, including whitespace and line endings.
+///
+/// # Example
+///
+/// ```
+/// use rppxml_parser::{Item, OneShotParser, PatchSet};
+///
+/// let chunk = "<FXCHAIN\n  SHOW 0\n  DOCKED 0\n>\n";
+/// let mut patches = PatchSet::new();
+/// for event in OneShotParser::new(chunk).events() {
+///     if let Item::Attribute(el) = &event.item {
+///         if el.name() == "SHOW" {
+///             patches.replace_event(&event, "  SHOW 1");
+///         }
+///     }
+/// }
+/// assert_eq!(
+///     patches.apply(chunk).unwrap(),
+///     "<FXCHAIN\n  SHOW 1\n  DOCKED 0\n>\n"
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct PatchSet {
+    patches: Vec<Patch>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Patch {
+    range: Range<usize>,
+    replacement: String,
+}
+
+impl PatchSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether no edits have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+
+    /// Replaces the given byte range with the given text.
+    pub fn replace(&mut self, range: Range<usize>, text: impl Into<String>) -> &mut Self {
+        self.patches.push(Patch {
+            range,
+            replacement: text.into(),
+        });
+        self
+    }
+
+    /// Inserts the given text at the given byte position.
+    ///
+    /// Multiple insertions at the same position end up in the order in which they were recorded.
+    pub fn insert(&mut self, position: usize, text: impl Into<String>) -> &mut Self {
+        self.replace(position..position, text)
+    }
+
+    /// Deletes the given byte range.
+    pub fn delete(&mut self, range: Range<usize>) -> &mut Self {
+        self.replace(range, "")
+    }
+
+    /// Replaces the line of the given event (without its line ending) with the given text.
+    pub fn replace_event(&mut self, event: &Event, text: impl Into<String>) -> &mut Self {
+        self.replace(event.start..event.end, text)
+    }
+
+    /// Inserts the given text as new line in front of the line of the given event.
+    ///
+    /// The text must not contain a line ending, it will be added automatically.
+    pub fn insert_line_before_event(&mut self, event: &Event, text: &str) -> &mut Self {
+        let ending = line_ending_at(event.rppxml, event.end).unwrap_or("\n");
+        self.insert(event.start, format!("{text}{ending}"))
+    }
+
+    /// Deletes the line of the given event including its line ending.
+    pub fn delete_event(&mut self, event: &Event) -> &mut Self {
+        let ending_len = line_ending_at(event.rppxml, event.end).map_or(0, str::len);
+        self.delete(event.start..event.end + ending_len)
+    }
+
+    /// Applies all recorded edits to the given chunk and returns the result.
+    ///
+    /// Fails if ranges are out of bounds, don't fall on character boundaries or overlap each other.
+    /// Insertions at the boundary of another edit don't count as overlap.
+    pub fn apply(&self, source: &str) -> Result<String, PatchError> {
+        let mut patches: Vec<&Patch> = self.patches.iter().collect();
+        // Stable sort keeps insertions at the same position in recorded order
+        patches.sort_by_key(|p| (p.range.start, p.range.end));
+        let mut result = String::with_capacity(source.len());
+        let mut position = 0;
+        for patch in patches {
+            let Range { start, end } = patch.range;
+            if start > end || end > source.len() {
+                return Err(PatchError::new("patch range out of bounds"));
+            }
+            if !source.is_char_boundary(start) || !source.is_char_boundary(end) {
+                return Err(PatchError::new("patch range not on character boundary"));
+            }
+            if start < position {
+                return Err(PatchError::new("overlapping patches"));
+            }
+            result.push_str(&source[position..start]);
+            result.push_str(&patch.replacement);
+            position = end;
+        }
+        result.push_str(&source[position..]);
+        Ok(result)
+    }
+}
+
+/// Error which can occur when applying a [`PatchSet`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PatchError {
+    message: &'static str,
+}
+
+impl PatchError {
+    fn new(message: &'static str) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl Error for PatchError {}
+
+fn line_ending_at(source: &str, position: usize) -> Option<&'static str> {
+    let rest = source.get(position..)?;
+    if rest.starts_with("\r\n") {
+        Some("\r\n")
+    } else if rest.starts_with('\n') {
+        Some("\n")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Item, OneShotParser};
+
+    #[test]
+    fn patch_basics() {
+        let chunk = "<FXCHAIN\r\n  SHOW 0\r\n  DOCKED 0\r\n>";
+        let mut patches = PatchSet::new();
+        for event in OneShotParser::new(chunk).events() {
+            match &event.item {
+                Item::Attribute(el) if el.name() == "SHOW" => {
+                    patches.insert_line_before_event(&event, "  LASTSEL 1");
+                }
+                Item::Attribute(el) if el.name() == "DOCKED" => {
+                    patches.delete_event(&event);
+                }
+                _ => {}
+            }
+        }
+        patches.insert(chunk.len(), "\r\n");
+        assert_eq!(
+            patches.apply(chunk).unwrap(),
+            "<FXCHAIN\r\n  LASTSEL 1\r\n  SHOW 0\r\n>\r\n"
+        );
+        patches.replace(0..2, "");
+        assert!(patches.apply(chunk).is_ok());
+        patches.replace(1..3, "");
+        assert!(patches.apply(chunk).is_err());
+    }
+}
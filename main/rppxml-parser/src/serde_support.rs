@@ -0,0 +1,955 @@
+//! Mapping of element trees onto custom types via serde.
+//!
+//! A [`Node`] is treated like a struct/map:
+//!
+//! - Each attribute is an entry named like the attribute. An attribute with one value maps to a
+//!   scalar (`u32`, `bool`, `String`, unit enum variant, ...), an attribute with several values to
+//!   a tuple or array (e.g. `BYPASS 0 0 0` → `(u8, u8, u8)`).
+//! - Each child node is an entry named like the child, mapping to a nested struct/map.
+//! - If an attribute or child occurs several times, use a `Vec` to get all occurrences (a `Vec`
+//!   always represents the occurrences, not the values of an attribute).
+//! - The special entries `$values` and `$content` contain the start tag values and the content
+//!   lines of the node itself (e.g. as `Vec<String>`), `$name` contains its name.
+//!
+//! Booleans are represented as `0` and `1`.
+use crate::{Attribute, Node, NodeItem};
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{
+    Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple,
+    SerializeTupleStruct, Serializer,
+};
+use serde::{forward_to_deserialize_any, Deserialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+const VALUES_KEY: &str = "$values";
+const CONTENT_KEY: &str = "$content";
+const NAME_KEY: &str = "$name";
+
+/// Deserializes a custom type from the given node.
+pub fn from_node<'a, T: Deserialize<'a>>(node: &'a Node) -> Result<T, SerdeError> {
+    T::deserialize(NodeDeserializer(node))
+}
+
+/// Serializes the given value (which must be a struct or map) into a node with the given name.
+pub fn to_node<T: Serialize + ?Sized>(name: &str, value: &T) -> Result<Node, SerdeError> {
+    match value.serialize(ValueSerializer)? {
+        Value::Map(entries) => build_node(name, entries),
+        _ => Err(SerdeError::new(
+            "only structs and maps can be serialized to a node",
+        )),
+    }
+}
+
+/// Error which can occur when mapping between nodes and custom types.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SerdeError {
+    message: String,
+}
+
+impl SerdeError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for SerdeError {}
+
+impl serde::de::Error for SerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+impl serde::ser::Error for SerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+// Deserialization
+
+struct NodeDeserializer<'a>(&'a Node);
+
+impl<'de> Deserializer<'de> for NodeDeserializer<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_map(NodeMapAccess::new(self.0))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Occurrence<'a> {
+    Attribute(&'a Attribute),
+    Node(&'a Node),
+}
+
+enum Entry<'a> {
+    Values(Vec<&'a str>),
+    Name(&'a str),
+    Occurrences(Vec<Occurrence<'a>>),
+}
+
+struct NodeMapAccess<'a> {
+    entries: std::vec::IntoIter<(&'a str, Entry<'a>)>,
+    next_value: Option<Entry<'a>>,
+}
+
+impl<'a> NodeMapAccess<'a> {
+    fn new(node: &'a Node) -> Self {
+        let mut entries: Vec<(&'a str, Entry<'a>)> = vec![
+            (NAME_KEY, Entry::Name(&node.name)),
+            (VALUES_KEY, Entry::Values(strs(&node.values))),
+            (CONTENT_KEY, Entry::Values(node.content().collect())),
+        ];
+        let occurrences = node.items.iter().filter_map(|item| match item {
+            NodeItem::Attribute(a) => Some((a.name.as_str(), Occurrence::Attribute(a))),
+            NodeItem::Child(c) => Some((c.name.as_str(), Occurrence::Node(c))),
+            NodeItem::Content(_) => None,
+        });
+        // Group occurrences with the same name into one entry
+        for (name, occurrence) in occurrences {
+            let existing = entries.iter_mut().find_map(|(n, e)| match e {
+                Entry::Occurrences(o) if *n == name => Some(o),
+                _ => None,
+            });
+            match existing {
+                Some(o) => o.push(occurrence),
+                None => entries.push((name, Entry::Occurrences(vec![occurrence]))),
+            }
+        }
+        Self {
+            entries: entries.into_iter(),
+            next_value: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for NodeMapAccess<'de> {
+    type Error = SerdeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, SerdeError> {
+        let Some((key, entry)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.next_value = Some(entry);
+        seed.deserialize(BorrowedStrDeserializer::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self.next_value.take() {
+            Some(Entry::Values(values)) => seed.deserialize(ValuesDeserializer(values)),
+            Some(Entry::Name(name)) => seed.deserialize(ValueDeserializer(name)),
+            Some(Entry::Occurrences(o)) => seed.deserialize(OccurrencesDeserializer(o)),
+            None => Err(SerdeError::new("value requested before key")),
+        }
+    }
+}
+
+/// Deserializes all occurrences of an attribute or child with a certain name.
+struct OccurrencesDeserializer<'a>(Vec<Occurrence<'a>>);
+
+impl<'de> OccurrencesDeserializer<'de> {
+    fn first(&self) -> Occurrence<'de> {
+        // Entries are only created with at least one occurrence
+        self.0[0]
+    }
+}
+
+macro_rules! delegate_to_first_occurrence {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+                self.first().$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for OccurrencesDeserializer<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        if self.0.len() > 1 {
+            self.deserialize_seq(visitor)
+        } else {
+            self.first().deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_seq(IterSeqAccess(self.0.into_iter()))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.first().deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.first().deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.first().deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.first().deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.first().deserialize_unit_struct(name, visitor)
+    }
+
+    delegate_to_first_occurrence! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf deserialize_unit
+        deserialize_map deserialize_identifier deserialize_ignored_any
+    }
+}
+
+macro_rules! delegate_to_occurrence {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+                match self {
+                    Occurrence::Attribute(a) => ValuesDeserializer(strs(&a.values)).$method(visitor),
+                    Occurrence::Node(n) => NodeDeserializer(n).$method(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Occurrence<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self {
+            Occurrence::Attribute(a) => {
+                ValuesDeserializer(strs(&a.values)).deserialize_tuple(len, visitor)
+            }
+            Occurrence::Node(n) => NodeDeserializer(n).deserialize_tuple(len, visitor),
+        }
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self {
+            Occurrence::Attribute(a) => {
+                ValuesDeserializer(strs(&a.values)).deserialize_tuple_struct(name, len, visitor)
+            }
+            Occurrence::Node(n) => NodeDeserializer(n).deserialize_tuple_struct(name, len, visitor),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self {
+            Occurrence::Attribute(a) => {
+                ValuesDeserializer(strs(&a.values)).deserialize_struct(name, fields, visitor)
+            }
+            Occurrence::Node(n) => NodeDeserializer(n).deserialize_struct(name, fields, visitor),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self {
+            Occurrence::Attribute(a) => {
+                ValuesDeserializer(strs(&a.values)).deserialize_enum(name, variants, visitor)
+            }
+            Occurrence::Node(n) => NodeDeserializer(n).deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self {
+            Occurrence::Attribute(a) => {
+                ValuesDeserializer(strs(&a.values)).deserialize_unit_struct(name, visitor)
+            }
+            Occurrence::Node(n) => NodeDeserializer(n).deserialize_unit_struct(name, visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    delegate_to_occurrence! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+}
+
+/// Deserializes a list of values, e.g. the values of an attribute.
+///
+/// A single value can be deserialized as scalar.
+struct ValuesDeserializer<'a>(Vec<&'a str>);
+
+impl<'de> ValuesDeserializer<'de> {
+    fn single(&self) -> Result<ValueDeserializer<'de>, SerdeError> {
+        match self.0.as_slice() {
+            [value] => Ok(ValueDeserializer(value)),
+            _ => Err(SerdeError::new(format!(
+                "expected exactly one value but found {}",
+                self.0.len()
+            ))),
+        }
+    }
+}
+
+macro_rules! delegate_to_single_value {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+                self.single()?.$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for ValuesDeserializer<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        if self.0.len() == 1 {
+            self.single()?.deserialize_any(visitor)
+        } else {
+            self.deserialize_seq(visitor)
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_seq(IterSeqAccess(self.0.into_iter().map(ValueDeserializer)))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        Err(SerdeError::new("values can't be deserialized as struct"))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        self.single()?.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_unit()
+    }
+
+    delegate_to_single_value! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf deserialize_map
+        deserialize_identifier
+    }
+}
+
+fn strs(values: &[String]) -> Vec<&str> {
+    values.iter().map(String::as_str).collect()
+}
+
+/// Deserializes a single value, parsing it as needed.
+#[derive(Copy, Clone)]
+struct ValueDeserializer<'a>(&'a str);
+
+impl<'de> ValueDeserializer<'de> {
+    fn parse<T: std::str::FromStr>(&self) -> Result<T, SerdeError> {
+        self.0
+            .parse()
+            .map_err(|_| SerdeError::new(format!("couldn't parse value \"{}\"", self.0)))
+    }
+}
+
+macro_rules! parse_value {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        match self.0 {
+            "0" | "false" => visitor.visit_bool(false),
+            "1" | "true" => visitor.visit_bool(true),
+            _ => Err(SerdeError::new(format!("invalid boolean \"{}\"", self.0))),
+        }
+    }
+
+    parse_value! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_enum(BorrowedStrDeserializer::new(self.0))
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct IterSeqAccess<I>(I);
+
+impl<'de, I, D> SeqAccess<'de> for IterSeqAccess<I>
+where
+    I: Iterator<Item = D>,
+    D: Deserializer<'de, Error = SerdeError>,
+{
+    type Error = SerdeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, SerdeError> {
+        self.0.next().map(|d| seed.deserialize(d)).transpose()
+    }
+}
+
+// Serialization
+
+/// Intermediate representation of a serialized value.
+enum Value {
+    Scalar(String),
+    Tuple(Vec<String>),
+    Seq(Vec<Value>),
+    Map(Vec<(String, Value)>),
+    None,
+}
+
+fn build_node(name: &str, entries: Vec<(String, Value)>) -> Result<Node, SerdeError> {
+    let mut node = Node {
+        name: name.to_string(),
+        ..Default::default()
+    };
+    for (key, value) in entries {
+        match key.as_str() {
+            NAME_KEY => {}
+            VALUES_KEY => node.values = into_strings(value)?,
+            CONTENT_KEY => node
+                .items
+                .extend(into_strings(value)?.into_iter().map(NodeItem::Content)),
+            _ => add_entry(&mut node, &key, value, true)?,
+        }
+    }
+    Ok(node)
+}
+
+fn add_entry(node: &mut Node, key: &str, value: Value, allow_seq: bool) -> Result<(), SerdeError> {
+    match value {
+        Value::Scalar(v) => node.items.push(NodeItem::Attribute(Attribute {
+            name: key.to_string(),
+            values: vec![v],
+        })),
+        Value::Tuple(values) => node.items.push(NodeItem::Attribute(Attribute {
+            name: key.to_string(),
+            values,
+        })),
+        Value::Map(entries) => node.items.push(NodeItem::Child(build_node(key, entries)?)),
+        Value::Seq(items) if allow_seq => {
+            for item in items {
+                add_entry(node, key, item, false)?;
+            }
+        }
+        Value::Seq(_) => {
+            return Err(SerdeError::new(format!(
+                "nested sequences are not supported (at \"{key}\")"
+            )))
+        }
+        Value::None => {}
+    }
+    Ok(())
+}
+
+fn into_strings(value: Value) -> Result<Vec<String>, SerdeError> {
+    match value {
+        Value::Scalar(v) => Ok(vec![v]),
+        Value::Tuple(values) => Ok(values),
+        Value::Seq(items) => items.into_iter().map(into_scalar).collect(),
+        Value::None => Ok(vec![]),
+        Value::Map(_) => Err(SerdeError::new("expected values but found map")),
+    }
+}
+
+fn into_scalar(value: Value) -> Result<String, SerdeError> {
+    match value {
+        Value::Scalar(v) => Ok(v),
+        _ => Err(SerdeError::new("expected scalar value")),
+    }
+}
+
+struct ValueSerializer;
+
+macro_rules! serialize_display {
+    ($($method:ident: $ty:ty,)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Value, SerdeError> {
+                Ok(Value::Scalar(v.to_string()))
+            }
+        )*
+    };
+}
+
+impl Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = TupleSerializer;
+    type SerializeTupleStruct = TupleSerializer;
+    type SerializeTupleVariant = Impossible<Value, SerdeError>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = Impossible<Value, SerdeError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, SerdeError> {
+        Ok(Value::Scalar(if v { "1" } else { "0" }.to_string()))
+    }
+
+    serialize_display! {
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Value, SerdeError> {
+        Err(SerdeError::new("bytes are not supported"))
+    }
+
+    fn serialize_none(self) -> Result<Value, SerdeError> {
+        Ok(Value::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, SerdeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, SerdeError> {
+        Ok(Value::Tuple(vec![]))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, SerdeError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, SerdeError> {
+        Ok(Value::Scalar(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, SerdeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Value, SerdeError> {
+        Err(SerdeError::new("newtype variants are not supported"))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, SerdeError> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<TupleSerializer, SerdeError> {
+        Ok(TupleSerializer(Vec::with_capacity(len)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<TupleSerializer, SerdeError> {
+        self.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerdeError> {
+        Err(SerdeError::new("tuple variants are not supported"))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer, SerdeError> {
+        Ok(MapSerializer {
+            entries: Vec::with_capacity(len.unwrap_or(0)),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer, SerdeError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, SerdeError> {
+        Err(SerdeError::new("struct variants are not supported"))
+    }
+}
+
+struct SeqSerializer(Vec<Value>);
+
+impl SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        self.0.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(Value::Seq(self.0))
+    }
+}
+
+struct TupleSerializer(Vec<String>);
+
+impl SerializeTuple for TupleSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        self.0.push(into_scalar(value.serialize(ValueSerializer)?)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(Value::Tuple(self.0))
+    }
+}
+
+impl SerializeTupleStruct for TupleSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        SerializeTuple::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        SerializeTuple::end(self)
+    }
+}
+
+struct MapSerializer {
+    entries: Vec<(String, Value)>,
+    next_key: Option<String>,
+}
+
+impl SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerdeError> {
+        let key = into_scalar(key.serialize(ValueSerializer)?)
+            .map_err(|_| SerdeError::new("map keys must be scalar"))?;
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        let key = self
+            .next_key
+            .take()
+            .ok_or_else(|| SerdeError::new("value serialized before key"))?;
+        self.entries.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(Value::Map(self.entries))
+    }
+}
+
+impl SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerdeError> {
+        self.entries
+            .push((key.to_string(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(Value::Map(self.entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TreeParser;
+    use serde::Serialize;
+
+    #[derive(Deserialize, Serialize, PartialEq, Debug)]
+    #[allow(non_snake_case)]
+    struct FxChain {
+        SHOW: bool,
+        WNDRECT: (i32, i32, i32, i32),
+        VST: Vec<Vst>,
+        JS_SER: Option<Content>,
+        DOCKED: Option<u32>,
+        MISSING: Option<u32>,
+    }
+
+    #[derive(Deserialize, Serialize, PartialEq, Debug)]
+    #[allow(non_snake_case)]
+    struct Vst {
+        #[serde(rename = "$values")]
+        values: Vec<String>,
+        PRESETNAME: Option<String>,
+    }
+
+    #[derive(Deserialize, Serialize, PartialEq, Debug)]
+    struct Content {
+        #[serde(rename = "$content")]
+        lines: Vec<String>,
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let text = include_str!("examples/fx-chain-tag.rpp");
+        let root = TreeParser::new(text).parse().unwrap();
+        let chain: FxChain = from_node(&root).unwrap();
+        assert!(!chain.SHOW);
+        assert_eq!(chain.WNDRECT, (169, 270, 790, 422));
+        assert_eq!(chain.VST.len(), 2);
+        assert_eq!(chain.VST[1].values[1], "Zebra2.vst3");
+        assert_eq!(chain.JS_SER.as_ref().unwrap().lines, vec!["AAAAAA=="]);
+        assert_eq!(chain.MISSING, None);
+        let node = to_node("FXCHAIN", &chain).unwrap();
+        let reparsed: FxChain = from_node(&node).unwrap();
+        assert_eq!(reparsed, chain);
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{Item, OneShotParser};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// This is a tree parser, producing an owned tree of nodes.
+///
+/// Pros:
+///
+/// - Random access to the complete chunk, including convenient query methods.
+///
+/// Cons:
+///
+/// - Allocates for each node, attribute and value.
+///
+/// Verdict: Use this if you need to look at different parts of a chunk at once and don't care
+/// much about performance!
+pub struct TreeParser<'a> {
+    source: &'a str,
+}
+
+impl<'a> TreeParser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source }
+    }
+
+    /// Parses the source into a tree.
+    ///
+    /// The source must contain exactly one top-level element (as chunks do).
+    pub fn parse(&self) -> Result<Node, TreeParseError> {
+        // Nodes which have been started but not yet ended
+        let mut stack: Vec<Node> = vec![];
+        let mut root: Option<Node> = None;
+        for event in OneShotParser::new(self.source).events() {
+            match event.item {
+                Item::StartTag(el) => {
+                    if root.is_some() {
+                        return Err(TreeParseError::new("multiple top-level elements"));
+                    }
+                    stack.push(Node {
+                        name: el.name().to_string(),
+                        values: el.into_values().map(String::from).collect(),
+                        ..Default::default()
+                    });
+                }
+                Item::EndTag => {
+                    let node = stack
+                        .pop()
+                        .ok_or_else(|| TreeParseError::new("end tag without start tag"))?;
+                    match stack.last_mut() {
+                        None => root = Some(node),
+                        Some(parent) => parent.items.push(NodeItem::Child(node)),
+                    }
+                }
+                Item::Attribute(el) => {
+                    let parent = stack
+                        .last_mut()
+                        .ok_or_else(|| TreeParseError::new("attribute outside of element"))?;
+                    parent.items.push(NodeItem::Attribute(Attribute {
+                        name: el.name().to_string(),
+                        values: el.into_values().map(String::from).collect(),
+                    }));
+                }
+                Item::Content(line) => {
+                    let parent = stack
+                        .last_mut()
+                        .ok_or_else(|| TreeParseError::new("content outside of element"))?;
+                    parent.items.push(NodeItem::Content(line.to_string()));
+                }
+                Item::Empty => {}
+            }
+        }
+        if !stack.is_empty() {
+            return Err(TreeParseError::new("element not closed"));
+        }
+        root.ok_or_else(|| TreeParseError::new("no element found"))
+    }
+}
+
+/// An element such as `<FXCHAIN` ... `>`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Node {
+    pub name: String,
+    /// Values following the name in the start tag (with quotes removed).
+    pub values: Vec<String>,
+    /// Everything between the start and the end tag, in source order.
+    pub items: Vec<NodeItem>,
+}
+
+/// A single entry in the body of a [`Node`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NodeItem {
+    Child(Node),
+    Attribute(Attribute),
+    /// A line which is neither a tag nor an attribute, e.g. base64-encoded data.
+    Content(String),
+}
+
+/// A single-line attribute such as `BYPASS 0 0 0`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Attribute {
+    pub name: String,
+    /// Values following the name (with quotes removed).
+    pub values: Vec<String>,
+}
+
+impl Node {
+    /// Returns all direct children.
+    pub fn children(&self) -> impl Iterator<Item = &Node> {
+        self.items.iter().filter_map(|item| match item {
+            NodeItem::Child(c) => Some(c),
+            _ => None,
+        })
+    }
+
+    /// Returns all attributes.
+    pub fn attributes(&self) -> impl Iterator<Item = &Attribute> {
+        self.items.iter().filter_map(|item| match item {
+            NodeItem::Attribute(a) => Some(a),
+            _ => None,
+        })
+    }
+
+    /// Returns all content lines.
+    pub fn content(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().filter_map(|item| match item {
+            NodeItem::Content(line) => Some(line.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns the first direct child with the given name.
+    pub fn child(&self, name: &str) -> Option<&Node> {
+        self.children().find(|c| c.name == name)
+    }
+
+    /// Returns all direct children with the given name.
+    pub fn children_named<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Node> {
+        self.children().filter(move |c| c.name == name)
+    }
+
+    /// Returns the first attribute with the given name.
+    pub fn attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes().find(|a| a.name == name)
+    }
+
+    /// Returns the first descendant (depth-first, not including this node) with the given name.
+    pub fn find_first(&self, name: &str) -> Option<&Node> {
+        self.children().find_map(|c| {
+            if c.name == name {
+                Some(c)
+            } else {
+                c.find_first(name)
+            }
+        })
+    }
+
+    /// Returns all descendants (depth-first, not including this node) with the given name.
+    pub fn find_all<'b>(&'b self, name: &'b str) -> Vec<&'b Node> {
+        let mut result = vec![];
+        self.collect_descendants(name, &mut result);
+        result
+    }
+
+    /// Follows the given slash-separated path of child names, e.g. `"TRACK/FXCHAIN"`.
+    ///
+    /// Each segment picks the first direct child with that name. An empty path returns this node.
+    pub fn at_path(&self, path: &str) -> Option<&Node> {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(self, |node, segment| node.child(segment))
+    }
+
+    fn collect_descendants<'b>(&'b self, name: &str, result: &mut Vec<&'b Node>) {
+        for child in self.children() {
+            if child.name == name {
+                result.push(child);
+            }
+            child.collect_descendants(name, result);
+        }
+    }
+}
+
+/// Writes the node in RPP format, indented by 2 spaces per level.
+///
+/// Attributes, content lines and children are written in their original order. Values are quoted
+/// if necessary.
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_indented(f, 0)
+    }
+}
+
+impl Node {
+    fn write_indented(&self, f: &mut Formatter<'_>, level: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(level);
+        write!(f, "{indent}<{}", self.name)?;
+        write_values(f, &self.values)?;
+        writeln!(f)?;
+        for item in &self.items {
+            match item {
+                NodeItem::Child(child) => child.write_indented(f, level + 1)?,
+                NodeItem::Attribute(attribute) => {
+                    write!(f, "{indent}  {}", attribute.name)?;
+                    write_values(f, &attribute.values)?;
+                    writeln!(f)?;
+                }
+                NodeItem::Content(line) => writeln!(f, "{indent}  {line}")?,
+            }
+        }
+        writeln!(f, "{indent}>")
+    }
+}
+
+impl Attribute {
+    /// Returns the value at the given index.
+    pub fn value(&self, index: usize) -> Option<&str> {
+        self.values.get(index).map(String::as_str)
+    }
+}
+
+fn write_values(f: &mut Formatter<'_>, values: &[String]) -> std::fmt::Result {
+    for value in values {
+        f.write_str(" ")?;
+        if !value.is_empty() && !value.contains(|c: char| c.is_whitespace() || "\"'`".contains(c)) {
+            f.write_str(value)?;
+        } else if !value.contains('"') {
+            write!(f, "\"{value}\"")?;
+        } else if !value.contains('\'') {
+            write!(f, "'{value}'")?;
+        } else {
+            // REAPER itself replaces backticks in this case
+            write!(f, "`{}`", value.replace('`', "'"))?;
+        }
+    }
+    Ok(())
+}
+
+/// Error which can occur when parsing a tree.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TreeParseError {
+    message: &'static str,
+}
+
+impl TreeParseError {
+    fn new(message: &'static str) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for TreeParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl Error for TreeParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tree_basics() {
+        let text = include_str!("examples/fx-chain-tag.rpp");
+        let root = TreeParser::new(text).parse().unwrap();
+        assert_eq!(root.name, "FXCHAIN");
+        assert_eq!(root.attribute("SHOW").unwrap().value(0), Some("0"));
+        let vst = root.child("VST").unwrap();
+        assert_eq!(vst.values[1], "Pianoteq 8.vst3");
+        assert_eq!(vst.content().count(), 5);
+        assert_eq!(root.find_all("JS").len(), root.children_named("JS").count());
+        assert!(root.find_first("JS_SER").is_some());
+        assert_eq!(root.at_path("JS_PINMAP").unwrap().content().count(), 2);
+        assert!(root.at_path("VST/FOO").is_none());
+        let reparsed = TreeParser::new(&root.to_string()).parse().unwrap();
+        assert_eq!(reparsed, root);
+    }
+
+    #[test]
+    fn item_order_roundtrip() {
+        let text = "\
+<FXCHAIN
+  SHOW 0
+  BYPASS 0 0 0
+  <VST \"VST: A\" a.vst
+    AAAA
+  >
+  FXID {A}
+  BYPASS 1 0 0
+  <VST \"VST: B\" b.vst
+    BBBB
+  >
+  FXID {B}
+>
+";
+        let root = TreeParser::new(text).parse().unwrap();
+        let summary: Vec<String> = root
+            .items
+            .iter()
+            .map(|item| match item {
+                NodeItem::Child(c) => c.values[1].clone(),
+                NodeItem::Attribute(a) => format!("{} {}", a.name, a.values[0]),
+                NodeItem::Content(line) => line.clone(),
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            ["SHOW 0", "BYPASS 0", "a.vst", "FXID {A}", "BYPASS 1", "b.vst", "FXID {B}"]
+        );
+        assert_eq!(root.to_string(), text);
+        let reparsed = TreeParser::new(&root.to_string()).parse().unwrap();
+        assert_eq!(reparsed, root);
+    }
+}
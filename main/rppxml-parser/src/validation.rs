@@ -0,0 +1,185 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Checks the given chunk for structural problems.
+///
+/// The parsers are lenient: they never fail and deliver anything they don't understand as
+/// [`Item::Content`](crate::Item::Content). Use this function up-front if you process chunks
+/// which come from an untrusted source (e.g. user input) and want to report problems instead.
+///
+/// Returns all found problems in the order of their occurrence. An empty vector means the chunk
+/// is well-formed.
+pub fn validate(source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    // Open tags with their name and location
+    let mut stack: Vec<(&str, usize, usize)> = vec![];
+    let mut root_closed = false;
+    let mut last_line_number = 0;
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        last_line_number = line_number;
+        let trimmed = line.trim_start();
+        if trimmed.trim_end().is_empty() {
+            continue;
+        }
+        let column = line.len() - trimmed.len() + 1;
+        let mut report = |column, kind| {
+            diagnostics.push(Diagnostic {
+                line: line_number,
+                column,
+                kind,
+            })
+        };
+        if stack.is_empty() && (root_closed || !trimmed.starts_with('<')) {
+            report(column, DiagnosticKind::ContentOutsideOfElement);
+            if !trimmed.starts_with('<') {
+                continue;
+            }
+        }
+        if let Some(remainder) = trimmed.strip_prefix('<') {
+            // Tag names can contain all kinds of characters, e.g. "S&M_TRACKNOTES"
+            let name = remainder.split_whitespace().next().unwrap_or("");
+            if name.is_empty() {
+                report(column + 1, DiagnosticKind::MalformedStartTag);
+            }
+            if let Some(offset) = find_unclosed_quote(remainder) {
+                report(column + 1 + offset, DiagnosticKind::UnclosedQuote);
+            }
+            stack.push((name, line_number, column));
+        } else if let Some(remainder) = trimmed.strip_prefix('>') {
+            if !remainder.trim().is_empty() {
+                report(column + 1, DiagnosticKind::MalformedEndTag);
+            }
+            if stack.pop().is_some() && stack.is_empty() {
+                root_closed = true;
+            }
+        } else if let Some(offset) = find_unclosed_quote(trimmed) {
+            report(column + offset, DiagnosticKind::UnclosedQuote);
+        }
+    }
+    if let Some((name, line, column)) = stack.pop() {
+        diagnostics.push(Diagnostic {
+            line: last_line_number.max(1),
+            column: 1,
+            kind: DiagnosticKind::UnexpectedEof {
+                open_tag: name.to_string(),
+                open_tag_line: line,
+                open_tag_column: column,
+            },
+        });
+    }
+    diagnostics
+}
+
+/// A problem found by [`validate`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Diagnostic {
+    /// Line number (starting at 1).
+    pub line: usize,
+    /// Column (starting at 1, counted in bytes).
+    pub column: usize,
+    pub kind: DiagnosticKind,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DiagnosticKind {
+    /// A line which is not enclosed in an element, e.g. an end tag without start tag.
+    ContentOutsideOfElement,
+    /// A start tag without name.
+    MalformedStartTag,
+    /// An end tag followed by something else.
+    MalformedEndTag,
+    /// A value starting with a quote character which is not closed.
+    UnclosedQuote,
+    /// The end of the chunk has been reached while the given tag was still open.
+    UnexpectedEof {
+        open_tag: String,
+        open_tag_line: usize,
+        open_tag_column: usize,
+    },
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        use DiagnosticKind::*;
+        match &self.kind {
+            ContentOutsideOfElement => f.write_str("content outside of element"),
+            MalformedStartTag => f.write_str("malformed start tag"),
+            MalformedEndTag => f.write_str("malformed end tag"),
+            UnclosedQuote => f.write_str("unclosed quote"),
+            UnexpectedEof {
+                open_tag,
+                open_tag_line,
+                open_tag_column,
+            } => write!(
+                f,
+                "unexpected end of chunk, tag \"{open_tag}\" opened at line {open_tag_line}, \
+                 column {open_tag_column} is not closed"
+            ),
+        }
+    }
+}
+
+impl Error for Diagnostic {}
+
+/// Returns the byte offset of the first value which starts with a quote but isn't closed.
+fn find_unclosed_quote(line: &str) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let quote = bytes[i];
+        if matches!(quote, b'"' | b'\'' | b'`') {
+            let Some(closing) = bytes[start + 1..].iter().position(|b| *b == quote) else {
+                return Some(start);
+            };
+            i = start + 1 + closing + 1;
+            if i < bytes.len() && !bytes[i].is_ascii_whitespace() {
+                // Closing quote must end the value
+                return Some(start);
+            }
+            continue;
+        }
+        while i < bytes.len() && !bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        let text = include_str!("examples/fx-chain-tag.rpp");
+        assert_eq!(validate(text), vec![]);
+        assert_eq!(
+            validate(
+                "<S&M_TRACKNOTES
+  |foo
+>
+"
+            ),
+            vec![]
+        );
+        let diagnostics = validate("<TRACK\n  NAME \"foo\n  <\n  >\n>\n>\n<FXCHAIN\n");
+        let kinds: Vec<_> = diagnostics.iter().map(|d| (d.line, d.column)).collect();
+        assert_eq!(kinds, vec![(2, 8), (3, 4), (6, 1), (7, 1), (7, 1)]);
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::UnclosedQuote);
+        assert_eq!(
+            diagnostics[4].kind,
+            DiagnosticKind::UnexpectedEof {
+                open_tag: "FXCHAIN".to_string(),
+                open_tag_line: 7,
+                open_tag_column: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Maximum length of a base64 line as written by REAPER.
+const BASE64_LINE_LENGTH: usize = 128;
+
+/// Magic number within the header of the VST state (little endian in the chunk).
+const VST_MAGIC: u32 = 0xfeed5eee;
+
+/// Concatenates and decodes the base64-encoded content lines of a tag such as `<VST` or `<AU`.
+///
+/// REAPER splits binary data into several blobs, each of which is base64-encoded separately and
+/// written in lines of at most 128 characters. A blob ends with the first line which is shorter
+/// than that or which ends with padding.
+///
+/// Returns the decoded blobs.
+pub fn decode_base64_blobs(
+    lines: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<Vec<Vec<u8>>, DecodeError> {
+    let mut blobs = vec![];
+    let mut current = String::new();
+    for line in lines {
+        let line = line.as_ref().trim();
+        if line.is_empty() {
+            continue;
+        }
+        current.push_str(line);
+        if line.len() < BASE64_LINE_LENGTH || line.ends_with('=') {
+            blobs.push(decode_base64(&current)?);
+            current.clear();
+        }
+    }
+    if !current.is_empty() {
+        blobs.push(decode_base64(&current)?);
+    }
+    Ok(blobs)
+}
+
+/// Encodes the given blobs into base64 content lines, the way REAPER writes them.
+///
+/// This is the inverse of [`decode_base64_blobs`].
+pub fn encode_base64_blobs(blobs: impl IntoIterator<Item = impl AsRef<[u8]>>) -> Vec<String> {
+    let mut lines = vec![];
+    for blob in blobs {
+        let encoded = base64::encode(blob);
+        // Base64 is ASCII-only, so splitting at arbitrary byte positions is fine
+        lines.extend(
+            encoded
+                .as_bytes()
+                .chunks(BASE64_LINE_LENGTH)
+                .map(|chunk| String::from_utf8_lossy(chunk).into_owned()),
+        );
+    }
+    lines
+}
+
+/// The state of a VST plug-in as contained in the content lines of a `<VST` tag.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct VstState {
+    /// Plug-in unique ID.
+    pub plugin_id: u32,
+    /// One bit mask per plug-in input pin, denoting the track channels routed to it.
+    pub input_pin_masks: Vec<u64>,
+    /// One bit mask per plug-in output pin, denoting the track channels it's routed to.
+    pub output_pin_masks: Vec<u64>,
+    /// Header bytes following the chunk size, preserved as they are.
+    pub header_rest: Vec<u8>,
+    /// The plug-in's own state chunk.
+    pub data: Vec<u8>,
+    pub program_name: String,
+    /// Bytes following the program name, preserved as they are.
+    pub program_rest: Vec<u8>,
+}
+
+impl VstState {
+    /// Decodes the state from the content lines of a `<VST` tag.
+    pub fn decode(lines: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, DecodeError> {
+        let blobs = decode_base64_blobs(lines)?;
+        let (header, rest) = blobs
+            .split_first()
+            .ok_or_else(|| DecodeError::new("VST state is empty"))?;
+        // The program is always the last blob. If there are only 2 blobs, the data is empty.
+        let (program, data_blobs) = rest
+            .split_last()
+            .ok_or_else(|| DecodeError::new("VST state has no program"))?;
+        let mut reader = ByteReader(header);
+        let plugin_id = reader.read_u32()?;
+        if reader.read_u32()? != VST_MAGIC {
+            return Err(DecodeError::new(
+                "VST state header has unexpected magic number",
+            ));
+        }
+        let input_pin_count = reader.read_u32()?;
+        let input_pin_masks = (0..input_pin_count)
+            .map(|_| reader.read_u64())
+            .collect::<Result<_, _>>()?;
+        let output_pin_count = reader.read_u32()?;
+        let output_pin_masks = (0..output_pin_count)
+            .map(|_| reader.read_u64())
+            .collect::<Result<_, _>>()?;
+        // Chunk size, we derive it from the data itself
+        reader.read_u32()?;
+        let header_rest = reader.0.to_vec();
+        let (program_name, program_rest) = match program.split_first() {
+            Some((_, rest)) => {
+                let name_len = rest.iter().position(|b| *b == 0).unwrap_or(rest.len());
+                let name = String::from_utf8_lossy(&rest[..name_len]).into_owned();
+                let program_rest = rest.get(name_len + 1..).unwrap_or_default().to_vec();
+                (name, program_rest)
+            }
+            None => (String::new(), vec![]),
+        };
+        Ok(Self {
+            plugin_id,
+            input_pin_masks,
+            output_pin_masks,
+            header_rest,
+            data: data_blobs.concat(),
+            program_name,
+            program_rest,
+        })
+    }
+
+    /// Encodes the state into content lines for a `<VST` tag.
+    pub fn encode(&self) -> Vec<String> {
+        let mut header = vec![];
+        header.extend_from_slice(&self.plugin_id.to_le_bytes());
+        header.extend_from_slice(&VST_MAGIC.to_le_bytes());
+        header.extend_from_slice(&(self.input_pin_masks.len() as u32).to_le_bytes());
+        for mask in &self.input_pin_masks {
+            header.extend_from_slice(&mask.to_le_bytes());
+        }
+        header.extend_from_slice(&(self.output_pin_masks.len() as u32).to_le_bytes());
+        for mask in &self.output_pin_masks {
+            header.extend_from_slice(&mask.to_le_bytes());
+        }
+        header.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        header.extend_from_slice(&self.header_rest);
+        let mut program = vec![0];
+        program.extend_from_slice(self.program_name.as_bytes());
+        program.push(0);
+        program.extend_from_slice(&self.program_rest);
+        let blobs: &[&[u8]] = if self.data.is_empty() {
+            &[&header, &program]
+        } else {
+            &[&header, &self.data, &program]
+        };
+        encode_base64_blobs(blobs)
+    }
+}
+
+/// Error which can occur when decoding binary data from a chunk.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct DecodeError {
+    message: &'static str,
+}
+
+impl DecodeError {
+    pub(crate) fn new(message: &'static str) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl Error for DecodeError {}
+
+fn decode_base64(encoded: &str) -> Result<Vec<u8>, DecodeError> {
+    base64::decode(encoded).map_err(|_| DecodeError::new("invalid base64 data"))
+}
+
+struct ByteReader<'a>(&'a [u8]);
+
+impl<'a> ByteReader<'a> {
+    fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        if self.0.len() < N {
+            return Err(DecodeError::new("VST state header is truncated"));
+        }
+        let (bytes, rest) = self.0.split_at(N);
+        self.0 = rest;
+        Ok(bytes.try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TreeParser;
+
+    #[test]
+    fn vst_state_roundtrip() {
+        let text = include_str!("examples/fx-chain-tag.rpp");
+        let root = TreeParser::new(text).parse().unwrap();
+        let vst = root.child("VST").unwrap();
+        let state = VstState::decode(vst.content()).unwrap();
+        assert_eq!(state.input_pin_masks, vec![1, 2, 4, 8]);
+        assert_eq!(state.output_pin_masks.len(), 5);
+        assert_eq!(state.program_name, "NY Steinway D Classical");
+        assert_eq!(&state.data[8..12], b"VstW");
+        let encoded = state.encode();
+        assert_eq!(VstState::decode(&encoded).unwrap(), state);
+    }
+}
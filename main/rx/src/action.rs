@@ -4,6 +4,7 @@ use reaper_medium::{
     ActionValueChange, CommandId, HookPostCommand, HookPostCommand2, ReaProject, SectionContext,
     WindowContext,
 };
+#[cfg(feature = "rxrust")]
 use rxrust::prelude::*;
 use std::marker::PhantomData;
 use std::rc::Rc;
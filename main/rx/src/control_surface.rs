@@ -1,28 +1,50 @@
 use crate::{EventStreamSubject, ReactiveEvent};
-use reaper_high::{AvailablePanValue, ChangeEvent, Fx, FxParameter, Project, Track, TrackRoute};
-use reaper_medium::Pan;
+use reaper_high::{
+    AvailablePanValue, ChangeEvent, Envelope, Fx, FxParameter, Item, MetronomeState, Project,
+    Reaper, Track, TrackRoute, TransportState,
+};
+use reaper_medium::{Bpm, Pan, PositionInSeconds};
+#[cfg(feature = "rxrust")]
 use rxrust::prelude::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 
 #[derive(Debug)]
 pub struct ControlSurfaceRxMiddleware {
     rx: ControlSurfaceRx,
+    last_position: Cell<Option<(Project, PositionInSeconds)>>,
 }
 
 impl ControlSurfaceRxMiddleware {
     pub fn new(rx: ControlSurfaceRx) -> ControlSurfaceRxMiddleware {
-        ControlSurfaceRxMiddleware { rx }
+        ControlSurfaceRxMiddleware {
+            rx,
+            last_position: Cell::new(None),
+        }
     }
 
     pub fn run(&self) {
         self.rx.main_thread_idle.borrow_mut().next(());
+        self.sample_position();
+    }
+
+    fn sample_position(&self) {
+        let project = Reaper::get().current_project();
+        let position = project.play_or_edit_cursor_position();
+        let new = Some((project, position));
+        if self.last_position.replace(new) != new {
+            self.rx
+                .position_changed
+                .borrow_mut()
+                .next((project, position));
+        }
     }
 
     pub fn handle_change(&self, event: ChangeEvent) {
         use ChangeEvent::*;
         match event {
             ProjectSwitched(e) => self.rx.project_switched.borrow_mut().next(e.new_project),
+            ProjectOpened(e) => self.rx.project_opened.borrow_mut().next(e.project),
             TrackVolumeChanged(e) => {
                 self.rx
                     .track_volume_changed
@@ -102,7 +124,12 @@ impl ControlSurfaceRxMiddleware {
             FxEnabledChanged(e) => self.rx.fx_enabled_changed.borrow_mut().next(e.fx),
             FxOpened(e) => self.rx.fx_opened.borrow_mut().next(e.fx),
             FxClosed(e) => self.rx.fx_closed.borrow_mut().next(e.fx),
-            FxFocused(e) => self.rx.fx_focused.borrow_mut().next(e.fx),
+            FxFocused(e) => {
+                if e.fx.is_none() {
+                    self.rx.fx_unfocused.borrow_mut().next(());
+                }
+                self.rx.fx_focused.borrow_mut().next(e.fx);
+            }
             FxReordered(e) => self.rx.fx_reordered.borrow_mut().next(e.track),
             FxParameterValueChanged(e) => {
                 self.rx
@@ -116,6 +143,10 @@ impl ControlSurfaceRxMiddleware {
             FxPresetChanged(e) => self.rx.fx_preset_changed.borrow_mut().next(e.fx),
             MasterTempoChanged(e) => {
                 self.rx.master_tempo_changed.borrow_mut().next(());
+                self.rx
+                    .tempo_changed
+                    .borrow_mut()
+                    .next((e.project, e.new_value));
                 if e.touched {
                     self.rx.master_tempo_touched.borrow_mut().next(());
                 }
@@ -125,9 +156,16 @@ impl ControlSurfaceRxMiddleware {
                 if e.touched {
                     self.rx.master_playrate_touched.borrow_mut().next(());
                 }
+                self.notify_transport_state_changed(e.project);
+            }
+            PlayStateChanged(e) => {
+                self.rx.play_state_changed.borrow_mut().next(());
+                self.notify_transport_state_changed(e.project);
+            }
+            RepeatStateChanged(e) => {
+                self.rx.repeat_state_changed.borrow_mut().next(());
+                self.notify_transport_state_changed(e.project);
             }
-            PlayStateChanged(_) => self.rx.play_state_changed.borrow_mut().next(()),
-            RepeatStateChanged(_) => self.rx.repeat_state_changed.borrow_mut().next(()),
             ProjectClosed(e) => self.rx.project_closed.borrow_mut().next(e.project),
             GlobalAutomationOverrideChanged(_) => self
                 .rx
@@ -135,6 +173,21 @@ impl ControlSurfaceRxMiddleware {
                 .borrow_mut()
                 .next(()),
             BookmarksChanged(_) => self.rx.bookmarks_changed.borrow_mut().next(()),
+            TempoMapChanged(e) => self.rx.tempo_map_changed.borrow_mut().next(e.project),
+            ProjectSaved(e) => self.rx.project_saved.borrow_mut().next(e.project),
+            TimeSelectionChanged(e) => self.rx.time_selection_changed.borrow_mut().next(e.project),
+            LoopRangeChanged(e) => self.rx.loop_range_changed.borrow_mut().next(e.project),
+            MetronomeChanged(e) => self.rx.metronome_changed.borrow_mut().next(e.new_value),
+            MidiDevicesChanged(_) => self.rx.midi_devices_changed.borrow_mut().next(()),
+            ItemAdded(e) => self.rx.item_added.borrow_mut().next(e.item),
+            ItemRemoved(e) => self.rx.item_removed.borrow_mut().next(e.item),
+            ItemMoved(e) => self.rx.item_position_changed.borrow_mut().next(e.item),
+            EnvelopeChanged(e) => self.rx.envelope_changed.borrow_mut().next(e.envelope),
+            ItemSelectedChanged(e) => self
+                .rx
+                .item_selected
+                .borrow_mut()
+                .next((e.item, e.new_value)),
             ReceiveCountChanged(e) => self.rx.receive_count_changed.borrow_mut().next(e.track),
             HardwareOutputSendCountChanged(e) => self
                 .rx
@@ -146,12 +199,21 @@ impl ControlSurfaceRxMiddleware {
             _ => {}
         };
     }
+
+    fn notify_transport_state_changed(&self, project: Project) {
+        let state = project.transport().state();
+        self.rx
+            .transport_state_changed
+            .borrow_mut()
+            .next((project, state));
+    }
 }
 
 #[derive(Clone, Default)]
 pub struct ControlSurfaceRx {
     pub main_thread_idle: EventStreamSubject<()>,
     pub project_switched: EventStreamSubject<Project>,
+    pub project_opened: EventStreamSubject<Project>,
     pub global_automation_override_changed: EventStreamSubject<()>,
     pub track_volume_changed: EventStreamSubject<Track>,
     pub track_volume_touched: EventStreamSubject<Track>,
@@ -183,6 +245,7 @@ pub struct ControlSurfaceRx {
     pub fx_opened: EventStreamSubject<Fx>,
     pub fx_closed: EventStreamSubject<Fx>,
     pub fx_focused: EventStreamSubject<Option<Fx>>,
+    pub fx_unfocused: EventStreamSubject<()>,
     pub fx_reordered: EventStreamSubject<Track>,
     pub fx_parameter_value_changed: EventStreamSubject<FxParameter>,
     pub fx_parameter_touched: EventStreamSubject<FxParameter>,
@@ -192,9 +255,23 @@ pub struct ControlSurfaceRx {
     pub master_playrate_changed: EventStreamSubject<()>,
     pub master_playrate_touched: EventStreamSubject<()>,
     pub play_state_changed: EventStreamSubject<()>,
+    pub position_changed: EventStreamSubject<(Project, PositionInSeconds)>,
+    pub tempo_changed: EventStreamSubject<(Project, Bpm)>,
     pub repeat_state_changed: EventStreamSubject<()>,
     pub project_closed: EventStreamSubject<Project>,
     pub bookmarks_changed: EventStreamSubject<()>,
+    pub tempo_map_changed: EventStreamSubject<Project>,
+    pub project_saved: EventStreamSubject<Project>,
+    pub time_selection_changed: EventStreamSubject<Project>,
+    pub loop_range_changed: EventStreamSubject<Project>,
+    pub transport_state_changed: EventStreamSubject<(Project, TransportState)>,
+    pub metronome_changed: EventStreamSubject<MetronomeState>,
+    pub midi_devices_changed: EventStreamSubject<()>,
+    pub item_added: EventStreamSubject<Item>,
+    pub item_removed: EventStreamSubject<Item>,
+    pub item_position_changed: EventStreamSubject<Item>,
+    pub item_selected: EventStreamSubject<(Item, bool)>,
+    pub envelope_changed: EventStreamSubject<Envelope>,
 }
 
 impl fmt::Debug for ControlSurfaceRx {
@@ -206,11 +283,12 @@ impl fmt::Debug for ControlSurfaceRx {
 impl ControlSurfaceRx {
     pub fn new() -> ControlSurfaceRx {
         fn default<T>() -> EventStreamSubject<T> {
-            RefCell::new(LocalSubject::new())
+            RefCell::new(ReactiveEvent::new())
         }
         ControlSurfaceRx {
             main_thread_idle: default(),
             project_switched: default(),
+            project_opened: default(),
             global_automation_override_changed: default(),
             track_volume_changed: default(),
             track_volume_touched: default(),
@@ -241,6 +319,7 @@ impl ControlSurfaceRx {
             fx_opened: default(),
             fx_closed: default(),
             fx_focused: default(),
+            fx_unfocused: default(),
             fx_reordered: default(),
             fx_parameter_value_changed: default(),
             fx_parameter_touched: default(),
@@ -250,9 +329,23 @@ impl ControlSurfaceRx {
             master_playrate_changed: default(),
             master_playrate_touched: default(),
             play_state_changed: default(),
+            position_changed: default(),
+            tempo_changed: default(),
             repeat_state_changed: default(),
             project_closed: default(),
             bookmarks_changed: default(),
+            tempo_map_changed: default(),
+            project_saved: default(),
+            time_selection_changed: default(),
+            loop_range_changed: default(),
+            transport_state_changed: default(),
+            metronome_changed: default(),
+            midi_devices_changed: default(),
+            item_added: default(),
+            item_removed: default(),
+            item_position_changed: default(),
+            item_selected: default(),
+            envelope_changed: default(),
         }
     }
 
@@ -260,6 +353,11 @@ impl ControlSurfaceRx {
         self.project_switched.borrow().clone()
     }
 
+    /// Fires when a project has been opened or created in a new project tab.
+    pub fn project_opened(&self) -> ReactiveEvent<Project> {
+        self.project_opened.borrow().clone()
+    }
+
     pub fn global_automation_override_changed(&self) -> ReactiveEvent<()> {
         self.global_automation_override_changed.borrow().clone()
     }
@@ -268,6 +366,55 @@ impl ControlSurfaceRx {
         self.bookmarks_changed.borrow().clone()
     }
 
+    pub fn tempo_map_changed(&self) -> ReactiveEvent<Project> {
+        self.tempo_map_changed.borrow().clone()
+    }
+
+    pub fn project_saved(&self) -> ReactiveEvent<Project> {
+        self.project_saved.borrow().clone()
+    }
+
+    pub fn time_selection_changed(&self) -> ReactiveEvent<Project> {
+        self.time_selection_changed.borrow().clone()
+    }
+
+    pub fn loop_range_changed(&self) -> ReactiveEvent<Project> {
+        self.loop_range_changed.borrow().clone()
+    }
+
+    pub fn metronome_changed(&self) -> ReactiveEvent<MetronomeState> {
+        self.metronome_changed.borrow().clone()
+    }
+
+    /// Fires when MIDI devices have been connected or disconnected.
+    pub fn midi_devices_changed(&self) -> ReactiveEvent<()> {
+        self.midi_devices_changed.borrow().clone()
+    }
+
+    pub fn item_added(&self) -> ReactiveEvent<Item> {
+        self.item_added.borrow().clone()
+    }
+
+    /// The emitted item is not valid anymore, it's just for identification.
+    pub fn item_removed(&self) -> ReactiveEvent<Item> {
+        self.item_removed.borrow().clone()
+    }
+
+    /// Fires when an item has been moved in time or to another track.
+    pub fn item_position_changed(&self) -> ReactiveEvent<Item> {
+        self.item_position_changed.borrow().clone()
+    }
+
+    /// Fires when an item has been selected (`true`) or unselected (`false`).
+    pub fn item_selected(&self) -> ReactiveEvent<(Item, bool)> {
+        self.item_selected.borrow().clone()
+    }
+
+    /// Fires when points of a track envelope have been added, removed or edited.
+    pub fn envelope_changed(&self) -> ReactiveEvent<Envelope> {
+        self.envelope_changed.borrow().clone()
+    }
+
     pub fn fx_opened(&self) -> ReactiveEvent<Fx> {
         self.fx_opened.borrow().clone()
     }
@@ -276,10 +423,20 @@ impl ControlSurfaceRx {
         self.fx_closed.borrow().clone()
     }
 
+    /// Fires when an FX window gets focused (or `None` if no FX window is focused anymore).
+    ///
+    /// Use [`Fx::query_index()`] to get the location of the FX within its chain.
+    ///
+    /// [`Fx::query_index()`]: reaper_high::Fx::query_index
     pub fn fx_focused(&self) -> ReactiveEvent<Option<Fx>> {
         self.fx_focused.borrow().clone()
     }
 
+    /// Fires when no FX window is focused anymore.
+    pub fn fx_unfocused(&self) -> ReactiveEvent<()> {
+        self.fx_unfocused.borrow().clone()
+    }
+
     pub fn track_added(&self) -> ReactiveEvent<Track> {
         self.track_added.borrow().clone()
     }
@@ -325,14 +482,42 @@ impl ControlSurfaceRx {
         self.master_playrate_touched.borrow().clone()
     }
 
+    /// Fires when playback has been started, paused or stopped or recording has been started or
+    /// stopped.
+    ///
+    /// Emitted synchronously from within the control surface callback, so it's delivered in the
+    /// same main loop cycle in which REAPER notifies us.
     pub fn play_state_changed(&self) -> ReactiveEvent<()> {
         self.play_state_changed.borrow().clone()
     }
 
+    /// Fires with the play position (when playing) or edit cursor position (when stopped) of the
+    /// current project.
+    ///
+    /// The position is sampled once per control surface run loop cycle (about 30 times per
+    /// second), right after `main_thread_idle`, and emitted only if it has changed since the last
+    /// cycle. It's therefore not suitable for sample-accurate timing.
+    pub fn position_changed(&self) -> ReactiveEvent<(Project, PositionInSeconds)> {
+        self.position_changed.borrow().clone()
+    }
+
+    /// Fires with the new master tempo.
+    ///
+    /// Emitted synchronously from within the control surface callback. Due to REAPER API
+    /// limitations, the project is always the current project.
+    pub fn tempo_changed(&self) -> ReactiveEvent<(Project, Bpm)> {
+        self.tempo_changed.borrow().clone()
+    }
+
     pub fn repeat_state_changed(&self) -> ReactiveEvent<()> {
         self.repeat_state_changed.borrow().clone()
     }
 
+    /// Fires whenever the play state, repeat state or play rate changes.
+    pub fn transport_state_changed(&self) -> ReactiveEvent<(Project, TransportState)> {
+        self.transport_state_changed.borrow().clone()
+    }
+
     pub fn fx_added(&self) -> ReactiveEvent<Fx> {
         self.fx_added.borrow().clone()
     }
@@ -10,5 +10,16 @@ pub use midi::*;
 mod main_thread;
 pub use main_thread::*;
 
+#[cfg(feature = "rxrust")]
+mod operators;
+
+#[cfg(not(feature = "rxrust"))]
+mod subject;
+#[cfg(not(feature = "rxrust"))]
+pub use subject::*;
+
+mod stream;
+pub use stream::*;
+
 mod types;
 pub use types::*;
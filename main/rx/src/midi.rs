@@ -1,6 +1,7 @@
 use crate::ReactiveEvent;
 use helgoboss_midi::{RawShortMessage, ShortMessage, ShortMessageType};
 use reaper_medium::{MidiInputDeviceId, OnAudioBufferArgs, RealTimeAudioThreadScope};
+#[cfg(feature = "rxrust")]
 use rxrust::prelude::*;
 
 pub struct MidiRxMiddleware {
@@ -10,7 +11,7 @@ pub struct MidiRxMiddleware {
 
 #[derive(Clone, Default)]
 pub struct MidiRx {
-    midi_message_received: LocalSubject<'static, MidiEvent<RawShortMessage>, ()>,
+    midi_message_received: ReactiveEvent<MidiEvent<RawShortMessage>>,
 }
 
 impl MidiRxMiddleware {
@@ -0,0 +1,59 @@
+use crate::ControlSurfaceRx;
+use rxrust::prelude::*;
+use std::cell::Cell;
+
+/// Operators which are aware of the control surface run loop.
+///
+/// Some events are fired in bursts, e.g. `SETSURFACEVOLUME` is called for each tiny volume change
+/// during a fade. Reacting to each of them is wasteful and - if the reaction involves scheduling
+/// closures - can make queues grow without bound. These operators coalesce such bursts so that
+/// at most one value (the latest one) gets through per control surface cycle.
+///
+/// Like `main_thread_idle()`, they only work if `run()` is called on the driver.
+impl ControlSurfaceRx {
+    /// Emits the latest value of the given source at most once per control surface cycle.
+    ///
+    /// Values are emitted right after `main_thread_idle()` fires. Cycles in which the source
+    /// didn't emit anything don't produce an emission.
+    pub fn sample_per_cycle<T, O>(
+        &self,
+        source: O,
+    ) -> impl LocalObservable<'static, Item = T, Err = ()>
+    where
+        T: Clone + 'static,
+        O: LocalObservable<'static, Item = T, Err = ()>,
+    {
+        source.sample(self.main_thread_idle())
+    }
+
+    /// Emits the latest value of the given source at most once every `n` control surface cycles.
+    ///
+    /// `throttle_cycles(1)` is equivalent to `sample_per_cycle()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn throttle_cycles<T, O>(
+        &self,
+        source: O,
+        n: u32,
+    ) -> impl LocalObservable<'static, Item = T, Err = ()>
+    where
+        T: Clone + 'static,
+        O: LocalObservable<'static, Item = T, Err = ()>,
+    {
+        assert!(n > 0, "cycle count must be greater than 0");
+        let elapsed_cycles = Cell::new(0u32);
+        let every_nth_cycle = self.main_thread_idle().filter(move |_| {
+            let count = elapsed_cycles.get() + 1;
+            if count < n {
+                elapsed_cycles.set(count);
+                false
+            } else {
+                elapsed_cycles.set(0);
+                true
+            }
+        });
+        source.sample(every_nth_cycle)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::ReactiveEvent;
+use futures::channel::mpsc;
+use futures::Stream;
+#[cfg(feature = "rxrust")]
+use rxrust::prelude::*;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Conversion of reactive events into [`futures::Stream`]s.
+///
+/// Makes it possible to consume events in async code, e.g. in futures spawned via
+/// [`FutureSupport::spawn_in_main_thread_from_main_thread()`], without dealing with rxRust at
+/// all:
+///
+/// ```ignore
+/// let mut track_added = rx.track_added().into_stream();
+/// future_support.spawn_in_main_thread_from_main_thread(async move {
+///     while let Some(track) = track_added.next().await {
+///         // ...
+///     }
+///     Ok(())
+/// });
+/// ```
+///
+/// [`FutureSupport::spawn_in_main_thread_from_main_thread()`]:
+/// reaper_high::FutureSupport::spawn_in_main_thread_from_main_thread
+pub trait ReactiveEventStreamExt<T> {
+    /// Subscribes to this event and returns a stream yielding each subsequent value.
+    ///
+    /// Values are buffered until the stream is polled. The subscription ends when the stream is
+    /// dropped.
+    fn into_stream(self) -> EventStream<T>;
+}
+
+impl<T: Clone + 'static> ReactiveEventStreamExt<T> for ReactiveEvent<T> {
+    fn into_stream(self) -> EventStream<T> {
+        let (sender, receiver) = mpsc::unbounded();
+        let subscription = self.subscribe(move |value| {
+            // If the receiver is gone, the subscription is about to be ended anyway.
+            let _ = sender.unbounded_send(value);
+        });
+        EventStream {
+            receiver,
+            unsubscribe: Some(unsubscribe_fn(subscription)),
+        }
+    }
+}
+
+/// A stream of event values, created via [`ReactiveEventStreamExt::into_stream()`].
+pub struct EventStream<T> {
+    receiver: mpsc::UnboundedReceiver<T>,
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+impl<T> Drop for EventStream<T> {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl<T> fmt::Debug for EventStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventStream").finish()
+    }
+}
+
+#[cfg(feature = "rxrust")]
+fn unsubscribe_fn(mut subscription: impl SubscriptionLike + 'static) -> Box<dyn FnOnce()> {
+    Box::new(move || subscription.unsubscribe())
+}
+
+#[cfg(not(feature = "rxrust"))]
+fn unsubscribe_fn(subscription: crate::LocalSubscription) -> Box<dyn FnOnce()> {
+    Box::new(move || subscription.unsubscribe())
+}
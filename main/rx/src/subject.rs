@@ -0,0 +1,118 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+/// A minimal, single-threaded event subject used when the `rxrust` feature is disabled.
+///
+/// Mirrors the small part of rxRust's `LocalSubject` API which is needed for consuming the
+/// events of this crate: cloning (all clones share the same observers), `next()`, `subscribe()`
+/// and `subscribed_size()`. There are no operators. If you need them, enable the `rxrust`
+/// feature (which requires nightly Rust).
+pub struct LocalSubject<T> {
+    observers: Rc<RefCell<Vec<ObserverEntry<T>>>>,
+    next_observer_id: Rc<Cell<u64>>,
+}
+
+struct ObserverEntry<T> {
+    id: u64,
+    callback: Rc<RefCell<dyn FnMut(T)>>,
+}
+
+impl<T> Clone for ObserverEntry<T> {
+    fn clone(&self) -> Self {
+        ObserverEntry {
+            id: self.id,
+            callback: self.callback.clone(),
+        }
+    }
+}
+
+impl<T> LocalSubject<T> {
+    pub fn new() -> LocalSubject<T> {
+        LocalSubject {
+            observers: Default::default(),
+            next_observer_id: Default::default(),
+        }
+    }
+
+    /// Returns the number of current observers.
+    pub fn subscribed_size(&self) -> usize {
+        self.observers.borrow().len()
+    }
+}
+
+impl<T: Clone + 'static> LocalSubject<T> {
+    /// Emits the given value to all current observers.
+    ///
+    /// Observers which subscribe or unsubscribe while the value is emitted don't affect the
+    /// current emission. Emitting a value to an observer which is currently handling a value
+    /// (reentrancy) panics.
+    pub fn next(&mut self, value: T) {
+        let observers = self.observers.borrow().clone();
+        for observer in observers {
+            (observer.callback.borrow_mut())(value.clone());
+        }
+    }
+
+    /// Registers the given function to be called for each subsequent value.
+    ///
+    /// The subscription lasts until [`LocalSubscription::unsubscribe`] is called.
+    pub fn subscribe(self, next: impl FnMut(T) + 'static) -> LocalSubscription {
+        let id = self.next_observer_id.get();
+        self.next_observer_id.set(id + 1);
+        self.observers.borrow_mut().push(ObserverEntry {
+            id,
+            callback: Rc::new(RefCell::new(next)),
+        });
+        let observers = Rc::downgrade(&self.observers);
+        LocalSubscription {
+            unsubscribe: Box::new(move || {
+                if let Some(observers) = observers.upgrade() {
+                    observers.borrow_mut().retain(|o| o.id != id);
+                }
+            }),
+        }
+    }
+}
+
+impl<T> Clone for LocalSubject<T> {
+    fn clone(&self) -> Self {
+        LocalSubject {
+            observers: self.observers.clone(),
+            next_observer_id: self.next_observer_id.clone(),
+        }
+    }
+}
+
+impl<T> Default for LocalSubject<T> {
+    fn default() -> Self {
+        LocalSubject::new()
+    }
+}
+
+impl<T> fmt::Debug for LocalSubject<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSubject")
+            .field("observer_count", &self.observers.borrow().len())
+            .finish()
+    }
+}
+
+/// Handle to a subscription created via [`LocalSubject::subscribe`].
+///
+/// Dropping it doesn't end the subscription.
+pub struct LocalSubscription {
+    unsubscribe: Box<dyn FnOnce()>,
+}
+
+impl LocalSubscription {
+    pub fn unsubscribe(self) {
+        (self.unsubscribe)();
+    }
+}
+
+impl fmt::Debug for LocalSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSubscription").finish()
+    }
+}
@@ -1,7 +1,13 @@
+#[cfg(not(feature = "rxrust"))]
+use crate::LocalSubject;
+#[cfg(feature = "rxrust")]
 use rxrust::prelude::*;
 use std::cell::RefCell;
 
+#[cfg(feature = "rxrust")]
 pub type ReactiveEvent<T> = LocalSubject<'static, T, ()>;
+#[cfg(not(feature = "rxrust"))]
+pub type ReactiveEvent<T> = LocalSubject<T>;
 
 // This is a RefCell. So calling next() while another next() is still running will panic.
 // I guess it's good that way because this is very generic code, panicking or not panicking
@@ -10,4 +16,4 @@ pub type ReactiveEvent<T> = LocalSubject<'static, T, ()>;
 // the next `ControlSurface::run()` invocation (execute things in next main loop cycle).
 //
 // Mutex is not necessary because control surface methods are called from main thread only.
-pub(crate) type EventStreamSubject<T> = RefCell<LocalSubject<'static, T, ()>>;
+pub(crate) type EventStreamSubject<T> = RefCell<ReactiveEvent<T>>;
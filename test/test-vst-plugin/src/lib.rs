@@ -1,14 +1,15 @@
 use futures_timer::Delay;
 use reaper_high::{
-    ActionKind, FutureMiddleware, FutureSupport, PluginInfo, Reaper, ReaperGuard,
-    DEFAULT_MAIN_THREAD_TASK_BULK_SIZE,
+    real_time_channel, ActionKind, FutureMiddleware, FutureSupport, PluginInfo, RealTimeReceiver,
+    RealTimeSender, Reaper, ReaperGuard, DEFAULT_MAIN_THREAD_TASK_BULK_SIZE,
 };
 use reaper_low::{reaper_vst_plugin, static_plugin_context, PluginContext};
-use reaper_medium::{CommandId, ControlSurface, HookPostCommand, OnAudioBuffer, OnAudioBufferArgs};
+use reaper_medium::{
+    CommandId, ControlSurface, HookPostCommand, OnAudioBuffer, OnAudioBufferArgs, Registration,
+};
 use reaper_rx::{ControlSurfaceRx, ControlSurfaceRxMiddleware};
 use rxrust::prelude::*;
 use std::error::Error;
-use std::sync::mpsc::{channel, Receiver};
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::debug;
@@ -23,6 +24,7 @@ reaper_vst_plugin!();
 struct TestVstPlugin {
     host: HostCallback,
     _session: Option<reaper_medium::ReaperSession>,
+    _control_surface_registration: Option<Registration>,
     _reaper_guard: Option<Arc<ReaperGuard>>,
 }
 
@@ -32,6 +34,7 @@ impl Plugin for TestVstPlugin {
         Self {
             host,
             _session: None,
+            _control_surface_registration: None,
             _reaper_guard: None,
         }
     }
@@ -50,22 +53,32 @@ impl Plugin for TestVstPlugin {
     }
 }
 
+/// Sent from the audio thread. Formatting happens in the main thread because it allocates.
+#[derive(Debug)]
+struct AudioBufferInfo {
+    counter: u64,
+    is_post: bool,
+    len: u32,
+    input_channel_count: u32,
+    output_channel_count: u32,
+}
+
 struct MyOnAudioBuffer {
-    sender: std::sync::mpsc::Sender<String>,
+    sender: RealTimeSender<AudioBufferInfo>,
     counter: u64,
 }
 
 impl OnAudioBuffer for MyOnAudioBuffer {
     fn call(&mut self, args: OnAudioBufferArgs) {
         if self.counter % 100 == 0 {
-            self.sender
-                .send(format!(
-                    "Counter: {}, Args: {:?}, Channels: {:?}\n",
-                    self.counter,
-                    args,
-                    (args.reg.input_nch(), args.reg.output_nch())
-                ))
-                .expect("couldn't send console logging message to main thread");
+            // If the main thread doesn't keep up, we just skip this one.
+            let _ = self.sender.try_send(AudioBufferInfo {
+                counter: self.counter,
+                is_post: args.is_post,
+                len: args.len,
+                input_channel_count: args.reg.input_nch(),
+                output_channel_count: args.reg.output_nch(),
+            });
         }
         self.counter += 1;
     }
@@ -87,20 +100,20 @@ impl TestVstPlugin {
         let low = reaper_low::Reaper::load(context);
         let mut med = reaper_medium::ReaperSession::new(low);
         {
-            let (sender, receiver) = channel::<String>();
+            let (sender, receiver) = real_time_channel::<AudioBufferInfo>(100);
             med.reaper()
                 .show_console_msg("Registering control surface ...");
             #[derive(Debug)]
             struct MyControlSurface {
                 reaper: reaper_medium::Reaper,
-                receiver: Receiver<String>,
+                receiver: RealTimeReceiver<AudioBufferInfo>,
             }
 
             impl ControlSurface for MyControlSurface {
                 fn run(&mut self) {
-                    for msg in self.receiver.try_iter() {
-                        self.reaper.show_console_msg(msg);
-                    }
+                    self.receiver.drain(100, |info| {
+                        self.reaper.show_console_msg(format!("{info:?}\n"));
+                    });
                 }
 
                 fn set_track_list_change(&self) {
@@ -190,11 +203,9 @@ impl TestVstPlugin {
             FutureMiddleware::new(executor, local_executor),
         );
         let reaper = Reaper::get();
-        // TODO-medium This should be unregistered when VST plug-in removed.
-        reaper
-            .medium_session()
-            .plugin_register_add_csurf_inst(Box::new(control_surface))
-            .unwrap();
+        // Unregistered when VST plug-in removed
+        let registration = reaper.register_control_surface(control_surface).unwrap();
+        self._control_surface_registration = Some(registration);
         control_surface_rx.main_thread_idle().subscribe(move |_| {
             if counter > 10 {
                 return;
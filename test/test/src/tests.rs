@@ -9,7 +9,7 @@ use c_str_macro::c_str;
 use reaper_high::{
     get_media_track_guid, toggleable, ActionCharacter, ActionKind, FxChain, FxInfo,
     FxParameterCharacter, GroupingBehavior, Guid, Pan, PlayRate, Reaper, SendPartnerType,
-    SliderVolume, Tempo, Track, TrackRoutePartner, TrackSetSmartOpts, Width,
+    SliderVolume, Tempo, TimeRange, Track, TrackRoutePartner, TrackSetSmartOpts, Width,
 };
 use rxrust::prelude::*;
 
@@ -787,10 +787,10 @@ fn set_time_ranges() -> TestStep {
         // Given
         let project = Reaper::get().current_project();
         // When
-        project.set_time_selection(
+        project.set_time_selection(TimeRange::new_panic(
             PositionInSeconds::new_panic(5.0),
             PositionInSeconds::new_panic(7.0),
-        );
+        ));
         project.set_loop_points(
             PositionInSeconds::new_panic(5.0),
             PositionInSeconds::new_panic(7.0),
@@ -798,11 +798,11 @@ fn set_time_ranges() -> TestStep {
         );
         // Then
         let time_selection = project.time_selection().unwrap();
-        assert!(abs_diff_eq!(time_selection.start.get(), 5.0));
-        assert!(abs_diff_eq!(time_selection.end.get(), 7.0));
+        assert!(abs_diff_eq!(time_selection.start().get(), 5.0));
+        assert!(abs_diff_eq!(time_selection.end().get(), 7.0));
         let loop_points = project.loop_points().unwrap();
-        assert!(abs_diff_eq!(loop_points.start.get(), 5.0));
-        assert!(abs_diff_eq!(loop_points.end.get(), 7.0));
+        assert!(abs_diff_eq!(loop_points.start().get(), 5.0));
+        assert!(abs_diff_eq!(loop_points.end().get(), 7.0));
         Ok(())
     })
 }
@@ -2554,7 +2554,7 @@ fn low_plugin_context() -> TestStep {
                 );
                 assert!(result > 0);
             },
-            Vst(_ctx) => {}
+            Vst(_) | Clap(_) | Other(_) => {}
         };
         Ok(())
     })
@@ -2589,6 +2589,10 @@ fn medium_plugin_context() -> TestStep {
                 // TODO-medium We must pass the AEffect for this to work. Refactor test step API
                 //  a bit so that it only takes one parameter which also contains passed AEffect.
             }
+            Clap(ctx) => {
+                assert_eq!(ctx.caller_version(), raw::REAPER_PLUGIN_VERSION);
+            }
+            Other => {}
         };
         Ok(())
     })